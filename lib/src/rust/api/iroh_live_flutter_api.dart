@@ -5,758 +5,1143 @@
 
 import '../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'iroh_live_flutter_api.freezed.dart';
 
-// These functions are ignored because they are not marked as `pub`: `generate_color_bars`, `generate_gradient`, `generate_moving_box`
+            // These functions are ignored because they are not marked as `pub`: `audio_rendition`, `catalog_from_flutter`, `catalog_to_flutter`, `chat_kind`, `generate_color_bars`, `generate_gradient`, `generate_moving_box`, `init_node`, `new`, `pcm_to_f32`, `video_rendition`
 // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `CaptureState`, `PublishState`, `SubscribeState`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`, `from`, `from`, `try_from`, `try_from`
 
-/// Initialize the iroh-live node
-Future<String> irohNodeInit() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeInit();
+
+            /// Initialize the iroh-live node using the n0 relays
+///
+/// A new identity is generated, so tickets change on every launch; use
+/// `iroh_node_init_with_key` or `iroh_node_init_from_file` to keep them.
+Future<String>  irohNodeInit() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeInit();
+
+/// Initialize the iroh-live node with custom relay, port and discovery settings
+///
+/// With relays disabled startup doesn't wait for internet connectivity.
+/// The settings only apply when the node is first created.
+Future<String>  irohNodeInitWithConfig({required FlutterEndpointConfig config }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeInitWithConfig(config: config);
+
+/// Initialize the iroh-live node with a stored identity
+///
+/// `secret_key` is the 32 bytes from `iroh_identity_generate` or
+/// `iroh_node_export_key`, typically kept in the platform's secure storage.
+Future<String>  irohNodeInitWithKey({required List<int> secretKey , FlutterEndpointConfig? config }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeInitWithKey(secretKey: secretKey, config: config);
+
+/// Initialize the iroh-live node with the identity stored at `path`
+///
+/// The key file is created on first launch and reused afterwards, so the
+/// endpoint ID and shared tickets stay the same across restarts.
+Future<String>  irohNodeInitFromFile({required String path , FlutterEndpointConfig? config }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeInitFromFile(path: path, config: config);
 
 /// Get the node's endpoint ID
-Future<String> irohNodeGetEndpointId() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeGetEndpointId();
+Future<String>  irohNodeGetEndpointId() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeGetEndpointId();
+
+/// Export the running node's secret key, to persist its identity
+Future<Uint8List>  irohNodeExportKey() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeExportKey();
+
+/// Generate a new identity (32 secret key bytes) for secure storage
+Uint8List  irohIdentityGenerate() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohIdentityGenerate();
+
+/// Endpoint ID belonging to a secret key
+String  irohIdentityEndpointId({required List<int> secretKey }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohIdentityEndpointId(secretKey: secretKey);
 
 /// Shutdown the node
-Future<void> irohNodeShutdown() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeShutdown();
+Future<void>  irohNodeShutdown() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeShutdown();
 
 /// Initialize the capture system
-bool irohCaptureInit() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureInit();
+bool  irohCaptureInit() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureInit();
 
 /// List available capture devices
-List<FlutterCaptureDevice> irohCaptureListDevices() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureListDevices();
+List<FlutterCaptureDevice>  irohCaptureListDevices() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureListDevices();
 
 /// Start capturing from a device
-bool irohCaptureStart({required String deviceId}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohCaptureStart(deviceId: deviceId);
+bool  irohCaptureStart({required String deviceId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureStart(deviceId: deviceId);
 
 /// Stop capturing
-bool irohCaptureStop() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureStop();
+bool  irohCaptureStop() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureStop();
 
 /// Get current capture device
-String? irohCaptureCurrentDevice() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureCurrentDevice();
+String?  irohCaptureCurrentDevice() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureCurrentDevice();
 
 /// Generate a test frame (for testing)
-FlutterVideoFrame irohCaptureGetTestFrame({
-  required int width,
-  required int height,
-  required String pattern,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureGetTestFrame(
-  width: width,
-  height: height,
-  pattern: pattern,
-);
+FlutterVideoFrame  irohCaptureGetTestFrame({required int width , required int height , required String pattern }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCaptureGetTestFrame(width: width, height: height, pattern: pattern);
 
 /// Create a new publisher and get the broadcast ticket
 /// Returns the ticket string that can be shared with subscribers
-Future<String> irohPublishCreateAsync({
-  required String publisherId,
-  required String broadcastName,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishCreateAsync(
-  publisherId: publisherId,
-  broadcastName: broadcastName,
-);
+Future<String>  irohPublishCreateAsync({required String publisherId , required String broadcastName }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishCreateAsync(publisherId: publisherId, broadcastName: broadcastName);
 
 /// Create a new publisher (sync version for compatibility)
-bool irohPublishCreate({required String publisherId}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohPublishCreate(publisherId: publisherId);
+bool  irohPublishCreate({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishCreate(publisherId: publisherId);
 
 /// Start publishing (async version with real backend)
-Future<void> irohPublishStartAsync({required String publisherId}) => RustLib
-    .instance
-    .api
-    .crateApiIrohLiveFlutterApiIrohPublishStartAsync(publisherId: publisherId);
+Future<void>  irohPublishStartAsync({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishStartAsync(publisherId: publisherId);
 
 /// Start publishing (sync version for compatibility)
-bool irohPublishStart({required String publisherId}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohPublishStart(publisherId: publisherId);
+bool  irohPublishStart({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishStart(publisherId: publisherId);
 
 /// Stop publishing (async version)
-Future<void> irohPublishStopAsync({required String publisherId}) => RustLib
-    .instance
-    .api
-    .crateApiIrohLiveFlutterApiIrohPublishStopAsync(publisherId: publisherId);
+Future<void>  irohPublishStopAsync({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishStopAsync(publisherId: publisherId);
 
 /// Stop publishing (sync version)
-bool irohPublishStop({required String publisherId}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohPublishStop(publisherId: publisherId);
+bool  irohPublishStop({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishStop(publisherId: publisherId);
+
+/// Publish an MP4 file (H.264 with AAC or Opus) as a live broadcast
+///
+/// Viewers subscribe with the publisher's ticket as for camera broadcasts.
+/// Returns the file's duration in milliseconds.
+Future<BigInt>  irohPublishFileStartAsync({required String publisherId , required String path , required bool looping }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishFileStartAsync(publisherId: publisherId, path: path, looping: looping);
+
+/// Seek a file publisher; playback resumes at the keyframe before `position_ms`
+Future<void>  irohPublishFileSeek({required String publisherId , required BigInt positionMs }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishFileSeek(publisherId: publisherId, positionMs: positionMs);
+
+/// Current position of a file publisher in milliseconds
+Future<BigInt?>  irohPublishFilePosition({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishFilePosition(publisherId: publisherId);
+
+/// Record an active publisher's broadcast to a fragmented MP4 file
+Future<void>  irohPublishRecordStart({required String publisherId , required String path }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishRecordStart(publisherId: publisherId, path: path);
+
+/// Stop recording a publisher's broadcast
+Future<FlutterRecordingStats>  irohPublishRecordStop({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishRecordStop(publisherId: publisherId);
 
 /// Remove a publisher
-bool irohPublishRemove({required String publisherId}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohPublishRemove(publisherId: publisherId);
+bool  irohPublishRemove({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishRemove(publisherId: publisherId);
 
 /// Push a video frame to publisher
-bool irohPublishPushVideo({
-  required String publisherId,
-  required FlutterVideoFrame frame,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushVideo(
-  publisherId: publisherId,
-  frame: frame,
-);
-
-/// Push audio samples to publisher
-bool irohPublishPushAudio({
-  required String publisherId,
-  required FlutterAudioSamples samples,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushAudio(
-  publisherId: publisherId,
-  samples: samples,
-);
+bool  irohPublishPushVideo({required String publisherId , required FlutterVideoFrame frame }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushVideo(publisherId: publisherId, frame: frame);
 
-/// Push an already-encoded video packet to publisher
+/// Push raw audio samples to publisher
 ///
+/// The samples are encoded to Opus on the Rust side (requires the ffmpeg
+/// feature) and must be 48 kHz stereo, "pcm_s16le" or "pcm_f32le".
+bool  irohPublishPushAudio({required String publisherId , required FlutterAudioSamples samples }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushAudio(publisherId: publisherId, samples: samples);
+
+/// Push an already-encoded video packet to publisher
+/// 
 /// Use this when encoding is done on the Flutter side (e.g., using FFmpegKit).
 /// This is the preferred method for mobile platforms where Rust FFmpeg
 /// cross-compilation is difficult.
-bool irohPublishPushEncodedVideo({
-  required String publisherId,
-  required FlutterEncodedVideoPacket packet,
-}) =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushEncodedVideo(
-      publisherId: publisherId,
-      packet: packet,
-    );
+bool  irohPublishPushEncodedVideo({required String publisherId , required FlutterEncodedVideoPacket packet }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushEncodedVideo(publisherId: publisherId, packet: packet);
 
 /// Push an already-encoded audio packet to publisher
-bool irohPublishPushEncodedAudio({
-  required String publisherId,
-  required FlutterEncodedAudioPacket packet,
-}) =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushEncodedAudio(
-      publisherId: publisherId,
-      packet: packet,
-    );
+bool  irohPublishPushEncodedAudio({required String publisherId , required FlutterEncodedAudioPacket packet }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishPushEncodedAudio(publisherId: publisherId, packet: packet);
 
 /// Get publisher status
-FlutterPublisherStatus? irohPublishGetStatus({required String publisherId}) =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishGetStatus(
-      publisherId: publisherId,
-    );
+FlutterPublisherStatus?  irohPublishGetStatus({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishGetStatus(publisherId: publisherId);
+
+/// Get per-rendition video counters, including frames dropped under congestion
+List<FlutterRenditionStats>  irohPublishGetVideoStats({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishGetVideoStats(publisherId: publisherId);
+
+/// Check whether the publisher wants a keyframe
+///
+/// Returns true once per request. Poll this from the encoder loop and
+/// force an IDR frame (e.g. MediaCodec `PARAMETER_KEY_REQUEST_SYNC_FRAME`)
+/// when it fires.
+bool  irohPublishTakeKeyframeRequest({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishTakeKeyframeRequest(publisherId: publisherId);
 
 /// Set video renditions for publisher
-bool irohPublishSetVideoRenditions({
-  required String publisherId,
-  required List<String> renditions,
-}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohPublishSetVideoRenditions(
-      publisherId: publisherId,
-      renditions: renditions,
-    );
+bool  irohPublishSetVideoRenditions({required String publisherId , required List<String> renditions }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishSetVideoRenditions(publisherId: publisherId, renditions: renditions);
 
 /// Get available video presets
-List<FlutterVideoRendition> irohGetVideoPresets() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetVideoPresets();
+List<FlutterVideoRendition>  irohGetVideoPresets() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetVideoPresets();
 
 /// Get available audio presets
-List<FlutterAudioRendition> irohGetAudioPresets() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetAudioPresets();
+List<FlutterAudioRendition>  irohGetAudioPresets() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetAudioPresets();
 
 /// Create a new subscriber (async version with real backend)
-Future<void> irohSubscribeCreateAsync({
-  required String subscriberId,
-  required String broadcastId,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeCreateAsync(
-  subscriberId: subscriberId,
-  broadcastId: broadcastId,
-);
+Future<void>  irohSubscribeCreateAsync({required String subscriberId , required String broadcastId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeCreateAsync(subscriberId: subscriberId, broadcastId: broadcastId);
 
 /// Create a new subscriber (sync version for compatibility)
-bool irohSubscribeCreate({
-  required String subscriberId,
-  required String broadcastId,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeCreate(
-  subscriberId: subscriberId,
-  broadcastId: broadcastId,
-);
+bool  irohSubscribeCreate({required String subscriberId , required String broadcastId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeCreate(subscriberId: subscriberId, broadcastId: broadcastId);
 
 /// Connect subscriber to broadcast using ticket string (async with real backend)
-Future<void> irohSubscribeConnectAsync({
-  required String subscriberId,
-  required String ticketString,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeConnectAsync(
-  subscriberId: subscriberId,
-  ticketString: ticketString,
-);
+Future<void>  irohSubscribeConnectAsync({required String subscriberId , required String ticketString }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeConnectAsync(subscriberId: subscriberId, ticketString: ticketString);
+
+/// Issue a ticket with a signed, expiring token for a publisher's broadcast
+///
+/// With `subject_endpoint_id` the ticket only works for that viewer.
+Future<FlutterIssuedTicket>  irohPublishIssueTicket({required String publisherId , required BigInt ttlSecs , String? subjectEndpointId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishIssueTicket(publisherId: publisherId, ttlSecs: ttlSecs, subjectEndpointId: subjectEndpointId);
+
+/// Restrict who may watch a publisher's broadcast
+///
+/// Connected viewers that no longer qualify stop receiving it; their
+/// subscriptions end with an access denied error.
+Future<void>  irohPublishSetAccess({required String publisherId , required FlutterAccessPolicy policy }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishSetAccess(publisherId: publisherId, policy: policy);
+
+/// Revoke an issued ticket, ending the subscriptions that rely on it
+Future<void>  irohPublishRevokeToken({required BigInt tokenId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishRevokeToken(tokenId: tokenId);
+
+/// Encrypt a publisher's broadcast end to end, before it starts
+///
+/// Returns a ticket carrying the media key; share it only with viewers.
+/// Relays forward the broadcast without being able to decode it.
+Future<String>  irohPublishEnableEncryption({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishEnableEncryption(publisherId: publisherId);
+
+/// Strip the media key from a ticket, e.g. before handing it to a relay
+String  irohTicketWithoutMediaKey({required String ticket }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohTicketWithoutMediaKey(ticket: ticket);
+
+/// Add the media key from `key_ticket` to a relay's `ticket`
+String  irohTicketWithMediaKey({required String ticket , required String keyTicket }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohTicketWithMediaKey(ticket: ticket, keyTicket: keyTicket);
+
+/// Set the name publishers see for this node in their viewer rosters
+///
+/// Applies to connections opened after the call.
+Future<void>  irohNodeSetDisplayName({String? name }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeSetDisplayName(name: name);
+
+/// List the viewers connected to this node
+Future<List<FlutterViewer>>  irohRosterList() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRosterList();
+
+/// Collect the viewer joins and leaves since the last call
+Future<List<FlutterRosterEvent>>  irohRosterEvents() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRosterEvents();
+
+/// Disconnect a viewer; returns false if it wasn't connected
+///
+/// The viewer's subscription ends instead of reconnecting. To keep the
+/// peer out for good, also deny-list it with `iroh_publish_set_access`.
+Future<bool>  irohRosterKick({required String endpointId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRosterKick(endpointId: endpointId);
+
+/// Post to a publisher's chat, or react to message `reaction_to` with `text`
+Future<void>  irohPublishSendChat({required String publisherId , required String text , BigInt? reactionTo }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishSendChat(publisherId: publisherId, text: text, reactionTo: reactionTo);
+
+/// Collect the messages on a publisher's chat since the last call
+///
+/// Call periodically; together the results form the chat stream,
+/// including the publisher's own messages.
+Future<List<FlutterChatMessage>>  irohPublishChatMessages({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishChatMessages(publisherId: publisherId);
+
+/// Post to the chat of the broadcast a subscriber watches, or react to
+/// message `reaction_to` with `text`
+Future<void>  irohSubscribeSendChat({required String subscriberId , required String text , BigInt? reactionTo }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeSendChat(subscriberId: subscriberId, text: text, reactionTo: reactionTo);
+
+/// Collect the messages on a subscriber's chat since the last call
+Future<List<FlutterChatMessage>>  irohSubscribeChatMessages({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeChatMessages(subscriberId: subscriberId);
+
+/// Host a call room on this node; returns the ticket members join with
+///
+/// Only holders of the ticket may join, for `ttl_secs`.
+Future<String>  irohRoomHost({required String room , required BigInt ttlSecs }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomHost(room: room, ttlSecs: ttlSecs);
+
+/// Join a room from its ticket; returns the room name
+Future<String>  irohRoomJoin({required String ticketString }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomJoin(ticketString: ticketString);
+
+/// Send an active publisher's broadcast to a room
+Future<void>  irohRoomPublish({required String room , required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomPublish(room: room, publisherId: publisherId);
+
+/// Decode the video of members joining a room from now on
+///
+/// Takes the same pixel formats as `iroh_subscribe_set_decode`.
+Future<void>  irohRoomSetDecode({required String room , required String pixelFormat }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomSetDecode(room: room, pixelFormat: pixelFormat);
+
+/// Endpoint IDs of the members of a room this node receives
+Future<List<String>>  irohRoomMembers({required String room }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomMembers(room: room);
+
+/// Collect a room's member joins and leaves since the last call
+///
+/// Call periodically: members are only subscribed as their joins are
+/// collected. Each joined member's video is read through the event's
+/// `subscriber_id` with the `iroh_subscribe_receive_*` functions.
+Future<List<FlutterRoomEvent>>  irohRoomEvents({required String room }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomEvents(room: room);
+
+/// Leave a room; leaving a room this node hosts ends it
+Future<void>  irohRoomLeave({required String room }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRoomLeave(room: room);
+
+/// Ticket for this node rather than one broadcast, for others to discover
+/// what it streams
+Future<String>  irohNodeGetTicket() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohNodeGetTicket();
+
+/// Start following the broadcasts of the node in `ticket_string`
+///
+/// Any ticket for the node works; its broadcast name is ignored. An empty
+/// `prefix` follows every broadcast the node offers.
+Future<void>  irohDiscoveryStart({required String discoveryId , required String ticketString , required String prefix }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohDiscoveryStart(discoveryId: discoveryId, ticketString: ticketString, prefix: prefix);
+
+/// Broadcasts the node currently offers, sorted by name
+Future<List<String>>  irohDiscoveryList({required String discoveryId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohDiscoveryList(discoveryId: discoveryId);
+
+/// Collect the broadcasts added and removed since the last call
+Future<List<FlutterDiscoveryEvent>>  irohDiscoveryEvents({required String discoveryId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohDiscoveryEvents(discoveryId: discoveryId);
+
+/// Stop following a node's broadcasts
+Future<void>  irohDiscoveryStop({required String discoveryId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohDiscoveryStop(discoveryId: discoveryId);
+
+/// Ticket for a discovered broadcast, from the ticket used to discover it
+///
+/// Keeps the node address and token; connect a subscriber with the result.
+String  irohTicketForBroadcast({required String ticket , required String broadcastName }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohTicketForBroadcast(ticket: ticket, broadcastName: broadcastName);
+
+/// Relay a broadcast from its publisher (or another relay) to viewers of this node
+///
+/// Returns a ticket viewers can use to watch through this node. Fails if
+/// relaying would create a loop between relays.
+Future<String>  irohRelayStart({required String ticketString }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRelayStart(ticketString: ticketString);
+
+/// Stop relaying a broadcast
+Future<void>  irohRelayStop({required String broadcastName }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRelayStop(broadcastName: broadcastName);
+
+/// List the broadcasts relayed through this node
+Future<List<FlutterRelayInfo>>  irohRelayList() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohRelayList();
 
 /// Connect subscriber to broadcast (sync version)
-bool irohSubscribeConnect({required String subscriberId}) => RustLib
-    .instance
-    .api
-    .crateApiIrohLiveFlutterApiIrohSubscribeConnect(subscriberId: subscriberId);
+bool  irohSubscribeConnect({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeConnect(subscriberId: subscriberId);
 
 /// Disconnect subscriber (async with real backend)
-Future<void> irohSubscribeDisconnectAsync({required String subscriberId}) =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsync(
-      subscriberId: subscriberId,
-    );
+Future<void>  irohSubscribeDisconnectAsync({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsync(subscriberId: subscriberId);
 
 /// Disconnect subscriber (sync version)
-bool irohSubscribeDisconnect({required String subscriberId}) =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeDisconnect(
-      subscriberId: subscriberId,
-    );
+bool  irohSubscribeDisconnect({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeDisconnect(subscriberId: subscriberId);
 
 /// Remove a subscriber
-bool irohSubscribeRemove({required String subscriberId}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohSubscribeRemove(subscriberId: subscriberId);
+bool  irohSubscribeRemove({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeRemove(subscriberId: subscriberId);
 
 /// Set quality preference for subscriber
-bool irohSubscribeSetQuality({
-  required String subscriberId,
-  required String quality,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeSetQuality(
-  subscriberId: subscriberId,
-  quality: quality,
-);
+///
+/// `quality` is a track suffix ("low", "med", "high"), a preset name
+/// ("P720") or "auto". A connected subscriber switches rendition at the next
+/// keyframe; returns false if the broadcast has no such rendition. "auto"
+/// lets the ABR controller pick; anything else pauses it.
+bool  irohSubscribeSetQuality({required String subscriberId , required String quality }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeSetQuality(subscriberId: subscriberId, quality: quality);
+
+/// Record a connected subscriber's broadcast to a fragmented MP4 file
+///
+/// The recording follows quality switches; each switch continues in a new
+/// file next to `path` (`rec.mp4`, `rec.1.mp4`, ...).
+Future<void>  irohSubscribeRecordStart({required String subscriberId , required String path }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeRecordStart(subscriberId: subscriberId, path: path);
+
+/// Stop recording a subscriber's broadcast
+Future<FlutterRecordingStats>  irohSubscribeRecordStop({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeRecordStop(subscriberId: subscriberId);
+
+/// Collect the adaptive bitrate decisions made since the last call
+Future<List<FlutterAbrEvent>>  irohSubscribeAbrEvents({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeAbrEvents(subscriberId: subscriberId);
+
+/// Where a subscriber's connection to its publisher stands
+///
+/// None for subscribers not connected over MoQ.
+Future<FlutterConnectionState?>  irohSubscribeConnectionState({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeConnectionState(subscriberId: subscriberId);
+
+/// Collect the connection state changes since the last call
+///
+/// Subscribers reconnect by themselves when the connection drops; "ended"
+/// is final, with `error` unset when the publisher ended the broadcast.
+Future<List<FlutterConnectionState>>  irohSubscribeConnectionEvents({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeConnectionEvents(subscriberId: subscriberId);
 
 /// Get subscriber status
-FlutterSubscriberStatus? irohSubscribeGetStatus({
-  required String subscriberId,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeGetStatus(
-  subscriberId: subscriberId,
-);
+FlutterSubscriberStatus?  irohSubscribeGetStatus({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeGetStatus(subscriberId: subscriberId);
 
 /// Simulate receiving a video frame (for testing)
-bool irohSubscribeSimulateVideoReceive({
-  required String subscriberId,
-  required BigInt frameSize,
-}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceive(
-      subscriberId: subscriberId,
-      frameSize: frameSize,
-    );
+bool  irohSubscribeSimulateVideoReceive({required String subscriberId , required BigInt frameSize }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceive(subscriberId: subscriberId, frameSize: frameSize);
 
 /// Receive a video frame from a subscriber (non-blocking)
 /// Returns None if no frame is available
-Future<FlutterReceivedVideoFrame?> irohSubscribeReceiveFrame({
-  required String subscriberId,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeReceiveFrame(
-  subscriberId: subscriberId,
-);
+Future<FlutterReceivedVideoFrame?>  irohSubscribeReceiveFrame({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeReceiveFrame(subscriberId: subscriberId);
+
+/// Decode a subscriber's video into raw frames (call before connecting)
+///
+/// `pixel_format` is "rgba", "bgra", "i420" or "nv12"; an empty string turns
+/// decoding off. Decoded frames are read with
+/// `iroh_subscribe_receive_decoded_frame`. Requires the ffmpeg feature.
+Future<void>  irohSubscribeSetDecode({required String subscriberId , required String pixelFormat }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeSetDecode(subscriberId: subscriberId, pixelFormat: pixelFormat);
+
+/// Receive a decoded video frame from a subscriber (non-blocking)
+/// Returns None if no frame is available
+Future<FlutterVideoFrame?>  irohSubscribeReceiveDecodedFrame({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeReceiveDecodedFrame(subscriberId: subscriberId);
 
 /// Create a broadcast catalog
-FlutterBroadcastCatalog irohCatalogCreate({
-  required String broadcastId,
-  required List<String> videoRenditions,
-  required List<String> audioRenditions,
-}) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCatalogCreate(
-  broadcastId: broadcastId,
-  videoRenditions: videoRenditions,
-  audioRenditions: audioRenditions,
-);
+///
+/// Builds the same hang catalog a publisher announces for these renditions.
+/// Presets that share a MoQ track (e.g. P180 and P360) collapse into one entry.
+FlutterBroadcastCatalog  irohCatalogCreate({required String broadcastId , required List<String> videoRenditions , required List<String> audioRenditions }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCatalogCreate(broadcastId: broadcastId, videoRenditions: videoRenditions, audioRenditions: audioRenditions);
 
 /// Serialize catalog to JSON
-String irohCatalogToJson({required FlutterBroadcastCatalog catalog}) => RustLib
-    .instance
-    .api
-    .crateApiIrohLiveFlutterApiIrohCatalogToJson(catalog: catalog);
+///
+/// Produces the hang `catalog.json` format published on the catalog track.
+String  irohCatalogToJson({required FlutterBroadcastCatalog catalog }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohCatalogToJson(catalog: catalog);
+
+/// Get the catalog an active publisher is announcing, as JSON
+Future<String?>  irohPublishGetCatalogJson({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishGetCatalogJson(publisherId: publisherId);
+
+/// Get the catalog received by a connected subscriber, as JSON
+Future<String?>  irohSubscribeGetCatalogJson({required String subscriberId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohSubscribeGetCatalogJson(subscriberId: subscriberId);
 
 /// Parse a ticket string and return its components
-FlutterTicketInfo? irohTicketParse({required String ticketString}) => RustLib
-    .instance
-    .api
-    .crateApiIrohLiveFlutterApiIrohTicketParse(ticketString: ticketString);
+FlutterTicketInfo?  irohTicketParse({required String ticketString }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohTicketParse(ticketString: ticketString);
 
 /// Get the ticket for a publisher
-String? irohPublishGetTicket({required String publisherId}) => RustLib
-    .instance
-    .api
-    .crateApiIrohLiveFlutterApiIrohPublishGetTicket(publisherId: publisherId);
+String?  irohPublishGetTicket({required String publisherId }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohPublishGetTicket(publisherId: publisherId);
 
 /// Get supported video codecs
-List<String> irohGetSupportedVideoCodecs() => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecs();
+List<String>  irohGetSupportedVideoCodecs() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecs();
 
 /// Get supported audio codecs
-List<String> irohGetSupportedAudioCodecs() => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecs();
+List<String>  irohGetSupportedAudioCodecs() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecs();
 
 /// Check if a codec is hardware accelerated
-bool irohIsCodecHwAccelerated({required String codec}) => RustLib.instance.api
-    .crateApiIrohLiveFlutterApiIrohIsCodecHwAccelerated(codec: codec);
+bool  irohIsCodecHwAccelerated({required String codec }) => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohIsCodecHwAccelerated(codec: codec);
 
 /// Get library version
-String irohGetVersion() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetVersion();
+String  irohGetVersion() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetVersion();
 
 /// Get feature flags
-Map<String, bool> irohGetFeatures() =>
-    RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetFeatures();
+Map<String, bool>  irohGetFeatures() => RustLib.instance.api.crateApiIrohLiveFlutterApiIrohGetFeatures();
+
+            /// Adaptive bitrate decision for Flutter
+class FlutterAbrEvent  {
+                final String fromTrack;
+final String toTrack;
+final String reason;
+final BigInt throughputBps;
+final int rttMs;
+final double lossPercent;
+final BigInt lateFrames;
+
+                const FlutterAbrEvent({required this.fromTrack ,required this.toTrack ,required this.reason ,required this.throughputBps ,required this.rttMs ,required this.lossPercent ,required this.lateFrames ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => fromTrack.hashCode^toTrack.hashCode^reason.hashCode^throughputBps.hashCode^rttMs.hashCode^lossPercent.hashCode^lateFrames.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterAbrEvent &&
+                runtimeType == other.runtimeType
+                && fromTrack == other.fromTrack&& toTrack == other.toTrack&& reason == other.reason&& throughputBps == other.throughputBps&& rttMs == other.rttMs&& lossPercent == other.lossPercent&& lateFrames == other.lateFrames;
+        
+            }
+
+/// Who may watch a broadcast
+class FlutterAccessPolicy  {
+                /// Viewers need a ticket from `iroh_publish_issue_ticket`, unless allow-listed
+final bool requireToken;
+/// Endpoint IDs always admitted
+final List<String> allow;
+/// Endpoint IDs always refused
+final List<String> deny;
+
+                const FlutterAccessPolicy({required this.requireToken ,required this.allow ,required this.deny ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => requireToken.hashCode^allow.hashCode^deny.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterAccessPolicy &&
+                runtimeType == other.runtimeType
+                && requireToken == other.requireToken&& allow == other.allow&& deny == other.deny;
+        
+            }
 
 /// Audio rendition quality info
-class FlutterAudioRendition {
-  final String name;
-  final int sampleRate;
-  final int channels;
-  final int bitrate;
-  final String codec;
-
-  const FlutterAudioRendition({
-    required this.name,
-    required this.sampleRate,
-    required this.channels,
-    required this.bitrate,
-    required this.codec,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      sampleRate.hashCode ^
-      channels.hashCode ^
-      bitrate.hashCode ^
-      codec.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterAudioRendition &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          sampleRate == other.sampleRate &&
-          channels == other.channels &&
-          bitrate == other.bitrate &&
-          codec == other.codec;
-}
+class FlutterAudioRendition  {
+                final String name;
+final int sampleRate;
+final int channels;
+final int bitrate;
+final String codec;
+
+                const FlutterAudioRendition({required this.name ,required this.sampleRate ,required this.channels ,required this.bitrate ,required this.codec ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^sampleRate.hashCode^channels.hashCode^bitrate.hashCode^codec.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterAudioRendition &&
+                runtimeType == other.runtimeType
+                && name == other.name&& sampleRate == other.sampleRate&& channels == other.channels&& bitrate == other.bitrate&& codec == other.codec;
+        
+            }
 
 /// Audio samples for Flutter (raw PCM)
-class FlutterAudioSamples {
-  final Uint8List data;
-  final int sampleRate;
-  final int channels;
-  final BigInt timestampMs;
-  final String format;
-
-  const FlutterAudioSamples({
-    required this.data,
-    required this.sampleRate,
-    required this.channels,
-    required this.timestampMs,
-    required this.format,
-  });
-
-  @override
-  int get hashCode =>
-      data.hashCode ^
-      sampleRate.hashCode ^
-      channels.hashCode ^
-      timestampMs.hashCode ^
-      format.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterAudioSamples &&
-          runtimeType == other.runtimeType &&
-          data == other.data &&
-          sampleRate == other.sampleRate &&
-          channels == other.channels &&
-          timestampMs == other.timestampMs &&
-          format == other.format;
-}
+class FlutterAudioSamples  {
+                final Uint8List data;
+final int sampleRate;
+final int channels;
+final BigInt timestampMs;
+final String format;
+
+                const FlutterAudioSamples({required this.data ,required this.sampleRate ,required this.channels ,required this.timestampMs ,required this.format ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^sampleRate.hashCode^channels.hashCode^timestampMs.hashCode^format.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterAudioSamples &&
+                runtimeType == other.runtimeType
+                && data == other.data&& sampleRate == other.sampleRate&& channels == other.channels&& timestampMs == other.timestampMs&& format == other.format;
+        
+            }
 
 /// Broadcast catalog info for Flutter
-class FlutterBroadcastCatalog {
-  final String broadcastId;
-  final List<FlutterTrackInfo> videoTracks;
-  final List<FlutterTrackInfo> audioTracks;
-  final BigInt createdAtMs;
-
-  const FlutterBroadcastCatalog({
-    required this.broadcastId,
-    required this.videoTracks,
-    required this.audioTracks,
-    required this.createdAtMs,
-  });
-
-  @override
-  int get hashCode =>
-      broadcastId.hashCode ^
-      videoTracks.hashCode ^
-      audioTracks.hashCode ^
-      createdAtMs.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterBroadcastCatalog &&
-          runtimeType == other.runtimeType &&
-          broadcastId == other.broadcastId &&
-          videoTracks == other.videoTracks &&
-          audioTracks == other.audioTracks &&
-          createdAtMs == other.createdAtMs;
-}
+class FlutterBroadcastCatalog  {
+                final String broadcastId;
+final List<FlutterTrackInfo> videoTracks;
+final List<FlutterTrackInfo> audioTracks;
+final BigInt createdAtMs;
+
+                const FlutterBroadcastCatalog({required this.broadcastId ,required this.videoTracks ,required this.audioTracks ,required this.createdAtMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => broadcastId.hashCode^videoTracks.hashCode^audioTracks.hashCode^createdAtMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterBroadcastCatalog &&
+                runtimeType == other.runtimeType
+                && broadcastId == other.broadcastId&& videoTracks == other.videoTracks&& audioTracks == other.audioTracks&& createdAtMs == other.createdAtMs;
+        
+            }
 
 /// Capture device info for Flutter
-class FlutterCaptureDevice {
-  final String id;
-  final String name;
-  final String deviceType;
-  final bool isDefault;
-
-  const FlutterCaptureDevice({
-    required this.id,
-    required this.name,
-    required this.deviceType,
-    required this.isDefault,
-  });
-
-  @override
-  int get hashCode =>
-      id.hashCode ^ name.hashCode ^ deviceType.hashCode ^ isDefault.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterCaptureDevice &&
-          runtimeType == other.runtimeType &&
-          id == other.id &&
-          name == other.name &&
-          deviceType == other.deviceType &&
-          isDefault == other.isDefault;
-}
+class FlutterCaptureDevice  {
+                final String id;
+final String name;
+final String deviceType;
+final bool isDefault;
+
+                const FlutterCaptureDevice({required this.id ,required this.name ,required this.deviceType ,required this.isDefault ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => id.hashCode^name.hashCode^deviceType.hashCode^isDefault.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterCaptureDevice &&
+                runtimeType == other.runtimeType
+                && id == other.id&& name == other.name&& deviceType == other.deviceType&& isDefault == other.isDefault;
+        
+            }
+
+/// A message on a broadcast's chat
+class FlutterChatMessage  {
+                final BigInt seq;
+final String sender;
+final String? displayName;
+/// Unix time (milliseconds) the publisher accepted the message
+final BigInt timestampMs;
+final String kind;
+/// Message text, or the reaction
+final String text;
+/// For reactions, the `seq` of the message reacted to
+final BigInt? reactionTo;
+
+                const FlutterChatMessage({required this.seq ,required this.sender ,this.displayName ,required this.timestampMs ,required this.kind ,required this.text ,this.reactionTo ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => seq.hashCode^sender.hashCode^displayName.hashCode^timestampMs.hashCode^kind.hashCode^text.hashCode^reactionTo.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterChatMessage &&
+                runtimeType == other.runtimeType
+                && seq == other.seq&& sender == other.sender&& displayName == other.displayName&& timestampMs == other.timestampMs&& kind == other.kind&& text == other.text&& reactionTo == other.reactionTo;
+        
+            }
+
+/// Where a subscriber's connection to its publisher stands
+class FlutterConnectionState  {
+                final String state;
+/// Reconnect attempt about to be made, while reconnecting
+final int attempt;
+/// Wait before that attempt, while reconnecting
+final BigInt retryInMs;
+/// Why the subscription ended, if not because the broadcast did
+final String? error;
+
+                const FlutterConnectionState({required this.state ,required this.attempt ,required this.retryInMs ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => state.hashCode^attempt.hashCode^retryInMs.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterConnectionState &&
+                runtimeType == other.runtimeType
+                && state == other.state&& attempt == other.attempt&& retryInMs == other.retryInMs&& error == other.error;
+        
+            }
+
+/// A broadcast appearing on or leaving a discovered node
+class FlutterDiscoveryEvent  {
+                final String kind;
+final String broadcastName;
+
+                const FlutterDiscoveryEvent({required this.kind ,required this.broadcastName ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^broadcastName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterDiscoveryEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& broadcastName == other.broadcastName;
+        
+            }
 
 /// Encoded audio packet for Flutter (Opus/AAC)
-class FlutterEncodedAudioPacket {
-  final Uint8List data;
-  final BigInt timestampMs;
-  final String codec;
-  final int sampleRate;
-  final int channels;
-
-  const FlutterEncodedAudioPacket({
-    required this.data,
-    required this.timestampMs,
-    required this.codec,
-    required this.sampleRate,
-    required this.channels,
-  });
-
-  @override
-  int get hashCode =>
-      data.hashCode ^
-      timestampMs.hashCode ^
-      codec.hashCode ^
-      sampleRate.hashCode ^
-      channels.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterEncodedAudioPacket &&
-          runtimeType == other.runtimeType &&
-          data == other.data &&
-          timestampMs == other.timestampMs &&
-          codec == other.codec &&
-          sampleRate == other.sampleRate &&
-          channels == other.channels;
-}
+class FlutterEncodedAudioPacket  {
+                final Uint8List data;
+final BigInt timestampMs;
+final String codec;
+final int sampleRate;
+final int channels;
+
+                const FlutterEncodedAudioPacket({required this.data ,required this.timestampMs ,required this.codec ,required this.sampleRate ,required this.channels ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^timestampMs.hashCode^codec.hashCode^sampleRate.hashCode^channels.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterEncodedAudioPacket &&
+                runtimeType == other.runtimeType
+                && data == other.data&& timestampMs == other.timestampMs&& codec == other.codec&& sampleRate == other.sampleRate&& channels == other.channels;
+        
+            }
 
 /// Encoded video packet for Flutter (H264/H265)
 /// Use this when encoding is done on the Flutter side (e.g., FFmpegKit)
-class FlutterEncodedVideoPacket {
-  final Uint8List data;
-  final BigInt timestampMs;
-  final bool isKeyframe;
-  final String codec;
-  final int width;
-  final int height;
-
-  const FlutterEncodedVideoPacket({
-    required this.data,
-    required this.timestampMs,
-    required this.isKeyframe,
-    required this.codec,
-    required this.width,
-    required this.height,
-  });
-
-  @override
-  int get hashCode =>
-      data.hashCode ^
-      timestampMs.hashCode ^
-      isKeyframe.hashCode ^
-      codec.hashCode ^
-      width.hashCode ^
-      height.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterEncodedVideoPacket &&
-          runtimeType == other.runtimeType &&
-          data == other.data &&
-          timestampMs == other.timestampMs &&
-          isKeyframe == other.isKeyframe &&
-          codec == other.codec &&
-          width == other.width &&
-          height == other.height;
-}
+class FlutterEncodedVideoPacket  {
+                final Uint8List data;
+final BigInt timestampMs;
+final bool isKeyframe;
+final String codec;
+final int width;
+final int height;
+
+                const FlutterEncodedVideoPacket({required this.data ,required this.timestampMs ,required this.isKeyframe ,required this.codec ,required this.width ,required this.height ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => data.hashCode^timestampMs.hashCode^isKeyframe.hashCode^codec.hashCode^width.hashCode^height.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterEncodedVideoPacket &&
+                runtimeType == other.runtimeType
+                && data == other.data&& timestampMs == other.timestampMs&& isKeyframe == other.isKeyframe&& codec == other.codec&& width == other.width&& height == other.height;
+        
+            }
+
+/// Endpoint settings for `iroh_node_init_with_config`
+class FlutterEndpointConfig  {
+                final FlutterRelayMode relayMode;
+/// UDP port to bind; random when None
+final int? bindPort;
+/// Find peers on the local network with mDNS
+final bool localDiscovery;
+
+                const FlutterEndpointConfig({required this.relayMode ,this.bindPort ,required this.localDiscovery ,});
+
+                static Future<FlutterEndpointConfig>  default_()=>RustLib.instance.api.crateApiIrohLiveFlutterApiFlutterEndpointConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => relayMode.hashCode^bindPort.hashCode^localDiscovery.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterEndpointConfig &&
+                runtimeType == other.runtimeType
+                && relayMode == other.relayMode&& bindPort == other.bindPort&& localDiscovery == other.localDiscovery;
+        
+            }
+
+/// A ticket carrying a capability token
+class FlutterIssuedTicket  {
+                final String ticket;
+/// Pass to `iroh_publish_revoke_token` to revoke the ticket
+final BigInt tokenId;
+/// Unix time (seconds) the ticket stops working
+final BigInt expiresAt;
+
+                const FlutterIssuedTicket({required this.ticket ,required this.tokenId ,required this.expiresAt ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ticket.hashCode^tokenId.hashCode^expiresAt.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterIssuedTicket &&
+                runtimeType == other.runtimeType
+                && ticket == other.ticket&& tokenId == other.tokenId&& expiresAt == other.expiresAt;
+        
+            }
 
 /// Publisher status
-class FlutterPublisherStatus {
-  final String publisherId;
-  final bool isActive;
-  final BigInt framesPublished;
-  final BigInt bytesSent;
-  final int currentBitrate;
-  final List<String> videoRenditions;
-  final List<String> audioRenditions;
-
-  const FlutterPublisherStatus({
-    required this.publisherId,
-    required this.isActive,
-    required this.framesPublished,
-    required this.bytesSent,
-    required this.currentBitrate,
-    required this.videoRenditions,
-    required this.audioRenditions,
-  });
-
-  @override
-  int get hashCode =>
-      publisherId.hashCode ^
-      isActive.hashCode ^
-      framesPublished.hashCode ^
-      bytesSent.hashCode ^
-      currentBitrate.hashCode ^
-      videoRenditions.hashCode ^
-      audioRenditions.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterPublisherStatus &&
-          runtimeType == other.runtimeType &&
-          publisherId == other.publisherId &&
-          isActive == other.isActive &&
-          framesPublished == other.framesPublished &&
-          bytesSent == other.bytesSent &&
-          currentBitrate == other.currentBitrate &&
-          videoRenditions == other.videoRenditions &&
-          audioRenditions == other.audioRenditions;
-}
+class FlutterPublisherStatus  {
+                final String publisherId;
+final bool isActive;
+final BigInt framesPublished;
+final BigInt bytesSent;
+final int currentBitrate;
+final List<String> videoRenditions;
+final List<String> audioRenditions;
+
+                const FlutterPublisherStatus({required this.publisherId ,required this.isActive ,required this.framesPublished ,required this.bytesSent ,required this.currentBitrate ,required this.videoRenditions ,required this.audioRenditions ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => publisherId.hashCode^isActive.hashCode^framesPublished.hashCode^bytesSent.hashCode^currentBitrate.hashCode^videoRenditions.hashCode^audioRenditions.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterPublisherStatus &&
+                runtimeType == other.runtimeType
+                && publisherId == other.publisherId&& isActive == other.isActive&& framesPublished == other.framesPublished&& bytesSent == other.bytesSent&& currentBitrate == other.currentBitrate&& videoRenditions == other.videoRenditions&& audioRenditions == other.audioRenditions;
+        
+            }
 
 /// Received video frame from network
-class FlutterReceivedVideoFrame {
-  final BigInt timestampMs;
-  final int width;
-  final int height;
-  final bool isKeyframe;
-  final Uint8List data;
-
-  const FlutterReceivedVideoFrame({
-    required this.timestampMs,
-    required this.width,
-    required this.height,
-    required this.isKeyframe,
-    required this.data,
-  });
-
-  @override
-  int get hashCode =>
-      timestampMs.hashCode ^
-      width.hashCode ^
-      height.hashCode ^
-      isKeyframe.hashCode ^
-      data.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterReceivedVideoFrame &&
-          runtimeType == other.runtimeType &&
-          timestampMs == other.timestampMs &&
-          width == other.width &&
-          height == other.height &&
-          isKeyframe == other.isKeyframe &&
-          data == other.data;
-}
+class FlutterReceivedVideoFrame  {
+                final BigInt timestampMs;
+final int width;
+final int height;
+final bool isKeyframe;
+final Uint8List data;
+
+                const FlutterReceivedVideoFrame({required this.timestampMs ,required this.width ,required this.height ,required this.isKeyframe ,required this.data ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^width.hashCode^height.hashCode^isKeyframe.hashCode^data.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterReceivedVideoFrame &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& width == other.width&& height == other.height&& isKeyframe == other.isKeyframe&& data == other.data;
+        
+            }
+
+/// Result of a finished recording
+class FlutterRecordingStats  {
+                /// Files written, one per rendition switch
+final List<String> files;
+final BigInt fragments;
+final BigInt videoFrames;
+final BigInt audioFrames;
+final BigInt bytes;
+
+                const FlutterRecordingStats({required this.files ,required this.fragments ,required this.videoFrames ,required this.audioFrames ,required this.bytes ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => files.hashCode^fragments.hashCode^videoFrames.hashCode^audioFrames.hashCode^bytes.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterRecordingStats &&
+                runtimeType == other.runtimeType
+                && files == other.files&& fragments == other.fragments&& videoFrames == other.videoFrames&& audioFrames == other.audioFrames&& bytes == other.bytes;
+        
+            }
+
+/// A broadcast relayed through this node
+class FlutterRelayInfo  {
+                final String broadcastName;
+/// Endpoint IDs the broadcast passes through to get here, origin first
+final List<String> route;
+
+                const FlutterRelayInfo({required this.broadcastName ,required this.route ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => broadcastName.hashCode^route.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterRelayInfo &&
+                runtimeType == other.runtimeType
+                && broadcastName == other.broadcastName&& route == other.route;
+        
+            }
+
+@freezed
+                sealed class FlutterRelayMode with _$FlutterRelayMode  {
+                    const FlutterRelayMode._();
+
+                     /// The public n0 relays
+const factory FlutterRelayMode.default_() = FlutterRelayMode_Default;
+ /// A self-hosted relay
+const factory FlutterRelayMode.custom({   required String url , }) = FlutterRelayMode_Custom;
+ /// LAN only: direct connections, tickets carry only direct addresses
+const factory FlutterRelayMode.disabled() = FlutterRelayMode_Disabled;
+
+                    
+
+                    
+                }
+
+/// Per-rendition publishing counters for Flutter
+class FlutterRenditionStats  {
+                final String trackName;
+final BigInt framesWritten;
+final BigInt bytesWritten;
+final BigInt framesDropped;
+final BigInt groupsCut;
+
+                const FlutterRenditionStats({required this.trackName ,required this.framesWritten ,required this.bytesWritten ,required this.framesDropped ,required this.groupsCut ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => trackName.hashCode^framesWritten.hashCode^bytesWritten.hashCode^framesDropped.hashCode^groupsCut.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterRenditionStats &&
+                runtimeType == other.runtimeType
+                && trackName == other.trackName&& framesWritten == other.framesWritten&& bytesWritten == other.bytesWritten&& framesDropped == other.framesDropped&& groupsCut == other.groupsCut;
+        
+            }
+
+/// A room member joining or leaving
+class FlutterRoomEvent  {
+                final String kind;
+final String member;
+/// Subscriber playing the member's broadcast, while it is in the room
+final String subscriberId;
+
+                const FlutterRoomEvent({required this.kind ,required this.member ,required this.subscriberId ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^member.hashCode^subscriberId.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterRoomEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& member == other.member&& subscriberId == other.subscriberId;
+        
+            }
+
+/// A viewer joining or leaving
+class FlutterRosterEvent  {
+                final String kind;
+final String endpointId;
+/// The viewer that joined
+final FlutterViewer? viewer;
+/// Whether a viewer that left was kicked
+final bool kicked;
+
+                const FlutterRosterEvent({required this.kind ,required this.endpointId ,this.viewer ,required this.kicked ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => kind.hashCode^endpointId.hashCode^viewer.hashCode^kicked.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterRosterEvent &&
+                runtimeType == other.runtimeType
+                && kind == other.kind&& endpointId == other.endpointId&& viewer == other.viewer&& kicked == other.kicked;
+        
+            }
 
 /// Subscriber status
-class FlutterSubscriberStatus {
-  final String subscriberId;
-  final String broadcastId;
-  final bool isConnected;
-  final BigInt framesReceived;
-  final BigInt bytesReceived;
-  final String currentQuality;
-  final double bufferHealth;
-
-  const FlutterSubscriberStatus({
-    required this.subscriberId,
-    required this.broadcastId,
-    required this.isConnected,
-    required this.framesReceived,
-    required this.bytesReceived,
-    required this.currentQuality,
-    required this.bufferHealth,
-  });
-
-  @override
-  int get hashCode =>
-      subscriberId.hashCode ^
-      broadcastId.hashCode ^
-      isConnected.hashCode ^
-      framesReceived.hashCode ^
-      bytesReceived.hashCode ^
-      currentQuality.hashCode ^
-      bufferHealth.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterSubscriberStatus &&
-          runtimeType == other.runtimeType &&
-          subscriberId == other.subscriberId &&
-          broadcastId == other.broadcastId &&
-          isConnected == other.isConnected &&
-          framesReceived == other.framesReceived &&
-          bytesReceived == other.bytesReceived &&
-          currentQuality == other.currentQuality &&
-          bufferHealth == other.bufferHealth;
-}
+class FlutterSubscriberStatus  {
+                final String subscriberId;
+final String broadcastId;
+final bool isConnected;
+final BigInt framesReceived;
+final BigInt bytesReceived;
+final String currentQuality;
+final double bufferHealth;
+
+                const FlutterSubscriberStatus({required this.subscriberId ,required this.broadcastId ,required this.isConnected ,required this.framesReceived ,required this.bytesReceived ,required this.currentQuality ,required this.bufferHealth ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => subscriberId.hashCode^broadcastId.hashCode^isConnected.hashCode^framesReceived.hashCode^bytesReceived.hashCode^currentQuality.hashCode^bufferHealth.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterSubscriberStatus &&
+                runtimeType == other.runtimeType
+                && subscriberId == other.subscriberId&& broadcastId == other.broadcastId&& isConnected == other.isConnected&& framesReceived == other.framesReceived&& bytesReceived == other.bytesReceived&& currentQuality == other.currentQuality&& bufferHealth == other.bufferHealth;
+        
+            }
 
 /// Ticket information for Flutter
-class FlutterTicketInfo {
-  final String broadcastName;
-  final String endpointId;
-  final String ticketString;
-
-  const FlutterTicketInfo({
-    required this.broadcastName,
-    required this.endpointId,
-    required this.ticketString,
-  });
-
-  @override
-  int get hashCode =>
-      broadcastName.hashCode ^ endpointId.hashCode ^ ticketString.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterTicketInfo &&
-          runtimeType == other.runtimeType &&
-          broadcastName == other.broadcastName &&
-          endpointId == other.endpointId &&
-          ticketString == other.ticketString;
-}
+class FlutterTicketInfo  {
+                final String broadcastName;
+final String endpointId;
+final String ticketString;
+
+                const FlutterTicketInfo({required this.broadcastName ,required this.endpointId ,required this.ticketString ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => broadcastName.hashCode^endpointId.hashCode^ticketString.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterTicketInfo &&
+                runtimeType == other.runtimeType
+                && broadcastName == other.broadcastName&& endpointId == other.endpointId&& ticketString == other.ticketString;
+        
+            }
 
 /// Track info for catalog
-class FlutterTrackInfo {
-  final String trackId;
-  final String name;
-  final String codec;
-  final int bitrate;
-  final Map<String, String> extra;
-
-  const FlutterTrackInfo({
-    required this.trackId,
-    required this.name,
-    required this.codec,
-    required this.bitrate,
-    required this.extra,
-  });
-
-  @override
-  int get hashCode =>
-      trackId.hashCode ^
-      name.hashCode ^
-      codec.hashCode ^
-      bitrate.hashCode ^
-      extra.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterTrackInfo &&
-          runtimeType == other.runtimeType &&
-          trackId == other.trackId &&
-          name == other.name &&
-          codec == other.codec &&
-          bitrate == other.bitrate &&
-          extra == other.extra;
-}
+class FlutterTrackInfo  {
+                final String trackId;
+final String name;
+final String codec;
+final int bitrate;
+final Map<String, String> extra;
+
+                const FlutterTrackInfo({required this.trackId ,required this.name ,required this.codec ,required this.bitrate ,required this.extra ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => trackId.hashCode^name.hashCode^codec.hashCode^bitrate.hashCode^extra.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterTrackInfo &&
+                runtimeType == other.runtimeType
+                && trackId == other.trackId&& name == other.name&& codec == other.codec&& bitrate == other.bitrate&& extra == other.extra;
+        
+            }
 
 /// Video frame data for Flutter (raw, unencoded)
-class FlutterVideoFrame {
-  final int width;
-  final int height;
-  final Uint8List data;
-  final BigInt timestampMs;
-  final String format;
-
-  const FlutterVideoFrame({
-    required this.width,
-    required this.height,
-    required this.data,
-    required this.timestampMs,
-    required this.format,
-  });
-
-  @override
-  int get hashCode =>
-      width.hashCode ^
-      height.hashCode ^
-      data.hashCode ^
-      timestampMs.hashCode ^
-      format.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterVideoFrame &&
-          runtimeType == other.runtimeType &&
-          width == other.width &&
-          height == other.height &&
-          data == other.data &&
-          timestampMs == other.timestampMs &&
-          format == other.format;
-}
+class FlutterVideoFrame  {
+                final int width;
+final int height;
+final Uint8List data;
+final BigInt timestampMs;
+final String format;
+
+                const FlutterVideoFrame({required this.width ,required this.height ,required this.data ,required this.timestampMs ,required this.format ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => width.hashCode^height.hashCode^data.hashCode^timestampMs.hashCode^format.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterVideoFrame &&
+                runtimeType == other.runtimeType
+                && width == other.width&& height == other.height&& data == other.data&& timestampMs == other.timestampMs&& format == other.format;
+        
+            }
 
 /// Video rendition quality info
-class FlutterVideoRendition {
-  final String name;
-  final int width;
-  final int height;
-  final int fps;
-  final int bitrate;
-  final String codec;
-
-  const FlutterVideoRendition({
-    required this.name,
-    required this.width,
-    required this.height,
-    required this.fps,
-    required this.bitrate,
-    required this.codec,
-  });
-
-  @override
-  int get hashCode =>
-      name.hashCode ^
-      width.hashCode ^
-      height.hashCode ^
-      fps.hashCode ^
-      bitrate.hashCode ^
-      codec.hashCode;
-
-  @override
-  bool operator ==(Object other) =>
-      identical(this, other) ||
-      other is FlutterVideoRendition &&
-          runtimeType == other.runtimeType &&
-          name == other.name &&
-          width == other.width &&
-          height == other.height &&
-          fps == other.fps &&
-          bitrate == other.bitrate &&
-          codec == other.codec;
-}
+class FlutterVideoRendition  {
+                final String name;
+final int width;
+final int height;
+final int fps;
+final int bitrate;
+final String codec;
+
+                const FlutterVideoRendition({required this.name ,required this.width ,required this.height ,required this.fps ,required this.bitrate ,required this.codec ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => name.hashCode^width.hashCode^height.hashCode^fps.hashCode^bitrate.hashCode^codec.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterVideoRendition &&
+                runtimeType == other.runtimeType
+                && name == other.name&& width == other.width&& height == other.height&& fps == other.fps&& bitrate == other.bitrate&& codec == other.codec;
+        
+            }
+
+/// A viewer connected to this node
+class FlutterViewer  {
+                final String endpointId;
+final String? displayName;
+/// Unix time (milliseconds) the viewer connected
+final BigInt connectedAtMs;
+/// Tracks being received, as "broadcast/track"
+final List<String> tracks;
+final BigInt bytesSent;
+final BigInt bytesReceived;
+final int rttMs;
+
+                const FlutterViewer({required this.endpointId ,this.displayName ,required this.connectedAtMs ,required this.tracks ,required this.bytesSent ,required this.bytesReceived ,required this.rttMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => endpointId.hashCode^displayName.hashCode^connectedAtMs.hashCode^tracks.hashCode^bytesSent.hashCode^bytesReceived.hashCode^rttMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FlutterViewer &&
+                runtimeType == other.runtimeType
+                && endpointId == other.endpointId&& displayName == other.displayName&& connectedAtMs == other.connectedAtMs&& tracks == other.tracks&& bytesSent == other.bytesSent&& bytesReceived == other.bytesReceived&& rttMs == other.rttMs;
+        
+            }
+            
\ No newline at end of file
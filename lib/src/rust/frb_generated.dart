@@ -13,9335 +13,8238 @@ import 'api/simple.dart';
 import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  @internal
-  static final instance = RustLib._();
-
-  RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    RustLibApi? api,
-    BaseHandler? handler,
-    ExternalLibrary? externalLibrary,
-    bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({required RustLibApi api}) {
-    instance.initMockImpl(api: api);
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {
-    await api.crateApiSimpleInitApp();
-  }
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.11.1';
-
-  @override
-  int get rustContentHash => 1470217968;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-        stem: 'rust_lib_cyberfly_streaming',
-        ioDirectory: 'rust/target/release/',
-        webPrefix: 'pkg/',
-      );
-}
-
-abstract class RustLibApi extends BaseApi {
-  Future<void> crateApiLiveFlutterApiAddVideoTrack({
-    required FlutterVideoQuality quality,
-    required String codec,
-  });
-
-  Future<void> crateApiLiveFlutterApiBroadcastCatalog();
-
-  Future<void> crateApiFlutterApiBroadcastChunk({
-    required List<int> data,
-    required BigInt sequence,
-  });
-
-  Future<String> crateApiDirectFlutterApiCreateDirectStream({
-    required String name,
-  });
-
-  Future<String> crateApiLiveFlutterApiCreateLiveBroadcast({
-    required String name,
-  });
-
-  Future<String> crateApiLiveFlutterApiCreateLiveTicket({
-    required String broadcastName,
-    required String endpointIdHex,
-  });
-
-  Future<String> crateApiFlutterApiCreateStream({required String name});
-
-  Future<void> crateApiDirectFlutterApiDirectRequestChunk({required int index});
-
-  Future<void> crateApiDirectFlutterApiDirectRequestMetadata();
-
-  Future<void> crateApiDirectFlutterApiDirectSendChunk({
-    required int index,
-    required List<int> data,
-  });
-
-  Future<void> crateApiDirectFlutterApiDirectSendMetadata({
-    required String fileName,
-    required BigInt fileSize,
-    required String mimeType,
-    required int totalChunks,
-    double? duration,
-  });
-
-  Future<void> crateApiDirectFlutterApiDirectSendPresence({
-    required String name,
-  });
-
-  Future<void> crateApiDirectFlutterApiDirectSendSignal({
-    required List<int> data,
-  });
-
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioDecoder({
-    required FlutterAudioCodec codec,
-  });
-
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioEncoder({
-    required FlutterAudioCodec codec,
-    required int sampleRate,
-    required int channels,
-    required int bitrateKbps,
-  });
-
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateMusicEncoder();
 
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegCreateQualityLadder({
-    required FlutterVideoQualityFfmpeg sourceQuality,
-    required FlutterVideoCodec codec,
-  });
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoDecoder({
-    required FlutterVideoCodec codec,
-    required FlutterHardwareAccel hardware,
-  });
+                  RustLib._();
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoEncoder({
-    required FlutterVideoQualityFfmpeg quality,
-    required FlutterVideoCodec codec,
-    required FlutterHardwareAccel hardware,
-    required FlutterEncoderPreset preset,
-    int? bitrateKbps,
-    required bool lowLatency,
-  });
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateVoiceEncoder();
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
 
-  Future<FlutterDecodedAudioFrame>
-  crateApiFfmpegFlutterApiFfmpegDecodeAudioFrame({
-    required List<int> data,
-    required PlatformInt64 ptsUs,
-    required FlutterAudioCodec codec,
-  });
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
 
-  Future<FlutterDecodedVideoFrame>
-  crateApiFfmpegFlutterApiFfmpegDecodeVideoFrame({
-    required List<int> data,
-    required PlatformInt64 ptsUs,
-    required bool isKeyframe,
-    required FlutterVideoQualityFfmpeg quality,
-  });
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioDecoder();
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioEncoder();
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    await api.crateApiSimpleInitApp();
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyQualityLadder();
+                  }
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoDecoder();
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoEncoder();
+                  @override
+                  String get codegenVersion => '2.11.1';
 
-  Future<FlutterHardwareAccel> crateApiFfmpegFlutterApiFfmpegDetectHardware();
+                  @override
+                  int get rustContentHash => 887595007;
 
-  Future<List<FlutterEncodedVideoFrame>>
-  crateApiFfmpegFlutterApiFfmpegEncodeAllQualities({
-    required List<int> rawFrame,
-    required PlatformInt64 ptsUs,
-  });
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'rust_lib_cyberfly_streaming',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                  );
+                }
+                
 
-  Future<FlutterEncodedAudioFrame>
-  crateApiFfmpegFlutterApiFfmpegEncodeAudioFrame({
-    required List<int> pcmSamples,
-    required PlatformInt64 ptsUs,
-  });
+                abstract class RustLibApi extends BaseApi {
+                  Future<void> crateApiLiveFlutterApiAddVideoTrack({required FlutterVideoQuality quality , required String codec });
 
-  Future<FlutterEncodedVideoFrame>
-  crateApiFfmpegFlutterApiFfmpegEncodeVideoFrame({
-    required List<int> rawFrame,
-    required PlatformInt64 ptsUs,
-  });
+Future<void> crateApiLiveFlutterApiBroadcastCatalog();
 
-  Future<List<FlutterEncodedVideoFrame>>
-  crateApiFfmpegFlutterApiFfmpegFlushVideoEncoder();
+Future<void> crateApiFlutterApiBroadcastChunk({required List<int> data , required BigInt sequence });
 
-  Future<int> crateApiFfmpegFlutterApiFfmpegGetAudioBitrate({
-    required FlutterAudioCodec codec,
-    required bool highQuality,
-  });
+Future<String> crateApiDirectFlutterApiCreateDirectStream({required String name });
 
-  Future<String> crateApiFfmpegFlutterApiFfmpegGetAudioMimeType({
-    required FlutterAudioCodec codec,
-  });
+Future<String> crateApiLiveFlutterApiCreateLiveBroadcast({required String name });
 
-  Future<(int, int)> crateApiFfmpegFlutterApiFfmpegGetQualityDimensions({
-    required FlutterVideoQualityFfmpeg quality,
-  });
+Future<String> crateApiLiveFlutterApiCreateLiveTicket({required String broadcastName , required String endpointIdHex });
 
-  Future<int> crateApiFfmpegFlutterApiFfmpegGetRecommendedBitrate({
-    required FlutterVideoQualityFfmpeg quality,
-  });
+Future<String> crateApiFlutterApiCreateStream({required String name });
 
-  Future<String?> crateApiFfmpegFlutterApiFfmpegGetVersion();
+Future<void> crateApiDirectFlutterApiDirectRequestChunk({required int index });
 
-  Future<String> crateApiFfmpegFlutterApiFfmpegGetVideoMimeType({
-    required FlutterVideoCodec codec,
-  });
+Future<void> crateApiDirectFlutterApiDirectRequestMetadata();
 
-  Future<bool> crateApiFfmpegFlutterApiFfmpegIsAvailable();
+Future<void> crateApiDirectFlutterApiDirectSendChunk({required int index , required List<int> data });
 
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegListAudioCodecs();
+Future<void> crateApiDirectFlutterApiDirectSendMetadata({required String fileName , required BigInt fileSize , required String mimeType , required int totalChunks , double? duration });
 
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegListHardwareAccels();
+Future<void> crateApiDirectFlutterApiDirectSendPresence({required String name });
 
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegListVideoCodecs();
+Future<void> crateApiDirectFlutterApiDirectSendSignal({required List<int> data });
 
-  Future<void> crateApiFfmpegFlutterApiFfmpegResetVideoEncoder();
+Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioDecoder({required FlutterAudioCodec codec });
 
-  Future<List<FlutterVideoQuality>>
-  crateApiLiveFlutterApiGetAllVideoQualities();
+Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioEncoder({required FlutterAudioCodec codec , required int sampleRate , required int channels , required int bitrateKbps });
 
-  Future<String> crateApiDirectFlutterApiGetDirectEndpointId();
+Future<void> crateApiFfmpegFlutterApiFfmpegCreateMusicEncoder();
 
-  Future<int> crateApiDirectFlutterApiGetDirectPeerCount();
+Future<List<String>> crateApiFfmpegFlutterApiFfmpegCreateQualityLadder({required FlutterVideoQualityFfmpeg sourceQuality , required FlutterVideoCodec codec });
 
-  Future<String> crateApiLiveFlutterApiGetDirectTicket();
+Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoDecoder({required FlutterVideoCodec codec , required FlutterHardwareAccel hardware });
 
-  Future<String> crateApiFlutterApiGetEndpointId();
+Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoEncoder({required FlutterVideoQualityFfmpeg quality , required FlutterVideoCodec codec , required FlutterHardwareAccel hardware , required FlutterEncoderPreset preset , int? bitrateKbps , required bool lowLatency });
 
-  Future<FlutterConnectionStats> crateApiLiveFlutterApiGetLiveConnectionStats();
+Future<void> crateApiFfmpegFlutterApiFfmpegCreateVoiceEncoder();
 
-  Future<String> crateApiLiveFlutterApiGetLiveTicket();
+Future<FlutterDecodedAudioFrame> crateApiFfmpegFlutterApiFfmpegDecodeAudioFrame({required List<int> data , required PlatformInt64 ptsUs , required FlutterAudioCodec codec });
 
-  Future<int> crateApiLiveFlutterApiGetQualityBitrate({
-    required FlutterVideoQuality quality,
-  });
+Future<FlutterDecodedVideoFrame> crateApiFfmpegFlutterApiFfmpegDecodeVideoFrame({required List<int> data , required PlatformInt64 ptsUs , required bool isKeyframe , required FlutterVideoQualityFfmpeg quality });
 
-  QualityConstraints crateApiFlutterApiGetQualityConstraints({
-    required Quality quality,
-  });
+Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioDecoder();
 
-  Future<(int, int)> crateApiLiveFlutterApiGetQualityDimensions({
-    required FlutterVideoQuality quality,
-  });
+Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioEncoder();
 
-  Future<FlutterVideoQuality> crateApiLiveFlutterApiGetRecommendedQuality();
+Future<void> crateApiFfmpegFlutterApiFfmpegDestroyQualityLadder();
 
-  String crateApiSimpleGreet({required String name});
+Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoDecoder();
 
-  Future<bool> crateApiLiveFlutterApiHasActiveBroadcast();
+Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoEncoder();
 
-  Future<bool> crateApiLiveFlutterApiHasActiveSubscription();
+Future<FlutterHardwareAccel> crateApiFfmpegFlutterApiFfmpegDetectHardware();
 
-  Future<void> crateApiSimpleInitApp();
+Future<List<FlutterEncodedVideoFrame>> crateApiFfmpegFlutterApiFfmpegEncodeAllQualities({required List<int> rawFrame , required PlatformInt64 ptsUs });
 
-  Future<String> crateApiDirectFlutterApiInitDirectStreaming();
+Future<FlutterEncodedAudioFrame> crateApiFfmpegFlutterApiFfmpegEncodeAudioFrame({required List<int> pcmSamples , required PlatformInt64 ptsUs });
 
-  Future<String> crateApiFlutterApiInitStreamingNode();
+Future<FlutterEncodedVideoFrame> crateApiFfmpegFlutterApiFfmpegEncodeVideoFrame({required List<int> rawFrame , required PlatformInt64 ptsUs });
 
-  String? crateApiIrohLiveFlutterApiIrohCaptureCurrentDevice();
+Future<List<FlutterEncodedVideoFrame>> crateApiFfmpegFlutterApiFfmpegFlushVideoEncoder();
 
-  FlutterVideoFrame crateApiIrohLiveFlutterApiIrohCaptureGetTestFrame({
-    required int width,
-    required int height,
-    required String pattern,
-  });
+Future<int> crateApiFfmpegFlutterApiFfmpegGetAudioBitrate({required FlutterAudioCodec codec , required bool highQuality });
 
-  bool crateApiIrohLiveFlutterApiIrohCaptureInit();
+Future<String> crateApiFfmpegFlutterApiFfmpegGetAudioMimeType({required FlutterAudioCodec codec });
 
-  List<FlutterCaptureDevice> crateApiIrohLiveFlutterApiIrohCaptureListDevices();
+Future<(int,int)> crateApiFfmpegFlutterApiFfmpegGetQualityDimensions({required FlutterVideoQualityFfmpeg quality });
 
-  bool crateApiIrohLiveFlutterApiIrohCaptureStart({required String deviceId});
+Future<int> crateApiFfmpegFlutterApiFfmpegGetRecommendedBitrate({required FlutterVideoQualityFfmpeg quality });
 
-  bool crateApiIrohLiveFlutterApiIrohCaptureStop();
+Future<String?> crateApiFfmpegFlutterApiFfmpegGetVersion();
 
-  FlutterBroadcastCatalog crateApiIrohLiveFlutterApiIrohCatalogCreate({
-    required String broadcastId,
-    required List<String> videoRenditions,
-    required List<String> audioRenditions,
-  });
+Future<String> crateApiFfmpegFlutterApiFfmpegGetVideoMimeType({required FlutterVideoCodec codec });
 
-  String crateApiIrohLiveFlutterApiIrohCatalogToJson({
-    required FlutterBroadcastCatalog catalog,
-  });
+Future<bool> crateApiFfmpegFlutterApiFfmpegIsAvailable();
 
-  List<FlutterAudioRendition> crateApiIrohLiveFlutterApiIrohGetAudioPresets();
+Future<List<String>> crateApiFfmpegFlutterApiFfmpegListAudioCodecs();
 
-  Map<String, bool> crateApiIrohLiveFlutterApiIrohGetFeatures();
+Future<List<String>> crateApiFfmpegFlutterApiFfmpegListHardwareAccels();
 
-  List<String> crateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecs();
+Future<List<String>> crateApiFfmpegFlutterApiFfmpegListVideoCodecs();
 
-  List<String> crateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecs();
+Future<void> crateApiFfmpegFlutterApiFfmpegResetVideoEncoder();
 
-  String crateApiIrohLiveFlutterApiIrohGetVersion();
+Future<FlutterEndpointConfig> crateApiIrohLiveFlutterApiFlutterEndpointConfigDefault();
 
-  List<FlutterVideoRendition> crateApiIrohLiveFlutterApiIrohGetVideoPresets();
+Future<List<FlutterVideoQuality>> crateApiLiveFlutterApiGetAllVideoQualities();
 
-  bool crateApiIrohLiveFlutterApiIrohIsCodecHwAccelerated({
-    required String codec,
-  });
+Future<String> crateApiDirectFlutterApiGetDirectEndpointId();
 
-  Future<String> crateApiIrohLiveFlutterApiIrohNodeGetEndpointId();
+Future<int> crateApiDirectFlutterApiGetDirectPeerCount();
 
-  Future<String> crateApiIrohLiveFlutterApiIrohNodeInit();
+Future<String> crateApiLiveFlutterApiGetDirectTicket();
 
-  Future<void> crateApiIrohLiveFlutterApiIrohNodeShutdown();
+Future<String> crateApiFlutterApiGetEndpointId();
 
-  bool crateApiIrohLiveFlutterApiIrohPublishCreate({
-    required String publisherId,
-  });
+Future<FlutterConnectionStats> crateApiLiveFlutterApiGetLiveConnectionStats();
 
-  Future<String> crateApiIrohLiveFlutterApiIrohPublishCreateAsync({
-    required String publisherId,
-    required String broadcastName,
-  });
+Future<String> crateApiLiveFlutterApiGetLiveTicket();
 
-  FlutterPublisherStatus? crateApiIrohLiveFlutterApiIrohPublishGetStatus({
-    required String publisherId,
-  });
+Future<int> crateApiLiveFlutterApiGetQualityBitrate({required FlutterVideoQuality quality });
 
-  String? crateApiIrohLiveFlutterApiIrohPublishGetTicket({
-    required String publisherId,
-  });
+QualityConstraints crateApiFlutterApiGetQualityConstraints({required Quality quality });
 
-  bool crateApiIrohLiveFlutterApiIrohPublishPushAudio({
-    required String publisherId,
-    required FlutterAudioSamples samples,
-  });
+Future<(int,int)> crateApiLiveFlutterApiGetQualityDimensions({required FlutterVideoQuality quality });
 
-  bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedAudio({
-    required String publisherId,
-    required FlutterEncodedAudioPacket packet,
-  });
+Future<FlutterVideoQuality> crateApiLiveFlutterApiGetRecommendedQuality();
 
-  bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedVideo({
-    required String publisherId,
-    required FlutterEncodedVideoPacket packet,
-  });
+String crateApiSimpleGreet({required String name });
 
-  bool crateApiIrohLiveFlutterApiIrohPublishPushVideo({
-    required String publisherId,
-    required FlutterVideoFrame frame,
-  });
+Future<bool> crateApiLiveFlutterApiHasActiveBroadcast();
 
-  bool crateApiIrohLiveFlutterApiIrohPublishRemove({
-    required String publisherId,
-  });
+Future<bool> crateApiLiveFlutterApiHasActiveSubscription();
 
-  bool crateApiIrohLiveFlutterApiIrohPublishSetVideoRenditions({
-    required String publisherId,
-    required List<String> renditions,
-  });
+Future<void> crateApiSimpleInitApp();
 
-  bool crateApiIrohLiveFlutterApiIrohPublishStart({
-    required String publisherId,
-  });
+Future<String> crateApiDirectFlutterApiInitDirectStreaming();
 
-  Future<void> crateApiIrohLiveFlutterApiIrohPublishStartAsync({
-    required String publisherId,
-  });
+Future<String> crateApiDirectFlutterApiInitDirectStreamingWithKey({required List<int> secretKey });
 
-  bool crateApiIrohLiveFlutterApiIrohPublishStop({required String publisherId});
+Future<String> crateApiFlutterApiInitStreamingNode();
 
-  Future<void> crateApiIrohLiveFlutterApiIrohPublishStopAsync({
-    required String publisherId,
-  });
+String? crateApiIrohLiveFlutterApiIrohCaptureCurrentDevice();
 
-  bool crateApiIrohLiveFlutterApiIrohSubscribeConnect({
-    required String subscriberId,
-  });
+FlutterVideoFrame crateApiIrohLiveFlutterApiIrohCaptureGetTestFrame({required int width , required int height , required String pattern });
 
-  Future<void> crateApiIrohLiveFlutterApiIrohSubscribeConnectAsync({
-    required String subscriberId,
-    required String ticketString,
-  });
+bool crateApiIrohLiveFlutterApiIrohCaptureInit();
 
-  bool crateApiIrohLiveFlutterApiIrohSubscribeCreate({
-    required String subscriberId,
-    required String broadcastId,
-  });
+List<FlutterCaptureDevice> crateApiIrohLiveFlutterApiIrohCaptureListDevices();
 
-  Future<void> crateApiIrohLiveFlutterApiIrohSubscribeCreateAsync({
-    required String subscriberId,
-    required String broadcastId,
-  });
+bool crateApiIrohLiveFlutterApiIrohCaptureStart({required String deviceId });
 
-  bool crateApiIrohLiveFlutterApiIrohSubscribeDisconnect({
-    required String subscriberId,
-  });
+bool crateApiIrohLiveFlutterApiIrohCaptureStop();
 
-  Future<void> crateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsync({
-    required String subscriberId,
-  });
+FlutterBroadcastCatalog crateApiIrohLiveFlutterApiIrohCatalogCreate({required String broadcastId , required List<String> videoRenditions , required List<String> audioRenditions });
 
-  FlutterSubscriberStatus? crateApiIrohLiveFlutterApiIrohSubscribeGetStatus({
-    required String subscriberId,
-  });
-
-  Future<FlutterReceivedVideoFrame?>
-  crateApiIrohLiveFlutterApiIrohSubscribeReceiveFrame({
-    required String subscriberId,
-  });
+String crateApiIrohLiveFlutterApiIrohCatalogToJson({required FlutterBroadcastCatalog catalog });
 
-  bool crateApiIrohLiveFlutterApiIrohSubscribeRemove({
-    required String subscriberId,
-  });
-
-  bool crateApiIrohLiveFlutterApiIrohSubscribeSetQuality({
-    required String subscriberId,
-    required String quality,
-  });
-
-  bool crateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceive({
-    required String subscriberId,
-    required BigInt frameSize,
-  });
-
-  FlutterTicketInfo? crateApiIrohLiveFlutterApiIrohTicketParse({
-    required String ticketString,
-  });
-
-  bool crateApiFlutterApiIsConnectedToStream();
-
-  bool crateApiDirectFlutterApiIsDirectStreamingInitialized();
-
-  bool crateApiFlutterApiIsStreamingInitialized();
-
-  Future<String> crateApiDirectFlutterApiJoinDirectStream({
-    required String ticketStr,
-    required String name,
-  });
-
-  Future<String> crateApiLiveFlutterApiJoinLiveBroadcast({
-    required String ticketStr,
-  });
-
-  Future<String> crateApiFlutterApiJoinStream({
-    required String ticketStr,
-    required String name,
-  });
-
-  Future<void> crateApiDirectFlutterApiLeaveDirectStream();
-
-  Future<void> crateApiLiveFlutterApiLeaveLiveBroadcast();
-
-  Future<void> crateApiFlutterApiLeaveStream();
+Future<List<FlutterDiscoveryEvent>> crateApiIrohLiveFlutterApiIrohDiscoveryEvents({required String discoveryId });
 
-  Future<void> crateApiLiveFlutterApiLiveBroadcastChunk({
-    required int index,
-    required List<int> data,
-  });
+Future<List<String>> crateApiIrohLiveFlutterApiIrohDiscoveryList({required String discoveryId });
 
-  Future<void> crateApiLiveFlutterApiLiveBroadcastMetadata({
-    required String fileName,
-    required BigInt fileSize,
-    required String mimeType,
-    required int totalChunks,
-    double? duration,
-  });
+Future<void> crateApiIrohLiveFlutterApiIrohDiscoveryStart({required String discoveryId , required String ticketString , required String prefix });
 
-  Future<int> crateApiLiveFlutterApiLiveBroadcastPeerCount();
-
-  Future<void> crateApiLiveFlutterApiLiveRequestChunk({required int index});
-
-  Future<FlutterMoqObject> crateApiMoqFlutterApiMoqAddObject({
-    required String trackPath,
-    required BigInt groupId,
-    required BigInt subgroupId,
-    required List<int> payload,
-    int? priority,
-    BigInt? ttlMs,
-  });
-
-  Future<void> crateApiMoqFlutterApiMoqAnnounceNamespace({
-    required String namespacePath,
-    required List<String> tracks,
-    required bool acceptsPublishing,
-  });
-
-  Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfGroup({
-    required String trackPath,
-    required BigInt groupId,
-  });
-
-  Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfTrack({
-    required String trackPath,
-    required BigInt groupId,
-  });
-
-  Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqCreateTrack({
-    required String trackPath,
-  });
-
-  Future<String> crateApiMoqFlutterApiMoqCreateTrackPath({
-    required String namespace,
-    required String trackName,
-  });
+Future<void> crateApiIrohLiveFlutterApiIrohDiscoveryStop({required String discoveryId });
 
-  Future<FlutterMoqObject?> crateApiMoqFlutterApiMoqDequeueObject();
+List<FlutterAudioRendition> crateApiIrohLiveFlutterApiIrohGetAudioPresets();
 
-  Future<int> crateApiMoqFlutterApiMoqDropLowPriority({required int threshold});
+Map<String, bool> crateApiIrohLiveFlutterApiIrohGetFeatures();
 
-  Future<void> crateApiMoqFlutterApiMoqEnqueueObject({
-    required BigInt groupId,
-    required BigInt subgroupId,
-    required BigInt objectId,
-    required List<int> payload,
-    required int publisherPriority,
-    required int subscriberPriority,
-    BigInt? ttlMs,
-  });
+List<String> crateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecs();
 
-  Future<int> crateApiMoqFlutterApiMoqEstimateDeliveryTime({
-    required int priority,
-    required int queueLength,
-  });
+List<String> crateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecs();
 
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqFetch({
-    required String trackPath,
-    required BigInt startGroup,
-    required BigInt startObject,
-    required BigInt endGroup,
-    BigInt? endObject,
-    int? priority,
-  });
+String crateApiIrohLiveFlutterApiIrohGetVersion();
 
-  Future<List<String>> crateApiMoqFlutterApiMoqGetFilterTypes();
-
-  Future<List<String>> crateApiMoqFlutterApiMoqGetGroupOrders();
-
-  Future<FlutterNamespaceAnnouncement?> crateApiMoqFlutterApiMoqGetNamespace({
-    required String namespacePath,
-  });
-
-  Future<(int, int, int)> crateApiMoqFlutterApiMoqGetPriorityInfo();
-
-  Future<int> crateApiMoqFlutterApiMoqGetQueueLength();
-
-  Future<FlutterMoqStats> crateApiMoqFlutterApiMoqGetStats();
-
-  Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqGetTrackStatus({
-    required String trackPath,
-  });
-
-  Future<List<FlutterNamespaceAnnouncement>>
-  crateApiMoqFlutterApiMoqListNamespaces();
-
-  Future<bool> crateApiMoqFlutterApiMoqNamespaceIsPrefix({
-    required String prefix,
-    required String fullPath,
-  });
-
-  Future<(String, String)> crateApiMoqFlutterApiMoqParseTrackPath({
-    required String trackPath,
-  });
-
-  Future<BigInt> crateApiMoqFlutterApiMoqStartGroup({
-    required String trackPath,
-  });
-
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribe({
-    required String trackPath,
-    required FlutterFilterType filter,
-    required FlutterGroupOrder groupOrder,
-    required int subscriberPriority,
-  });
-
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLatestGroup({
-    required String trackPath,
-  });
-
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLive({
-    required String trackPath,
-  });
-
-  Future<List<FlutterNamespaceAnnouncement>>
-  crateApiMoqFlutterApiMoqSubscribeNamespace({required String namespacePrefix});
-
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeRange({
-    required String trackPath,
-    required BigInt startGroup,
-    required BigInt startObject,
-    required BigInt endGroup,
-    BigInt? endObject,
-  });
-
-  Future<(String, String)> crateApiLiveFlutterApiParseLiveTicket({
-    required String ticketStr,
-  });
-
-  Future<List<FlutterDirectEvent>> crateApiDirectFlutterApiPollDirectEvents();
-
-  Future<List<FlutterStreamEvent>> crateApiFlutterApiPollEvents();
-
-  Future<List<FlutterLiveEvent>> crateApiLiveFlutterApiPollLiveEvents();
-
-  Future<void> crateApiLiveFlutterApiRequestLiveCatalog();
-
-  Future<void> crateApiFlutterApiSendPresence();
-
-  Future<void> crateApiFlutterApiSendSignal({required List<int> data});
-
-  Future<void> crateApiLiveFlutterApiSetVideoQuality({
-    required FlutterVideoQuality quality,
-  });
-
-  Future<void> crateApiDirectFlutterApiShutdownDirectStreaming();
-
-  Future<void> crateApiFlutterApiShutdownStreaming();
-
-  Future<void> crateApiLiveFlutterApiStopLiveBroadcast();
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  Future<void> crateApiLiveFlutterApiAddVideoTrack({
-    required FlutterVideoQuality quality,
-    required String codec,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality(quality, serializer);
-          sse_encode_String(codec, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 1,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+List<FlutterVideoRendition> crateApiIrohLiveFlutterApiIrohGetVideoPresets();
+
+String crateApiIrohLiveFlutterApiIrohIdentityEndpointId({required List<int> secretKey });
+
+Uint8List crateApiIrohLiveFlutterApiIrohIdentityGenerate();
+
+bool crateApiIrohLiveFlutterApiIrohIsCodecHwAccelerated({required String codec });
+
+Future<Uint8List> crateApiIrohLiveFlutterApiIrohNodeExportKey();
+
+Future<String> crateApiIrohLiveFlutterApiIrohNodeGetEndpointId();
+
+Future<String> crateApiIrohLiveFlutterApiIrohNodeGetTicket();
+
+Future<String> crateApiIrohLiveFlutterApiIrohNodeInit();
+
+Future<String> crateApiIrohLiveFlutterApiIrohNodeInitFromFile({required String path , FlutterEndpointConfig? config });
+
+Future<String> crateApiIrohLiveFlutterApiIrohNodeInitWithConfig({required FlutterEndpointConfig config });
+
+Future<String> crateApiIrohLiveFlutterApiIrohNodeInitWithKey({required List<int> secretKey , FlutterEndpointConfig? config });
+
+Future<void> crateApiIrohLiveFlutterApiIrohNodeSetDisplayName({String? name });
+
+Future<void> crateApiIrohLiveFlutterApiIrohNodeShutdown();
+
+Future<List<FlutterChatMessage>> crateApiIrohLiveFlutterApiIrohPublishChatMessages({required String publisherId });
+
+bool crateApiIrohLiveFlutterApiIrohPublishCreate({required String publisherId });
+
+Future<String> crateApiIrohLiveFlutterApiIrohPublishCreateAsync({required String publisherId , required String broadcastName });
+
+Future<String> crateApiIrohLiveFlutterApiIrohPublishEnableEncryption({required String publisherId });
+
+Future<BigInt?> crateApiIrohLiveFlutterApiIrohPublishFilePosition({required String publisherId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishFileSeek({required String publisherId , required BigInt positionMs });
+
+Future<BigInt> crateApiIrohLiveFlutterApiIrohPublishFileStartAsync({required String publisherId , required String path , required bool looping });
+
+Future<String?> crateApiIrohLiveFlutterApiIrohPublishGetCatalogJson({required String publisherId });
+
+FlutterPublisherStatus? crateApiIrohLiveFlutterApiIrohPublishGetStatus({required String publisherId });
+
+String? crateApiIrohLiveFlutterApiIrohPublishGetTicket({required String publisherId });
+
+List<FlutterRenditionStats> crateApiIrohLiveFlutterApiIrohPublishGetVideoStats({required String publisherId });
+
+Future<FlutterIssuedTicket> crateApiIrohLiveFlutterApiIrohPublishIssueTicket({required String publisherId , required BigInt ttlSecs , String? subjectEndpointId });
+
+bool crateApiIrohLiveFlutterApiIrohPublishPushAudio({required String publisherId , required FlutterAudioSamples samples });
+
+bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedAudio({required String publisherId , required FlutterEncodedAudioPacket packet });
+
+bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedVideo({required String publisherId , required FlutterEncodedVideoPacket packet });
+
+bool crateApiIrohLiveFlutterApiIrohPublishPushVideo({required String publisherId , required FlutterVideoFrame frame });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishRecordStart({required String publisherId , required String path });
+
+Future<FlutterRecordingStats> crateApiIrohLiveFlutterApiIrohPublishRecordStop({required String publisherId });
+
+bool crateApiIrohLiveFlutterApiIrohPublishRemove({required String publisherId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishRevokeToken({required BigInt tokenId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishSendChat({required String publisherId , required String text , BigInt? reactionTo });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishSetAccess({required String publisherId , required FlutterAccessPolicy policy });
+
+bool crateApiIrohLiveFlutterApiIrohPublishSetVideoRenditions({required String publisherId , required List<String> renditions });
+
+bool crateApiIrohLiveFlutterApiIrohPublishStart({required String publisherId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishStartAsync({required String publisherId });
+
+bool crateApiIrohLiveFlutterApiIrohPublishStop({required String publisherId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohPublishStopAsync({required String publisherId });
+
+bool crateApiIrohLiveFlutterApiIrohPublishTakeKeyframeRequest({required String publisherId });
+
+Future<List<FlutterRelayInfo>> crateApiIrohLiveFlutterApiIrohRelayList();
+
+Future<String> crateApiIrohLiveFlutterApiIrohRelayStart({required String ticketString });
+
+Future<void> crateApiIrohLiveFlutterApiIrohRelayStop({required String broadcastName });
+
+Future<List<FlutterRoomEvent>> crateApiIrohLiveFlutterApiIrohRoomEvents({required String room });
+
+Future<String> crateApiIrohLiveFlutterApiIrohRoomHost({required String room , required BigInt ttlSecs });
+
+Future<String> crateApiIrohLiveFlutterApiIrohRoomJoin({required String ticketString });
+
+Future<void> crateApiIrohLiveFlutterApiIrohRoomLeave({required String room });
+
+Future<List<String>> crateApiIrohLiveFlutterApiIrohRoomMembers({required String room });
+
+Future<void> crateApiIrohLiveFlutterApiIrohRoomPublish({required String room , required String publisherId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohRoomSetDecode({required String room , required String pixelFormat });
+
+Future<List<FlutterRosterEvent>> crateApiIrohLiveFlutterApiIrohRosterEvents();
+
+Future<bool> crateApiIrohLiveFlutterApiIrohRosterKick({required String endpointId });
+
+Future<List<FlutterViewer>> crateApiIrohLiveFlutterApiIrohRosterList();
+
+Future<List<FlutterAbrEvent>> crateApiIrohLiveFlutterApiIrohSubscribeAbrEvents({required String subscriberId });
+
+Future<List<FlutterChatMessage>> crateApiIrohLiveFlutterApiIrohSubscribeChatMessages({required String subscriberId });
+
+bool crateApiIrohLiveFlutterApiIrohSubscribeConnect({required String subscriberId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohSubscribeConnectAsync({required String subscriberId , required String ticketString });
+
+Future<List<FlutterConnectionState>> crateApiIrohLiveFlutterApiIrohSubscribeConnectionEvents({required String subscriberId });
+
+Future<FlutterConnectionState?> crateApiIrohLiveFlutterApiIrohSubscribeConnectionState({required String subscriberId });
+
+bool crateApiIrohLiveFlutterApiIrohSubscribeCreate({required String subscriberId , required String broadcastId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohSubscribeCreateAsync({required String subscriberId , required String broadcastId });
+
+bool crateApiIrohLiveFlutterApiIrohSubscribeDisconnect({required String subscriberId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsync({required String subscriberId });
+
+Future<String?> crateApiIrohLiveFlutterApiIrohSubscribeGetCatalogJson({required String subscriberId });
+
+FlutterSubscriberStatus? crateApiIrohLiveFlutterApiIrohSubscribeGetStatus({required String subscriberId });
+
+Future<FlutterVideoFrame?> crateApiIrohLiveFlutterApiIrohSubscribeReceiveDecodedFrame({required String subscriberId });
+
+Future<FlutterReceivedVideoFrame?> crateApiIrohLiveFlutterApiIrohSubscribeReceiveFrame({required String subscriberId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohSubscribeRecordStart({required String subscriberId , required String path });
+
+Future<FlutterRecordingStats> crateApiIrohLiveFlutterApiIrohSubscribeRecordStop({required String subscriberId });
+
+bool crateApiIrohLiveFlutterApiIrohSubscribeRemove({required String subscriberId });
+
+Future<void> crateApiIrohLiveFlutterApiIrohSubscribeSendChat({required String subscriberId , required String text , BigInt? reactionTo });
+
+Future<void> crateApiIrohLiveFlutterApiIrohSubscribeSetDecode({required String subscriberId , required String pixelFormat });
+
+bool crateApiIrohLiveFlutterApiIrohSubscribeSetQuality({required String subscriberId , required String quality });
+
+bool crateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceive({required String subscriberId , required BigInt frameSize });
+
+String crateApiIrohLiveFlutterApiIrohTicketForBroadcast({required String ticket , required String broadcastName });
+
+FlutterTicketInfo? crateApiIrohLiveFlutterApiIrohTicketParse({required String ticketString });
+
+String crateApiIrohLiveFlutterApiIrohTicketWithMediaKey({required String ticket , required String keyTicket });
+
+String crateApiIrohLiveFlutterApiIrohTicketWithoutMediaKey({required String ticket });
+
+bool crateApiFlutterApiIsConnectedToStream();
+
+bool crateApiDirectFlutterApiIsDirectStreamingInitialized();
+
+bool crateApiFlutterApiIsStreamingInitialized();
+
+Future<String> crateApiDirectFlutterApiJoinDirectStream({required String ticketStr , required String name });
+
+Future<String> crateApiLiveFlutterApiJoinLiveBroadcast({required String ticketStr });
+
+Future<String> crateApiFlutterApiJoinStream({required String ticketStr , required String name });
+
+Future<void> crateApiDirectFlutterApiLeaveDirectStream();
+
+Future<void> crateApiLiveFlutterApiLeaveLiveBroadcast();
+
+Future<void> crateApiFlutterApiLeaveStream();
+
+Future<void> crateApiLiveFlutterApiLiveBroadcastChunk({required int index , required List<int> data });
+
+Future<void> crateApiLiveFlutterApiLiveBroadcastMetadata({required String fileName , required BigInt fileSize , required String mimeType , required int totalChunks , double? duration });
+
+Future<int> crateApiLiveFlutterApiLiveBroadcastPeerCount();
+
+Future<void> crateApiLiveFlutterApiLiveRequestChunk({required int index });
+
+Future<FlutterMoqObject> crateApiMoqFlutterApiMoqAddObject({required String trackPath , required BigInt groupId , required BigInt subgroupId , required List<int> payload , int? priority , BigInt? ttlMs });
+
+Future<void> crateApiMoqFlutterApiMoqAnnounceNamespace({required String namespacePath , required List<String> tracks , required bool acceptsPublishing });
+
+Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfGroup({required String trackPath , required BigInt groupId });
+
+Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfTrack({required String trackPath , required BigInt groupId });
+
+Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqCreateTrack({required String trackPath });
+
+Future<String> crateApiMoqFlutterApiMoqCreateTrackPath({required String namespace , required String trackName });
+
+Future<FlutterMoqObject?> crateApiMoqFlutterApiMoqDequeueObject();
+
+Future<int> crateApiMoqFlutterApiMoqDropLowPriority({required int threshold });
+
+Future<void> crateApiMoqFlutterApiMoqEnqueueObject({required BigInt groupId , required BigInt subgroupId , required BigInt objectId , required List<int> payload , required int publisherPriority , required int subscriberPriority , BigInt? ttlMs });
+
+Future<int> crateApiMoqFlutterApiMoqEstimateDeliveryTime({required int priority , required int queueLength });
+
+Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqFetch({required String trackPath , required BigInt startGroup , required BigInt startObject , required BigInt endGroup , BigInt? endObject , int? priority });
+
+Future<List<String>> crateApiMoqFlutterApiMoqGetFilterTypes();
+
+Future<List<String>> crateApiMoqFlutterApiMoqGetGroupOrders();
+
+Future<FlutterNamespaceAnnouncement?> crateApiMoqFlutterApiMoqGetNamespace({required String namespacePath });
+
+Future<(int,int,int)> crateApiMoqFlutterApiMoqGetPriorityInfo();
+
+Future<int> crateApiMoqFlutterApiMoqGetQueueLength();
+
+Future<FlutterMoqStats> crateApiMoqFlutterApiMoqGetStats();
+
+Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqGetTrackStatus({required String trackPath });
+
+Future<List<FlutterNamespaceAnnouncement>> crateApiMoqFlutterApiMoqListNamespaces();
+
+Future<bool> crateApiMoqFlutterApiMoqNamespaceIsPrefix({required String prefix , required String fullPath });
+
+Future<(String,String)> crateApiMoqFlutterApiMoqParseTrackPath({required String trackPath });
+
+Future<BigInt> crateApiMoqFlutterApiMoqStartGroup({required String trackPath });
+
+Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribe({required String trackPath , required FlutterFilterType filter , required FlutterGroupOrder groupOrder , required int subscriberPriority });
+
+Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLatestGroup({required String trackPath });
+
+Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLive({required String trackPath });
+
+Future<List<FlutterNamespaceAnnouncement>> crateApiMoqFlutterApiMoqSubscribeNamespace({required String namespacePrefix });
+
+Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeRange({required String trackPath , required BigInt startGroup , required BigInt startObject , required BigInt endGroup , BigInt? endObject });
+
+Future<(String,String)> crateApiLiveFlutterApiParseLiveTicket({required String ticketStr });
+
+Future<List<FlutterDirectEvent>> crateApiDirectFlutterApiPollDirectEvents();
+
+Future<List<FlutterStreamEvent>> crateApiFlutterApiPollEvents();
+
+Future<List<FlutterLiveEvent>> crateApiLiveFlutterApiPollLiveEvents();
+
+Future<void> crateApiLiveFlutterApiRequestLiveCatalog();
+
+Future<void> crateApiFlutterApiSendPresence();
+
+Future<void> crateApiFlutterApiSendSignal({required List<int> data });
+
+Future<void> crateApiLiveFlutterApiSetVideoQuality({required FlutterVideoQuality quality });
+
+Future<void> crateApiDirectFlutterApiShutdownDirectStreaming();
+
+Future<void> crateApiFlutterApiShutdownStreaming();
+
+Future<void> crateApiLiveFlutterApiStopLiveBroadcast();
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override Future<void> crateApiLiveFlutterApiAddVideoTrack({required FlutterVideoQuality quality , required String codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality(quality, serializer);
+sse_encode_String(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiAddVideoTrackConstMeta,
-        argValues: [quality, codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiAddVideoTrackConstMeta =>
-      const TaskConstMeta(
-        debugName: "add_video_track",
-        argNames: ["quality", "codec"],
-      );
-
-  @override
-  Future<void> crateApiLiveFlutterApiBroadcastCatalog() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 2,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiAddVideoTrackConstMeta,
+            argValues: [quality, codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiAddVideoTrackConstMeta => const TaskConstMeta(
+            debugName: "add_video_track",
+            argNames: ["quality", "codec"],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiBroadcastCatalog()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiBroadcastCatalogConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiBroadcastCatalogConstMeta =>
-      const TaskConstMeta(debugName: "broadcast_catalog", argNames: []);
-
-  @override
-  Future<void> crateApiFlutterApiBroadcastChunk({
-    required List<int> data,
-    required BigInt sequence,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_u_64(sequence, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 3,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiBroadcastCatalogConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiBroadcastCatalogConstMeta => const TaskConstMeta(
+            debugName: "broadcast_catalog",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFlutterApiBroadcastChunk({required List<int> data , required BigInt sequence })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_u_64(sequence, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiBroadcastChunkConstMeta,
-        argValues: [data, sequence],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiBroadcastChunkConstMeta =>
-      const TaskConstMeta(
-        debugName: "broadcast_chunk",
-        argNames: ["data", "sequence"],
-      );
-
-  @override
-  Future<String> crateApiDirectFlutterApiCreateDirectStream({
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiBroadcastChunkConstMeta,
+            argValues: [data, sequence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiBroadcastChunkConstMeta => const TaskConstMeta(
+            debugName: "broadcast_chunk",
+            argNames: ["data", "sequence"],
+        );
+        
+
+@override Future<String> crateApiDirectFlutterApiCreateDirectStream({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiCreateDirectStreamConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiCreateDirectStreamConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_direct_stream",
-        argNames: ["name"],
-      );
-
-  @override
-  Future<String> crateApiLiveFlutterApiCreateLiveBroadcast({
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 5,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiCreateDirectStreamConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiCreateDirectStreamConstMeta => const TaskConstMeta(
+            debugName: "create_direct_stream",
+            argNames: ["name"],
+        );
+        
+
+@override Future<String> crateApiLiveFlutterApiCreateLiveBroadcast({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiCreateLiveBroadcastConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiCreateLiveBroadcastConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_live_broadcast",
-        argNames: ["name"],
-      );
-
-  @override
-  Future<String> crateApiLiveFlutterApiCreateLiveTicket({
-    required String broadcastName,
-    required String endpointIdHex,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(broadcastName, serializer);
-          sse_encode_String(endpointIdHex, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 6,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiCreateLiveBroadcastConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiCreateLiveBroadcastConstMeta => const TaskConstMeta(
+            debugName: "create_live_broadcast",
+            argNames: ["name"],
+        );
+        
+
+@override Future<String> crateApiLiveFlutterApiCreateLiveTicket({required String broadcastName , required String endpointIdHex })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(broadcastName, serializer);
+sse_encode_String(endpointIdHex, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiCreateLiveTicketConstMeta,
-        argValues: [broadcastName, endpointIdHex],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiCreateLiveTicketConstMeta =>
-      const TaskConstMeta(
-        debugName: "create_live_ticket",
-        argNames: ["broadcastName", "endpointIdHex"],
-      );
-
-  @override
-  Future<String> crateApiFlutterApiCreateStream({required String name}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 7,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiCreateLiveTicketConstMeta,
+            argValues: [broadcastName, endpointIdHex],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiCreateLiveTicketConstMeta => const TaskConstMeta(
+            debugName: "create_live_ticket",
+            argNames: ["broadcastName", "endpointIdHex"],
+        );
+        
+
+@override Future<String> crateApiFlutterApiCreateStream({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiCreateStreamConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiCreateStreamConstMeta =>
-      const TaskConstMeta(debugName: "create_stream", argNames: ["name"]);
-
-  @override
-  Future<void> crateApiDirectFlutterApiDirectRequestChunk({
-    required int index,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(index, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 8,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiCreateStreamConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiCreateStreamConstMeta => const TaskConstMeta(
+            debugName: "create_stream",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiDirectRequestChunk({required int index })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(index, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiDirectRequestChunkConstMeta,
-        argValues: [index],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiDirectRequestChunkConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_request_chunk",
-        argNames: ["index"],
-      );
-
-  @override
-  Future<void> crateApiDirectFlutterApiDirectRequestMetadata() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 9,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiDirectRequestChunkConstMeta,
+            argValues: [index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiDirectRequestChunkConstMeta => const TaskConstMeta(
+            debugName: "direct_request_chunk",
+            argNames: ["index"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiDirectRequestMetadata()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiDirectRequestMetadataConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiDirectRequestMetadataConstMeta =>
-      const TaskConstMeta(debugName: "direct_request_metadata", argNames: []);
-
-  @override
-  Future<void> crateApiDirectFlutterApiDirectSendChunk({
-    required int index,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(index, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 10,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiDirectRequestMetadataConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiDirectRequestMetadataConstMeta => const TaskConstMeta(
+            debugName: "direct_request_metadata",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiDirectSendChunk({required int index , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(index, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiDirectSendChunkConstMeta,
-        argValues: [index, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiDirectSendChunkConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_send_chunk",
-        argNames: ["index", "data"],
-      );
-
-  @override
-  Future<void> crateApiDirectFlutterApiDirectSendMetadata({
-    required String fileName,
-    required BigInt fileSize,
-    required String mimeType,
-    required int totalChunks,
-    double? duration,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(fileName, serializer);
-          sse_encode_u_64(fileSize, serializer);
-          sse_encode_String(mimeType, serializer);
-          sse_encode_u_32(totalChunks, serializer);
-          sse_encode_opt_box_autoadd_f_64(duration, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 11,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiDirectSendChunkConstMeta,
+            argValues: [index, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiDirectSendChunkConstMeta => const TaskConstMeta(
+            debugName: "direct_send_chunk",
+            argNames: ["index", "data"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiDirectSendMetadata({required String fileName , required BigInt fileSize , required String mimeType , required int totalChunks , double? duration })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(fileName, serializer);
+sse_encode_u_64(fileSize, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_u_32(totalChunks, serializer);
+sse_encode_opt_box_autoadd_f_64(duration, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiDirectSendMetadataConstMeta,
-        argValues: [fileName, fileSize, mimeType, totalChunks, duration],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiDirectSendMetadataConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_send_metadata",
-        argNames: [
-          "fileName",
-          "fileSize",
-          "mimeType",
-          "totalChunks",
-          "duration",
-        ],
-      );
-
-  @override
-  Future<void> crateApiDirectFlutterApiDirectSendPresence({
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 12,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiDirectSendMetadataConstMeta,
+            argValues: [fileName, fileSize, mimeType, totalChunks, duration],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiDirectSendMetadataConstMeta => const TaskConstMeta(
+            debugName: "direct_send_metadata",
+            argNames: ["fileName", "fileSize", "mimeType", "totalChunks", "duration"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiDirectSendPresence({required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiDirectSendPresenceConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiDirectSendPresenceConstMeta =>
-      const TaskConstMeta(
-        debugName: "direct_send_presence",
-        argNames: ["name"],
-      );
-
-  @override
-  Future<void> crateApiDirectFlutterApiDirectSendSignal({
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 13,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiDirectSendPresenceConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiDirectSendPresenceConstMeta => const TaskConstMeta(
+            debugName: "direct_send_presence",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiDirectSendSignal({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiDirectSendSignalConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiDirectSendSignalConstMeta =>
-      const TaskConstMeta(debugName: "direct_send_signal", argNames: ["data"]);
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioDecoder({
-    required FlutterAudioCodec codec,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_audio_codec(codec, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 14,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiDirectSendSignalConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiDirectSendSignalConstMeta => const TaskConstMeta(
+            debugName: "direct_send_signal",
+            argNames: ["data"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioDecoder({required FlutterAudioCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_audio_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateAudioDecoderConstMeta,
-        argValues: [codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateAudioDecoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_audio_decoder",
-        argNames: ["codec"],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioEncoder({
-    required FlutterAudioCodec codec,
-    required int sampleRate,
-    required int channels,
-    required int bitrateKbps,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_audio_codec(codec, serializer);
-          sse_encode_u_32(sampleRate, serializer);
-          sse_encode_u_32(channels, serializer);
-          sse_encode_u_32(bitrateKbps, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 15,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateAudioDecoderConstMeta,
+            argValues: [codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateAudioDecoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_audio_decoder",
+            argNames: ["codec"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegCreateAudioEncoder({required FlutterAudioCodec codec , required int sampleRate , required int channels , required int bitrateKbps })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_audio_codec(codec, serializer);
+sse_encode_u_32(sampleRate, serializer);
+sse_encode_u_32(channels, serializer);
+sse_encode_u_32(bitrateKbps, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateAudioEncoderConstMeta,
-        argValues: [codec, sampleRate, channels, bitrateKbps],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateAudioEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_audio_encoder",
-        argNames: ["codec", "sampleRate", "channels", "bitrateKbps"],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateMusicEncoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 16,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateAudioEncoderConstMeta,
+            argValues: [codec, sampleRate, channels, bitrateKbps],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateAudioEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_audio_encoder",
+            argNames: ["codec", "sampleRate", "channels", "bitrateKbps"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegCreateMusicEncoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateMusicEncoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateMusicEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_music_encoder",
-        argNames: [],
-      );
-
-  @override
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegCreateQualityLadder({
-    required FlutterVideoQualityFfmpeg sourceQuality,
-    required FlutterVideoCodec codec,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality_ffmpeg(sourceQuality, serializer);
-          sse_encode_flutter_video_codec(codec, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 17,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateMusicEncoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateMusicEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_music_encoder",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiFfmpegFlutterApiFfmpegCreateQualityLadder({required FlutterVideoQualityFfmpeg sourceQuality , required FlutterVideoCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality_ffmpeg(sourceQuality, serializer);
+sse_encode_flutter_video_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateQualityLadderConstMeta,
-        argValues: [sourceQuality, codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateQualityLadderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_quality_ladder",
-        argNames: ["sourceQuality", "codec"],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoDecoder({
-    required FlutterVideoCodec codec,
-    required FlutterHardwareAccel hardware,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_codec(codec, serializer);
-          sse_encode_flutter_hardware_accel(hardware, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 18,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateQualityLadderConstMeta,
+            argValues: [sourceQuality, codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateQualityLadderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_quality_ladder",
+            argNames: ["sourceQuality", "codec"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoDecoder({required FlutterVideoCodec codec , required FlutterHardwareAccel hardware })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_codec(codec, serializer);
+sse_encode_flutter_hardware_accel(hardware, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateVideoDecoderConstMeta,
-        argValues: [codec, hardware],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateVideoDecoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_video_decoder",
-        argNames: ["codec", "hardware"],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoEncoder({
-    required FlutterVideoQualityFfmpeg quality,
-    required FlutterVideoCodec codec,
-    required FlutterHardwareAccel hardware,
-    required FlutterEncoderPreset preset,
-    int? bitrateKbps,
-    required bool lowLatency,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
-          sse_encode_flutter_video_codec(codec, serializer);
-          sse_encode_flutter_hardware_accel(hardware, serializer);
-          sse_encode_flutter_encoder_preset(preset, serializer);
-          sse_encode_opt_box_autoadd_u_32(bitrateKbps, serializer);
-          sse_encode_bool(lowLatency, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateVideoDecoderConstMeta,
+            argValues: [codec, hardware],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateVideoDecoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_video_decoder",
+            argNames: ["codec", "hardware"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegCreateVideoEncoder({required FlutterVideoQualityFfmpeg quality , required FlutterVideoCodec codec , required FlutterHardwareAccel hardware , required FlutterEncoderPreset preset , int? bitrateKbps , required bool lowLatency })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
+sse_encode_flutter_video_codec(codec, serializer);
+sse_encode_flutter_hardware_accel(hardware, serializer);
+sse_encode_flutter_encoder_preset(preset, serializer);
+sse_encode_opt_box_autoadd_u_32(bitrateKbps, serializer);
+sse_encode_bool(lowLatency, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateVideoEncoderConstMeta,
-        argValues: [quality, codec, hardware, preset, bitrateKbps, lowLatency],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateVideoEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_video_encoder",
-        argNames: [
-          "quality",
-          "codec",
-          "hardware",
-          "preset",
-          "bitrateKbps",
-          "lowLatency",
-        ],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegCreateVoiceEncoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 20,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateVideoEncoderConstMeta,
+            argValues: [quality, codec, hardware, preset, bitrateKbps, lowLatency],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateVideoEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_video_encoder",
+            argNames: ["quality", "codec", "hardware", "preset", "bitrateKbps", "lowLatency"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegCreateVoiceEncoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateVoiceEncoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegCreateVoiceEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_create_voice_encoder",
-        argNames: [],
-      );
-
-  @override
-  Future<FlutterDecodedAudioFrame>
-  crateApiFfmpegFlutterApiFfmpegDecodeAudioFrame({
-    required List<int> data,
-    required PlatformInt64 ptsUs,
-    required FlutterAudioCodec codec,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_i_64(ptsUs, serializer);
-          sse_encode_flutter_audio_codec(codec, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 21,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegCreateVoiceEncoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegCreateVoiceEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_create_voice_encoder",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterDecodedAudioFrame> crateApiFfmpegFlutterApiFfmpegDecodeAudioFrame({required List<int> data , required PlatformInt64 ptsUs , required FlutterAudioCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_i_64(ptsUs, serializer);
+sse_encode_flutter_audio_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_decoded_audio_frame,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDecodeAudioFrameConstMeta,
-        argValues: [data, ptsUs, codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDecodeAudioFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_decode_audio_frame",
-        argNames: ["data", "ptsUs", "codec"],
-      );
-
-  @override
-  Future<FlutterDecodedVideoFrame>
-  crateApiFfmpegFlutterApiFfmpegDecodeVideoFrame({
-    required List<int> data,
-    required PlatformInt64 ptsUs,
-    required bool isKeyframe,
-    required FlutterVideoQualityFfmpeg quality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          sse_encode_i_64(ptsUs, serializer);
-          sse_encode_bool(isKeyframe, serializer);
-          sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDecodeAudioFrameConstMeta,
+            argValues: [data, ptsUs, codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDecodeAudioFrameConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_decode_audio_frame",
+            argNames: ["data", "ptsUs", "codec"],
+        );
+        
+
+@override Future<FlutterDecodedVideoFrame> crateApiFfmpegFlutterApiFfmpegDecodeVideoFrame({required List<int> data , required PlatformInt64 ptsUs , required bool isKeyframe , required FlutterVideoQualityFfmpeg quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+sse_encode_i_64(ptsUs, serializer);
+sse_encode_bool(isKeyframe, serializer);
+sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_decoded_video_frame,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDecodeVideoFrameConstMeta,
-        argValues: [data, ptsUs, isKeyframe, quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDecodeVideoFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_decode_video_frame",
-        argNames: ["data", "ptsUs", "isKeyframe", "quality"],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioDecoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDecodeVideoFrameConstMeta,
+            argValues: [data, ptsUs, isKeyframe, quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDecodeVideoFrameConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_decode_video_frame",
+            argNames: ["data", "ptsUs", "isKeyframe", "quality"],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioDecoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyAudioDecoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegDestroyAudioDecoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_destroy_audio_decoder",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioEncoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyAudioDecoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDestroyAudioDecoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_destroy_audio_decoder",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegDestroyAudioEncoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyAudioEncoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegDestroyAudioEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_destroy_audio_encoder",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyQualityLadder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyAudioEncoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDestroyAudioEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_destroy_audio_encoder",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegDestroyQualityLadder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyQualityLadderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegDestroyQualityLadderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_destroy_quality_ladder",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoDecoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyQualityLadderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDestroyQualityLadderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_destroy_quality_ladder",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoDecoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyVideoDecoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegDestroyVideoDecoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_destroy_video_decoder",
-        argNames: [],
-      );
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoEncoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyVideoDecoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDestroyVideoDecoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_destroy_video_decoder",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegDestroyVideoEncoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyVideoEncoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegDestroyVideoEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_destroy_video_encoder",
-        argNames: [],
-      );
-
-  @override
-  Future<FlutterHardwareAccel> crateApiFfmpegFlutterApiFfmpegDetectHardware() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDestroyVideoEncoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDestroyVideoEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_destroy_video_encoder",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterHardwareAccel> crateApiFfmpegFlutterApiFfmpegDetectHardware()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_hardware_accel,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegDetectHardwareConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDetectHardwareConstMeta =>
-      const TaskConstMeta(debugName: "ffmpeg_detect_hardware", argNames: []);
-
-  @override
-  Future<List<FlutterEncodedVideoFrame>>
-  crateApiFfmpegFlutterApiFfmpegEncodeAllQualities({
-    required List<int> rawFrame,
-    required PlatformInt64 ptsUs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(rawFrame, serializer);
-          sse_encode_i_64(ptsUs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegDetectHardwareConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegDetectHardwareConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_detect_hardware",
+            argNames: [],
+        );
+        
+
+@override Future<List<FlutterEncodedVideoFrame>> crateApiFfmpegFlutterApiFfmpegEncodeAllQualities({required List<int> rawFrame , required PlatformInt64 ptsUs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(rawFrame, serializer);
+sse_encode_i_64(ptsUs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_encoded_video_frame,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegEncodeAllQualitiesConstMeta,
-        argValues: [rawFrame, ptsUs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegEncodeAllQualitiesConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_encode_all_qualities",
-        argNames: ["rawFrame", "ptsUs"],
-      );
-
-  @override
-  Future<FlutterEncodedAudioFrame>
-  crateApiFfmpegFlutterApiFfmpegEncodeAudioFrame({
-    required List<int> pcmSamples,
-    required PlatformInt64 ptsUs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_i_16_loose(pcmSamples, serializer);
-          sse_encode_i_64(ptsUs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegEncodeAllQualitiesConstMeta,
+            argValues: [rawFrame, ptsUs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegEncodeAllQualitiesConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_encode_all_qualities",
+            argNames: ["rawFrame", "ptsUs"],
+        );
+        
+
+@override Future<FlutterEncodedAudioFrame> crateApiFfmpegFlutterApiFfmpegEncodeAudioFrame({required List<int> pcmSamples , required PlatformInt64 ptsUs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_i_16_loose(pcmSamples, serializer);
+sse_encode_i_64(ptsUs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_encoded_audio_frame,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegEncodeAudioFrameConstMeta,
-        argValues: [pcmSamples, ptsUs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegEncodeAudioFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_encode_audio_frame",
-        argNames: ["pcmSamples", "ptsUs"],
-      );
-
-  @override
-  Future<FlutterEncodedVideoFrame>
-  crateApiFfmpegFlutterApiFfmpegEncodeVideoFrame({
-    required List<int> rawFrame,
-    required PlatformInt64 ptsUs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(rawFrame, serializer);
-          sse_encode_i_64(ptsUs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegEncodeAudioFrameConstMeta,
+            argValues: [pcmSamples, ptsUs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegEncodeAudioFrameConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_encode_audio_frame",
+            argNames: ["pcmSamples", "ptsUs"],
+        );
+        
+
+@override Future<FlutterEncodedVideoFrame> crateApiFfmpegFlutterApiFfmpegEncodeVideoFrame({required List<int> rawFrame , required PlatformInt64 ptsUs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(rawFrame, serializer);
+sse_encode_i_64(ptsUs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_encoded_video_frame,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegEncodeVideoFrameConstMeta,
-        argValues: [rawFrame, ptsUs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegEncodeVideoFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_encode_video_frame",
-        argNames: ["rawFrame", "ptsUs"],
-      );
-
-  @override
-  Future<List<FlutterEncodedVideoFrame>>
-  crateApiFfmpegFlutterApiFfmpegFlushVideoEncoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegEncodeVideoFrameConstMeta,
+            argValues: [rawFrame, ptsUs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegEncodeVideoFrameConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_encode_video_frame",
+            argNames: ["rawFrame", "ptsUs"],
+        );
+        
+
+@override Future<List<FlutterEncodedVideoFrame>> crateApiFfmpegFlutterApiFfmpegFlushVideoEncoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_encoded_video_frame,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegFlushVideoEncoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegFlushVideoEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_flush_video_encoder",
-        argNames: [],
-      );
-
-  @override
-  Future<int> crateApiFfmpegFlutterApiFfmpegGetAudioBitrate({
-    required FlutterAudioCodec codec,
-    required bool highQuality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_audio_codec(codec, serializer);
-          sse_encode_bool(highQuality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegFlushVideoEncoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegFlushVideoEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_flush_video_encoder",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiFfmpegFlutterApiFfmpegGetAudioBitrate({required FlutterAudioCodec codec , required bool highQuality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_audio_codec(codec, serializer);
+sse_encode_bool(highQuality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegGetAudioBitrateConstMeta,
-        argValues: [codec, highQuality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetAudioBitrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_get_audio_bitrate",
-        argNames: ["codec", "highQuality"],
-      );
-
-  @override
-  Future<String> crateApiFfmpegFlutterApiFfmpegGetAudioMimeType({
-    required FlutterAudioCodec codec,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_audio_codec(codec, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegGetAudioBitrateConstMeta,
+            argValues: [codec, highQuality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetAudioBitrateConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_get_audio_bitrate",
+            argNames: ["codec", "highQuality"],
+        );
+        
+
+@override Future<String> crateApiFfmpegFlutterApiFfmpegGetAudioMimeType({required FlutterAudioCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_audio_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegGetAudioMimeTypeConstMeta,
-        argValues: [codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetAudioMimeTypeConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_get_audio_mime_type",
-        argNames: ["codec"],
-      );
-
-  @override
-  Future<(int, int)> crateApiFfmpegFlutterApiFfmpegGetQualityDimensions({
-    required FlutterVideoQualityFfmpeg quality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegGetAudioMimeTypeConstMeta,
+            argValues: [codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetAudioMimeTypeConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_get_audio_mime_type",
+            argNames: ["codec"],
+        );
+        
+
+@override Future<(int,int)> crateApiFfmpegFlutterApiFfmpegGetQualityDimensions({required FlutterVideoQualityFfmpeg quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_record_u_32_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegGetQualityDimensionsConstMeta,
-        argValues: [quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegGetQualityDimensionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_get_quality_dimensions",
-        argNames: ["quality"],
-      );
-
-  @override
-  Future<int> crateApiFfmpegFlutterApiFfmpegGetRecommendedBitrate({
-    required FlutterVideoQualityFfmpeg quality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegGetQualityDimensionsConstMeta,
+            argValues: [quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetQualityDimensionsConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_get_quality_dimensions",
+            argNames: ["quality"],
+        );
+        
+
+@override Future<int> crateApiFfmpegFlutterApiFfmpegGetRecommendedBitrate({required FlutterVideoQualityFfmpeg quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality_ffmpeg(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiFfmpegFlutterApiFfmpegGetRecommendedBitrateConstMeta,
-        argValues: [quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegGetRecommendedBitrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_get_recommended_bitrate",
-        argNames: ["quality"],
-      );
-
-  @override
-  Future<String?> crateApiFfmpegFlutterApiFfmpegGetVersion() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegGetRecommendedBitrateConstMeta,
+            argValues: [quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetRecommendedBitrateConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_get_recommended_bitrate",
+            argNames: ["quality"],
+        );
+        
+
+@override Future<String?> crateApiFfmpegFlutterApiFfmpegGetVersion()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegGetVersionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetVersionConstMeta =>
-      const TaskConstMeta(debugName: "ffmpeg_get_version", argNames: []);
-
-  @override
-  Future<String> crateApiFfmpegFlutterApiFfmpegGetVideoMimeType({
-    required FlutterVideoCodec codec,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_codec(codec, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegGetVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetVersionConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_get_version",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiFfmpegFlutterApiFfmpegGetVideoMimeType({required FlutterVideoCodec codec })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_codec(codec, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegGetVideoMimeTypeConstMeta,
-        argValues: [codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetVideoMimeTypeConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_get_video_mime_type",
-        argNames: ["codec"],
-      );
-
-  @override
-  Future<bool> crateApiFfmpegFlutterApiFfmpegIsAvailable() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegGetVideoMimeTypeConstMeta,
+            argValues: [codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegGetVideoMimeTypeConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_get_video_mime_type",
+            argNames: ["codec"],
+        );
+        
+
+@override Future<bool> crateApiFfmpegFlutterApiFfmpegIsAvailable()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegIsAvailableConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegIsAvailableConstMeta =>
-      const TaskConstMeta(debugName: "ffmpeg_is_available", argNames: []);
-
-  @override
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegListAudioCodecs() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegIsAvailableConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegIsAvailableConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_is_available",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiFfmpegFlutterApiFfmpegListAudioCodecs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegListAudioCodecsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegListAudioCodecsConstMeta =>
-      const TaskConstMeta(debugName: "ffmpeg_list_audio_codecs", argNames: []);
-
-  @override
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegListHardwareAccels() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegListAudioCodecsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegListAudioCodecsConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_list_audio_codecs",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiFfmpegFlutterApiFfmpegListHardwareAccels()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegListHardwareAccelsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiFfmpegFlutterApiFfmpegListHardwareAccelsConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_list_hardware_accels",
-        argNames: [],
-      );
-
-  @override
-  Future<List<String>> crateApiFfmpegFlutterApiFfmpegListVideoCodecs() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegListHardwareAccelsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegListHardwareAccelsConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_list_hardware_accels",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiFfmpegFlutterApiFfmpegListVideoCodecs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegListVideoCodecsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegListVideoCodecsConstMeta =>
-      const TaskConstMeta(debugName: "ffmpeg_list_video_codecs", argNames: []);
-
-  @override
-  Future<void> crateApiFfmpegFlutterApiFfmpegResetVideoEncoder() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegListVideoCodecsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegListVideoCodecsConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_list_video_codecs",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFfmpegFlutterApiFfmpegResetVideoEncoder()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFfmpegFlutterApiFfmpegResetVideoEncoderConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegResetVideoEncoderConstMeta =>
-      const TaskConstMeta(
-        debugName: "ffmpeg_reset_video_encoder",
-        argNames: [],
-      );
-
-  @override
-  Future<List<FlutterVideoQuality>>
-  crateApiLiveFlutterApiGetAllVideoQualities() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFfmpegFlutterApiFfmpegResetVideoEncoderConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFfmpegFlutterApiFfmpegResetVideoEncoderConstMeta => const TaskConstMeta(
+            debugName: "ffmpeg_reset_video_encoder",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterEndpointConfig> crateApiIrohLiveFlutterApiFlutterEndpointConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_flutter_endpoint_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiFlutterEndpointConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiFlutterEndpointConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "flutter_endpoint_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<FlutterVideoQuality>> crateApiLiveFlutterApiGetAllVideoQualities()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_video_quality,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetAllVideoQualitiesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetAllVideoQualitiesConstMeta =>
-      const TaskConstMeta(debugName: "get_all_video_qualities", argNames: []);
-
-  @override
-  Future<String> crateApiDirectFlutterApiGetDirectEndpointId() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetAllVideoQualitiesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetAllVideoQualitiesConstMeta => const TaskConstMeta(
+            debugName: "get_all_video_qualities",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiDirectFlutterApiGetDirectEndpointId()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiGetDirectEndpointIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiGetDirectEndpointIdConstMeta =>
-      const TaskConstMeta(debugName: "get_direct_endpoint_id", argNames: []);
-
-  @override
-  Future<int> crateApiDirectFlutterApiGetDirectPeerCount() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiGetDirectEndpointIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiGetDirectEndpointIdConstMeta => const TaskConstMeta(
+            debugName: "get_direct_endpoint_id",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiDirectFlutterApiGetDirectPeerCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDirectFlutterApiGetDirectPeerCountConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiGetDirectPeerCountConstMeta =>
-      const TaskConstMeta(debugName: "get_direct_peer_count", argNames: []);
-
-  @override
-  Future<String> crateApiLiveFlutterApiGetDirectTicket() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 47,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiGetDirectPeerCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiGetDirectPeerCountConstMeta => const TaskConstMeta(
+            debugName: "get_direct_peer_count",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiLiveFlutterApiGetDirectTicket()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetDirectTicketConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetDirectTicketConstMeta =>
-      const TaskConstMeta(debugName: "get_direct_ticket", argNames: []);
-
-  @override
-  Future<String> crateApiFlutterApiGetEndpointId() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 48,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetDirectTicketConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetDirectTicketConstMeta => const TaskConstMeta(
+            debugName: "get_direct_ticket",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiFlutterApiGetEndpointId()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiGetEndpointIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiGetEndpointIdConstMeta =>
-      const TaskConstMeta(debugName: "get_endpoint_id", argNames: []);
-
-  @override
-  Future<FlutterConnectionStats>
-  crateApiLiveFlutterApiGetLiveConnectionStats() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 49,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiGetEndpointIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiGetEndpointIdConstMeta => const TaskConstMeta(
+            debugName: "get_endpoint_id",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterConnectionStats> crateApiLiveFlutterApiGetLiveConnectionStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_connection_stats,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetLiveConnectionStatsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetLiveConnectionStatsConstMeta =>
-      const TaskConstMeta(debugName: "get_live_connection_stats", argNames: []);
-
-  @override
-  Future<String> crateApiLiveFlutterApiGetLiveTicket() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 50,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetLiveConnectionStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetLiveConnectionStatsConstMeta => const TaskConstMeta(
+            debugName: "get_live_connection_stats",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiLiveFlutterApiGetLiveTicket()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetLiveTicketConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetLiveTicketConstMeta =>
-      const TaskConstMeta(debugName: "get_live_ticket", argNames: []);
-
-  @override
-  Future<int> crateApiLiveFlutterApiGetQualityBitrate({
-    required FlutterVideoQuality quality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality(quality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 51,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetLiveTicketConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetLiveTicketConstMeta => const TaskConstMeta(
+            debugName: "get_live_ticket",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiLiveFlutterApiGetQualityBitrate({required FlutterVideoQuality quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetQualityBitrateConstMeta,
-        argValues: [quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetQualityBitrateConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_quality_bitrate",
-        argNames: ["quality"],
-      );
-
-  @override
-  QualityConstraints crateApiFlutterApiGetQualityConstraints({
-    required Quality quality,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_quality(quality, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetQualityBitrateConstMeta,
+            argValues: [quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetQualityBitrateConstMeta => const TaskConstMeta(
+            debugName: "get_quality_bitrate",
+            argNames: ["quality"],
+        );
+        
+
+@override QualityConstraints crateApiFlutterApiGetQualityConstraints({required Quality quality })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_quality(quality, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_quality_constraints,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFlutterApiGetQualityConstraintsConstMeta,
-        argValues: [quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiGetQualityConstraintsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_quality_constraints",
-        argNames: ["quality"],
-      );
-
-  @override
-  Future<(int, int)> crateApiLiveFlutterApiGetQualityDimensions({
-    required FlutterVideoQuality quality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality(quality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 53,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiGetQualityConstraintsConstMeta,
+            argValues: [quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiGetQualityConstraintsConstMeta => const TaskConstMeta(
+            debugName: "get_quality_constraints",
+            argNames: ["quality"],
+        );
+        
+
+@override Future<(int,int)> crateApiLiveFlutterApiGetQualityDimensions({required FlutterVideoQuality quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_record_u_32_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetQualityDimensionsConstMeta,
-        argValues: [quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetQualityDimensionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "get_quality_dimensions",
-        argNames: ["quality"],
-      );
-
-  @override
-  Future<FlutterVideoQuality> crateApiLiveFlutterApiGetRecommendedQuality() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 54,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetQualityDimensionsConstMeta,
+            argValues: [quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetQualityDimensionsConstMeta => const TaskConstMeta(
+            debugName: "get_quality_dimensions",
+            argNames: ["quality"],
+        );
+        
+
+@override Future<FlutterVideoQuality> crateApiLiveFlutterApiGetRecommendedQuality()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_video_quality,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiGetRecommendedQualityConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiGetRecommendedQualityConstMeta =>
-      const TaskConstMeta(debugName: "get_recommended_quality", argNames: []);
-
-  @override
-  String crateApiSimpleGreet({required String name}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiGetRecommendedQualityConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiGetRecommendedQualityConstMeta => const TaskConstMeta(
+            debugName: "get_recommended_quality",
+            argNames: [],
+        );
+        
+
+@override String crateApiSimpleGreet({required String name })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(name, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
-
-  @override
-  Future<bool> crateApiLiveFlutterApiHasActiveBroadcast() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 56,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleGreetConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleGreetConstMeta => const TaskConstMeta(
+            debugName: "greet",
+            argNames: ["name"],
+        );
+        
+
+@override Future<bool> crateApiLiveFlutterApiHasActiveBroadcast()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLiveFlutterApiHasActiveBroadcastConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiHasActiveBroadcastConstMeta =>
-      const TaskConstMeta(debugName: "has_active_broadcast", argNames: []);
-
-  @override
-  Future<bool> crateApiLiveFlutterApiHasActiveSubscription() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 57,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiHasActiveBroadcastConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiHasActiveBroadcastConstMeta => const TaskConstMeta(
+            debugName: "has_active_broadcast",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiLiveFlutterApiHasActiveSubscription()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLiveFlutterApiHasActiveSubscriptionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiHasActiveSubscriptionConstMeta =>
-      const TaskConstMeta(debugName: "has_active_subscription", argNames: []);
-
-  @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 58,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiHasActiveSubscriptionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiHasActiveSubscriptionConstMeta => const TaskConstMeta(
+            debugName: "has_active_subscription",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiSimpleInitApp()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
-
-  @override
-  Future<String> crateApiDirectFlutterApiInitDirectStreaming() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 59,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiSimpleInitAppConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiSimpleInitAppConstMeta => const TaskConstMeta(
+            debugName: "init_app",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiDirectFlutterApiInitDirectStreaming()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiInitDirectStreamingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiInitDirectStreamingConstMeta => const TaskConstMeta(
+            debugName: "init_direct_streaming",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiDirectFlutterApiInitDirectStreamingWithKey({required List<int> secretKey })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiInitDirectStreamingConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiInitDirectStreamingConstMeta =>
-      const TaskConstMeta(debugName: "init_direct_streaming", argNames: []);
-
-  @override
-  Future<String> crateApiFlutterApiInitStreamingNode() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 60,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiInitDirectStreamingWithKeyConstMeta,
+            argValues: [secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiInitDirectStreamingWithKeyConstMeta => const TaskConstMeta(
+            debugName: "init_direct_streaming_with_key",
+            argNames: ["secretKey"],
+        );
+        
+
+@override Future<String> crateApiFlutterApiInitStreamingNode()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiInitStreamingNodeConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiInitStreamingNodeConstMeta =>
-      const TaskConstMeta(debugName: "init_streaming_node", argNames: []);
-
-  @override
-  String? crateApiIrohLiveFlutterApiIrohCaptureCurrentDevice() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiInitStreamingNodeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiInitStreamingNodeConstMeta => const TaskConstMeta(
+            debugName: "init_streaming_node",
+            argNames: [],
+        );
+        
+
+@override String? crateApiIrohLiveFlutterApiIrohCaptureCurrentDevice()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureCurrentDeviceConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohCaptureCurrentDeviceConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_capture_current_device",
-        argNames: [],
-      );
-
-  @override
-  FlutterVideoFrame crateApiIrohLiveFlutterApiIrohCaptureGetTestFrame({
-    required int width,
-    required int height,
-    required String pattern,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(width, serializer);
-          sse_encode_u_32(height, serializer);
-          sse_encode_String(pattern, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureCurrentDeviceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureCurrentDeviceConstMeta => const TaskConstMeta(
+            debugName: "iroh_capture_current_device",
+            argNames: [],
+        );
+        
+
+@override FlutterVideoFrame crateApiIrohLiveFlutterApiIrohCaptureGetTestFrame({required int width , required int height , required String pattern })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(width, serializer);
+sse_encode_u_32(height, serializer);
+sse_encode_String(pattern, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_video_frame,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureGetTestFrameConstMeta,
-        argValues: [width, height, pattern],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohCaptureGetTestFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_capture_get_test_frame",
-        argNames: ["width", "height", "pattern"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohCaptureInit() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureGetTestFrameConstMeta,
+            argValues: [width, height, pattern],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureGetTestFrameConstMeta => const TaskConstMeta(
+            debugName: "iroh_capture_get_test_frame",
+            argNames: ["width", "height", "pattern"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohCaptureInit()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureInitConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureInitConstMeta =>
-      const TaskConstMeta(debugName: "iroh_capture_init", argNames: []);
-
-  @override
-  List<FlutterCaptureDevice>
-  crateApiIrohLiveFlutterApiIrohCaptureListDevices() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureInitConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureInitConstMeta => const TaskConstMeta(
+            debugName: "iroh_capture_init",
+            argNames: [],
+        );
+        
+
+@override List<FlutterCaptureDevice> crateApiIrohLiveFlutterApiIrohCaptureListDevices()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_capture_device,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureListDevicesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohCaptureListDevicesConstMeta =>
-      const TaskConstMeta(debugName: "iroh_capture_list_devices", argNames: []);
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohCaptureStart({required String deviceId}) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(deviceId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureListDevicesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureListDevicesConstMeta => const TaskConstMeta(
+            debugName: "iroh_capture_list_devices",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohCaptureStart({required String deviceId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(deviceId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureStartConstMeta,
-        argValues: [deviceId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureStartConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_capture_start",
-        argNames: ["deviceId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohCaptureStop() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureStartConstMeta,
+            argValues: [deviceId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureStartConstMeta => const TaskConstMeta(
+            debugName: "iroh_capture_start",
+            argNames: ["deviceId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohCaptureStop()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureStopConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureStopConstMeta =>
-      const TaskConstMeta(debugName: "iroh_capture_stop", argNames: []);
-
-  @override
-  FlutterBroadcastCatalog crateApiIrohLiveFlutterApiIrohCatalogCreate({
-    required String broadcastId,
-    required List<String> videoRenditions,
-    required List<String> audioRenditions,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(broadcastId, serializer);
-          sse_encode_list_String(videoRenditions, serializer);
-          sse_encode_list_String(audioRenditions, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCaptureStopConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCaptureStopConstMeta => const TaskConstMeta(
+            debugName: "iroh_capture_stop",
+            argNames: [],
+        );
+        
+
+@override FlutterBroadcastCatalog crateApiIrohLiveFlutterApiIrohCatalogCreate({required String broadcastId , required List<String> videoRenditions , required List<String> audioRenditions })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(broadcastId, serializer);
+sse_encode_list_String(videoRenditions, serializer);
+sse_encode_list_String(audioRenditions, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_broadcast_catalog,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCatalogCreateConstMeta,
-        argValues: [broadcastId, videoRenditions, audioRenditions],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCatalogCreateConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_catalog_create",
-        argNames: ["broadcastId", "videoRenditions", "audioRenditions"],
-      );
-
-  @override
-  String crateApiIrohLiveFlutterApiIrohCatalogToJson({
-    required FlutterBroadcastCatalog catalog,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_flutter_broadcast_catalog(catalog, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCatalogCreateConstMeta,
+            argValues: [broadcastId, videoRenditions, audioRenditions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCatalogCreateConstMeta => const TaskConstMeta(
+            debugName: "iroh_catalog_create",
+            argNames: ["broadcastId", "videoRenditions", "audioRenditions"],
+        );
+        
+
+@override String crateApiIrohLiveFlutterApiIrohCatalogToJson({required FlutterBroadcastCatalog catalog })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_flutter_broadcast_catalog(catalog, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohCatalogToJsonConstMeta,
-        argValues: [catalog],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCatalogToJsonConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_catalog_to_json",
-        argNames: ["catalog"],
-      );
-
-  @override
-  List<FlutterAudioRendition> crateApiIrohLiveFlutterApiIrohGetAudioPresets() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohCatalogToJsonConstMeta,
+            argValues: [catalog],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohCatalogToJsonConstMeta => const TaskConstMeta(
+            debugName: "iroh_catalog_to_json",
+            argNames: ["catalog"],
+        );
+        
+
+@override Future<List<FlutterDiscoveryEvent>> crateApiIrohLiveFlutterApiIrohDiscoveryEvents({required String discoveryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(discoveryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_discovery_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohDiscoveryEventsConstMeta,
+            argValues: [discoveryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohDiscoveryEventsConstMeta => const TaskConstMeta(
+            debugName: "iroh_discovery_events",
+            argNames: ["discoveryId"],
+        );
+        
+
+@override Future<List<String>> crateApiIrohLiveFlutterApiIrohDiscoveryList({required String discoveryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(discoveryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohDiscoveryListConstMeta,
+            argValues: [discoveryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohDiscoveryListConstMeta => const TaskConstMeta(
+            debugName: "iroh_discovery_list",
+            argNames: ["discoveryId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohDiscoveryStart({required String discoveryId , required String ticketString , required String prefix })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(discoveryId, serializer);
+sse_encode_String(ticketString, serializer);
+sse_encode_String(prefix, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohDiscoveryStartConstMeta,
+            argValues: [discoveryId, ticketString, prefix],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohDiscoveryStartConstMeta => const TaskConstMeta(
+            debugName: "iroh_discovery_start",
+            argNames: ["discoveryId", "ticketString", "prefix"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohDiscoveryStop({required String discoveryId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(discoveryId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohDiscoveryStopConstMeta,
+            argValues: [discoveryId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohDiscoveryStopConstMeta => const TaskConstMeta(
+            debugName: "iroh_discovery_stop",
+            argNames: ["discoveryId"],
+        );
+        
+
+@override List<FlutterAudioRendition> crateApiIrohLiveFlutterApiIrohGetAudioPresets()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_audio_rendition,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohGetAudioPresetsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetAudioPresetsConstMeta =>
-      const TaskConstMeta(debugName: "iroh_get_audio_presets", argNames: []);
-
-  @override
-  Map<String, bool> crateApiIrohLiveFlutterApiIrohGetFeatures() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohGetAudioPresetsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetAudioPresetsConstMeta => const TaskConstMeta(
+            debugName: "iroh_get_audio_presets",
+            argNames: [],
+        );
+        
+
+@override Map<String, bool> crateApiIrohLiveFlutterApiIrohGetFeatures()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_Map_String_bool_None,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohGetFeaturesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetFeaturesConstMeta =>
-      const TaskConstMeta(debugName: "iroh_get_features", argNames: []);
-
-  @override
-  List<String> crateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecs() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohGetFeaturesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetFeaturesConstMeta => const TaskConstMeta(
+            debugName: "iroh_get_features",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecs()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecsConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_get_supported_audio_codecs",
-        argNames: [],
-      );
-
-  @override
-  List<String> crateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecs() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetSupportedAudioCodecsConstMeta => const TaskConstMeta(
+            debugName: "iroh_get_supported_audio_codecs",
+            argNames: [],
+        );
+        
+
+@override List<String> crateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecs()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecsConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_get_supported_video_codecs",
-        argNames: [],
-      );
-
-  @override
-  String crateApiIrohLiveFlutterApiIrohGetVersion() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetSupportedVideoCodecsConstMeta => const TaskConstMeta(
+            debugName: "iroh_get_supported_video_codecs",
+            argNames: [],
+        );
+        
+
+@override String crateApiIrohLiveFlutterApiIrohGetVersion()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohGetVersionConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetVersionConstMeta =>
-      const TaskConstMeta(debugName: "iroh_get_version", argNames: []);
-
-  @override
-  List<FlutterVideoRendition> crateApiIrohLiveFlutterApiIrohGetVideoPresets() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohGetVersionConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetVersionConstMeta => const TaskConstMeta(
+            debugName: "iroh_get_version",
+            argNames: [],
+        );
+        
+
+@override List<FlutterVideoRendition> crateApiIrohLiveFlutterApiIrohGetVideoPresets()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_video_rendition,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohGetVideoPresetsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetVideoPresetsConstMeta =>
-      const TaskConstMeta(debugName: "iroh_get_video_presets", argNames: []);
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohIsCodecHwAccelerated({
-    required String codec,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(codec, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohGetVideoPresetsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohGetVideoPresetsConstMeta => const TaskConstMeta(
+            debugName: "iroh_get_video_presets",
+            argNames: [],
+        );
+        
+
+@override String crateApiIrohLiveFlutterApiIrohIdentityEndpointId({required List<int> secretKey })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohIdentityEndpointIdConstMeta,
+            argValues: [secretKey],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohIdentityEndpointIdConstMeta => const TaskConstMeta(
+            debugName: "iroh_identity_endpoint_id",
+            argNames: ["secretKey"],
+        );
+        
+
+@override Uint8List crateApiIrohLiveFlutterApiIrohIdentityGenerate()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohIdentityGenerateConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohIdentityGenerateConstMeta => const TaskConstMeta(
+            debugName: "iroh_identity_generate",
+            argNames: [],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohIsCodecHwAccelerated({required String codec })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(codec, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohIsCodecHwAcceleratedConstMeta,
-        argValues: [codec],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohIsCodecHwAcceleratedConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_is_codec_hw_accelerated",
-        argNames: ["codec"],
-      );
-
-  @override
-  Future<String> crateApiIrohLiveFlutterApiIrohNodeGetEndpointId() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 76,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohIsCodecHwAcceleratedConstMeta,
+            argValues: [codec],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohIsCodecHwAcceleratedConstMeta => const TaskConstMeta(
+            debugName: "iroh_is_codec_hw_accelerated",
+            argNames: ["codec"],
+        );
+        
+
+@override Future<Uint8List> crateApiIrohLiveFlutterApiIrohNodeExportKey()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeExportKeyConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeExportKeyConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_export_key",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohNodeGetEndpointId()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeGetEndpointIdConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeGetEndpointIdConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_get_endpoint_id",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohNodeGetTicket()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeGetTicketConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeGetTicketConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_get_ticket",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohNodeInit()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeInitConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeInitConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_init",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohNodeInitFromFile({required String path , FlutterEndpointConfig? config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(path, serializer);
+sse_encode_opt_box_autoadd_flutter_endpoint_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeInitFromFileConstMeta,
+            argValues: [path, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeInitFromFileConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_init_from_file",
+            argNames: ["path", "config"],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohNodeInitWithConfig({required FlutterEndpointConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_flutter_endpoint_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohNodeGetEndpointIdConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeGetEndpointIdConstMeta =>
-      const TaskConstMeta(debugName: "iroh_node_get_endpoint_id", argNames: []);
-
-  @override
-  Future<String> crateApiIrohLiveFlutterApiIrohNodeInit() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 77,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeInitWithConfigConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeInitWithConfigConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_init_with_config",
+            argNames: ["config"],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohNodeInitWithKey({required List<int> secretKey , FlutterEndpointConfig? config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(secretKey, serializer);
+sse_encode_opt_box_autoadd_flutter_endpoint_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohNodeInitConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeInitConstMeta =>
-      const TaskConstMeta(debugName: "iroh_node_init", argNames: []);
-
-  @override
-  Future<void> crateApiIrohLiveFlutterApiIrohNodeShutdown() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 78,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeInitWithKeyConstMeta,
+            argValues: [secretKey, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeInitWithKeyConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_init_with_key",
+            argNames: ["secretKey", "config"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohNodeSetDisplayName({String? name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_opt_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeSetDisplayNameConstMeta,
+            argValues: [name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeSetDisplayNameConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_set_display_name",
+            argNames: ["name"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohNodeShutdown()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohNodeShutdownConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeShutdownConstMeta =>
-      const TaskConstMeta(debugName: "iroh_node_shutdown", argNames: []);
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishCreate({
-    required String publisherId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohNodeShutdownConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohNodeShutdownConstMeta => const TaskConstMeta(
+            debugName: "iroh_node_shutdown",
+            argNames: [],
+        );
+        
+
+@override Future<List<FlutterChatMessage>> crateApiIrohLiveFlutterApiIrohPublishChatMessages({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_chat_message,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishChatMessagesConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishChatMessagesConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_chat_messages",
+            argNames: ["publisherId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishCreate({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishCreateConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishCreateConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_create",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  Future<String> crateApiIrohLiveFlutterApiIrohPublishCreateAsync({
-    required String publisherId,
-    required String broadcastName,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          sse_encode_String(broadcastName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 80,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishCreateConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishCreateConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_create",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohPublishCreateAsync({required String publisherId , required String broadcastName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_String(broadcastName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishCreateAsyncConstMeta,
-        argValues: [publisherId, broadcastName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohPublishCreateAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_create_async",
-        argNames: ["publisherId", "broadcastName"],
-      );
-
-  @override
-  FlutterPublisherStatus? crateApiIrohLiveFlutterApiIrohPublishGetStatus({
-    required String publisherId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_flutter_publisher_status,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishCreateAsyncConstMeta,
+            argValues: [publisherId, broadcastName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishCreateAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_create_async",
+            argNames: ["publisherId", "broadcastName"],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohPublishEnableEncryption({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishEnableEncryptionConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishEnableEncryptionConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_enable_encryption",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<BigInt?> crateApiIrohLiveFlutterApiIrohPublishFilePosition({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishGetStatusConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishGetStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_get_status",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  String? crateApiIrohLiveFlutterApiIrohPublishGetTicket({
-    required String publisherId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishFilePositionConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishFilePositionConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_file_position",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishFileSeek({required String publisherId , required BigInt positionMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_u_64(positionMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishFileSeekConstMeta,
+            argValues: [publisherId, positionMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishFileSeekConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_file_seek",
+            argNames: ["publisherId", "positionMs"],
+        );
+        
+
+@override Future<BigInt> crateApiIrohLiveFlutterApiIrohPublishFileStartAsync({required String publisherId , required String path , required bool looping })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_String(path, serializer);
+sse_encode_bool(looping, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishFileStartAsyncConstMeta,
+            argValues: [publisherId, path, looping],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishFileStartAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_file_start_async",
+            argNames: ["publisherId", "path", "looping"],
+        );
+        
+
+@override Future<String?> crateApiIrohLiveFlutterApiIrohPublishGetCatalogJson({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishGetTicketConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishGetTicketConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_get_ticket",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishPushAudio({
-    required String publisherId,
-    required FlutterAudioSamples samples,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          sse_encode_box_autoadd_flutter_audio_samples(samples, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishGetCatalogJsonConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishGetCatalogJsonConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_get_catalog_json",
+            argNames: ["publisherId"],
+        );
+        
+
+@override FlutterPublisherStatus? crateApiIrohLiveFlutterApiIrohPublishGetStatus({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_flutter_publisher_status,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishPushAudioConstMeta,
-        argValues: [publisherId, samples],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishPushAudioConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_push_audio",
-        argNames: ["publisherId", "samples"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedAudio({
-    required String publisherId,
-    required FlutterEncodedAudioPacket packet,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          sse_encode_box_autoadd_flutter_encoded_audio_packet(
-            packet,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishGetStatusConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishGetStatusConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_get_status",
+            argNames: ["publisherId"],
+        );
+        
+
+@override String? crateApiIrohLiveFlutterApiIrohPublishGetTicket({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedAudioConstMeta,
-        argValues: [publisherId, packet],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedAudioConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_push_encoded_audio",
-        argNames: ["publisherId", "packet"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedVideo({
-    required String publisherId,
-    required FlutterEncodedVideoPacket packet,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          sse_encode_box_autoadd_flutter_encoded_video_packet(
-            packet,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishGetTicketConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishGetTicketConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_get_ticket",
+            argNames: ["publisherId"],
+        );
+        
+
+@override List<FlutterRenditionStats> crateApiIrohLiveFlutterApiIrohPublishGetVideoStats({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_rendition_stats,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedVideoConstMeta,
-        argValues: [publisherId, packet],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedVideoConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_push_encoded_video",
-        argNames: ["publisherId", "packet"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishPushVideo({
-    required String publisherId,
-    required FlutterVideoFrame frame,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          sse_encode_box_autoadd_flutter_video_frame(frame, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishGetVideoStatsConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishGetVideoStatsConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_get_video_stats",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<FlutterIssuedTicket> crateApiIrohLiveFlutterApiIrohPublishIssueTicket({required String publisherId , required BigInt ttlSecs , String? subjectEndpointId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_u_64(ttlSecs, serializer);
+sse_encode_opt_String(subjectEndpointId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_flutter_issued_ticket,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishIssueTicketConstMeta,
+            argValues: [publisherId, ttlSecs, subjectEndpointId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishIssueTicketConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_issue_ticket",
+            argNames: ["publisherId", "ttlSecs", "subjectEndpointId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishPushAudio({required String publisherId , required FlutterAudioSamples samples })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_box_autoadd_flutter_audio_samples(samples, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishPushVideoConstMeta,
-        argValues: [publisherId, frame],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishPushVideoConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_push_video",
-        argNames: ["publisherId", "frame"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishRemove({
-    required String publisherId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishPushAudioConstMeta,
+            argValues: [publisherId, samples],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishPushAudioConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_push_audio",
+            argNames: ["publisherId", "samples"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedAudio({required String publisherId , required FlutterEncodedAudioPacket packet })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_box_autoadd_flutter_encoded_audio_packet(packet, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishRemoveConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishRemoveConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_remove",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishSetVideoRenditions({
-    required String publisherId,
-    required List<String> renditions,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          sse_encode_list_String(renditions, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedAudioConstMeta,
+            argValues: [publisherId, packet],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedAudioConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_push_encoded_audio",
+            argNames: ["publisherId", "packet"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishPushEncodedVideo({required String publisherId , required FlutterEncodedVideoPacket packet })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_box_autoadd_flutter_encoded_video_packet(packet, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohPublishSetVideoRenditionsConstMeta,
-        argValues: [publisherId, renditions],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohPublishSetVideoRenditionsConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_set_video_renditions",
-        argNames: ["publisherId", "renditions"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishStart({
-    required String publisherId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedVideoConstMeta,
+            argValues: [publisherId, packet],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishPushEncodedVideoConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_push_encoded_video",
+            argNames: ["publisherId", "packet"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishPushVideo({required String publisherId , required FlutterVideoFrame frame })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_box_autoadd_flutter_video_frame(frame, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStartConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStartConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_start",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  Future<void> crateApiIrohLiveFlutterApiIrohPublishStartAsync({
-    required String publisherId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 90,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishPushVideoConstMeta,
+            argValues: [publisherId, frame],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishPushVideoConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_push_video",
+            argNames: ["publisherId", "frame"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishRecordStart({required String publisherId , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStartAsyncConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStartAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_start_async",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohPublishStop({
-    required String publisherId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStopConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStopConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_stop",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  Future<void> crateApiIrohLiveFlutterApiIrohPublishStopAsync({
-    required String publisherId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(publisherId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 92,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishRecordStartConstMeta,
+            argValues: [publisherId, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishRecordStartConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_record_start",
+            argNames: ["publisherId", "path"],
+        );
+        
+
+@override Future<FlutterRecordingStats> crateApiIrohLiveFlutterApiIrohPublishRecordStop({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_flutter_recording_stats,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStopAsyncConstMeta,
-        argValues: [publisherId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStopAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_publish_stop_async",
-        argNames: ["publisherId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohSubscribeConnect({
-    required String subscriberId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93)!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishRecordStopConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishRecordStopConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_record_stop",
+            argNames: ["publisherId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishRemove({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeConnectConstMeta,
-        argValues: [subscriberId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeConnectConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_connect",
-        argNames: ["subscriberId"],
-      );
-
-  @override
-  Future<void> crateApiIrohLiveFlutterApiIrohSubscribeConnectAsync({
-    required String subscriberId,
-    required String ticketString,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          sse_encode_String(ticketString, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 94,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishRemoveConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishRemoveConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_remove",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishRevokeToken({required BigInt tokenId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(tokenId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohSubscribeConnectAsyncConstMeta,
-        argValues: [subscriberId, ticketString],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeConnectAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_connect_async",
-        argNames: ["subscriberId", "ticketString"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohSubscribeCreate({
-    required String subscriberId,
-    required String broadcastId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          sse_encode_String(broadcastId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeCreateConstMeta,
-        argValues: [subscriberId, broadcastId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeCreateConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_create",
-        argNames: ["subscriberId", "broadcastId"],
-      );
-
-  @override
-  Future<void> crateApiIrohLiveFlutterApiIrohSubscribeCreateAsync({
-    required String subscriberId,
-    required String broadcastId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          sse_encode_String(broadcastId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 96,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishRevokeTokenConstMeta,
+            argValues: [tokenId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishRevokeTokenConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_revoke_token",
+            argNames: ["tokenId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishSendChat({required String publisherId , required String text , BigInt? reactionTo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_String(text, serializer);
+sse_encode_opt_box_autoadd_u_64(reactionTo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeCreateAsyncConstMeta,
-        argValues: [subscriberId, broadcastId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeCreateAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_create_async",
-        argNames: ["subscriberId", "broadcastId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohSubscribeDisconnect({
-    required String subscriberId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectConstMeta,
-        argValues: [subscriberId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_disconnect",
-        argNames: ["subscriberId"],
-      );
-
-  @override
-  Future<void> crateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsync({
-    required String subscriberId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 98,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishSendChatConstMeta,
+            argValues: [publisherId, text, reactionTo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishSendChatConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_send_chat",
+            argNames: ["publisherId", "text", "reactionTo"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishSetAccess({required String publisherId , required FlutterAccessPolicy policy })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_box_autoadd_flutter_access_policy(policy, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishSetAccessConstMeta,
+            argValues: [publisherId, policy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishSetAccessConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_set_access",
+            argNames: ["publisherId", "policy"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishSetVideoRenditions({required String publisherId , required List<String> renditions })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+sse_encode_list_String(renditions, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishSetVideoRenditionsConstMeta,
+            argValues: [publisherId, renditions],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishSetVideoRenditionsConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_set_video_renditions",
+            argNames: ["publisherId", "renditions"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishStart({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStartConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStartConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_start",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishStartAsync({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStartAsyncConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStartAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_start_async",
+            argNames: ["publisherId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishStop({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStopConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStopConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_stop",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohPublishStopAsync({required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishStopAsyncConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishStopAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_stop_async",
+            argNames: ["publisherId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohPublishTakeKeyframeRequest({required String publisherId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(publisherId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohPublishTakeKeyframeRequestConstMeta,
+            argValues: [publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohPublishTakeKeyframeRequestConstMeta => const TaskConstMeta(
+            debugName: "iroh_publish_take_keyframe_request",
+            argNames: ["publisherId"],
+        );
+        
+
+@override Future<List<FlutterRelayInfo>> crateApiIrohLiveFlutterApiIrohRelayList()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_relay_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRelayListConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRelayListConstMeta => const TaskConstMeta(
+            debugName: "iroh_relay_list",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohRelayStart({required String ticketString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRelayStartConstMeta,
+            argValues: [ticketString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRelayStartConstMeta => const TaskConstMeta(
+            debugName: "iroh_relay_start",
+            argNames: ["ticketString"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohRelayStop({required String broadcastName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(broadcastName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRelayStopConstMeta,
+            argValues: [broadcastName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRelayStopConstMeta => const TaskConstMeta(
+            debugName: "iroh_relay_stop",
+            argNames: ["broadcastName"],
+        );
+        
+
+@override Future<List<FlutterRoomEvent>> crateApiIrohLiveFlutterApiIrohRoomEvents({required String room })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(room, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_room_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomEventsConstMeta,
+            argValues: [room],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomEventsConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_events",
+            argNames: ["room"],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohRoomHost({required String room , required BigInt ttlSecs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(room, serializer);
+sse_encode_u_64(ttlSecs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomHostConstMeta,
+            argValues: [room, ttlSecs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomHostConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_host",
+            argNames: ["room", "ttlSecs"],
+        );
+        
+
+@override Future<String> crateApiIrohLiveFlutterApiIrohRoomJoin({required String ticketString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomJoinConstMeta,
+            argValues: [ticketString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomJoinConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_join",
+            argNames: ["ticketString"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohRoomLeave({required String room })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(room, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomLeaveConstMeta,
+            argValues: [room],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomLeaveConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_leave",
+            argNames: ["room"],
+        );
+        
+
+@override Future<List<String>> crateApiIrohLiveFlutterApiIrohRoomMembers({required String room })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(room, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomMembersConstMeta,
+            argValues: [room],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomMembersConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_members",
+            argNames: ["room"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohRoomPublish({required String room , required String publisherId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(room, serializer);
+sse_encode_String(publisherId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomPublishConstMeta,
+            argValues: [room, publisherId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomPublishConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_publish",
+            argNames: ["room", "publisherId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohRoomSetDecode({required String room , required String pixelFormat })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(room, serializer);
+sse_encode_String(pixelFormat, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsyncConstMeta,
-        argValues: [subscriberId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsyncConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_disconnect_async",
-        argNames: ["subscriberId"],
-      );
-
-  @override
-  FlutterSubscriberStatus? crateApiIrohLiveFlutterApiIrohSubscribeGetStatus({
-    required String subscriberId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_flutter_subscriber_status,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRoomSetDecodeConstMeta,
+            argValues: [room, pixelFormat],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRoomSetDecodeConstMeta => const TaskConstMeta(
+            debugName: "iroh_room_set_decode",
+            argNames: ["room", "pixelFormat"],
+        );
+        
+
+@override Future<List<FlutterRosterEvent>> crateApiIrohLiveFlutterApiIrohRosterEvents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_roster_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRosterEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRosterEventsConstMeta => const TaskConstMeta(
+            debugName: "iroh_roster_events",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiIrohLiveFlutterApiIrohRosterKick({required String endpointId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(endpointId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 132, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRosterKickConstMeta,
+            argValues: [endpointId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRosterKickConstMeta => const TaskConstMeta(
+            debugName: "iroh_roster_kick",
+            argNames: ["endpointId"],
+        );
+        
+
+@override Future<List<FlutterViewer>> crateApiIrohLiveFlutterApiIrohRosterList()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_viewer,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohRosterListConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohRosterListConstMeta => const TaskConstMeta(
+            debugName: "iroh_roster_list",
+            argNames: [],
+        );
+        
+
+@override Future<List<FlutterAbrEvent>> crateApiIrohLiveFlutterApiIrohSubscribeAbrEvents({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_abr_event,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeGetStatusConstMeta,
-        argValues: [subscriberId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeGetStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_get_status",
-        argNames: ["subscriberId"],
-      );
-
-  @override
-  Future<FlutterReceivedVideoFrame?>
-  crateApiIrohLiveFlutterApiIrohSubscribeReceiveFrame({
-    required String subscriberId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 100,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_flutter_received_video_frame,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeAbrEventsConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeAbrEventsConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_abr_events",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<List<FlutterChatMessage>> crateApiIrohLiveFlutterApiIrohSubscribeChatMessages({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_chat_message,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohSubscribeReceiveFrameConstMeta,
-        argValues: [subscriberId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeReceiveFrameConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_receive_frame",
-        argNames: ["subscriberId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohSubscribeRemove({
-    required String subscriberId,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 101,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeChatMessagesConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeChatMessagesConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_chat_messages",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohSubscribeConnect({required String subscriberId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeRemoveConstMeta,
-        argValues: [subscriberId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeRemoveConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_remove",
-        argNames: ["subscriberId"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohSubscribeSetQuality({
-    required String subscriberId,
-    required String quality,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          sse_encode_String(quality, serializer);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 102,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeConnectConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeConnectConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_connect",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohSubscribeConnectAsync({required String subscriberId , required String ticketString })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(ticketString, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeConnectAsyncConstMeta,
+            argValues: [subscriberId, ticketString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeConnectAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_connect_async",
+            argNames: ["subscriberId", "ticketString"],
+        );
+        
+
+@override Future<List<FlutterConnectionState>> crateApiIrohLiveFlutterApiIrohSubscribeConnectionEvents({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_flutter_connection_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeConnectionEventsConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeConnectionEventsConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_connection_events",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<FlutterConnectionState?> crateApiIrohLiveFlutterApiIrohSubscribeConnectionState({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_flutter_connection_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeConnectionStateConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeConnectionStateConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_connection_state",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohSubscribeCreate({required String subscriberId , required String broadcastId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(broadcastId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeSetQualityConstMeta,
-        argValues: [subscriberId, quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeSetQualityConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_set_quality",
-        argNames: ["subscriberId", "quality"],
-      );
-
-  @override
-  bool crateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceive({
-    required String subscriberId,
-    required BigInt frameSize,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(subscriberId, serializer);
-          sse_encode_u_64(frameSize, serializer);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 103,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeCreateConstMeta,
+            argValues: [subscriberId, broadcastId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeCreateConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_create",
+            argNames: ["subscriberId", "broadcastId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohSubscribeCreateAsync({required String subscriberId , required String broadcastId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(broadcastId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeCreateAsyncConstMeta,
+            argValues: [subscriberId, broadcastId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeCreateAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_create_async",
+            argNames: ["subscriberId", "broadcastId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohSubscribeDisconnect({required String subscriberId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceiveConstMeta,
-        argValues: [subscriberId, frameSize],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceiveConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_subscribe_simulate_video_receive",
-        argNames: ["subscriberId", "frameSize"],
-      );
-
-  @override
-  FlutterTicketInfo? crateApiIrohLiveFlutterApiIrohTicketParse({
-    required String ticketString,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ticketString, serializer);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 104,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_disconnect",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsync({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsyncConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeDisconnectAsyncConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_disconnect_async",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<String?> crateApiIrohLiveFlutterApiIrohSubscribeGetCatalogJson({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeGetCatalogJsonConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeGetCatalogJsonConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_get_catalog_json",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override FlutterSubscriberStatus? crateApiIrohLiveFlutterApiIrohSubscribeGetStatus({required String subscriberId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_flutter_subscriber_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeGetStatusConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeGetStatusConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_get_status",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<FlutterVideoFrame?> crateApiIrohLiveFlutterApiIrohSubscribeReceiveDecodedFrame({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_flutter_video_frame,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeReceiveDecodedFrameConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeReceiveDecodedFrameConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_receive_decoded_frame",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<FlutterReceivedVideoFrame?> crateApiIrohLiveFlutterApiIrohSubscribeReceiveFrame({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_flutter_received_video_frame,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeReceiveFrameConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeReceiveFrameConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_receive_frame",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohSubscribeRecordStart({required String subscriberId , required String path })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(path, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeRecordStartConstMeta,
+            argValues: [subscriberId, path],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeRecordStartConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_record_start",
+            argNames: ["subscriberId", "path"],
+        );
+        
+
+@override Future<FlutterRecordingStats> crateApiIrohLiveFlutterApiIrohSubscribeRecordStop({required String subscriberId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_flutter_recording_stats,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeRecordStopConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeRecordStopConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_record_stop",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohSubscribeRemove({required String subscriberId })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeRemoveConstMeta,
+            argValues: [subscriberId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeRemoveConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_remove",
+            argNames: ["subscriberId"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohSubscribeSendChat({required String subscriberId , required String text , BigInt? reactionTo })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(text, serializer);
+sse_encode_opt_box_autoadd_u_64(reactionTo, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeSendChatConstMeta,
+            argValues: [subscriberId, text, reactionTo],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeSendChatConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_send_chat",
+            argNames: ["subscriberId", "text", "reactionTo"],
+        );
+        
+
+@override Future<void> crateApiIrohLiveFlutterApiIrohSubscribeSetDecode({required String subscriberId , required String pixelFormat })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(pixelFormat, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeSetDecodeConstMeta,
+            argValues: [subscriberId, pixelFormat],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeSetDecodeConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_set_decode",
+            argNames: ["subscriberId", "pixelFormat"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohSubscribeSetQuality({required String subscriberId , required String quality })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_String(quality, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeSetQualityConstMeta,
+            argValues: [subscriberId, quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeSetQualityConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_set_quality",
+            argNames: ["subscriberId", "quality"],
+        );
+        
+
+@override bool crateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceive({required String subscriberId , required BigInt frameSize })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(subscriberId, serializer);
+sse_encode_u_64(frameSize, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceiveConstMeta,
+            argValues: [subscriberId, frameSize],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohSubscribeSimulateVideoReceiveConstMeta => const TaskConstMeta(
+            debugName: "iroh_subscribe_simulate_video_receive",
+            argNames: ["subscriberId", "frameSize"],
+        );
+        
+
+@override String crateApiIrohLiveFlutterApiIrohTicketForBroadcast({required String ticket , required String broadcastName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticket, serializer);
+sse_encode_String(broadcastName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohTicketForBroadcastConstMeta,
+            argValues: [ticket, broadcastName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohTicketForBroadcastConstMeta => const TaskConstMeta(
+            debugName: "iroh_ticket_for_broadcast",
+            argNames: ["ticket", "broadcastName"],
+        );
+        
+
+@override FlutterTicketInfo? crateApiIrohLiveFlutterApiIrohTicketParse({required String ticketString })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketString, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_flutter_ticket_info,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiIrohLiveFlutterApiIrohTicketParseConstMeta,
-        argValues: [ticketString],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohTicketParseConstMeta =>
-      const TaskConstMeta(
-        debugName: "iroh_ticket_parse",
-        argNames: ["ticketString"],
-      );
-
-  @override
-  bool crateApiFlutterApiIsConnectedToStream() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 105,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohTicketParseConstMeta,
+            argValues: [ticketString],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohTicketParseConstMeta => const TaskConstMeta(
+            debugName: "iroh_ticket_parse",
+            argNames: ["ticketString"],
+        );
+        
+
+@override String crateApiIrohLiveFlutterApiIrohTicketWithMediaKey({required String ticket , required String keyTicket })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticket, serializer);
+sse_encode_String(keyTicket, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohTicketWithMediaKeyConstMeta,
+            argValues: [ticket, keyTicket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohTicketWithMediaKeyConstMeta => const TaskConstMeta(
+            debugName: "iroh_ticket_with_media_key",
+            argNames: ["ticket", "keyTicket"],
+        );
+        
+
+@override String crateApiIrohLiveFlutterApiIrohTicketWithoutMediaKey({required String ticket })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticket, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_String,
+        )
+        ,
+            constMeta: kCrateApiIrohLiveFlutterApiIrohTicketWithoutMediaKeyConstMeta,
+            argValues: [ticket],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiIrohLiveFlutterApiIrohTicketWithoutMediaKeyConstMeta => const TaskConstMeta(
+            debugName: "iroh_ticket_without_media_key",
+            argNames: ["ticket"],
+        );
+        
+
+@override bool crateApiFlutterApiIsConnectedToStream()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFlutterApiIsConnectedToStreamConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiIsConnectedToStreamConstMeta =>
-      const TaskConstMeta(debugName: "is_connected_to_stream", argNames: []);
-
-  @override
-  bool crateApiDirectFlutterApiIsDirectStreamingInitialized() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 106,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiIsConnectedToStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiIsConnectedToStreamConstMeta => const TaskConstMeta(
+            debugName: "is_connected_to_stream",
+            argNames: [],
+        );
+        
+
+@override bool crateApiDirectFlutterApiIsDirectStreamingInitialized()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiDirectFlutterApiIsDirectStreamingInitializedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-  get kCrateApiDirectFlutterApiIsDirectStreamingInitializedConstMeta =>
-      const TaskConstMeta(
-        debugName: "is_direct_streaming_initialized",
-        argNames: [],
-      );
-
-  @override
-  bool crateApiFlutterApiIsStreamingInitialized() {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          return pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 107,
-          )!;
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiIsDirectStreamingInitializedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiIsDirectStreamingInitializedConstMeta => const TaskConstMeta(
+            debugName: "is_direct_streaming_initialized",
+            argNames: [],
+        );
+        
+
+@override bool crateApiFlutterApiIsStreamingInitialized()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFlutterApiIsStreamingInitializedConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiIsStreamingInitializedConstMeta =>
-      const TaskConstMeta(debugName: "is_streaming_initialized", argNames: []);
-
-  @override
-  Future<String> crateApiDirectFlutterApiJoinDirectStream({
-    required String ticketStr,
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ticketStr, serializer);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 108,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiIsStreamingInitializedConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiIsStreamingInitializedConstMeta => const TaskConstMeta(
+            debugName: "is_streaming_initialized",
+            argNames: [],
+        );
+        
+
+@override Future<String> crateApiDirectFlutterApiJoinDirectStream({required String ticketStr , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketStr, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiJoinDirectStreamConstMeta,
-        argValues: [ticketStr, name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiJoinDirectStreamConstMeta =>
-      const TaskConstMeta(
-        debugName: "join_direct_stream",
-        argNames: ["ticketStr", "name"],
-      );
-
-  @override
-  Future<String> crateApiLiveFlutterApiJoinLiveBroadcast({
-    required String ticketStr,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ticketStr, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 109,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiJoinDirectStreamConstMeta,
+            argValues: [ticketStr, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiJoinDirectStreamConstMeta => const TaskConstMeta(
+            debugName: "join_direct_stream",
+            argNames: ["ticketStr", "name"],
+        );
+        
+
+@override Future<String> crateApiLiveFlutterApiJoinLiveBroadcast({required String ticketStr })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketStr, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiJoinLiveBroadcastConstMeta,
-        argValues: [ticketStr],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiJoinLiveBroadcastConstMeta =>
-      const TaskConstMeta(
-        debugName: "join_live_broadcast",
-        argNames: ["ticketStr"],
-      );
-
-  @override
-  Future<String> crateApiFlutterApiJoinStream({
-    required String ticketStr,
-    required String name,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ticketStr, serializer);
-          sse_encode_String(name, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 110,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiJoinLiveBroadcastConstMeta,
+            argValues: [ticketStr],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiJoinLiveBroadcastConstMeta => const TaskConstMeta(
+            debugName: "join_live_broadcast",
+            argNames: ["ticketStr"],
+        );
+        
+
+@override Future<String> crateApiFlutterApiJoinStream({required String ticketStr , required String name })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketStr, serializer);
+sse_encode_String(name, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiJoinStreamConstMeta,
-        argValues: [ticketStr, name],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiJoinStreamConstMeta =>
-      const TaskConstMeta(
-        debugName: "join_stream",
-        argNames: ["ticketStr", "name"],
-      );
-
-  @override
-  Future<void> crateApiDirectFlutterApiLeaveDirectStream() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 111,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiJoinStreamConstMeta,
+            argValues: [ticketStr, name],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiJoinStreamConstMeta => const TaskConstMeta(
+            debugName: "join_stream",
+            argNames: ["ticketStr", "name"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiLeaveDirectStream()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiLeaveDirectStreamConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiLeaveDirectStreamConstMeta =>
-      const TaskConstMeta(debugName: "leave_direct_stream", argNames: []);
-
-  @override
-  Future<void> crateApiLiveFlutterApiLeaveLiveBroadcast() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 112,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiLeaveDirectStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiLeaveDirectStreamConstMeta => const TaskConstMeta(
+            debugName: "leave_direct_stream",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiLeaveLiveBroadcast()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiLeaveLiveBroadcastConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiLeaveLiveBroadcastConstMeta =>
-      const TaskConstMeta(debugName: "leave_live_broadcast", argNames: []);
-
-  @override
-  Future<void> crateApiFlutterApiLeaveStream() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 113,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiLeaveLiveBroadcastConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiLeaveLiveBroadcastConstMeta => const TaskConstMeta(
+            debugName: "leave_live_broadcast",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFlutterApiLeaveStream()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiLeaveStreamConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiLeaveStreamConstMeta =>
-      const TaskConstMeta(debugName: "leave_stream", argNames: []);
-
-  @override
-  Future<void> crateApiLiveFlutterApiLiveBroadcastChunk({
-    required int index,
-    required List<int> data,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(index, serializer);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 114,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiLeaveStreamConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiLeaveStreamConstMeta => const TaskConstMeta(
+            debugName: "leave_stream",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiLiveBroadcastChunk({required int index , required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(index, serializer);
+sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiLiveBroadcastChunkConstMeta,
-        argValues: [index, data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiLiveBroadcastChunkConstMeta =>
-      const TaskConstMeta(
-        debugName: "live_broadcast_chunk",
-        argNames: ["index", "data"],
-      );
-
-  @override
-  Future<void> crateApiLiveFlutterApiLiveBroadcastMetadata({
-    required String fileName,
-    required BigInt fileSize,
-    required String mimeType,
-    required int totalChunks,
-    double? duration,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(fileName, serializer);
-          sse_encode_u_64(fileSize, serializer);
-          sse_encode_String(mimeType, serializer);
-          sse_encode_u_32(totalChunks, serializer);
-          sse_encode_opt_box_autoadd_f_64(duration, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 115,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiLiveBroadcastChunkConstMeta,
+            argValues: [index, data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiLiveBroadcastChunkConstMeta => const TaskConstMeta(
+            debugName: "live_broadcast_chunk",
+            argNames: ["index", "data"],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiLiveBroadcastMetadata({required String fileName , required BigInt fileSize , required String mimeType , required int totalChunks , double? duration })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(fileName, serializer);
+sse_encode_u_64(fileSize, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_u_32(totalChunks, serializer);
+sse_encode_opt_box_autoadd_f_64(duration, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiLiveBroadcastMetadataConstMeta,
-        argValues: [fileName, fileSize, mimeType, totalChunks, duration],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiLiveBroadcastMetadataConstMeta =>
-      const TaskConstMeta(
-        debugName: "live_broadcast_metadata",
-        argNames: [
-          "fileName",
-          "fileSize",
-          "mimeType",
-          "totalChunks",
-          "duration",
-        ],
-      );
-
-  @override
-  Future<int> crateApiLiveFlutterApiLiveBroadcastPeerCount() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 116,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiLiveBroadcastMetadataConstMeta,
+            argValues: [fileName, fileSize, mimeType, totalChunks, duration],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiLiveBroadcastMetadataConstMeta => const TaskConstMeta(
+            debugName: "live_broadcast_metadata",
+            argNames: ["fileName", "fileSize", "mimeType", "totalChunks", "duration"],
+        );
+        
+
+@override Future<int> crateApiLiveFlutterApiLiveBroadcastPeerCount()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiLiveBroadcastPeerCountConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiLiveBroadcastPeerCountConstMeta =>
-      const TaskConstMeta(debugName: "live_broadcast_peer_count", argNames: []);
-
-  @override
-  Future<void> crateApiLiveFlutterApiLiveRequestChunk({required int index}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_32(index, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 117,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiLiveBroadcastPeerCountConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiLiveBroadcastPeerCountConstMeta => const TaskConstMeta(
+            debugName: "live_broadcast_peer_count",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiLiveRequestChunk({required int index })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_32(index, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiLiveRequestChunkConstMeta,
-        argValues: [index],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiLiveRequestChunkConstMeta =>
-      const TaskConstMeta(debugName: "live_request_chunk", argNames: ["index"]);
-
-  @override
-  Future<FlutterMoqObject> crateApiMoqFlutterApiMoqAddObject({
-    required String trackPath,
-    required BigInt groupId,
-    required BigInt subgroupId,
-    required List<int> payload,
-    int? priority,
-    BigInt? ttlMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          sse_encode_u_64(groupId, serializer);
-          sse_encode_u_64(subgroupId, serializer);
-          sse_encode_list_prim_u_8_loose(payload, serializer);
-          sse_encode_opt_box_autoadd_u_8(priority, serializer);
-          sse_encode_opt_box_autoadd_u_64(ttlMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 118,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiLiveRequestChunkConstMeta,
+            argValues: [index],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiLiveRequestChunkConstMeta => const TaskConstMeta(
+            debugName: "live_request_chunk",
+            argNames: ["index"],
+        );
+        
+
+@override Future<FlutterMoqObject> crateApiMoqFlutterApiMoqAddObject({required String trackPath , required BigInt groupId , required BigInt subgroupId , required List<int> payload , int? priority , BigInt? ttlMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+sse_encode_u_64(groupId, serializer);
+sse_encode_u_64(subgroupId, serializer);
+sse_encode_list_prim_u_8_loose(payload, serializer);
+sse_encode_opt_box_autoadd_u_8(priority, serializer);
+sse_encode_opt_box_autoadd_u_64(ttlMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqAddObjectConstMeta,
-        argValues: [trackPath, groupId, subgroupId, payload, priority, ttlMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqAddObjectConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_add_object",
-        argNames: [
-          "trackPath",
-          "groupId",
-          "subgroupId",
-          "payload",
-          "priority",
-          "ttlMs",
-        ],
-      );
-
-  @override
-  Future<void> crateApiMoqFlutterApiMoqAnnounceNamespace({
-    required String namespacePath,
-    required List<String> tracks,
-    required bool acceptsPublishing,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(namespacePath, serializer);
-          sse_encode_list_String(tracks, serializer);
-          sse_encode_bool(acceptsPublishing, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 119,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqAddObjectConstMeta,
+            argValues: [trackPath, groupId, subgroupId, payload, priority, ttlMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqAddObjectConstMeta => const TaskConstMeta(
+            debugName: "moq_add_object",
+            argNames: ["trackPath", "groupId", "subgroupId", "payload", "priority", "ttlMs"],
+        );
+        
+
+@override Future<void> crateApiMoqFlutterApiMoqAnnounceNamespace({required String namespacePath , required List<String> tracks , required bool acceptsPublishing })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(namespacePath, serializer);
+sse_encode_list_String(tracks, serializer);
+sse_encode_bool(acceptsPublishing, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqAnnounceNamespaceConstMeta,
-        argValues: [namespacePath, tracks, acceptsPublishing],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqAnnounceNamespaceConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_announce_namespace",
-        argNames: ["namespacePath", "tracks", "acceptsPublishing"],
-      );
-
-  @override
-  Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfGroup({
-    required String trackPath,
-    required BigInt groupId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          sse_encode_u_64(groupId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 120,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqAnnounceNamespaceConstMeta,
+            argValues: [namespacePath, tracks, acceptsPublishing],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqAnnounceNamespaceConstMeta => const TaskConstMeta(
+            debugName: "moq_announce_namespace",
+            argNames: ["namespacePath", "tracks", "acceptsPublishing"],
+        );
+        
+
+@override Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfGroup({required String trackPath , required BigInt groupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+sse_encode_u_64(groupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqCreateEndOfGroupConstMeta,
-        argValues: [trackPath, groupId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateEndOfGroupConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_create_end_of_group",
-        argNames: ["trackPath", "groupId"],
-      );
-
-  @override
-  Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfTrack({
-    required String trackPath,
-    required BigInt groupId,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          sse_encode_u_64(groupId, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 121,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqCreateEndOfGroupConstMeta,
+            argValues: [trackPath, groupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateEndOfGroupConstMeta => const TaskConstMeta(
+            debugName: "moq_create_end_of_group",
+            argNames: ["trackPath", "groupId"],
+        );
+        
+
+@override Future<FlutterMoqObject> crateApiMoqFlutterApiMoqCreateEndOfTrack({required String trackPath , required BigInt groupId })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+sse_encode_u_64(groupId, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqCreateEndOfTrackConstMeta,
-        argValues: [trackPath, groupId],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateEndOfTrackConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_create_end_of_track",
-        argNames: ["trackPath", "groupId"],
-      );
-
-  @override
-  Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqCreateTrack({
-    required String trackPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 122,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqCreateEndOfTrackConstMeta,
+            argValues: [trackPath, groupId],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateEndOfTrackConstMeta => const TaskConstMeta(
+            debugName: "moq_create_end_of_track",
+            argNames: ["trackPath", "groupId"],
+        );
+        
+
+@override Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqCreateTrack({required String trackPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_track_status,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqCreateTrackConstMeta,
-        argValues: [trackPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateTrackConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_create_track",
-        argNames: ["trackPath"],
-      );
-
-  @override
-  Future<String> crateApiMoqFlutterApiMoqCreateTrackPath({
-    required String namespace,
-    required String trackName,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(namespace, serializer);
-          sse_encode_String(trackName, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 123,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqCreateTrackConstMeta,
+            argValues: [trackPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateTrackConstMeta => const TaskConstMeta(
+            debugName: "moq_create_track",
+            argNames: ["trackPath"],
+        );
+        
+
+@override Future<String> crateApiMoqFlutterApiMoqCreateTrackPath({required String namespace , required String trackName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(namespace, serializer);
+sse_encode_String(trackName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqCreateTrackPathConstMeta,
-        argValues: [namespace, trackName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateTrackPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_create_track_path",
-        argNames: ["namespace", "trackName"],
-      );
-
-  @override
-  Future<FlutterMoqObject?> crateApiMoqFlutterApiMoqDequeueObject() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 124,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqCreateTrackPathConstMeta,
+            argValues: [namespace, trackName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqCreateTrackPathConstMeta => const TaskConstMeta(
+            debugName: "moq_create_track_path",
+            argNames: ["namespace", "trackName"],
+        );
+        
+
+@override Future<FlutterMoqObject?> crateApiMoqFlutterApiMoqDequeueObject()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_opt_box_autoadd_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqDequeueObjectConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqDequeueObjectConstMeta =>
-      const TaskConstMeta(debugName: "moq_dequeue_object", argNames: []);
-
-  @override
-  Future<int> crateApiMoqFlutterApiMoqDropLowPriority({
-    required int threshold,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_8(threshold, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 125,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqDequeueObjectConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqDequeueObjectConstMeta => const TaskConstMeta(
+            debugName: "moq_dequeue_object",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiMoqFlutterApiMoqDropLowPriority({required int threshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_8(threshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqDropLowPriorityConstMeta,
-        argValues: [threshold],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqDropLowPriorityConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_drop_low_priority",
-        argNames: ["threshold"],
-      );
-
-  @override
-  Future<void> crateApiMoqFlutterApiMoqEnqueueObject({
-    required BigInt groupId,
-    required BigInt subgroupId,
-    required BigInt objectId,
-    required List<int> payload,
-    required int publisherPriority,
-    required int subscriberPriority,
-    BigInt? ttlMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(groupId, serializer);
-          sse_encode_u_64(subgroupId, serializer);
-          sse_encode_u_64(objectId, serializer);
-          sse_encode_list_prim_u_8_loose(payload, serializer);
-          sse_encode_u_8(publisherPriority, serializer);
-          sse_encode_u_8(subscriberPriority, serializer);
-          sse_encode_opt_box_autoadd_u_64(ttlMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 126,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqDropLowPriorityConstMeta,
+            argValues: [threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqDropLowPriorityConstMeta => const TaskConstMeta(
+            debugName: "moq_drop_low_priority",
+            argNames: ["threshold"],
+        );
+        
+
+@override Future<void> crateApiMoqFlutterApiMoqEnqueueObject({required BigInt groupId , required BigInt subgroupId , required BigInt objectId , required List<int> payload , required int publisherPriority , required int subscriberPriority , BigInt? ttlMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(groupId, serializer);
+sse_encode_u_64(subgroupId, serializer);
+sse_encode_u_64(objectId, serializer);
+sse_encode_list_prim_u_8_loose(payload, serializer);
+sse_encode_u_8(publisherPriority, serializer);
+sse_encode_u_8(subscriberPriority, serializer);
+sse_encode_opt_box_autoadd_u_64(ttlMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqEnqueueObjectConstMeta,
-        argValues: [
-          groupId,
-          subgroupId,
-          objectId,
-          payload,
-          publisherPriority,
-          subscriberPriority,
-          ttlMs,
-        ],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqEnqueueObjectConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_enqueue_object",
-        argNames: [
-          "groupId",
-          "subgroupId",
-          "objectId",
-          "payload",
-          "publisherPriority",
-          "subscriberPriority",
-          "ttlMs",
-        ],
-      );
-
-  @override
-  Future<int> crateApiMoqFlutterApiMoqEstimateDeliveryTime({
-    required int priority,
-    required int queueLength,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_8(priority, serializer);
-          sse_encode_u_32(queueLength, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 127,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqEnqueueObjectConstMeta,
+            argValues: [groupId, subgroupId, objectId, payload, publisherPriority, subscriberPriority, ttlMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqEnqueueObjectConstMeta => const TaskConstMeta(
+            debugName: "moq_enqueue_object",
+            argNames: ["groupId", "subgroupId", "objectId", "payload", "publisherPriority", "subscriberPriority", "ttlMs"],
+        );
+        
+
+@override Future<int> crateApiMoqFlutterApiMoqEstimateDeliveryTime({required int priority , required int queueLength })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_8(priority, serializer);
+sse_encode_u_32(queueLength, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqEstimateDeliveryTimeConstMeta,
-        argValues: [priority, queueLength],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqEstimateDeliveryTimeConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_estimate_delivery_time",
-        argNames: ["priority", "queueLength"],
-      );
-
-  @override
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqFetch({
-    required String trackPath,
-    required BigInt startGroup,
-    required BigInt startObject,
-    required BigInt endGroup,
-    BigInt? endObject,
-    int? priority,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          sse_encode_u_64(startGroup, serializer);
-          sse_encode_u_64(startObject, serializer);
-          sse_encode_u_64(endGroup, serializer);
-          sse_encode_opt_box_autoadd_u_64(endObject, serializer);
-          sse_encode_opt_box_autoadd_u_8(priority, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 128,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqEstimateDeliveryTimeConstMeta,
+            argValues: [priority, queueLength],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqEstimateDeliveryTimeConstMeta => const TaskConstMeta(
+            debugName: "moq_estimate_delivery_time",
+            argNames: ["priority", "queueLength"],
+        );
+        
+
+@override Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqFetch({required String trackPath , required BigInt startGroup , required BigInt startObject , required BigInt endGroup , BigInt? endObject , int? priority })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+sse_encode_u_64(startGroup, serializer);
+sse_encode_u_64(startObject, serializer);
+sse_encode_u_64(endGroup, serializer);
+sse_encode_opt_box_autoadd_u_64(endObject, serializer);
+sse_encode_opt_box_autoadd_u_8(priority, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqFetchConstMeta,
-        argValues: [
-          trackPath,
-          startGroup,
-          startObject,
-          endGroup,
-          endObject,
-          priority,
-        ],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqFetchConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_fetch",
-        argNames: [
-          "trackPath",
-          "startGroup",
-          "startObject",
-          "endGroup",
-          "endObject",
-          "priority",
-        ],
-      );
-
-  @override
-  Future<List<String>> crateApiMoqFlutterApiMoqGetFilterTypes() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 129,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqFetchConstMeta,
+            argValues: [trackPath, startGroup, startObject, endGroup, endObject, priority],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqFetchConstMeta => const TaskConstMeta(
+            debugName: "moq_fetch",
+            argNames: ["trackPath", "startGroup", "startObject", "endGroup", "endObject", "priority"],
+        );
+        
+
+@override Future<List<String>> crateApiMoqFlutterApiMoqGetFilterTypes()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetFilterTypesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetFilterTypesConstMeta =>
-      const TaskConstMeta(debugName: "moq_get_filter_types", argNames: []);
-
-  @override
-  Future<List<String>> crateApiMoqFlutterApiMoqGetGroupOrders() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 130,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetFilterTypesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetFilterTypesConstMeta => const TaskConstMeta(
+            debugName: "moq_get_filter_types",
+            argNames: [],
+        );
+        
+
+@override Future<List<String>> crateApiMoqFlutterApiMoqGetGroupOrders()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_String,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetGroupOrdersConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetGroupOrdersConstMeta =>
-      const TaskConstMeta(debugName: "moq_get_group_orders", argNames: []);
-
-  @override
-  Future<FlutterNamespaceAnnouncement?> crateApiMoqFlutterApiMoqGetNamespace({
-    required String namespacePath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(namespacePath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 131,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_opt_box_autoadd_flutter_namespace_announcement,
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetGroupOrdersConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetGroupOrdersConstMeta => const TaskConstMeta(
+            debugName: "moq_get_group_orders",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterNamespaceAnnouncement?> crateApiMoqFlutterApiMoqGetNamespace({required String namespacePath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(namespacePath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_flutter_namespace_announcement,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetNamespaceConstMeta,
-        argValues: [namespacePath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetNamespaceConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_get_namespace",
-        argNames: ["namespacePath"],
-      );
-
-  @override
-  Future<(int, int, int)> crateApiMoqFlutterApiMoqGetPriorityInfo() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 132,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetNamespaceConstMeta,
+            argValues: [namespacePath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetNamespaceConstMeta => const TaskConstMeta(
+            debugName: "moq_get_namespace",
+            argNames: ["namespacePath"],
+        );
+        
+
+@override Future<(int,int,int)> crateApiMoqFlutterApiMoqGetPriorityInfo()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_record_u_8_u_8_u_8,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetPriorityInfoConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetPriorityInfoConstMeta =>
-      const TaskConstMeta(debugName: "moq_get_priority_info", argNames: []);
-
-  @override
-  Future<int> crateApiMoqFlutterApiMoqGetQueueLength() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 133,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetPriorityInfoConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetPriorityInfoConstMeta => const TaskConstMeta(
+            debugName: "moq_get_priority_info",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiMoqFlutterApiMoqGetQueueLength()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_32,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetQueueLengthConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetQueueLengthConstMeta =>
-      const TaskConstMeta(debugName: "moq_get_queue_length", argNames: []);
-
-  @override
-  Future<FlutterMoqStats> crateApiMoqFlutterApiMoqGetStats() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 134,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetQueueLengthConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetQueueLengthConstMeta => const TaskConstMeta(
+            debugName: "moq_get_queue_length",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterMoqStats> crateApiMoqFlutterApiMoqGetStats()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_moq_stats,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetStatsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetStatsConstMeta =>
-      const TaskConstMeta(debugName: "moq_get_stats", argNames: []);
-
-  @override
-  Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqGetTrackStatus({
-    required String trackPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 135,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetStatsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetStatsConstMeta => const TaskConstMeta(
+            debugName: "moq_get_stats",
+            argNames: [],
+        );
+        
+
+@override Future<FlutterTrackStatus> crateApiMoqFlutterApiMoqGetTrackStatus({required String trackPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_flutter_track_status,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqGetTrackStatusConstMeta,
-        argValues: [trackPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqGetTrackStatusConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_get_track_status",
-        argNames: ["trackPath"],
-      );
-
-  @override
-  Future<List<FlutterNamespaceAnnouncement>>
-  crateApiMoqFlutterApiMoqListNamespaces() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 136,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqGetTrackStatusConstMeta,
+            argValues: [trackPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqGetTrackStatusConstMeta => const TaskConstMeta(
+            debugName: "moq_get_track_status",
+            argNames: ["trackPath"],
+        );
+        
+
+@override Future<List<FlutterNamespaceAnnouncement>> crateApiMoqFlutterApiMoqListNamespaces()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_namespace_announcement,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqListNamespacesConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqListNamespacesConstMeta =>
-      const TaskConstMeta(debugName: "moq_list_namespaces", argNames: []);
-
-  @override
-  Future<bool> crateApiMoqFlutterApiMoqNamespaceIsPrefix({
-    required String prefix,
-    required String fullPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(prefix, serializer);
-          sse_encode_String(fullPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 137,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqListNamespacesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqListNamespacesConstMeta => const TaskConstMeta(
+            debugName: "moq_list_namespaces",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiMoqFlutterApiMoqNamespaceIsPrefix({required String prefix , required String fullPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(prefix, serializer);
+sse_encode_String(fullPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_bool,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqNamespaceIsPrefixConstMeta,
-        argValues: [prefix, fullPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqNamespaceIsPrefixConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_namespace_is_prefix",
-        argNames: ["prefix", "fullPath"],
-      );
-
-  @override
-  Future<(String, String)> crateApiMoqFlutterApiMoqParseTrackPath({
-    required String trackPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 138,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqNamespaceIsPrefixConstMeta,
+            argValues: [prefix, fullPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqNamespaceIsPrefixConstMeta => const TaskConstMeta(
+            debugName: "moq_namespace_is_prefix",
+            argNames: ["prefix", "fullPath"],
+        );
+        
+
+@override Future<(String,String)> crateApiMoqFlutterApiMoqParseTrackPath({required String trackPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_record_string_string,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqParseTrackPathConstMeta,
-        argValues: [trackPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqParseTrackPathConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_parse_track_path",
-        argNames: ["trackPath"],
-      );
-
-  @override
-  Future<BigInt> crateApiMoqFlutterApiMoqStartGroup({
-    required String trackPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 139,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqParseTrackPathConstMeta,
+            argValues: [trackPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqParseTrackPathConstMeta => const TaskConstMeta(
+            debugName: "moq_parse_track_path",
+            argNames: ["trackPath"],
+        );
+        
+
+@override Future<BigInt> crateApiMoqFlutterApiMoqStartGroup({required String trackPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_u_64,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqStartGroupConstMeta,
-        argValues: [trackPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqStartGroupConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_start_group",
-        argNames: ["trackPath"],
-      );
-
-  @override
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribe({
-    required String trackPath,
-    required FlutterFilterType filter,
-    required FlutterGroupOrder groupOrder,
-    required int subscriberPriority,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          sse_encode_box_autoadd_flutter_filter_type(filter, serializer);
-          sse_encode_flutter_group_order(groupOrder, serializer);
-          sse_encode_u_8(subscriberPriority, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 140,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqStartGroupConstMeta,
+            argValues: [trackPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqStartGroupConstMeta => const TaskConstMeta(
+            debugName: "moq_start_group",
+            argNames: ["trackPath"],
+        );
+        
+
+@override Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribe({required String trackPath , required FlutterFilterType filter , required FlutterGroupOrder groupOrder , required int subscriberPriority })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+sse_encode_box_autoadd_flutter_filter_type(filter, serializer);
+sse_encode_flutter_group_order(groupOrder, serializer);
+sse_encode_u_8(subscriberPriority, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqSubscribeConstMeta,
-        argValues: [trackPath, filter, groupOrder, subscriberPriority],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_subscribe",
-        argNames: ["trackPath", "filter", "groupOrder", "subscriberPriority"],
-      );
-
-  @override
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLatestGroup({
-    required String trackPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 141,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqSubscribeConstMeta,
+            argValues: [trackPath, filter, groupOrder, subscriberPriority],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeConstMeta => const TaskConstMeta(
+            debugName: "moq_subscribe",
+            argNames: ["trackPath", "filter", "groupOrder", "subscriberPriority"],
+        );
+        
+
+@override Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLatestGroup({required String trackPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqSubscribeLatestGroupConstMeta,
-        argValues: [trackPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeLatestGroupConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_subscribe_latest_group",
-        argNames: ["trackPath"],
-      );
-
-  @override
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLive({
-    required String trackPath,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 142,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqSubscribeLatestGroupConstMeta,
+            argValues: [trackPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeLatestGroupConstMeta => const TaskConstMeta(
+            debugName: "moq_subscribe_latest_group",
+            argNames: ["trackPath"],
+        );
+        
+
+@override Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeLive({required String trackPath })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqSubscribeLiveConstMeta,
-        argValues: [trackPath],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeLiveConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_subscribe_live",
-        argNames: ["trackPath"],
-      );
-
-  @override
-  Future<List<FlutterNamespaceAnnouncement>>
-  crateApiMoqFlutterApiMoqSubscribeNamespace({
-    required String namespacePrefix,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(namespacePrefix, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 143,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqSubscribeLiveConstMeta,
+            argValues: [trackPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeLiveConstMeta => const TaskConstMeta(
+            debugName: "moq_subscribe_live",
+            argNames: ["trackPath"],
+        );
+        
+
+@override Future<List<FlutterNamespaceAnnouncement>> crateApiMoqFlutterApiMoqSubscribeNamespace({required String namespacePrefix })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(namespacePrefix, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_namespace_announcement,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqSubscribeNamespaceConstMeta,
-        argValues: [namespacePrefix],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeNamespaceConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_subscribe_namespace",
-        argNames: ["namespacePrefix"],
-      );
-
-  @override
-  Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeRange({
-    required String trackPath,
-    required BigInt startGroup,
-    required BigInt startObject,
-    required BigInt endGroup,
-    BigInt? endObject,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(trackPath, serializer);
-          sse_encode_u_64(startGroup, serializer);
-          sse_encode_u_64(startObject, serializer);
-          sse_encode_u_64(endGroup, serializer);
-          sse_encode_opt_box_autoadd_u_64(endObject, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 144,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqSubscribeNamespaceConstMeta,
+            argValues: [namespacePrefix],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeNamespaceConstMeta => const TaskConstMeta(
+            debugName: "moq_subscribe_namespace",
+            argNames: ["namespacePrefix"],
+        );
+        
+
+@override Future<List<FlutterMoqObject>> crateApiMoqFlutterApiMoqSubscribeRange({required String trackPath , required BigInt startGroup , required BigInt startObject , required BigInt endGroup , BigInt? endObject })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(trackPath, serializer);
+sse_encode_u_64(startGroup, serializer);
+sse_encode_u_64(startObject, serializer);
+sse_encode_u_64(endGroup, serializer);
+sse_encode_opt_box_autoadd_u_64(endObject, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_moq_object,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiMoqFlutterApiMoqSubscribeRangeConstMeta,
-        argValues: [trackPath, startGroup, startObject, endGroup, endObject],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeRangeConstMeta =>
-      const TaskConstMeta(
-        debugName: "moq_subscribe_range",
-        argNames: [
-          "trackPath",
-          "startGroup",
-          "startObject",
-          "endGroup",
-          "endObject",
-        ],
-      );
-
-  @override
-  Future<(String, String)> crateApiLiveFlutterApiParseLiveTicket({
-    required String ticketStr,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(ticketStr, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 145,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiMoqFlutterApiMoqSubscribeRangeConstMeta,
+            argValues: [trackPath, startGroup, startObject, endGroup, endObject],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMoqFlutterApiMoqSubscribeRangeConstMeta => const TaskConstMeta(
+            debugName: "moq_subscribe_range",
+            argNames: ["trackPath", "startGroup", "startObject", "endGroup", "endObject"],
+        );
+        
+
+@override Future<(String,String)> crateApiLiveFlutterApiParseLiveTicket({required String ticketStr })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(ticketStr, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_record_string_string,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiParseLiveTicketConstMeta,
-        argValues: [ticketStr],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiParseLiveTicketConstMeta =>
-      const TaskConstMeta(
-        debugName: "parse_live_ticket",
-        argNames: ["ticketStr"],
-      );
-
-  @override
-  Future<List<FlutterDirectEvent>> crateApiDirectFlutterApiPollDirectEvents() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 146,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiParseLiveTicketConstMeta,
+            argValues: [ticketStr],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiParseLiveTicketConstMeta => const TaskConstMeta(
+            debugName: "parse_live_ticket",
+            argNames: ["ticketStr"],
+        );
+        
+
+@override Future<List<FlutterDirectEvent>> crateApiDirectFlutterApiPollDirectEvents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_direct_event,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiDirectFlutterApiPollDirectEventsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiPollDirectEventsConstMeta =>
-      const TaskConstMeta(debugName: "poll_direct_events", argNames: []);
-
-  @override
-  Future<List<FlutterStreamEvent>> crateApiFlutterApiPollEvents() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 147,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiPollDirectEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiPollDirectEventsConstMeta => const TaskConstMeta(
+            debugName: "poll_direct_events",
+            argNames: [],
+        );
+        
+
+@override Future<List<FlutterStreamEvent>> crateApiFlutterApiPollEvents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_stream_event,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiFlutterApiPollEventsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiPollEventsConstMeta =>
-      const TaskConstMeta(debugName: "poll_events", argNames: []);
-
-  @override
-  Future<List<FlutterLiveEvent>> crateApiLiveFlutterApiPollLiveEvents() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 148,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiPollEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiPollEventsConstMeta => const TaskConstMeta(
+            debugName: "poll_events",
+            argNames: [],
+        );
+        
+
+@override Future<List<FlutterLiveEvent>> crateApiLiveFlutterApiPollLiveEvents()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_list_flutter_live_event,
           decodeErrorData: null,
-        ),
-        constMeta: kCrateApiLiveFlutterApiPollLiveEventsConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiPollLiveEventsConstMeta =>
-      const TaskConstMeta(debugName: "poll_live_events", argNames: []);
-
-  @override
-  Future<void> crateApiLiveFlutterApiRequestLiveCatalog() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 149,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiPollLiveEventsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiPollLiveEventsConstMeta => const TaskConstMeta(
+            debugName: "poll_live_events",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiRequestLiveCatalog()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiRequestLiveCatalogConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiRequestLiveCatalogConstMeta =>
-      const TaskConstMeta(debugName: "request_live_catalog", argNames: []);
-
-  @override
-  Future<void> crateApiFlutterApiSendPresence() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 150,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiRequestLiveCatalogConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiRequestLiveCatalogConstMeta => const TaskConstMeta(
+            debugName: "request_live_catalog",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFlutterApiSendPresence()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiSendPresenceConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiSendPresenceConstMeta =>
-      const TaskConstMeta(debugName: "send_presence", argNames: []);
-
-  @override
-  Future<void> crateApiFlutterApiSendSignal({required List<int> data}) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_8_loose(data, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 151,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiSendPresenceConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiSendPresenceConstMeta => const TaskConstMeta(
+            debugName: "send_presence",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFlutterApiSendSignal({required List<int> data })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_8_loose(data, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiSendSignalConstMeta,
-        argValues: [data],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiSendSignalConstMeta =>
-      const TaskConstMeta(debugName: "send_signal", argNames: ["data"]);
-
-  @override
-  Future<void> crateApiLiveFlutterApiSetVideoQuality({
-    required FlutterVideoQuality quality,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_flutter_video_quality(quality, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 152,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiSendSignalConstMeta,
+            argValues: [data],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiSendSignalConstMeta => const TaskConstMeta(
+            debugName: "send_signal",
+            argNames: ["data"],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiSetVideoQuality({required FlutterVideoQuality quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_flutter_video_quality(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiSetVideoQualityConstMeta,
-        argValues: [quality],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiSetVideoQualityConstMeta =>
-      const TaskConstMeta(
-        debugName: "set_video_quality",
-        argNames: ["quality"],
-      );
-
-  @override
-  Future<void> crateApiDirectFlutterApiShutdownDirectStreaming() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 153,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiSetVideoQualityConstMeta,
+            argValues: [quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiSetVideoQualityConstMeta => const TaskConstMeta(
+            debugName: "set_video_quality",
+            argNames: ["quality"],
+        );
+        
+
+@override Future<void> crateApiDirectFlutterApiShutdownDirectStreaming()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiDirectFlutterApiShutdownDirectStreamingConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiDirectFlutterApiShutdownDirectStreamingConstMeta =>
-      const TaskConstMeta(debugName: "shutdown_direct_streaming", argNames: []);
-
-  @override
-  Future<void> crateApiFlutterApiShutdownStreaming() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 154,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiDirectFlutterApiShutdownDirectStreamingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiDirectFlutterApiShutdownDirectStreamingConstMeta => const TaskConstMeta(
+            debugName: "shutdown_direct_streaming",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiFlutterApiShutdownStreaming()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiFlutterApiShutdownStreamingConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiFlutterApiShutdownStreamingConstMeta =>
-      const TaskConstMeta(debugName: "shutdown_streaming", argNames: []);
-
-  @override
-  Future<void> crateApiLiveFlutterApiStopLiveBroadcast() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 155,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
+        )
+        ,
+            constMeta: kCrateApiFlutterApiShutdownStreamingConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiFlutterApiShutdownStreamingConstMeta => const TaskConstMeta(
+            debugName: "shutdown_streaming",
+            argNames: [],
+        );
+        
+
+@override Future<void> crateApiLiveFlutterApiStopLiveBroadcast()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
           decodeSuccessData: sse_decode_unit,
           decodeErrorData: sse_decode_String,
-        ),
-        constMeta: kCrateApiLiveFlutterApiStopLiveBroadcastConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiLiveFlutterApiStopLiveBroadcastConstMeta =>
-      const TaskConstMeta(debugName: "stop_live_broadcast", argNames: []);
-
-  @protected
-  Map<String, String> dco_decode_Map_String_String_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_string(
-        raw,
-      ).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  Map<String, bool> dco_decode_Map_String_bool_None(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Map.fromEntries(
-      dco_decode_list_record_string_bool(raw).map((e) => MapEntry(e.$1, e.$2)),
-    );
-  }
-
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  double dco_decode_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  FlutterAudioSamples dco_decode_box_autoadd_flutter_audio_samples(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_audio_samples(raw);
-  }
-
-  @protected
-  FlutterBroadcastCatalog dco_decode_box_autoadd_flutter_broadcast_catalog(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_broadcast_catalog(raw);
-  }
-
-  @protected
-  FlutterCatalog dco_decode_box_autoadd_flutter_catalog(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_catalog(raw);
-  }
-
-  @protected
-  FlutterConnectionStats dco_decode_box_autoadd_flutter_connection_stats(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_connection_stats(raw);
-  }
-
-  @protected
-  FlutterEncodedAudioPacket dco_decode_box_autoadd_flutter_encoded_audio_packet(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_encoded_audio_packet(raw);
-  }
-
-  @protected
-  FlutterEncodedVideoPacket dco_decode_box_autoadd_flutter_encoded_video_packet(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_encoded_video_packet(raw);
-  }
-
-  @protected
-  FlutterFilterType dco_decode_box_autoadd_flutter_filter_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_filter_type(raw);
-  }
-
-  @protected
-  FlutterMoqObject dco_decode_box_autoadd_flutter_moq_object(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_moq_object(raw);
-  }
-
-  @protected
-  FlutterNamespaceAnnouncement
-  dco_decode_box_autoadd_flutter_namespace_announcement(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_namespace_announcement(raw);
-  }
-
-  @protected
-  FlutterPublisherStatus dco_decode_box_autoadd_flutter_publisher_status(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_publisher_status(raw);
-  }
-
-  @protected
-  FlutterReceivedVideoFrame dco_decode_box_autoadd_flutter_received_video_frame(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_received_video_frame(raw);
-  }
-
-  @protected
-  FlutterSubscriberStatus dco_decode_box_autoadd_flutter_subscriber_status(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_subscriber_status(raw);
-  }
-
-  @protected
-  FlutterTicketInfo dco_decode_box_autoadd_flutter_ticket_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_ticket_info(raw);
-  }
-
-  @protected
-  FlutterVideoFrame dco_decode_box_autoadd_flutter_video_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_flutter_video_frame(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
-  }
-
-  @protected
-  int dco_decode_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  double dco_decode_f_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  double dco_decode_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  FlutterAudioCodec dco_decode_flutter_audio_codec(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterAudioCodec.values[raw as int];
-  }
-
-  @protected
-  FlutterAudioRendition dco_decode_flutter_audio_rendition(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterAudioRendition(
-      name: dco_decode_String(arr[0]),
-      sampleRate: dco_decode_u_32(arr[1]),
-      channels: dco_decode_u_16(arr[2]),
-      bitrate: dco_decode_u_32(arr[3]),
-      codec: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterAudioSamples dco_decode_flutter_audio_samples(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterAudioSamples(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      sampleRate: dco_decode_u_32(arr[1]),
-      channels: dco_decode_u_16(arr[2]),
-      timestampMs: dco_decode_u_64(arr[3]),
-      format: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterBroadcastCatalog dco_decode_flutter_broadcast_catalog(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return FlutterBroadcastCatalog(
-      broadcastId: dco_decode_String(arr[0]),
-      videoTracks: dco_decode_list_flutter_track_info(arr[1]),
-      audioTracks: dco_decode_list_flutter_track_info(arr[2]),
-      createdAtMs: dco_decode_u_64(arr[3]),
-    );
-  }
-
-  @protected
-  FlutterCaptureDevice dco_decode_flutter_capture_device(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return FlutterCaptureDevice(
-      id: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      deviceType: dco_decode_String(arr[2]),
-      isDefault: dco_decode_bool(arr[3]),
-    );
-  }
-
-  @protected
-  FlutterCatalog dco_decode_flutter_catalog(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterCatalog(
-      name: dco_decode_String(arr[0]),
-      description: dco_decode_opt_String(arr[1]),
-      videoTracks: dco_decode_list_flutter_video_track(arr[2]),
-      isLive: dco_decode_bool(arr[3]),
-      durationSecs: dco_decode_opt_box_autoadd_f_64(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterConnectionStats dco_decode_flutter_connection_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 8)
-      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
-    return FlutterConnectionStats(
-      bytesSent: dco_decode_u_64(arr[0]),
-      bytesReceived: dco_decode_u_64(arr[1]),
-      chunksSent: dco_decode_u_64(arr[2]),
-      chunksReceived: dco_decode_u_64(arr[3]),
-      bandwidthBps: dco_decode_u_64(arr[4]),
-      rttMs: dco_decode_u_64(arr[5]),
-      qualityScore: dco_decode_u_32(arr[6]),
-      recommendedQuality: dco_decode_flutter_video_quality(arr[7]),
-    );
-  }
-
-  @protected
-  FlutterDecodedAudioFrame dco_decode_flutter_decoded_audio_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return FlutterDecodedAudioFrame(
-      samples: dco_decode_list_prim_i_16_strict(arr[0]),
-      ptsUs: dco_decode_i_64(arr[1]),
-      sampleRate: dco_decode_u_32(arr[2]),
-      channels: dco_decode_u_32(arr[3]),
-    );
-  }
-
-  @protected
-  FlutterDecodedVideoFrame dco_decode_flutter_decoded_video_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterDecodedVideoFrame(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      width: dco_decode_u_32(arr[1]),
-      height: dco_decode_u_32(arr[2]),
-      ptsUs: dco_decode_i_64(arr[3]),
-      isKeyframe: dco_decode_bool(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterDirectEvent dco_decode_flutter_direct_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return FlutterDirectEvent_PeerConnected(
-          endpointId: dco_decode_String(raw[1]),
-        );
-      case 1:
-        return FlutterDirectEvent_PeerDisconnected(
-          endpointId: dco_decode_String(raw[1]),
-        );
-      case 2:
-        return FlutterDirectEvent_RequestMetadata(
-          from: dco_decode_String(raw[1]),
-          timestamp: dco_decode_u_64(raw[2]),
-        );
-      case 3:
-        return FlutterDirectEvent_Metadata(
-          from: dco_decode_String(raw[1]),
-          fileName: dco_decode_String(raw[2]),
-          fileSize: dco_decode_u_64(raw[3]),
-          mimeType: dco_decode_String(raw[4]),
-          totalChunks: dco_decode_u_32(raw[5]),
-          duration: dco_decode_opt_box_autoadd_f_64(raw[6]),
-          timestamp: dco_decode_u_64(raw[7]),
-        );
-      case 4:
-        return FlutterDirectEvent_RequestChunk(
-          from: dco_decode_String(raw[1]),
-          index: dco_decode_u_32(raw[2]),
-          timestamp: dco_decode_u_64(raw[3]),
-        );
-      case 5:
-        return FlutterDirectEvent_Chunk(
-          from: dco_decode_String(raw[1]),
-          index: dco_decode_u_32(raw[2]),
-          data: dco_decode_list_prim_u_8_strict(raw[3]),
-          timestamp: dco_decode_u_64(raw[4]),
-        );
-      case 6:
-        return FlutterDirectEvent_Presence(
-          from: dco_decode_String(raw[1]),
-          name: dco_decode_String(raw[2]),
-          timestamp: dco_decode_u_64(raw[3]),
-        );
-      case 7:
-        return FlutterDirectEvent_Signal(
-          from: dco_decode_String(raw[1]),
-          data: dco_decode_list_prim_u_8_strict(raw[2]),
-          timestamp: dco_decode_u_64(raw[3]),
-        );
-      case 8:
-        return FlutterDirectEvent_Error(message: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  FlutterEncodedAudioFrame dco_decode_flutter_encoded_audio_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterEncodedAudioFrame(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      ptsUs: dco_decode_i_64(arr[1]),
-      durationUs: dco_decode_i_64(arr[2]),
-      samples: dco_decode_u_32(arr[3]),
-      codec: dco_decode_flutter_audio_codec(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterEncodedAudioPacket dco_decode_flutter_encoded_audio_packet(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterEncodedAudioPacket(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      timestampMs: dco_decode_u_64(arr[1]),
-      codec: dco_decode_String(arr[2]),
-      sampleRate: dco_decode_u_32(arr[3]),
-      channels: dco_decode_u_16(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterEncodedVideoFrame dco_decode_flutter_encoded_video_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return FlutterEncodedVideoFrame(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      ptsUs: dco_decode_i_64(arr[1]),
-      dtsUs: dco_decode_i_64(arr[2]),
-      isKeyframe: dco_decode_bool(arr[3]),
-      durationUs: dco_decode_i_64(arr[4]),
-      frameIndex: dco_decode_u_64(arr[5]),
-      codec: dco_decode_flutter_video_codec(arr[6]),
-      moqPriority: dco_decode_u_8(arr[7]),
-      moqTtlMs: dco_decode_u_64(arr[8]),
-    );
-  }
-
-  @protected
-  FlutterEncodedVideoPacket dco_decode_flutter_encoded_video_packet(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return FlutterEncodedVideoPacket(
-      data: dco_decode_list_prim_u_8_strict(arr[0]),
-      timestampMs: dco_decode_u_64(arr[1]),
-      isKeyframe: dco_decode_bool(arr[2]),
-      codec: dco_decode_String(arr[3]),
-      width: dco_decode_u_32(arr[4]),
-      height: dco_decode_u_32(arr[5]),
-    );
-  }
-
-  @protected
-  FlutterEncoderPreset dco_decode_flutter_encoder_preset(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterEncoderPreset.values[raw as int];
-  }
-
-  @protected
-  FlutterFilterType dco_decode_flutter_filter_type(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return FlutterFilterType_LatestGroup();
-      case 1:
-        return FlutterFilterType_LatestObject();
-      case 2:
-        return FlutterFilterType_NextGroup();
-      case 3:
-        return FlutterFilterType_AbsoluteStart(
-          startGroup: dco_decode_u_64(raw[1]),
-          startObject: dco_decode_u_64(raw[2]),
-        );
-      case 4:
-        return FlutterFilterType_AbsoluteRange(
-          startGroup: dco_decode_u_64(raw[1]),
-          startObject: dco_decode_u_64(raw[2]),
-          endGroup: dco_decode_u_64(raw[3]),
-          endObject: dco_decode_opt_box_autoadd_u_64(raw[4]),
-        );
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  FlutterGroupOrder dco_decode_flutter_group_order(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterGroupOrder.values[raw as int];
-  }
-
-  @protected
-  FlutterHardwareAccel dco_decode_flutter_hardware_accel(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterHardwareAccel.values[raw as int];
-  }
-
-  @protected
-  FlutterLiveEvent dco_decode_flutter_live_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return FlutterLiveEvent_PeerConnected(
-          peerId: dco_decode_String(raw[1]),
-        );
-      case 1:
-        return FlutterLiveEvent_PeerDisconnected(
-          peerId: dco_decode_String(raw[1]),
-        );
-      case 2:
-        return FlutterLiveEvent_CatalogReceived(
-          catalog: dco_decode_box_autoadd_flutter_catalog(raw[1]),
-        );
-      case 3:
-        return FlutterLiveEvent_MetadataReceived(
-          from: dco_decode_String(raw[1]),
-          fileName: dco_decode_String(raw[2]),
-          fileSize: dco_decode_u_64(raw[3]),
-          mimeType: dco_decode_String(raw[4]),
-          totalChunks: dco_decode_u_32(raw[5]),
-          duration: dco_decode_opt_box_autoadd_f_64(raw[6]),
-        );
-      case 4:
-        return FlutterLiveEvent_ChunkRequested(
-          from: dco_decode_String(raw[1]),
-          index: dco_decode_u_32(raw[2]),
-        );
-      case 5:
-        return FlutterLiveEvent_ChunkReceived(
-          from: dco_decode_String(raw[1]),
-          index: dco_decode_u_32(raw[2]),
-          data: dco_decode_list_prim_u_8_strict(raw[3]),
-        );
-      case 6:
-        return FlutterLiveEvent_MetadataRequested(
-          from: dco_decode_String(raw[1]),
-        );
-      case 7:
-        return FlutterLiveEvent_StatsUpdated(
-          stats: dco_decode_box_autoadd_flutter_connection_stats(raw[1]),
-        );
-      case 8:
-        return FlutterLiveEvent_Error(message: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  FlutterMoqObject dco_decode_flutter_moq_object(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 9)
-      throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
-    return FlutterMoqObject(
-      groupId: dco_decode_u_64(arr[0]),
-      subgroupId: dco_decode_u_64(arr[1]),
-      objectId: dco_decode_u_64(arr[2]),
-      publisherPriority: dco_decode_u_8(arr[3]),
-      expiresAt: dco_decode_opt_box_autoadd_u_64(arr[4]),
-      status: dco_decode_flutter_object_status(arr[5]),
-      payload: dco_decode_list_prim_u_8_strict(arr[6]),
-      createdAt: dco_decode_u_64(arr[7]),
-      isExpired: dco_decode_bool(arr[8]),
-    );
-  }
-
-  @protected
-  FlutterMoqStats dco_decode_flutter_moq_stats(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return FlutterMoqStats(
-      schedulerQueueLength: dco_decode_u_32(arr[0]),
-      namespaceCount: dco_decode_u_32(arr[1]),
-    );
-  }
-
-  @protected
-  FlutterNamespaceAnnouncement dco_decode_flutter_namespace_announcement(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FlutterNamespaceAnnouncement(
-      namespacePath: dco_decode_String(arr[0]),
-      tracks: dco_decode_list_String(arr[1]),
-      acceptsPublishing: dco_decode_bool(arr[2]),
-    );
-  }
-
-  @protected
-  FlutterObjectStatus dco_decode_flutter_object_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterObjectStatus.values[raw as int];
-  }
-
-  @protected
-  FlutterPublisherStatus dco_decode_flutter_publisher_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return FlutterPublisherStatus(
-      publisherId: dco_decode_String(arr[0]),
-      isActive: dco_decode_bool(arr[1]),
-      framesPublished: dco_decode_u_64(arr[2]),
-      bytesSent: dco_decode_u_64(arr[3]),
-      currentBitrate: dco_decode_u_32(arr[4]),
-      videoRenditions: dco_decode_list_String(arr[5]),
-      audioRenditions: dco_decode_list_String(arr[6]),
-    );
-  }
-
-  @protected
-  FlutterReceivedVideoFrame dco_decode_flutter_received_video_frame(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterReceivedVideoFrame(
-      timestampMs: dco_decode_u_64(arr[0]),
-      width: dco_decode_i_32(arr[1]),
-      height: dco_decode_i_32(arr[2]),
-      isKeyframe: dco_decode_bool(arr[3]),
-      data: dco_decode_list_prim_u_8_strict(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterStreamEvent dco_decode_flutter_stream_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    switch (raw[0]) {
-      case 0:
-        return FlutterStreamEvent_NeighborUp(
-          endpointId: dco_decode_String(raw[1]),
-        );
-      case 1:
-        return FlutterStreamEvent_NeighborDown(
-          endpointId: dco_decode_String(raw[1]),
-        );
-      case 2:
-        return FlutterStreamEvent_Presence(
-          from: dco_decode_String(raw[1]),
-          name: dco_decode_String(raw[2]),
-          timestamp: dco_decode_u_64(raw[3]),
-        );
-      case 3:
-        return FlutterStreamEvent_MediaChunk(
-          from: dco_decode_String(raw[1]),
-          data: dco_decode_list_prim_u_8_strict(raw[2]),
-          sequence: dco_decode_u_64(raw[3]),
-          timestamp: dco_decode_u_64(raw[4]),
-        );
-      case 4:
-        return FlutterStreamEvent_Signal(
-          from: dco_decode_String(raw[1]),
-          data: dco_decode_list_prim_u_8_strict(raw[2]),
-          timestamp: dco_decode_u_64(raw[3]),
-        );
-      case 5:
-        return FlutterStreamEvent_Lagged();
-      case 6:
-        return FlutterStreamEvent_Error(message: dco_decode_String(raw[1]));
-      default:
-        throw Exception("unreachable");
-    }
-  }
-
-  @protected
-  FlutterSubscriberStatus dco_decode_flutter_subscriber_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return FlutterSubscriberStatus(
-      subscriberId: dco_decode_String(arr[0]),
-      broadcastId: dco_decode_String(arr[1]),
-      isConnected: dco_decode_bool(arr[2]),
-      framesReceived: dco_decode_u_64(arr[3]),
-      bytesReceived: dco_decode_u_64(arr[4]),
-      currentQuality: dco_decode_String(arr[5]),
-      bufferHealth: dco_decode_f_32(arr[6]),
-    );
-  }
-
-  @protected
-  FlutterTicketInfo dco_decode_flutter_ticket_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3)
-      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
-    return FlutterTicketInfo(
-      broadcastName: dco_decode_String(arr[0]),
-      endpointId: dco_decode_String(arr[1]),
-      ticketString: dco_decode_String(arr[2]),
-    );
-  }
-
-  @protected
-  FlutterTrackInfo dco_decode_flutter_track_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterTrackInfo(
-      trackId: dco_decode_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      codec: dco_decode_String(arr[2]),
-      bitrate: dco_decode_u_32(arr[3]),
-      extra: dco_decode_Map_String_String_None(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterTrackStatus dco_decode_flutter_track_status(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return FlutterTrackStatus(
-      trackPath: dco_decode_String(arr[0]),
-      status: dco_decode_flutter_track_status_code(arr[1]),
-      latestGroupId: dco_decode_opt_box_autoadd_u_64(arr[2]),
-      latestObjectId: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      groupOrder: dco_decode_flutter_group_order(arr[4]),
-      publisherPriority: dco_decode_u_8(arr[5]),
-    );
-  }
-
-  @protected
-  FlutterTrackStatusCode dco_decode_flutter_track_status_code(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterTrackStatusCode.values[raw as int];
-  }
-
-  @protected
-  FlutterVideoCodec dco_decode_flutter_video_codec(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterVideoCodec.values[raw as int];
-  }
-
-  @protected
-  FlutterVideoFrame dco_decode_flutter_video_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return FlutterVideoFrame(
-      width: dco_decode_u_32(arr[0]),
-      height: dco_decode_u_32(arr[1]),
-      data: dco_decode_list_prim_u_8_strict(arr[2]),
-      timestampMs: dco_decode_u_64(arr[3]),
-      format: dco_decode_String(arr[4]),
-    );
-  }
-
-  @protected
-  FlutterVideoQuality dco_decode_flutter_video_quality(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterVideoQuality.values[raw as int];
-  }
-
-  @protected
-  FlutterVideoQualityFfmpeg dco_decode_flutter_video_quality_ffmpeg(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return FlutterVideoQualityFfmpeg.values[raw as int];
-  }
-
-  @protected
-  FlutterVideoRendition dco_decode_flutter_video_rendition(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return FlutterVideoRendition(
-      name: dco_decode_String(arr[0]),
-      width: dco_decode_u_32(arr[1]),
-      height: dco_decode_u_32(arr[2]),
-      fps: dco_decode_u_32(arr[3]),
-      bitrate: dco_decode_u_32(arr[4]),
-      codec: dco_decode_String(arr[5]),
-    );
-  }
-
-  @protected
-  FlutterVideoTrack dco_decode_flutter_video_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return FlutterVideoTrack(
-      name: dco_decode_String(arr[0]),
-      quality: dco_decode_flutter_video_quality(arr[1]),
-      codec: dco_decode_String(arr[2]),
-      width: dco_decode_u_32(arr[3]),
-      height: dco_decode_u_32(arr[4]),
-      fps: dco_decode_u_32(arr[5]),
-      bitrateKbps: dco_decode_u_32(arr[6]),
-    );
-  }
-
-  @protected
-  int dco_decode_i_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_i_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  PlatformInt64 dco_decode_i_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeI64(raw);
-  }
-
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
-  }
-
-  @protected
-  List<FlutterAudioRendition> dco_decode_list_flutter_audio_rendition(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_flutter_audio_rendition)
-        .toList();
-  }
-
-  @protected
-  List<FlutterCaptureDevice> dco_decode_list_flutter_capture_device(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_flutter_capture_device)
-        .toList();
-  }
-
-  @protected
-  List<FlutterDirectEvent> dco_decode_list_flutter_direct_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_flutter_direct_event).toList();
-  }
-
-  @protected
-  List<FlutterEncodedVideoFrame> dco_decode_list_flutter_encoded_video_frame(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_flutter_encoded_video_frame)
-        .toList();
-  }
-
-  @protected
-  List<FlutterLiveEvent> dco_decode_list_flutter_live_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_flutter_live_event).toList();
-  }
-
-  @protected
-  List<FlutterMoqObject> dco_decode_list_flutter_moq_object(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_flutter_moq_object).toList();
-  }
-
-  @protected
-  List<FlutterNamespaceAnnouncement>
-  dco_decode_list_flutter_namespace_announcement(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_flutter_namespace_announcement)
-        .toList();
-  }
-
-  @protected
-  List<FlutterStreamEvent> dco_decode_list_flutter_stream_event(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_flutter_stream_event).toList();
-  }
-
-  @protected
-  List<FlutterTrackInfo> dco_decode_list_flutter_track_info(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_flutter_track_info).toList();
-  }
-
-  @protected
-  List<FlutterVideoQuality> dco_decode_list_flutter_video_quality(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_flutter_video_quality)
-        .toList();
-  }
-
-  @protected
-  List<FlutterVideoRendition> dco_decode_list_flutter_video_rendition(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>)
-        .map(dco_decode_flutter_video_rendition)
-        .toList();
-  }
-
-  @protected
-  List<FlutterVideoTrack> dco_decode_list_flutter_video_track(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_flutter_video_track).toList();
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_i_16_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Int16List dco_decode_list_prim_i_16_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Int16List;
-  }
-
-  @protected
-  List<int> dco_decode_list_prim_u_8_loose(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as List<int>;
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<(String, bool)> dco_decode_list_record_string_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_bool).toList();
-  }
-
-  @protected
-  List<(String, String)> dco_decode_list_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_record_string_string).toList();
-  }
-
-  @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
-  }
-
-  @protected
-  FlutterMoqObject? dco_decode_opt_box_autoadd_flutter_moq_object(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_flutter_moq_object(raw);
-  }
-
-  @protected
-  FlutterNamespaceAnnouncement?
-  dco_decode_opt_box_autoadd_flutter_namespace_announcement(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_flutter_namespace_announcement(raw);
-  }
-
-  @protected
-  FlutterPublisherStatus? dco_decode_opt_box_autoadd_flutter_publisher_status(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_flutter_publisher_status(raw);
-  }
-
-  @protected
-  FlutterReceivedVideoFrame?
-  dco_decode_opt_box_autoadd_flutter_received_video_frame(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_flutter_received_video_frame(raw);
-  }
-
-  @protected
-  FlutterSubscriberStatus? dco_decode_opt_box_autoadd_flutter_subscriber_status(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null
-        ? null
-        : dco_decode_box_autoadd_flutter_subscriber_status(raw);
-  }
-
-  @protected
-  FlutterTicketInfo? dco_decode_opt_box_autoadd_flutter_ticket_info(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_flutter_ticket_info(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  int? dco_decode_opt_box_autoadd_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_8(raw);
-  }
-
-  @protected
-  Quality dco_decode_quality(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return Quality.values[raw as int];
-  }
-
-  @protected
-  QualityConstraints dco_decode_quality_constraints(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return QualityConstraints(
-      width: dco_decode_u_32(arr[0]),
-      height: dco_decode_u_32(arr[1]),
-      framerate: dco_decode_u_32(arr[2]),
-      audioBitrate: dco_decode_u_32(arr[3]),
-    );
-  }
-
-  @protected
-  (String, bool) dco_decode_record_string_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_bool(arr[1]));
-  }
-
-  @protected
-  (String, String) dco_decode_record_string_string(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_String(arr[0]), dco_decode_String(arr[1]));
-  }
-
-  @protected
-  (int, int) dco_decode_record_u_32_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (dco_decode_u_32(arr[0]), dco_decode_u_32(arr[1]));
-  }
-
-  @protected
-  (int, int, int) dco_decode_record_u_8_u_8_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 3) {
-      throw Exception('Expected 3 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_u_8(arr[0]),
-      dco_decode_u_8(arr[1]),
-      dco_decode_u_8(arr[2]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  int dco_decode_u_32(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  Map<String, String> sse_decode_Map_String_String_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_string(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  Map<String, bool> sse_decode_Map_String_bool_None(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_record_string_bool(deserializer);
-    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
-  }
-
-  @protected
-  String sse_decode_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  double sse_decode_box_autoadd_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
-  }
-
-  @protected
-  FlutterAudioSamples sse_decode_box_autoadd_flutter_audio_samples(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_audio_samples(deserializer));
-  }
-
-  @protected
-  FlutterBroadcastCatalog sse_decode_box_autoadd_flutter_broadcast_catalog(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_broadcast_catalog(deserializer));
-  }
-
-  @protected
-  FlutterCatalog sse_decode_box_autoadd_flutter_catalog(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_catalog(deserializer));
-  }
-
-  @protected
-  FlutterConnectionStats sse_decode_box_autoadd_flutter_connection_stats(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_connection_stats(deserializer));
-  }
-
-  @protected
-  FlutterEncodedAudioPacket sse_decode_box_autoadd_flutter_encoded_audio_packet(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_encoded_audio_packet(deserializer));
-  }
-
-  @protected
-  FlutterEncodedVideoPacket sse_decode_box_autoadd_flutter_encoded_video_packet(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_encoded_video_packet(deserializer));
-  }
-
-  @protected
-  FlutterFilterType sse_decode_box_autoadd_flutter_filter_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_filter_type(deserializer));
-  }
-
-  @protected
-  FlutterMoqObject sse_decode_box_autoadd_flutter_moq_object(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_moq_object(deserializer));
-  }
-
-  @protected
-  FlutterNamespaceAnnouncement
-  sse_decode_box_autoadd_flutter_namespace_announcement(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_namespace_announcement(deserializer));
-  }
-
-  @protected
-  FlutterPublisherStatus sse_decode_box_autoadd_flutter_publisher_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_publisher_status(deserializer));
-  }
-
-  @protected
-  FlutterReceivedVideoFrame sse_decode_box_autoadd_flutter_received_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_received_video_frame(deserializer));
-  }
-
-  @protected
-  FlutterSubscriberStatus sse_decode_box_autoadd_flutter_subscriber_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_subscriber_status(deserializer));
-  }
-
-  @protected
-  FlutterTicketInfo sse_decode_box_autoadd_flutter_ticket_info(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_ticket_info(deserializer));
-  }
-
-  @protected
-  FlutterVideoFrame sse_decode_box_autoadd_flutter_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_flutter_video_frame(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_32(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
-  }
-
-  @protected
-  int sse_decode_box_autoadd_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_8(deserializer));
-  }
-
-  @protected
-  double sse_decode_f_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  double sse_decode_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  FlutterAudioCodec sse_decode_flutter_audio_codec(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterAudioCodec.values[inner];
-  }
-
-  @protected
-  FlutterAudioRendition sse_decode_flutter_audio_rendition(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_channels = sse_decode_u_16(deserializer);
-    var var_bitrate = sse_decode_u_32(deserializer);
-    var var_codec = sse_decode_String(deserializer);
-    return FlutterAudioRendition(
-      name: var_name,
-      sampleRate: var_sampleRate,
-      channels: var_channels,
-      bitrate: var_bitrate,
-      codec: var_codec,
-    );
-  }
-
-  @protected
-  FlutterAudioSamples sse_decode_flutter_audio_samples(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_channels = sse_decode_u_16(deserializer);
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_format = sse_decode_String(deserializer);
-    return FlutterAudioSamples(
-      data: var_data,
-      sampleRate: var_sampleRate,
-      channels: var_channels,
-      timestampMs: var_timestampMs,
-      format: var_format,
-    );
-  }
-
-  @protected
-  FlutterBroadcastCatalog sse_decode_flutter_broadcast_catalog(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_broadcastId = sse_decode_String(deserializer);
-    var var_videoTracks = sse_decode_list_flutter_track_info(deserializer);
-    var var_audioTracks = sse_decode_list_flutter_track_info(deserializer);
-    var var_createdAtMs = sse_decode_u_64(deserializer);
-    return FlutterBroadcastCatalog(
-      broadcastId: var_broadcastId,
-      videoTracks: var_videoTracks,
-      audioTracks: var_audioTracks,
-      createdAtMs: var_createdAtMs,
-    );
-  }
-
-  @protected
-  FlutterCaptureDevice sse_decode_flutter_capture_device(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_id = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_deviceType = sse_decode_String(deserializer);
-    var var_isDefault = sse_decode_bool(deserializer);
-    return FlutterCaptureDevice(
-      id: var_id,
-      name: var_name,
-      deviceType: var_deviceType,
-      isDefault: var_isDefault,
-    );
-  }
-
-  @protected
-  FlutterCatalog sse_decode_flutter_catalog(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_description = sse_decode_opt_String(deserializer);
-    var var_videoTracks = sse_decode_list_flutter_video_track(deserializer);
-    var var_isLive = sse_decode_bool(deserializer);
-    var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
-    return FlutterCatalog(
-      name: var_name,
-      description: var_description,
-      videoTracks: var_videoTracks,
-      isLive: var_isLive,
-      durationSecs: var_durationSecs,
-    );
-  }
-
-  @protected
-  FlutterConnectionStats sse_decode_flutter_connection_stats(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_bytesSent = sse_decode_u_64(deserializer);
-    var var_bytesReceived = sse_decode_u_64(deserializer);
-    var var_chunksSent = sse_decode_u_64(deserializer);
-    var var_chunksReceived = sse_decode_u_64(deserializer);
-    var var_bandwidthBps = sse_decode_u_64(deserializer);
-    var var_rttMs = sse_decode_u_64(deserializer);
-    var var_qualityScore = sse_decode_u_32(deserializer);
-    var var_recommendedQuality = sse_decode_flutter_video_quality(deserializer);
-    return FlutterConnectionStats(
-      bytesSent: var_bytesSent,
-      bytesReceived: var_bytesReceived,
-      chunksSent: var_chunksSent,
-      chunksReceived: var_chunksReceived,
-      bandwidthBps: var_bandwidthBps,
-      rttMs: var_rttMs,
-      qualityScore: var_qualityScore,
-      recommendedQuality: var_recommendedQuality,
-    );
-  }
-
-  @protected
-  FlutterDecodedAudioFrame sse_decode_flutter_decoded_audio_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_samples = sse_decode_list_prim_i_16_strict(deserializer);
-    var var_ptsUs = sse_decode_i_64(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_channels = sse_decode_u_32(deserializer);
-    return FlutterDecodedAudioFrame(
-      samples: var_samples,
-      ptsUs: var_ptsUs,
-      sampleRate: var_sampleRate,
-      channels: var_channels,
-    );
-  }
-
-  @protected
-  FlutterDecodedVideoFrame sse_decode_flutter_decoded_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_ptsUs = sse_decode_i_64(deserializer);
-    var var_isKeyframe = sse_decode_bool(deserializer);
-    return FlutterDecodedVideoFrame(
-      data: var_data,
-      width: var_width,
-      height: var_height,
-      ptsUs: var_ptsUs,
-      isKeyframe: var_isKeyframe,
-    );
-  }
-
-  @protected
-  FlutterDirectEvent sse_decode_flutter_direct_event(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_endpointId = sse_decode_String(deserializer);
-        return FlutterDirectEvent_PeerConnected(endpointId: var_endpointId);
-      case 1:
-        var var_endpointId = sse_decode_String(deserializer);
-        return FlutterDirectEvent_PeerDisconnected(endpointId: var_endpointId);
-      case 2:
-        var var_from = sse_decode_String(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterDirectEvent_RequestMetadata(
-          from: var_from,
-          timestamp: var_timestamp,
-        );
-      case 3:
-        var var_from = sse_decode_String(deserializer);
-        var var_fileName = sse_decode_String(deserializer);
-        var var_fileSize = sse_decode_u_64(deserializer);
-        var var_mimeType = sse_decode_String(deserializer);
-        var var_totalChunks = sse_decode_u_32(deserializer);
-        var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterDirectEvent_Metadata(
-          from: var_from,
-          fileName: var_fileName,
-          fileSize: var_fileSize,
-          mimeType: var_mimeType,
-          totalChunks: var_totalChunks,
-          duration: var_duration,
-          timestamp: var_timestamp,
-        );
-      case 4:
-        var var_from = sse_decode_String(deserializer);
-        var var_index = sse_decode_u_32(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterDirectEvent_RequestChunk(
-          from: var_from,
-          index: var_index,
-          timestamp: var_timestamp,
-        );
-      case 5:
-        var var_from = sse_decode_String(deserializer);
-        var var_index = sse_decode_u_32(deserializer);
-        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterDirectEvent_Chunk(
-          from: var_from,
-          index: var_index,
-          data: var_data,
-          timestamp: var_timestamp,
-        );
-      case 6:
-        var var_from = sse_decode_String(deserializer);
-        var var_name = sse_decode_String(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterDirectEvent_Presence(
-          from: var_from,
-          name: var_name,
-          timestamp: var_timestamp,
-        );
-      case 7:
-        var var_from = sse_decode_String(deserializer);
-        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterDirectEvent_Signal(
-          from: var_from,
-          data: var_data,
-          timestamp: var_timestamp,
-        );
-      case 8:
-        var var_message = sse_decode_String(deserializer);
-        return FlutterDirectEvent_Error(message: var_message);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  FlutterEncodedAudioFrame sse_decode_flutter_encoded_audio_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_ptsUs = sse_decode_i_64(deserializer);
-    var var_durationUs = sse_decode_i_64(deserializer);
-    var var_samples = sse_decode_u_32(deserializer);
-    var var_codec = sse_decode_flutter_audio_codec(deserializer);
-    return FlutterEncodedAudioFrame(
-      data: var_data,
-      ptsUs: var_ptsUs,
-      durationUs: var_durationUs,
-      samples: var_samples,
-      codec: var_codec,
-    );
-  }
-
-  @protected
-  FlutterEncodedAudioPacket sse_decode_flutter_encoded_audio_packet(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_codec = sse_decode_String(deserializer);
-    var var_sampleRate = sse_decode_u_32(deserializer);
-    var var_channels = sse_decode_u_16(deserializer);
-    return FlutterEncodedAudioPacket(
-      data: var_data,
-      timestampMs: var_timestampMs,
-      codec: var_codec,
-      sampleRate: var_sampleRate,
-      channels: var_channels,
-    );
-  }
-
-  @protected
-  FlutterEncodedVideoFrame sse_decode_flutter_encoded_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_ptsUs = sse_decode_i_64(deserializer);
-    var var_dtsUs = sse_decode_i_64(deserializer);
-    var var_isKeyframe = sse_decode_bool(deserializer);
-    var var_durationUs = sse_decode_i_64(deserializer);
-    var var_frameIndex = sse_decode_u_64(deserializer);
-    var var_codec = sse_decode_flutter_video_codec(deserializer);
-    var var_moqPriority = sse_decode_u_8(deserializer);
-    var var_moqTtlMs = sse_decode_u_64(deserializer);
-    return FlutterEncodedVideoFrame(
-      data: var_data,
-      ptsUs: var_ptsUs,
-      dtsUs: var_dtsUs,
-      isKeyframe: var_isKeyframe,
-      durationUs: var_durationUs,
-      frameIndex: var_frameIndex,
-      codec: var_codec,
-      moqPriority: var_moqPriority,
-      moqTtlMs: var_moqTtlMs,
-    );
-  }
-
-  @protected
-  FlutterEncodedVideoPacket sse_decode_flutter_encoded_video_packet(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_isKeyframe = sse_decode_bool(deserializer);
-    var var_codec = sse_decode_String(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    return FlutterEncodedVideoPacket(
-      data: var_data,
-      timestampMs: var_timestampMs,
-      isKeyframe: var_isKeyframe,
-      codec: var_codec,
-      width: var_width,
-      height: var_height,
-    );
-  }
-
-  @protected
-  FlutterEncoderPreset sse_decode_flutter_encoder_preset(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterEncoderPreset.values[inner];
-  }
-
-  @protected
-  FlutterFilterType sse_decode_flutter_filter_type(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        return FlutterFilterType_LatestGroup();
-      case 1:
-        return FlutterFilterType_LatestObject();
-      case 2:
-        return FlutterFilterType_NextGroup();
-      case 3:
-        var var_startGroup = sse_decode_u_64(deserializer);
-        var var_startObject = sse_decode_u_64(deserializer);
-        return FlutterFilterType_AbsoluteStart(
-          startGroup: var_startGroup,
-          startObject: var_startObject,
-        );
-      case 4:
-        var var_startGroup = sse_decode_u_64(deserializer);
-        var var_startObject = sse_decode_u_64(deserializer);
-        var var_endGroup = sse_decode_u_64(deserializer);
-        var var_endObject = sse_decode_opt_box_autoadd_u_64(deserializer);
-        return FlutterFilterType_AbsoluteRange(
-          startGroup: var_startGroup,
-          startObject: var_startObject,
-          endGroup: var_endGroup,
-          endObject: var_endObject,
-        );
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  FlutterGroupOrder sse_decode_flutter_group_order(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterGroupOrder.values[inner];
-  }
-
-  @protected
-  FlutterHardwareAccel sse_decode_flutter_hardware_accel(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterHardwareAccel.values[inner];
-  }
-
-  @protected
-  FlutterLiveEvent sse_decode_flutter_live_event(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_peerId = sse_decode_String(deserializer);
-        return FlutterLiveEvent_PeerConnected(peerId: var_peerId);
-      case 1:
-        var var_peerId = sse_decode_String(deserializer);
-        return FlutterLiveEvent_PeerDisconnected(peerId: var_peerId);
-      case 2:
-        var var_catalog = sse_decode_box_autoadd_flutter_catalog(deserializer);
-        return FlutterLiveEvent_CatalogReceived(catalog: var_catalog);
-      case 3:
-        var var_from = sse_decode_String(deserializer);
-        var var_fileName = sse_decode_String(deserializer);
-        var var_fileSize = sse_decode_u_64(deserializer);
-        var var_mimeType = sse_decode_String(deserializer);
-        var var_totalChunks = sse_decode_u_32(deserializer);
-        var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
-        return FlutterLiveEvent_MetadataReceived(
-          from: var_from,
-          fileName: var_fileName,
-          fileSize: var_fileSize,
-          mimeType: var_mimeType,
-          totalChunks: var_totalChunks,
-          duration: var_duration,
-        );
-      case 4:
-        var var_from = sse_decode_String(deserializer);
-        var var_index = sse_decode_u_32(deserializer);
-        return FlutterLiveEvent_ChunkRequested(
-          from: var_from,
-          index: var_index,
-        );
-      case 5:
-        var var_from = sse_decode_String(deserializer);
-        var var_index = sse_decode_u_32(deserializer);
-        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-        return FlutterLiveEvent_ChunkReceived(
-          from: var_from,
-          index: var_index,
-          data: var_data,
-        );
-      case 6:
-        var var_from = sse_decode_String(deserializer);
-        return FlutterLiveEvent_MetadataRequested(from: var_from);
-      case 7:
-        var var_stats = sse_decode_box_autoadd_flutter_connection_stats(
-          deserializer,
-        );
-        return FlutterLiveEvent_StatsUpdated(stats: var_stats);
-      case 8:
-        var var_message = sse_decode_String(deserializer);
-        return FlutterLiveEvent_Error(message: var_message);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  FlutterMoqObject sse_decode_flutter_moq_object(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_groupId = sse_decode_u_64(deserializer);
-    var var_subgroupId = sse_decode_u_64(deserializer);
-    var var_objectId = sse_decode_u_64(deserializer);
-    var var_publisherPriority = sse_decode_u_8(deserializer);
-    var var_expiresAt = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_status = sse_decode_flutter_object_status(deserializer);
-    var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_createdAt = sse_decode_u_64(deserializer);
-    var var_isExpired = sse_decode_bool(deserializer);
-    return FlutterMoqObject(
-      groupId: var_groupId,
-      subgroupId: var_subgroupId,
-      objectId: var_objectId,
-      publisherPriority: var_publisherPriority,
-      expiresAt: var_expiresAt,
-      status: var_status,
-      payload: var_payload,
-      createdAt: var_createdAt,
-      isExpired: var_isExpired,
-    );
-  }
-
-  @protected
-  FlutterMoqStats sse_decode_flutter_moq_stats(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_schedulerQueueLength = sse_decode_u_32(deserializer);
-    var var_namespaceCount = sse_decode_u_32(deserializer);
-    return FlutterMoqStats(
-      schedulerQueueLength: var_schedulerQueueLength,
-      namespaceCount: var_namespaceCount,
-    );
-  }
-
-  @protected
-  FlutterNamespaceAnnouncement sse_decode_flutter_namespace_announcement(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_namespacePath = sse_decode_String(deserializer);
-    var var_tracks = sse_decode_list_String(deserializer);
-    var var_acceptsPublishing = sse_decode_bool(deserializer);
-    return FlutterNamespaceAnnouncement(
-      namespacePath: var_namespacePath,
-      tracks: var_tracks,
-      acceptsPublishing: var_acceptsPublishing,
-    );
-  }
-
-  @protected
-  FlutterObjectStatus sse_decode_flutter_object_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterObjectStatus.values[inner];
-  }
-
-  @protected
-  FlutterPublisherStatus sse_decode_flutter_publisher_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_publisherId = sse_decode_String(deserializer);
-    var var_isActive = sse_decode_bool(deserializer);
-    var var_framesPublished = sse_decode_u_64(deserializer);
-    var var_bytesSent = sse_decode_u_64(deserializer);
-    var var_currentBitrate = sse_decode_u_32(deserializer);
-    var var_videoRenditions = sse_decode_list_String(deserializer);
-    var var_audioRenditions = sse_decode_list_String(deserializer);
-    return FlutterPublisherStatus(
-      publisherId: var_publisherId,
-      isActive: var_isActive,
-      framesPublished: var_framesPublished,
-      bytesSent: var_bytesSent,
-      currentBitrate: var_currentBitrate,
-      videoRenditions: var_videoRenditions,
-      audioRenditions: var_audioRenditions,
-    );
-  }
-
-  @protected
-  FlutterReceivedVideoFrame sse_decode_flutter_received_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_width = sse_decode_i_32(deserializer);
-    var var_height = sse_decode_i_32(deserializer);
-    var var_isKeyframe = sse_decode_bool(deserializer);
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    return FlutterReceivedVideoFrame(
-      timestampMs: var_timestampMs,
-      width: var_width,
-      height: var_height,
-      isKeyframe: var_isKeyframe,
-      data: var_data,
-    );
-  }
-
-  @protected
-  FlutterStreamEvent sse_decode_flutter_stream_event(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var tag_ = sse_decode_i_32(deserializer);
-    switch (tag_) {
-      case 0:
-        var var_endpointId = sse_decode_String(deserializer);
-        return FlutterStreamEvent_NeighborUp(endpointId: var_endpointId);
-      case 1:
-        var var_endpointId = sse_decode_String(deserializer);
-        return FlutterStreamEvent_NeighborDown(endpointId: var_endpointId);
-      case 2:
-        var var_from = sse_decode_String(deserializer);
-        var var_name = sse_decode_String(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterStreamEvent_Presence(
-          from: var_from,
-          name: var_name,
-          timestamp: var_timestamp,
-        );
-      case 3:
-        var var_from = sse_decode_String(deserializer);
-        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-        var var_sequence = sse_decode_u_64(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterStreamEvent_MediaChunk(
-          from: var_from,
-          data: var_data,
-          sequence: var_sequence,
-          timestamp: var_timestamp,
-        );
-      case 4:
-        var var_from = sse_decode_String(deserializer);
-        var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-        var var_timestamp = sse_decode_u_64(deserializer);
-        return FlutterStreamEvent_Signal(
-          from: var_from,
-          data: var_data,
-          timestamp: var_timestamp,
-        );
-      case 5:
-        return FlutterStreamEvent_Lagged();
-      case 6:
-        var var_message = sse_decode_String(deserializer);
-        return FlutterStreamEvent_Error(message: var_message);
-      default:
-        throw UnimplementedError('');
-    }
-  }
-
-  @protected
-  FlutterSubscriberStatus sse_decode_flutter_subscriber_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_subscriberId = sse_decode_String(deserializer);
-    var var_broadcastId = sse_decode_String(deserializer);
-    var var_isConnected = sse_decode_bool(deserializer);
-    var var_framesReceived = sse_decode_u_64(deserializer);
-    var var_bytesReceived = sse_decode_u_64(deserializer);
-    var var_currentQuality = sse_decode_String(deserializer);
-    var var_bufferHealth = sse_decode_f_32(deserializer);
-    return FlutterSubscriberStatus(
-      subscriberId: var_subscriberId,
-      broadcastId: var_broadcastId,
-      isConnected: var_isConnected,
-      framesReceived: var_framesReceived,
-      bytesReceived: var_bytesReceived,
-      currentQuality: var_currentQuality,
-      bufferHealth: var_bufferHealth,
-    );
-  }
-
-  @protected
-  FlutterTicketInfo sse_decode_flutter_ticket_info(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_broadcastName = sse_decode_String(deserializer);
-    var var_endpointId = sse_decode_String(deserializer);
-    var var_ticketString = sse_decode_String(deserializer);
-    return FlutterTicketInfo(
-      broadcastName: var_broadcastName,
-      endpointId: var_endpointId,
-      ticketString: var_ticketString,
-    );
-  }
-
-  @protected
-  FlutterTrackInfo sse_decode_flutter_track_info(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_trackId = sse_decode_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_codec = sse_decode_String(deserializer);
-    var var_bitrate = sse_decode_u_32(deserializer);
-    var var_extra = sse_decode_Map_String_String_None(deserializer);
-    return FlutterTrackInfo(
-      trackId: var_trackId,
-      name: var_name,
-      codec: var_codec,
-      bitrate: var_bitrate,
-      extra: var_extra,
-    );
-  }
-
-  @protected
-  FlutterTrackStatus sse_decode_flutter_track_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_trackPath = sse_decode_String(deserializer);
-    var var_status = sse_decode_flutter_track_status_code(deserializer);
-    var var_latestGroupId = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_latestObjectId = sse_decode_opt_box_autoadd_u_64(deserializer);
-    var var_groupOrder = sse_decode_flutter_group_order(deserializer);
-    var var_publisherPriority = sse_decode_u_8(deserializer);
-    return FlutterTrackStatus(
-      trackPath: var_trackPath,
-      status: var_status,
-      latestGroupId: var_latestGroupId,
-      latestObjectId: var_latestObjectId,
-      groupOrder: var_groupOrder,
-      publisherPriority: var_publisherPriority,
-    );
-  }
-
-  @protected
-  FlutterTrackStatusCode sse_decode_flutter_track_status_code(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterTrackStatusCode.values[inner];
-  }
-
-  @protected
-  FlutterVideoCodec sse_decode_flutter_video_codec(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterVideoCodec.values[inner];
-  }
-
-  @protected
-  FlutterVideoFrame sse_decode_flutter_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_data = sse_decode_list_prim_u_8_strict(deserializer);
-    var var_timestampMs = sse_decode_u_64(deserializer);
-    var var_format = sse_decode_String(deserializer);
-    return FlutterVideoFrame(
-      width: var_width,
-      height: var_height,
-      data: var_data,
-      timestampMs: var_timestampMs,
-      format: var_format,
-    );
-  }
-
-  @protected
-  FlutterVideoQuality sse_decode_flutter_video_quality(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterVideoQuality.values[inner];
-  }
-
-  @protected
-  FlutterVideoQualityFfmpeg sse_decode_flutter_video_quality_ffmpeg(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return FlutterVideoQualityFfmpeg.values[inner];
-  }
-
-  @protected
-  FlutterVideoRendition sse_decode_flutter_video_rendition(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_fps = sse_decode_u_32(deserializer);
-    var var_bitrate = sse_decode_u_32(deserializer);
-    var var_codec = sse_decode_String(deserializer);
-    return FlutterVideoRendition(
-      name: var_name,
-      width: var_width,
-      height: var_height,
-      fps: var_fps,
-      bitrate: var_bitrate,
-      codec: var_codec,
-    );
-  }
-
-  @protected
-  FlutterVideoTrack sse_decode_flutter_video_track(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_name = sse_decode_String(deserializer);
-    var var_quality = sse_decode_flutter_video_quality(deserializer);
-    var var_codec = sse_decode_String(deserializer);
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_fps = sse_decode_u_32(deserializer);
-    var var_bitrateKbps = sse_decode_u_32(deserializer);
-    return FlutterVideoTrack(
-      name: var_name,
-      quality: var_quality,
-      codec: var_codec,
-      width: var_width,
-      height: var_height,
-      fps: var_fps,
-      bitrateKbps: var_bitrateKbps,
-    );
-  }
-
-  @protected
-  int sse_decode_i_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt16();
-  }
-
-  @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getPlatformInt64();
-  }
-
-  @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterAudioRendition> sse_decode_list_flutter_audio_rendition(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterAudioRendition>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_audio_rendition(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterCaptureDevice> sse_decode_list_flutter_capture_device(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterCaptureDevice>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_capture_device(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterDirectEvent> sse_decode_list_flutter_direct_event(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterDirectEvent>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_direct_event(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterEncodedVideoFrame> sse_decode_list_flutter_encoded_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterEncodedVideoFrame>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_encoded_video_frame(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterLiveEvent> sse_decode_list_flutter_live_event(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterLiveEvent>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_live_event(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterMoqObject> sse_decode_list_flutter_moq_object(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterMoqObject>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_moq_object(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterNamespaceAnnouncement>
-  sse_decode_list_flutter_namespace_announcement(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterNamespaceAnnouncement>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_namespace_announcement(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterStreamEvent> sse_decode_list_flutter_stream_event(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterStreamEvent>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_stream_event(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterTrackInfo> sse_decode_list_flutter_track_info(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterTrackInfo>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_track_info(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterVideoQuality> sse_decode_list_flutter_video_quality(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterVideoQuality>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_video_quality(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterVideoRendition> sse_decode_list_flutter_video_rendition(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterVideoRendition>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_video_rendition(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<FlutterVideoTrack> sse_decode_list_flutter_video_track(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <FlutterVideoTrack>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_flutter_video_track(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_i_16_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getInt16List(len_);
-  }
-
-  @protected
-  Int16List sse_decode_list_prim_i_16_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getInt16List(len_);
-  }
-
-  @protected
-  List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<(String, bool)> sse_decode_list_record_string_bool(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, bool)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_bool(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  List<(String, String)> sse_decode_list_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <(String, String)>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_record_string_string(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FlutterMoqObject? sse_decode_opt_box_autoadd_flutter_moq_object(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_flutter_moq_object(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FlutterNamespaceAnnouncement?
-  sse_decode_opt_box_autoadd_flutter_namespace_announcement(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_flutter_namespace_announcement(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FlutterPublisherStatus? sse_decode_opt_box_autoadd_flutter_publisher_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_flutter_publisher_status(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FlutterReceivedVideoFrame?
-  sse_decode_opt_box_autoadd_flutter_received_video_frame(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_flutter_received_video_frame(
-        deserializer,
-      ));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FlutterSubscriberStatus? sse_decode_opt_box_autoadd_flutter_subscriber_status(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_flutter_subscriber_status(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  FlutterTicketInfo? sse_decode_opt_box_autoadd_flutter_ticket_info(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_flutter_ticket_info(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_32(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_8(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  Quality sse_decode_quality(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_i_32(deserializer);
-    return Quality.values[inner];
-  }
-
-  @protected
-  QualityConstraints sse_decode_quality_constraints(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_width = sse_decode_u_32(deserializer);
-    var var_height = sse_decode_u_32(deserializer);
-    var var_framerate = sse_decode_u_32(deserializer);
-    var var_audioBitrate = sse_decode_u_32(deserializer);
-    return QualityConstraints(
-      width: var_width,
-      height: var_height,
-      framerate: var_framerate,
-      audioBitrate: var_audioBitrate,
-    );
-  }
-
-  @protected
-  (String, bool) sse_decode_record_string_bool(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_bool(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (String, String) sse_decode_record_string_string(
-    SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_String(deserializer);
-    var var_field1 = sse_decode_String(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, int) sse_decode_record_u_32_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_32(deserializer);
-    var var_field1 = sse_decode_u_32(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (int, int, int) sse_decode_record_u_8_u_8_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_field0 = sse_decode_u_8(deserializer);
-    var var_field1 = sse_decode_u_8(deserializer);
-    var var_field2 = sse_decode_u_8(deserializer);
-    return (var_field0, var_field1, var_field2);
-  }
-
-  @protected
-  int sse_decode_u_16(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  int sse_decode_u_32(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint32();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_Map_String_String_None(
-    Map<String, String> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_string(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_Map_String_bool_None(
-    Map<String, bool> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_record_string_bool(
-      self.entries.map((e) => (e.key, e.value)).toList(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_audio_samples(
-    FlutterAudioSamples self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_audio_samples(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_broadcast_catalog(
-    FlutterBroadcastCatalog self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_broadcast_catalog(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_catalog(
-    FlutterCatalog self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_catalog(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_connection_stats(
-    FlutterConnectionStats self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_connection_stats(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_encoded_audio_packet(
-    FlutterEncodedAudioPacket self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_encoded_audio_packet(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_encoded_video_packet(
-    FlutterEncodedVideoPacket self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_encoded_video_packet(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_filter_type(
-    FlutterFilterType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_filter_type(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_moq_object(
-    FlutterMoqObject self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_moq_object(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_namespace_announcement(
-    FlutterNamespaceAnnouncement self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_namespace_announcement(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_publisher_status(
-    FlutterPublisherStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_publisher_status(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_received_video_frame(
-    FlutterReceivedVideoFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_received_video_frame(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_subscriber_status(
-    FlutterSubscriberStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_subscriber_status(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_ticket_info(
-    FlutterTicketInfo self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_ticket_info(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_flutter_video_frame(
-    FlutterVideoFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_flutter_video_frame(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_f_64(double self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_flutter_audio_codec(
-    FlutterAudioCodec self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_audio_rendition(
-    FlutterAudioRendition self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_16(self.channels, serializer);
-    sse_encode_u_32(self.bitrate, serializer);
-    sse_encode_String(self.codec, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_audio_samples(
-    FlutterAudioSamples self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_16(self.channels, serializer);
-    sse_encode_u_64(self.timestampMs, serializer);
-    sse_encode_String(self.format, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_broadcast_catalog(
-    FlutterBroadcastCatalog self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.broadcastId, serializer);
-    sse_encode_list_flutter_track_info(self.videoTracks, serializer);
-    sse_encode_list_flutter_track_info(self.audioTracks, serializer);
-    sse_encode_u_64(self.createdAtMs, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_capture_device(
-    FlutterCaptureDevice self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.id, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.deviceType, serializer);
-    sse_encode_bool(self.isDefault, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_catalog(
-    FlutterCatalog self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_opt_String(self.description, serializer);
-    sse_encode_list_flutter_video_track(self.videoTracks, serializer);
-    sse_encode_bool(self.isLive, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.durationSecs, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_connection_stats(
-    FlutterConnectionStats self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.bytesSent, serializer);
-    sse_encode_u_64(self.bytesReceived, serializer);
-    sse_encode_u_64(self.chunksSent, serializer);
-    sse_encode_u_64(self.chunksReceived, serializer);
-    sse_encode_u_64(self.bandwidthBps, serializer);
-    sse_encode_u_64(self.rttMs, serializer);
-    sse_encode_u_32(self.qualityScore, serializer);
-    sse_encode_flutter_video_quality(self.recommendedQuality, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_decoded_audio_frame(
-    FlutterDecodedAudioFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_i_16_strict(self.samples, serializer);
-    sse_encode_i_64(self.ptsUs, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_32(self.channels, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_decoded_video_frame(
-    FlutterDecodedVideoFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
-    sse_encode_i_64(self.ptsUs, serializer);
-    sse_encode_bool(self.isKeyframe, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_direct_event(
-    FlutterDirectEvent self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case FlutterDirectEvent_PeerConnected(endpointId: final endpointId):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(endpointId, serializer);
-      case FlutterDirectEvent_PeerDisconnected(endpointId: final endpointId):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(endpointId, serializer);
-      case FlutterDirectEvent_RequestMetadata(
-        from: final from,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterDirectEvent_Metadata(
-        from: final from,
-        fileName: final fileName,
-        fileSize: final fileSize,
-        mimeType: final mimeType,
-        totalChunks: final totalChunks,
-        duration: final duration,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_String(fileName, serializer);
-        sse_encode_u_64(fileSize, serializer);
-        sse_encode_String(mimeType, serializer);
-        sse_encode_u_32(totalChunks, serializer);
-        sse_encode_opt_box_autoadd_f_64(duration, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterDirectEvent_RequestChunk(
-        from: final from,
-        index: final index,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_u_32(index, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterDirectEvent_Chunk(
-        from: final from,
-        index: final index,
-        data: final data,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_u_32(index, serializer);
-        sse_encode_list_prim_u_8_strict(data, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterDirectEvent_Presence(
-        from: final from,
-        name: final name,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(6, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_String(name, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterDirectEvent_Signal(
-        from: final from,
-        data: final data,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(7, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_list_prim_u_8_strict(data, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterDirectEvent_Error(message: final message):
-        sse_encode_i_32(8, serializer);
-        sse_encode_String(message, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_flutter_encoded_audio_frame(
-    FlutterEncodedAudioFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_i_64(self.ptsUs, serializer);
-    sse_encode_i_64(self.durationUs, serializer);
-    sse_encode_u_32(self.samples, serializer);
-    sse_encode_flutter_audio_codec(self.codec, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_encoded_audio_packet(
-    FlutterEncodedAudioPacket self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_u_64(self.timestampMs, serializer);
-    sse_encode_String(self.codec, serializer);
-    sse_encode_u_32(self.sampleRate, serializer);
-    sse_encode_u_16(self.channels, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_encoded_video_frame(
-    FlutterEncodedVideoFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_i_64(self.ptsUs, serializer);
-    sse_encode_i_64(self.dtsUs, serializer);
-    sse_encode_bool(self.isKeyframe, serializer);
-    sse_encode_i_64(self.durationUs, serializer);
-    sse_encode_u_64(self.frameIndex, serializer);
-    sse_encode_flutter_video_codec(self.codec, serializer);
-    sse_encode_u_8(self.moqPriority, serializer);
-    sse_encode_u_64(self.moqTtlMs, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_encoded_video_packet(
-    FlutterEncodedVideoPacket self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_u_64(self.timestampMs, serializer);
-    sse_encode_bool(self.isKeyframe, serializer);
-    sse_encode_String(self.codec, serializer);
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_encoder_preset(
-    FlutterEncoderPreset self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_filter_type(
-    FlutterFilterType self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case FlutterFilterType_LatestGroup():
-        sse_encode_i_32(0, serializer);
-      case FlutterFilterType_LatestObject():
-        sse_encode_i_32(1, serializer);
-      case FlutterFilterType_NextGroup():
-        sse_encode_i_32(2, serializer);
-      case FlutterFilterType_AbsoluteStart(
-        startGroup: final startGroup,
-        startObject: final startObject,
-      ):
-        sse_encode_i_32(3, serializer);
-        sse_encode_u_64(startGroup, serializer);
-        sse_encode_u_64(startObject, serializer);
-      case FlutterFilterType_AbsoluteRange(
-        startGroup: final startGroup,
-        startObject: final startObject,
-        endGroup: final endGroup,
-        endObject: final endObject,
-      ):
-        sse_encode_i_32(4, serializer);
-        sse_encode_u_64(startGroup, serializer);
-        sse_encode_u_64(startObject, serializer);
-        sse_encode_u_64(endGroup, serializer);
-        sse_encode_opt_box_autoadd_u_64(endObject, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_flutter_group_order(
-    FlutterGroupOrder self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_hardware_accel(
-    FlutterHardwareAccel self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_live_event(
-    FlutterLiveEvent self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case FlutterLiveEvent_PeerConnected(peerId: final peerId):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(peerId, serializer);
-      case FlutterLiveEvent_PeerDisconnected(peerId: final peerId):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(peerId, serializer);
-      case FlutterLiveEvent_CatalogReceived(catalog: final catalog):
-        sse_encode_i_32(2, serializer);
-        sse_encode_box_autoadd_flutter_catalog(catalog, serializer);
-      case FlutterLiveEvent_MetadataReceived(
-        from: final from,
-        fileName: final fileName,
-        fileSize: final fileSize,
-        mimeType: final mimeType,
-        totalChunks: final totalChunks,
-        duration: final duration,
-      ):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_String(fileName, serializer);
-        sse_encode_u_64(fileSize, serializer);
-        sse_encode_String(mimeType, serializer);
-        sse_encode_u_32(totalChunks, serializer);
-        sse_encode_opt_box_autoadd_f_64(duration, serializer);
-      case FlutterLiveEvent_ChunkRequested(
-        from: final from,
-        index: final index,
-      ):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_u_32(index, serializer);
-      case FlutterLiveEvent_ChunkReceived(
-        from: final from,
-        index: final index,
-        data: final data,
-      ):
-        sse_encode_i_32(5, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_u_32(index, serializer);
-        sse_encode_list_prim_u_8_strict(data, serializer);
-      case FlutterLiveEvent_MetadataRequested(from: final from):
-        sse_encode_i_32(6, serializer);
-        sse_encode_String(from, serializer);
-      case FlutterLiveEvent_StatsUpdated(stats: final stats):
-        sse_encode_i_32(7, serializer);
-        sse_encode_box_autoadd_flutter_connection_stats(stats, serializer);
-      case FlutterLiveEvent_Error(message: final message):
-        sse_encode_i_32(8, serializer);
-        sse_encode_String(message, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_flutter_moq_object(
-    FlutterMoqObject self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.groupId, serializer);
-    sse_encode_u_64(self.subgroupId, serializer);
-    sse_encode_u_64(self.objectId, serializer);
-    sse_encode_u_8(self.publisherPriority, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.expiresAt, serializer);
-    sse_encode_flutter_object_status(self.status, serializer);
-    sse_encode_list_prim_u_8_strict(self.payload, serializer);
-    sse_encode_u_64(self.createdAt, serializer);
-    sse_encode_bool(self.isExpired, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_moq_stats(
-    FlutterMoqStats self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.schedulerQueueLength, serializer);
-    sse_encode_u_32(self.namespaceCount, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_namespace_announcement(
-    FlutterNamespaceAnnouncement self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.namespacePath, serializer);
-    sse_encode_list_String(self.tracks, serializer);
-    sse_encode_bool(self.acceptsPublishing, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_object_status(
-    FlutterObjectStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_publisher_status(
-    FlutterPublisherStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.publisherId, serializer);
-    sse_encode_bool(self.isActive, serializer);
-    sse_encode_u_64(self.framesPublished, serializer);
-    sse_encode_u_64(self.bytesSent, serializer);
-    sse_encode_u_32(self.currentBitrate, serializer);
-    sse_encode_list_String(self.videoRenditions, serializer);
-    sse_encode_list_String(self.audioRenditions, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_received_video_frame(
-    FlutterReceivedVideoFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.timestampMs, serializer);
-    sse_encode_i_32(self.width, serializer);
-    sse_encode_i_32(self.height, serializer);
-    sse_encode_bool(self.isKeyframe, serializer);
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_stream_event(
-    FlutterStreamEvent self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    switch (self) {
-      case FlutterStreamEvent_NeighborUp(endpointId: final endpointId):
-        sse_encode_i_32(0, serializer);
-        sse_encode_String(endpointId, serializer);
-      case FlutterStreamEvent_NeighborDown(endpointId: final endpointId):
-        sse_encode_i_32(1, serializer);
-        sse_encode_String(endpointId, serializer);
-      case FlutterStreamEvent_Presence(
-        from: final from,
-        name: final name,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(2, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_String(name, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterStreamEvent_MediaChunk(
-        from: final from,
-        data: final data,
-        sequence: final sequence,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(3, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_list_prim_u_8_strict(data, serializer);
-        sse_encode_u_64(sequence, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterStreamEvent_Signal(
-        from: final from,
-        data: final data,
-        timestamp: final timestamp,
-      ):
-        sse_encode_i_32(4, serializer);
-        sse_encode_String(from, serializer);
-        sse_encode_list_prim_u_8_strict(data, serializer);
-        sse_encode_u_64(timestamp, serializer);
-      case FlutterStreamEvent_Lagged():
-        sse_encode_i_32(5, serializer);
-      case FlutterStreamEvent_Error(message: final message):
-        sse_encode_i_32(6, serializer);
-        sse_encode_String(message, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_flutter_subscriber_status(
-    FlutterSubscriberStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.subscriberId, serializer);
-    sse_encode_String(self.broadcastId, serializer);
-    sse_encode_bool(self.isConnected, serializer);
-    sse_encode_u_64(self.framesReceived, serializer);
-    sse_encode_u_64(self.bytesReceived, serializer);
-    sse_encode_String(self.currentQuality, serializer);
-    sse_encode_f_32(self.bufferHealth, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_ticket_info(
-    FlutterTicketInfo self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.broadcastName, serializer);
-    sse_encode_String(self.endpointId, serializer);
-    sse_encode_String(self.ticketString, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_track_info(
-    FlutterTrackInfo self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.trackId, serializer);
-    sse_encode_String(self.name, serializer);
-    sse_encode_String(self.codec, serializer);
-    sse_encode_u_32(self.bitrate, serializer);
-    sse_encode_Map_String_String_None(self.extra, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_track_status(
-    FlutterTrackStatus self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.trackPath, serializer);
-    sse_encode_flutter_track_status_code(self.status, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.latestGroupId, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.latestObjectId, serializer);
-    sse_encode_flutter_group_order(self.groupOrder, serializer);
-    sse_encode_u_8(self.publisherPriority, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_track_status_code(
-    FlutterTrackStatusCode self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_video_codec(
-    FlutterVideoCodec self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_video_frame(
-    FlutterVideoFrame self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
-    sse_encode_list_prim_u_8_strict(self.data, serializer);
-    sse_encode_u_64(self.timestampMs, serializer);
-    sse_encode_String(self.format, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_video_quality(
-    FlutterVideoQuality self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_video_quality_ffmpeg(
-    FlutterVideoQualityFfmpeg self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_video_rendition(
-    FlutterVideoRendition self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
-    sse_encode_u_32(self.fps, serializer);
-    sse_encode_u_32(self.bitrate, serializer);
-    sse_encode_String(self.codec, serializer);
-  }
-
-  @protected
-  void sse_encode_flutter_video_track(
-    FlutterVideoTrack self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.name, serializer);
-    sse_encode_flutter_video_quality(self.quality, serializer);
-    sse_encode_String(self.codec, serializer);
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
-    sse_encode_u_32(self.fps, serializer);
-    sse_encode_u_32(self.bitrateKbps, serializer);
-  }
-
-  @protected
-  void sse_encode_i_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt16(self);
-  }
-
-  @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putPlatformInt64(self);
-  }
-
-  @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_audio_rendition(
-    List<FlutterAudioRendition> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_audio_rendition(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_capture_device(
-    List<FlutterCaptureDevice> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_capture_device(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_direct_event(
-    List<FlutterDirectEvent> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_direct_event(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_encoded_video_frame(
-    List<FlutterEncodedVideoFrame> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_encoded_video_frame(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_live_event(
-    List<FlutterLiveEvent> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_live_event(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_moq_object(
-    List<FlutterMoqObject> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_moq_object(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_namespace_announcement(
-    List<FlutterNamespaceAnnouncement> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_namespace_announcement(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_stream_event(
-    List<FlutterStreamEvent> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_stream_event(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_track_info(
-    List<FlutterTrackInfo> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_track_info(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_video_quality(
-    List<FlutterVideoQuality> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_video_quality(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_video_rendition(
-    List<FlutterVideoRendition> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_video_rendition(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_flutter_video_track(
-    List<FlutterVideoTrack> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_flutter_video_track(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_i_16_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putInt16List(
-      self is Int16List ? self : Int16List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_i_16_strict(
-    Int16List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putInt16List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_loose(
-    List<int> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(
-      self is Uint8List ? self : Uint8List.fromList(self),
-    );
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_record_string_bool(
-    List<(String, bool)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_bool(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_record_string_string(
-    List<(String, String)> self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_record_string_string(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_flutter_moq_object(
-    FlutterMoqObject? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_flutter_moq_object(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_flutter_namespace_announcement(
-    FlutterNamespaceAnnouncement? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_flutter_namespace_announcement(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_flutter_publisher_status(
-    FlutterPublisherStatus? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_flutter_publisher_status(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_flutter_received_video_frame(
-    FlutterReceivedVideoFrame? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_flutter_received_video_frame(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_flutter_subscriber_status(
-    FlutterSubscriberStatus? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_flutter_subscriber_status(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_flutter_ticket_info(
-    FlutterTicketInfo? self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_flutter_ticket_info(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_32(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_8(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_quality(Quality self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_quality_constraints(
-    QualityConstraints self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.width, serializer);
-    sse_encode_u_32(self.height, serializer);
-    sse_encode_u_32(self.framerate, serializer);
-    sse_encode_u_32(self.audioBitrate, serializer);
-  }
-
-  @protected
-  void sse_encode_record_string_bool(
-    (String, bool) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_bool(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_string_string(
-    (String, String) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.$1, serializer);
-    sse_encode_String(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_32_u_32((int, int) self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_32(self.$1, serializer);
-    sse_encode_u_32(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_u_8_u_8_u_8(
-    (int, int, int) self,
-    SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_8(self.$1, serializer);
-    sse_encode_u_8(self.$2, serializer);
-    sse_encode_u_8(self.$3, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_32(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint32(self);
-  }
-
-  @protected
-  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(int self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(void self, SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-}
+        )
+        ,
+            constMeta: kCrateApiLiveFlutterApiStopLiveBroadcastConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiLiveFlutterApiStopLiveBroadcastConstMeta => const TaskConstMeta(
+            debugName: "stop_live_broadcast",
+            argNames: [],
+        );
+        
+
+
+
+                  @protected Map<String, String> dco_decode_Map_String_String_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_string(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, bool> dco_decode_Map_String_bool_None(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Map.fromEntries(dco_decode_list_record_string_bool(raw).map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FlutterAccessPolicy dco_decode_box_autoadd_flutter_access_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_access_policy(raw); }
+
+@protected FlutterAudioSamples dco_decode_box_autoadd_flutter_audio_samples(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_audio_samples(raw); }
+
+@protected FlutterBroadcastCatalog dco_decode_box_autoadd_flutter_broadcast_catalog(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_broadcast_catalog(raw); }
+
+@protected FlutterCatalog dco_decode_box_autoadd_flutter_catalog(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_catalog(raw); }
+
+@protected FlutterConnectionState dco_decode_box_autoadd_flutter_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_connection_state(raw); }
+
+@protected FlutterConnectionStats dco_decode_box_autoadd_flutter_connection_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_connection_stats(raw); }
+
+@protected FlutterEncodedAudioPacket dco_decode_box_autoadd_flutter_encoded_audio_packet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_encoded_audio_packet(raw); }
+
+@protected FlutterEncodedVideoPacket dco_decode_box_autoadd_flutter_encoded_video_packet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_encoded_video_packet(raw); }
+
+@protected FlutterEndpointConfig dco_decode_box_autoadd_flutter_endpoint_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_endpoint_config(raw); }
+
+@protected FlutterFilterType dco_decode_box_autoadd_flutter_filter_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_filter_type(raw); }
+
+@protected FlutterMoqObject dco_decode_box_autoadd_flutter_moq_object(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_moq_object(raw); }
+
+@protected FlutterNamespaceAnnouncement dco_decode_box_autoadd_flutter_namespace_announcement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_namespace_announcement(raw); }
+
+@protected FlutterPublisherStatus dco_decode_box_autoadd_flutter_publisher_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_publisher_status(raw); }
+
+@protected FlutterReceivedVideoFrame dco_decode_box_autoadd_flutter_received_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_received_video_frame(raw); }
+
+@protected FlutterSubscriberStatus dco_decode_box_autoadd_flutter_subscriber_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_subscriber_status(raw); }
+
+@protected FlutterTicketInfo dco_decode_box_autoadd_flutter_ticket_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_ticket_info(raw); }
+
+@protected FlutterVideoFrame dco_decode_box_autoadd_flutter_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_video_frame(raw); }
+
+@protected FlutterViewer dco_decode_box_autoadd_flutter_viewer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_flutter_viewer(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FlutterAbrEvent dco_decode_flutter_abr_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FlutterAbrEvent(fromTrack: dco_decode_String(arr[0]),
+toTrack: dco_decode_String(arr[1]),
+reason: dco_decode_String(arr[2]),
+throughputBps: dco_decode_u_64(arr[3]),
+rttMs: dco_decode_u_32(arr[4]),
+lossPercent: dco_decode_f_32(arr[5]),
+lateFrames: dco_decode_u_64(arr[6]),); }
+
+@protected FlutterAccessPolicy dco_decode_flutter_access_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FlutterAccessPolicy(requireToken: dco_decode_bool(arr[0]),
+allow: dco_decode_list_String(arr[1]),
+deny: dco_decode_list_String(arr[2]),); }
+
+@protected FlutterAudioCodec dco_decode_flutter_audio_codec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterAudioCodec.values[raw as int]; }
+
+@protected FlutterAudioRendition dco_decode_flutter_audio_rendition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterAudioRendition(name: dco_decode_String(arr[0]),
+sampleRate: dco_decode_u_32(arr[1]),
+channels: dco_decode_u_16(arr[2]),
+bitrate: dco_decode_u_32(arr[3]),
+codec: dco_decode_String(arr[4]),); }
+
+@protected FlutterAudioSamples dco_decode_flutter_audio_samples(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterAudioSamples(data: dco_decode_list_prim_u_8_strict(arr[0]),
+sampleRate: dco_decode_u_32(arr[1]),
+channels: dco_decode_u_16(arr[2]),
+timestampMs: dco_decode_u_64(arr[3]),
+format: dco_decode_String(arr[4]),); }
+
+@protected FlutterBroadcastCatalog dco_decode_flutter_broadcast_catalog(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FlutterBroadcastCatalog(broadcastId: dco_decode_String(arr[0]),
+videoTracks: dco_decode_list_flutter_track_info(arr[1]),
+audioTracks: dco_decode_list_flutter_track_info(arr[2]),
+createdAtMs: dco_decode_u_64(arr[3]),); }
+
+@protected FlutterCaptureDevice dco_decode_flutter_capture_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FlutterCaptureDevice(id: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+deviceType: dco_decode_String(arr[2]),
+isDefault: dco_decode_bool(arr[3]),); }
+
+@protected FlutterCatalog dco_decode_flutter_catalog(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterCatalog(name: dco_decode_String(arr[0]),
+description: dco_decode_opt_String(arr[1]),
+videoTracks: dco_decode_list_flutter_video_track(arr[2]),
+isLive: dco_decode_bool(arr[3]),
+durationSecs: dco_decode_opt_box_autoadd_f_64(arr[4]),); }
+
+@protected FlutterChatMessage dco_decode_flutter_chat_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FlutterChatMessage(seq: dco_decode_u_64(arr[0]),
+sender: dco_decode_String(arr[1]),
+displayName: dco_decode_opt_String(arr[2]),
+timestampMs: dco_decode_u_64(arr[3]),
+kind: dco_decode_String(arr[4]),
+text: dco_decode_String(arr[5]),
+reactionTo: dco_decode_opt_box_autoadd_u_64(arr[6]),); }
+
+@protected FlutterConnectionState dco_decode_flutter_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FlutterConnectionState(state: dco_decode_String(arr[0]),
+attempt: dco_decode_u_32(arr[1]),
+retryInMs: dco_decode_u_64(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected FlutterConnectionStats dco_decode_flutter_connection_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return FlutterConnectionStats(bytesSent: dco_decode_u_64(arr[0]),
+bytesReceived: dco_decode_u_64(arr[1]),
+chunksSent: dco_decode_u_64(arr[2]),
+chunksReceived: dco_decode_u_64(arr[3]),
+bandwidthBps: dco_decode_u_64(arr[4]),
+rttMs: dco_decode_u_64(arr[5]),
+qualityScore: dco_decode_u_32(arr[6]),
+recommendedQuality: dco_decode_flutter_video_quality(arr[7]),); }
+
+@protected FlutterDecodedAudioFrame dco_decode_flutter_decoded_audio_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FlutterDecodedAudioFrame(samples: dco_decode_list_prim_i_16_strict(arr[0]),
+ptsUs: dco_decode_i_64(arr[1]),
+sampleRate: dco_decode_u_32(arr[2]),
+channels: dco_decode_u_32(arr[3]),); }
+
+@protected FlutterDecodedVideoFrame dco_decode_flutter_decoded_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterDecodedVideoFrame(data: dco_decode_list_prim_u_8_strict(arr[0]),
+width: dco_decode_u_32(arr[1]),
+height: dco_decode_u_32(arr[2]),
+ptsUs: dco_decode_i_64(arr[3]),
+isKeyframe: dco_decode_bool(arr[4]),); }
+
+@protected FlutterDirectEvent dco_decode_flutter_direct_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FlutterDirectEvent_PeerConnected(endpointId: dco_decode_String(raw[1]),);
+case 1: return FlutterDirectEvent_PeerDisconnected(endpointId: dco_decode_String(raw[1]),);
+case 2: return FlutterDirectEvent_RequestMetadata(from: dco_decode_String(raw[1]),timestamp: dco_decode_u_64(raw[2]),);
+case 3: return FlutterDirectEvent_Metadata(from: dco_decode_String(raw[1]),fileName: dco_decode_String(raw[2]),fileSize: dco_decode_u_64(raw[3]),mimeType: dco_decode_String(raw[4]),totalChunks: dco_decode_u_32(raw[5]),duration: dco_decode_opt_box_autoadd_f_64(raw[6]),timestamp: dco_decode_u_64(raw[7]),);
+case 4: return FlutterDirectEvent_RequestChunk(from: dco_decode_String(raw[1]),index: dco_decode_u_32(raw[2]),timestamp: dco_decode_u_64(raw[3]),);
+case 5: return FlutterDirectEvent_Chunk(from: dco_decode_String(raw[1]),index: dco_decode_u_32(raw[2]),data: dco_decode_list_prim_u_8_strict(raw[3]),timestamp: dco_decode_u_64(raw[4]),);
+case 6: return FlutterDirectEvent_Presence(from: dco_decode_String(raw[1]),name: dco_decode_String(raw[2]),timestamp: dco_decode_u_64(raw[3]),);
+case 7: return FlutterDirectEvent_Signal(from: dco_decode_String(raw[1]),data: dco_decode_list_prim_u_8_strict(raw[2]),timestamp: dco_decode_u_64(raw[3]),);
+case 8: return FlutterDirectEvent_Error(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FlutterDiscoveryEvent dco_decode_flutter_discovery_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FlutterDiscoveryEvent(kind: dco_decode_String(arr[0]),
+broadcastName: dco_decode_String(arr[1]),); }
+
+@protected FlutterEncodedAudioFrame dco_decode_flutter_encoded_audio_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterEncodedAudioFrame(data: dco_decode_list_prim_u_8_strict(arr[0]),
+ptsUs: dco_decode_i_64(arr[1]),
+durationUs: dco_decode_i_64(arr[2]),
+samples: dco_decode_u_32(arr[3]),
+codec: dco_decode_flutter_audio_codec(arr[4]),); }
+
+@protected FlutterEncodedAudioPacket dco_decode_flutter_encoded_audio_packet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterEncodedAudioPacket(data: dco_decode_list_prim_u_8_strict(arr[0]),
+timestampMs: dco_decode_u_64(arr[1]),
+codec: dco_decode_String(arr[2]),
+sampleRate: dco_decode_u_32(arr[3]),
+channels: dco_decode_u_16(arr[4]),); }
+
+@protected FlutterEncodedVideoFrame dco_decode_flutter_encoded_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return FlutterEncodedVideoFrame(data: dco_decode_list_prim_u_8_strict(arr[0]),
+ptsUs: dco_decode_i_64(arr[1]),
+dtsUs: dco_decode_i_64(arr[2]),
+isKeyframe: dco_decode_bool(arr[3]),
+durationUs: dco_decode_i_64(arr[4]),
+frameIndex: dco_decode_u_64(arr[5]),
+codec: dco_decode_flutter_video_codec(arr[6]),
+moqPriority: dco_decode_u_8(arr[7]),
+moqTtlMs: dco_decode_u_64(arr[8]),); }
+
+@protected FlutterEncodedVideoPacket dco_decode_flutter_encoded_video_packet(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return FlutterEncodedVideoPacket(data: dco_decode_list_prim_u_8_strict(arr[0]),
+timestampMs: dco_decode_u_64(arr[1]),
+isKeyframe: dco_decode_bool(arr[2]),
+codec: dco_decode_String(arr[3]),
+width: dco_decode_u_32(arr[4]),
+height: dco_decode_u_32(arr[5]),); }
+
+@protected FlutterEncoderPreset dco_decode_flutter_encoder_preset(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterEncoderPreset.values[raw as int]; }
+
+@protected FlutterEndpointConfig dco_decode_flutter_endpoint_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FlutterEndpointConfig(relayMode: dco_decode_flutter_relay_mode(arr[0]),
+bindPort: dco_decode_opt_box_autoadd_u_16(arr[1]),
+localDiscovery: dco_decode_bool(arr[2]),); }
+
+@protected FlutterFilterType dco_decode_flutter_filter_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FlutterFilterType_LatestGroup();
+case 1: return FlutterFilterType_LatestObject();
+case 2: return FlutterFilterType_NextGroup();
+case 3: return FlutterFilterType_AbsoluteStart(startGroup: dco_decode_u_64(raw[1]),startObject: dco_decode_u_64(raw[2]),);
+case 4: return FlutterFilterType_AbsoluteRange(startGroup: dco_decode_u_64(raw[1]),startObject: dco_decode_u_64(raw[2]),endGroup: dco_decode_u_64(raw[3]),endObject: dco_decode_opt_box_autoadd_u_64(raw[4]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FlutterGroupOrder dco_decode_flutter_group_order(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterGroupOrder.values[raw as int]; }
+
+@protected FlutterHardwareAccel dco_decode_flutter_hardware_accel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterHardwareAccel.values[raw as int]; }
+
+@protected FlutterIssuedTicket dco_decode_flutter_issued_ticket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FlutterIssuedTicket(ticket: dco_decode_String(arr[0]),
+tokenId: dco_decode_u_64(arr[1]),
+expiresAt: dco_decode_u_64(arr[2]),); }
+
+@protected FlutterLiveEvent dco_decode_flutter_live_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FlutterLiveEvent_PeerConnected(peerId: dco_decode_String(raw[1]),);
+case 1: return FlutterLiveEvent_PeerDisconnected(peerId: dco_decode_String(raw[1]),);
+case 2: return FlutterLiveEvent_CatalogReceived(catalog: dco_decode_box_autoadd_flutter_catalog(raw[1]),);
+case 3: return FlutterLiveEvent_MetadataReceived(from: dco_decode_String(raw[1]),fileName: dco_decode_String(raw[2]),fileSize: dco_decode_u_64(raw[3]),mimeType: dco_decode_String(raw[4]),totalChunks: dco_decode_u_32(raw[5]),duration: dco_decode_opt_box_autoadd_f_64(raw[6]),);
+case 4: return FlutterLiveEvent_ChunkRequested(from: dco_decode_String(raw[1]),index: dco_decode_u_32(raw[2]),);
+case 5: return FlutterLiveEvent_ChunkReceived(from: dco_decode_String(raw[1]),index: dco_decode_u_32(raw[2]),data: dco_decode_list_prim_u_8_strict(raw[3]),);
+case 6: return FlutterLiveEvent_MetadataRequested(from: dco_decode_String(raw[1]),);
+case 7: return FlutterLiveEvent_StatsUpdated(stats: dco_decode_box_autoadd_flutter_connection_stats(raw[1]),);
+case 8: return FlutterLiveEvent_Error(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FlutterMoqObject dco_decode_flutter_moq_object(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return FlutterMoqObject(groupId: dco_decode_u_64(arr[0]),
+subgroupId: dco_decode_u_64(arr[1]),
+objectId: dco_decode_u_64(arr[2]),
+publisherPriority: dco_decode_u_8(arr[3]),
+expiresAt: dco_decode_opt_box_autoadd_u_64(arr[4]),
+status: dco_decode_flutter_object_status(arr[5]),
+payload: dco_decode_list_prim_u_8_strict(arr[6]),
+createdAt: dco_decode_u_64(arr[7]),
+isExpired: dco_decode_bool(arr[8]),); }
+
+@protected FlutterMoqStats dco_decode_flutter_moq_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FlutterMoqStats(schedulerQueueLength: dco_decode_u_32(arr[0]),
+namespaceCount: dco_decode_u_32(arr[1]),); }
+
+@protected FlutterNamespaceAnnouncement dco_decode_flutter_namespace_announcement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FlutterNamespaceAnnouncement(namespacePath: dco_decode_String(arr[0]),
+tracks: dco_decode_list_String(arr[1]),
+acceptsPublishing: dco_decode_bool(arr[2]),); }
+
+@protected FlutterObjectStatus dco_decode_flutter_object_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterObjectStatus.values[raw as int]; }
+
+@protected FlutterPublisherStatus dco_decode_flutter_publisher_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FlutterPublisherStatus(publisherId: dco_decode_String(arr[0]),
+isActive: dco_decode_bool(arr[1]),
+framesPublished: dco_decode_u_64(arr[2]),
+bytesSent: dco_decode_u_64(arr[3]),
+currentBitrate: dco_decode_u_32(arr[4]),
+videoRenditions: dco_decode_list_String(arr[5]),
+audioRenditions: dco_decode_list_String(arr[6]),); }
+
+@protected FlutterReceivedVideoFrame dco_decode_flutter_received_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterReceivedVideoFrame(timestampMs: dco_decode_u_64(arr[0]),
+width: dco_decode_i_32(arr[1]),
+height: dco_decode_i_32(arr[2]),
+isKeyframe: dco_decode_bool(arr[3]),
+data: dco_decode_list_prim_u_8_strict(arr[4]),); }
+
+@protected FlutterRecordingStats dco_decode_flutter_recording_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterRecordingStats(files: dco_decode_list_String(arr[0]),
+fragments: dco_decode_u_64(arr[1]),
+videoFrames: dco_decode_u_64(arr[2]),
+audioFrames: dco_decode_u_64(arr[3]),
+bytes: dco_decode_u_64(arr[4]),); }
+
+@protected FlutterRelayInfo dco_decode_flutter_relay_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return FlutterRelayInfo(broadcastName: dco_decode_String(arr[0]),
+route: dco_decode_list_String(arr[1]),); }
+
+@protected FlutterRelayMode dco_decode_flutter_relay_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FlutterRelayMode_Default();
+case 1: return FlutterRelayMode_Custom(url: dco_decode_String(raw[1]),);
+case 2: return FlutterRelayMode_Disabled();
+                default: throw Exception("unreachable");
+            } }
+
+@protected FlutterRenditionStats dco_decode_flutter_rendition_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterRenditionStats(trackName: dco_decode_String(arr[0]),
+framesWritten: dco_decode_u_64(arr[1]),
+bytesWritten: dco_decode_u_64(arr[2]),
+framesDropped: dco_decode_u_64(arr[3]),
+groupsCut: dco_decode_u_64(arr[4]),); }
+
+@protected FlutterRoomEvent dco_decode_flutter_room_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FlutterRoomEvent(kind: dco_decode_String(arr[0]),
+member: dco_decode_String(arr[1]),
+subscriberId: dco_decode_String(arr[2]),); }
+
+@protected FlutterRosterEvent dco_decode_flutter_roster_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FlutterRosterEvent(kind: dco_decode_String(arr[0]),
+endpointId: dco_decode_String(arr[1]),
+viewer: dco_decode_opt_box_autoadd_flutter_viewer(arr[2]),
+kicked: dco_decode_bool(arr[3]),); }
+
+@protected FlutterStreamEvent dco_decode_flutter_stream_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return FlutterStreamEvent_NeighborUp(endpointId: dco_decode_String(raw[1]),);
+case 1: return FlutterStreamEvent_NeighborDown(endpointId: dco_decode_String(raw[1]),);
+case 2: return FlutterStreamEvent_Presence(from: dco_decode_String(raw[1]),name: dco_decode_String(raw[2]),timestamp: dco_decode_u_64(raw[3]),);
+case 3: return FlutterStreamEvent_MediaChunk(from: dco_decode_String(raw[1]),data: dco_decode_list_prim_u_8_strict(raw[2]),sequence: dco_decode_u_64(raw[3]),timestamp: dco_decode_u_64(raw[4]),);
+case 4: return FlutterStreamEvent_Signal(from: dco_decode_String(raw[1]),data: dco_decode_list_prim_u_8_strict(raw[2]),timestamp: dco_decode_u_64(raw[3]),);
+case 5: return FlutterStreamEvent_Lagged();
+case 6: return FlutterStreamEvent_Error(message: dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected FlutterSubscriberStatus dco_decode_flutter_subscriber_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FlutterSubscriberStatus(subscriberId: dco_decode_String(arr[0]),
+broadcastId: dco_decode_String(arr[1]),
+isConnected: dco_decode_bool(arr[2]),
+framesReceived: dco_decode_u_64(arr[3]),
+bytesReceived: dco_decode_u_64(arr[4]),
+currentQuality: dco_decode_String(arr[5]),
+bufferHealth: dco_decode_f_32(arr[6]),); }
+
+@protected FlutterTicketInfo dco_decode_flutter_ticket_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return FlutterTicketInfo(broadcastName: dco_decode_String(arr[0]),
+endpointId: dco_decode_String(arr[1]),
+ticketString: dco_decode_String(arr[2]),); }
+
+@protected FlutterTrackInfo dco_decode_flutter_track_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterTrackInfo(trackId: dco_decode_String(arr[0]),
+name: dco_decode_String(arr[1]),
+codec: dco_decode_String(arr[2]),
+bitrate: dco_decode_u_32(arr[3]),
+extra: dco_decode_Map_String_String_None(arr[4]),); }
+
+@protected FlutterTrackStatus dco_decode_flutter_track_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return FlutterTrackStatus(trackPath: dco_decode_String(arr[0]),
+status: dco_decode_flutter_track_status_code(arr[1]),
+latestGroupId: dco_decode_opt_box_autoadd_u_64(arr[2]),
+latestObjectId: dco_decode_opt_box_autoadd_u_64(arr[3]),
+groupOrder: dco_decode_flutter_group_order(arr[4]),
+publisherPriority: dco_decode_u_8(arr[5]),); }
+
+@protected FlutterTrackStatusCode dco_decode_flutter_track_status_code(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterTrackStatusCode.values[raw as int]; }
+
+@protected FlutterVideoCodec dco_decode_flutter_video_codec(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterVideoCodec.values[raw as int]; }
+
+@protected FlutterVideoFrame dco_decode_flutter_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return FlutterVideoFrame(width: dco_decode_u_32(arr[0]),
+height: dco_decode_u_32(arr[1]),
+data: dco_decode_list_prim_u_8_strict(arr[2]),
+timestampMs: dco_decode_u_64(arr[3]),
+format: dco_decode_String(arr[4]),); }
+
+@protected FlutterVideoQuality dco_decode_flutter_video_quality(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterVideoQuality.values[raw as int]; }
+
+@protected FlutterVideoQualityFfmpeg dco_decode_flutter_video_quality_ffmpeg(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return FlutterVideoQualityFfmpeg.values[raw as int]; }
+
+@protected FlutterVideoRendition dco_decode_flutter_video_rendition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return FlutterVideoRendition(name: dco_decode_String(arr[0]),
+width: dco_decode_u_32(arr[1]),
+height: dco_decode_u_32(arr[2]),
+fps: dco_decode_u_32(arr[3]),
+bitrate: dco_decode_u_32(arr[4]),
+codec: dco_decode_String(arr[5]),); }
+
+@protected FlutterVideoTrack dco_decode_flutter_video_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FlutterVideoTrack(name: dco_decode_String(arr[0]),
+quality: dco_decode_flutter_video_quality(arr[1]),
+codec: dco_decode_String(arr[2]),
+width: dco_decode_u_32(arr[3]),
+height: dco_decode_u_32(arr[4]),
+fps: dco_decode_u_32(arr[5]),
+bitrateKbps: dco_decode_u_32(arr[6]),); }
+
+@protected FlutterViewer dco_decode_flutter_viewer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return FlutterViewer(endpointId: dco_decode_String(arr[0]),
+displayName: dco_decode_opt_String(arr[1]),
+connectedAtMs: dco_decode_u_64(arr[2]),
+tracks: dco_decode_list_String(arr[3]),
+bytesSent: dco_decode_u_64(arr[4]),
+bytesReceived: dco_decode_u_64(arr[5]),
+rttMs: dco_decode_u_32(arr[6]),); }
+
+@protected int dco_decode_i_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<FlutterAbrEvent> dco_decode_list_flutter_abr_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_abr_event).toList(); }
+
+@protected List<FlutterAudioRendition> dco_decode_list_flutter_audio_rendition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_audio_rendition).toList(); }
+
+@protected List<FlutterCaptureDevice> dco_decode_list_flutter_capture_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_capture_device).toList(); }
+
+@protected List<FlutterChatMessage> dco_decode_list_flutter_chat_message(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_chat_message).toList(); }
+
+@protected List<FlutterConnectionState> dco_decode_list_flutter_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_connection_state).toList(); }
+
+@protected List<FlutterDirectEvent> dco_decode_list_flutter_direct_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_direct_event).toList(); }
+
+@protected List<FlutterDiscoveryEvent> dco_decode_list_flutter_discovery_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_discovery_event).toList(); }
+
+@protected List<FlutterEncodedVideoFrame> dco_decode_list_flutter_encoded_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_encoded_video_frame).toList(); }
+
+@protected List<FlutterLiveEvent> dco_decode_list_flutter_live_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_live_event).toList(); }
+
+@protected List<FlutterMoqObject> dco_decode_list_flutter_moq_object(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_moq_object).toList(); }
+
+@protected List<FlutterNamespaceAnnouncement> dco_decode_list_flutter_namespace_announcement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_namespace_announcement).toList(); }
+
+@protected List<FlutterRelayInfo> dco_decode_list_flutter_relay_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_relay_info).toList(); }
+
+@protected List<FlutterRenditionStats> dco_decode_list_flutter_rendition_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_rendition_stats).toList(); }
+
+@protected List<FlutterRoomEvent> dco_decode_list_flutter_room_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_room_event).toList(); }
+
+@protected List<FlutterRosterEvent> dco_decode_list_flutter_roster_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_roster_event).toList(); }
+
+@protected List<FlutterStreamEvent> dco_decode_list_flutter_stream_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_stream_event).toList(); }
+
+@protected List<FlutterTrackInfo> dco_decode_list_flutter_track_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_track_info).toList(); }
+
+@protected List<FlutterVideoQuality> dco_decode_list_flutter_video_quality(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_video_quality).toList(); }
+
+@protected List<FlutterVideoRendition> dco_decode_list_flutter_video_rendition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_video_rendition).toList(); }
+
+@protected List<FlutterVideoTrack> dco_decode_list_flutter_video_track(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_video_track).toList(); }
+
+@protected List<FlutterViewer> dco_decode_list_flutter_viewer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_flutter_viewer).toList(); }
+
+@protected List<int> dco_decode_list_prim_i_16_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Int16List dco_decode_list_prim_i_16_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Int16List; }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<(String,bool)> dco_decode_list_record_string_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_bool).toList(); }
+
+@protected List<(String,String)> dco_decode_list_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_record_string_string).toList(); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected FlutterConnectionState? dco_decode_opt_box_autoadd_flutter_connection_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_connection_state(raw); }
+
+@protected FlutterEndpointConfig? dco_decode_opt_box_autoadd_flutter_endpoint_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_endpoint_config(raw); }
+
+@protected FlutterMoqObject? dco_decode_opt_box_autoadd_flutter_moq_object(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_moq_object(raw); }
+
+@protected FlutterNamespaceAnnouncement? dco_decode_opt_box_autoadd_flutter_namespace_announcement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_namespace_announcement(raw); }
+
+@protected FlutterPublisherStatus? dco_decode_opt_box_autoadd_flutter_publisher_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_publisher_status(raw); }
+
+@protected FlutterReceivedVideoFrame? dco_decode_opt_box_autoadd_flutter_received_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_received_video_frame(raw); }
+
+@protected FlutterSubscriberStatus? dco_decode_opt_box_autoadd_flutter_subscriber_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_subscriber_status(raw); }
+
+@protected FlutterTicketInfo? dco_decode_opt_box_autoadd_flutter_ticket_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_ticket_info(raw); }
+
+@protected FlutterVideoFrame? dco_decode_opt_box_autoadd_flutter_video_frame(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_video_frame(raw); }
+
+@protected FlutterViewer? dco_decode_opt_box_autoadd_flutter_viewer(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_flutter_viewer(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_32(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected Quality dco_decode_quality(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Quality.values[raw as int]; }
+
+@protected QualityConstraints dco_decode_quality_constraints(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return QualityConstraints(width: dco_decode_u_32(arr[0]),
+height: dco_decode_u_32(arr[1]),
+framerate: dco_decode_u_32(arr[2]),
+audioBitrate: dco_decode_u_32(arr[3]),); }
+
+@protected (String,bool) dco_decode_record_string_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_bool(arr[1]),); }
+
+@protected (String,String) dco_decode_record_string_string(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_String(arr[0]),dco_decode_String(arr[1]),); }
+
+@protected (int,int) dco_decode_record_u_32_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_32(arr[0]),dco_decode_u_32(arr[1]),); }
+
+@protected (int,int,int) dco_decode_record_u_8_u_8_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 3) {
+                throw Exception('Expected 3 elements, got ${arr.length}');
+            }
+            return (dco_decode_u_8(arr[0]),dco_decode_u_8(arr[1]),dco_decode_u_8(arr[2]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected Map<String, String> sse_decode_Map_String_String_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_string(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected Map<String, bool> sse_decode_Map_String_bool_None(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_record_string_bool(deserializer);
+        return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2))); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FlutterAccessPolicy sse_decode_box_autoadd_flutter_access_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_access_policy(deserializer)); }
+
+@protected FlutterAudioSamples sse_decode_box_autoadd_flutter_audio_samples(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_audio_samples(deserializer)); }
+
+@protected FlutterBroadcastCatalog sse_decode_box_autoadd_flutter_broadcast_catalog(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_broadcast_catalog(deserializer)); }
+
+@protected FlutterCatalog sse_decode_box_autoadd_flutter_catalog(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_catalog(deserializer)); }
+
+@protected FlutterConnectionState sse_decode_box_autoadd_flutter_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_connection_state(deserializer)); }
+
+@protected FlutterConnectionStats sse_decode_box_autoadd_flutter_connection_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_connection_stats(deserializer)); }
+
+@protected FlutterEncodedAudioPacket sse_decode_box_autoadd_flutter_encoded_audio_packet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_encoded_audio_packet(deserializer)); }
+
+@protected FlutterEncodedVideoPacket sse_decode_box_autoadd_flutter_encoded_video_packet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_encoded_video_packet(deserializer)); }
+
+@protected FlutterEndpointConfig sse_decode_box_autoadd_flutter_endpoint_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_endpoint_config(deserializer)); }
+
+@protected FlutterFilterType sse_decode_box_autoadd_flutter_filter_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_filter_type(deserializer)); }
+
+@protected FlutterMoqObject sse_decode_box_autoadd_flutter_moq_object(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_moq_object(deserializer)); }
+
+@protected FlutterNamespaceAnnouncement sse_decode_box_autoadd_flutter_namespace_announcement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_namespace_announcement(deserializer)); }
+
+@protected FlutterPublisherStatus sse_decode_box_autoadd_flutter_publisher_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_publisher_status(deserializer)); }
+
+@protected FlutterReceivedVideoFrame sse_decode_box_autoadd_flutter_received_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_received_video_frame(deserializer)); }
+
+@protected FlutterSubscriberStatus sse_decode_box_autoadd_flutter_subscriber_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_subscriber_status(deserializer)); }
+
+@protected FlutterTicketInfo sse_decode_box_autoadd_flutter_ticket_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_ticket_info(deserializer)); }
+
+@protected FlutterVideoFrame sse_decode_box_autoadd_flutter_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_video_frame(deserializer)); }
+
+@protected FlutterViewer sse_decode_box_autoadd_flutter_viewer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_flutter_viewer(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_32(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FlutterAbrEvent sse_decode_flutter_abr_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_fromTrack = sse_decode_String(deserializer);
+var var_toTrack = sse_decode_String(deserializer);
+var var_reason = sse_decode_String(deserializer);
+var var_throughputBps = sse_decode_u_64(deserializer);
+var var_rttMs = sse_decode_u_32(deserializer);
+var var_lossPercent = sse_decode_f_32(deserializer);
+var var_lateFrames = sse_decode_u_64(deserializer);
+return FlutterAbrEvent(fromTrack: var_fromTrack, toTrack: var_toTrack, reason: var_reason, throughputBps: var_throughputBps, rttMs: var_rttMs, lossPercent: var_lossPercent, lateFrames: var_lateFrames); }
+
+@protected FlutterAccessPolicy sse_decode_flutter_access_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_requireToken = sse_decode_bool(deserializer);
+var var_allow = sse_decode_list_String(deserializer);
+var var_deny = sse_decode_list_String(deserializer);
+return FlutterAccessPolicy(requireToken: var_requireToken, allow: var_allow, deny: var_deny); }
+
+@protected FlutterAudioCodec sse_decode_flutter_audio_codec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterAudioCodec.values[inner]; }
+
+@protected FlutterAudioRendition sse_decode_flutter_audio_rendition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_16(deserializer);
+var var_bitrate = sse_decode_u_32(deserializer);
+var var_codec = sse_decode_String(deserializer);
+return FlutterAudioRendition(name: var_name, sampleRate: var_sampleRate, channels: var_channels, bitrate: var_bitrate, codec: var_codec); }
+
+@protected FlutterAudioSamples sse_decode_flutter_audio_samples(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_16(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_format = sse_decode_String(deserializer);
+return FlutterAudioSamples(data: var_data, sampleRate: var_sampleRate, channels: var_channels, timestampMs: var_timestampMs, format: var_format); }
+
+@protected FlutterBroadcastCatalog sse_decode_flutter_broadcast_catalog(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_broadcastId = sse_decode_String(deserializer);
+var var_videoTracks = sse_decode_list_flutter_track_info(deserializer);
+var var_audioTracks = sse_decode_list_flutter_track_info(deserializer);
+var var_createdAtMs = sse_decode_u_64(deserializer);
+return FlutterBroadcastCatalog(broadcastId: var_broadcastId, videoTracks: var_videoTracks, audioTracks: var_audioTracks, createdAtMs: var_createdAtMs); }
+
+@protected FlutterCaptureDevice sse_decode_flutter_capture_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_id = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_deviceType = sse_decode_String(deserializer);
+var var_isDefault = sse_decode_bool(deserializer);
+return FlutterCaptureDevice(id: var_id, name: var_name, deviceType: var_deviceType, isDefault: var_isDefault); }
+
+@protected FlutterCatalog sse_decode_flutter_catalog(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_description = sse_decode_opt_String(deserializer);
+var var_videoTracks = sse_decode_list_flutter_video_track(deserializer);
+var var_isLive = sse_decode_bool(deserializer);
+var var_durationSecs = sse_decode_opt_box_autoadd_f_64(deserializer);
+return FlutterCatalog(name: var_name, description: var_description, videoTracks: var_videoTracks, isLive: var_isLive, durationSecs: var_durationSecs); }
+
+@protected FlutterChatMessage sse_decode_flutter_chat_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_seq = sse_decode_u_64(deserializer);
+var var_sender = sse_decode_String(deserializer);
+var var_displayName = sse_decode_opt_String(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_kind = sse_decode_String(deserializer);
+var var_text = sse_decode_String(deserializer);
+var var_reactionTo = sse_decode_opt_box_autoadd_u_64(deserializer);
+return FlutterChatMessage(seq: var_seq, sender: var_sender, displayName: var_displayName, timestampMs: var_timestampMs, kind: var_kind, text: var_text, reactionTo: var_reactionTo); }
+
+@protected FlutterConnectionState sse_decode_flutter_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_String(deserializer);
+var var_attempt = sse_decode_u_32(deserializer);
+var var_retryInMs = sse_decode_u_64(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return FlutterConnectionState(state: var_state, attempt: var_attempt, retryInMs: var_retryInMs, error: var_error); }
+
+@protected FlutterConnectionStats sse_decode_flutter_connection_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_chunksSent = sse_decode_u_64(deserializer);
+var var_chunksReceived = sse_decode_u_64(deserializer);
+var var_bandwidthBps = sse_decode_u_64(deserializer);
+var var_rttMs = sse_decode_u_64(deserializer);
+var var_qualityScore = sse_decode_u_32(deserializer);
+var var_recommendedQuality = sse_decode_flutter_video_quality(deserializer);
+return FlutterConnectionStats(bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, chunksSent: var_chunksSent, chunksReceived: var_chunksReceived, bandwidthBps: var_bandwidthBps, rttMs: var_rttMs, qualityScore: var_qualityScore, recommendedQuality: var_recommendedQuality); }
+
+@protected FlutterDecodedAudioFrame sse_decode_flutter_decoded_audio_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_samples = sse_decode_list_prim_i_16_strict(deserializer);
+var var_ptsUs = sse_decode_i_64(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_32(deserializer);
+return FlutterDecodedAudioFrame(samples: var_samples, ptsUs: var_ptsUs, sampleRate: var_sampleRate, channels: var_channels); }
+
+@protected FlutterDecodedVideoFrame sse_decode_flutter_decoded_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_ptsUs = sse_decode_i_64(deserializer);
+var var_isKeyframe = sse_decode_bool(deserializer);
+return FlutterDecodedVideoFrame(data: var_data, width: var_width, height: var_height, ptsUs: var_ptsUs, isKeyframe: var_isKeyframe); }
+
+@protected FlutterDirectEvent sse_decode_flutter_direct_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_endpointId = sse_decode_String(deserializer);
+return FlutterDirectEvent_PeerConnected(endpointId: var_endpointId);case 1: var var_endpointId = sse_decode_String(deserializer);
+return FlutterDirectEvent_PeerDisconnected(endpointId: var_endpointId);case 2: var var_from = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterDirectEvent_RequestMetadata(from: var_from, timestamp: var_timestamp);case 3: var var_from = sse_decode_String(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_fileSize = sse_decode_u_64(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_totalChunks = sse_decode_u_32(deserializer);
+var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterDirectEvent_Metadata(from: var_from, fileName: var_fileName, fileSize: var_fileSize, mimeType: var_mimeType, totalChunks: var_totalChunks, duration: var_duration, timestamp: var_timestamp);case 4: var var_from = sse_decode_String(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterDirectEvent_RequestChunk(from: var_from, index: var_index, timestamp: var_timestamp);case 5: var var_from = sse_decode_String(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterDirectEvent_Chunk(from: var_from, index: var_index, data: var_data, timestamp: var_timestamp);case 6: var var_from = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterDirectEvent_Presence(from: var_from, name: var_name, timestamp: var_timestamp);case 7: var var_from = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterDirectEvent_Signal(from: var_from, data: var_data, timestamp: var_timestamp);case 8: var var_message = sse_decode_String(deserializer);
+return FlutterDirectEvent_Error(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected FlutterDiscoveryEvent sse_decode_flutter_discovery_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_String(deserializer);
+var var_broadcastName = sse_decode_String(deserializer);
+return FlutterDiscoveryEvent(kind: var_kind, broadcastName: var_broadcastName); }
+
+@protected FlutterEncodedAudioFrame sse_decode_flutter_encoded_audio_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_ptsUs = sse_decode_i_64(deserializer);
+var var_durationUs = sse_decode_i_64(deserializer);
+var var_samples = sse_decode_u_32(deserializer);
+var var_codec = sse_decode_flutter_audio_codec(deserializer);
+return FlutterEncodedAudioFrame(data: var_data, ptsUs: var_ptsUs, durationUs: var_durationUs, samples: var_samples, codec: var_codec); }
+
+@protected FlutterEncodedAudioPacket sse_decode_flutter_encoded_audio_packet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_codec = sse_decode_String(deserializer);
+var var_sampleRate = sse_decode_u_32(deserializer);
+var var_channels = sse_decode_u_16(deserializer);
+return FlutterEncodedAudioPacket(data: var_data, timestampMs: var_timestampMs, codec: var_codec, sampleRate: var_sampleRate, channels: var_channels); }
+
+@protected FlutterEncodedVideoFrame sse_decode_flutter_encoded_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_ptsUs = sse_decode_i_64(deserializer);
+var var_dtsUs = sse_decode_i_64(deserializer);
+var var_isKeyframe = sse_decode_bool(deserializer);
+var var_durationUs = sse_decode_i_64(deserializer);
+var var_frameIndex = sse_decode_u_64(deserializer);
+var var_codec = sse_decode_flutter_video_codec(deserializer);
+var var_moqPriority = sse_decode_u_8(deserializer);
+var var_moqTtlMs = sse_decode_u_64(deserializer);
+return FlutterEncodedVideoFrame(data: var_data, ptsUs: var_ptsUs, dtsUs: var_dtsUs, isKeyframe: var_isKeyframe, durationUs: var_durationUs, frameIndex: var_frameIndex, codec: var_codec, moqPriority: var_moqPriority, moqTtlMs: var_moqTtlMs); }
+
+@protected FlutterEncodedVideoPacket sse_decode_flutter_encoded_video_packet(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_isKeyframe = sse_decode_bool(deserializer);
+var var_codec = sse_decode_String(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+return FlutterEncodedVideoPacket(data: var_data, timestampMs: var_timestampMs, isKeyframe: var_isKeyframe, codec: var_codec, width: var_width, height: var_height); }
+
+@protected FlutterEncoderPreset sse_decode_flutter_encoder_preset(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterEncoderPreset.values[inner]; }
+
+@protected FlutterEndpointConfig sse_decode_flutter_endpoint_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_relayMode = sse_decode_flutter_relay_mode(deserializer);
+var var_bindPort = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_localDiscovery = sse_decode_bool(deserializer);
+return FlutterEndpointConfig(relayMode: var_relayMode, bindPort: var_bindPort, localDiscovery: var_localDiscovery); }
+
+@protected FlutterFilterType sse_decode_flutter_filter_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return FlutterFilterType_LatestGroup();case 1: return FlutterFilterType_LatestObject();case 2: return FlutterFilterType_NextGroup();case 3: var var_startGroup = sse_decode_u_64(deserializer);
+var var_startObject = sse_decode_u_64(deserializer);
+return FlutterFilterType_AbsoluteStart(startGroup: var_startGroup, startObject: var_startObject);case 4: var var_startGroup = sse_decode_u_64(deserializer);
+var var_startObject = sse_decode_u_64(deserializer);
+var var_endGroup = sse_decode_u_64(deserializer);
+var var_endObject = sse_decode_opt_box_autoadd_u_64(deserializer);
+return FlutterFilterType_AbsoluteRange(startGroup: var_startGroup, startObject: var_startObject, endGroup: var_endGroup, endObject: var_endObject); default: throw UnimplementedError(''); }
+             }
+
+@protected FlutterGroupOrder sse_decode_flutter_group_order(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterGroupOrder.values[inner]; }
+
+@protected FlutterHardwareAccel sse_decode_flutter_hardware_accel(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterHardwareAccel.values[inner]; }
+
+@protected FlutterIssuedTicket sse_decode_flutter_issued_ticket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ticket = sse_decode_String(deserializer);
+var var_tokenId = sse_decode_u_64(deserializer);
+var var_expiresAt = sse_decode_u_64(deserializer);
+return FlutterIssuedTicket(ticket: var_ticket, tokenId: var_tokenId, expiresAt: var_expiresAt); }
+
+@protected FlutterLiveEvent sse_decode_flutter_live_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_peerId = sse_decode_String(deserializer);
+return FlutterLiveEvent_PeerConnected(peerId: var_peerId);case 1: var var_peerId = sse_decode_String(deserializer);
+return FlutterLiveEvent_PeerDisconnected(peerId: var_peerId);case 2: var var_catalog = sse_decode_box_autoadd_flutter_catalog(deserializer);
+return FlutterLiveEvent_CatalogReceived(catalog: var_catalog);case 3: var var_from = sse_decode_String(deserializer);
+var var_fileName = sse_decode_String(deserializer);
+var var_fileSize = sse_decode_u_64(deserializer);
+var var_mimeType = sse_decode_String(deserializer);
+var var_totalChunks = sse_decode_u_32(deserializer);
+var var_duration = sse_decode_opt_box_autoadd_f_64(deserializer);
+return FlutterLiveEvent_MetadataReceived(from: var_from, fileName: var_fileName, fileSize: var_fileSize, mimeType: var_mimeType, totalChunks: var_totalChunks, duration: var_duration);case 4: var var_from = sse_decode_String(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+return FlutterLiveEvent_ChunkRequested(from: var_from, index: var_index);case 5: var var_from = sse_decode_String(deserializer);
+var var_index = sse_decode_u_32(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return FlutterLiveEvent_ChunkReceived(from: var_from, index: var_index, data: var_data);case 6: var var_from = sse_decode_String(deserializer);
+return FlutterLiveEvent_MetadataRequested(from: var_from);case 7: var var_stats = sse_decode_box_autoadd_flutter_connection_stats(deserializer);
+return FlutterLiveEvent_StatsUpdated(stats: var_stats);case 8: var var_message = sse_decode_String(deserializer);
+return FlutterLiveEvent_Error(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected FlutterMoqObject sse_decode_flutter_moq_object(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_groupId = sse_decode_u_64(deserializer);
+var var_subgroupId = sse_decode_u_64(deserializer);
+var var_objectId = sse_decode_u_64(deserializer);
+var var_publisherPriority = sse_decode_u_8(deserializer);
+var var_expiresAt = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_status = sse_decode_flutter_object_status(deserializer);
+var var_payload = sse_decode_list_prim_u_8_strict(deserializer);
+var var_createdAt = sse_decode_u_64(deserializer);
+var var_isExpired = sse_decode_bool(deserializer);
+return FlutterMoqObject(groupId: var_groupId, subgroupId: var_subgroupId, objectId: var_objectId, publisherPriority: var_publisherPriority, expiresAt: var_expiresAt, status: var_status, payload: var_payload, createdAt: var_createdAt, isExpired: var_isExpired); }
+
+@protected FlutterMoqStats sse_decode_flutter_moq_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_schedulerQueueLength = sse_decode_u_32(deserializer);
+var var_namespaceCount = sse_decode_u_32(deserializer);
+return FlutterMoqStats(schedulerQueueLength: var_schedulerQueueLength, namespaceCount: var_namespaceCount); }
+
+@protected FlutterNamespaceAnnouncement sse_decode_flutter_namespace_announcement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_namespacePath = sse_decode_String(deserializer);
+var var_tracks = sse_decode_list_String(deserializer);
+var var_acceptsPublishing = sse_decode_bool(deserializer);
+return FlutterNamespaceAnnouncement(namespacePath: var_namespacePath, tracks: var_tracks, acceptsPublishing: var_acceptsPublishing); }
+
+@protected FlutterObjectStatus sse_decode_flutter_object_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterObjectStatus.values[inner]; }
+
+@protected FlutterPublisherStatus sse_decode_flutter_publisher_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_publisherId = sse_decode_String(deserializer);
+var var_isActive = sse_decode_bool(deserializer);
+var var_framesPublished = sse_decode_u_64(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_currentBitrate = sse_decode_u_32(deserializer);
+var var_videoRenditions = sse_decode_list_String(deserializer);
+var var_audioRenditions = sse_decode_list_String(deserializer);
+return FlutterPublisherStatus(publisherId: var_publisherId, isActive: var_isActive, framesPublished: var_framesPublished, bytesSent: var_bytesSent, currentBitrate: var_currentBitrate, videoRenditions: var_videoRenditions, audioRenditions: var_audioRenditions); }
+
+@protected FlutterReceivedVideoFrame sse_decode_flutter_received_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_width = sse_decode_i_32(deserializer);
+var var_height = sse_decode_i_32(deserializer);
+var var_isKeyframe = sse_decode_bool(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+return FlutterReceivedVideoFrame(timestampMs: var_timestampMs, width: var_width, height: var_height, isKeyframe: var_isKeyframe, data: var_data); }
+
+@protected FlutterRecordingStats sse_decode_flutter_recording_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_files = sse_decode_list_String(deserializer);
+var var_fragments = sse_decode_u_64(deserializer);
+var var_videoFrames = sse_decode_u_64(deserializer);
+var var_audioFrames = sse_decode_u_64(deserializer);
+var var_bytes = sse_decode_u_64(deserializer);
+return FlutterRecordingStats(files: var_files, fragments: var_fragments, videoFrames: var_videoFrames, audioFrames: var_audioFrames, bytes: var_bytes); }
+
+@protected FlutterRelayInfo sse_decode_flutter_relay_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_broadcastName = sse_decode_String(deserializer);
+var var_route = sse_decode_list_String(deserializer);
+return FlutterRelayInfo(broadcastName: var_broadcastName, route: var_route); }
+
+@protected FlutterRelayMode sse_decode_flutter_relay_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return FlutterRelayMode_Default();case 1: var var_url = sse_decode_String(deserializer);
+return FlutterRelayMode_Custom(url: var_url);case 2: return FlutterRelayMode_Disabled(); default: throw UnimplementedError(''); }
+             }
+
+@protected FlutterRenditionStats sse_decode_flutter_rendition_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackName = sse_decode_String(deserializer);
+var var_framesWritten = sse_decode_u_64(deserializer);
+var var_bytesWritten = sse_decode_u_64(deserializer);
+var var_framesDropped = sse_decode_u_64(deserializer);
+var var_groupsCut = sse_decode_u_64(deserializer);
+return FlutterRenditionStats(trackName: var_trackName, framesWritten: var_framesWritten, bytesWritten: var_bytesWritten, framesDropped: var_framesDropped, groupsCut: var_groupsCut); }
+
+@protected FlutterRoomEvent sse_decode_flutter_room_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_String(deserializer);
+var var_member = sse_decode_String(deserializer);
+var var_subscriberId = sse_decode_String(deserializer);
+return FlutterRoomEvent(kind: var_kind, member: var_member, subscriberId: var_subscriberId); }
+
+@protected FlutterRosterEvent sse_decode_flutter_roster_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_kind = sse_decode_String(deserializer);
+var var_endpointId = sse_decode_String(deserializer);
+var var_viewer = sse_decode_opt_box_autoadd_flutter_viewer(deserializer);
+var var_kicked = sse_decode_bool(deserializer);
+return FlutterRosterEvent(kind: var_kind, endpointId: var_endpointId, viewer: var_viewer, kicked: var_kicked); }
+
+@protected FlutterStreamEvent sse_decode_flutter_stream_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_endpointId = sse_decode_String(deserializer);
+return FlutterStreamEvent_NeighborUp(endpointId: var_endpointId);case 1: var var_endpointId = sse_decode_String(deserializer);
+return FlutterStreamEvent_NeighborDown(endpointId: var_endpointId);case 2: var var_from = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterStreamEvent_Presence(from: var_from, name: var_name, timestamp: var_timestamp);case 3: var var_from = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_sequence = sse_decode_u_64(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterStreamEvent_MediaChunk(from: var_from, data: var_data, sequence: var_sequence, timestamp: var_timestamp);case 4: var var_from = sse_decode_String(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_timestamp = sse_decode_u_64(deserializer);
+return FlutterStreamEvent_Signal(from: var_from, data: var_data, timestamp: var_timestamp);case 5: return FlutterStreamEvent_Lagged();case 6: var var_message = sse_decode_String(deserializer);
+return FlutterStreamEvent_Error(message: var_message); default: throw UnimplementedError(''); }
+             }
+
+@protected FlutterSubscriberStatus sse_decode_flutter_subscriber_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subscriberId = sse_decode_String(deserializer);
+var var_broadcastId = sse_decode_String(deserializer);
+var var_isConnected = sse_decode_bool(deserializer);
+var var_framesReceived = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_currentQuality = sse_decode_String(deserializer);
+var var_bufferHealth = sse_decode_f_32(deserializer);
+return FlutterSubscriberStatus(subscriberId: var_subscriberId, broadcastId: var_broadcastId, isConnected: var_isConnected, framesReceived: var_framesReceived, bytesReceived: var_bytesReceived, currentQuality: var_currentQuality, bufferHealth: var_bufferHealth); }
+
+@protected FlutterTicketInfo sse_decode_flutter_ticket_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_broadcastName = sse_decode_String(deserializer);
+var var_endpointId = sse_decode_String(deserializer);
+var var_ticketString = sse_decode_String(deserializer);
+return FlutterTicketInfo(broadcastName: var_broadcastName, endpointId: var_endpointId, ticketString: var_ticketString); }
+
+@protected FlutterTrackInfo sse_decode_flutter_track_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackId = sse_decode_String(deserializer);
+var var_name = sse_decode_String(deserializer);
+var var_codec = sse_decode_String(deserializer);
+var var_bitrate = sse_decode_u_32(deserializer);
+var var_extra = sse_decode_Map_String_String_None(deserializer);
+return FlutterTrackInfo(trackId: var_trackId, name: var_name, codec: var_codec, bitrate: var_bitrate, extra: var_extra); }
+
+@protected FlutterTrackStatus sse_decode_flutter_track_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_trackPath = sse_decode_String(deserializer);
+var var_status = sse_decode_flutter_track_status_code(deserializer);
+var var_latestGroupId = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_latestObjectId = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_groupOrder = sse_decode_flutter_group_order(deserializer);
+var var_publisherPriority = sse_decode_u_8(deserializer);
+return FlutterTrackStatus(trackPath: var_trackPath, status: var_status, latestGroupId: var_latestGroupId, latestObjectId: var_latestObjectId, groupOrder: var_groupOrder, publisherPriority: var_publisherPriority); }
+
+@protected FlutterTrackStatusCode sse_decode_flutter_track_status_code(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterTrackStatusCode.values[inner]; }
+
+@protected FlutterVideoCodec sse_decode_flutter_video_codec(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterVideoCodec.values[inner]; }
+
+@protected FlutterVideoFrame sse_decode_flutter_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_data = sse_decode_list_prim_u_8_strict(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_format = sse_decode_String(deserializer);
+return FlutterVideoFrame(width: var_width, height: var_height, data: var_data, timestampMs: var_timestampMs, format: var_format); }
+
+@protected FlutterVideoQuality sse_decode_flutter_video_quality(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterVideoQuality.values[inner]; }
+
+@protected FlutterVideoQualityFfmpeg sse_decode_flutter_video_quality_ffmpeg(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return FlutterVideoQualityFfmpeg.values[inner]; }
+
+@protected FlutterVideoRendition sse_decode_flutter_video_rendition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_fps = sse_decode_u_32(deserializer);
+var var_bitrate = sse_decode_u_32(deserializer);
+var var_codec = sse_decode_String(deserializer);
+return FlutterVideoRendition(name: var_name, width: var_width, height: var_height, fps: var_fps, bitrate: var_bitrate, codec: var_codec); }
+
+@protected FlutterVideoTrack sse_decode_flutter_video_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_name = sse_decode_String(deserializer);
+var var_quality = sse_decode_flutter_video_quality(deserializer);
+var var_codec = sse_decode_String(deserializer);
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_fps = sse_decode_u_32(deserializer);
+var var_bitrateKbps = sse_decode_u_32(deserializer);
+return FlutterVideoTrack(name: var_name, quality: var_quality, codec: var_codec, width: var_width, height: var_height, fps: var_fps, bitrateKbps: var_bitrateKbps); }
+
+@protected FlutterViewer sse_decode_flutter_viewer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_endpointId = sse_decode_String(deserializer);
+var var_displayName = sse_decode_opt_String(deserializer);
+var var_connectedAtMs = sse_decode_u_64(deserializer);
+var var_tracks = sse_decode_list_String(deserializer);
+var var_bytesSent = sse_decode_u_64(deserializer);
+var var_bytesReceived = sse_decode_u_64(deserializer);
+var var_rttMs = sse_decode_u_32(deserializer);
+return FlutterViewer(endpointId: var_endpointId, displayName: var_displayName, connectedAtMs: var_connectedAtMs, tracks: var_tracks, bytesSent: var_bytesSent, bytesReceived: var_bytesReceived, rttMs: var_rttMs); }
+
+@protected int sse_decode_i_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt16(); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterAbrEvent> sse_decode_list_flutter_abr_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterAbrEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_abr_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterAudioRendition> sse_decode_list_flutter_audio_rendition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterAudioRendition>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_audio_rendition(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterCaptureDevice> sse_decode_list_flutter_capture_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterCaptureDevice>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_capture_device(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterChatMessage> sse_decode_list_flutter_chat_message(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterChatMessage>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_chat_message(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterConnectionState> sse_decode_list_flutter_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterConnectionState>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_connection_state(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterDirectEvent> sse_decode_list_flutter_direct_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterDirectEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_direct_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterDiscoveryEvent> sse_decode_list_flutter_discovery_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterDiscoveryEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_discovery_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterEncodedVideoFrame> sse_decode_list_flutter_encoded_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterEncodedVideoFrame>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_encoded_video_frame(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterLiveEvent> sse_decode_list_flutter_live_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterLiveEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_live_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterMoqObject> sse_decode_list_flutter_moq_object(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterMoqObject>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_moq_object(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterNamespaceAnnouncement> sse_decode_list_flutter_namespace_announcement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterNamespaceAnnouncement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_namespace_announcement(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterRelayInfo> sse_decode_list_flutter_relay_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterRelayInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_relay_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterRenditionStats> sse_decode_list_flutter_rendition_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterRenditionStats>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_rendition_stats(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterRoomEvent> sse_decode_list_flutter_room_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterRoomEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_room_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterRosterEvent> sse_decode_list_flutter_roster_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterRosterEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_roster_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterStreamEvent> sse_decode_list_flutter_stream_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterStreamEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_stream_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterTrackInfo> sse_decode_list_flutter_track_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterTrackInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_track_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterVideoQuality> sse_decode_list_flutter_video_quality(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterVideoQuality>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_video_quality(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterVideoRendition> sse_decode_list_flutter_video_rendition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterVideoRendition>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_video_rendition(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterVideoTrack> sse_decode_list_flutter_video_track(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterVideoTrack>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_video_track(deserializer)); }
+        return ans_;
+         }
+
+@protected List<FlutterViewer> sse_decode_list_flutter_viewer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <FlutterViewer>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_flutter_viewer(deserializer)); }
+        return ans_;
+         }
+
+@protected List<int> sse_decode_list_prim_i_16_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt16List(len_); }
+
+@protected Int16List sse_decode_list_prim_i_16_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getInt16List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<(String,bool)> sse_decode_list_record_string_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,bool)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_bool(deserializer)); }
+        return ans_;
+         }
+
+@protected List<(String,String)> sse_decode_list_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <(String,String)>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_record_string_string(deserializer)); }
+        return ans_;
+         }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterConnectionState? sse_decode_opt_box_autoadd_flutter_connection_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_connection_state(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterEndpointConfig? sse_decode_opt_box_autoadd_flutter_endpoint_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_endpoint_config(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterMoqObject? sse_decode_opt_box_autoadd_flutter_moq_object(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_moq_object(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterNamespaceAnnouncement? sse_decode_opt_box_autoadd_flutter_namespace_announcement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_namespace_announcement(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterPublisherStatus? sse_decode_opt_box_autoadd_flutter_publisher_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_publisher_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterReceivedVideoFrame? sse_decode_opt_box_autoadd_flutter_received_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_received_video_frame(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterSubscriberStatus? sse_decode_opt_box_autoadd_flutter_subscriber_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_subscriber_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterTicketInfo? sse_decode_opt_box_autoadd_flutter_ticket_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_ticket_info(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterVideoFrame? sse_decode_opt_box_autoadd_flutter_video_frame(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_video_frame(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FlutterViewer? sse_decode_opt_box_autoadd_flutter_viewer(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_flutter_viewer(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected Quality sse_decode_quality(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Quality.values[inner]; }
+
+@protected QualityConstraints sse_decode_quality_constraints(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_width = sse_decode_u_32(deserializer);
+var var_height = sse_decode_u_32(deserializer);
+var var_framerate = sse_decode_u_32(deserializer);
+var var_audioBitrate = sse_decode_u_32(deserializer);
+return QualityConstraints(width: var_width, height: var_height, framerate: var_framerate, audioBitrate: var_audioBitrate); }
+
+@protected (String,bool) sse_decode_record_string_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_bool(deserializer);
+return (var_field0, var_field1); }
+
+@protected (String,String) sse_decode_record_string_string(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_String(deserializer);
+var var_field1 = sse_decode_String(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,int) sse_decode_record_u_32_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_32(deserializer);
+var var_field1 = sse_decode_u_32(deserializer);
+return (var_field0, var_field1); }
+
+@protected (int,int,int) sse_decode_record_u_8_u_8_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_u_8(deserializer);
+var var_field1 = sse_decode_u_8(deserializer);
+var var_field2 = sse_decode_u_8(deserializer);
+return (var_field0, var_field1, var_field2); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_Map_String_String_None(Map<String, String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_string(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_Map_String_bool_None(Map<String, bool> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_record_string_bool(self.entries.map((e) => (e.key, e.value)).toList(), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_access_policy(FlutterAccessPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_access_policy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_audio_samples(FlutterAudioSamples self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_audio_samples(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_broadcast_catalog(FlutterBroadcastCatalog self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_broadcast_catalog(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_catalog(FlutterCatalog self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_catalog(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_connection_state(FlutterConnectionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_connection_state(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_connection_stats(FlutterConnectionStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_connection_stats(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_encoded_audio_packet(FlutterEncodedAudioPacket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_encoded_audio_packet(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_encoded_video_packet(FlutterEncodedVideoPacket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_encoded_video_packet(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_endpoint_config(FlutterEndpointConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_endpoint_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_filter_type(FlutterFilterType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_filter_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_moq_object(FlutterMoqObject self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_moq_object(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_namespace_announcement(FlutterNamespaceAnnouncement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_namespace_announcement(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_publisher_status(FlutterPublisherStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_publisher_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_received_video_frame(FlutterReceivedVideoFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_received_video_frame(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_subscriber_status(FlutterSubscriberStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_subscriber_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_ticket_info(FlutterTicketInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_ticket_info(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_video_frame(FlutterVideoFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_video_frame(self, serializer); }
+
+@protected void sse_encode_box_autoadd_flutter_viewer(FlutterViewer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_viewer(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_flutter_abr_event(FlutterAbrEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.fromTrack, serializer);
+sse_encode_String(self.toTrack, serializer);
+sse_encode_String(self.reason, serializer);
+sse_encode_u_64(self.throughputBps, serializer);
+sse_encode_u_32(self.rttMs, serializer);
+sse_encode_f_32(self.lossPercent, serializer);
+sse_encode_u_64(self.lateFrames, serializer);
+ }
+
+@protected void sse_encode_flutter_access_policy(FlutterAccessPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.requireToken, serializer);
+sse_encode_list_String(self.allow, serializer);
+sse_encode_list_String(self.deny, serializer);
+ }
+
+@protected void sse_encode_flutter_audio_codec(FlutterAudioCodec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_audio_rendition(FlutterAudioRendition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_16(self.channels, serializer);
+sse_encode_u_32(self.bitrate, serializer);
+sse_encode_String(self.codec, serializer);
+ }
+
+@protected void sse_encode_flutter_audio_samples(FlutterAudioSamples self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_16(self.channels, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_String(self.format, serializer);
+ }
+
+@protected void sse_encode_flutter_broadcast_catalog(FlutterBroadcastCatalog self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.broadcastId, serializer);
+sse_encode_list_flutter_track_info(self.videoTracks, serializer);
+sse_encode_list_flutter_track_info(self.audioTracks, serializer);
+sse_encode_u_64(self.createdAtMs, serializer);
+ }
+
+@protected void sse_encode_flutter_capture_device(FlutterCaptureDevice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.id, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.deviceType, serializer);
+sse_encode_bool(self.isDefault, serializer);
+ }
+
+@protected void sse_encode_flutter_catalog(FlutterCatalog self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_opt_String(self.description, serializer);
+sse_encode_list_flutter_video_track(self.videoTracks, serializer);
+sse_encode_bool(self.isLive, serializer);
+sse_encode_opt_box_autoadd_f_64(self.durationSecs, serializer);
+ }
+
+@protected void sse_encode_flutter_chat_message(FlutterChatMessage self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.seq, serializer);
+sse_encode_String(self.sender, serializer);
+sse_encode_opt_String(self.displayName, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_String(self.kind, serializer);
+sse_encode_String(self.text, serializer);
+sse_encode_opt_box_autoadd_u_64(self.reactionTo, serializer);
+ }
+
+@protected void sse_encode_flutter_connection_state(FlutterConnectionState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.state, serializer);
+sse_encode_u_32(self.attempt, serializer);
+sse_encode_u_64(self.retryInMs, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_flutter_connection_stats(FlutterConnectionStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_u_64(self.chunksSent, serializer);
+sse_encode_u_64(self.chunksReceived, serializer);
+sse_encode_u_64(self.bandwidthBps, serializer);
+sse_encode_u_64(self.rttMs, serializer);
+sse_encode_u_32(self.qualityScore, serializer);
+sse_encode_flutter_video_quality(self.recommendedQuality, serializer);
+ }
+
+@protected void sse_encode_flutter_decoded_audio_frame(FlutterDecodedAudioFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_i_16_strict(self.samples, serializer);
+sse_encode_i_64(self.ptsUs, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_32(self.channels, serializer);
+ }
+
+@protected void sse_encode_flutter_decoded_video_frame(FlutterDecodedVideoFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_i_64(self.ptsUs, serializer);
+sse_encode_bool(self.isKeyframe, serializer);
+ }
+
+@protected void sse_encode_flutter_direct_event(FlutterDirectEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FlutterDirectEvent_PeerConnected(endpointId: final endpointId): sse_encode_i_32(0, serializer); sse_encode_String(endpointId, serializer);
+case FlutterDirectEvent_PeerDisconnected(endpointId: final endpointId): sse_encode_i_32(1, serializer); sse_encode_String(endpointId, serializer);
+case FlutterDirectEvent_RequestMetadata(from: final from,timestamp: final timestamp): sse_encode_i_32(2, serializer); sse_encode_String(from, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterDirectEvent_Metadata(from: final from,fileName: final fileName,fileSize: final fileSize,mimeType: final mimeType,totalChunks: final totalChunks,duration: final duration,timestamp: final timestamp): sse_encode_i_32(3, serializer); sse_encode_String(from, serializer);
+sse_encode_String(fileName, serializer);
+sse_encode_u_64(fileSize, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_u_32(totalChunks, serializer);
+sse_encode_opt_box_autoadd_f_64(duration, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterDirectEvent_RequestChunk(from: final from,index: final index,timestamp: final timestamp): sse_encode_i_32(4, serializer); sse_encode_String(from, serializer);
+sse_encode_u_32(index, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterDirectEvent_Chunk(from: final from,index: final index,data: final data,timestamp: final timestamp): sse_encode_i_32(5, serializer); sse_encode_String(from, serializer);
+sse_encode_u_32(index, serializer);
+sse_encode_list_prim_u_8_strict(data, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterDirectEvent_Presence(from: final from,name: final name,timestamp: final timestamp): sse_encode_i_32(6, serializer); sse_encode_String(from, serializer);
+sse_encode_String(name, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterDirectEvent_Signal(from: final from,data: final data,timestamp: final timestamp): sse_encode_i_32(7, serializer); sse_encode_String(from, serializer);
+sse_encode_list_prim_u_8_strict(data, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterDirectEvent_Error(message: final message): sse_encode_i_32(8, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_flutter_discovery_event(FlutterDiscoveryEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.kind, serializer);
+sse_encode_String(self.broadcastName, serializer);
+ }
+
+@protected void sse_encode_flutter_encoded_audio_frame(FlutterEncodedAudioFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_i_64(self.ptsUs, serializer);
+sse_encode_i_64(self.durationUs, serializer);
+sse_encode_u_32(self.samples, serializer);
+sse_encode_flutter_audio_codec(self.codec, serializer);
+ }
+
+@protected void sse_encode_flutter_encoded_audio_packet(FlutterEncodedAudioPacket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_String(self.codec, serializer);
+sse_encode_u_32(self.sampleRate, serializer);
+sse_encode_u_16(self.channels, serializer);
+ }
+
+@protected void sse_encode_flutter_encoded_video_frame(FlutterEncodedVideoFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_i_64(self.ptsUs, serializer);
+sse_encode_i_64(self.dtsUs, serializer);
+sse_encode_bool(self.isKeyframe, serializer);
+sse_encode_i_64(self.durationUs, serializer);
+sse_encode_u_64(self.frameIndex, serializer);
+sse_encode_flutter_video_codec(self.codec, serializer);
+sse_encode_u_8(self.moqPriority, serializer);
+sse_encode_u_64(self.moqTtlMs, serializer);
+ }
+
+@protected void sse_encode_flutter_encoded_video_packet(FlutterEncodedVideoPacket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_bool(self.isKeyframe, serializer);
+sse_encode_String(self.codec, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+ }
+
+@protected void sse_encode_flutter_encoder_preset(FlutterEncoderPreset self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_endpoint_config(FlutterEndpointConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_flutter_relay_mode(self.relayMode, serializer);
+sse_encode_opt_box_autoadd_u_16(self.bindPort, serializer);
+sse_encode_bool(self.localDiscovery, serializer);
+ }
+
+@protected void sse_encode_flutter_filter_type(FlutterFilterType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FlutterFilterType_LatestGroup(): sse_encode_i_32(0, serializer); case FlutterFilterType_LatestObject(): sse_encode_i_32(1, serializer); case FlutterFilterType_NextGroup(): sse_encode_i_32(2, serializer); case FlutterFilterType_AbsoluteStart(startGroup: final startGroup,startObject: final startObject): sse_encode_i_32(3, serializer); sse_encode_u_64(startGroup, serializer);
+sse_encode_u_64(startObject, serializer);
+case FlutterFilterType_AbsoluteRange(startGroup: final startGroup,startObject: final startObject,endGroup: final endGroup,endObject: final endObject): sse_encode_i_32(4, serializer); sse_encode_u_64(startGroup, serializer);
+sse_encode_u_64(startObject, serializer);
+sse_encode_u_64(endGroup, serializer);
+sse_encode_opt_box_autoadd_u_64(endObject, serializer);
+  } }
+
+@protected void sse_encode_flutter_group_order(FlutterGroupOrder self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_hardware_accel(FlutterHardwareAccel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_issued_ticket(FlutterIssuedTicket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.ticket, serializer);
+sse_encode_u_64(self.tokenId, serializer);
+sse_encode_u_64(self.expiresAt, serializer);
+ }
+
+@protected void sse_encode_flutter_live_event(FlutterLiveEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FlutterLiveEvent_PeerConnected(peerId: final peerId): sse_encode_i_32(0, serializer); sse_encode_String(peerId, serializer);
+case FlutterLiveEvent_PeerDisconnected(peerId: final peerId): sse_encode_i_32(1, serializer); sse_encode_String(peerId, serializer);
+case FlutterLiveEvent_CatalogReceived(catalog: final catalog): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_flutter_catalog(catalog, serializer);
+case FlutterLiveEvent_MetadataReceived(from: final from,fileName: final fileName,fileSize: final fileSize,mimeType: final mimeType,totalChunks: final totalChunks,duration: final duration): sse_encode_i_32(3, serializer); sse_encode_String(from, serializer);
+sse_encode_String(fileName, serializer);
+sse_encode_u_64(fileSize, serializer);
+sse_encode_String(mimeType, serializer);
+sse_encode_u_32(totalChunks, serializer);
+sse_encode_opt_box_autoadd_f_64(duration, serializer);
+case FlutterLiveEvent_ChunkRequested(from: final from,index: final index): sse_encode_i_32(4, serializer); sse_encode_String(from, serializer);
+sse_encode_u_32(index, serializer);
+case FlutterLiveEvent_ChunkReceived(from: final from,index: final index,data: final data): sse_encode_i_32(5, serializer); sse_encode_String(from, serializer);
+sse_encode_u_32(index, serializer);
+sse_encode_list_prim_u_8_strict(data, serializer);
+case FlutterLiveEvent_MetadataRequested(from: final from): sse_encode_i_32(6, serializer); sse_encode_String(from, serializer);
+case FlutterLiveEvent_StatsUpdated(stats: final stats): sse_encode_i_32(7, serializer); sse_encode_box_autoadd_flutter_connection_stats(stats, serializer);
+case FlutterLiveEvent_Error(message: final message): sse_encode_i_32(8, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_flutter_moq_object(FlutterMoqObject self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.groupId, serializer);
+sse_encode_u_64(self.subgroupId, serializer);
+sse_encode_u_64(self.objectId, serializer);
+sse_encode_u_8(self.publisherPriority, serializer);
+sse_encode_opt_box_autoadd_u_64(self.expiresAt, serializer);
+sse_encode_flutter_object_status(self.status, serializer);
+sse_encode_list_prim_u_8_strict(self.payload, serializer);
+sse_encode_u_64(self.createdAt, serializer);
+sse_encode_bool(self.isExpired, serializer);
+ }
+
+@protected void sse_encode_flutter_moq_stats(FlutterMoqStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.schedulerQueueLength, serializer);
+sse_encode_u_32(self.namespaceCount, serializer);
+ }
+
+@protected void sse_encode_flutter_namespace_announcement(FlutterNamespaceAnnouncement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.namespacePath, serializer);
+sse_encode_list_String(self.tracks, serializer);
+sse_encode_bool(self.acceptsPublishing, serializer);
+ }
+
+@protected void sse_encode_flutter_object_status(FlutterObjectStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_publisher_status(FlutterPublisherStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.publisherId, serializer);
+sse_encode_bool(self.isActive, serializer);
+sse_encode_u_64(self.framesPublished, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_32(self.currentBitrate, serializer);
+sse_encode_list_String(self.videoRenditions, serializer);
+sse_encode_list_String(self.audioRenditions, serializer);
+ }
+
+@protected void sse_encode_flutter_received_video_frame(FlutterReceivedVideoFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_i_32(self.width, serializer);
+sse_encode_i_32(self.height, serializer);
+sse_encode_bool(self.isKeyframe, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+ }
+
+@protected void sse_encode_flutter_recording_stats(FlutterRecordingStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.files, serializer);
+sse_encode_u_64(self.fragments, serializer);
+sse_encode_u_64(self.videoFrames, serializer);
+sse_encode_u_64(self.audioFrames, serializer);
+sse_encode_u_64(self.bytes, serializer);
+ }
+
+@protected void sse_encode_flutter_relay_info(FlutterRelayInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.broadcastName, serializer);
+sse_encode_list_String(self.route, serializer);
+ }
+
+@protected void sse_encode_flutter_relay_mode(FlutterRelayMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FlutterRelayMode_Default(): sse_encode_i_32(0, serializer); case FlutterRelayMode_Custom(url: final url): sse_encode_i_32(1, serializer); sse_encode_String(url, serializer);
+case FlutterRelayMode_Disabled(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_flutter_rendition_stats(FlutterRenditionStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.trackName, serializer);
+sse_encode_u_64(self.framesWritten, serializer);
+sse_encode_u_64(self.bytesWritten, serializer);
+sse_encode_u_64(self.framesDropped, serializer);
+sse_encode_u_64(self.groupsCut, serializer);
+ }
+
+@protected void sse_encode_flutter_room_event(FlutterRoomEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.kind, serializer);
+sse_encode_String(self.member, serializer);
+sse_encode_String(self.subscriberId, serializer);
+ }
+
+@protected void sse_encode_flutter_roster_event(FlutterRosterEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.kind, serializer);
+sse_encode_String(self.endpointId, serializer);
+sse_encode_opt_box_autoadd_flutter_viewer(self.viewer, serializer);
+sse_encode_bool(self.kicked, serializer);
+ }
+
+@protected void sse_encode_flutter_stream_event(FlutterStreamEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case FlutterStreamEvent_NeighborUp(endpointId: final endpointId): sse_encode_i_32(0, serializer); sse_encode_String(endpointId, serializer);
+case FlutterStreamEvent_NeighborDown(endpointId: final endpointId): sse_encode_i_32(1, serializer); sse_encode_String(endpointId, serializer);
+case FlutterStreamEvent_Presence(from: final from,name: final name,timestamp: final timestamp): sse_encode_i_32(2, serializer); sse_encode_String(from, serializer);
+sse_encode_String(name, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterStreamEvent_MediaChunk(from: final from,data: final data,sequence: final sequence,timestamp: final timestamp): sse_encode_i_32(3, serializer); sse_encode_String(from, serializer);
+sse_encode_list_prim_u_8_strict(data, serializer);
+sse_encode_u_64(sequence, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterStreamEvent_Signal(from: final from,data: final data,timestamp: final timestamp): sse_encode_i_32(4, serializer); sse_encode_String(from, serializer);
+sse_encode_list_prim_u_8_strict(data, serializer);
+sse_encode_u_64(timestamp, serializer);
+case FlutterStreamEvent_Lagged(): sse_encode_i_32(5, serializer); case FlutterStreamEvent_Error(message: final message): sse_encode_i_32(6, serializer); sse_encode_String(message, serializer);
+  } }
+
+@protected void sse_encode_flutter_subscriber_status(FlutterSubscriberStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subscriberId, serializer);
+sse_encode_String(self.broadcastId, serializer);
+sse_encode_bool(self.isConnected, serializer);
+sse_encode_u_64(self.framesReceived, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_String(self.currentQuality, serializer);
+sse_encode_f_32(self.bufferHealth, serializer);
+ }
+
+@protected void sse_encode_flutter_ticket_info(FlutterTicketInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.broadcastName, serializer);
+sse_encode_String(self.endpointId, serializer);
+sse_encode_String(self.ticketString, serializer);
+ }
+
+@protected void sse_encode_flutter_track_info(FlutterTrackInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.trackId, serializer);
+sse_encode_String(self.name, serializer);
+sse_encode_String(self.codec, serializer);
+sse_encode_u_32(self.bitrate, serializer);
+sse_encode_Map_String_String_None(self.extra, serializer);
+ }
+
+@protected void sse_encode_flutter_track_status(FlutterTrackStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.trackPath, serializer);
+sse_encode_flutter_track_status_code(self.status, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latestGroupId, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latestObjectId, serializer);
+sse_encode_flutter_group_order(self.groupOrder, serializer);
+sse_encode_u_8(self.publisherPriority, serializer);
+ }
+
+@protected void sse_encode_flutter_track_status_code(FlutterTrackStatusCode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_video_codec(FlutterVideoCodec self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_video_frame(FlutterVideoFrame self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_list_prim_u_8_strict(self.data, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_String(self.format, serializer);
+ }
+
+@protected void sse_encode_flutter_video_quality(FlutterVideoQuality self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_video_quality_ffmpeg(FlutterVideoQualityFfmpeg self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_flutter_video_rendition(FlutterVideoRendition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_32(self.fps, serializer);
+sse_encode_u_32(self.bitrate, serializer);
+sse_encode_String(self.codec, serializer);
+ }
+
+@protected void sse_encode_flutter_video_track(FlutterVideoTrack self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.name, serializer);
+sse_encode_flutter_video_quality(self.quality, serializer);
+sse_encode_String(self.codec, serializer);
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_32(self.fps, serializer);
+sse_encode_u_32(self.bitrateKbps, serializer);
+ }
+
+@protected void sse_encode_flutter_viewer(FlutterViewer self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.endpointId, serializer);
+sse_encode_opt_String(self.displayName, serializer);
+sse_encode_u_64(self.connectedAtMs, serializer);
+sse_encode_list_String(self.tracks, serializer);
+sse_encode_u_64(self.bytesSent, serializer);
+sse_encode_u_64(self.bytesReceived, serializer);
+sse_encode_u_32(self.rttMs, serializer);
+ }
+
+@protected void sse_encode_i_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt16(self); }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_flutter_abr_event(List<FlutterAbrEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_abr_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_audio_rendition(List<FlutterAudioRendition> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_audio_rendition(item, serializer); } }
+
+@protected void sse_encode_list_flutter_capture_device(List<FlutterCaptureDevice> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_capture_device(item, serializer); } }
+
+@protected void sse_encode_list_flutter_chat_message(List<FlutterChatMessage> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_chat_message(item, serializer); } }
+
+@protected void sse_encode_list_flutter_connection_state(List<FlutterConnectionState> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_connection_state(item, serializer); } }
+
+@protected void sse_encode_list_flutter_direct_event(List<FlutterDirectEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_direct_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_discovery_event(List<FlutterDiscoveryEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_discovery_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_encoded_video_frame(List<FlutterEncodedVideoFrame> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_encoded_video_frame(item, serializer); } }
+
+@protected void sse_encode_list_flutter_live_event(List<FlutterLiveEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_live_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_moq_object(List<FlutterMoqObject> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_moq_object(item, serializer); } }
+
+@protected void sse_encode_list_flutter_namespace_announcement(List<FlutterNamespaceAnnouncement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_namespace_announcement(item, serializer); } }
+
+@protected void sse_encode_list_flutter_relay_info(List<FlutterRelayInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_relay_info(item, serializer); } }
+
+@protected void sse_encode_list_flutter_rendition_stats(List<FlutterRenditionStats> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_rendition_stats(item, serializer); } }
+
+@protected void sse_encode_list_flutter_room_event(List<FlutterRoomEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_room_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_roster_event(List<FlutterRosterEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_roster_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_stream_event(List<FlutterStreamEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_stream_event(item, serializer); } }
+
+@protected void sse_encode_list_flutter_track_info(List<FlutterTrackInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_track_info(item, serializer); } }
+
+@protected void sse_encode_list_flutter_video_quality(List<FlutterVideoQuality> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_video_quality(item, serializer); } }
+
+@protected void sse_encode_list_flutter_video_rendition(List<FlutterVideoRendition> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_video_rendition(item, serializer); } }
+
+@protected void sse_encode_list_flutter_video_track(List<FlutterVideoTrack> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_video_track(item, serializer); } }
+
+@protected void sse_encode_list_flutter_viewer(List<FlutterViewer> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_flutter_viewer(item, serializer); } }
+
+@protected void sse_encode_list_prim_i_16_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt16List(self is Int16List ? self : Int16List.fromList(self)); }
+
+@protected void sse_encode_list_prim_i_16_strict(Int16List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putInt16List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_record_string_bool(List<(String,bool)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_bool(item, serializer); } }
+
+@protected void sse_encode_list_record_string_string(List<(String,String)> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_record_string_string(item, serializer); } }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_connection_state(FlutterConnectionState? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_connection_state(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_endpoint_config(FlutterEndpointConfig? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_endpoint_config(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_moq_object(FlutterMoqObject? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_moq_object(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_namespace_announcement(FlutterNamespaceAnnouncement? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_namespace_announcement(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_publisher_status(FlutterPublisherStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_publisher_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_received_video_frame(FlutterReceivedVideoFrame? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_received_video_frame(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_subscriber_status(FlutterSubscriberStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_subscriber_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_ticket_info(FlutterTicketInfo? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_ticket_info(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_video_frame(FlutterVideoFrame? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_video_frame(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_flutter_viewer(FlutterViewer? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_flutter_viewer(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_quality(Quality self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_quality_constraints(QualityConstraints self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.width, serializer);
+sse_encode_u_32(self.height, serializer);
+sse_encode_u_32(self.framerate, serializer);
+sse_encode_u_32(self.audioBitrate, serializer);
+ }
+
+@protected void sse_encode_record_string_bool((String,bool) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_bool(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_string_string((String,String) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.$1, serializer);
+sse_encode_String(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_32_u_32((int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.$1, serializer);
+sse_encode_u_32(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_u_8_u_8_u_8((int,int,int) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.$1, serializer);
+sse_encode_u_8(self.$2, serializer);
+sse_encode_u_8(self.$3, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+                }
+                
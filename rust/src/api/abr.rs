@@ -0,0 +1,553 @@
+//! Adaptive bitrate control for subscribers
+//!
+//! This module provides:
+//! - AbrController: Decides rendition changes from network samples
+//! - Abr: Background task sampling a connection and driving a VideoSwitcher
+//!
+//! Downswitches react within a couple of samples; upswitches need a longer
+//! run of clean samples, and that hold doubles whenever an upswitch has to be
+//! undone, so a marginal link doesn't flap between renditions.
+
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+use hang::Catalog;
+use iroh::endpoint::Connection;
+use n0_future::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info};
+
+use super::subscribe_broadcast::{TrackStats, VideoSwitcher};
+
+/// How long a requested switch may take to cut over before it's given up on
+const SWITCH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// ABR tuning
+#[derive(Debug, Clone)]
+pub struct AbrConfig {
+    /// Time between samples
+    pub interval: Duration,
+    /// Consecutive bad samples before switching down
+    pub down_after: u32,
+    /// Clean time required before switching up
+    pub up_hold: Duration,
+    /// Longest the up hold can back off to
+    pub max_up_hold: Duration,
+    /// Packet loss rate above which a sample is bad
+    pub max_loss: f64,
+    /// RTT growth over the minimum seen that signals queueing
+    pub rtt_inflation: f64,
+    /// Fraction of measured throughput a rendition may use after a downswitch
+    pub safety: f64,
+}
+
+impl Default for AbrConfig {
+    fn default() -> Self {
+        Self {
+            interval: Duration::from_secs(1),
+            down_after: 2,
+            up_hold: Duration::from_secs(10),
+            max_up_hold: Duration::from_secs(80),
+            max_loss: 0.05,
+            rtt_inflation: 2.0,
+            safety: 0.8,
+        }
+    }
+}
+
+/// A video rendition the controller can choose
+#[derive(Debug, Clone, PartialEq)]
+pub struct Rendition {
+    /// Track name
+    pub track: String,
+    /// Bitrate from the catalog (bps)
+    pub bitrate: u64,
+}
+
+impl Rendition {
+    /// Video renditions from a catalog, lowest bitrate first
+    pub fn from_catalog(catalog: &Catalog) -> Vec<Rendition> {
+        let mut renditions: Vec<Rendition> = catalog
+            .video
+            .iter()
+            .flat_map(|video| video.renditions.iter())
+            .map(|(track, config)| Rendition {
+                track: track.clone(),
+                bitrate: config.bitrate.unwrap_or(0),
+            })
+            .collect();
+        renditions.sort_by(|a, b| a.bitrate.cmp(&b.bitrate).then_with(|| a.track.cmp(&b.track)));
+        renditions
+    }
+}
+
+/// One interval of network measurements
+#[derive(Debug, Clone, Default)]
+pub struct AbrSample {
+    /// Video payload throughput (bps)
+    pub throughput_bps: u64,
+    /// Smoothed round-trip time
+    pub rtt: Duration,
+    /// Rate the congestion window allows per RTT (bps), 0 if unknown
+    pub cwnd_bps: u64,
+    /// Fraction of packets lost on the path
+    pub loss_rate: f64,
+    /// Frames that arrived late
+    pub late_frames: u64,
+    /// Groups skipped because we fell behind
+    pub skipped_groups: u64,
+}
+
+/// Why the controller changed rendition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AbrReason {
+    /// Frames arrived late or groups were skipped
+    LateFrames,
+    /// Packet loss exceeded the limit
+    PacketLoss,
+    /// RTT grew well beyond its minimum (queues building up)
+    RttInflation,
+    /// Conditions were clean long enough to try a higher rendition
+    Recovered,
+}
+
+impl AbrReason {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AbrReason::LateFrames => "late_frames",
+            AbrReason::PacketLoss => "packet_loss",
+            AbrReason::RttInflation => "rtt_inflation",
+            AbrReason::Recovered => "recovered",
+        }
+    }
+}
+
+/// A rendition change decided by the controller
+#[derive(Debug, Clone)]
+pub struct AbrEvent {
+    /// Track switched away from
+    pub from: String,
+    /// Track switched to
+    pub to: String,
+    pub reason: AbrReason,
+    /// The sample that triggered the decision
+    pub sample: AbrSample,
+}
+
+/// Rendition decision logic, independent of any connection
+pub struct AbrController {
+    config: AbrConfig,
+    renditions: Vec<Rendition>,
+    current: usize,
+    min_rtt: Option<Duration>,
+    bad_streak: u32,
+    good_since: Option<Instant>,
+    up_hold: Duration,
+    last_up: Option<Instant>,
+}
+
+impl AbrController {
+    /// Create a controller starting on `current` (a track name)
+    pub fn new(config: AbrConfig, renditions: Vec<Rendition>, current: &str) -> Self {
+        let up_hold = config.up_hold;
+        let mut controller = Self {
+            config,
+            renditions,
+            current: 0,
+            min_rtt: None,
+            bad_streak: 0,
+            good_since: None,
+            up_hold,
+            last_up: None,
+        };
+        controller.set_current(current);
+        controller
+    }
+
+    /// Track the controller believes is active
+    pub fn current(&self) -> Option<&str> {
+        self.renditions.get(self.current).map(|r| r.track.as_str())
+    }
+
+    /// Sync with a rendition change made elsewhere (e.g. a manual switch)
+    pub fn set_current(&mut self, track: &str) {
+        if let Some(index) = self.renditions.iter().position(|r| r.track == track) {
+            if index != self.current {
+                self.current = index;
+                self.bad_streak = 0;
+                self.good_since = None;
+            }
+        }
+    }
+
+    /// Classify a sample, returning why it is bad (if it is)
+    fn problem(&self, sample: &AbrSample) -> Option<AbrReason> {
+        if sample.late_frames > 0 || sample.skipped_groups > 0 {
+            return Some(AbrReason::LateFrames);
+        }
+        if sample.loss_rate > self.config.max_loss {
+            return Some(AbrReason::PacketLoss);
+        }
+        let min_rtt = self.min_rtt?;
+        let inflated = sample.rtt.as_secs_f64() > min_rtt.as_secs_f64() * self.config.rtt_inflation;
+        // Ignore inflation on very short paths where a few ms doubles the RTT
+        if inflated && sample.rtt > min_rtt + Duration::from_millis(50) {
+            return Some(AbrReason::RttInflation);
+        }
+        None
+    }
+
+    /// Feed a sample, returning a rendition change if one is due
+    pub fn decide(&mut self, sample: &AbrSample, now: Instant) -> Option<AbrEvent> {
+        if !sample.rtt.is_zero() {
+            self.min_rtt = Some(self.min_rtt.map_or(sample.rtt, |min| min.min(sample.rtt)));
+        }
+
+        if let Some(reason) = self.problem(sample) {
+            self.good_since = None;
+            self.bad_streak += 1;
+            if self.bad_streak < self.config.down_after || self.current == 0 {
+                return None;
+            }
+            self.bad_streak = 0;
+
+            // Drop to what the measured throughput supports, at least one step
+            let budget = (sample.throughput_bps as f64 * self.config.safety) as u64;
+            let target = self.renditions[..self.current]
+                .iter()
+                .rposition(|r| r.bitrate <= budget)
+                .unwrap_or(0);
+
+            // An upswitch that had to be undone quickly means the hold was too short
+            if self.last_up.is_some_and(|up| now.duration_since(up) < self.up_hold * 2) {
+                self.up_hold = (self.up_hold * 2).min(self.config.max_up_hold);
+            }
+            return Some(self.switch_to(target, reason, sample));
+        }
+
+        self.bad_streak = 0;
+        let good_since = *self.good_since.get_or_insert(now);
+        if self.current + 1 >= self.renditions.len() || now.duration_since(good_since) < self.up_hold {
+            return None;
+        }
+        // Don't step up past what the congestion window could carry
+        let next = self.renditions[self.current + 1].bitrate;
+        if sample.cwnd_bps > 0 && next > sample.cwnd_bps {
+            return None;
+        }
+
+        // A long stable stretch earns back a shorter hold
+        if self.last_up.is_some_and(|up| now.duration_since(up) > self.up_hold * 4) {
+            self.up_hold = (self.up_hold / 2).max(self.config.up_hold);
+        }
+        self.last_up = Some(now);
+        self.good_since = Some(now);
+        Some(self.switch_to(self.current + 1, AbrReason::Recovered, sample))
+    }
+
+    fn switch_to(&mut self, index: usize, reason: AbrReason, sample: &AbrSample) -> AbrEvent {
+        let from = self.renditions[self.current].track.clone();
+        self.current = index;
+        AbrEvent {
+            from,
+            to: self.renditions[index].track.clone(),
+            reason,
+            sample: sample.clone(),
+        }
+    }
+}
+
+/// Handle to a running ABR task
+#[derive(Clone)]
+pub struct AbrHandle {
+    enabled: Arc<AtomicBool>,
+    cancel: CancellationToken,
+}
+
+impl AbrHandle {
+    /// Enable or pause automatic switching (manual quality pauses it)
+    pub fn set_enabled(&self, enabled: bool) {
+        self.enabled.store(enabled, Ordering::Relaxed);
+    }
+
+    /// Whether automatic switching is active
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.load(Ordering::Relaxed)
+    }
+
+    /// Stop the ABR task
+    pub fn stop(&self) {
+        self.cancel.cancel();
+    }
+}
+
+/// A switch that was requested but hasn't cut over yet
+struct PendingSwitch {
+    /// Track switched to
+    target: String,
+    /// Track that was active when the switch was requested
+    from: String,
+    at: Instant,
+}
+
+impl PendingSwitch {
+    /// Track the controller should treat as current
+    ///
+    /// A switch takes effect at the new track's next group boundary. Until
+    /// then the target counts as current, so the controller doesn't fall
+    /// back to the old track and ask for the same switch again. The pending
+    /// switch is dropped once the active track changes or it times out.
+    fn effective(pending: &mut Option<PendingSwitch>, active: String, now: Instant) -> String {
+        if let Some(switch) = pending.take() {
+            if switch.from == active && now.duration_since(switch.at) < SWITCH_TIMEOUT {
+                let target = switch.target.clone();
+                *pending = Some(switch);
+                return target;
+            }
+        }
+        active
+    }
+}
+
+/// Background ABR task for one subscription
+pub struct Abr;
+
+impl Abr {
+    /// Start sampling `conn` and `stats`, switching renditions through `switcher`
+    ///
+    /// On the subscriber, path loss and congestion describe the upstream
+    /// direction, so late frames and RTT inflation are the main downstream
+    /// signals. Decisions are reported on the returned channel.
+    pub fn spawn(
+        conn: Connection,
+        switcher: VideoSwitcher,
+        stats: Arc<TrackStats>,
+        renditions: Vec<Rendition>,
+        config: AbrConfig,
+        enabled: bool,
+    ) -> (AbrHandle, mpsc::UnboundedReceiver<AbrEvent>) {
+        let (events_tx, events_rx) = mpsc::unbounded_channel();
+        let handle = AbrHandle {
+            enabled: Arc::new(AtomicBool::new(enabled)),
+            cancel: CancellationToken::new(),
+        };
+
+        let task = handle.clone();
+        tokio::spawn(async move {
+            let interval = config.interval;
+            let mut controller = AbrController::new(config, renditions, &switcher.current_track());
+            let mut last = Snapshot::take(&conn, &stats);
+            let mut pending = None;
+
+            loop {
+                tokio::select! {
+                    _ = task.cancel.cancelled() => break,
+                    _ = n0_future::time::sleep(interval) => {}
+                }
+                if conn.close_reason().is_some() {
+                    break;
+                }
+
+                let now = Snapshot::take(&conn, &stats);
+                let sample = now.sample_since(&last);
+                last = now;
+
+                let active = switcher.current_track();
+                controller.set_current(&PendingSwitch::effective(&mut pending, active.clone(), Instant::now()));
+                if !task.is_enabled() {
+                    continue;
+                }
+                let Some(event) = controller.decide(&sample, Instant::now()) else {
+                    continue;
+                };
+
+                let quality = event.to.strip_prefix("video.").unwrap_or(&event.to);
+                if let Err(e) = switcher.switch_video(quality) {
+                    debug!("ABR switch failed: {e}");
+                    controller.set_current(&PendingSwitch::effective(&mut pending, active, Instant::now()));
+                    continue;
+                }
+                pending = Some(PendingSwitch {
+                    target: event.to.clone(),
+                    from: active,
+                    at: Instant::now(),
+                });
+                info!(from = %event.from, to = %event.to, reason = event.reason.as_str(), "ABR switch");
+                if events_tx.send(event).is_err() {
+                    break;
+                }
+            }
+            debug!("ABR stopped");
+        });
+
+        (handle, events_rx)
+    }
+}
+
+/// Counter values at one point in time
+struct Snapshot {
+    at: Instant,
+    bytes: u64,
+    late_frames: u64,
+    skipped_groups: u64,
+    lost_packets: u64,
+    sent_packets: u64,
+    cwnd: u64,
+    rtt: Duration,
+}
+
+impl Snapshot {
+    fn take(conn: &Connection, stats: &TrackStats) -> Self {
+        let path = conn.stats().path;
+        Self {
+            at: Instant::now(),
+            bytes: stats.bytes(),
+            late_frames: stats.late_frames(),
+            skipped_groups: stats.skipped_groups(),
+            lost_packets: path.lost_packets,
+            sent_packets: path.sent_packets,
+            cwnd: path.cwnd,
+            rtt: conn.rtt(),
+        }
+    }
+
+    fn sample_since(&self, earlier: &Snapshot) -> AbrSample {
+        let elapsed = self.at.duration_since(earlier.at).as_secs_f64().max(0.001);
+        let sent = self.sent_packets.saturating_sub(earlier.sent_packets);
+        let lost = self.lost_packets.saturating_sub(earlier.lost_packets);
+        AbrSample {
+            throughput_bps: (self.bytes.saturating_sub(earlier.bytes) as f64 * 8.0 / elapsed) as u64,
+            rtt: self.rtt,
+            cwnd_bps: if self.rtt.is_zero() {
+                0
+            } else {
+                (self.cwnd as f64 * 8.0 / self.rtt.as_secs_f64()) as u64
+            },
+            loss_rate: if sent == 0 { 0.0 } else { lost as f64 / sent as f64 },
+            late_frames: self.late_frames.saturating_sub(earlier.late_frames),
+            skipped_groups: self.skipped_groups.saturating_sub(earlier.skipped_groups),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn renditions() -> Vec<Rendition> {
+        [("video.low", 1_000_000), ("video.med", 2_500_000), ("video.high", 5_000_000)]
+            .into_iter()
+            .map(|(track, bitrate)| Rendition { track: track.to_string(), bitrate })
+            .collect()
+    }
+
+    fn good() -> AbrSample {
+        AbrSample {
+            throughput_bps: 2_500_000,
+            rtt: Duration::from_millis(40),
+            ..Default::default()
+        }
+    }
+
+    fn late() -> AbrSample {
+        AbrSample {
+            throughput_bps: 1_500_000,
+            late_frames: 3,
+            ..good()
+        }
+    }
+
+    #[test]
+    fn test_down_needs_consecutive_bad_samples() {
+        let mut abr = AbrController::new(AbrConfig::default(), renditions(), "video.high");
+        let now = Instant::now();
+
+        assert!(abr.decide(&late(), now).is_none());
+        assert!(abr.decide(&good(), now).is_none());
+        assert!(abr.decide(&late(), now).is_none());
+
+        // 1.5 Mbps measured supports only the low rendition
+        let event = abr.decide(&late(), now).unwrap();
+        assert_eq!((event.from.as_str(), event.to.as_str()), ("video.high", "video.low"));
+        assert_eq!(event.reason, AbrReason::LateFrames);
+    }
+
+    #[test]
+    fn test_up_after_hold() {
+        let mut abr = AbrController::new(AbrConfig::default(), renditions(), "video.low");
+        let start = Instant::now();
+
+        assert!(abr.decide(&good(), start).is_none());
+        assert!(abr.decide(&good(), start + Duration::from_secs(5)).is_none());
+
+        // The congestion window can't carry the next rendition yet
+        let narrow = AbrSample { cwnd_bps: 2_000_000, ..good() };
+        assert!(abr.decide(&narrow, start + Duration::from_secs(10)).is_none());
+
+        let event = abr.decide(&good(), start + Duration::from_secs(10)).unwrap();
+        assert_eq!((event.to.as_str(), event.reason), ("video.med", AbrReason::Recovered));
+    }
+
+    #[test]
+    fn test_failed_upswitch_backs_off() {
+        let mut abr = AbrController::new(AbrConfig::default(), renditions(), "video.low");
+        let start = Instant::now();
+        let at = |secs| start + Duration::from_secs(secs);
+
+        abr.decide(&good(), at(0));
+        assert!(abr.decide(&good(), at(10)).is_some());
+        abr.decide(&late(), at(11));
+        assert!(abr.decide(&late(), at(12)).is_some());
+
+        // The hold doubled to 20s, so 10s of clean samples is no longer enough
+        abr.decide(&good(), at(13));
+        assert!(abr.decide(&good(), at(23)).is_none());
+        assert!(abr.decide(&good(), at(33)).is_some());
+    }
+
+    #[test]
+    fn test_pending_switch_counts_until_cut_over() {
+        let mut abr = AbrController::new(AbrConfig::default(), renditions(), "video.high");
+        let start = Instant::now();
+        let slow = AbrSample { throughput_bps: 3_500_000, ..late() };
+        abr.decide(&slow, start);
+        let event = abr.decide(&slow, start).unwrap();
+        assert_eq!(event.to, "video.med");
+        let mut pending = Some(PendingSwitch {
+            target: event.to,
+            from: "video.high".to_string(),
+            at: start,
+        });
+
+        // Still on the old track: the controller keeps the target
+        let current = PendingSwitch::effective(&mut pending, "video.high".to_string(), start + Duration::from_secs(1));
+        abr.set_current(&current);
+        assert_eq!(abr.current(), Some("video.med"));
+
+        // Cut over
+        assert_eq!(PendingSwitch::effective(&mut pending, "video.low".to_string(), start), "video.low");
+        assert!(pending.is_none());
+
+        // A switch that never cuts over is given up on
+        let mut pending = Some(PendingSwitch {
+            target: "video.low".to_string(),
+            from: "video.high".to_string(),
+            at: start,
+        });
+        assert_eq!(PendingSwitch::effective(&mut pending, "video.high".to_string(), start + SWITCH_TIMEOUT), "video.high");
+        assert!(pending.is_none());
+    }
+
+    #[test]
+    fn test_rtt_inflation() {
+        let mut abr = AbrController::new(AbrConfig::default(), renditions(), "video.med");
+        let now = Instant::now();
+        abr.decide(&good(), now);
+
+        let slow = AbrSample { rtt: Duration::from_millis(200), ..good() };
+        assert!(abr.decide(&slow, now).is_none());
+        let event = abr.decide(&slow, now).unwrap();
+        assert_eq!(event.reason, AbrReason::RttInflation);
+    }
+}
@@ -0,0 +1,301 @@
+//! Broadcast access control
+//!
+//! Broadcasts are open by default. A publisher can restrict one with a
+//! [`BroadcastPolicy`]: peers on its deny list are always refused, peers on
+//! its allow list are always admitted, and when `require_token` is set
+//! everyone else must present a [`SignedToken`] naming the broadcast.
+//!
+//! Tokens are capabilities signed by the publisher's [`SecretKey`], so any
+//! node holding only the publisher's endpoint id can check them. They carry
+//! an expiry, the broadcasts they grant and optionally the one peer allowed
+//! to use them. Subscribers present their token in the session handshake
+//! (see [`super::live_protocol`]); the publisher re-checks sessions whenever
+//! a policy changes, a token is revoked or a token expires, and withdraws
+//! broadcasts from the peers that lost access to them.
+
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+use iroh::{EndpointId, SecretKey, Signature};
+use n0_future::time::{Duration, SystemTime};
+use serde::{Deserialize, Serialize};
+use thiserror::Error;
+
+/// Why a peer may not receive a broadcast
+#[derive(Debug, Clone, PartialEq, Eq, Error)]
+pub enum AccessDenied {
+    #[error("peer is on the broadcast's deny list")]
+    Denied,
+    #[error("broadcast requires a token")]
+    TokenRequired,
+    #[error("token was not issued by this publisher")]
+    WrongIssuer,
+    #[error("token signature is invalid")]
+    BadSignature,
+    #[error("token has expired")]
+    Expired,
+    #[error("token has been revoked")]
+    Revoked,
+    #[error("token does not grant this broadcast")]
+    WrongBroadcast,
+    #[error("token was issued to another peer")]
+    WrongSubject,
+}
+
+/// Claims of a capability token
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Capability {
+    /// Random id, used to revoke the token
+    pub id: u64,
+    /// Publisher that signed the token
+    pub issuer: EndpointId,
+    /// Only this peer may use the token; anyone holding it if None
+    pub subject: Option<EndpointId>,
+    /// Broadcasts the token grants; every broadcast of the issuer if empty
+    pub broadcasts: Vec<String>,
+    /// Unix time (seconds) after which the token is invalid
+    pub expires_at: u64,
+}
+
+/// A capability signed by its issuer
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignedToken {
+    pub capability: Capability,
+    /// Ed25519 signature over the postcard-encoded capability
+    signature: Vec<u8>,
+}
+
+impl SignedToken {
+    /// Issue a token valid for `ttl` from now
+    pub fn issue(
+        secret_key: &SecretKey,
+        broadcasts: Vec<String>,
+        subject: Option<EndpointId>,
+        ttl: Duration,
+    ) -> Self {
+        let capability = Capability {
+            id: rand::random(),
+            issuer: secret_key.public(),
+            subject,
+            broadcasts,
+            expires_at: unix_now() + ttl.as_secs(),
+        };
+        let signature = secret_key.sign(&capability_bytes(&capability));
+        Self {
+            capability,
+            signature: signature.to_bytes().to_vec(),
+        }
+    }
+
+    pub fn id(&self) -> u64 {
+        self.capability.id
+    }
+
+    /// Check the signature and expiry against the expected issuer
+    pub fn verify(&self, issuer: EndpointId, now: u64) -> Result<(), AccessDenied> {
+        let capability = &self.capability;
+        if capability.issuer != issuer {
+            return Err(AccessDenied::WrongIssuer);
+        }
+        let signature: &[u8; 64] = self
+            .signature
+            .as_slice()
+            .try_into()
+            .map_err(|_| AccessDenied::BadSignature)?;
+        issuer
+            .verify(&capability_bytes(capability), &Signature::from_bytes(signature))
+            .map_err(|_| AccessDenied::BadSignature)?;
+        if now >= capability.expires_at {
+            return Err(AccessDenied::Expired);
+        }
+        Ok(())
+    }
+
+    /// Whether the token's claims cover `broadcast` for `remote`
+    pub fn grants(&self, broadcast: &str, remote: EndpointId) -> Result<(), AccessDenied> {
+        let capability = &self.capability;
+        if capability.subject.is_some_and(|subject| subject != remote) {
+            return Err(AccessDenied::WrongSubject);
+        }
+        if !capability.broadcasts.is_empty() && !capability.broadcasts.iter().any(|b| b == broadcast) {
+            return Err(AccessDenied::WrongBroadcast);
+        }
+        Ok(())
+    }
+
+    /// Encode as base32 text, like tickets
+    pub fn serialize(&self) -> String {
+        let bytes = postcard::to_stdvec(self).expect("token serializes");
+        data_encoding::BASE32_NOPAD.encode(&bytes).to_ascii_lowercase()
+    }
+
+    pub fn deserialize(s: &str) -> Result<Self> {
+        let bytes = data_encoding::BASE32_NOPAD.decode(s.trim().to_ascii_uppercase().as_bytes())?;
+        Ok(postcard::from_bytes(&bytes)?)
+    }
+}
+
+fn capability_bytes(capability: &Capability) -> Vec<u8> {
+    postcard::to_stdvec(capability).expect("capability serializes")
+}
+
+/// Current unix time in seconds
+pub fn unix_now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs()
+}
+
+/// Who may receive a broadcast
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BroadcastPolicy {
+    /// Peers without a valid token are refused, unless allow-listed
+    pub require_token: bool,
+    /// Always admitted, with or without a token
+    pub allow: HashSet<EndpointId>,
+    /// Always refused, even with a token
+    pub deny: HashSet<EndpointId>,
+}
+
+#[derive(Debug)]
+struct AccessState {
+    issuer: EndpointId,
+    policies: HashMap<String, BroadcastPolicy>,
+    /// Ids of revoked tokens
+    revoked: HashSet<u64>,
+}
+
+/// Access policies and revocations for a publisher's broadcasts
+///
+/// Cheap to clone; clones share state.
+#[derive(Debug, Clone)]
+pub struct AccessControl {
+    state: Arc<RwLock<AccessState>>,
+}
+
+impl AccessControl {
+    /// Access control for broadcasts published by `issuer`
+    pub fn new(issuer: EndpointId) -> Self {
+        Self {
+            state: Arc::new(RwLock::new(AccessState {
+                issuer,
+                policies: HashMap::new(),
+                revoked: HashSet::new(),
+            })),
+        }
+    }
+
+    /// Restrict a broadcast; replaces any previous policy
+    pub fn set_policy(&self, broadcast: impl ToString, policy: BroadcastPolicy) {
+        self.state.write().unwrap().policies.insert(broadcast.to_string(), policy);
+    }
+
+    /// Open a broadcast to everyone again
+    pub fn clear_policy(&self, broadcast: &str) {
+        self.state.write().unwrap().policies.remove(broadcast);
+    }
+
+    pub fn policy(&self, broadcast: &str) -> Option<BroadcastPolicy> {
+        self.state.read().unwrap().policies.get(broadcast).cloned()
+    }
+
+    /// Reject a token from now on, wherever it is presented
+    pub fn revoke(&self, token_id: u64) {
+        self.state.write().unwrap().revoked.insert(token_id);
+    }
+
+    /// Check a token presented in a session handshake
+    ///
+    /// Only the token itself is checked here; whether it grants a given
+    /// broadcast is up to [`AccessControl::check`].
+    pub fn verify_token(&self, token: &SignedToken) -> Result<(), AccessDenied> {
+        let state = self.state.read().unwrap();
+        if state.revoked.contains(&token.id()) {
+            return Err(AccessDenied::Revoked);
+        }
+        token.verify(state.issuer, unix_now())
+    }
+
+    /// Whether `remote` may receive `broadcast`, holding `token`
+    pub fn check(&self, remote: EndpointId, broadcast: &str, token: Option<&SignedToken>) -> Result<(), AccessDenied> {
+        let state = self.state.read().unwrap();
+        let Some(policy) = state.policies.get(broadcast) else {
+            return Ok(());
+        };
+        if policy.deny.contains(&remote) {
+            return Err(AccessDenied::Denied);
+        }
+        if policy.allow.contains(&remote) || !policy.require_token {
+            return Ok(());
+        }
+
+        let token = token.ok_or(AccessDenied::TokenRequired)?;
+        if state.revoked.contains(&token.id()) {
+            return Err(AccessDenied::Revoked);
+        }
+        token.verify(state.issuer, unix_now())?;
+        token.grants(broadcast, remote)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn key(seed: u8) -> SecretKey {
+        SecretKey::from_bytes(&[seed; 32])
+    }
+
+    #[test]
+    fn test_token_verify() {
+        let publisher = key(1);
+        let viewer = key(2).public();
+        let token = SignedToken::issue(&publisher, vec!["show".to_string()], Some(viewer), Duration::from_secs(60));
+        let now = unix_now();
+
+        assert_eq!(token.verify(publisher.public(), now), Ok(()));
+        assert_eq!(token.verify(key(3).public(), now), Err(AccessDenied::WrongIssuer));
+        assert_eq!(token.verify(publisher.public(), now + 61), Err(AccessDenied::Expired));
+        assert_eq!(token.grants("show", viewer), Ok(()));
+        assert_eq!(token.grants("other", viewer), Err(AccessDenied::WrongBroadcast));
+        assert_eq!(token.grants("show", key(3).public()), Err(AccessDenied::WrongSubject));
+
+        // Tampered claims no longer match the signature
+        let mut forged = token.clone();
+        forged.capability.broadcasts.push("other".to_string());
+        assert_eq!(forged.verify(publisher.public(), now), Err(AccessDenied::BadSignature));
+
+        let decoded = SignedToken::deserialize(&token.serialize()).unwrap();
+        assert_eq!(decoded, token);
+    }
+
+    #[test]
+    fn test_access_policy() {
+        let publisher = key(1);
+        let (viewer, allowed, denied) = (key(2).public(), key(3).public(), key(4).public());
+        let access = AccessControl::new(publisher.public());
+        let token = SignedToken::issue(&publisher, Vec::new(), None, Duration::from_secs(60));
+
+        // Broadcasts without a policy are open
+        assert_eq!(access.check(viewer, "show", None), Ok(()));
+
+        access.set_policy("show", BroadcastPolicy {
+            require_token: true,
+            allow: HashSet::from([allowed]),
+            deny: HashSet::from([denied]),
+        });
+        assert_eq!(access.check(viewer, "show", None), Err(AccessDenied::TokenRequired));
+        assert_eq!(access.check(viewer, "show", Some(&token)), Ok(()));
+        assert_eq!(access.check(allowed, "show", None), Ok(()));
+        assert_eq!(access.check(denied, "show", Some(&token)), Err(AccessDenied::Denied));
+
+        access.revoke(token.id());
+        assert_eq!(access.check(viewer, "show", Some(&token)), Err(AccessDenied::Revoked));
+        assert_eq!(access.verify_token(&token), Err(AccessDenied::Revoked));
+
+        access.clear_policy("show");
+        assert_eq!(access.check(viewer, "show", None), Ok(()));
+    }
+}
@@ -41,6 +41,31 @@ impl PixelFormat {
     pub fn is_planar(&self) -> bool {
         matches!(self, Self::Yuv420p | Self::Nv12 | Self::Nv21)
     }
+
+    /// Name used by the Flutter API ("rgba", "i420", ...)
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Rgba => "rgba",
+            Self::Bgra => "bgra",
+            Self::Rgb => "rgb",
+            Self::Yuv420p => "i420",
+            Self::Nv12 => "nv12",
+            Self::Nv21 => "nv21",
+        }
+    }
+
+    /// Parse a Flutter API name; "yuv420p" is accepted for "i420"
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_lowercase().as_str() {
+            "rgba" => Some(Self::Rgba),
+            "bgra" => Some(Self::Bgra),
+            "rgb" => Some(Self::Rgb),
+            "i420" | "yuv420p" => Some(Self::Yuv420p),
+            "nv12" => Some(Self::Nv12),
+            "nv21" => Some(Self::Nv21),
+            _ => None,
+        }
+    }
 }
 
 // ============================================================================
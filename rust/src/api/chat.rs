@@ -0,0 +1,500 @@
+//! Chat and reactions alongside a live broadcast
+//!
+//! A broadcast carries its chat on a `chat` track next to the media. The
+//! publisher is the only writer: every message gets a sequence number, the
+//! sender's endpoint ID and a timestamp, and goes out on one group at a time
+//! so viewers read it in order. Groups are cut every [`GROUP_MESSAGES`]
+//! messages, which also bounds the backlog a viewer sees on joining.
+//!
+//! Viewers can't write to the publisher's track. Instead each one publishes
+//! a small broadcast of its own back over the same session, at
+//! [`inbox_path`], with a `chat` track of [`ChatKind`] posts. The publisher's
+//! [`super::live_protocol::Live`] actor reads it, stamps the sender with the
+//! session's authenticated endpoint ID, applies the broadcast's
+//! [`ChatRate`] and hands the post to the broadcast's [`ChatInbox`]. Posts
+//! only reach the publisher over a direct session; relays don't forward
+//! them.
+//!
+//! On encrypted broadcasts the chat track is sealed with the media key like
+//! any other track, using the sequence number in place of a timestamp.
+
+use std::time::{Instant, SystemTime};
+
+use anyhow::{Context, Result, ensure};
+use bytes::{BufMut, Bytes, BytesMut};
+use iroh::EndpointId;
+use moq_lite::{
+    Broadcast, BroadcastConsumer, BroadcastProducer, GroupConsumer, GroupProducer, Track, TrackConsumer,
+    TrackProducer,
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc;
+use tracing::{debug, warn};
+
+use super::encryption::{FrameDecryptor, FrameEncryptor, MediaKey};
+
+/// Name of the chat track, in a broadcast and in a viewer's inbox
+pub const CHAT_TRACK: &str = "chat";
+
+/// Longest message text, in characters
+pub const MAX_TEXT: usize = 500;
+
+/// Longest reaction, in characters
+pub const MAX_REACTION: usize = 16;
+
+/// Messages per group; a joining viewer sees up to this many earlier ones
+pub const GROUP_MESSAGES: u64 = 64;
+
+/// Path suffix of the broadcast a viewer posts to `<broadcast>` with
+const INBOX_SUFFIX: &str = "/chat";
+
+/// Sequence number ahead of each message on the publisher's track
+const SEQ_LEN: usize = 8;
+
+/// Where a viewer publishes its posts for `broadcast`
+pub fn inbox_path(broadcast: &str) -> String {
+    format!("{broadcast}{INBOX_SUFFIX}")
+}
+
+/// What a chat message says
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ChatKind {
+    Text(String),
+    /// Reaction to the message with sequence number `target`
+    Reaction { target: u64, emoji: String },
+}
+
+impl ChatKind {
+    /// Check the message fits the limits every publisher enforces
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            ChatKind::Text(text) => {
+                ensure!(!text.trim().is_empty(), "empty message");
+                ensure!(text.chars().count() <= MAX_TEXT, "message longer than {MAX_TEXT} characters");
+            }
+            ChatKind::Reaction { emoji, .. } => {
+                ensure!(!emoji.is_empty(), "empty reaction");
+                ensure!(emoji.chars().count() <= MAX_REACTION, "reaction longer than {MAX_REACTION} characters");
+            }
+        }
+        Ok(())
+    }
+}
+
+/// A message as the publisher sent it out
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ChatMessage {
+    /// Position in the broadcast's chat, from 0
+    pub seq: u64,
+    pub sender: EndpointId,
+    /// Name the sender chose, if any; not verified
+    pub display_name: Option<String>,
+    /// Unix time (milliseconds) the publisher accepted the message
+    pub timestamp_ms: u64,
+    pub kind: ChatKind,
+}
+
+/// A message for a broadcast's chat, before the publisher numbers it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ChatPost {
+    pub sender: EndpointId,
+    pub display_name: Option<String>,
+    pub kind: ChatKind,
+}
+
+/// Where the live actor delivers viewers' posts for a broadcast
+pub type ChatInbox = mpsc::Sender<ChatPost>;
+
+/// How often each viewer may post
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChatRate {
+    /// Posts allowed in a row
+    pub burst: u32,
+    /// Posts regained per second
+    pub per_second: f64,
+}
+
+impl Default for ChatRate {
+    fn default() -> Self {
+        Self {
+            burst: 5,
+            per_second: 1.0,
+        }
+    }
+}
+
+/// Token bucket for one viewer's posts
+#[derive(Debug, Clone)]
+pub(crate) struct RateLimiter {
+    rate: ChatRate,
+    tokens: f64,
+    last: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(rate: ChatRate) -> Self {
+        Self {
+            rate,
+            tokens: rate.burst as f64,
+            last: Instant::now(),
+        }
+    }
+
+    /// Take a token if one is left
+    pub(crate) fn allow(&mut self, now: Instant) -> bool {
+        let elapsed = now.saturating_duration_since(self.last).as_secs_f64();
+        self.last = now;
+        self.tokens = (self.tokens + elapsed * self.rate.per_second).min(self.rate.burst as f64);
+        if self.tokens < 1.0 {
+            return false;
+        }
+        self.tokens -= 1.0;
+        true
+    }
+}
+
+/// Appends frames to a track, starting a new group every [`GROUP_MESSAGES`]
+struct TrackLog {
+    producer: TrackProducer,
+    group: Option<GroupProducer>,
+    in_group: u64,
+}
+
+impl TrackLog {
+    fn new(producer: TrackProducer) -> Self {
+        Self {
+            producer,
+            group: None,
+            in_group: 0,
+        }
+    }
+
+    /// Append the frame `frame` builds, told whether it starts a group
+    fn push(&mut self, frame: impl FnOnce(bool) -> Bytes) {
+        let rotate = self.group.is_none() || self.in_group >= GROUP_MESSAGES;
+        if rotate {
+            if let Some(group) = self.group.take() {
+                group.close();
+            }
+            self.group = Some(self.producer.append_group());
+            self.in_group = 0;
+        }
+        let data = frame(rotate);
+        let group = self.group.as_mut().unwrap();
+        let mut out = group.create_frame(data.len().into());
+        out.write_chunk(data);
+        out.close();
+        self.in_group += 1;
+    }
+
+    /// Close the open group and end the track
+    fn finish(mut self) {
+        if let Some(group) = self.group.take() {
+            group.close();
+        }
+        self.producer.close();
+    }
+}
+
+/// The publisher's side of a broadcast's chat track
+pub struct ChatWriter {
+    log: TrackLog,
+    next_seq: u64,
+    encryptor: Option<FrameEncryptor>,
+}
+
+impl ChatWriter {
+    pub fn new(producer: TrackProducer) -> Self {
+        Self {
+            log: TrackLog::new(producer),
+            next_seq: 0,
+            encryptor: None,
+        }
+    }
+
+    /// Seal messages with `key`
+    pub fn with_encryption(mut self, key: MediaKey) -> Self {
+        self.encryptor = Some(FrameEncryptor::new(key));
+        self
+    }
+
+    /// Number, stamp and send a post
+    pub fn post(&mut self, post: ChatPost) -> ChatMessage {
+        let message = ChatMessage {
+            seq: self.next_seq,
+            sender: post.sender,
+            display_name: post.display_name,
+            timestamp_ms: SystemTime::now()
+                .duration_since(SystemTime::UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            kind: post.kind,
+        };
+        self.next_seq += 1;
+
+        let body = Bytes::from(postcard::to_stdvec(&message).expect("chat message serializes"));
+        let encryptor = &mut self.encryptor;
+        self.log.push(|new_group| {
+            let body = match encryptor {
+                Some(encryptor) => {
+                    if new_group {
+                        encryptor.rotate();
+                    }
+                    encryptor.encrypt(message.seq as i64, &body)
+                }
+                None => body,
+            };
+            let mut frame = BytesMut::with_capacity(SEQ_LEN + body.len());
+            frame.put_u64(message.seq);
+            frame.put_slice(&body);
+            frame.freeze()
+        });
+        message
+    }
+
+    /// End the chat track with the broadcast
+    pub fn finish(self) {
+        self.log.finish();
+    }
+}
+
+/// A viewer's side of a broadcast's chat track
+pub struct ChatReader {
+    track: TrackConsumer,
+    group: Option<GroupConsumer>,
+    decryptor: Option<FrameDecryptor>,
+    next_seq: u64,
+}
+
+impl ChatReader {
+    /// Subscribe to the chat of `broadcast`
+    pub fn subscribe(broadcast: &BroadcastConsumer) -> Self {
+        Self::new(broadcast.subscribe_track(&Track {
+            name: CHAT_TRACK.to_string(),
+            priority: 0,
+        }))
+    }
+
+    pub fn new(track: TrackConsumer) -> Self {
+        Self {
+            track,
+            group: None,
+            decryptor: None,
+            next_seq: 0,
+        }
+    }
+
+    /// Open messages sealed with `key`
+    pub fn with_decryption(mut self, key: MediaKey) -> Self {
+        self.decryptor = Some(FrameDecryptor::new(key));
+        self
+    }
+
+    /// Next message, or None once the chat ends
+    ///
+    /// Messages come in order; ones that can't be read are skipped.
+    pub async fn recv(&mut self) -> Option<ChatMessage> {
+        loop {
+            let Some(group) = &mut self.group else {
+                match self.track.next_group().await {
+                    Ok(Some(group)) => self.group = Some(group),
+                    Ok(None) => return None,
+                    Err(e) => {
+                        debug!("chat track closed: {e}");
+                        return None;
+                    }
+                }
+                continue;
+            };
+            let frame = match group.read_frame().await {
+                Ok(Some(frame)) => frame,
+                Ok(None) | Err(_) => {
+                    self.group = None;
+                    continue;
+                }
+            };
+            match self.decode(&frame) {
+                Ok(message) if message.seq < self.next_seq => {}
+                Ok(message) => {
+                    if message.seq > self.next_seq && self.next_seq > 0 {
+                        debug!("missed chat messages {}..{}", self.next_seq, message.seq);
+                    }
+                    self.next_seq = message.seq + 1;
+                    return Some(message);
+                }
+                Err(e) => warn!("dropping chat message: {e:#}"),
+            }
+        }
+    }
+
+    fn decode(&mut self, frame: &[u8]) -> Result<ChatMessage> {
+        ensure!(frame.len() >= SEQ_LEN, "chat frame too short");
+        let (seq, body) = frame.split_at(SEQ_LEN);
+        let seq = u64::from_be_bytes(seq.try_into().unwrap());
+        let message: ChatMessage = match &mut self.decryptor {
+            Some(decryptor) => postcard::from_bytes(&decryptor.decrypt(seq as i64, body)?)?,
+            None => postcard::from_bytes(body)?,
+        };
+        ensure!(message.seq == seq, "chat sequence number mismatch");
+        Ok(message)
+    }
+}
+
+/// A viewer's posts to a broadcast's chat
+///
+/// Publish [`ChatSender::broadcast`] to the publisher at
+/// [`inbox_path`]; [`super::live_protocol::LiveSession::chat`] does both.
+/// Dropping the sender withdraws it.
+pub struct ChatSender {
+    log: TrackLog,
+    broadcast: BroadcastProducer,
+}
+
+impl ChatSender {
+    pub fn new() -> Self {
+        let produce = Broadcast::produce();
+        let mut broadcast = produce.producer;
+        let producer = broadcast.create_track(Track {
+            name: CHAT_TRACK.to_string(),
+            priority: 0,
+        });
+        Self {
+            log: TrackLog::new(producer),
+            broadcast,
+        }
+    }
+
+    /// The broadcast carrying the posts
+    pub fn broadcast(&self) -> BroadcastConsumer {
+        self.broadcast.consume()
+    }
+
+    /// Post to the chat
+    ///
+    /// The publisher may still drop the post, e.g. when it comes too fast.
+    pub fn send(&mut self, kind: ChatKind) -> Result<()> {
+        kind.validate()?;
+        let body = Bytes::from(postcard::to_stdvec(&kind).context("failed to encode chat post")?);
+        self.log.push(|_| body);
+        Ok(())
+    }
+}
+
+impl Default for ChatSender {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A viewer's post as read by the live actor
+#[derive(Debug)]
+pub(crate) struct InboundPost {
+    /// Session serial of the viewer
+    pub session: u64,
+    pub broadcast: String,
+    pub kind: ChatKind,
+}
+
+/// Broadcast a viewer posts to through the inbox at `path`, if it is one
+pub(crate) fn inbox_target(path: &str) -> Option<&str> {
+    path.strip_suffix(INBOX_SUFFIX)
+}
+
+/// Read the posts in a viewer's inbox for `target` until it closes
+pub(crate) async fn read_posts(
+    inbox: BroadcastConsumer,
+    session: u64,
+    target: String,
+    posts: mpsc::Sender<InboundPost>,
+) {
+    let mut track = inbox.subscribe_track(&Track {
+        name: CHAT_TRACK.to_string(),
+        priority: 0,
+    });
+    while let Ok(Some(mut group)) = track.next_group().await {
+        while let Ok(Some(frame)) = group.read_frame().await {
+            let kind = match postcard::from_bytes::<ChatKind>(&frame) {
+                Ok(kind) => kind,
+                Err(e) => {
+                    debug!("malformed chat post for {target}: {e}");
+                    continue;
+                }
+            };
+            let post = InboundPost {
+                session,
+                broadcast: target.clone(),
+                kind,
+            };
+            if posts.send(post).await.is_err() {
+                return;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+
+    fn post(text: &str) -> ChatPost {
+        ChatPost {
+            sender: iroh::SecretKey::generate(&mut rand::rng()).public(),
+            display_name: Some("bob".to_string()),
+            kind: ChatKind::Text(text.to_string()),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_chat_roundtrip_across_groups() {
+        let key = MediaKey::generate();
+        let track = Track::new(CHAT_TRACK).produce();
+        let mut writer = ChatWriter::new(track.producer).with_encryption(key.clone());
+        let mut reader = ChatReader::new(track.consumer).with_decryption(key);
+
+        // A reader keeping up gets every message, in order, across groups
+        for batch in [0..GROUP_MESSAGES, GROUP_MESSAGES..GROUP_MESSAGES + 3] {
+            for i in batch.clone() {
+                writer.post(post(&format!("message {i}")));
+            }
+            for i in batch {
+                let message = reader.recv().await.unwrap();
+                assert_eq!(message.seq, i);
+                assert_eq!(message.kind, ChatKind::Text(format!("message {i}")));
+                assert_eq!(message.display_name.as_deref(), Some("bob"));
+            }
+        }
+    }
+
+    #[test]
+    fn test_rate_limiter() {
+        let mut limiter = RateLimiter::new(ChatRate {
+            burst: 2,
+            per_second: 1.0,
+        });
+        let start = Instant::now();
+        assert!(limiter.allow(start));
+        assert!(limiter.allow(start));
+        assert!(!limiter.allow(start));
+        assert!(!limiter.allow(start + Duration::from_millis(500)));
+        assert!(limiter.allow(start + Duration::from_millis(1000)));
+        // Idle time refills no more than the burst
+        assert!(limiter.allow(start + Duration::from_secs(60)));
+        assert!(limiter.allow(start + Duration::from_secs(60)));
+        assert!(!limiter.allow(start + Duration::from_secs(60)));
+    }
+
+    #[test]
+    fn test_chat_kind_validate() {
+        assert!(ChatKind::Text("hi".to_string()).validate().is_ok());
+        assert!(ChatKind::Text("  ".to_string()).validate().is_err());
+        assert!(ChatKind::Text("x".repeat(MAX_TEXT + 1)).validate().is_err());
+        let reaction = |emoji: &str| ChatKind::Reaction {
+            target: 0,
+            emoji: emoji.to_string(),
+        };
+        assert!(reaction("👍").validate().is_ok());
+        assert!(reaction("").validate().is_err());
+    }
+}
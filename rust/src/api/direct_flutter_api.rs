@@ -7,6 +7,7 @@ use flutter_rust_bridge::frb;
 use super::direct_streaming::{
     DirectStreamingEndpoint, DirectStreamEvent, DirectMessage, DirectStreamTicket,
 };
+use super::identity::secret_key_from_bytes;
 
 /// Global direct streaming endpoint instance
 static DIRECT_ENDPOINT: once_cell::sync::OnceCell<Arc<Mutex<Option<DirectStreamingEndpoint>>>> = 
@@ -89,6 +90,19 @@ impl From<DirectStreamEvent> for FlutterDirectEvent {
 /// Initialize the direct streaming endpoint
 #[frb]
 pub async fn init_direct_streaming() -> Result<String, String> {
+    init_direct(None).await
+}
+
+/// Initialize the direct streaming endpoint with a stored identity
+///
+/// `secret_key` is 32 bytes, e.g. from `iroh_identity_generate`.
+#[frb]
+pub async fn init_direct_streaming_with_key(secret_key: Vec<u8>) -> Result<String, String> {
+    let key = secret_key_from_bytes(&secret_key).map_err(|e| e.to_string())?;
+    init_direct(Some(key)).await
+}
+
+async fn init_direct(secret_key: Option<iroh::SecretKey>) -> Result<String, String> {
     let holder = get_direct_endpoint_holder();
     let mut guard = holder.lock().await;
     
@@ -98,7 +112,7 @@ pub async fn init_direct_streaming() -> Result<String, String> {
         }
     }
     
-    let endpoint = DirectStreamingEndpoint::new(None)
+    let endpoint = DirectStreamingEndpoint::new(secret_key)
         .await
         .map_err(|e| e.to_string())?;
     
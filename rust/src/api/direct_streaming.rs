@@ -15,6 +15,8 @@ use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, mpsc, RwLock};
 use tracing::{info, warn, error, debug};
 
+use super::endpoint_config::EndpointConfig;
+
 /// ALPN protocol for our streaming
 pub const STREAMING_ALPN: &[u8] = b"cyberfly/streaming/0";
 
@@ -95,27 +97,22 @@ pub enum DirectStreamEvent {
 impl DirectStreamingEndpoint {
     /// Create a new direct streaming endpoint
     pub async fn new(secret_key: Option<SecretKey>) -> Result<Self> {
+        let config = EndpointConfig {
+            online_timeout: Duration::from_secs(10),
+            ..Default::default()
+        };
+        Self::with_config(secret_key, config).await
+    }
+
+    /// Create a new direct streaming endpoint with the given relay and discovery settings
+    pub async fn with_config(secret_key: Option<SecretKey>, config: EndpointConfig) -> Result<Self> {
         let secret_key = secret_key.unwrap_or_else(|| SecretKey::generate(&mut rand::rng()));
         
-        let endpoint = Endpoint::builder()
-            .secret_key(secret_key.clone())
-            .alpns(vec![STREAMING_ALPN.to_vec()])
-            .bind()
+        let endpoint = config
+            .bind(secret_key.clone(), vec![STREAMING_ALPN.to_vec()])
             .await?;
 
         info!("Direct streaming endpoint bound, id: {}", endpoint.id());
-        
-        // Wait for relay connection
-        info!("Waiting for endpoint to come online...");
-        let online_result = n0_future::time::timeout(
-            Duration::from_secs(10),
-            endpoint.online()
-        ).await;
-        
-        match online_result {
-            Ok(()) => info!("Endpoint is online"),
-            Err(_) => warn!("Timeout waiting for relay, continuing anyway"),
-        }
 
         let (event_tx, event_rx) = mpsc::unbounded_channel();
 
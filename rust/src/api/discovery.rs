@@ -0,0 +1,196 @@
+//! Browsing the broadcasts a peer announces
+//!
+//! A [`Discovery`] opens a session to a node and follows its moq-lite
+//! announcements, so a viewer can list what the node is streaming and watch
+//! broadcasts come and go without a ticket for each one. It only sees what
+//! the node offers it: broadcasts the node's access rules hide from the
+//! session's token are never announced.
+//!
+//! A prefix narrows discovery to one part of the node's broadcast names,
+//! matched on whole `/`-separated segments: `"room"` covers `"room"` and
+//! `"room/alice"` but not `"roomy"`. The empty prefix covers everything.
+
+use std::collections::BTreeSet;
+use std::sync::{Arc, RwLock};
+
+use anyhow::Result;
+use iroh::{EndpointAddr, EndpointId};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{debug, info, warn};
+
+use super::access::SignedToken;
+use super::live_protocol::{Live, LiveSession};
+
+/// Discovery events buffered for a slow reader
+pub const EVENT_CAPACITY: usize = 64;
+
+/// Whether `name` falls under `prefix`
+pub fn under_prefix(prefix: &str, name: &str) -> bool {
+    let prefix = prefix.trim_end_matches('/');
+    prefix.is_empty()
+        || name
+            .strip_prefix(prefix)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+/// A broadcast appearing or going away
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscoveryEvent {
+    Added { name: String },
+    Removed { name: String },
+}
+
+/// Announcements of one peer, under a prefix
+pub struct Discovery {
+    remote: EndpointId,
+    prefix: String,
+    broadcasts: Arc<RwLock<BTreeSet<String>>>,
+    events: mpsc::Receiver<DiscoveryEvent>,
+    task: JoinHandle<()>,
+    _cancel: DropGuard,
+}
+
+impl Discovery {
+    /// Open a session to `remote` and follow its broadcasts under `prefix`
+    pub async fn connect(
+        live: &Live,
+        remote: impl Into<EndpointAddr>,
+        prefix: impl ToString,
+        token: Option<&SignedToken>,
+    ) -> Result<Self> {
+        let session = live.connect_with_token(remote, token).await?;
+        Ok(Self::new(session, prefix))
+    }
+
+    /// Follow the broadcasts announced on an open session
+    ///
+    /// Takes the session over; announcements it already consumed are not
+    /// seen again.
+    pub fn new(session: LiveSession, prefix: impl ToString) -> Self {
+        let remote = session.remote;
+        let prefix = prefix.to_string();
+        let broadcasts = Arc::new(RwLock::new(BTreeSet::new()));
+        let (events_tx, events) = mpsc::channel(EVENT_CAPACITY);
+        let cancel = CancellationToken::new();
+        info!("discovering broadcasts of {} under {prefix:?}", remote.fmt_short());
+        let task = tokio::spawn(follow(
+            session,
+            prefix.clone(),
+            broadcasts.clone(),
+            events_tx,
+            cancel.clone(),
+        ));
+        Self {
+            remote,
+            prefix,
+            broadcasts,
+            events,
+            task,
+            _cancel: cancel.drop_guard(),
+        }
+    }
+
+    /// Node whose broadcasts are followed
+    pub fn remote(&self) -> EndpointId {
+        self.remote
+    }
+
+    pub fn prefix(&self) -> &str {
+        &self.prefix
+    }
+
+    /// Broadcasts currently announced under the prefix, sorted by name
+    pub fn broadcasts(&self) -> Vec<String> {
+        self.broadcasts.read().unwrap().iter().cloned().collect()
+    }
+
+    /// Whether the session is still open
+    pub fn is_active(&self) -> bool {
+        !self.task.is_finished()
+    }
+
+    /// Next broadcast added or removed, or None once the session closes
+    ///
+    /// Events are dropped rather than held for a reader more than
+    /// [`EVENT_CAPACITY`] behind; [`Discovery::broadcasts`] stays exact.
+    pub async fn recv(&mut self) -> Option<DiscoveryEvent> {
+        self.events.recv().await
+    }
+
+    /// Next broadcast added or removed, if one is waiting
+    pub fn try_recv(&mut self) -> Option<DiscoveryEvent> {
+        self.events.try_recv().ok()
+    }
+
+    /// Stop following and close the session
+    pub async fn close(self) {
+        let Self { _cancel: cancel, task, .. } = self;
+        drop(cancel);
+        if let Err(e) = task.await {
+            warn!("discovery task failed: {e}");
+        }
+    }
+}
+
+async fn follow(
+    mut session: LiveSession,
+    prefix: String,
+    broadcasts: Arc<RwLock<BTreeSet<String>>>,
+    events: mpsc::Sender<DiscoveryEvent>,
+    cancel: CancellationToken,
+) {
+    let report = |event: DiscoveryEvent| {
+        if events.try_send(event).is_err() {
+            debug!("discovery event dropped, reader lagging or gone");
+        }
+    };
+
+    loop {
+        let announced = tokio::select! {
+            _ = cancel.cancelled() => break,
+            announced = session.announced() => announced,
+        };
+        let Some((name, broadcast)) = announced else {
+            break;
+        };
+        if !under_prefix(&prefix, &name) {
+            continue;
+        }
+        let event = if broadcast.is_some() {
+            if !broadcasts.write().unwrap().insert(name.clone()) {
+                continue;
+            }
+            DiscoveryEvent::Added { name }
+        } else {
+            if !broadcasts.write().unwrap().remove(&name) {
+                continue;
+            }
+            DiscoveryEvent::Removed { name }
+        };
+        report(event);
+    }
+
+    // Whatever was still announced is gone with the session
+    let remaining = std::mem::take(&mut *broadcasts.write().unwrap());
+    for name in remaining {
+        report(DiscoveryEvent::Removed { name });
+    }
+    info!("stopped discovering broadcasts of {}", session.remote.fmt_short());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_under_prefix() {
+        assert!(under_prefix("", "anything"));
+        assert!(under_prefix("room", "room"));
+        assert!(under_prefix("room", "room/alice"));
+        assert!(under_prefix("room/", "room/alice"));
+        assert!(!under_prefix("room", "roomy"));
+        assert!(!under_prefix("room/alice", "room"));
+    }
+}
@@ -0,0 +1,225 @@
+//! End-to-end encryption of media payloads
+//!
+//! QUIC only protects a broadcast hop by hop, so every relay a broadcast
+//! passes through sees its frames in the clear. With a [`MediaKey`] the
+//! publisher seals each frame payload with XChaCha20-Poly1305 before it
+//! enters the MoQ track, and only subscribers holding the key can open it.
+//! Relays forward the sealed frames as they are; they never need the key.
+//!
+//! The key travels in the publisher's ticket. Frames are not sealed with it
+//! directly: every group gets its own key derived from the media key and a
+//! group epoch, so no derived key seals more than one group. This limits
+//! how much each key is used; it is not forward secrecy. Anyone holding the
+//! media key can derive every group key, past and future, so a leaked
+//! ticket exposes the whole broadcast, including recordings of it. A sealed
+//! payload is laid out as
+//!
+//! ```text
+//! version (1) | epoch (u64 BE) | nonce (24) | ciphertext + tag (16)
+//! ```
+//!
+//! The frame timestamp stays in the clear, in hang's framing, and is bound
+//! to the payload as associated data. The catalog is not encrypted either;
+//! relays can see codec parameters, but not media.
+
+use std::fmt;
+
+use anyhow::{Context, Result, bail, ensure};
+use bytes::{BufMut, Bytes, BytesMut};
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{XChaCha20Poly1305, XNonce};
+use serde::{Deserialize, Serialize};
+
+/// Length of a media key in bytes
+pub const MEDIA_KEY_LEN: usize = 32;
+
+/// Layout version of sealed payloads
+const VERSION: u8 = 1;
+const NONCE_LEN: usize = 24;
+const TAG_LEN: usize = 16;
+/// version + epoch
+const HEADER_LEN: usize = 1 + 8;
+
+/// blake3 context for per-group keys
+const GROUP_KEY_CONTEXT: &str = "cyberfly-live v1 media group key";
+
+/// Secret for sealing a broadcast's frames
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MediaKey([u8; MEDIA_KEY_LEN]);
+
+impl MediaKey {
+    /// Generate a new random key
+    pub fn generate() -> Self {
+        Self(rand::random())
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        let bytes: [u8; MEDIA_KEY_LEN] = bytes
+            .try_into()
+            .with_context(|| format!("media key must be {MEDIA_KEY_LEN} bytes, got {}", bytes.len()))?;
+        Ok(Self(bytes))
+    }
+
+    pub fn to_bytes(&self) -> [u8; MEDIA_KEY_LEN] {
+        self.0
+    }
+
+    /// Cipher for one group epoch
+    fn group_cipher(&self, epoch: u64) -> XChaCha20Poly1305 {
+        let mut material = [0u8; MEDIA_KEY_LEN + 8];
+        material[..MEDIA_KEY_LEN].copy_from_slice(&self.0);
+        material[MEDIA_KEY_LEN..].copy_from_slice(&epoch.to_be_bytes());
+        let key = blake3::derive_key(GROUP_KEY_CONTEXT, &material);
+        XChaCha20Poly1305::new(&key.into())
+    }
+}
+
+impl fmt::Debug for MediaKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("MediaKey(..)")
+    }
+}
+
+/// Associated data binding a payload to its header and timestamp
+fn associated_data(header: &[u8], pts_us: i64) -> [u8; HEADER_LEN + 8] {
+    let mut aad = [0u8; HEADER_LEN + 8];
+    aad[..HEADER_LEN].copy_from_slice(header);
+    aad[HEADER_LEN..].copy_from_slice(&pts_us.to_be_bytes());
+    aad
+}
+
+/// Seals the frames of one track
+pub struct FrameEncryptor {
+    key: MediaKey,
+    epoch: u64,
+    cipher: XChaCha20Poly1305,
+}
+
+impl FrameEncryptor {
+    pub fn new(key: MediaKey) -> Self {
+        // A random start keeps epochs from repeating when a publisher
+        // restarts with the same key
+        let epoch = rand::random();
+        let cipher = key.group_cipher(epoch);
+        Self { key, epoch, cipher }
+    }
+
+    /// Switch to the next group's key
+    ///
+    /// Track writers call this whenever they start a group.
+    pub fn rotate(&mut self) {
+        self.epoch = self.epoch.wrapping_add(1);
+        self.cipher = self.key.group_cipher(self.epoch);
+    }
+
+    /// Epoch of the current group key
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    /// Seal a frame payload
+    pub fn encrypt(&self, pts_us: i64, payload: &[u8]) -> Bytes {
+        let mut header = [0u8; HEADER_LEN];
+        header[0] = VERSION;
+        header[1..].copy_from_slice(&self.epoch.to_be_bytes());
+        let nonce = XNonce::from(rand::random::<[u8; NONCE_LEN]>());
+        let aad = associated_data(&header, pts_us);
+        let ciphertext = self
+            .cipher
+            .encrypt(&nonce, Payload { msg: payload, aad: &aad })
+            .expect("payload fits in one message");
+
+        let mut sealed = BytesMut::with_capacity(HEADER_LEN + NONCE_LEN + ciphertext.len());
+        sealed.put_slice(&header);
+        sealed.put_slice(&nonce);
+        sealed.put_slice(&ciphertext);
+        sealed.freeze()
+    }
+}
+
+/// Opens the frames of one track
+pub struct FrameDecryptor {
+    key: MediaKey,
+    /// Cipher for the last epoch seen; frames of a group share it
+    current: Option<(u64, XChaCha20Poly1305)>,
+}
+
+impl FrameDecryptor {
+    pub fn new(key: MediaKey) -> Self {
+        Self { key, current: None }
+    }
+
+    /// Open a sealed frame payload
+    ///
+    /// Fails for frames sealed with another key, tampered frames and frames
+    /// whose timestamp was changed in transit.
+    pub fn decrypt(&mut self, pts_us: i64, sealed: &[u8]) -> Result<Bytes> {
+        ensure!(sealed.len() >= HEADER_LEN + NONCE_LEN + TAG_LEN, "sealed frame too short");
+        let (header, rest) = sealed.split_at(HEADER_LEN);
+        if header[0] != VERSION {
+            bail!("unsupported sealed frame version {}", header[0]);
+        }
+        let epoch = u64::from_be_bytes(header[1..].try_into().unwrap());
+        let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+        if self.current.as_ref().is_none_or(|(current, _)| *current != epoch) {
+            self.current = Some((epoch, self.key.group_cipher(epoch)));
+        }
+        let (_, cipher) = self.current.as_ref().unwrap();
+        let aad = associated_data(header, pts_us);
+        let plaintext = cipher
+            .decrypt(XNonce::from_slice(nonce), Payload { msg: ciphertext, aad: &aad })
+            .map_err(|_| anyhow::anyhow!("failed to decrypt frame (wrong key or tampered)"))?;
+        Ok(Bytes::from(plaintext))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_frame_roundtrip_across_rotation() {
+        let key = MediaKey::generate();
+        let mut encryptor = FrameEncryptor::new(key.clone());
+        let mut decryptor = FrameDecryptor::new(key);
+
+        let first = encryptor.encrypt(0, b"keyframe");
+        assert!(!first.windows(8).any(|w| w == b"keyframe"));
+        let epoch = encryptor.epoch();
+        encryptor.rotate();
+        assert_eq!(encryptor.epoch(), epoch.wrapping_add(1));
+        let second = encryptor.encrypt(33_000, b"next group");
+        assert_ne!(first[1..HEADER_LEN], second[1..HEADER_LEN]);
+
+        assert_eq!(decryptor.decrypt(0, &first).unwrap(), Bytes::from_static(b"keyframe"));
+        assert_eq!(decryptor.decrypt(33_000, &second).unwrap(), Bytes::from_static(b"next group"));
+        // Late frames from an earlier group still open
+        assert_eq!(decryptor.decrypt(0, &first).unwrap(), Bytes::from_static(b"keyframe"));
+    }
+
+    #[test]
+    fn test_frame_rejects_wrong_key_and_tampering() {
+        let key = MediaKey::generate();
+        let encryptor = FrameEncryptor::new(key.clone());
+        let sealed = encryptor.encrypt(40_000, b"payload");
+
+        assert!(FrameDecryptor::new(MediaKey::generate()).decrypt(40_000, &sealed).is_err());
+
+        let mut decryptor = FrameDecryptor::new(key);
+        assert!(decryptor.decrypt(80_000, &sealed).is_err(), "timestamp is authenticated");
+        let mut tampered = sealed.to_vec();
+        *tampered.last_mut().unwrap() ^= 1;
+        assert!(decryptor.decrypt(40_000, &tampered).is_err());
+        assert!(decryptor.decrypt(40_000, &sealed[..10]).is_err());
+        assert!(decryptor.decrypt(40_000, &sealed).is_ok());
+    }
+
+    #[test]
+    fn test_media_key_bytes() {
+        let key = MediaKey::generate();
+        assert_eq!(MediaKey::from_bytes(&key.to_bytes()).unwrap(), key);
+        assert!(MediaKey::from_bytes(&[0; 16]).is_err());
+        assert_eq!(format!("{key:?}"), "MediaKey(..)");
+    }
+}
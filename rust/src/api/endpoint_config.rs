@@ -0,0 +1,121 @@
+//! Endpoint configuration shared by `LiveNode` and `DirectStreamingEndpoint`
+//!
+//! By default endpoints use the n0 relays and DNS discovery, and startup
+//! waits for a home relay so tickets carry a relay URL. For LAN-only events
+//! relays can be disabled: peers then reach each other by direct address,
+//! taken from tickets or found with mDNS, and startup never waits on the
+//! internet.
+
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+use anyhow::Result;
+use iroh::discovery::mdns::MdnsDiscovery;
+use iroh::{Endpoint, EndpointAddr, RelayMode, RelayUrl, SecretKey};
+use n0_future::time::{Duration, Instant, sleep, timeout};
+use tracing::{info, warn};
+
+/// How long a relay-less endpoint waits for its interface addresses
+const DIRECT_ADDR_TIMEOUT: Duration = Duration::from_secs(2);
+
+/// Which relay servers an endpoint uses
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum RelayOption {
+    /// The public n0 relays
+    #[default]
+    Default,
+    /// A self-hosted relay
+    Custom(RelayUrl),
+    /// No relays and no DNS discovery; direct connections only
+    Disabled,
+}
+
+/// Endpoint configuration
+#[derive(Debug, Clone)]
+pub struct EndpointConfig {
+    pub relay: RelayOption,
+    /// UDP port to bind on all interfaces; random when None
+    pub bind_port: Option<u16>,
+    /// Find peers on the local network with mDNS
+    pub local_discovery: bool,
+    /// How long startup waits for a home relay
+    pub online_timeout: Duration,
+}
+
+impl Default for EndpointConfig {
+    fn default() -> Self {
+        Self {
+            relay: RelayOption::Default,
+            bind_port: None,
+            local_discovery: false,
+            online_timeout: Duration::from_secs(15),
+        }
+    }
+}
+
+impl EndpointConfig {
+    /// LAN-only configuration: no relays, mDNS discovery on
+    pub fn local() -> Self {
+        Self {
+            relay: RelayOption::Disabled,
+            local_discovery: true,
+            ..Default::default()
+        }
+    }
+
+    pub fn relays_enabled(&self) -> bool {
+        self.relay != RelayOption::Disabled
+    }
+
+    /// Bind an endpoint and wait until it has an address worth sharing
+    pub async fn bind(&self, secret_key: SecretKey, alpns: Vec<Vec<u8>>) -> Result<Endpoint> {
+        let mut builder = match &self.relay {
+            RelayOption::Default => Endpoint::builder(),
+            RelayOption::Custom(url) => Endpoint::builder().relay_mode(RelayMode::Custom(url.clone().into())),
+            // The default builder would also publish to and resolve via n0 DNS
+            RelayOption::Disabled => Endpoint::empty_builder(RelayMode::Disabled),
+        };
+        builder = builder.secret_key(secret_key).alpns(alpns);
+        if let Some(port) = self.bind_port {
+            builder = builder.bind_addr_v4(SocketAddrV4::new(Ipv4Addr::UNSPECIFIED, port));
+        }
+        if self.local_discovery {
+            builder = builder.discovery(MdnsDiscovery::builder());
+        }
+        let endpoint = builder.bind().await?;
+
+        if self.relays_enabled() {
+            match timeout(self.online_timeout, endpoint.online()).await {
+                Ok(()) => info!("endpoint is online and connected to relay"),
+                Err(_) => warn!("timeout waiting for relay connection - P2P may not work reliably"),
+            }
+        } else {
+            wait_for_direct_addrs(&endpoint).await;
+        }
+        Ok(endpoint)
+    }
+
+    /// Address of `endpoint` to put in tickets
+    ///
+    /// Without relays this is only the direct addresses.
+    pub fn ticket_addr(&self, endpoint: &Endpoint) -> EndpointAddr {
+        let addr = endpoint.addr();
+        if self.relays_enabled() {
+            return addr;
+        }
+        addr.ip_addrs()
+            .fold(EndpointAddr::new(addr.id), |direct, ip| direct.with_ip_addr(*ip))
+    }
+}
+
+/// Give the endpoint a moment to learn its interface addresses
+async fn wait_for_direct_addrs(endpoint: &Endpoint) {
+    let start = Instant::now();
+    while endpoint.addr().ip_addrs().next().is_none() {
+        if start.elapsed() > DIRECT_ADDR_TIMEOUT {
+            warn!("no direct addresses yet, tickets will not be reachable");
+            return;
+        }
+        sleep(Duration::from_millis(50)).await;
+    }
+    info!("relays disabled, direct addresses: {:?}", endpoint.addr().ip_addrs().collect::<Vec<_>>());
+}
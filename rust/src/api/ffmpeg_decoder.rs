@@ -0,0 +1,208 @@
+//! FFmpeg H264 decoder for the subscribe pipeline
+//!
+//! Decodes received H.264 access units into raw pixel buffers
+//! ([`DecodedFrame`]) in the format the player asked for.
+//!
+//! The decoder is configured from the catalog: the `description` field holds
+//! the avcC record (SPS/PPS) for length-prefixed streams; without it the
+//! stream is expected in Annex B with in-band parameter sets. After a decode
+//! error or a corrupt picture (missing reference) frames are dropped until
+//! the next keyframe.
+//!
+//! Enable with: cargo build --features ffmpeg
+
+#![cfg(feature = "ffmpeg")]
+
+use std::time::Duration;
+
+use anyhow::{Context, Result, bail};
+use ffmpeg_next::{self as ffmpeg, codec, frame::Video as VideoFrame};
+use hang::catalog::{VideoCodec, VideoConfig};
+use tracing::{debug, info, warn};
+
+use super::av::{DecodedFrame, PixelFormat, PlaybackConfig};
+use super::ffmpeg_encoder::{Rescaler, pixel, plane_layout};
+use super::subscribe_broadcast::{ReceivedVideoFrame, VideoDecoder};
+
+/// H.264 software decoder
+pub struct H264Decoder {
+    decoder: Option<ffmpeg::decoder::Video>,
+    rescaler: Rescaler,
+    playback: PlaybackConfig,
+    /// Track the decoder was configured for
+    track: Option<String>,
+    /// Drop frames until a keyframe restores the reference state
+    waiting_for_keyframe: bool,
+}
+
+// Make decoder Send safe
+unsafe impl Send for H264Decoder {}
+
+impl H264Decoder {
+    /// Create a decoder producing frames for `playback`
+    ///
+    /// The decoder is opened by [`VideoDecoder::configure`] once the
+    /// track's catalog entry is known.
+    pub fn new(playback: PlaybackConfig) -> Result<Self> {
+        ffmpeg::init()?;
+        Ok(Self {
+            decoder: None,
+            rescaler: Rescaler::new(pixel(playback.pixel_format), playback.viewport)?,
+            playback,
+            track: None,
+            waiting_for_keyframe: true,
+        })
+    }
+
+    /// Create a decoder and open it for a catalog entry
+    pub fn from_config(config: &VideoConfig, playback: PlaybackConfig) -> Result<Self> {
+        let mut decoder = Self::new(playback)?;
+        decoder.open(config)?;
+        Ok(decoder)
+    }
+
+    /// Playback configuration
+    pub fn playback(&self) -> &PlaybackConfig {
+        &self.playback
+    }
+
+    /// Track the decoder is configured for
+    pub fn track(&self) -> Option<&str> {
+        self.track.as_deref()
+    }
+
+    /// Whether frames are being dropped until the next keyframe
+    pub fn is_waiting_for_keyframe(&self) -> bool {
+        self.waiting_for_keyframe
+    }
+
+    fn open(&mut self, config: &VideoConfig) -> Result<()> {
+        if !matches!(config.codec, VideoCodec::H264(_)) {
+            bail!("not an H.264 rendition: {}", config.codec);
+        }
+
+        let codec = ffmpeg::codec::decoder::find(codec::Id::H264)
+            .context("H.264 decoder not found")?;
+        let mut ctx = codec::context::Context::new_with_codec(codec);
+        // Slice threading only; frame threading adds a frame of latency per thread
+        ctx.set_threading(codec::threading::Config::kind(codec::threading::Type::Slice));
+
+        if let Some(description) = &config.description {
+            unsafe {
+                let ctx_mut = ctx.as_mut_ptr();
+                let padding = ffmpeg::ffi::AV_INPUT_BUFFER_PADDING_SIZE as usize;
+                // Freed with the codec context
+                let extradata =
+                    ffmpeg::ffi::av_mallocz(description.len() + padding) as *mut u8;
+                if extradata.is_null() {
+                    bail!("failed to allocate decoder extradata");
+                }
+                std::ptr::copy_nonoverlapping(description.as_ptr(), extradata, description.len());
+                (*ctx_mut).extradata = extradata;
+                (*ctx_mut).extradata_size = description.len() as i32;
+            }
+        }
+
+        let decoder = ctx.decoder().video()?;
+        info!(
+            "Initialized H264 decoder: {}x{} ({})",
+            config.coded_width.unwrap_or(0),
+            config.coded_height.unwrap_or(0),
+            if config.description.is_some() { "avcC" } else { "Annex B" }
+        );
+
+        self.decoder = Some(decoder);
+        self.waiting_for_keyframe = true;
+        Ok(())
+    }
+
+    /// Flush the decoder and drop frames until the next keyframe
+    fn resync(&mut self) {
+        if let Some(decoder) = self.decoder.as_mut() {
+            decoder.flush();
+        }
+        self.waiting_for_keyframe = true;
+    }
+
+    /// Convert a decoded picture into a tightly packed buffer
+    fn convert(&mut self, picture: &VideoFrame, fallback_pts_us: i64) -> Result<DecodedFrame> {
+        let output = self.rescaler.process(picture)?;
+        let pts_us = picture.pts().unwrap_or(fallback_pts_us).max(0);
+        Ok(DecodedFrame {
+            data: pack(&output, self.playback.pixel_format),
+            width: output.width(),
+            height: output.height(),
+            pixel_format: self.playback.pixel_format,
+            timestamp: Duration::from_micros(pts_us as u64),
+        })
+    }
+}
+
+impl VideoDecoder for H264Decoder {
+    fn configure(&mut self, track: &str, config: &VideoConfig) -> Result<()> {
+        self.open(config)?;
+        self.track = Some(track.to_string());
+        Ok(())
+    }
+
+    fn decode(&mut self, frame: &ReceivedVideoFrame) -> Result<Option<DecodedFrame>> {
+        if self.waiting_for_keyframe {
+            if !frame.is_keyframe {
+                return Ok(None);
+            }
+            self.waiting_for_keyframe = false;
+        }
+        let Some(decoder) = self.decoder.as_mut() else {
+            bail!("decoder not configured");
+        };
+
+        let mut packet = ffmpeg::Packet::copy(&frame.data);
+        packet.set_pts(Some(frame.pts_us));
+        if frame.is_keyframe {
+            packet.set_flags(ffmpeg::packet::Flags::KEY);
+        }
+        if let Err(e) = decoder.send_packet(&packet) {
+            warn!(sequence = frame.sequence, "decode failed, waiting for keyframe: {e}");
+            self.resync();
+            return Ok(None);
+        }
+
+        // Low-delay streams give at most one picture per packet; keep the newest
+        let mut latest = None;
+        let mut picture = VideoFrame::empty();
+        loop {
+            let Some(decoder) = self.decoder.as_mut() else { break };
+            match decoder.receive_frame(&mut picture) {
+                Ok(()) if picture.is_corrupt() => {
+                    debug!(sequence = frame.sequence, "corrupt picture, waiting for keyframe");
+                    self.resync();
+                    return Ok(None);
+                }
+                Ok(()) => latest = Some(self.convert(&picture, frame.pts_us)?),
+                Err(ffmpeg::Error::Eof) => break,
+                Err(ffmpeg::Error::Other { errno }) if errno == ffmpeg::util::error::EAGAIN => break,
+                Err(e) => {
+                    warn!(sequence = frame.sequence, "decode failed, waiting for keyframe: {e}");
+                    self.resync();
+                    return Ok(None);
+                }
+            }
+        }
+        Ok(latest)
+    }
+}
+
+/// Copy a picture's planes into one buffer without row padding
+fn pack(frame: &VideoFrame, format: PixelFormat) -> Vec<u8> {
+    let planes = plane_layout(format, frame.width(), frame.height());
+
+    let mut out = Vec::with_capacity(planes.iter().map(|(row, rows)| row * rows).sum());
+    for (index, (row, rows)) in planes.into_iter().enumerate() {
+        let stride = frame.stride(index);
+        let data = frame.data(index);
+        for y in 0..rows {
+            out.extend_from_slice(&data[y * stride..y * stride + row]);
+        }
+    }
+    out
+}
@@ -14,6 +14,8 @@ use anyhow::{Context, Result, anyhow};
 use ffmpeg_next::{self as ffmpeg, codec, format::Pixel, frame::Video as VideoFrame};
 use tracing::{debug, info, trace, warn};
 
+use super::av::PixelFormat;
+
 /// Video preset for encoding
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VideoPreset {
@@ -105,6 +107,30 @@ struct EncoderOpts {
     bitrate: u64,
 }
 
+/// FFmpeg pixel format for an `av` pixel format
+pub(crate) fn pixel(format: PixelFormat) -> Pixel {
+    match format {
+        PixelFormat::Rgba => Pixel::RGBA,
+        PixelFormat::Bgra => Pixel::BGRA,
+        PixelFormat::Rgb => Pixel::RGB24,
+        PixelFormat::Yuv420p => Pixel::YUV420P,
+        PixelFormat::Nv12 => Pixel::NV12,
+        PixelFormat::Nv21 => Pixel::NV21,
+    }
+}
+
+/// (bytes per row, rows) of each plane of a tightly packed picture
+pub(crate) fn plane_layout(format: PixelFormat, width: u32, height: u32) -> Vec<(usize, usize)> {
+    let (w, h) = (width as usize, height as usize);
+    let (cw, ch) = (w.div_ceil(2), h.div_ceil(2));
+    match format {
+        PixelFormat::Rgba | PixelFormat::Bgra => vec![(w * 4, h)],
+        PixelFormat::Rgb => vec![(w * 3, h)],
+        PixelFormat::Yuv420p => vec![(w, h), (cw, ch), (cw, ch)],
+        PixelFormat::Nv12 | PixelFormat::Nv21 => vec![(w, h), (cw * 2, ch)],
+    }
+}
+
 /// Color space converter (rescaler)
 pub struct Rescaler {
     ctx: Option<ffmpeg::software::scaling::Context>,
@@ -170,6 +196,8 @@ pub struct H264Encoder {
     backend: HwBackend,
     opts: EncoderOpts,
     frame_count: u64,
+    /// Encode the next frame as an IDR
+    force_keyframe: bool,
 }
 
 // Make encoder Send safe  
@@ -209,6 +237,7 @@ impl H264Encoder {
                         backend,
                         opts,
                         frame_count: 0,
+                        force_keyframe: false,
                     });
                 }
                 Err(e) => {
@@ -318,10 +347,13 @@ impl H264Encoder {
             match self.encoder.receive_packet(&mut packet) {
                 Ok(()) => {
                     let payload = packet.data().unwrap_or(&[]).to_vec();
+                    // Packets drained by `flush` trail the frame count, so
+                    // go by their own PTS (in frames)
+                    let index = packet.pts().map_or(self.frame_count, |pts| pts.max(0) as u64);
                     let hang_frame = hang::Frame {
                         payload: payload.into(),
                         timestamp: Duration::from_nanos(
-                            self.frame_count * 1_000_000_000 / self.opts.framerate as u64,
+                            index * 1_000_000_000 / self.opts.framerate as u64,
                         ),
                         keyframe: packet.is_key(),
                     };
@@ -337,8 +369,8 @@ impl H264Encoder {
     }
 
     /// Encode a frame
-    pub fn encode_frame(&mut self, mut frame: VideoFrame) -> Result<()> {
-        frame.set_pts(Some(self.frame_count as i64));
+    pub fn encode_frame(&mut self, frame: VideoFrame) -> Result<()> {
+        let pts = self.frame_count as i64;
         self.frame_count += 1;
 
         if self.frame_count % self.opts.framerate as u64 == 0 {
@@ -348,15 +380,20 @@ impl H264Encoder {
                 frame.width(),
                 frame.height(),
                 frame.format(),
-                frame.pts(),
+                pts,
             );
         }
 
         // Convert to YUV420P
-        let frame = self
+        let mut frame = self
             .rescaler
             .process(&frame)
             .context("failed to color-convert frame")?;
+        // Scaling doesn't carry the PTS over
+        frame.set_pts(Some(pts));
+        if std::mem::take(&mut self.force_keyframe) {
+            frame.set_kind(ffmpeg::picture::Type::I);
+        }
 
         self.encoder
             .send_frame(&frame)
@@ -365,10 +402,26 @@ impl H264Encoder {
         Ok(())
     }
 
-    /// Flush encoder
-    pub fn flush(&mut self) -> Result<()> {
+    /// Make the next encoded frame a keyframe
+    ///
+    /// Use after `PublishHandle::take_keyframe_request` fires so the
+    /// publisher can resume without waiting for the next GOP.
+    pub fn request_keyframe(&mut self) {
+        self.force_keyframe = true;
+    }
+
+    /// Flush encoder, returning the packets it was still holding
+    ///
+    /// Call once at the end of the stream so the last frames aren't lost;
+    /// the encoder takes no more frames afterwards.
+    pub fn flush(&mut self) -> Result<Vec<hang::Frame>> {
         self.encoder.send_eof()?;
-        Ok(())
+        let mut packets = Vec::new();
+        while let Poll::Ready(Some(packet)) = self.receive_packet()? {
+            packets.push(packet);
+        }
+        debug!("Flushed {} packets from the encoder", packets.len());
+        Ok(packets)
     }
 
     /// Push raw RGBA/BGRA frame
@@ -0,0 +1,142 @@
+//! Persistent node identity
+//!
+//! An endpoint's id is the public half of its [`SecretKey`], and every
+//! ticket a node hands out names that id. Generating a fresh key on each
+//! launch therefore invalidates every ticket shared before. These helpers
+//! keep one key across restarts, either in a file owned by the app or as
+//! bytes the app stores itself (e.g. in the platform keychain).
+//!
+//! Files hold the key as base32 text and are created readable by the owner
+//! only. They are not encrypted; apps that need that should keep the bytes
+//! in secure storage instead.
+
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+
+use anyhow::{Context, Result};
+use iroh::SecretKey;
+use tracing::info;
+
+/// Length of an encoded secret key in bytes
+pub const SECRET_KEY_LEN: usize = 32;
+
+/// Generate a new random identity
+pub fn generate_secret_key() -> SecretKey {
+    SecretKey::generate(&mut rand::rng())
+}
+
+/// Raw bytes of a secret key, for apps storing it themselves
+pub fn secret_key_to_bytes(key: &SecretKey) -> [u8; SECRET_KEY_LEN] {
+    key.to_bytes()
+}
+
+/// Restore a secret key from [`secret_key_to_bytes`] output
+pub fn secret_key_from_bytes(bytes: &[u8]) -> Result<SecretKey> {
+    let bytes: &[u8; SECRET_KEY_LEN] = bytes
+        .try_into()
+        .with_context(|| format!("secret key must be {SECRET_KEY_LEN} bytes, got {}", bytes.len()))?;
+    Ok(SecretKey::from_bytes(bytes))
+}
+
+/// Encode a secret key as text, in the same base32 alphabet as tickets
+pub fn encode_secret_key(key: &SecretKey) -> String {
+    data_encoding::BASE32_NOPAD
+        .encode(&key.to_bytes())
+        .to_ascii_lowercase()
+}
+
+/// Decode a secret key from [`encode_secret_key`] output
+pub fn decode_secret_key(s: &str) -> Result<SecretKey> {
+    let bytes = data_encoding::BASE32_NOPAD
+        .decode(s.trim().to_ascii_uppercase().as_bytes())
+        .context("invalid secret key encoding")?;
+    secret_key_from_bytes(&bytes)
+}
+
+/// Write a secret key to `path`, readable by the owner only
+///
+/// The key is written to a temporary file first, so a crash never leaves a
+/// truncated key behind.
+pub fn save_secret_key(path: impl AsRef<Path>, key: &SecretKey) -> Result<()> {
+    let path = path.as_ref();
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+
+    let tmp = path.with_extension("tmp");
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options
+        .open(&tmp)
+        .with_context(|| format!("failed to create {}", tmp.display()))?;
+    writeln!(file, "{}", encode_secret_key(key))?;
+    file.sync_all()?;
+    fs::rename(&tmp, path)?;
+    Ok(())
+}
+
+/// Read a secret key written by [`save_secret_key`]
+pub fn load_secret_key(path: impl AsRef<Path>) -> Result<SecretKey> {
+    let path = path.as_ref();
+    let text = fs::read_to_string(path).with_context(|| format!("failed to read {}", path.display()))?;
+    decode_secret_key(&text).with_context(|| format!("invalid secret key in {}", path.display()))
+}
+
+/// Load the identity at `path`, creating it on first use
+///
+/// An unreadable or corrupt file is an error rather than being replaced, so
+/// a node never silently changes its id.
+pub fn load_or_create_secret_key(path: impl AsRef<Path>) -> Result<SecretKey> {
+    let path = path.as_ref();
+    if path.exists() {
+        return load_secret_key(path);
+    }
+    let key = generate_secret_key();
+    save_secret_key(path, &key)?;
+    info!("created new identity {} at {}", key.public().fmt_short(), path.display());
+    Ok(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_secret_key_roundtrip() {
+        let key = generate_secret_key();
+        let bytes = secret_key_to_bytes(&key);
+        assert_eq!(secret_key_from_bytes(&bytes).unwrap().public(), key.public());
+        assert!(secret_key_from_bytes(&bytes[..31]).is_err());
+
+        let text = encode_secret_key(&key);
+        assert_eq!(decode_secret_key(&text).unwrap().public(), key.public());
+        assert_eq!(decode_secret_key(&format!("{}\n", text.to_uppercase())).unwrap().public(), key.public());
+        assert!(decode_secret_key("not a key").is_err());
+    }
+
+    #[test]
+    fn test_load_or_create_keeps_identity() {
+        let dir = std::env::temp_dir().join(format!("cyberfly-identity-{}", std::process::id()));
+        let path = dir.join("node.key");
+        let _ = fs::remove_dir_all(&dir);
+
+        let created = load_or_create_secret_key(&path).unwrap();
+        let loaded = load_or_create_secret_key(&path).unwrap();
+        assert_eq!(created.public(), loaded.public());
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+        }
+
+        fs::write(&path, "garbage").unwrap();
+        assert!(load_or_create_secret_key(&path).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -6,21 +6,44 @@
 //! - Catalog-based track management with hang crate
 //! - WebTransport session management
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::net::SocketAddr;
 use std::sync::Arc;
+use std::time::Instant;
 
 use anyhow::{Context, Result};
 use bytes::Bytes;
-use hang::{Catalog, CatalogConsumer, CatalogProducer, TrackConsumer};
-use iroh::{Endpoint, EndpointAddr, EndpointId, RelayUrl, SecretKey, protocol::Router};
-use iroh::endpoint::Connection;
-use moq_lite::{BroadcastConsumer, BroadcastProducer, OriginConsumer, OriginProducer};
+use hang::Catalog;
+use iroh::{Endpoint, EndpointAddr, EndpointId, RelayUrl, SecretKey, protocol::{ProtocolHandler, Router}};
+use iroh::endpoint::{Connection, SendDatagramError};
+use moq_lite::BroadcastConsumer;
 use n0_future::time::Duration;
 use serde::{Deserialize, Serialize};
 use tokio::sync::{Mutex, RwLock, mpsc, broadcast};
 use tokio_util::sync::CancellationToken;
-use tracing::{info, warn, error, debug, instrument};
+use tracing::{info, warn, error, debug};
+
+use super::abr::{Abr, AbrConfig, AbrEvent, AbrHandle, Rendition};
+use super::access::{AccessControl, BroadcastPolicy, SignedToken};
+use super::av::{AudioFormat, DecodedFrame, PlaybackConfig};
+use super::chat::{ChatKind, ChatMessage, ChatPost, ChatRate, ChatReader, ChatSender};
+use super::discovery::{Discovery, DiscoveryEvent};
+use super::encryption::MediaKey;
+use super::endpoint_config::EndpointConfig;
+use super::live_protocol::Live;
+#[cfg(feature = "ffmpeg")]
+use super::opus::{OpusConfig, OpusTrackSink};
+use super::reconnect::{BroadcastLink, ConnectionState, Lost, ReconnectConfig, StateReporter, watch_progress};
+use super::record::{RecordConfig, RecordHandle, RecordStats, Recorder};
+use super::relay::{ROUTE_ALPN, RelayConfig, RelayHandle, RouteProtocolHandler};
+use super::replay::{FileReplay, ReplayConfig, ReplayHandle};
+use super::room::{Room, RoomEvent, member_path};
+use super::roster::{RosterEvent, Viewer};
+use super::publish_broadcast::{
+    AudioQuality, EncodedAudioFrame, EncodedVideoFrame, PublishBroadcast, PublishConfig,
+    PublishHandle, VideoQuality, VideoTrackStats,
+};
+use super::subscribe_broadcast::{EndReason, SubscribeBroadcast, SubscribeConfig, VideoSwitcher};
 
 /// Video frame packet for network transport
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,10 +63,223 @@ impl VideoPacket {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
         Ok(postcard::from_bytes(bytes)?)
     }
+
+    /// Split the serialized packet into fragments that each fit in a datagram
+    pub fn fragment(&self, frame_id: u32, max_datagram_size: usize) -> Result<Vec<VideoFragment>> {
+        let bytes = self.to_bytes();
+        let chunk_size = max_datagram_size.saturating_sub(VideoFragment::OVERHEAD);
+        if chunk_size == 0 {
+            anyhow::bail!("Datagram size {} too small for fragments", max_datagram_size);
+        }
+
+        let count = bytes.len().div_ceil(chunk_size).max(1);
+        let count = u16::try_from(count).context("Video packet needs too many fragments")?;
+        let crc = crc32(&bytes);
+
+        Ok((0..count)
+            .map(|index| {
+                let start = index as usize * chunk_size;
+                let end = (start + chunk_size).min(bytes.len());
+                VideoFragment {
+                    frame_id,
+                    index,
+                    count,
+                    crc,
+                    payload: bytes[start..end].to_vec(),
+                }
+            })
+            .collect())
+    }
+}
+
+/// Fragment of a serialized [`VideoPacket`], sized to fit in one datagram
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VideoFragment {
+    /// Sender-assigned frame id, shared by all fragments of a frame
+    pub frame_id: u32,
+    /// Position of this fragment within the frame
+    pub index: u16,
+    /// Total number of fragments in the frame
+    pub count: u16,
+    /// CRC-32 of the complete serialized packet
+    pub crc: u32,
+    pub payload: Vec<u8>,
+}
+
+impl VideoFragment {
+    /// Worst-case postcard encoding size of everything but the payload bytes
+    pub const OVERHEAD: usize = 24;
+
+    pub fn to_bytes(&self) -> Vec<u8> {
+        postcard::to_stdvec(self).unwrap_or_default()
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        Ok(postcard::from_bytes(bytes)?)
+    }
+}
+
+/// CRC-32 (IEEE) checksum
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+/// Whether frame id `a` comes after `b`, allowing for wraparound
+fn frame_id_after(a: u32, b: u32) -> bool {
+    (a.wrapping_sub(b) as i32) > 0
+}
+
+/// Frame being reassembled from fragments
+struct PendingFrame {
+    crc: u32,
+    fragments: Vec<Option<Vec<u8>>>,
+    received: usize,
+    started: Instant,
+}
+
+/// Reassembles [`VideoFragment`]s back into [`VideoPacket`]s
+///
+/// Frames that are not complete within the deadline are discarded. After any
+/// loss, frames are dropped until the next keyframe so the decoder never sees
+/// a frame whose references are missing.
+pub struct FrameReassembler {
+    pending: HashMap<u32, PendingFrame>,
+    deadline: Duration,
+    last_frame_id: Option<u32>,
+    waiting_for_keyframe: bool,
+    dropped_frames: u64,
+}
+
+impl FrameReassembler {
+    /// Default time allowed for all fragments of a frame to arrive
+    pub const DEFAULT_DEADLINE: Duration = Duration::from_millis(500);
+
+    pub fn new(deadline: Duration) -> Self {
+        Self {
+            pending: HashMap::new(),
+            deadline,
+            last_frame_id: None,
+            // Joining mid-stream, so nothing is decodable before a keyframe
+            waiting_for_keyframe: true,
+            dropped_frames: 0,
+        }
+    }
+
+    /// Whether frames are being dropped until the next keyframe
+    pub fn is_waiting_for_keyframe(&self) -> bool {
+        self.waiting_for_keyframe
+    }
+
+    /// Number of frames discarded as incomplete, corrupt or undecodable
+    pub fn dropped_frames(&self) -> u64 {
+        self.dropped_frames
+    }
+
+    /// Discard incomplete frames older than the deadline
+    pub fn expire(&mut self, now: Instant) {
+        let deadline = self.deadline;
+        let before = self.pending.len();
+        self.pending
+            .retain(|_, frame| now.duration_since(frame.started) < deadline);
+        let expired = before - self.pending.len();
+        if expired > 0 {
+            debug!("Discarded {} incomplete frames", expired);
+            self.dropped_frames += expired as u64;
+            self.waiting_for_keyframe = true;
+        }
+    }
+
+    /// Add a fragment, returning the packet once its frame is complete
+    pub fn push(&mut self, fragment: VideoFragment, now: Instant) -> Option<VideoPacket> {
+        self.expire(now);
+
+        let frame_id = fragment.frame_id;
+        if let Some(last) = self.last_frame_id {
+            if !frame_id_after(frame_id, last) {
+                // Late fragment of a frame that was already delivered or skipped
+                return None;
+            }
+        }
+        if fragment.count == 0 || fragment.index >= fragment.count {
+            return None;
+        }
+
+        let frame = self.pending.entry(frame_id).or_insert_with(|| PendingFrame {
+            crc: fragment.crc,
+            fragments: vec![None; fragment.count as usize],
+            received: 0,
+            started: now,
+        });
+        if frame.fragments.len() != fragment.count as usize || frame.crc != fragment.crc {
+            return None;
+        }
+        let slot = &mut frame.fragments[fragment.index as usize];
+        if slot.is_none() {
+            *slot = Some(fragment.payload);
+            frame.received += 1;
+        }
+        if frame.received < frame.fragments.len() {
+            return None;
+        }
+
+        let frame = self.pending.remove(&frame_id)?;
+        let bytes: Vec<u8> = frame.fragments.into_iter().flatten().flatten().collect();
+
+        // Anything older that is still incomplete can no longer be delivered in order
+        let before = self.pending.len();
+        self.pending.retain(|&id, _| frame_id_after(id, frame_id));
+        self.dropped_frames += (before - self.pending.len()) as u64;
+
+        if let Some(last) = self.last_frame_id {
+            if frame_id != last.wrapping_add(1) {
+                self.waiting_for_keyframe = true;
+            }
+        }
+        self.last_frame_id = Some(frame_id);
+
+        let packet = if crc32(&bytes) == frame.crc {
+            VideoPacket::from_bytes(&bytes).ok()
+        } else {
+            None
+        };
+        let Some(packet) = packet else {
+            debug!("Discarded corrupt frame {}", frame_id);
+            self.dropped_frames += 1;
+            self.waiting_for_keyframe = true;
+            return None;
+        };
+
+        if self.waiting_for_keyframe {
+            if !packet.is_keyframe {
+                self.dropped_frames += 1;
+                return None;
+            }
+            self.waiting_for_keyframe = false;
+        }
+
+        Some(packet)
+    }
+}
+
+impl Default for FrameReassembler {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_DEADLINE)
+    }
 }
 
-/// ALPN protocol identifier for iroh-live
-pub const ALPN: &[u8] = b"iroh-live/1";
+/// ALPN protocol identifier for iroh-live (MoQ sessions)
+pub use super::live_protocol::{ALPN, LEGACY_ALPN};
+
+/// ALPN protocol identifier for the legacy datagram transport
+pub const DATAGRAM_ALPN: &[u8] = b"iroh-live-dgram/1";
 
 /// Live streaming ticket for sharing broadcast info
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -55,8 +291,17 @@ pub struct LiveTicket {
     /// Direct addresses if available
     #[serde(default)]
     pub direct_addrs: Vec<SocketAddr>,
+    /// Capability token for restricted broadcasts
+    #[serde(default)]
+    pub token: Option<SignedToken>,
+    /// Key for opening an encrypted broadcast's frames
+    #[serde(default)]
+    pub media_key: Option<MediaKey>,
 }
 
+/// Optional fields appended to the ticket layout since its first version
+const TRAILING_OPTIONS: usize = 2;
+
 impl LiveTicket {
     pub fn new(endpoint_id: EndpointId, broadcast_name: impl ToString) -> Self {
         Self {
@@ -64,6 +309,8 @@ impl LiveTicket {
             broadcast_name: broadcast_name.to_string(),
             relay_url: None,
             direct_addrs: Vec::new(),
+            token: None,
+            media_key: None,
         }
     }
     
@@ -73,8 +320,42 @@ impl LiveTicket {
             broadcast_name: broadcast_name.to_string(),
             relay_url: addr.relay_urls().next().map(|u| u.to_string()),
             direct_addrs: addr.ip_addrs().cloned().collect(),
+            token: None,
+            media_key: None,
+        }
+    }
+
+    /// Same node and credentials, another of its broadcasts
+    ///
+    /// Drops the media key, which belongs to the original broadcast.
+    pub fn for_broadcast(&self, broadcast_name: impl ToString) -> Self {
+        Self {
+            broadcast_name: broadcast_name.to_string(),
+            media_key: None,
+            ..self.clone()
         }
     }
+
+    /// Attach a capability token for a restricted broadcast
+    pub fn with_token(mut self, token: SignedToken) -> Self {
+        self.token = Some(token);
+        self
+    }
+
+    /// Attach the media key of an encrypted broadcast
+    ///
+    /// Relays hand out tickets without the key; viewers of an encrypted
+    /// broadcast combine them with the key from the publisher's ticket.
+    pub fn with_media_key(mut self, key: MediaKey) -> Self {
+        self.media_key = Some(key);
+        self
+    }
+
+    /// The same ticket without its media key, safe to give to a relay
+    pub fn without_media_key(mut self) -> Self {
+        self.media_key = None;
+        self
+    }
     
     /// Convert to EndpointAddr for connection
     pub fn to_endpoint_addr(&self) -> EndpointAddr {
@@ -95,8 +376,14 @@ impl LiveTicket {
     }
 
     pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
-        let ticket = postcard::from_bytes(bytes)?;
-        Ok(ticket)
+        if let Ok(ticket) = postcard::from_bytes(bytes) {
+            return Ok(ticket);
+        }
+        // Postcard has no field defaults; older tickets simply end before
+        // the optional fields added since, and a zero byte reads as None
+        let mut padded = bytes.to_vec();
+        padded.extend([0; TRAILING_OPTIONS]);
+        Ok(postcard::from_bytes(&padded)?)
     }
 
     /// Serialize to base32 encoded postcard format (includes all addressing info)
@@ -109,8 +396,7 @@ impl LiveTicket {
     pub fn deserialize(s: &str) -> Result<Self> {
         let bytes = data_encoding::BASE32_NOPAD
             .decode(s.to_ascii_uppercase().as_bytes())?;
-        let ticket: LiveTicket = postcard::from_bytes(&bytes)?;
-        Ok(ticket)
+        Self::from_bytes(&bytes)
     }
     
     /// Simple format for display: name@endpoint_id (truncated)
@@ -206,6 +492,38 @@ impl VideoPreset {
             VideoPreset::P1080 => "1080p",
         }
     }
+
+    /// MoQ rendition this preset is published on
+    pub fn video_quality(&self) -> VideoQuality {
+        match self {
+            VideoPreset::P180 | VideoPreset::P360 => VideoQuality::Low,
+            VideoPreset::P720 => VideoQuality::Medium,
+            VideoPreset::P1080 => VideoQuality::High,
+        }
+    }
+
+    /// Parse a preset from its Flutter ("P720") or display ("720p") name
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "P180" | "180p" => Some(VideoPreset::P180),
+            "P360" | "360p" => Some(VideoPreset::P360),
+            "P720" | "720p" => Some(VideoPreset::P720),
+            "P1080" | "1080p" => Some(VideoPreset::P1080),
+            _ => None,
+        }
+    }
+
+    /// Catalog entry for this preset
+    pub fn video_config(&self) -> hang::catalog::VideoConfig {
+        let (width, height) = self.resolution();
+        hang::catalog::VideoConfig {
+            coded_width: Some(width),
+            coded_height: Some(height),
+            bitrate: Some(self.bitrate() as u64),
+            framerate: Some(self.fps() as f64),
+            ..self.video_quality().catalog_config()
+        }
+    }
 }
 
 /// Publisher state
@@ -216,12 +534,25 @@ pub struct Publisher {
     pub frames_published: u64,
     pub bytes_sent: u64,
     pub video_preset: VideoPreset,
-    producer: Option<BroadcastProducer>,
-    catalog: Option<CatalogProducer>,
+    /// Handle into the running MoQ broadcast (set while publishing)
+    handle: Option<PublishHandle>,
+    /// Local view of the broadcast, for recording (set while publishing)
+    broadcast: Option<BroadcastConsumer>,
+    /// Recording of the broadcast to disk
+    recording: Option<RecordHandle>,
+    /// File being replayed as the broadcast, instead of pushed frames
+    replay: Option<ReplayHandle>,
+    /// Seals the broadcast's frames end to end when set
+    media_key: Option<MediaKey>,
+    /// Chat messages not yet taken (set while publishing)
+    chat_rx: Option<broadcast::Receiver<ChatMessage>>,
+    /// Encodes pushed PCM to Opus (set while publishing)
+    #[cfg(feature = "ffmpeg")]
+    audio_encoder: Option<Arc<std::sync::Mutex<OpusTrackSink>>>,
     shutdown: CancellationToken,
-    /// Broadcast channel for sending frames to all subscribers
+    /// Broadcast channel for sending frames to legacy datagram subscribers
     frame_broadcaster: broadcast::Sender<VideoPacket>,
-    /// Connected subscriber connections
+    /// Connected legacy datagram subscriber connections
     subscriber_connections: Arc<RwLock<Vec<Connection>>>,
 }
 
@@ -235,8 +566,14 @@ impl Publisher {
             frames_published: 0,
             bytes_sent: 0,
             video_preset: VideoPreset::P720,
-            producer: None,
-            catalog: None,
+            handle: None,
+            broadcast: None,
+            recording: None,
+            replay: None,
+            media_key: None,
+            chat_rx: None,
+            #[cfg(feature = "ffmpeg")]
+            audio_encoder: None,
             shutdown: CancellationToken::new(),
             frame_broadcaster,
             subscriber_connections: Arc::new(RwLock::new(Vec::new())),
@@ -254,6 +591,119 @@ impl Publisher {
     pub fn subscribe_frames(&self) -> broadcast::Receiver<VideoPacket> {
         self.frame_broadcaster.subscribe()
     }
+
+    /// Get a cloneable sink for pushing frames without holding the node lock
+    pub fn sink(&self) -> Option<PublisherSink> {
+        let handle = self.handle.clone()?;
+        Some(PublisherSink {
+            handle,
+            // Datagrams would carry the frames in the clear
+            frame_broadcaster: self.media_key.is_none().then(|| self.frame_broadcaster.clone()),
+            quality: self.video_preset.video_quality(),
+            #[cfg(feature = "ffmpeg")]
+            audio_encoder: self.audio_encoder.clone(),
+        })
+    }
+}
+
+/// Cloneable ingest handle for an active publisher
+///
+/// Frames are written to the MoQ broadcast and, unless the broadcast is
+/// encrypted, mirrored to any legacy datagram subscribers.
+#[derive(Clone)]
+pub struct PublisherSink {
+    handle: PublishHandle,
+    /// None for encrypted broadcasts
+    frame_broadcaster: Option<broadcast::Sender<VideoPacket>>,
+    quality: VideoQuality,
+    #[cfg(feature = "ffmpeg")]
+    audio_encoder: Option<Arc<std::sync::Mutex<OpusTrackSink>>>,
+}
+
+impl PublisherSink {
+    fn video_frame(&self, packet: &EncodedVideoPacket) -> EncodedVideoFrame {
+        EncodedVideoFrame {
+            data: Bytes::from(packet.data.clone()),
+            pts_us: packet.timestamp_ms as i64 * 1000,
+            is_keyframe: packet.is_keyframe,
+            quality: self.quality,
+        }
+    }
+
+    fn audio_frame(packet: &EncodedAudioPacket) -> EncodedAudioFrame {
+        EncodedAudioFrame {
+            data: Bytes::from(packet.data.clone()),
+            pts_us: packet.timestamp_ms as i64 * 1000,
+            quality: AudioQuality::Medium,
+        }
+    }
+
+    fn mirror_datagram(&self, packet: EncodedVideoPacket) {
+        let Some(frame_broadcaster) = &self.frame_broadcaster else {
+            return;
+        };
+        // Only legacy datagram subscribers listen here; no receivers is fine
+        let _ = frame_broadcaster.send(VideoPacket {
+            timestamp_ms: packet.timestamp_ms,
+            width: packet.width,
+            height: packet.height,
+            is_keyframe: packet.is_keyframe,
+            data: packet.data,
+        });
+    }
+
+    /// Push an encoded video packet, waiting for queue space
+    pub async fn push_encoded_video(&self, packet: EncodedVideoPacket) -> Result<()> {
+        self.handle.push_video(self.video_frame(&packet)).await?;
+        self.mirror_datagram(packet);
+        Ok(())
+    }
+
+    /// Push an encoded video packet, failing instead of blocking when the queue is full
+    pub fn try_push_encoded_video(&self, packet: EncodedVideoPacket) -> Result<()> {
+        self.handle.try_push_video(self.video_frame(&packet))?;
+        self.mirror_datagram(packet);
+        Ok(())
+    }
+
+    /// Push an encoded audio packet, waiting for queue space
+    pub async fn push_encoded_audio(&self, packet: EncodedAudioPacket) -> Result<()> {
+        self.handle.push_audio(Self::audio_frame(&packet)).await
+    }
+
+    /// Push an encoded audio packet, failing instead of blocking when the queue is full
+    pub fn try_push_encoded_audio(&self, packet: EncodedAudioPacket) -> Result<()> {
+        self.handle.try_push_audio(Self::audio_frame(&packet))
+    }
+
+    /// Push interleaved PCM captured at `timestamp_ms`, encoding it to Opus
+    ///
+    /// Complete 20ms frames are published right away; the rest waits for
+    /// the next push.
+    #[cfg(feature = "ffmpeg")]
+    pub fn push_audio_samples(&self, format: AudioFormat, samples: &[f32], timestamp_ms: u64) -> Result<()> {
+        let encoder = self.audio_encoder.as_ref().context("Publisher has no audio encoder")?;
+        encoder
+            .lock()
+            .expect("poisoned")
+            .push_at(format, samples, timestamp_ms as i64 * 1000)
+    }
+
+    /// Push interleaved PCM (needs the ffmpeg feature for Opus encoding)
+    #[cfg(not(feature = "ffmpeg"))]
+    pub fn push_audio_samples(&self, _format: AudioFormat, _samples: &[f32], _timestamp_ms: u64) -> Result<()> {
+        anyhow::bail!("Encoding raw audio requires the ffmpeg feature; push encoded audio instead")
+    }
+
+    /// Take a pending request for the encoder to emit a keyframe
+    pub fn take_keyframe_request(&self) -> bool {
+        self.handle.take_keyframe_request()
+    }
+
+    /// Per-rendition video counters, including frames dropped under congestion
+    pub fn video_stats(&self) -> Vec<(VideoQuality, VideoTrackStats)> {
+        self.handle.video_stats()
+    }
 }
 
 /// Subscriber state
@@ -270,6 +720,28 @@ pub struct Subscriber {
     shutdown: CancellationToken,
     /// Connection to publisher
     connection: Option<Connection>,
+    /// Connection state of a MoQ subscription, kept up by its supervisor
+    states: Option<StateReporter>,
+    /// Connection state changes not yet taken
+    state_rx: Option<mpsc::UnboundedReceiver<ConnectionState>>,
+    /// Video rendition switching for the MoQ subscription
+    switcher: Option<VideoSwitcher>,
+    /// Adaptive bitrate task (enabled while quality is "auto")
+    abr: Option<AbrHandle>,
+    /// ABR decisions not yet collected
+    abr_events: Option<mpsc::UnboundedReceiver<AbrEvent>>,
+    /// Decode video to raw pixels before queueing (requires ffmpeg feature)
+    playback: Option<PlaybackConfig>,
+    /// Decoded frames, when decoding
+    decoded_rx: Option<mpsc::Receiver<DecodedFrame>>,
+    /// Recording of the received broadcast to disk
+    recording: Option<RecordHandle>,
+    /// Media key from the ticket, for encrypted broadcasts
+    media_key: Option<MediaKey>,
+    /// Posts to the broadcast's chat (MoQ subscriptions only)
+    chat: Option<ChatSender>,
+    /// Chat messages not yet taken
+    chat_rx: Option<mpsc::UnboundedReceiver<ChatMessage>>,
     /// Channel to receive video frames
     frame_rx: Option<mpsc::UnboundedReceiver<VideoPacket>>,
     /// Sender for frame channel (stored to create receiver)
@@ -291,6 +763,17 @@ impl Subscriber {
             catalog: None,
             shutdown: CancellationToken::new(),
             connection: None,
+            states: None,
+            state_rx: None,
+            switcher: None,
+            abr: None,
+            abr_events: None,
+            playback: None,
+            decoded_rx: None,
+            recording: None,
+            media_key: None,
+            chat: None,
+            chat_rx: None,
             frame_rx: Some(frame_rx),
             frame_tx,
         }
@@ -300,15 +783,70 @@ impl Subscriber {
     pub fn take_frame_rx(&mut self) -> Option<mpsc::UnboundedReceiver<VideoPacket>> {
         self.frame_rx.take()
     }
+
+    /// Output format when video is decoded
+    pub fn playback(&self) -> Option<&PlaybackConfig> {
+        self.playback.as_ref()
+    }
+}
+
+/// Protocol handler for legacy datagram subscribers
+#[derive(Clone)]
+struct DatagramProtocolHandler {
+    publishers: Arc<RwLock<HashMap<String, Publisher>>>,
+    access: AccessControl,
+}
+
+impl std::fmt::Debug for DatagramProtocolHandler {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DatagramProtocolHandler").finish_non_exhaustive()
+    }
+}
+
+impl ProtocolHandler for DatagramProtocolHandler {
+    async fn accept(&self, conn: Connection) -> Result<(), iroh::protocol::AcceptError> {
+        info!("Datagram connection established from: {}", conn.remote_id());
+        if let Err(e) = LiveNode::handle_subscriber_connection(conn, self.publishers.clone(), &self.access).await {
+            error!("Error handling subscriber: {}", e);
+        }
+        Ok(())
+    }
+}
+
+/// A room this node is in
+struct JoinedRoom {
+    room: Room,
+    /// Members with a subscriber on this node
+    members: HashSet<EndpointId>,
+    /// Decoding for members' subscribers
+    playback: Option<PlaybackConfig>,
+}
+
+impl JoinedRoom {
+    fn new(room: Room) -> Self {
+        Self { room, members: HashSet::new(), playback: None }
+    }
 }
 
 /// Live streaming node - manages endpoint, publishers, and subscribers
 pub struct LiveNode {
     endpoint: Endpoint,
-    router: Option<Router>,
+    /// MoQ broadcast/session coordinator
+    live: Live,
+    router: Mutex<Option<Router>>,
     secret_key: SecretKey,
+    /// Relay and discovery settings the endpoint was bound with
+    config: EndpointConfig,
     publishers: Arc<RwLock<HashMap<String, Publisher>>>,
     subscribers: Arc<RwLock<HashMap<String, Subscriber>>>,
+    /// Broadcasts relayed from other nodes, by name
+    relays: RwLock<HashMap<String, RelayHandle>>,
+    /// Rooms this node hosts or has joined, by name
+    rooms: Mutex<HashMap<String, JoinedRoom>>,
+    /// Peers whose broadcasts are being browsed, by discovery ID
+    discoveries: Mutex<HashMap<String, Discovery>>,
+    /// Roster events not yet taken with [`LiveNode::take_roster_events`]
+    roster_events: Mutex<broadcast::Receiver<RosterEvent>>,
     shutdown: CancellationToken,
     /// Channel for receiving video frames from Flutter
     frame_tx: mpsc::UnboundedSender<(String, VideoFrame)>,
@@ -316,42 +854,41 @@ pub struct LiveNode {
 }
 
 impl LiveNode {
-    /// Create a new live streaming node
+    /// Create a new live streaming node using the n0 relays
     pub async fn new(secret_key: Option<SecretKey>) -> Result<Self> {
+        Self::with_config(secret_key, EndpointConfig::default()).await
+    }
+
+    /// Create a new live streaming node with the given relay and discovery settings
+    pub async fn with_config(secret_key: Option<SecretKey>, config: EndpointConfig) -> Result<Self> {
         let secret_key = secret_key.unwrap_or_else(|| SecretKey::generate(&mut rand::rng()));
         
-        let endpoint = Endpoint::builder()
-            .secret_key(secret_key.clone())
-            .alpns(vec![ALPN.to_vec()])
-            .bind()
+        let endpoint = config
+            .bind(
+                secret_key.clone(),
+                vec![ALPN.to_vec(), LEGACY_ALPN.to_vec(), DATAGRAM_ALPN.to_vec(), ROUTE_ALPN.to_vec()],
+            )
             .await?;
 
         info!("LiveNode bound, endpoint_id: {}", endpoint.id());
-
-        // Wait for relay connection with better logging
-        let online_result = n0_future::time::timeout(
-            Duration::from_secs(15),
-            endpoint.online()
-        ).await;
-        
-        match online_result {
-            Ok(()) => {
-                info!("LiveNode is online and connected to relay");
-                // Log endpoint address for debugging
-                let addr = endpoint.addr();
-                info!("Endpoint address: {:?}", addr);
-            },
-            Err(_) => warn!("Timeout waiting for relay connection - P2P may not work reliably"),
-        }
+        info!("Endpoint address: {:?}", endpoint.addr());
 
         let (frame_tx, frame_rx) = mpsc::unbounded_channel();
+        let live = Live::new(endpoint.clone());
+        let roster_events = Mutex::new(live.roster_events());
 
         let node = Self {
             endpoint: endpoint.clone(),
-            router: None,
+            live,
+            router: Mutex::new(None),
             secret_key,
+            config,
             publishers: Arc::new(RwLock::new(HashMap::new())),
             subscribers: Arc::new(RwLock::new(HashMap::new())),
+            relays: RwLock::new(HashMap::new()),
+            rooms: Mutex::new(HashMap::new()),
+            discoveries: Mutex::new(HashMap::new()),
+            roster_events,
             shutdown: CancellationToken::new(),
             frame_tx,
             frame_rx: Arc::new(Mutex::new(frame_rx)),
@@ -361,58 +898,44 @@ impl LiveNode {
     }
     
     /// Start accepting incoming connections (MUST be called for publisher to work)
+    ///
+    /// MoQ sessions are handed to the [`Live`] actor; connections on
+    /// [`DATAGRAM_ALPN`] are served by the legacy datagram fan-out and
+    /// relay route queries on [`ROUTE_ALPN`] are answered by the actor.
     pub async fn start_accepting(&self) -> Result<()> {
-        let endpoint = self.endpoint.clone();
-        let publishers = self.publishers.clone();
-        let shutdown = self.shutdown.clone();
-        
+        let mut router = self.router.lock().await;
+        if router.is_some() {
+            debug!("Already accepting connections");
+            return Ok(());
+        }
+
         info!("Starting to accept incoming connections...");
-        
-        tokio::spawn(async move {
-            loop {
-                tokio::select! {
-                    _ = shutdown.cancelled() => {
-                        info!("Stopping connection acceptor");
-                        break;
-                    }
-                    incoming_opt = endpoint.accept() => {
-                        match incoming_opt {
-                            Some(incoming) => {
-                                info!("Incoming connection received");
-                                
-                                // Await the incoming to get the Connection
-                                match incoming.await {
-                                    Ok(conn) => {
-                                        let remote_id = conn.remote_id();
-                                        info!("Connection established from: {}", remote_id);
-                                        
-                                        let publishers = publishers.clone();
-                                        tokio::spawn(async move {
-                                            if let Err(e) = Self::handle_subscriber_connection(conn, publishers).await {
-                                                error!("Error handling subscriber: {}", e);
-                                            }
-                                        });
-                                    }
-                                    Err(e) => error!("Failed to complete connection: {}", e),
-                                }
-                            }
-                            None => {
-                                info!("Endpoint closed");
-                                break;
-                            }
-                        }
-                    }
-                }
-            }
-        });
-        
+
+        let datagram = DatagramProtocolHandler {
+            publishers: self.publishers.clone(),
+            access: self.live.access().clone(),
+        };
+        *router = Some(
+            Router::builder(self.endpoint.clone())
+                .accept(ALPN, self.live.protocol_handler())
+                .accept(LEGACY_ALPN, self.live.protocol_handler())
+                .accept(DATAGRAM_ALPN, datagram)
+                .accept(ROUTE_ALPN, RouteProtocolHandler::new(self.live.clone()))
+                .spawn(),
+        );
+
         Ok(())
     }
     
     /// Handle an incoming subscriber connection
+    ///
+    /// The datagram handshake has no room for a token, so restricted
+    /// broadcasts are only served here to allow-listed peers. Encrypted
+    /// broadcasts aren't served at all: datagrams aren't sealed.
     async fn handle_subscriber_connection(
         conn: Connection,
         publishers: Arc<RwLock<HashMap<String, Publisher>>>,
+        access: &AccessControl,
     ) -> Result<()> {
         info!("Handling subscriber connection from: {:?}", conn.remote_id());
         
@@ -424,26 +947,20 @@ impl LiveNode {
                 // Fallback: just add to first active publisher
                 info!("No handshake stream, using first active publisher: {}", e);
                 let publishers_read = publishers.read().await;
-                if let Some((_, publisher)) = publishers_read.iter().find(|(_, p)| p.is_active) {
+                if let Some((_, publisher)) = publishers_read.iter().find(|(_, p)| {
+                    p.is_active
+                        && p.media_key.is_none()
+                        && access.check(conn.remote_id(), &p.broadcast_name, None).is_ok()
+                }) {
                     let subscriber_connections = publisher.subscriber_connections.clone();
-                    let mut frame_rx = publisher.frame_broadcaster.subscribe();
+                    let frame_rx = publisher.frame_broadcaster.subscribe();
                     drop(publishers_read);
                     
                     // Add connection to publisher's subscriber list
                     subscriber_connections.write().await.push(conn.clone());
                     
                     // Forward frames to this subscriber
-                    tokio::spawn(async move {
-                        info!("Starting frame forwarding to subscriber");
-                        while let Ok(packet) = frame_rx.recv().await {
-                            let data = packet.to_bytes();
-                            if let Err(e) = conn.send_datagram(Bytes::from(data)) {
-                                info!("Failed to send datagram to subscriber: {}", e);
-                                break;
-                            }
-                        }
-                        info!("Frame forwarding ended");
-                    });
+                    tokio::spawn(Self::forward_datagrams(conn, frame_rx));
                     
                     return Ok(());
                 }
@@ -456,6 +973,10 @@ impl LiveNode {
         let n = recv_stream.read(&mut buf).await?.unwrap_or(0);
         let broadcast_name = String::from_utf8_lossy(&buf[..n]).to_string();
         info!("Subscriber wants to join broadcast: {}", broadcast_name);
+        if let Err(e) = access.check(conn.remote_id(), &broadcast_name, None) {
+            conn.close(1u32.into(), b"unauthorized");
+            anyhow::bail!("Refused datagram subscriber for '{}': {}", broadcast_name, e);
+        }
         
         // Find matching publisher
         let publishers_read = publishers.read().await;
@@ -464,8 +985,12 @@ impl LiveNode {
             .map(|(_, p)| p);
         
         if let Some(publisher) = publisher {
+            if publisher.media_key.is_some() {
+                conn.close(1u32.into(), b"encrypted");
+                anyhow::bail!("Refused datagram subscriber for '{}': broadcast is encrypted", broadcast_name);
+            }
             let subscriber_connections = publisher.subscriber_connections.clone();
-            let mut frame_rx = publisher.frame_broadcaster.subscribe();
+            let frame_rx = publisher.frame_broadcaster.subscribe();
             drop(publishers_read);
             
             // Add connection to publisher's subscriber list
@@ -474,17 +999,7 @@ impl LiveNode {
             info!("Subscriber connected to broadcast: {}", broadcast_name);
             
             // Forward frames to this subscriber via datagrams
-            tokio::spawn(async move {
-                info!("Starting frame forwarding to subscriber");
-                while let Ok(packet) = frame_rx.recv().await {
-                    let data = packet.to_bytes();
-                    if let Err(e) = conn.send_datagram(Bytes::from(data)) {
-                        info!("Failed to send datagram to subscriber: {}", e);
-                        break;
-                    }
-                }
-                info!("Frame forwarding ended");
-            });
+            tokio::spawn(Self::forward_datagrams(conn, frame_rx));
         } else {
             warn!("No active publisher found for broadcast: {}", broadcast_name);
         }
@@ -492,14 +1007,74 @@ impl LiveNode {
         Ok(())
     }
 
+    /// Send published frames to a subscriber, fragmented to the datagram size
+    async fn forward_datagrams(conn: Connection, mut frame_rx: broadcast::Receiver<VideoPacket>) {
+        info!("Starting frame forwarding to subscriber");
+        let mut frame_id: u32 = 0;
+        loop {
+            let packet = match frame_rx.recv().await {
+                Ok(packet) => packet,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    // Leave a gap in the frame ids so the subscriber resyncs on a keyframe
+                    debug!("Subscriber lagged, skipped {} frames", skipped);
+                    frame_id = frame_id.wrapping_add(skipped as u32);
+                    continue;
+                }
+                Err(broadcast::error::RecvError::Closed) => break,
+            };
+
+            let Some(max_size) = conn.max_datagram_size() else {
+                warn!("Subscriber does not support datagrams");
+                break;
+            };
+            let fragments = match packet.fragment(frame_id, max_size) {
+                Ok(fragments) => fragments,
+                Err(e) => {
+                    warn!("Failed to fragment video packet: {}", e);
+                    continue;
+                }
+            };
+            frame_id = frame_id.wrapping_add(1);
+
+            for fragment in fragments {
+                match conn.send_datagram(Bytes::from(fragment.to_bytes())) {
+                    Ok(()) => {}
+                    Err(SendDatagramError::TooLarge) => {
+                        // Path MTU shrank mid-frame; the rest of this frame is useless
+                        debug!("Datagram too large, dropping frame");
+                        break;
+                    }
+                    Err(e) => {
+                        info!("Failed to send datagram to subscriber: {}", e);
+                        info!("Frame forwarding ended");
+                        return;
+                    }
+                }
+            }
+        }
+        info!("Frame forwarding ended");
+    }
+
     /// Get endpoint ID
     pub fn endpoint_id(&self) -> EndpointId {
         self.endpoint.id()
     }
 
+    /// Secret key behind the endpoint ID, for persisting the node's identity
+    pub fn secret_key(&self) -> &SecretKey {
+        &self.secret_key
+    }
+
     /// Get endpoint address for sharing
+    ///
+    /// Only direct addresses when relays are disabled.
     pub fn endpoint_addr(&self) -> EndpointAddr {
-        self.endpoint.addr()
+        self.config.ticket_addr(&self.endpoint)
+    }
+
+    /// Ticket for the node itself rather than one broadcast, for discovery
+    pub fn node_ticket(&self) -> LiveTicket {
+        LiveTicket::with_addr(self.endpoint_addr(), "")
     }
 
     /// Create a publisher
@@ -514,8 +1089,7 @@ impl LiveNode {
         publishers.insert(publisher_id.clone(), publisher);
 
         // Create ticket with full addressing info (including relay URL)
-        let addr = self.endpoint.addr();
-        let ticket = LiveTicket::with_addr(addr, broadcast_name);
+        let ticket = LiveTicket::with_addr(self.endpoint_addr(), broadcast_name);
         info!("Created publisher: {}, ticket: {}", publisher_id, ticket.display());
         info!("Full ticket (for sharing): {}", ticket.serialize());
         info!("Relay URL: {:?}", ticket.relay_url);
@@ -524,54 +1098,489 @@ impl LiveNode {
         Ok(ticket)
     }
 
-    /// Start publishing
-    pub async fn start_publishing(&self, publisher_id: &str) -> Result<()> {
+    /// Issue a ticket carrying a capability token for a publisher's broadcast
+    ///
+    /// The token is valid for `ttl` and, with `subject`, only for that peer.
+    /// It is only needed once the broadcast is restricted with
+    /// [`LiveNode::set_broadcast_access`].
+    pub async fn issue_ticket(
+        &self,
+        publisher_id: &str,
+        ttl: Duration,
+        subject: Option<EndpointId>,
+    ) -> Result<LiveTicket> {
+        let (broadcast_name, media_key) = {
+            let publishers = self.publishers.read().await;
+            let publisher = publishers.get(publisher_id).context("Publisher not found")?;
+            (publisher.broadcast_name.clone(), publisher.media_key.clone())
+        };
+        let token = SignedToken::issue(&self.secret_key, vec![broadcast_name.clone()], subject, ttl);
+        info!("Issued token {} for '{}', expires at {}", token.id(), broadcast_name, token.capability.expires_at);
+        let mut ticket = LiveTicket::with_addr(self.endpoint_addr(), broadcast_name).with_token(token);
+        ticket.media_key = media_key;
+        Ok(ticket)
+    }
+
+    /// Encrypt a publisher's broadcast end to end
+    ///
+    /// Generates a media key and returns a ticket carrying it; only holders
+    /// of that ticket (or one from [`LiveNode::issue_ticket`]) can watch.
+    /// Relays and other hops forward the sealed frames without the key.
+    /// Must be called before publishing starts.
+    pub async fn enable_encryption(&self, publisher_id: &str) -> Result<LiveTicket> {
         let mut publishers = self.publishers.write().await;
-        let publisher = publishers.get_mut(publisher_id)
-            .context("Publisher not found")?;
-        
-        // Create MoQ broadcast producer
-        let mut producer = BroadcastProducer::default();
-        let catalog = Catalog::default().produce();
-        producer.insert_track(catalog.consumer.track);
-        
-        publisher.producer = Some(producer);
-        publisher.catalog = Some(catalog.producer);
-        publisher.is_active = true;
+        let publisher = publishers.get_mut(publisher_id).context("Publisher not found")?;
+        if publisher.is_active {
+            anyhow::bail!("Encryption must be enabled before publishing starts");
+        }
+        let key = publisher.media_key.get_or_insert_with(MediaKey::generate).clone();
+        info!("Encryption enabled for publisher {}", publisher_id);
+        Ok(LiveTicket::with_addr(self.endpoint_addr(), &publisher.broadcast_name).with_media_key(key))
+    }
 
-        info!("Started publishing: {}", publisher_id);
-        Ok(())
+    /// Restrict who may watch a publisher's broadcast
+    ///
+    /// Viewers that no longer qualify are disconnected.
+    pub async fn set_broadcast_access(&self, publisher_id: &str, policy: BroadcastPolicy) -> Result<()> {
+        let broadcast_name = self.publishers.read().await
+            .get(publisher_id)
+            .context("Publisher not found")?
+            .broadcast_name.clone();
+        self.live.set_access_policy(broadcast_name, policy).await
     }
 
-    /// Stop publishing
-    pub async fn stop_publishing(&self, publisher_id: &str) -> Result<()> {
-        let mut publishers = self.publishers.write().await;
-        let publisher = publishers.get_mut(publisher_id)
-            .context("Publisher not found")?;
-        
-        publisher.is_active = false;
-        publisher.shutdown.cancel();
-        
-        info!("Stopped publishing: {}", publisher_id);
-        Ok(())
+    /// Revoke a token issued by [`LiveNode::issue_ticket`]
+    pub async fn revoke_token(&self, token_id: u64) -> Result<()> {
+        info!("Revoking token {}", token_id);
+        self.live.revoke_token(token_id).await
     }
 
-    /// Push a video frame to a publisher
-    pub async fn push_video_frame(&self, publisher_id: &str, frame: VideoFrame) -> Result<()> {
-        let mut publishers = self.publishers.write().await;
-        let publisher = publishers.get_mut(publisher_id)
-            .context("Publisher not found")?;
-        
-        if !publisher.is_active {
-            anyhow::bail!("Publisher is not active");
-        }
+    /// Name shown in the viewer rosters of publishers this node watches
+    pub fn set_display_name(&self, name: Option<String>) {
+        self.live.set_display_name(name);
+    }
 
-        let frame_size = frame.data.len() as u64;
-        publisher.frames_published += 1;
-        publisher.bytes_sent += frame_size;
+    /// Viewers connected to this node, across all its broadcasts
+    ///
+    /// Relays pulling a broadcast from this node show up as viewers too.
+    pub async fn viewers(&self) -> Result<Vec<Viewer>> {
+        self.live.roster().await
+    }
 
-        // In a real implementation, this would:
-        // 1. Encode the frame (if not already encoded)
+    /// Follow viewers joining and leaving
+    pub fn roster_events(&self) -> broadcast::Receiver<RosterEvent> {
+        self.live.roster_events()
+    }
+
+    /// Collect the roster events since the last call
+    ///
+    /// Events beyond the buffer of a slow caller are dropped; list
+    /// [`LiveNode::viewers`] to catch up.
+    pub async fn take_roster_events(&self) -> Vec<RosterEvent> {
+        let mut rx = self.roster_events.lock().await;
+        let mut events = Vec::new();
+        loop {
+            match rx.try_recv() {
+                Ok(event) => events.push(event),
+                Err(broadcast::error::TryRecvError::Lagged(n)) => warn!("Dropped {} roster events", n),
+                Err(_) => break,
+            }
+        }
+        events
+    }
+
+    /// Disconnect a viewer, returning false if it wasn't connected
+    ///
+    /// See [`Live::kick`]: the viewer's subscription ends rather than
+    /// reconnecting, but a deny-list entry is what keeps it out.
+    pub async fn kick_viewer(&self, viewer: EndpointId) -> Result<bool> {
+        info!("Kicking viewer {}", viewer.fmt_short());
+        self.live.kick(viewer).await
+    }
+
+    /// Host a call room on this node
+    ///
+    /// Returns the ticket members join with; it names the room where a
+    /// broadcast ticket names the broadcast. Only peers holding the ticket
+    /// may join: it carries a token for the room, valid for `ttl`.
+    pub async fn host_room(&self, name: &str, ttl: Duration) -> Result<LiveTicket> {
+        self.start_accepting().await?;
+        let mut rooms = self.rooms.lock().await;
+        if rooms.contains_key(name) {
+            anyhow::bail!("Already in room: {}", name);
+        }
+        let policy = BroadcastPolicy {
+            require_token: true,
+            ..Default::default()
+        };
+        self.live.set_access_policy(name, policy).await?;
+        rooms.insert(name.to_string(), JoinedRoom::new(Room::host(&self.live, name)));
+        let token = SignedToken::issue(&self.secret_key, vec![name.to_string()], None, ttl);
+        info!("Hosting room '{}', token {} expires at {}", name, token.id(), token.capability.expires_at);
+        Ok(LiveTicket::with_addr(self.endpoint_addr(), name).with_token(token))
+    }
+
+    /// Join a room from a ticket made with [`LiveNode::host_room`]
+    pub async fn join_room(&self, ticket: &LiveTicket) -> Result<()> {
+        let name = &ticket.broadcast_name;
+        // Held while joining, so a second join of the room waits and fails
+        let mut rooms = self.rooms.lock().await;
+        if rooms.contains_key(name) {
+            anyhow::bail!("Already in room: {}", name);
+        }
+        let room = Room::join(&self.live, ticket.to_endpoint_addr(), name, ticket.token.as_ref()).await?;
+        rooms.insert(name.clone(), JoinedRoom::new(room));
+        Ok(())
+    }
+
+    /// Send an active publisher's broadcast to a room
+    ///
+    /// The broadcast stays available under its own name as well.
+    pub async fn room_publish(&self, room: &str, publisher_id: &str) -> Result<()> {
+        let broadcast = {
+            let publishers = self.publishers.read().await;
+            let publisher = publishers.get(publisher_id).context("Publisher not found")?;
+            if publisher.media_key.is_some() {
+                anyhow::bail!("Encrypted broadcasts can't be sent to a room");
+            }
+            publisher.broadcast.clone().context("Publisher is not active")?
+        };
+        let rooms = self.rooms.lock().await;
+        rooms.get(room).context("Not in room")?.room.publish(broadcast).await
+    }
+
+    /// Decode the video of members joining a room from now on
+    ///
+    /// Like [`LiveNode::set_subscriber_decoding`] for each member's subscriber.
+    pub async fn set_room_decoding(&self, room: &str, playback: Option<PlaybackConfig>) -> Result<()> {
+        if playback.is_some() && !cfg!(feature = "ffmpeg") {
+            anyhow::bail!("Decoding video requires the ffmpeg feature");
+        }
+        let mut rooms = self.rooms.lock().await;
+        rooms.get_mut(room).context("Not in room")?.playback = playback;
+        Ok(())
+    }
+
+    /// Members of a room whose broadcasts this node receives
+    pub async fn room_members(&self, room: &str) -> Vec<EndpointId> {
+        let rooms = self.rooms.lock().await;
+        rooms.get(room).map(|r| r.members.iter().copied().collect()).unwrap_or_default()
+    }
+
+    /// Take a room's member joins and leaves since the last call
+    ///
+    /// Each member that joins gets a subscriber named by [`member_path`],
+    /// read like any other, and loses it on leaving. Members are only
+    /// subscribed as their events are taken, so call this regularly.
+    pub async fn take_room_events(&self, room: &str) -> Vec<RoomEvent> {
+        let (events, playback) = {
+            let mut rooms = self.rooms.lock().await;
+            let Some(joined) = rooms.get_mut(room) else {
+                return Vec::new();
+            };
+            let events: Vec<_> = std::iter::from_fn(|| joined.room.try_recv()).collect();
+            for event in &events {
+                match event {
+                    RoomEvent::Joined { member, .. } => joined.members.insert(*member),
+                    RoomEvent::Left { member } => joined.members.remove(member),
+                };
+            }
+            (events, joined.playback.clone())
+        };
+
+        for event in &events {
+            match event {
+                RoomEvent::Joined { member, broadcast } => {
+                    let subscriber_id = member_path(room, *member);
+                    // A member rejoining replaces its earlier broadcast
+                    self.remove_room_subscriber(&subscriber_id).await;
+                    let mut subscriber = Subscriber::new(subscriber_id.clone(), subscriber_id.clone());
+                    subscriber.playback = playback.clone();
+                    self.subscribers.write().await.insert(subscriber_id.clone(), subscriber);
+                    if let Err(e) = Self::attach_subscriber(&self.subscribers, &subscriber_id, broadcast.clone(), None, None, None).await {
+                        warn!("Failed to subscribe to room member {}: {}", member.fmt_short(), e);
+                    }
+                }
+                RoomEvent::Left { member } => {
+                    self.remove_room_subscriber(&member_path(room, *member)).await;
+                }
+            }
+        }
+        events
+    }
+
+    /// Leave a room, dropping its members' subscribers
+    ///
+    /// Leaving a room this node hosts ends it for everyone.
+    pub async fn leave_room(&self, room: &str) -> Result<()> {
+        let joined = self.rooms.lock().await.remove(room).context("Not in room")?;
+        for member in &joined.members {
+            self.remove_room_subscriber(&member_path(room, *member)).await;
+        }
+        let hosted = joined.room.host_id() == self.endpoint.id();
+        joined.room.leave().await;
+        if hosted {
+            self.live.clear_access_policy(room).await?;
+        }
+        Ok(())
+    }
+
+    async fn remove_room_subscriber(&self, subscriber_id: &str) {
+        if let Some(subscriber) = self.subscribers.write().await.remove(subscriber_id) {
+            subscriber.shutdown.cancel();
+            if let Some(abr) = subscriber.abr {
+                abr.stop();
+            }
+        }
+    }
+
+    /// Start following the broadcasts the node in `ticket` announces
+    ///
+    /// Only broadcasts under `prefix` are followed; the ticket's broadcast
+    /// name is ignored, while its token decides what the node offers.
+    pub async fn start_discovery(&self, discovery_id: &str, ticket: &LiveTicket, prefix: &str) -> Result<()> {
+        if self.discoveries.lock().await.contains_key(discovery_id) {
+            anyhow::bail!("Discovery already exists: {}", discovery_id);
+        }
+        let discovery = Discovery::connect(
+            &self.live,
+            ticket.to_endpoint_addr(),
+            prefix,
+            ticket.token.as_ref(),
+        )
+        .await?;
+        self.discoveries.lock().await.insert(discovery_id.to_string(), discovery);
+        Ok(())
+    }
+
+    /// Broadcasts a discovery currently sees, sorted by name
+    pub async fn discovered_broadcasts(&self, discovery_id: &str) -> Option<Vec<String>> {
+        let discoveries = self.discoveries.lock().await;
+        discoveries.get(discovery_id).map(|d| d.broadcasts())
+    }
+
+    /// Take the broadcasts added and removed since the last call
+    pub async fn take_discovery_events(&self, discovery_id: &str) -> Vec<DiscoveryEvent> {
+        let mut discoveries = self.discoveries.lock().await;
+        let Some(discovery) = discoveries.get_mut(discovery_id) else {
+            return Vec::new();
+        };
+        std::iter::from_fn(|| discovery.try_recv()).collect()
+    }
+
+    /// Stop a discovery and close its session
+    pub async fn stop_discovery(&self, discovery_id: &str) -> Result<()> {
+        let discovery = self.discoveries.lock().await
+            .remove(discovery_id)
+            .context("Discovery not found")?;
+        discovery.close().await;
+        Ok(())
+    }
+
+    /// Relay a remote broadcast to this node's subscribers
+    ///
+    /// Subscribes to the broadcast named in `ticket` and re-publishes it, so
+    /// viewers can connect here instead of to the publisher. Returns a ticket
+    /// for the relayed broadcast on this node. The relay ends by itself when
+    /// the upstream broadcast does.
+    ///
+    /// Encrypted broadcasts are forwarded still sealed. The returned ticket
+    /// never carries a media key, even if `ticket` did; viewers add it with
+    /// [`LiveTicket::with_media_key`].
+    pub async fn start_relay(&self, ticket: &LiveTicket) -> Result<LiveTicket> {
+        self.start_accepting().await?;
+
+        let name = &ticket.broadcast_name;
+        if self.relays.read().await.get(name).is_some_and(|r| r.is_active()) {
+            anyhow::bail!("Already relaying '{}'", name);
+        }
+        let config = RelayConfig {
+            token: ticket.token.clone(),
+            ..Default::default()
+        };
+        let relay = super::relay::relay(&self.live, ticket.to_endpoint_addr(), name, &config).await?;
+        self.relays.write().await.insert(name.clone(), relay);
+
+        let ticket = LiveTicket::with_addr(self.endpoint_addr(), name);
+        info!("Relaying '{}', ticket: {}", name, ticket.display());
+        Ok(ticket)
+    }
+
+    /// Stop relaying a broadcast
+    pub async fn stop_relay(&self, broadcast_name: &str) -> Result<()> {
+        let relay = self.relays.write().await
+            .remove(broadcast_name)
+            .context("Broadcast is not relayed")?;
+        relay.stop();
+        info!("Stopped relaying '{}'", broadcast_name);
+        Ok(())
+    }
+
+    /// Broadcasts currently relayed through this node
+    pub async fn relays(&self) -> Vec<RelayHandle> {
+        let mut relays = self.relays.write().await;
+        relays.retain(|_, relay| relay.is_active());
+        relays.values().cloned().collect()
+    }
+
+    /// Start publishing
+    ///
+    /// Creates the MoQ broadcast for this publisher and announces it through
+    /// the [`Live`] actor so remote sessions can subscribe to it.
+    pub async fn start_publishing(&self, publisher_id: &str) -> Result<()> {
+        let mut publishers = self.publishers.write().await;
+        let publisher = publishers.get_mut(publisher_id)
+            .context("Publisher not found")?;
+
+        if publisher.is_active {
+            return Ok(());
+        }
+
+        let config = PublishConfig {
+            name: publisher.broadcast_name.clone(),
+            video_qualities: vec![publisher.video_preset.video_quality()],
+            audio_qualities: vec![AudioQuality::Medium],
+            encryption: publisher.media_key.clone(),
+            ..Default::default()
+        };
+        let (broadcast, handle) = PublishBroadcast::new(config);
+        let preset = publisher.video_preset;
+        handle.set_video_config(preset.video_quality(), preset.video_config())?;
+        #[cfg(feature = "ffmpeg")]
+        {
+            let quality = AudioQuality::Medium;
+            let encoder = OpusTrackSink::new(handle.clone(), quality, OpusConfig::for_quality(quality))?;
+            publisher.audio_encoder = Some(Arc::new(std::sync::Mutex::new(encoder)));
+        }
+        self.live
+            .publish_tracked(&publisher.broadcast_name, broadcast.producer().clone(), broadcast.send_backlog())
+            .await?;
+        self.open_chat(publisher, &handle).await?;
+        publisher.broadcast = Some(broadcast.producer().consume());
+        tokio::spawn(broadcast.run());
+
+        publisher.handle = Some(handle);
+        publisher.is_active = true;
+
+        info!("Started publishing: {}", publisher_id);
+        Ok(())
+    }
+
+    /// Start publishing an MP4 file as the publisher's broadcast
+    ///
+    /// The file's samples are sent in real time, so subscribers watch it
+    /// live rather than downloading it. Returns the file's duration.
+    pub async fn start_file_publishing(&self, publisher_id: &str, path: &str, looping: bool) -> Result<Duration> {
+        let mut publishers = self.publishers.write().await;
+        let publisher = publishers.get_mut(publisher_id)
+            .context("Publisher not found")?;
+
+        if publisher.is_active {
+            anyhow::bail!("Publisher is already active");
+        }
+
+        let replay = FileReplay::open(path, ReplayConfig { looping })?;
+        let config = PublishConfig {
+            encryption: publisher.media_key.clone(),
+            ..replay.publish_config(&publisher.broadcast_name)
+        };
+        let (broadcast, handle) = PublishBroadcast::new(config);
+        self.live
+            .publish_tracked(&publisher.broadcast_name, broadcast.producer().clone(), broadcast.send_backlog())
+            .await?;
+        self.open_chat(publisher, &handle).await?;
+        publisher.broadcast = Some(broadcast.producer().consume());
+        tokio::spawn(broadcast.run());
+        let replay = replay.start(handle.clone())?;
+        let duration = replay.duration();
+
+        publisher.handle = Some(handle);
+        publisher.replay = Some(replay);
+        publisher.is_active = true;
+
+        info!("Started publishing file {} as {}", path, publisher_id);
+        Ok(duration)
+    }
+
+    /// Let viewers post to a publisher's chat and start collecting it
+    async fn open_chat(&self, publisher: &mut Publisher, handle: &PublishHandle) -> Result<()> {
+        publisher.chat_rx = Some(handle.chat_messages());
+        if let Some(inbox) = handle.chat_inbox() {
+            self.live.open_chat(&publisher.broadcast_name, inbox, ChatRate::default()).await?;
+        }
+        Ok(())
+    }
+
+    /// Seek a file publisher to `position`
+    pub async fn seek_file_publisher(&self, publisher_id: &str, position: Duration) -> Result<()> {
+        let publishers = self.publishers.read().await;
+        publishers.get(publisher_id)
+            .context("Publisher not found")?
+            .replay.as_ref()
+            .context("Publisher is not replaying a file")?
+            .seek(position)
+    }
+
+    /// Position of a file publisher in its file
+    pub async fn file_publisher_position(&self, publisher_id: &str) -> Option<Duration> {
+        let publishers = self.publishers.read().await;
+        publishers.get(publisher_id)?.replay.as_ref().map(|r| r.position())
+    }
+
+    /// Stop publishing
+    pub async fn stop_publishing(&self, publisher_id: &str) -> Result<()> {
+        let mut publishers = self.publishers.write().await;
+        let publisher = publishers.get_mut(publisher_id)
+            .context("Publisher not found")?;
+        
+        publisher.is_active = false;
+        publisher.shutdown.cancel();
+
+        publisher.broadcast = None;
+        publisher.chat_rx = None;
+        #[cfg(feature = "ffmpeg")]
+        {
+            publisher.audio_encoder = None;
+        }
+        if let Some(replay) = publisher.replay.take() {
+            replay.stop();
+        }
+        if let Some(handle) = publisher.handle.take() {
+            if let Err(e) = handle.stop().await {
+                debug!("Publish task already stopped: {}", e);
+            }
+            self.live.unpublish(&publisher.broadcast_name).await?;
+        }
+        
+        info!("Stopped publishing: {}", publisher_id);
+        Ok(())
+    }
+
+    /// Get a sink for pushing encoded media to an active publisher
+    ///
+    /// The sink can be used without holding the node lock, which keeps
+    /// per-frame pushes off the node's async mutex.
+    pub async fn publisher_sink(&self, publisher_id: &str) -> Option<PublisherSink> {
+        let publishers = self.publishers.read().await;
+        publishers.get(publisher_id).and_then(|p| p.sink())
+    }
+
+    /// Push a video frame to a publisher
+    pub async fn push_video_frame(&self, publisher_id: &str, frame: VideoFrame) -> Result<()> {
+        let mut publishers = self.publishers.write().await;
+        let publisher = publishers.get_mut(publisher_id)
+            .context("Publisher not found")?;
+        
+        if !publisher.is_active {
+            anyhow::bail!("Publisher is not active");
+        }
+
+        let frame_size = frame.data.len() as u64;
+        publisher.frames_published += 1;
+        publisher.bytes_sent += frame_size;
+
+        // In a real implementation, this would:
+        // 1. Encode the frame (if not already encoded)
         // 2. Chunk it into MoQ groups
         // 3. Send via the broadcast producer
         
@@ -594,26 +1603,18 @@ impl LiveNode {
             anyhow::bail!("Publisher is not active");
         }
 
+        let sink = publisher.sink().context("Publisher has no broadcast")?;
         let packet_size = packet.data.len() as u64;
+        let (is_keyframe, timestamp_ms) = (packet.is_keyframe, packet.timestamp_ms);
         publisher.frames_published += 1;
         publisher.bytes_sent += packet_size;
+        drop(publishers);
 
-        // Create a VideoPacket for broadcast
-        let video_packet = VideoPacket {
-            timestamp_ms: packet.timestamp_ms,
-            width: packet.width,
-            height: packet.height,
-            is_keyframe: packet.is_keyframe,
-            data: packet.data.clone(),
-        };
-        
-        // Broadcast to all subscribers via the channel
-        let _ = publisher.frame_broadcaster.send(video_packet);
+        sink.push_encoded_video(packet).await?;
         
         debug!(
-            "Push encoded video: {} bytes, keyframe={}, ts={}, subscribers={}",
-            packet_size, packet.is_keyframe, packet.timestamp_ms,
-            publisher.frame_broadcaster.receiver_count()
+            "Push encoded video: {} bytes, keyframe={}, ts={}",
+            packet_size, is_keyframe, timestamp_ms
         );
 
         Ok(())
@@ -629,12 +1630,17 @@ impl LiveNode {
             anyhow::bail!("Publisher is not active");
         }
 
+        let sink = publisher.sink().context("Publisher has no broadcast")?;
         let packet_size = packet.data.len() as u64;
+        let timestamp_ms = packet.timestamp_ms;
         publisher.bytes_sent += packet_size;
+        drop(publishers);
+
+        sink.push_encoded_audio(packet).await?;
 
         debug!(
             "Push encoded audio: {} bytes, ts={}",
-            packet_size, packet.timestamp_ms
+            packet_size, timestamp_ms
         );
 
         Ok(())
@@ -655,8 +1661,8 @@ impl LiveNode {
         Ok(())
     }
 
-    /// Connect subscriber to a broadcast
-    pub async fn connect_subscriber(&self, subscriber_id: &str, ticket: &LiveTicket) -> Result<()> {
+    /// Dial the publisher named in a ticket on the given ALPN
+    async fn dial(&self, ticket: &LiveTicket, alpn: &[u8]) -> Result<Connection> {
         // Convert ticket to EndpointAddr (includes relay and direct addresses)
         let target_addr = ticket.to_endpoint_addr();
         
@@ -672,7 +1678,7 @@ impl LiveNode {
         // Try to connect with a timeout using full address
         let connect_result = n0_future::time::timeout(
             Duration::from_secs(30),
-            self.endpoint.connect(target_addr, ALPN)
+            self.endpoint.connect(target_addr, alpn)
         ).await;
         
         let conn = match connect_result {
@@ -707,6 +1713,254 @@ impl LiveNode {
             }
         };
 
+        Ok(conn)
+    }
+
+    /// Connect subscriber to a broadcast
+    ///
+    /// Opens a MoQ session to the publisher, subscribes to the broadcast
+    /// named in the ticket and forwards its video frames to the subscriber.
+    /// The first connection is made before returning; after that the
+    /// subscriber reconnects by itself when the session drops, reporting
+    /// its progress as [`ConnectionState`]s.
+    pub async fn connect_subscriber(&self, subscriber_id: &str, ticket: &LiveTicket) -> Result<()> {
+        info!("Connecting to endpoint {} for broadcast '{}'", ticket.endpoint_id, ticket.broadcast_name);
+        let (states, state_rx) = StateReporter::new();
+        let mut link = BroadcastLink::new(
+            self.live.clone(),
+            ticket.to_endpoint_addr(),
+            &ticket.broadcast_name,
+            ticket.token.clone(),
+            ReconnectConfig::default(),
+            states.clone(),
+        );
+        let consumer = link.connect().await
+            .with_context(|| format!("Failed to subscribe to '{}'", ticket.broadcast_name))?;
+
+        let shutdown = {
+            let mut subscribers = self.subscribers.write().await;
+            let subscriber = subscribers.get_mut(subscriber_id)
+                .context("Subscriber not found")?;
+            subscriber.states = Some(states);
+            subscriber.state_rx = Some(state_rx);
+            subscriber.shutdown.clone()
+        };
+        let (chat_tx, chat_rx) = mpsc::unbounded_channel();
+        let connection = Self::attach_session(&self.subscribers, subscriber_id, ticket, &link, consumer, &chat_tx).await?;
+        if let Some(subscriber) = self.subscribers.write().await.get_mut(subscriber_id) {
+            subscriber.chat_rx = Some(chat_rx);
+        }
+
+        tokio::spawn(supervise_subscriber(
+            self.subscribers.clone(),
+            subscriber_id.to_string(),
+            ticket.clone(),
+            link,
+            connection,
+            chat_tx,
+            shutdown,
+        ));
+
+        info!("Subscriber {} connected to broadcast '{}'", subscriber_id, ticket.broadcast_name);
+        Ok(())
+    }
+
+    /// Play a broadcast received over a link's session through a subscriber
+    ///
+    /// Also opens the broadcast's chat, collecting it into `chat_tx`.
+    /// Returns the token that stops this connection's tasks.
+    async fn attach_session(
+        subscribers: &RwLock<HashMap<String, Subscriber>>,
+        subscriber_id: &str,
+        ticket: &LiveTicket,
+        link: &BroadcastLink,
+        consumer: BroadcastConsumer,
+        chat_tx: &mpsc::UnboundedSender<ChatMessage>,
+    ) -> Result<CancellationToken> {
+        let session = link.session().context("Not connected")?;
+        let connection = Self::attach_subscriber(
+            subscribers,
+            subscriber_id,
+            consumer.clone(),
+            Some(session.conn().clone()),
+            ticket.media_key.clone(),
+            Some((link.states().clone(), link.config().stall_timeout)),
+        )
+        .await?;
+
+        let mut subscribers = subscribers.write().await;
+        let subscriber = subscribers.get_mut(subscriber_id)
+            .context("Subscriber not found")?;
+        subscriber.chat = Some(session.chat(&ticket.broadcast_name));
+
+        // Collect the broadcast's chat until the connection goes
+        let chat = ChatReader::subscribe(&consumer);
+        let mut chat = match &ticket.media_key {
+            Some(key) => chat.with_decryption(key.clone()),
+            None => chat,
+        };
+        let chat_tx = chat_tx.clone();
+        let chat_shutdown = connection.clone();
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = chat_shutdown.cancelled() => break,
+                    message = chat.recv() => {
+                        let Some(message) = message else { break };
+                        if chat_tx.send(message).is_err() {
+                            break;
+                        }
+                    }
+                }
+            }
+        });
+        Ok(connection)
+    }
+
+    /// Play a broadcast through a subscriber
+    ///
+    /// Starts decoding the broadcast and forwarding its video frames to the
+    /// subscriber's queues. Adaptive bitrate runs only with the connection
+    /// the broadcast arrives on, and connection states are reported live or
+    /// stalled with `states`. A subscriber that played another broadcast
+    /// before stays on the rendition it was receiving. Returns the token
+    /// that stops the tasks started here.
+    async fn attach_subscriber(
+        subscribers: &RwLock<HashMap<String, Subscriber>>,
+        subscriber_id: &str,
+        consumer: BroadcastConsumer,
+        conn: Option<Connection>,
+        media_key: Option<MediaKey>,
+        states: Option<(StateReporter, Duration)>,
+    ) -> Result<CancellationToken> {
+        let video_quality = {
+            let subscribers = subscribers.read().await;
+            let subscriber = subscribers.get(subscriber_id)
+                .context("Subscriber not found")?;
+            let previous = subscriber.switcher.as_ref()
+                .and_then(|s| s.current_track().strip_prefix("video.").map(str::to_string));
+            // "auto" leaves the choice to the subscriber's default rendition
+            previous.or_else(|| Some(subscriber.current_quality.clone()).filter(|q| q != "auto"))
+        };
+        let config = SubscribeConfig {
+            video_quality,
+            encryption: media_key.clone(),
+            ..Default::default()
+        };
+        let subscribe = SubscribeBroadcast::new(consumer.clone(), config);
+        #[cfg(feature = "ffmpeg")]
+        let subscribe = match subscribers.read().await
+            .get(subscriber_id)
+            .and_then(|s| s.playback.clone())
+        {
+            Some(playback) => subscribe
+                .with_video_decoder(super::ffmpeg_decoder::H264Decoder::new(playback)?),
+            None => subscribe,
+        };
+        // Waits for the catalog, so don't hold the subscribers lock here
+        let mut handle = subscribe.start().await?;
+
+        let mut subscribers = subscribers.write().await;
+        let subscriber = subscribers.get_mut(subscriber_id)
+            .context("Subscriber not found")?;
+        let connection = subscriber.shutdown.child_token();
+
+        if let Some(abr) = subscriber.abr.take() {
+            abr.stop();
+        }
+        if let Some(conn) = &conn {
+            let renditions = handle.catalog().map(|c| Rendition::from_catalog(&c)).unwrap_or_default();
+            let (abr, abr_events) = Abr::spawn(
+                conn.clone(),
+                handle.switcher(),
+                handle.video_stats(),
+                renditions,
+                AbrConfig::default(),
+                subscriber.current_quality == "auto",
+            );
+            subscriber.abr = Some(abr);
+            subscriber.abr_events = Some(abr_events);
+        }
+
+        subscriber.is_connected = true;
+        subscriber.catalog = handle.catalog();
+        subscriber.switcher = Some(handle.switcher());
+        subscriber.connection = conn;
+        subscriber.media_key = media_key;
+        subscriber.consumer = Some(consumer);
+
+        let states = states.map(|(states, stall_timeout)| {
+            tokio::spawn(watch_progress(handle.video_stats(), states.clone(), stall_timeout, connection.clone()));
+            states
+        });
+
+        // Forward received frames to the subscriber's frame queue
+        let frame_tx = subscriber.frame_tx.clone();
+        let shutdown = connection.clone();
+        let subscriber_id_clone = subscriber_id.to_string();
+        // Packets carry the coded size of the rendition they came from
+        let sizes: HashMap<String, (u32, u32)> = subscriber.catalog.iter()
+            .flat_map(|catalog| catalog.video.iter())
+            .flat_map(|video| video.renditions.iter())
+            .map(|(track, config)| {
+                (track.clone(), (config.coded_width.unwrap_or(0), config.coded_height.unwrap_or(0)))
+            })
+            .collect();
+        // Decoded frames are read straight from the decoder
+        subscriber.decoded_rx = handle.take_decoded_rx();
+        let mut video_open = subscriber.decoded_rx.is_none();
+
+        tokio::spawn(async move {
+            info!("Starting frame receiver for subscriber {}", subscriber_id_clone);
+            let ended = handle.ended();
+            tokio::pin!(ended);
+            loop {
+                tokio::select! {
+                    _ = shutdown.cancelled() => {
+                        info!("Frame receiver stopped for {}", subscriber_id_clone);
+                        break;
+                    }
+                    reason = &mut ended => {
+                        info!("Subscription ended for {}: {:?}", subscriber_id_clone, reason);
+                        // A finished broadcast won't come back, even if its
+                        // session closes before the announcement goes
+                        if reason == EndReason::Ended {
+                            if let Some(states) = &states {
+                                states.report(ConnectionState::Ended { error: None });
+                            }
+                        }
+                        break;
+                    }
+                    frame = handle.recv_video(), if video_open => {
+                        let Some(frame) = frame else {
+                            video_open = false;
+                            continue;
+                        };
+                        let (width, height) = sizes.get(&frame.track).copied().unwrap_or_default();
+                        let packet = VideoPacket {
+                            timestamp_ms: (frame.pts_us.max(0) / 1000) as u64,
+                            width,
+                            height,
+                            is_keyframe: frame.is_keyframe,
+                            data: frame.data.to_vec(),
+                        };
+                        if let Err(e) = frame_tx.send(packet) {
+                            warn!("Failed to forward frame: {}", e);
+                            break;
+                        }
+                    }
+                }
+            }
+            handle.stop();
+        });
+
+        Ok(connection)
+    }
+
+    /// Connect subscriber to a broadcast over the legacy datagram transport
+    pub async fn connect_subscriber_datagram(&self, subscriber_id: &str, ticket: &LiveTicket) -> Result<()> {
+        let conn = self.dial(ticket, DATAGRAM_ALPN).await?;
+
         let mut subscribers = self.subscribers.write().await;
         let subscriber = subscribers.get_mut(subscriber_id)
             .context("Subscriber not found")?;
@@ -736,6 +1990,7 @@ impl LiveNode {
         
         tokio::spawn(async move {
             info!("Starting frame receiver for subscriber {}", subscriber_id_clone);
+            let mut reassembler = FrameReassembler::default();
             loop {
                 tokio::select! {
                     _ = shutdown.cancelled() => {
@@ -745,18 +2000,21 @@ impl LiveNode {
                     result = conn.read_datagram() => {
                         match result {
                             Ok(data) => {
-                                match VideoPacket::from_bytes(&data) {
-                                    Ok(packet) => {
-                                        debug!("Received video packet: {}x{}, {} bytes", 
-                                            packet.width, packet.height, packet.data.len());
-                                        if let Err(e) = frame_tx.send(packet) {
-                                            warn!("Failed to forward frame: {}", e);
-                                            break;
-                                        }
-                                    }
+                                let fragment = match VideoFragment::from_bytes(&data) {
+                                    Ok(fragment) => fragment,
                                     Err(e) => {
-                                        debug!("Failed to parse video packet: {}", e);
+                                        debug!("Failed to parse video fragment: {}", e);
+                                        continue;
                                     }
+                                };
+                                let Some(packet) = reassembler.push(fragment, Instant::now()) else {
+                                    continue;
+                                };
+                                debug!("Received video packet: {}x{}, {} bytes", 
+                                    packet.width, packet.height, packet.data.len());
+                                if let Err(e) = frame_tx.send(packet) {
+                                    warn!("Failed to forward frame: {}", e);
+                                    break;
                                 }
                             }
                             Err(e) => {
@@ -779,7 +2037,13 @@ impl LiveNode {
             .context("Subscriber not found")?;
         
         subscriber.is_connected = false;
+        // Also stops the reconnect supervisor, closing the session
         subscriber.shutdown.cancel();
+        subscriber.chat = None;
+        subscriber.switcher = None;
+        if let Some(abr) = subscriber.abr.take() {
+            abr.stop();
+        }
 
         info!("Disconnected subscriber: {}", subscriber_id);
         Ok(())
@@ -798,6 +2062,221 @@ impl LiveNode {
         })
     }
 
+    /// Get a switcher for a connected subscriber's video rendition
+    pub async fn subscriber_switcher(&self, subscriber_id: &str) -> Option<VideoSwitcher> {
+        let subscribers = self.subscribers.read().await;
+        subscribers.get(subscriber_id)?.switcher.clone()
+    }
+
+    /// Get the ABR task handle for a connected subscriber
+    pub async fn subscriber_abr(&self, subscriber_id: &str) -> Option<AbrHandle> {
+        let subscribers = self.subscribers.read().await;
+        subscribers.get(subscriber_id)?.abr.clone()
+    }
+
+    /// Post to a publisher's own chat
+    pub async fn send_publisher_chat(&self, publisher_id: &str, kind: ChatKind) -> Result<()> {
+        let handle = self.publishers.read().await
+            .get(publisher_id)
+            .context("Publisher not found")?
+            .handle.clone()
+            .context("Publisher is not active")?;
+        let post = ChatPost {
+            sender: self.endpoint_id(),
+            display_name: self.live.hello(None).display_name,
+            kind,
+        };
+        handle.post_chat(post).await
+    }
+
+    /// Collect the messages on a publisher's chat since the last call
+    pub async fn take_publisher_chat(&self, publisher_id: &str) -> Vec<ChatMessage> {
+        let mut publishers = self.publishers.write().await;
+        let Some(rx) = publishers.get_mut(publisher_id).and_then(|p| p.chat_rx.as_mut()) else {
+            return Vec::new();
+        };
+        let mut messages = Vec::new();
+        loop {
+            match rx.try_recv() {
+                Ok(message) => messages.push(message),
+                Err(broadcast::error::TryRecvError::Lagged(n)) => warn!("Dropped {} chat messages", n),
+                Err(_) => break,
+            }
+        }
+        messages
+    }
+
+    /// Post to the chat of the broadcast a subscriber watches
+    ///
+    /// The publisher stamps the post with this node's endpoint ID and may
+    /// drop it if it comes too fast.
+    pub async fn send_subscriber_chat(&self, subscriber_id: &str, kind: ChatKind) -> Result<()> {
+        let mut subscribers = self.subscribers.write().await;
+        subscribers.get_mut(subscriber_id)
+            .context("Subscriber not found")?
+            .chat.as_mut()
+            .context("Subscriber is not connected")?
+            .send(kind)
+    }
+
+    /// Collect the messages on a subscriber's chat since the last call
+    pub async fn take_subscriber_chat(&self, subscriber_id: &str) -> Vec<ChatMessage> {
+        let mut subscribers = self.subscribers.write().await;
+        let Some(rx) = subscribers.get_mut(subscriber_id).and_then(|s| s.chat_rx.as_mut()) else {
+            return Vec::new();
+        };
+        let mut messages = Vec::new();
+        while let Ok(message) = rx.try_recv() {
+            messages.push(message);
+        }
+        messages
+    }
+
+    /// Where a subscriber's connection to its publisher stands
+    pub async fn subscriber_connection_state(&self, subscriber_id: &str) -> Option<ConnectionState> {
+        let subscribers = self.subscribers.read().await;
+        subscribers.get(subscriber_id)?.states.as_ref().map(|s| s.current())
+    }
+
+    /// Take the connection state changes since the last call
+    pub async fn take_connection_events(&self, subscriber_id: &str) -> Vec<ConnectionState> {
+        let mut subscribers = self.subscribers.write().await;
+        let Some(rx) = subscribers.get_mut(subscriber_id).and_then(|s| s.state_rx.as_mut()) else {
+            return Vec::new();
+        };
+        std::iter::from_fn(|| rx.try_recv().ok()).collect()
+    }
+
+    /// Collect the ABR decisions made since the last call
+    pub async fn take_abr_events(&self, subscriber_id: &str) -> Vec<AbrEvent> {
+        let mut subscribers = self.subscribers.write().await;
+        let Some(rx) = subscribers.get_mut(subscriber_id).and_then(|s| s.abr_events.as_mut()) else {
+            return Vec::new();
+        };
+        let mut events = Vec::new();
+        while let Ok(event) = rx.try_recv() {
+            events.push(event);
+        }
+        events
+    }
+
+    /// Change a subscriber's video quality
+    ///
+    /// Accepts a track suffix ("low", "med", "high") or a preset name
+    /// ("P720"). A connected subscriber switches rendition at the next group
+    /// boundary; otherwise the quality applies on connect. "auto" hands
+    /// control to the ABR controller, and any other quality pauses it.
+    pub async fn set_subscriber_quality(&self, subscriber_id: &str, quality: &str) -> Result<()> {
+        let mut subscribers = self.subscribers.write().await;
+        let subscriber = subscribers.get_mut(subscriber_id)
+            .context("Subscriber not found")?;
+
+        let suffix = VideoPreset::from_name(quality)
+            .map(|preset| preset.video_quality().suffix())
+            .unwrap_or(quality);
+        if suffix != "auto" {
+            if let Some(switcher) = &subscriber.switcher {
+                switcher.switch_video(suffix)?;
+            }
+        }
+        if let Some(abr) = &subscriber.abr {
+            abr.set_enabled(suffix == "auto");
+        }
+        subscriber.current_quality = suffix.to_string();
+
+        info!("Subscriber {} quality set to {}", subscriber_id, suffix);
+        Ok(())
+    }
+
+    /// Record an active publisher's broadcast to fragmented MP4 at `path`
+    ///
+    /// Records the highest rendition being published.
+    pub async fn start_publisher_recording(&self, publisher_id: &str, path: &str) -> Result<()> {
+        let (broadcast, config) = {
+            let publishers = self.publishers.read().await;
+            let publisher = publishers.get(publisher_id).context("Publisher not found")?;
+            if publisher.recording.is_some() {
+                anyhow::bail!("Publisher is already recording");
+            }
+            let config = RecordConfig {
+                encryption: publisher.media_key.clone(),
+                ..Default::default()
+            };
+            (publisher.broadcast.clone().context("Publisher is not active")?, config)
+        };
+        // Waits for the catalog, so don't hold the publishers lock here
+        let recording = Recorder::new(broadcast, path, config).start().await?;
+
+        let mut publishers = self.publishers.write().await;
+        let publisher = publishers.get_mut(publisher_id).context("Publisher not found")?;
+        publisher.recording = Some(recording);
+        info!("Publisher {} recording to {}", publisher_id, path);
+        Ok(())
+    }
+
+    /// Stop recording a publisher's broadcast
+    pub async fn stop_publisher_recording(&self, publisher_id: &str) -> Result<RecordStats> {
+        let recording = self.publishers.write().await
+            .get_mut(publisher_id)
+            .context("Publisher not found")?
+            .recording.take()
+            .context("Publisher is not recording")?;
+        recording.stop().await
+    }
+
+    /// Record what a connected subscriber receives to fragmented MP4 at `path`
+    ///
+    /// The recording follows the subscriber's rendition switches, manual or
+    /// ABR, starting a new file at each one.
+    pub async fn start_subscriber_recording(&self, subscriber_id: &str, path: &str) -> Result<()> {
+        let (broadcast, switcher, config) = {
+            let subscribers = self.subscribers.read().await;
+            let subscriber = subscribers.get(subscriber_id).context("Subscriber not found")?;
+            if subscriber.recording.is_some() {
+                anyhow::bail!("Subscriber is already recording");
+            }
+            let broadcast = subscriber.consumer.clone().context("Subscriber is not connected")?;
+            let config = RecordConfig {
+                encryption: subscriber.media_key.clone(),
+                ..Default::default()
+            };
+            (broadcast, subscriber.switcher.clone(), config)
+        };
+        let mut recorder = Recorder::new(broadcast, path, config);
+        if let Some(switcher) = switcher {
+            recorder = recorder.follow(switcher.watch_track());
+        }
+        let recording = recorder.start().await?;
+
+        let mut subscribers = self.subscribers.write().await;
+        let subscriber = subscribers.get_mut(subscriber_id).context("Subscriber not found")?;
+        subscriber.recording = Some(recording);
+        info!("Subscriber {} recording to {}", subscriber_id, path);
+        Ok(())
+    }
+
+    /// Stop recording a subscriber's broadcast
+    pub async fn stop_subscriber_recording(&self, subscriber_id: &str) -> Result<RecordStats> {
+        let recording = self.subscribers.write().await
+            .get_mut(subscriber_id)
+            .context("Subscriber not found")?
+            .recording.take()
+            .context("Subscriber is not recording")?;
+        recording.stop().await
+    }
+
+    /// Get the catalog an active publisher is announcing
+    pub async fn get_publisher_catalog(&self, publisher_id: &str) -> Option<Catalog> {
+        let publishers = self.publishers.read().await;
+        publishers.get(publisher_id)?.handle.as_ref().map(|h| h.catalog())
+    }
+
+    /// Get the catalog a subscriber received when it connected
+    pub async fn get_subscriber_catalog(&self, subscriber_id: &str) -> Option<Catalog> {
+        let subscribers = self.subscribers.read().await;
+        subscribers.get(subscriber_id)?.catalog.clone()
+    }
+
     /// Get subscriber status
     pub async fn get_subscriber_status(&self, subscriber_id: &str) -> Option<SubscriberStatus> {
         let subscribers = self.subscribers.read().await;
@@ -832,6 +2311,31 @@ impl LiveNode {
         }
     }
 
+    /// Decode a subscriber's video to raw pixels (set before connecting)
+    ///
+    /// None turns decoding off. Requires the ffmpeg feature.
+    pub async fn set_subscriber_decoding(&self, subscriber_id: &str, playback: Option<PlaybackConfig>) -> Result<()> {
+        if playback.is_some() && !cfg!(feature = "ffmpeg") {
+            anyhow::bail!("Decoding video requires the ffmpeg feature");
+        }
+        let mut subscribers = self.subscribers.write().await;
+        let subscriber = subscribers.get_mut(subscriber_id)
+            .context("Subscriber not found")?;
+        subscriber.playback = playback;
+        Ok(())
+    }
+
+    /// Receive a decoded video frame (non-blocking)
+    pub async fn receive_decoded_frame(&self, subscriber_id: &str) -> Option<DecodedFrame> {
+        let mut subscribers = self.subscribers.write().await;
+        let subscriber = subscribers.get_mut(subscriber_id)?;
+
+        let frame = subscriber.decoded_rx.as_mut()?.try_recv().ok()?;
+        subscriber.frames_received += 1;
+        subscriber.bytes_received += frame.data.len() as u64;
+        Some(frame)
+    }
+
     /// Simulate receiving video for testing
     pub async fn simulate_video_receive(&self, subscriber_id: &str, frame_size: u64) -> Result<()> {
         let mut subscribers = self.subscribers.write().await;
@@ -850,10 +2354,74 @@ impl LiveNode {
     pub async fn shutdown(&self) {
         info!("Shutting down LiveNode");
         self.shutdown.cancel();
+        for (_, relay) in self.relays.write().await.drain() {
+            relay.stop();
+        }
+        for (_, joined) in self.rooms.lock().await.drain() {
+            joined.room.leave().await;
+        }
+        for (_, discovery) in self.discoveries.lock().await.drain() {
+            discovery.close().await;
+        }
+        self.live.shutdown();
+        if let Some(router) = self.router.lock().await.take() {
+            if let Err(e) = router.shutdown().await {
+                warn!("Router shutdown failed: {}", e);
+            }
+        }
         self.endpoint.close().await;
     }
 }
 
+/// Keep a subscriber connected, reconnecting whenever its session drops
+///
+/// Runs until the subscriber is disconnected, the publisher ends the
+/// broadcast or the link gives up.
+async fn supervise_subscriber(
+    subscribers: Arc<RwLock<HashMap<String, Subscriber>>>,
+    subscriber_id: String,
+    ticket: LiveTicket,
+    mut link: BroadcastLink,
+    mut connection: CancellationToken,
+    chat_tx: mpsc::UnboundedSender<ChatMessage>,
+    shutdown: CancellationToken,
+) {
+    loop {
+        let lost = tokio::select! {
+            _ = shutdown.cancelled() => return,
+            lost = link.lost() => lost,
+        };
+        connection.cancel();
+        // The publisher may have finished the tracks and then gone away
+        if lost == Lost::Ended || link.states().is_ended() {
+            break;
+        }
+
+        warn!("Subscriber {} lost its connection, reconnecting", subscriber_id);
+        let consumer = tokio::select! {
+            _ = shutdown.cancelled() => return,
+            consumer = link.reconnect() => consumer,
+        };
+        let Some(consumer) = consumer else {
+            break;
+        };
+        match LiveNode::attach_session(&subscribers, &subscriber_id, &ticket, &link, consumer, &chat_tx).await {
+            Ok(token) => connection = token,
+            Err(e) => {
+                warn!("Subscriber {} failed to resume: {}", subscriber_id, e);
+                link.states().report(ConnectionState::Ended { error: Some(e.to_string()) });
+                break;
+            }
+        }
+    }
+
+    info!("Subscriber {} stopped: {:?}", subscriber_id, link.states().current());
+    if let Some(subscriber) = subscribers.write().await.get_mut(&subscriber_id) {
+        subscriber.is_connected = false;
+        subscriber.chat = None;
+    }
+}
+
 /// Publisher status for Flutter
 #[derive(Debug, Clone)]
 pub struct PublisherStatus {
@@ -876,3 +2444,161 @@ pub struct SubscriberStatus {
     pub current_quality: String,
     pub buffer_health: f32,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn packet(is_keyframe: bool, size: usize) -> VideoPacket {
+        VideoPacket {
+            timestamp_ms: 1000,
+            width: 1280,
+            height: 720,
+            is_keyframe,
+            data: (0..size).map(|i| i as u8).collect(),
+        }
+    }
+
+    #[test]
+    fn test_ticket_reads_older_layouts() {
+        /// Ticket layout before tokens and media keys
+        #[derive(Serialize)]
+        struct Original {
+            endpoint_id: EndpointId,
+            broadcast_name: String,
+            relay_url: Option<String>,
+            direct_addrs: Vec<SocketAddr>,
+        }
+
+        let endpoint_id = SecretKey::from_bytes(&[7; 32]).public();
+        let original = Original {
+            endpoint_id,
+            broadcast_name: "show".to_string(),
+            relay_url: None,
+            direct_addrs: vec!["127.0.0.1:4433".parse().unwrap()],
+        };
+        let ticket = LiveTicket::from_bytes(&postcard::to_stdvec(&original).unwrap()).unwrap();
+        assert_eq!((ticket.endpoint_id, ticket.broadcast_name.as_str()), (endpoint_id, "show"));
+        assert_eq!(ticket.direct_addrs, original.direct_addrs);
+        assert!(ticket.token.is_none() && ticket.media_key.is_none());
+
+        let key = MediaKey::generate();
+        let ticket = LiveTicket::deserialize(&ticket.with_media_key(key.clone()).serialize()).unwrap();
+        assert_eq!(ticket.media_key, Some(key));
+        assert_eq!(ticket.without_media_key().media_key, None);
+    }
+
+    #[test]
+    fn test_crc32() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn test_fragment_fits_datagram() {
+        let fragments = packet(true, 10_000).fragment(7, 1200).unwrap();
+        assert!(fragments.len() > 1);
+        for fragment in &fragments {
+            assert!(fragment.to_bytes().len() <= 1200);
+            assert_eq!(fragment.frame_id, 7);
+        }
+    }
+
+    #[test]
+    fn test_reassemble_out_of_order() {
+        let mut reassembler = FrameReassembler::default();
+        let now = Instant::now();
+        let mut fragments = packet(true, 5000).fragment(0, 1200).unwrap();
+        fragments.reverse();
+
+        let mut result = None;
+        for fragment in fragments {
+            result = reassembler.push(fragment, now);
+        }
+        let result = result.unwrap();
+        assert_eq!(result.data, packet(true, 5000).data);
+        assert!(!reassembler.is_waiting_for_keyframe());
+    }
+
+    #[test]
+    fn test_waits_for_keyframe_after_loss() {
+        let mut reassembler = FrameReassembler::default();
+        let now = Instant::now();
+
+        for fragment in packet(true, 3000).fragment(0, 1200).unwrap() {
+            reassembler.push(fragment, now);
+        }
+
+        // Frame 1 loses a fragment; frame 2 is complete but depends on it
+        let mut lost = packet(false, 3000).fragment(1, 1200).unwrap();
+        lost.pop();
+        for fragment in lost {
+            assert!(reassembler.push(fragment, now).is_none());
+        }
+        let mut delivered = None;
+        for fragment in packet(false, 3000).fragment(2, 1200).unwrap() {
+            delivered = reassembler.push(fragment, now);
+        }
+        assert!(delivered.is_none());
+        assert!(reassembler.is_waiting_for_keyframe());
+
+        for fragment in packet(true, 3000).fragment(3, 1200).unwrap() {
+            delivered = reassembler.push(fragment, now);
+        }
+        assert!(delivered.unwrap().is_keyframe);
+        assert!(!reassembler.is_waiting_for_keyframe());
+    }
+
+    #[test]
+    fn test_incomplete_frame_expires() {
+        let mut reassembler = FrameReassembler::new(Duration::from_millis(100));
+        let start = Instant::now();
+
+        let fragments = packet(true, 3000).fragment(0, 1200).unwrap();
+        reassembler.push(fragments[0].clone(), start);
+        reassembler.expire(start + Duration::from_millis(200));
+        assert_eq!(reassembler.dropped_frames(), 1);
+
+        // The rest of the expired frame starts a fresh, incomplete frame
+        assert!(reassembler.push(fragments[1].clone(), start + Duration::from_millis(200)).is_none());
+    }
+
+    #[test]
+    fn test_corrupt_frame_discarded() {
+        let mut reassembler = FrameReassembler::default();
+        let now = Instant::now();
+
+        let mut fragments = packet(true, 3000).fragment(0, 1200).unwrap();
+        fragments[0].payload[20] ^= 0xFF;
+        let mut delivered = None;
+        for fragment in fragments {
+            delivered = reassembler.push(fragment, now);
+        }
+        assert!(delivered.is_none());
+        assert_eq!(reassembler.dropped_frames(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_publisher_skips_datagrams() {
+        let video = EncodedVideoPacket {
+            data: vec![0; 100],
+            timestamp_ms: 1000,
+            is_keyframe: true,
+            codec: "h264".to_string(),
+            width: 1280,
+            height: 720,
+        };
+        for media_key in [None, Some(MediaKey::generate())] {
+            let mut publisher = Publisher::new("publisher".to_string(), "broadcast".to_string());
+            let (_broadcast, handle) = PublishBroadcast::new(PublishConfig {
+                encryption: media_key.clone(),
+                ..Default::default()
+            });
+            publisher.media_key = media_key.clone();
+            publisher.handle = Some(handle);
+
+            let mut frames = publisher.subscribe_frames();
+            publisher.sink().unwrap().try_push_encoded_video(video.clone()).unwrap();
+            assert_eq!(frames.try_recv().is_ok(), media_key.is_none());
+        }
+    }
+}
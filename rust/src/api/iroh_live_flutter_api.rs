@@ -5,11 +5,29 @@
 
 use std::collections::HashMap;
 use std::sync::RwLock;
+use std::time::{Duration, UNIX_EPOCH};
 use flutter_rust_bridge::frb;
 use once_cell::sync::Lazy;
 use tokio::sync::Mutex as TokioMutex;
-
-use super::iroh_live::{LiveNode, LiveTicket, VideoFrame as IrohVideoFrame, EncodedVideoPacket, EncodedAudioPacket};
+use tracing::debug;
+
+use hang::Catalog;
+use hang::catalog::{AudioCodec, AudioConfig, VideoConfig, AAC};
+
+use super::iroh_live::{LiveNode, LiveTicket, PublisherSink, VideoPreset, VideoFrame as IrohVideoFrame, EncodedVideoPacket, EncodedAudioPacket};
+use super::publish_broadcast::{catalog_for, AudioQuality, VideoQuality};
+use super::abr::AbrHandle;
+use super::chat::{ChatKind, ChatMessage};
+use super::discovery::DiscoveryEvent;
+use super::access::BroadcastPolicy;
+use super::av::{AudioFormat, PixelFormat, PlaybackConfig};
+use super::endpoint_config::{EndpointConfig, RelayOption};
+use super::identity;
+use super::reconnect::ConnectionState;
+use super::record::RecordStats;
+use super::room::{RoomEvent, member_path};
+use super::roster::{RosterEvent, Viewer};
+use super::subscribe_broadcast::VideoSwitcher;
 
 // ============================================================================
 // Types for Flutter (all use primitives or simple structs)
@@ -23,7 +41,7 @@ pub struct FlutterVideoFrame {
     pub height: u32,
     pub data: Vec<u8>,
     pub timestamp_ms: u64,
-    pub format: String, // "rgba", "nv12", "i420"
+    pub format: String, // "rgba", "bgra", "nv12", "i420"
 }
 
 /// Encoded video packet for Flutter (H264/H265)
@@ -128,6 +146,17 @@ pub struct FlutterPublisherStatus {
     pub audio_renditions: Vec<String>,
 }
 
+/// Per-rendition publishing counters for Flutter
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterRenditionStats {
+    pub track_name: String,
+    pub frames_written: u64,
+    pub bytes_written: u64,
+    pub frames_dropped: u64,
+    pub groups_cut: u64,
+}
+
 /// Subscriber status
 #[frb(non_opaque)]
 #[derive(Debug, Clone)]
@@ -141,6 +170,307 @@ pub struct FlutterSubscriberStatus {
     pub buffer_health: f32,
 }
 
+/// Result of a finished recording
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterRecordingStats {
+    /// Files written, one per rendition switch
+    pub files: Vec<String>,
+    pub fragments: u64,
+    pub video_frames: u64,
+    pub audio_frames: u64,
+    pub bytes: u64,
+}
+
+impl From<RecordStats> for FlutterRecordingStats {
+    fn from(stats: RecordStats) -> Self {
+        Self {
+            files: stats.files.iter().map(|path| path.display().to_string()).collect(),
+            fragments: stats.fragments,
+            video_frames: stats.video_frames,
+            audio_frames: stats.audio_frames,
+            bytes: stats.bytes,
+        }
+    }
+}
+
+/// Relay servers used to reach peers behind NATs
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub enum FlutterRelayMode {
+    /// The public n0 relays
+    Default,
+    /// A self-hosted relay
+    Custom { url: String },
+    /// LAN only: direct connections, tickets carry only direct addresses
+    Disabled,
+}
+
+/// Endpoint settings for `iroh_node_init_with_config`
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterEndpointConfig {
+    pub relay_mode: FlutterRelayMode,
+    /// UDP port to bind; random when None
+    pub bind_port: Option<u16>,
+    /// Find peers on the local network with mDNS
+    pub local_discovery: bool,
+}
+
+impl TryFrom<FlutterEndpointConfig> for EndpointConfig {
+    type Error = String;
+
+    fn try_from(config: FlutterEndpointConfig) -> Result<Self, String> {
+        let relay = match config.relay_mode {
+            FlutterRelayMode::Default => RelayOption::Default,
+            FlutterRelayMode::Custom { url } => RelayOption::Custom(
+                url.parse().map_err(|e| format!("Invalid relay URL: {}", e))?,
+            ),
+            FlutterRelayMode::Disabled => RelayOption::Disabled,
+        };
+        Ok(EndpointConfig {
+            relay,
+            bind_port: config.bind_port,
+            local_discovery: config.local_discovery,
+            ..Default::default()
+        })
+    }
+}
+
+/// Who may watch a broadcast
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterAccessPolicy {
+    /// Viewers need a ticket from `iroh_publish_issue_ticket`, unless allow-listed
+    pub require_token: bool,
+    /// Endpoint IDs always admitted
+    pub allow: Vec<String>,
+    /// Endpoint IDs always refused
+    pub deny: Vec<String>,
+}
+
+impl TryFrom<FlutterAccessPolicy> for BroadcastPolicy {
+    type Error = String;
+
+    fn try_from(policy: FlutterAccessPolicy) -> Result<Self, String> {
+        let parse = |ids: Vec<String>| {
+            ids.iter()
+                .map(|id| id.parse().map_err(|e| format!("Invalid endpoint ID '{}': {}", id, e)))
+                .collect::<Result<_, String>>()
+        };
+        Ok(BroadcastPolicy {
+            require_token: policy.require_token,
+            allow: parse(policy.allow)?,
+            deny: parse(policy.deny)?,
+        })
+    }
+}
+
+/// A ticket carrying a capability token
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterIssuedTicket {
+    pub ticket: String,
+    /// Pass to `iroh_publish_revoke_token` to revoke the ticket
+    pub token_id: u64,
+    /// Unix time (seconds) the ticket stops working
+    pub expires_at: u64,
+}
+
+/// A broadcast relayed through this node
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterRelayInfo {
+    pub broadcast_name: String,
+    /// Endpoint IDs the broadcast passes through to get here, origin first
+    pub route: Vec<String>,
+}
+
+/// A viewer connected to this node
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterViewer {
+    pub endpoint_id: String,
+    pub display_name: Option<String>,
+    /// Unix time (milliseconds) the viewer connected
+    pub connected_at_ms: u64,
+    /// Tracks being received, as "broadcast/track"
+    pub tracks: Vec<String>,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub rtt_ms: u32,
+}
+
+impl From<Viewer> for FlutterViewer {
+    fn from(viewer: Viewer) -> Self {
+        Self {
+            endpoint_id: viewer.endpoint_id.to_string(),
+            display_name: viewer.display_name,
+            connected_at_ms: viewer.connected_at
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            tracks: viewer.tracks,
+            bytes_sent: viewer.bytes_sent,
+            bytes_received: viewer.bytes_received,
+            rtt_ms: viewer.rtt.as_millis() as u32,
+        }
+    }
+}
+
+/// A viewer joining or leaving
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterRosterEvent {
+    pub kind: String, // "joined", "left"
+    pub endpoint_id: String,
+    /// The viewer that joined
+    pub viewer: Option<FlutterViewer>,
+    /// Whether a viewer that left was kicked
+    pub kicked: bool,
+}
+
+impl From<RosterEvent> for FlutterRosterEvent {
+    fn from(event: RosterEvent) -> Self {
+        match event {
+            RosterEvent::Joined(viewer) => Self {
+                kind: "joined".to_string(),
+                endpoint_id: viewer.endpoint_id.to_string(),
+                viewer: Some(viewer.into()),
+                kicked: false,
+            },
+            RosterEvent::Left { endpoint_id, kicked } => Self {
+                kind: "left".to_string(),
+                endpoint_id: endpoint_id.to_string(),
+                viewer: None,
+                kicked,
+            },
+        }
+    }
+}
+
+/// A message on a broadcast's chat
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterChatMessage {
+    pub seq: u64,
+    pub sender: String,
+    pub display_name: Option<String>,
+    /// Unix time (milliseconds) the publisher accepted the message
+    pub timestamp_ms: u64,
+    pub kind: String, // "text", "reaction"
+    /// Message text, or the reaction
+    pub text: String,
+    /// For reactions, the `seq` of the message reacted to
+    pub reaction_to: Option<u64>,
+}
+
+impl From<ChatMessage> for FlutterChatMessage {
+    fn from(message: ChatMessage) -> Self {
+        let (kind, text, reaction_to) = match message.kind {
+            ChatKind::Text(text) => ("text", text, None),
+            ChatKind::Reaction { target, emoji } => ("reaction", emoji, Some(target)),
+        };
+        Self {
+            seq: message.seq,
+            sender: message.sender.to_string(),
+            display_name: message.display_name,
+            timestamp_ms: message.timestamp_ms,
+            kind: kind.to_string(),
+            text,
+            reaction_to,
+        }
+    }
+}
+
+/// A room member joining or leaving
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterRoomEvent {
+    pub kind: String, // "joined", "left"
+    pub member: String,
+    /// Subscriber playing the member's broadcast, while it is in the room
+    pub subscriber_id: String,
+}
+
+impl FlutterRoomEvent {
+    fn new(room: &str, event: &RoomEvent) -> Self {
+        let (kind, member) = match event {
+            RoomEvent::Joined { member, .. } => ("joined", *member),
+            RoomEvent::Left { member } => ("left", *member),
+        };
+        Self {
+            kind: kind.to_string(),
+            member: member.to_string(),
+            subscriber_id: member_path(room, member),
+        }
+    }
+}
+
+/// A broadcast appearing on or leaving a discovered node
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterDiscoveryEvent {
+    pub kind: String, // "added", "removed"
+    pub broadcast_name: String,
+}
+
+impl From<DiscoveryEvent> for FlutterDiscoveryEvent {
+    fn from(event: DiscoveryEvent) -> Self {
+        let (kind, broadcast_name) = match event {
+            DiscoveryEvent::Added { name } => ("added", name),
+            DiscoveryEvent::Removed { name } => ("removed", name),
+        };
+        Self {
+            kind: kind.to_string(),
+            broadcast_name,
+        }
+    }
+}
+
+/// Where a subscriber's connection to its publisher stands
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterConnectionState {
+    pub state: String, // "connecting", "live", "stalled", "reconnecting", "ended"
+    /// Reconnect attempt about to be made, while reconnecting
+    pub attempt: u32,
+    /// Wait before that attempt, while reconnecting
+    pub retry_in_ms: u64,
+    /// Why the subscription ended, if not because the broadcast did
+    pub error: Option<String>,
+}
+
+impl From<ConnectionState> for FlutterConnectionState {
+    fn from(state: ConnectionState) -> Self {
+        let name = state.name().to_string();
+        let (attempt, retry_in_ms, error) = match state {
+            ConnectionState::Reconnecting { attempt, delay } => (attempt, delay.as_millis() as u64, None),
+            ConnectionState::Ended { error } => (0, 0, error),
+            _ => (0, 0, None),
+        };
+        Self {
+            state: name,
+            attempt,
+            retry_in_ms,
+            error,
+        }
+    }
+}
+
+/// Adaptive bitrate decision for Flutter
+#[frb(non_opaque)]
+#[derive(Debug, Clone)]
+pub struct FlutterAbrEvent {
+    pub from_track: String,
+    pub to_track: String,
+    pub reason: String, // "late_frames", "packet_loss", "rtt_inflation", "recovered"
+    pub throughput_bps: u64,
+    pub rtt_ms: u32,
+    pub loss_percent: f32,
+    pub late_frames: u64,
+}
+
 // ============================================================================
 // Global State
 // ============================================================================
@@ -195,19 +525,85 @@ static SUBSCRIBERS: Lazy<RwLock<HashMap<String, SubscribeState>>> = Lazy::new(||
     RwLock::new(HashMap::new())
 });
 
+/// Ingest sinks for active publishers, usable from sync push calls
+static PUBLISHER_SINKS: Lazy<RwLock<HashMap<String, PublisherSink>>> = Lazy::new(|| {
+    RwLock::new(HashMap::new())
+});
+
+/// Rendition switchers for connected subscribers, usable from sync calls
+static SUBSCRIBER_SWITCHERS: Lazy<RwLock<HashMap<String, VideoSwitcher>>> = Lazy::new(|| {
+    RwLock::new(HashMap::new())
+});
+
+/// ABR tasks for connected subscribers, paused by manual quality changes
+static SUBSCRIBER_ABR: Lazy<RwLock<HashMap<String, AbrHandle>>> = Lazy::new(|| {
+    RwLock::new(HashMap::new())
+});
+
 // ============================================================================
 // Node Management API
 // ============================================================================
 
-/// Initialize the iroh-live node
+impl Default for FlutterEndpointConfig {
+    fn default() -> Self {
+        Self {
+            relay_mode: FlutterRelayMode::Default,
+            bind_port: None,
+            local_discovery: false,
+        }
+    }
+}
+
+/// Initialize the iroh-live node using the n0 relays
+///
+/// A new identity is generated, so tickets change on every launch; use
+/// `iroh_node_init_with_key` or `iroh_node_init_from_file` to keep them.
 pub async fn iroh_node_init() -> Result<String, String> {
+    init_node(None, FlutterEndpointConfig::default()).await
+}
+
+/// Initialize the iroh-live node with custom relay, port and discovery settings
+///
+/// With relays disabled startup doesn't wait for internet connectivity.
+/// The settings only apply when the node is first created.
+pub async fn iroh_node_init_with_config(config: FlutterEndpointConfig) -> Result<String, String> {
+    init_node(None, config).await
+}
+
+/// Initialize the iroh-live node with a stored identity
+///
+/// `secret_key` is the 32 bytes from `iroh_identity_generate` or
+/// `iroh_node_export_key`, typically kept in the platform's secure storage.
+pub async fn iroh_node_init_with_key(
+    secret_key: Vec<u8>,
+    config: Option<FlutterEndpointConfig>,
+) -> Result<String, String> {
+    let key = identity::secret_key_from_bytes(&secret_key).map_err(|e| e.to_string())?;
+    init_node(Some(key), config.unwrap_or_default()).await
+}
+
+/// Initialize the iroh-live node with the identity stored at `path`
+///
+/// The key file is created on first launch and reused afterwards, so the
+/// endpoint ID and shared tickets stay the same across restarts.
+pub async fn iroh_node_init_from_file(
+    path: String,
+    config: Option<FlutterEndpointConfig>,
+) -> Result<String, String> {
+    let key = identity::load_or_create_secret_key(&path)
+        .map_err(|e| format!("Failed to load identity: {:#}", e))?;
+    init_node(Some(key), config.unwrap_or_default()).await
+}
+
+async fn init_node(secret_key: Option<iroh::SecretKey>, config: FlutterEndpointConfig) -> Result<String, String> {
+    let config = EndpointConfig::try_from(config)?;
     let mut node_guard = LIVE_NODE.lock().await;
     
     if node_guard.is_some() {
         return Ok("Node already initialized".to_string());
     }
     
-    match LiveNode::new(None).await {
+    match LiveNode::with_config(secret_key, config).await {
         Ok(node) => {
             let endpoint_id = node.endpoint_id().to_string();
             *node_guard = Some(node);
@@ -227,6 +623,27 @@ pub async fn iroh_node_get_endpoint_id() -> Result<String, String> {
     }
 }
 
+/// Export the running node's secret key, to persist its identity
+pub async fn iroh_node_export_key() -> Result<Vec<u8>, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+    Ok(identity::secret_key_to_bytes(node.secret_key()).to_vec())
+}
+
+/// Generate a new identity (32 secret key bytes) for secure storage
+#[frb(sync)]
+pub fn iroh_identity_generate() -> Vec<u8> {
+    identity::secret_key_to_bytes(&identity::generate_secret_key()).to_vec()
+}
+
+/// Endpoint ID belonging to a secret key
+#[frb(sync)]
+pub fn iroh_identity_endpoint_id(secret_key: Vec<u8>) -> Result<String, String> {
+    identity::secret_key_from_bytes(&secret_key)
+        .map(|key| key.public().to_string())
+        .map_err(|e| e.to_string())
+}
+
 /// Shutdown the node
 pub async fn iroh_node_shutdown() -> Result<(), String> {
     let mut node_guard = LIVE_NODE.lock().await;
@@ -504,6 +921,10 @@ pub async fn iroh_publish_start_async(publisher_id: String) -> Result<(), String
     node.start_publishing(&publisher_id)
         .await
         .map_err(|e| format!("Failed to start publishing: {}", e))?;
+
+    if let Some(sink) = node.publisher_sink(&publisher_id).await {
+        PUBLISHER_SINKS.write().unwrap().insert(publisher_id.clone(), sink);
+    }
     
     let mut publishers = PUBLISHERS.write().unwrap();
     if let Some(state) = publishers.get_mut(&publisher_id) {
@@ -531,6 +952,8 @@ pub async fn iroh_publish_stop_async(publisher_id: String) -> Result<(), String>
     let node_guard = LIVE_NODE.lock().await;
     let node = node_guard.as_ref().ok_or("Node not initialized")?;
     
+    PUBLISHER_SINKS.write().unwrap().remove(&publisher_id);
+
     node.stop_publishing(&publisher_id)
         .await
         .map_err(|e| format!("Failed to stop publishing: {}", e))?;
@@ -556,9 +979,75 @@ pub fn iroh_publish_stop(publisher_id: String) -> bool {
     }
 }
 
+/// Publish an MP4 file (H.264 with AAC or Opus) as a live broadcast
+///
+/// Viewers subscribe with the publisher's ticket as for camera broadcasts.
+/// Returns the file's duration in milliseconds.
+pub async fn iroh_publish_file_start_async(publisher_id: String, path: String, looping: bool) -> Result<u64, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.start_accepting()
+        .await
+        .map_err(|e| format!("Failed to start accepting connections: {}", e))?;
+
+    let duration = node.start_file_publishing(&publisher_id, &path, looping)
+        .await
+        .map_err(|e| format!("Failed to publish file: {}", e))?;
+
+    let mut publishers = PUBLISHERS.write().unwrap();
+    if let Some(state) = publishers.get_mut(&publisher_id) {
+        state.is_active = true;
+    }
+
+    Ok(duration.as_millis() as u64)
+}
+
+/// Seek a file publisher; playback resumes at the keyframe before `position_ms`
+pub async fn iroh_publish_file_seek(publisher_id: String, position_ms: u64) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.seek_file_publisher(&publisher_id, Duration::from_millis(position_ms))
+        .await
+        .map_err(|e| format!("Failed to seek: {}", e))
+}
+
+/// Current position of a file publisher in milliseconds
+pub async fn iroh_publish_file_position(publisher_id: String) -> Option<u64> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref()?;
+
+    node.file_publisher_position(&publisher_id)
+        .await
+        .map(|position| position.as_millis() as u64)
+}
+
+/// Record an active publisher's broadcast to a fragmented MP4 file
+pub async fn iroh_publish_record_start(publisher_id: String, path: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.start_publisher_recording(&publisher_id, &path)
+        .await
+        .map_err(|e| format!("Failed to start recording: {}", e))
+}
+
+/// Stop recording a publisher's broadcast
+pub async fn iroh_publish_record_stop(publisher_id: String) -> Result<FlutterRecordingStats, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.stop_publisher_recording(&publisher_id)
+        .await
+        .map(FlutterRecordingStats::from)
+        .map_err(|e| format!("Failed to stop recording: {}", e))
+}
+
 /// Remove a publisher
 #[frb(sync)]
 pub fn iroh_publish_remove(publisher_id: String) -> bool {
+    PUBLISHER_SINKS.write().unwrap().remove(&publisher_id);
     let mut publishers = PUBLISHERS.write().unwrap();
     publishers.remove(&publisher_id).is_some()
 }
@@ -581,13 +1070,29 @@ pub fn iroh_publish_push_video(publisher_id: String, frame: FlutterVideoFrame) -
     }
 }
 
-/// Push audio samples to publisher
+/// Push raw audio samples to publisher
+///
+/// The samples are encoded to Opus on the Rust side (requires the ffmpeg
+/// feature) and must be 48 kHz stereo, "pcm_s16le" or "pcm_f32le".
 #[frb(sync)]
 pub fn iroh_publish_push_audio(publisher_id: String, samples: FlutterAudioSamples) -> bool {
     let mut publishers = PUBLISHERS.write().unwrap();
     
     if let Some(state) = publishers.get_mut(&publisher_id) {
         if state.is_active {
+            let Some(pcm) = pcm_to_f32(&samples.data, &samples.format) else {
+                debug!("Unsupported audio sample format: {}", samples.format);
+                return false;
+            };
+            let sinks = PUBLISHER_SINKS.read().unwrap();
+            let Some(sink) = sinks.get(&publisher_id) else {
+                return false;
+            };
+            let format = AudioFormat::new(samples.sample_rate, samples.channels as u32);
+            if let Err(e) = sink.push_audio_samples(format, &pcm, samples.timestamp_ms) {
+                debug!("Dropped audio samples for {}: {}", publisher_id, e);
+                return false;
+            }
             state.bytes_sent += samples.data.len() as u64;
             true
         } else {
@@ -598,6 +1103,23 @@ pub fn iroh_publish_push_audio(publisher_id: String, samples: FlutterAudioSample
     }
 }
 
+/// Convert little-endian PCM bytes to f32 samples
+fn pcm_to_f32(data: &[u8], format: &str) -> Option<Vec<f32>> {
+    match format {
+        "pcm_s16le" => Some(
+            data.chunks_exact(2)
+                .map(|b| i16::from_le_bytes([b[0], b[1]]) as f32 / 32768.0)
+                .collect(),
+        ),
+        "pcm_f32le" => Some(
+            data.chunks_exact(4)
+                .map(|b| f32::from_le_bytes([b[0], b[1], b[2], b[3]]))
+                .collect(),
+        ),
+        _ => None,
+    }
+}
+
 /// Push an already-encoded video packet to publisher
 /// 
 /// Use this when encoding is done on the Flutter side (e.g., using FFmpegKit).
@@ -609,12 +1131,26 @@ pub fn iroh_publish_push_encoded_video(publisher_id: String, packet: FlutterEnco
     
     if let Some(state) = publishers.get_mut(&publisher_id) {
         if state.is_active {
+            let size = packet.data.len() as u64;
+            let sinks = PUBLISHER_SINKS.read().unwrap();
+            let Some(sink) = sinks.get(&publisher_id) else {
+                return false;
+            };
+            let packet = EncodedVideoPacket {
+                data: packet.data,
+                timestamp_ms: packet.timestamp_ms,
+                is_keyframe: packet.is_keyframe,
+                codec: packet.codec,
+                width: packet.width,
+                height: packet.height,
+            };
+            // A full queue drops the frame rather than blocking the caller
+            if let Err(e) = sink.try_push_encoded_video(packet) {
+                debug!("Dropped video packet for {}: {}", publisher_id, e);
+                return false;
+            }
             state.frames_published += 1;
-            state.bytes_sent += packet.data.len() as u64;
-            
-            // TODO: Send to LiveNode for actual network transmission
-            // For now, just count the bytes
-            
+            state.bytes_sent += size;
             true
         } else {
             false
@@ -631,7 +1167,23 @@ pub fn iroh_publish_push_encoded_audio(publisher_id: String, packet: FlutterEnco
     
     if let Some(state) = publishers.get_mut(&publisher_id) {
         if state.is_active {
-            state.bytes_sent += packet.data.len() as u64;
+            let size = packet.data.len() as u64;
+            let sinks = PUBLISHER_SINKS.read().unwrap();
+            let Some(sink) = sinks.get(&publisher_id) else {
+                return false;
+            };
+            let packet = EncodedAudioPacket {
+                data: packet.data,
+                timestamp_ms: packet.timestamp_ms,
+                codec: packet.codec,
+                sample_rate: packet.sample_rate,
+                channels: packet.channels,
+            };
+            if let Err(e) = sink.try_push_encoded_audio(packet) {
+                debug!("Dropped audio packet for {}: {}", publisher_id, e);
+                return false;
+            }
+            state.bytes_sent += size;
             true
         } else {
             false
@@ -659,6 +1211,39 @@ pub fn iroh_publish_get_status(publisher_id: String) -> Option<FlutterPublisherS
     })
 }
 
+/// Get per-rendition video counters, including frames dropped under congestion
+#[frb(sync)]
+pub fn iroh_publish_get_video_stats(publisher_id: String) -> Vec<FlutterRenditionStats> {
+    let sinks = PUBLISHER_SINKS.read().unwrap();
+    let Some(sink) = sinks.get(&publisher_id) else {
+        return Vec::new();
+    };
+
+    sink.video_stats().into_iter().map(|(quality, stats)| {
+        FlutterRenditionStats {
+            track_name: quality.track_name(),
+            frames_written: stats.frames_written,
+            bytes_written: stats.bytes_written,
+            frames_dropped: stats.frames_dropped,
+            groups_cut: stats.groups_cut,
+        }
+    }).collect()
+}
+
+/// Check whether the publisher wants a keyframe
+///
+/// Returns true once per request. Poll this from the encoder loop and
+/// force an IDR frame (e.g. MediaCodec `PARAMETER_KEY_REQUEST_SYNC_FRAME`)
+/// when it fires.
+#[frb(sync)]
+pub fn iroh_publish_take_keyframe_request(publisher_id: String) -> bool {
+    PUBLISHER_SINKS
+        .read()
+        .unwrap()
+        .get(&publisher_id)
+        .is_some_and(|sink| sink.take_keyframe_request())
+}
+
 /// Set video renditions for publisher
 #[frb(sync)]
 pub fn iroh_publish_set_video_renditions(publisher_id: String, renditions: Vec<String>) -> bool {
@@ -796,6 +1381,13 @@ pub async fn iroh_subscribe_connect_async(subscriber_id: String, ticket_string:
     node.connect_subscriber(&subscriber_id, &ticket)
         .await
         .map_err(|e| format!("Failed to connect: {}", e))?;
+
+    if let Some(switcher) = node.subscriber_switcher(&subscriber_id).await {
+        SUBSCRIBER_SWITCHERS.write().unwrap().insert(subscriber_id.clone(), switcher);
+    }
+    if let Some(abr) = node.subscriber_abr(&subscriber_id).await {
+        SUBSCRIBER_ABR.write().unwrap().insert(subscriber_id.clone(), abr);
+    }
     
     let mut subscribers = SUBSCRIBERS.write().unwrap();
     if let Some(state) = subscribers.get_mut(&subscriber_id) {
@@ -806,6 +1398,432 @@ pub async fn iroh_subscribe_connect_async(subscriber_id: String, ticket_string:
     Ok(())
 }
 
+// ============================================================================
+// Access Control API
+// ============================================================================
+
+/// Issue a ticket with a signed, expiring token for a publisher's broadcast
+///
+/// With `subject_endpoint_id` the ticket only works for that viewer.
+pub async fn iroh_publish_issue_ticket(
+    publisher_id: String,
+    ttl_secs: u64,
+    subject_endpoint_id: Option<String>,
+) -> Result<FlutterIssuedTicket, String> {
+    let subject = subject_endpoint_id
+        .map(|id| id.parse().map_err(|e| format!("Invalid endpoint ID: {}", e)))
+        .transpose()?;
+
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    let ticket = node.issue_ticket(&publisher_id, Duration::from_secs(ttl_secs), subject)
+        .await
+        .map_err(|e| format!("Failed to issue ticket: {}", e))?;
+    let token = ticket.token.as_ref().ok_or("Ticket has no token")?;
+    Ok(FlutterIssuedTicket {
+        token_id: token.id(),
+        expires_at: token.capability.expires_at,
+        ticket: ticket.serialize(),
+    })
+}
+
+/// Restrict who may watch a publisher's broadcast
+///
+/// Connected viewers that no longer qualify stop receiving it; their
+/// subscriptions end with an access denied error.
+pub async fn iroh_publish_set_access(publisher_id: String, policy: FlutterAccessPolicy) -> Result<(), String> {
+    let policy = BroadcastPolicy::try_from(policy)?;
+
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.set_broadcast_access(&publisher_id, policy)
+        .await
+        .map_err(|e| format!("Failed to set access policy: {}", e))
+}
+
+/// Revoke an issued ticket, ending the subscriptions that rely on it
+pub async fn iroh_publish_revoke_token(token_id: u64) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.revoke_token(token_id)
+        .await
+        .map_err(|e| format!("Failed to revoke token: {}", e))
+}
+
+// ============================================================================
+// Encryption API
+// ============================================================================
+
+/// Encrypt a publisher's broadcast end to end, before it starts
+///
+/// Returns a ticket carrying the media key; share it only with viewers.
+/// Relays forward the broadcast without being able to decode it.
+pub async fn iroh_publish_enable_encryption(publisher_id: String) -> Result<String, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.enable_encryption(&publisher_id)
+        .await
+        .map(|ticket| ticket.serialize())
+        .map_err(|e| format!("Failed to enable encryption: {}", e))
+}
+
+/// Strip the media key from a ticket, e.g. before handing it to a relay
+#[frb(sync)]
+pub fn iroh_ticket_without_media_key(ticket: String) -> Result<String, String> {
+    LiveTicket::deserialize(&ticket)
+        .map(|ticket| ticket.without_media_key().serialize())
+        .map_err(|e| format!("Invalid ticket: {}", e))
+}
+
+/// Add the media key from `key_ticket` to a relay's `ticket`
+#[frb(sync)]
+pub fn iroh_ticket_with_media_key(ticket: String, key_ticket: String) -> Result<String, String> {
+    let ticket = LiveTicket::deserialize(&ticket).map_err(|e| format!("Invalid ticket: {}", e))?;
+    let key = LiveTicket::deserialize(&key_ticket)
+        .map_err(|e| format!("Invalid key ticket: {}", e))?
+        .media_key
+        .ok_or("Key ticket has no media key")?;
+    Ok(ticket.with_media_key(key).serialize())
+}
+
+// ============================================================================
+// Viewer Roster API
+// ============================================================================
+
+/// Set the name publishers see for this node in their viewer rosters
+///
+/// Applies to connections opened after the call.
+pub async fn iroh_node_set_display_name(name: Option<String>) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+    node.set_display_name(name);
+    Ok(())
+}
+
+/// List the viewers connected to this node
+pub async fn iroh_roster_list() -> Result<Vec<FlutterViewer>, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.viewers()
+        .await
+        .map(|viewers| viewers.into_iter().map(FlutterViewer::from).collect())
+        .map_err(|e| format!("Failed to list viewers: {}", e))
+}
+
+/// Collect the viewer joins and leaves since the last call
+pub async fn iroh_roster_events() -> Vec<FlutterRosterEvent> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_roster_events().await.into_iter().map(FlutterRosterEvent::from).collect()
+}
+
+/// Disconnect a viewer; returns false if it wasn't connected
+///
+/// The viewer's subscription ends instead of reconnecting. To keep the
+/// peer out for good, also deny-list it with `iroh_publish_set_access`.
+pub async fn iroh_roster_kick(endpoint_id: String) -> Result<bool, String> {
+    let endpoint_id = endpoint_id.parse().map_err(|e| format!("Invalid endpoint ID: {}", e))?;
+
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.kick_viewer(endpoint_id)
+        .await
+        .map_err(|e| format!("Failed to kick viewer: {}", e))
+}
+
+// ============================================================================
+// Chat API
+// ============================================================================
+
+/// Chat content from Flutter arguments: a reaction when `reaction_to` is set
+fn chat_kind(text: String, reaction_to: Option<u64>) -> ChatKind {
+    match reaction_to {
+        Some(target) => ChatKind::Reaction { target, emoji: text },
+        None => ChatKind::Text(text),
+    }
+}
+
+/// Post to a publisher's chat, or react to message `reaction_to` with `text`
+pub async fn iroh_publish_send_chat(
+    publisher_id: String,
+    text: String,
+    reaction_to: Option<u64>,
+) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.send_publisher_chat(&publisher_id, chat_kind(text, reaction_to))
+        .await
+        .map_err(|e| format!("Failed to send chat message: {}", e))
+}
+
+/// Collect the messages on a publisher's chat since the last call
+///
+/// Call periodically; together the results form the chat stream,
+/// including the publisher's own messages.
+pub async fn iroh_publish_chat_messages(publisher_id: String) -> Vec<FlutterChatMessage> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_publisher_chat(&publisher_id).await.into_iter().map(FlutterChatMessage::from).collect()
+}
+
+/// Post to the chat of the broadcast a subscriber watches, or react to
+/// message `reaction_to` with `text`
+pub async fn iroh_subscribe_send_chat(
+    subscriber_id: String,
+    text: String,
+    reaction_to: Option<u64>,
+) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.send_subscriber_chat(&subscriber_id, chat_kind(text, reaction_to))
+        .await
+        .map_err(|e| format!("Failed to send chat message: {}", e))
+}
+
+/// Collect the messages on a subscriber's chat since the last call
+pub async fn iroh_subscribe_chat_messages(subscriber_id: String) -> Vec<FlutterChatMessage> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_subscriber_chat(&subscriber_id).await.into_iter().map(FlutterChatMessage::from).collect()
+}
+
+// ============================================================================
+// Room API
+// ============================================================================
+
+/// Host a call room on this node; returns the ticket members join with
+///
+/// Only holders of the ticket may join, for `ttl_secs`.
+pub async fn iroh_room_host(room: String, ttl_secs: u64) -> Result<String, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.host_room(&room, Duration::from_secs(ttl_secs))
+        .await
+        .map(|ticket| ticket.serialize())
+        .map_err(|e| format!("Failed to host room: {}", e))
+}
+
+/// Join a room from its ticket; returns the room name
+pub async fn iroh_room_join(ticket_string: String) -> Result<String, String> {
+    let ticket = LiveTicket::deserialize(&ticket_string)
+        .map_err(|e| format!("Invalid ticket: {}", e))?;
+
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.join_room(&ticket)
+        .await
+        .map_err(|e| format!("Failed to join room: {}", e))?;
+    Ok(ticket.broadcast_name)
+}
+
+/// Send an active publisher's broadcast to a room
+pub async fn iroh_room_publish(room: String, publisher_id: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.room_publish(&room, &publisher_id)
+        .await
+        .map_err(|e| format!("Failed to publish to room: {}", e))
+}
+
+/// Decode the video of members joining a room from now on
+///
+/// Takes the same pixel formats as `iroh_subscribe_set_decode`.
+pub async fn iroh_room_set_decode(room: String, pixel_format: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    let playback = if pixel_format.is_empty() {
+        None
+    } else {
+        let pixel_format = PixelFormat::from_name(&pixel_format)
+            .ok_or_else(|| format!("Unknown pixel format: {}", pixel_format))?;
+        Some(PlaybackConfig { pixel_format, viewport: None })
+    };
+
+    node.set_room_decoding(&room, playback)
+        .await
+        .map_err(|e| format!("Failed to set decoding: {}", e))
+}
+
+/// Endpoint IDs of the members of a room this node receives
+pub async fn iroh_room_members(room: String) -> Vec<String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.room_members(&room).await.iter().map(|m| m.to_string()).collect()
+}
+
+/// Collect a room's member joins and leaves since the last call
+///
+/// Call periodically: members are only subscribed as their joins are
+/// collected. Each joined member's video is read through the event's
+/// `subscriber_id` with the `iroh_subscribe_receive_*` functions.
+pub async fn iroh_room_events(room: String) -> Vec<FlutterRoomEvent> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_room_events(&room)
+        .await
+        .iter()
+        .map(|event| FlutterRoomEvent::new(&room, event))
+        .collect()
+}
+
+/// Leave a room; leaving a room this node hosts ends it
+pub async fn iroh_room_leave(room: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.leave_room(&room)
+        .await
+        .map_err(|e| format!("Failed to leave room: {}", e))
+}
+
+// ============================================================================
+// Discovery API
+// ============================================================================
+
+/// Ticket for this node rather than one broadcast, for others to discover
+/// what it streams
+pub async fn iroh_node_get_ticket() -> Result<String, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+    Ok(node.node_ticket().serialize())
+}
+
+/// Start following the broadcasts of the node in `ticket_string`
+///
+/// Any ticket for the node works; its broadcast name is ignored. An empty
+/// `prefix` follows every broadcast the node offers.
+pub async fn iroh_discovery_start(
+    discovery_id: String,
+    ticket_string: String,
+    prefix: String,
+) -> Result<(), String> {
+    let ticket = LiveTicket::deserialize(&ticket_string)
+        .map_err(|e| format!("Invalid ticket: {}", e))?;
+
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.start_discovery(&discovery_id, &ticket, &prefix)
+        .await
+        .map_err(|e| format!("Failed to start discovery: {}", e))
+}
+
+/// Broadcasts the node currently offers, sorted by name
+pub async fn iroh_discovery_list(discovery_id: String) -> Result<Vec<String>, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.discovered_broadcasts(&discovery_id)
+        .await
+        .ok_or_else(|| format!("Discovery not found: {}", discovery_id))
+}
+
+/// Collect the broadcasts added and removed since the last call
+pub async fn iroh_discovery_events(discovery_id: String) -> Vec<FlutterDiscoveryEvent> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_discovery_events(&discovery_id)
+        .await
+        .into_iter()
+        .map(FlutterDiscoveryEvent::from)
+        .collect()
+}
+
+/// Stop following a node's broadcasts
+pub async fn iroh_discovery_stop(discovery_id: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.stop_discovery(&discovery_id)
+        .await
+        .map_err(|e| format!("Failed to stop discovery: {}", e))
+}
+
+/// Ticket for a discovered broadcast, from the ticket used to discover it
+///
+/// Keeps the node address and token; connect a subscriber with the result.
+#[frb(sync)]
+pub fn iroh_ticket_for_broadcast(ticket: String, broadcast_name: String) -> Result<String, String> {
+    let ticket = LiveTicket::deserialize(&ticket).map_err(|e| format!("Invalid ticket: {}", e))?;
+    Ok(ticket.for_broadcast(broadcast_name).serialize())
+}
+
+// ============================================================================
+// Relay API
+// ============================================================================
+
+/// Relay a broadcast from its publisher (or another relay) to viewers of this node
+///
+/// Returns a ticket viewers can use to watch through this node. Fails if
+/// relaying would create a loop between relays.
+pub async fn iroh_relay_start(ticket_string: String) -> Result<String, String> {
+    let ticket = LiveTicket::deserialize(&ticket_string)
+        .map_err(|e| format!("Invalid ticket: {}", e))?;
+
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.start_relay(&ticket)
+        .await
+        .map(|ticket| ticket.serialize())
+        .map_err(|e| format!("Failed to relay: {}", e))
+}
+
+/// Stop relaying a broadcast
+pub async fn iroh_relay_stop(broadcast_name: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.stop_relay(&broadcast_name)
+        .await
+        .map_err(|e| format!("Failed to stop relay: {}", e))
+}
+
+/// List the broadcasts relayed through this node
+pub async fn iroh_relay_list() -> Vec<FlutterRelayInfo> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.relays().await.into_iter().map(|relay| {
+        FlutterRelayInfo {
+            broadcast_name: relay.name().to_string(),
+            route: relay.route().iter().map(|id| id.to_string()).collect(),
+        }
+    }).collect()
+}
+
 /// Connect subscriber to broadcast (sync version)
 #[frb(sync)]
 pub fn iroh_subscribe_connect(subscriber_id: String) -> bool {
@@ -824,6 +1842,9 @@ pub async fn iroh_subscribe_disconnect_async(subscriber_id: String) -> Result<()
     let node_guard = LIVE_NODE.lock().await;
     let node = node_guard.as_ref().ok_or("Node not initialized")?;
     
+    SUBSCRIBER_SWITCHERS.write().unwrap().remove(&subscriber_id);
+    SUBSCRIBER_ABR.write().unwrap().remove(&subscriber_id);
+
     node.disconnect_subscriber(&subscriber_id)
         .await
         .map_err(|e| format!("Failed to disconnect: {}", e))?;
@@ -852,23 +1873,116 @@ pub fn iroh_subscribe_disconnect(subscriber_id: String) -> bool {
 /// Remove a subscriber
 #[frb(sync)]
 pub fn iroh_subscribe_remove(subscriber_id: String) -> bool {
+    SUBSCRIBER_SWITCHERS.write().unwrap().remove(&subscriber_id);
+    if let Some(abr) = SUBSCRIBER_ABR.write().unwrap().remove(&subscriber_id) {
+        abr.stop();
+    }
     let mut subscribers = SUBSCRIBERS.write().unwrap();
     subscribers.remove(&subscriber_id).is_some()
 }
 
 /// Set quality preference for subscriber
+///
+/// `quality` is a track suffix ("low", "med", "high"), a preset name
+/// ("P720") or "auto". A connected subscriber switches rendition at the next
+/// keyframe; returns false if the broadcast has no such rendition. "auto"
+/// lets the ABR controller pick; anything else pauses it.
 #[frb(sync)]
 pub fn iroh_subscribe_set_quality(subscriber_id: String, quality: String) -> bool {
     let mut subscribers = SUBSCRIBERS.write().unwrap();
     
     if let Some(state) = subscribers.get_mut(&subscriber_id) {
-        state.current_quality = quality;
+        let suffix = VideoPreset::from_name(&quality)
+            .map(|preset| preset.video_quality().suffix().to_string())
+            .unwrap_or(quality);
+        if suffix != "auto" {
+            if let Some(switcher) = SUBSCRIBER_SWITCHERS.read().unwrap().get(&subscriber_id) {
+                if let Err(e) = switcher.switch_video(&suffix) {
+                    debug!("Quality switch failed for {}: {}", subscriber_id, e);
+                    return false;
+                }
+            }
+        }
+        if let Some(abr) = SUBSCRIBER_ABR.read().unwrap().get(&subscriber_id) {
+            abr.set_enabled(suffix == "auto");
+        }
+        state.current_quality = suffix;
         true
     } else {
         false
     }
 }
 
+/// Record a connected subscriber's broadcast to a fragmented MP4 file
+///
+/// The recording follows quality switches; each switch continues in a new
+/// file next to `path` (`rec.mp4`, `rec.1.mp4`, ...).
+pub async fn iroh_subscribe_record_start(subscriber_id: String, path: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.start_subscriber_recording(&subscriber_id, &path)
+        .await
+        .map_err(|e| format!("Failed to start recording: {}", e))
+}
+
+/// Stop recording a subscriber's broadcast
+pub async fn iroh_subscribe_record_stop(subscriber_id: String) -> Result<FlutterRecordingStats, String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    node.stop_subscriber_recording(&subscriber_id)
+        .await
+        .map(FlutterRecordingStats::from)
+        .map_err(|e| format!("Failed to stop recording: {}", e))
+}
+
+/// Collect the adaptive bitrate decisions made since the last call
+pub async fn iroh_subscribe_abr_events(subscriber_id: String) -> Vec<FlutterAbrEvent> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_abr_events(&subscriber_id).await.into_iter().map(|event| {
+        FlutterAbrEvent {
+            from_track: event.from,
+            to_track: event.to,
+            reason: event.reason.as_str().to_string(),
+            throughput_bps: event.sample.throughput_bps,
+            rtt_ms: event.sample.rtt.as_millis() as u32,
+            loss_percent: (event.sample.loss_rate * 100.0) as f32,
+            late_frames: event.sample.late_frames,
+        }
+    }).collect()
+}
+
+/// Where a subscriber's connection to its publisher stands
+///
+/// None for subscribers not connected over MoQ.
+pub async fn iroh_subscribe_connection_state(subscriber_id: String) -> Option<FlutterConnectionState> {
+    let node_guard = LIVE_NODE.lock().await;
+    let state = node_guard.as_ref()?.subscriber_connection_state(&subscriber_id).await?;
+    Some(state.into())
+}
+
+/// Collect the connection state changes since the last call
+///
+/// Subscribers reconnect by themselves when the connection drops; "ended"
+/// is final, with `error` unset when the publisher ended the broadcast.
+pub async fn iroh_subscribe_connection_events(subscriber_id: String) -> Vec<FlutterConnectionState> {
+    let node_guard = LIVE_NODE.lock().await;
+    let Some(node) = node_guard.as_ref() else {
+        return Vec::new();
+    };
+
+    node.take_connection_events(&subscriber_id)
+        .await
+        .into_iter()
+        .map(FlutterConnectionState::from)
+        .collect()
+}
+
 /// Get subscriber status
 #[frb(sync)]
 pub fn iroh_subscribe_get_status(subscriber_id: String) -> Option<FlutterSubscriberStatus> {
@@ -941,70 +2055,247 @@ pub async fn iroh_subscribe_receive_frame(subscriber_id: String) -> Option<Flutt
     })
 }
 
+/// Decode a subscriber's video into raw frames (call before connecting)
+///
+/// `pixel_format` is "rgba", "bgra", "i420" or "nv12"; an empty string turns
+/// decoding off. Decoded frames are read with
+/// `iroh_subscribe_receive_decoded_frame`. Requires the ffmpeg feature.
+pub async fn iroh_subscribe_set_decode(subscriber_id: String, pixel_format: String) -> Result<(), String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref().ok_or("Node not initialized")?;
+
+    let playback = if pixel_format.is_empty() {
+        None
+    } else {
+        let pixel_format = PixelFormat::from_name(&pixel_format)
+            .ok_or_else(|| format!("Unknown pixel format: {}", pixel_format))?;
+        Some(PlaybackConfig { pixel_format, viewport: None })
+    };
+
+    node.set_subscriber_decoding(&subscriber_id, playback)
+        .await
+        .map_err(|e| format!("Failed to set decoding: {}", e))
+}
+
+/// Receive a decoded video frame from a subscriber (non-blocking)
+/// Returns None if no frame is available
+pub async fn iroh_subscribe_receive_decoded_frame(subscriber_id: String) -> Option<FlutterVideoFrame> {
+    let node_guard = LIVE_NODE.lock().await;
+    let node = node_guard.as_ref()?;
+
+    let frame = node.receive_decoded_frame(&subscriber_id).await?;
+
+    // Update local stats
+    {
+        let mut subscribers = SUBSCRIBERS.write().unwrap();
+        if let Some(state) = subscribers.get_mut(&subscriber_id) {
+            state.frames_received += 1;
+            state.bytes_received += frame.data.len() as u64;
+        }
+    }
+
+    Some(FlutterVideoFrame {
+        width: frame.width,
+        height: frame.height,
+        timestamp_ms: frame.timestamp.as_millis() as u64,
+        format: frame.pixel_format.name().to_string(),
+        data: frame.data,
+    })
+}
+
 // ============================================================================
 // Catalog API
 // ============================================================================
 
+/// Catalog rendition for a Flutter video preset name ("P720")
+fn video_rendition(name: &str) -> (VideoQuality, VideoConfig) {
+    match VideoPreset::from_name(name) {
+        Some(preset) => (preset.video_quality(), preset.video_config()),
+        None => (VideoQuality::Medium, VideoQuality::Medium.catalog_config()),
+    }
+}
+
+/// Catalog rendition for a Flutter audio preset name ("opus_hq")
+fn audio_rendition(name: &str) -> (AudioQuality, AudioConfig) {
+    let (quality, codec, sample_rate, channel_count, bitrate) = match name {
+        "opus_lq" => (AudioQuality::Low, AudioCodec::Opus, 24_000, 1, 24_000),
+        "opus_hq" => (AudioQuality::High, AudioCodec::Opus, 48_000, 2, 128_000),
+        "aac_hq" => (AudioQuality::High, AudioCodec::AAC(AAC { profile: 2 }), 44_100, 2, 192_000),
+        _ => return (AudioQuality::Medium, AudioQuality::Medium.catalog_config()),
+    };
+    let config = AudioConfig {
+        codec,
+        sample_rate,
+        channel_count,
+        bitrate: Some(bitrate),
+        description: None,
+    };
+    (quality, config)
+}
+
+/// Convert a hang catalog into its Flutter representation
+fn catalog_to_flutter(broadcast_id: String, catalog: &Catalog) -> FlutterBroadcastCatalog {
+    let hex = |bytes: &[u8]| data_encoding::HEXLOWER.encode(bytes);
+
+    let mut video_tracks: Vec<FlutterTrackInfo> = catalog.video.iter()
+        .flat_map(|video| video.renditions.iter())
+        .map(|(track, config)| {
+            let mut extra = HashMap::new();
+            if let Some(width) = config.coded_width {
+                extra.insert("width".to_string(), width.to_string());
+            }
+            if let Some(height) = config.coded_height {
+                extra.insert("height".to_string(), height.to_string());
+            }
+            if let Some(framerate) = config.framerate {
+                extra.insert("framerate".to_string(), framerate.to_string());
+            }
+            if let Some(description) = &config.description {
+                extra.insert("description".to_string(), hex(description));
+            }
+            FlutterTrackInfo {
+                track_id: track.clone(),
+                name: track.clone(),
+                codec: config.codec.to_string(),
+                bitrate: config.bitrate.unwrap_or(0) as u32,
+                extra,
+            }
+        })
+        .collect();
+    video_tracks.sort_by(|a, b| a.track_id.cmp(&b.track_id));
+
+    let mut audio_tracks: Vec<FlutterTrackInfo> = catalog.audio.iter()
+        .flat_map(|audio| audio.renditions.iter())
+        .map(|(track, config)| {
+            let mut extra = HashMap::new();
+            extra.insert("sample_rate".to_string(), config.sample_rate.to_string());
+            extra.insert("channels".to_string(), config.channel_count.to_string());
+            if let Some(description) = &config.description {
+                extra.insert("description".to_string(), hex(description));
+            }
+            FlutterTrackInfo {
+                track_id: track.clone(),
+                name: track.clone(),
+                codec: config.codec.to_string(),
+                bitrate: config.bitrate.unwrap_or(0) as u32,
+                extra,
+            }
+        })
+        .collect();
+    audio_tracks.sort_by(|a, b| a.track_id.cmp(&b.track_id));
+
+    FlutterBroadcastCatalog {
+        broadcast_id,
+        video_tracks,
+        audio_tracks,
+        created_at_ms: std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap_or_default()
+            .as_millis() as u64,
+    }
+}
+
+/// Rebuild a hang catalog from its Flutter representation
+fn catalog_from_flutter(catalog: &FlutterBroadcastCatalog) -> Catalog {
+    fn extra<T: std::str::FromStr>(track: &FlutterTrackInfo, key: &str) -> Option<T> {
+        track.extra.get(key).and_then(|v| v.parse().ok())
+    }
+    let description = |track: &FlutterTrackInfo| {
+        track.extra.get("description")
+            .and_then(|v| data_encoding::HEXLOWER.decode(v.as_bytes()).ok())
+            .map(Into::into)
+    };
+
+    let mut result = catalog_for(&[], &[]);
+    if !catalog.video_tracks.is_empty() {
+        let mut video = catalog_for(&[VideoQuality::Medium], &[]).video.unwrap();
+        video.renditions = catalog.video_tracks.iter()
+            .map(|track| {
+                let mut config = VideoQuality::Medium.catalog_config();
+                if let Ok(codec) = track.codec.parse() {
+                    config.codec = codec;
+                }
+                config.description = description(track);
+                config.coded_width = extra(track, "width");
+                config.coded_height = extra(track, "height");
+                config.framerate = extra(track, "framerate");
+                config.bitrate = Some(track.bitrate as u64);
+                (track.track_id.clone(), config)
+            })
+            .collect();
+        result.video = Some(video);
+    }
+    if !catalog.audio_tracks.is_empty() {
+        let mut audio = catalog_for(&[], &[AudioQuality::Medium]).audio.unwrap();
+        audio.renditions = catalog.audio_tracks.iter()
+            .map(|track| {
+                let mut config = AudioQuality::Medium.catalog_config();
+                if let Ok(codec) = track.codec.parse() {
+                    config.codec = codec;
+                }
+                config.description = description(track);
+                config.sample_rate = extra(track, "sample_rate").unwrap_or(config.sample_rate);
+                config.channel_count = extra(track, "channels").unwrap_or(config.channel_count);
+                config.bitrate = Some(track.bitrate as u64);
+                (track.track_id.clone(), config)
+            })
+            .collect();
+        result.audio = Some(audio);
+    }
+    result
+}
+
 /// Create a broadcast catalog
+///
+/// Builds the same hang catalog a publisher announces for these renditions.
+/// Presets that share a MoQ track (e.g. P180 and P360) collapse into one entry.
 #[frb(sync)]
 pub fn iroh_catalog_create(
     broadcast_id: String,
     video_renditions: Vec<String>,
     audio_renditions: Vec<String>,
 ) -> FlutterBroadcastCatalog {
-    let video_tracks: Vec<FlutterTrackInfo> = video_renditions.iter().map(|r| {
-        FlutterTrackInfo {
-            track_id: format!("video_{}", r),
-            name: r.clone(),
-            codec: "h264".to_string(),
-            bitrate: match r.as_str() {
-                "P180" => 150_000,
-                "P360" => 500_000,
-                "P720" => 2_000_000,
-                "P1080" => 4_500_000,
-                _ => 1_000_000,
-            },
-            extra: HashMap::new(),
+    let video: Vec<_> = video_renditions.iter().map(|r| video_rendition(r)).collect();
+    let audio: Vec<_> = audio_renditions.iter().map(|r| audio_rendition(r)).collect();
+
+    let video_qualities: Vec<_> = video.iter().map(|(q, _)| *q).collect();
+    let audio_qualities: Vec<_> = audio.iter().map(|(q, _)| *q).collect();
+    let mut catalog = catalog_for(&video_qualities, &audio_qualities);
+
+    if let Some(catalog_video) = catalog.video.as_mut() {
+        for (quality, config) in video {
+            catalog_video.renditions.insert(quality.track_name(), config);
         }
-    }).collect();
-    
-    let audio_tracks: Vec<FlutterTrackInfo> = audio_renditions.iter().map(|r| {
-        FlutterTrackInfo {
-            track_id: format!("audio_{}", r),
-            name: r.clone(),
-            codec: if r.contains("opus") { "opus" } else { "aac" }.to_string(),
-            bitrate: match r.as_str() {
-                "opus_lq" => 24_000,
-                "opus_hq" => 128_000,
-                "aac_hq" => 192_000,
-                _ => 64_000,
-            },
-            extra: HashMap::new(),
+    }
+    if let Some(catalog_audio) = catalog.audio.as_mut() {
+        for (quality, config) in audio {
+            catalog_audio.renditions.insert(quality.track_name(), config);
         }
-    }).collect();
-    
-    FlutterBroadcastCatalog {
-        broadcast_id,
-        video_tracks,
-        audio_tracks,
-        created_at_ms: std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64,
     }
+
+    catalog_to_flutter(broadcast_id, &catalog)
 }
 
 /// Serialize catalog to JSON
+///
+/// Produces the hang `catalog.json` format published on the catalog track.
 #[frb(sync)]
 pub fn iroh_catalog_to_json(catalog: FlutterBroadcastCatalog) -> String {
-    // Simple JSON serialization without serde
-    format!(
-        r#"{{"broadcast_id":"{}","video_tracks_count":{},"audio_tracks_count":{},"created_at_ms":{}}}"#,
-        catalog.broadcast_id,
-        catalog.video_tracks.len(),
-        catalog.audio_tracks.len(),
-        catalog.created_at_ms
-    )
+    catalog_from_flutter(&catalog).to_string().unwrap_or_default()
+}
+
+/// Get the catalog an active publisher is announcing, as JSON
+pub async fn iroh_publish_get_catalog_json(publisher_id: String) -> Option<String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let catalog = node_guard.as_ref()?.get_publisher_catalog(&publisher_id).await?;
+    catalog.to_string().ok()
+}
+
+/// Get the catalog received by a connected subscriber, as JSON
+pub async fn iroh_subscribe_get_catalog_json(subscriber_id: String) -> Option<String> {
+    let node_guard = LIVE_NODE.lock().await;
+    let catalog = node_guard.as_ref()?.get_subscriber_catalog(&subscriber_id).await?;
+    catalog.to_string().ok()
 }
 
 // ============================================================================
@@ -5,36 +5,168 @@
 //! - Managing active broadcast sessions
 //! - Publishing broadcasts to connected peers
 //! - Subscribing to remote broadcasts
+//!
+//! Broadcasts published here are either produced locally or relayed from
+//! another node (see [`super::relay`]); each keeps the route it took to reach
+//! this node so relays can refuse to form loops.
+//!
+//! Every session starts with a handshake on the connection's first stream:
+//! the connecting peer sends an optional capability token, and the accepting
+//! side checks it before the MoQ session starts. Broadcasts are only
+//! announced to peers the [`AccessControl`] admits, and are withdrawn from
+//! peers that later lose access (policy change, revocation, expiry). Peers
+//! still on [`LEGACY_ALPN`] skip the handshake and count as peers without a
+//! token.
+//!
+//! The handshake also carries the peer's display name. Accepted sessions
+//! make up the viewer roster (see [`super::roster`]), which the publisher
+//! can list, follow and kick viewers from.
+//!
+//! Viewers post to a broadcast's chat by publishing back over their session
+//! (see [`super::chat`]). The actor forwards posts from viewers admitted to
+//! the broadcast, rate limited per viewer endpoint across all its sessions,
+//! to the inbox opened with [`Live::open_chat`].
+//!
+//! Sessions are symmetric: either end may announce broadcasts to the other.
+//! On sessions this node opened, read them with [`LiveSession::subscribe`]
+//! or [`LiveSession::announced`]; on sessions it accepted, they arrive as
+//! [`PeerEvent`]s. Rooms (see [`super::room`]) build calls on this.
 
-use std::collections::HashMap;
-use std::sync::Arc;
+use std::collections::{HashMap, HashSet};
+use std::sync::{Arc, RwLock};
+use std::time::Instant;
 
 use anyhow::{Context, Result};
 use iroh::{Endpoint, EndpointAddr, EndpointId};
-use iroh::endpoint::Connection;
+use iroh::endpoint::{Connection, ConnectionError, TransportErrorCode};
 use iroh::protocol::ProtocolHandler;
 use moq_lite::{BroadcastConsumer, BroadcastProducer, Origin, OriginConsumer, OriginProducer};
 use n0_future::task::AbortOnDropHandle;
-use tokio::sync::mpsc;
+use n0_future::time::Duration;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc, oneshot};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, error, info, instrument, warn, Instrument, error_span};
 
+use super::access::{AccessControl, BroadcastPolicy, SignedToken};
+use super::chat::{self, ChatInbox, ChatPost, ChatRate, ChatSender, InboundPost, RateLimiter};
+use super::roster::{self, Presence, RosterEvent, SendBacklog, Viewer};
 use super::webtransport::Session;
 
 /// ALPN protocol identifier for iroh-live
-pub const ALPN: &[u8] = b"iroh-live/1";
+///
+/// Version 2 added the token handshake before the MoQ session.
+pub const ALPN: &[u8] = b"iroh-live/2";
+
+/// ALPN of version 1 peers, which start MoQ without the handshake
+///
+/// Accepted alongside [`ALPN`] and dialed when a peer turns [`ALPN`] down,
+/// so older peers still interoperate. They cannot present a token, so they
+/// only see broadcasts open to everyone.
+pub const LEGACY_ALPN: &[u8] = b"iroh-live/1";
+
+/// TLS alert a peer sends when it speaks none of the offered ALPNs
+const NO_APPLICATION_PROTOCOL: u8 = 120;
+
+/// Close code and reason of a kicked viewer's connection
+///
+/// The code alone is ambiguous: moq-lite closes with small codes too.
+const KICK_CODE: u32 = 2;
+const KICK_REASON: &[u8] = b"kicked";
+
+/// Upper bound on handshake messages
+const MAX_HANDSHAKE_MESSAGE: usize = 16 * 1024;
+
+/// How often sessions are re-checked for expired tokens
+const ACCESS_RECHECK_INTERVAL: Duration = Duration::from_secs(5);
+
+/// Roster events buffered for slow listeners
+const ROSTER_EVENT_CAPACITY: usize = 64;
+
+/// Viewers' chat posts queued for the actor
+const CHAT_POST_CAPACITY: usize = 64;
+
+/// Peer announcements buffered for slow listeners
+const PEER_EVENT_CAPACITY: usize = 64;
+
+/// First message of a session, from the connecting peer
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SessionHello {
+    /// Capability token for restricted broadcasts
+    pub token: Option<SignedToken>,
+    /// Name shown in the publisher's viewer roster
+    pub display_name: Option<String>,
+}
+
+/// A broadcast announced to this node by a peer that connected to it
+#[derive(Clone)]
+pub enum PeerEvent {
+    Announced {
+        remote: EndpointId,
+        name: BroadcastName,
+        broadcast: BroadcastConsumer,
+    },
+    /// The peer withdrew the broadcast or disconnected
+    Unannounced {
+        remote: EndpointId,
+        name: BroadcastName,
+    },
+}
+
+impl std::fmt::Debug for PeerEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Announced { remote, name, .. } => f
+                .debug_struct("Announced")
+                .field("remote", &remote.fmt_short().to_string())
+                .field("name", name)
+                .finish_non_exhaustive(),
+            Self::Unannounced { remote, name } => f
+                .debug_struct("Unannounced")
+                .field("remote", &remote.fmt_short().to_string())
+                .field("name", name)
+                .finish(),
+        }
+    }
+}
+
+/// Reply to [`SessionHello`]
+#[derive(Debug, Serialize, Deserialize)]
+struct SessionWelcome {
+    /// Why the session was refused, if it was
+    rejected: Option<String>,
+}
 
 /// Broadcast name type alias
 type BroadcastName = String;
 
+/// Nodes a broadcast passed through before reaching this one, origin first
+///
+/// Empty for broadcasts produced locally.
+pub type Route = Vec<EndpointId>;
+
 /// Messages sent to the Live actor
 enum ActorMessage {
     /// Handle a new incoming session
     HandleSession(LiveSession),
     /// Publish a broadcast
-    PublishBroadcast(BroadcastName, BroadcastProducer),
+    PublishBroadcast(BroadcastName, BroadcastConsumer, Route, SendBacklog),
     /// Remove a broadcast
     RemoveBroadcast(BroadcastName),
+    /// Remove a broadcast if it is still relayed from this upstream
+    RemoveRelayed(BroadcastName, EndpointId),
+    /// Look up the route of a published broadcast
+    Route(BroadcastName, oneshot::Sender<Option<Route>>),
+    /// Access rules changed; re-check every session
+    Reauthorize,
+    /// List connected viewers
+    Roster(oneshot::Sender<Vec<Viewer>>),
+    /// Close a viewer's session; replies whether it was connected
+    Kick(EndpointId, oneshot::Sender<bool>),
+    /// Whether a connected peer may receive a broadcast
+    Admits(EndpointId, BroadcastName, oneshot::Sender<bool>),
+    /// Accept viewers' chat posts for a broadcast
+    OpenChat(BroadcastName, ChatInbox, ChatRate),
 }
 
 /// Live streaming coordinator
@@ -46,6 +178,11 @@ enum ActorMessage {
 #[derive(Debug, Clone)]
 pub struct Live {
     endpoint: Endpoint,
+    access: AccessControl,
+    /// Sent in the handshake of sessions this node opens
+    display_name: Arc<RwLock<Option<String>>>,
+    roster_events: broadcast::Sender<RosterEvent>,
+    peer_events: broadcast::Sender<PeerEvent>,
     tx: mpsc::Sender<ActorMessage>,
     shutdown_token: CancellationToken,
     _actor_handle: Arc<AbortOnDropHandle<()>>,
@@ -55,7 +192,10 @@ impl Live {
     /// Create a new Live instance with the given endpoint
     pub fn new(endpoint: Endpoint) -> Self {
         let (tx, rx) = mpsc::channel(16);
-        let actor = Actor::default();
+        let access = AccessControl::new(endpoint.id());
+        let (roster_events, _) = broadcast::channel(ROSTER_EVENT_CAPACITY);
+        let (peer_events, _) = broadcast::channel(PEER_EVENT_CAPACITY);
+        let actor = Actor::new(access.clone(), roster_events.clone(), peer_events.clone());
         let shutdown_token = actor.shutdown_token.clone();
         
         let actor_task = tokio::spawn(async move {
@@ -64,6 +204,10 @@ impl Live {
         
         Self {
             endpoint,
+            access,
+            display_name: Arc::default(),
+            roster_events,
+            peer_events,
             tx,
             shutdown_token,
             _actor_handle: Arc::new(AbortOnDropHandle::new(actor_task)),
@@ -74,33 +218,176 @@ impl Live {
     pub fn protocol_handler(&self) -> LiveProtocolHandler {
         LiveProtocolHandler {
             tx: self.tx.clone(),
+            access: self.access.clone(),
         }
     }
 
+    /// Access rules for broadcasts published here
+    pub fn access(&self) -> &AccessControl {
+        &self.access
+    }
+
+    /// Restrict who may receive a broadcast
+    ///
+    /// The broadcast is withdrawn from sessions that already received it
+    /// and are no longer admitted.
+    pub async fn set_access_policy(&self, name: impl ToString, policy: BroadcastPolicy) -> Result<()> {
+        self.access.set_policy(name, policy);
+        self.send(ActorMessage::Reauthorize).await
+    }
+
+    /// Open a broadcast to everyone again
+    pub async fn clear_access_policy(&self, name: &str) -> Result<()> {
+        self.access.clear_policy(name);
+        self.send(ActorMessage::Reauthorize).await
+    }
+
+    /// Revoke a token, withdrawing the broadcasts it granted from the
+    /// sessions that relied on it
+    pub async fn revoke_token(&self, token_id: u64) -> Result<()> {
+        self.access.revoke(token_id);
+        self.send(ActorMessage::Reauthorize).await
+    }
+
     /// Publish a broadcast
     /// 
     /// The broadcast will be announced to all connected peers
     /// and available for subscription.
     pub async fn publish(&self, name: impl ToString, producer: BroadcastProducer) -> Result<()> {
+        self.publish_tracked(name, producer, SendBacklog::default()).await
+    }
+
+    /// Publish a broadcast, reporting into `backlog` how far behind its
+    /// viewers are
+    ///
+    /// Pass [`super::publish_broadcast::PublishBroadcast::send_backlog`] so
+    /// the publisher sheds load when viewers can't keep up.
+    pub async fn publish_tracked(
+        &self,
+        name: impl ToString,
+        producer: BroadcastProducer,
+        backlog: SendBacklog,
+    ) -> Result<()> {
+        self.send(ActorMessage::PublishBroadcast(name.to_string(), producer.consume(), Route::new(), backlog))
+            .await
+    }
+
+    /// Publish a broadcast this node produces through a consumer, e.g. a
+    /// publisher's own view of it
+    pub async fn publish_consumer(&self, name: impl ToString, broadcast: BroadcastConsumer) -> Result<()> {
+        self.send(ActorMessage::PublishBroadcast(name.to_string(), broadcast, Route::new(), SendBacklog::default()))
+            .await
+    }
+
+    /// Publish a broadcast received from another node
+    ///
+    /// `route` is the path it took to get here, ending with the upstream
+    /// node it was subscribed from.
+    pub async fn publish_relayed(&self, name: impl ToString, broadcast: BroadcastConsumer, route: Route) -> Result<()> {
+        self.send(ActorMessage::PublishBroadcast(name.to_string(), broadcast, route, SendBacklog::default()))
+            .await
+    }
+
+    /// Remove a relayed broadcast, unless it has since been replaced by one
+    /// from a different upstream
+    pub async fn unpublish_relayed(&self, name: impl ToString, upstream: EndpointId) -> Result<()> {
+        self.send(ActorMessage::RemoveRelayed(name.to_string(), upstream))
+            .await
+    }
+
+    /// Route of a broadcast published on this node, if it is published
+    pub async fn route(&self, name: impl ToString) -> Result<Option<Route>> {
+        let (tx, rx) = oneshot::channel();
+        self.send(ActorMessage::Route(name.to_string(), tx)).await?;
+        rx.await.map_err(|_| anyhow::anyhow!("live actor died"))
+    }
+
+    async fn send(&self, msg: ActorMessage) -> Result<()> {
         self.tx
-            .send(ActorMessage::PublishBroadcast(name.to_string(), producer))
+            .send(msg)
             .await
-            .map_err(|_| anyhow::anyhow!("live actor died"))?;
-        Ok(())
+            .map_err(|_| anyhow::anyhow!("live actor died"))
     }
 
     /// Remove a published broadcast
     pub async fn unpublish(&self, name: impl ToString) -> Result<()> {
-        self.tx
-            .send(ActorMessage::RemoveBroadcast(name.to_string()))
-            .await
-            .map_err(|_| anyhow::anyhow!("live actor died"))?;
-        Ok(())
+        self.send(ActorMessage::RemoveBroadcast(name.to_string())).await
+    }
+
+    /// Name to show in the rosters of publishers this node watches
+    pub fn set_display_name(&self, name: Option<String>) {
+        *self.display_name.write().unwrap() = name;
+    }
+
+    /// Handshake for a session opened by this node
+    pub fn hello(&self, token: Option<&SignedToken>) -> SessionHello {
+        SessionHello {
+            token: token.cloned(),
+            display_name: self.display_name.read().unwrap().clone(),
+        }
+    }
+
+    /// Whether `remote` may receive `name`, with the token of any session
+    /// it has open to this node
+    ///
+    /// False if it has no session open.
+    pub async fn admits(&self, remote: EndpointId, name: impl ToString) -> Result<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.send(ActorMessage::Admits(remote, name.to_string(), tx)).await?;
+        rx.await.map_err(|_| anyhow::anyhow!("live actor died"))
+    }
+
+    /// Viewers connected to this node
+    pub async fn roster(&self) -> Result<Vec<Viewer>> {
+        let (tx, rx) = oneshot::channel();
+        self.send(ActorMessage::Roster(tx)).await?;
+        rx.await.map_err(|_| anyhow::anyhow!("live actor died"))
+    }
+
+    /// Follow viewers joining and leaving
+    pub fn roster_events(&self) -> broadcast::Receiver<RosterEvent> {
+        self.roster_events.subscribe()
+    }
+
+    /// Follow broadcasts that connected peers announce back to this node
+    pub fn peer_events(&self) -> broadcast::Receiver<PeerEvent> {
+        self.peer_events.subscribe()
+    }
+
+    /// Accept viewers' chat posts for a broadcast published on this node
+    ///
+    /// Posts from viewers admitted to the broadcast go to `inbox`, at most
+    /// as often as `rate` allows per viewer endpoint, however many sessions
+    /// it opens; the rest are dropped. The chat closes when the broadcast is
+    /// unpublished.
+    pub async fn open_chat(&self, name: impl ToString, inbox: ChatInbox, rate: ChatRate) -> Result<()> {
+        self.send(ActorMessage::OpenChat(name.to_string(), inbox, rate)).await
+    }
+
+    /// Disconnect a viewer, returning false if it wasn't connected
+    ///
+    /// The viewer can tell it was kicked (see [`LiveSession::kicked`]), and
+    /// a [`BroadcastLink`](super::reconnect::BroadcastLink) ends instead of
+    /// reconnecting. A peer that dials again anyway is let back in; deny-list
+    /// it with [`Live::set_access_policy`] to keep it out.
+    pub async fn kick(&self, viewer: EndpointId) -> Result<bool> {
+        let (tx, rx) = oneshot::channel();
+        self.send(ActorMessage::Kick(viewer, tx)).await?;
+        rx.await.map_err(|_| anyhow::anyhow!("live actor died"))
     }
 
     /// Connect to a remote peer and create a session
     pub async fn connect(&self, addr: impl Into<EndpointAddr>) -> Result<LiveSession> {
-        LiveSession::connect(&self.endpoint, addr).await
+        LiveSession::connect(&self.endpoint, addr, &self.hello(None)).await
+    }
+
+    /// Connect to a remote peer, presenting a token for restricted broadcasts
+    pub async fn connect_with_token(
+        &self,
+        addr: impl Into<EndpointAddr>,
+        token: Option<&SignedToken>,
+    ) -> Result<LiveSession> {
+        LiveSession::connect(&self.endpoint, addr, &self.hello(token)).await
     }
 
     /// Shutdown the live streaming service
@@ -118,17 +405,25 @@ impl Live {
 #[derive(Debug, Clone)]
 pub struct LiveProtocolHandler {
     tx: mpsc::Sender<ActorMessage>,
+    access: AccessControl,
 }
 
 impl LiveProtocolHandler {
     async fn handle_connection(&self, connection: Connection) -> Result<()> {
         info!(remote = %connection.remote_id().fmt_short(), "accepting connection");
         
+        let hello = if connection.alpn() == LEGACY_ALPN {
+            SessionHello::default()
+        } else {
+            accept_hello(&connection, &self.access).await?
+        };
+
         // Create WebTransport session
         let session = Session::new(connection);
         
         // Create MoQ session (accept mode - we're the server)
-        let live_session = LiveSession::session_accept(session).await?;
+        let mut live_session = LiveSession::session_accept(session).await?;
+        live_session.hello = hello;
         
         // Send to actor for management
         self.tx
@@ -150,6 +445,8 @@ impl ProtocolHandler for LiveProtocolHandler {
 }
 
 /// A live streaming session with a remote peer
+///
+/// Dropping it closes the session.
 pub struct LiveSession {
     /// Remote endpoint ID
     pub remote: EndpointId,
@@ -161,32 +458,119 @@ pub struct LiveSession {
     publish: OriginProducer,
     /// Origin consumer - for subscribing from this peer
     subscribe: OriginConsumer,
+    /// What the peer sent in the handshake, on accepted sessions
+    hello: SessionHello,
+    /// Closes the connection along with the session
+    close: CloseOnDrop,
+}
+
+/// Closes a session's connection when dropped
+///
+/// moq-lite's background tasks hold the connection open on their own.
+struct CloseOnDrop(Session);
+
+impl Drop for CloseOnDrop {
+    fn drop(&mut self) {
+        let err = moq_lite::Error::Cancel;
+        self.0.close(err.to_code(), &err.to_string());
+    }
+}
+
+/// Whether a connect attempt failed because the peer doesn't speak the ALPN
+fn is_alpn_mismatch(err: &(dyn std::error::Error + 'static)) -> bool {
+    let mut next = Some(err);
+    while let Some(err) = next {
+        if let Some(ConnectionError::ConnectionClosed(close)) = err.downcast_ref() {
+            return close.error_code == TransportErrorCode::crypto(NO_APPLICATION_PROTOCOL);
+        }
+        next = err.source();
+    }
+    false
+}
+
+/// Connecting side of the session handshake
+async fn send_hello(conn: &Connection, hello: &SessionHello) -> Result<()> {
+    let (mut send, mut recv) = conn.open_bi().await?;
+    send.write_all(&postcard::to_stdvec(hello)?).await?;
+    send.finish()?;
+
+    let welcome: SessionWelcome = postcard::from_bytes(&recv.read_to_end(MAX_HANDSHAKE_MESSAGE).await?)?;
+    if let Some(reason) = welcome.rejected {
+        anyhow::bail!("session rejected: {reason}");
+    }
+    Ok(())
+}
+
+/// Accepting side of the session handshake, returning the peer's hello
+///
+/// A peer without a token is admitted; what it may watch is decided per
+/// broadcast. An invalid token is refused outright so the peer learns why.
+async fn accept_hello(conn: &Connection, access: &AccessControl) -> Result<SessionHello> {
+    let (mut send, mut recv) = conn.accept_bi().await?;
+    let hello: SessionHello = postcard::from_bytes(&recv.read_to_end(MAX_HANDSHAKE_MESSAGE).await?)?;
+
+    let rejected = hello
+        .token
+        .as_ref()
+        .and_then(|token| access.verify_token(token).err());
+    let welcome = SessionWelcome {
+        rejected: rejected.as_ref().map(|e| e.to_string()),
+    };
+    send.write_all(&postcard::to_stdvec(&welcome)?).await?;
+    send.finish()?;
+
+    if let Some(e) = rejected {
+        // Let the peer read the reason before the connection goes away
+        let _ = send.stopped().await;
+        conn.close(1u32.into(), b"unauthorized");
+        anyhow::bail!("rejected session from {}: {e}", conn.remote_id().fmt_short());
+    }
+    Ok(hello)
 }
 
 impl LiveSession {
-    /// Connect to a remote peer
+    /// Connect to a remote peer, sending `hello` in the handshake
     #[instrument(skip_all, fields(remote = tracing::field::Empty))]
     pub async fn connect(
         endpoint: &Endpoint,
         remote_addr: impl Into<EndpointAddr>,
+        hello: &SessionHello,
     ) -> Result<Self> {
         let addr = remote_addr.into();
         info!("connecting to {:?}", addr);
         
-        let conn = endpoint.connect(addr, ALPN).await
-            .context("failed to connect")?;
+        // Offering both ALPNs at once would not work: the accepting side
+        // picks in its own order, and iroh's router sorts version 1 first.
+        let conn = match endpoint.connect(addr.clone(), ALPN).await {
+            Err(err) if is_alpn_mismatch(&err) => {
+                debug!("peer does not speak {}, falling back", String::from_utf8_lossy(ALPN));
+                endpoint.connect(addr, LEGACY_ALPN).await
+            }
+            conn => conn,
+        }
+        .context("failed to connect")?;
         
         let remote = conn.remote_id();
         tracing::Span::current().record("remote", tracing::field::display(remote.fmt_short()));
         info!("connected");
         
         let session = Session::new(conn);
-        Self::session_connect(session).await
+        Self::session_connect(session, hello).await
     }
 
     /// Create a session in connect mode (we're the client)
-    pub async fn session_connect(wt_session: Session) -> Result<Self> {
+    ///
+    /// Runs the session handshake before starting MoQ, unless the peer
+    /// only speaks [`LEGACY_ALPN`].
+    pub async fn session_connect(wt_session: Session, hello: &SessionHello) -> Result<Self> {
         let remote = wt_session.remote_id();
+        if wt_session.conn().alpn() == LEGACY_ALPN {
+            if hello.token.is_some() {
+                warn!(remote = %remote.fmt_short(), "peer predates tokens, connecting without one");
+            }
+        } else {
+            send_hello(wt_session.conn(), hello).await?;
+        }
         
         // Create MoQ origins for bidirectional pub/sub
         let publish = moq_lite::Origin::produce();
@@ -201,14 +585,18 @@ impl LiveSession {
         
         Ok(Self {
             remote,
+            close: CloseOnDrop(wt_session.clone()),
             wt_session,
             moq_session,
             publish: publish.producer,
             subscribe: subscribe.consumer,
+            hello: SessionHello::default(),
         })
     }
 
     /// Create a session in accept mode (we're the server)
+    ///
+    /// The token handshake must already have been answered.
     pub async fn session_accept(wt_session: Session) -> Result<Self> {
         let remote = wt_session.remote_id();
         
@@ -225,10 +613,12 @@ impl LiveSession {
         
         Ok(Self {
             remote,
+            close: CloseOnDrop(wt_session.clone()),
             wt_session,
             moq_session,
             publish: publish.producer,
             subscribe: subscribe.consumer,
+            hello: SessionHello::default(),
         })
     }
 
@@ -237,6 +627,16 @@ impl LiveSession {
         self.wt_session.conn()
     }
 
+    /// Whether the peer closed the session by kicking this node
+    pub fn kicked(&self) -> bool {
+        match self.conn().close_reason() {
+            Some(ConnectionError::ApplicationClosed(close)) => {
+                close.error_code.into_inner() == u64::from(KICK_CODE) && close.reason.as_ref() == KICK_REASON
+            }
+            _ => false,
+        }
+    }
+
     /// Subscribe to a broadcast from this peer
     pub async fn subscribe(&mut self, name: &str) -> Result<BroadcastConsumer> {
         let consumer = self.wait_for_broadcast(name).await?;
@@ -248,6 +648,49 @@ impl LiveSession {
         self.publish.publish_broadcast(name, broadcast);
     }
 
+    /// Start posting to the chat of the peer's broadcast `name`
+    ///
+    /// Read the chat itself with [`chat::ChatReader::subscribe`].
+    pub fn chat(&self, name: &str) -> ChatSender {
+        let sender = ChatSender::new();
+        self.publish.publish_broadcast(chat::inbox_path(name), sender.broadcast());
+        sender
+    }
+
+    /// Next broadcast the peer announces (with its consumer) or withdraws
+    /// (without), or None once the session closes
+    pub async fn announced(&mut self) -> Option<(BroadcastName, Option<BroadcastConsumer>)> {
+        tokio::select! {
+            _ = self.moq_session.closed() => None,
+            next = self.subscribe.announced() => {
+                next.map(|(path, broadcast)| (path.as_str().to_string(), broadcast))
+            }
+        }
+    }
+
+    /// Wait until the peer stops announcing `name` or the session closes
+    pub async fn broadcast_ended(&mut self, name: &str) {
+        loop {
+            tokio::select! {
+                result = self.moq_session.closed() => {
+                    debug!("session closed: {result:?}");
+                    return;
+                }
+                announced = self.subscribe.announced() => match announced {
+                    None => return,
+                    Some((path, None)) if path.as_str() == name => return,
+                    Some(_) => {}
+                },
+            }
+        }
+    }
+
+    /// Wait until the session closes
+    pub async fn closed(&mut self) {
+        let result = self.moq_session.closed().await;
+        debug!("session closed: {result:?}");
+    }
+
     /// Wait for a specific broadcast to be announced
     async fn wait_for_broadcast(&mut self, name: &str) -> Result<BroadcastConsumer> {
         // Check if already announced
@@ -281,25 +724,168 @@ impl std::fmt::Debug for LiveSession {
 
 /// State for a connected session
 struct SessionState {
+    remote: EndpointId,
     /// Origin producer for publishing to this peer
     publish: OriginProducer,
+    /// Token the peer presented in the handshake
+    token: Option<SignedToken>,
+    /// Broadcasts announced to this peer, with the token withdrawing each
+    granted: HashMap<BroadcastName, CancellationToken>,
+    /// Roster entry for this peer
+    presence: Presence,
+    /// Set when the publisher kicked the peer
+    kicked: bool,
+    /// Closes the session
+    cancel: CancellationToken,
+}
+
+impl SessionState {
+    /// Announce a broadcast to this peer through its own tracking proxy
+    fn offer(&mut self, name: &str, published: &Published) {
+        let withdraw = self.cancel.child_token();
+        let proxy = roster::tap_broadcast(
+            name,
+            published.broadcast.clone(),
+            self.presence.tracks.clone(),
+            published.backlog.clone(),
+            withdraw.clone(),
+        );
+        self.publish.publish_broadcast(name.to_string(), proxy);
+        if let Some(previous) = self.granted.insert(name.to_string(), withdraw) {
+            previous.cancel();
+        }
+    }
+
+    /// Withdraw a broadcast from this peer
+    fn withdraw(&mut self, name: &str) {
+        if let Some(withdraw) = self.granted.remove(name) {
+            withdraw.cancel();
+        }
+    }
+}
+
+/// Follow the broadcasts a connected peer announces to this node
+///
+/// Inboxes for chats open on this node are read into `posts`; everything
+/// else is passed on as [`PeerEvent`]s. Announcements still standing when
+/// the session closes are withdrawn.
+async fn watch_peer(
+    mut origin: OriginConsumer,
+    remote: EndpointId,
+    serial: u64,
+    posts: mpsc::Sender<InboundPost>,
+    open_chats: Arc<RwLock<HashSet<BroadcastName>>>,
+    peer_events: broadcast::Sender<PeerEvent>,
+    cancel: CancellationToken,
+) {
+    let mut announced = HashSet::new();
+    let mut inboxes = HashSet::new();
+    loop {
+        let (path, broadcast) = tokio::select! {
+            _ = cancel.cancelled() => break,
+            next = origin.announced() => match next {
+                Some(next) => next,
+                None => break,
+            },
+        };
+        let name = path.as_str().to_string();
+        // Other names that happen to end like an inbox are broadcasts
+        let target = chat::inbox_target(&name).filter(|target| open_chats.read().unwrap().contains(*target));
+        match (target, broadcast.as_ref()) {
+            (Some(target), Some(inbox)) => {
+                inboxes.insert(name.clone());
+                tokio::spawn(chat::read_posts(inbox.clone(), serial, target.to_string(), posts.clone()));
+                continue;
+            }
+            (_, None) if inboxes.remove(&name) => continue,
+            _ => {}
+        }
+        let event = match broadcast {
+            Some(broadcast) => {
+                debug!(remote = %remote.fmt_short(), "peer announced {name}");
+                announced.insert(name.clone());
+                PeerEvent::Announced { remote, name, broadcast }
+            }
+            None => {
+                announced.remove(&name);
+                PeerEvent::Unannounced { remote, name }
+            }
+        };
+        let _ = peer_events.send(event);
+    }
+    for name in announced {
+        let _ = peer_events.send(PeerEvent::Unannounced { remote, name });
+    }
+}
+
+/// A broadcast offered to sessions
+struct Published {
+    broadcast: BroadcastConsumer,
+    route: Route,
+    /// Filled in by the sessions it is offered to
+    backlog: SendBacklog,
+}
+
+/// Where viewers' posts to a broadcast go
+struct OpenChat {
+    inbox: ChatInbox,
+    rate: ChatRate,
+    /// Rate limits per viewer endpoint, shared by all of its sessions
+    limits: HashMap<EndpointId, RateLimiter>,
 }
 
 /// Background actor managing live sessions
-#[derive(Default)]
 struct Actor {
     shutdown_token: CancellationToken,
-    /// Published broadcasts
-    broadcasts: HashMap<BroadcastName, BroadcastProducer>,
-    /// Connected sessions
-    sessions: HashMap<EndpointId, SessionState>,
-    /// Session tasks
-    session_tasks: tokio::task::JoinSet<(EndpointId, Result<(), moq_lite::Error>)>,
+    /// Who may receive which broadcast
+    access: AccessControl,
+    /// Published broadcasts, local and relayed
+    broadcasts: HashMap<BroadcastName, Published>,
+    /// Connected sessions by serial; a peer may hold several
+    sessions: HashMap<u64, SessionState>,
+    /// Session tasks, yielding the session serial
+    session_tasks: tokio::task::JoinSet<(u64, Result<(), moq_lite::Error>)>,
+    /// Serial of the next session
+    next_serial: u64,
+    /// Viewer joins and leaves
+    roster_events: broadcast::Sender<RosterEvent>,
+    /// Broadcasts taking chat posts
+    chats: HashMap<BroadcastName, OpenChat>,
+    /// Names of the open chats, for telling inboxes from broadcasts
+    open_chats: Arc<RwLock<HashSet<BroadcastName>>>,
+    /// Broadcasts peers announce to this node
+    peer_events: broadcast::Sender<PeerEvent>,
+    /// Posts read from sessions
+    posts_tx: mpsc::Sender<InboundPost>,
+    posts_rx: mpsc::Receiver<InboundPost>,
 }
 
 impl Actor {
+    fn new(
+        access: AccessControl,
+        roster_events: broadcast::Sender<RosterEvent>,
+        peer_events: broadcast::Sender<PeerEvent>,
+    ) -> Self {
+        let (posts_tx, posts_rx) = mpsc::channel(CHAT_POST_CAPACITY);
+        Self {
+            shutdown_token: CancellationToken::new(),
+            access,
+            broadcasts: HashMap::new(),
+            sessions: HashMap::new(),
+            session_tasks: tokio::task::JoinSet::new(),
+            next_serial: 0,
+            roster_events,
+            chats: HashMap::new(),
+            open_chats: Arc::default(),
+            peer_events,
+            posts_tx,
+            posts_rx,
+        }
+    }
+
     /// Run the actor event loop
     pub async fn run(mut self, mut inbox: mpsc::Receiver<ActorMessage>) {
+        let mut recheck = n0_future::time::interval(ACCESS_RECHECK_INTERVAL);
         loop {
             tokio::select! {
                 msg = inbox.recv() => {
@@ -310,15 +896,23 @@ impl Actor {
                 }
                 Some(res) = self.session_tasks.join_next(), if !self.session_tasks.is_empty() => {
                     match res {
-                        Ok((endpoint_id, result)) => {
-                            info!(remote = %endpoint_id.fmt_short(), "session closed: {result:?}");
-                            self.sessions.remove(&endpoint_id);
+                        Ok((serial, result)) => {
+                            if let Some(session) = self.sessions.remove(&serial) {
+                                info!(remote = %session.remote.fmt_short(), "session closed: {result:?}");
+                                let _ = self.roster_events.send(RosterEvent::Left {
+                                    endpoint_id: session.remote,
+                                    kicked: session.kicked,
+                                });
+                            }
                         }
                         Err(e) => {
                             error!("session task panicked: {e}");
                         }
                     }
                 }
+                Some(post) = self.posts_rx.recv() => self.handle_chat_post(post),
+                // Tokens expire without anyone telling us
+                _ = recheck.tick() => self.reauthorize(),
                 _ = self.shutdown_token.cancelled() => {
                     info!("live actor shutting down");
                     break;
@@ -333,12 +927,53 @@ impl Actor {
     fn handle_message(&mut self, msg: ActorMessage) {
         match msg {
             ActorMessage::HandleSession(session) => self.handle_incoming_session(session),
-            ActorMessage::PublishBroadcast(name, producer) => {
-                self.handle_publish_broadcast(name, producer)
+            ActorMessage::PublishBroadcast(name, broadcast, route, backlog) => {
+                self.handle_publish_broadcast(name, Published { broadcast, route, backlog })
             }
             ActorMessage::RemoveBroadcast(name) => {
                 self.handle_remove_broadcast(name)
             }
+            ActorMessage::RemoveRelayed(name, upstream) => {
+                let relayed = self.broadcasts.get(&name)
+                    .is_some_and(|published| published.route.last() == Some(&upstream));
+                if relayed {
+                    self.handle_remove_broadcast(name);
+                }
+            }
+            ActorMessage::Route(name, reply) => {
+                let _ = reply.send(self.broadcasts.get(&name).map(|p| p.route.clone()));
+            }
+            ActorMessage::Reauthorize => self.reauthorize(),
+            ActorMessage::Roster(reply) => {
+                let viewers = self.sessions
+                    .values()
+                    .map(|session| session.presence.viewer(session.remote))
+                    .collect();
+                let _ = reply.send(viewers);
+            }
+            ActorMessage::Kick(remote, reply) => {
+                let mut kicked = false;
+                for session in self.sessions.values_mut().filter(|s| s.remote == remote) {
+                    info!(remote = %remote.fmt_short(), "kicking viewer");
+                    session.kicked = true;
+                    session.presence.conn.close(KICK_CODE.into(), KICK_REASON);
+                    session.cancel.cancel();
+                    kicked = true;
+                }
+                let _ = reply.send(kicked);
+            }
+            ActorMessage::Admits(remote, name, reply) => {
+                let admitted = self.sessions
+                    .values()
+                    .filter(|session| session.remote == remote)
+                    .any(|session| self.access.check(remote, &name, session.token.as_ref()).is_ok());
+                let _ = reply.send(admitted);
+            }
+            ActorMessage::OpenChat(name, inbox, rate) => {
+                info!("opening chat: {name}");
+                self.open_chats.write().unwrap().insert(name.clone());
+                self.chats.insert(name, OpenChat { inbox, rate, limits: HashMap::new() });
+            }
         }
     }
 
@@ -347,48 +982,149 @@ impl Actor {
         
         let LiveSession {
             remote,
+            wt_session,
             moq_session,
             publish,
-            subscribe: _,
-            ..
+            subscribe,
+            hello,
+            close,
         } = session;
         
-        // Publish all existing broadcasts to this peer
-        for (name, producer) in self.broadcasts.iter() {
-            publish.publish_broadcast(name.clone(), producer.consume());
+        let cancel = self.shutdown_token.child_token();
+        let serial = self.next_serial;
+        self.next_serial += 1;
+        let mut state = SessionState {
+            remote,
+            publish,
+            token: hello.token,
+            granted: HashMap::new(),
+            presence: Presence::new(wt_session.conn().clone(), hello.display_name),
+            kicked: false,
+            cancel: cancel.clone(),
+        };
+
+        // Publish the existing broadcasts this peer may receive
+        for (name, published) in self.broadcasts.iter() {
+            match self.access.check(remote, name, state.token.as_ref()) {
+                Ok(()) => state.offer(name, published),
+                Err(e) => debug!(remote = %remote.fmt_short(), "not announcing {name}: {e}"),
+            }
         }
         
-        // Store session state
-        self.sessions.insert(remote, SessionState { publish });
+        let _ = self.roster_events.send(RosterEvent::Joined(state.presence.viewer(remote)));
+        self.sessions.insert(serial, state);
+
+        // Follow what the peer publishes back for as long as the session lasts
+        tokio::spawn(watch_peer(
+            subscribe,
+            remote,
+            serial,
+            self.posts_tx.clone(),
+            self.open_chats.clone(),
+            self.peer_events.clone(),
+            cancel.clone(),
+        ));
         
         // Spawn task to monitor session
-        let shutdown = self.shutdown_token.child_token();
         self.session_tasks.spawn(async move {
+            let _close = close;
             let res = tokio::select! {
-                _ = shutdown.cancelled() => {
+                _ = cancel.cancelled() => {
                     moq_session.close(moq_lite::Error::Cancel);
                     Ok(())
                 }
                 result = moq_session.closed() => result,
             };
-            (remote, res)
+            (serial, res)
         });
     }
 
-    fn handle_publish_broadcast(&mut self, name: BroadcastName, producer: BroadcastProducer) {
-        info!("publishing broadcast: {name}");
+    fn handle_publish_broadcast(&mut self, name: BroadcastName, published: Published) {
+        info!(hops = published.route.len(), "publishing broadcast: {name}");
         
-        // Publish to all connected sessions
-        for session in self.sessions.values() {
-            session.publish.publish_broadcast(name.clone(), producer.consume());
+        // Publish to all connected sessions allowed to receive it
+        for session in self.sessions.values_mut() {
+            if self.access.check(session.remote, &name, session.token.as_ref()).is_ok() {
+                session.offer(&name, &published);
+            }
         }
         
         // Store for future sessions
-        self.broadcasts.insert(name, producer);
+        self.broadcasts.insert(name, published);
     }
 
     fn handle_remove_broadcast(&mut self, name: BroadcastName) {
         info!("removing broadcast: {name}");
         self.broadcasts.remove(&name);
+        self.chats.remove(&name);
+        self.open_chats.write().unwrap().remove(&name);
+        // Close each peer's proxy, so the broadcast is withdrawn even while
+        // something still holds the source
+        for session in self.sessions.values_mut() {
+            session.withdraw(&name);
+        }
+    }
+
+    /// Forward a viewer's chat post to the broadcast's inbox
+    ///
+    /// The sender is the session's peer, whatever the post claims.
+    fn handle_chat_post(&mut self, post: InboundPost) {
+        let Some(session) = self.sessions.get_mut(&post.session) else {
+            return;
+        };
+        let remote = session.remote;
+        let Some(open) = self.chats.get_mut(&post.broadcast) else {
+            debug!(remote = %remote.fmt_short(), "no chat open for {}", post.broadcast);
+            return;
+        };
+        if !session.granted.contains_key(&post.broadcast) {
+            debug!(remote = %remote.fmt_short(), "dropping chat post, not admitted to {}", post.broadcast);
+            return;
+        }
+        if let Err(e) = post.kind.validate() {
+            debug!(remote = %remote.fmt_short(), "dropping chat post: {e}");
+            return;
+        }
+        let limiter = open.limits
+            .entry(remote)
+            .or_insert_with(|| RateLimiter::new(open.rate));
+        if !limiter.allow(Instant::now()) {
+            debug!(remote = %remote.fmt_short(), "dropping chat post, rate limited");
+            return;
+        }
+        let forward = ChatPost {
+            sender: remote,
+            display_name: session.presence.display_name.clone(),
+            kind: post.kind,
+        };
+        if open.inbox.try_send(forward).is_err() {
+            warn!(remote = %remote.fmt_short(), "chat inbox for {} full or closed", post.broadcast);
+        }
+    }
+
+    /// Re-check every session against the current access rules
+    ///
+    /// Broadcasts a peer lost access to are withdrawn from that peer alone;
+    /// its session and everything else it receives stay up. Peers that
+    /// gained access are sent the broadcast.
+    fn reauthorize(&mut self) {
+        for session in self.sessions.values_mut() {
+            let remote = session.remote;
+            let lost: Vec<_> = session.granted.keys()
+                .filter_map(|name| {
+                    self.access.check(remote, name, session.token.as_ref()).err().map(|e| (name.clone(), e))
+                })
+                .collect();
+            for (name, e) in lost {
+                info!(remote = %remote.fmt_short(), "withdrawing {name}, access lost: {e}");
+                session.withdraw(&name);
+            }
+
+            for (name, published) in self.broadcasts.iter() {
+                if !session.granted.contains_key(name) && self.access.check(remote, name, session.token.as_ref()).is_ok() {
+                    session.offer(name, published);
+                }
+            }
+        }
     }
 }
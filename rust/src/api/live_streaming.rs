@@ -658,7 +658,7 @@ mod tests {
 
     #[test]
     fn test_live_ticket_roundtrip() {
-        let endpoint_id = EndpointId::from([42u8; 32]);
+        let endpoint_id = iroh::SecretKey::from_bytes(&[42u8; 32]).public();
         let ticket = LiveTicket::new("mystream", endpoint_id);
         
         let serialized = ticket.serialize();
@@ -10,6 +10,18 @@ pub mod iroh_live;
 // Flutter API for iroh-live features
 pub mod iroh_live_flutter_api;
 
+// Persistent node identity (secret key storage)
+pub mod identity;
+
+// Signed capability tokens and per-broadcast access policies
+pub mod access;
+
+// End-to-end encryption of media payloads
+pub mod encryption;
+
+// Relay, bind and discovery settings for iroh endpoints
+pub mod endpoint_config;
+
 // WebTransport session layer (required by moq-lite)
 pub mod webtransport;
 
@@ -22,10 +34,54 @@ pub mod publish_broadcast;
 // Broadcast subscription (receiving media)
 pub mod subscribe_broadcast;
 
+// Relaying remote broadcasts to downstream peers
+pub mod relay;
+
+// Viewer roster (presence, join/leave events, kick) for publishers
+pub mod roster;
+
+// Chat and reactions carried alongside broadcasts
+pub mod chat;
+
+// Browsing the broadcasts a peer announces
+pub mod discovery;
+
+// Rooms for calls, with every member publishing and subscribing
+pub mod room;
+
+// Reconnecting subscriptions and their connection states
+pub mod reconnect;
+
+// Adaptive bitrate control for subscriptions
+pub mod abr;
+
+// Audio-master playout clock for A/V sync
+pub mod playout;
+
 // FFmpeg encoder following iroh-live patterns (requires ffmpeg feature)
 #[cfg(feature = "ffmpeg")]
 pub mod ffmpeg_encoder;
 
+// FFmpeg H264 decoder for subscribers (requires ffmpeg feature)
+#[cfg(feature = "ffmpeg")]
+pub mod ffmpeg_decoder;
+
+// Simulcast encoding of one source into several renditions (requires ffmpeg feature)
+#[cfg(feature = "ffmpeg")]
+pub mod simulcast;
+
+// Fragmented MP4 muxing
+pub mod mp4;
+
+// Local recording of broadcasts to fragmented MP4
+pub mod record;
+
+// Replay of MP4 files as live broadcasts
+pub mod replay;
+
+// Opus audio framing and codec (libopus requires ffmpeg feature)
+pub mod opus;
+
 // Legacy modules (will be deprecated)
 mod streaming;  // Old gossip-based - not used
 mod direct_streaming;  // Old direct QUIC - not used
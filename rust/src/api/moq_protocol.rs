@@ -681,6 +681,9 @@ pub struct NamespaceAnnouncement {
 }
 
 /// Namespace manager for PUBLISH_NAMESPACE / SUBSCRIBE_NAMESPACE
+///
+/// Local bookkeeping only; to browse what a peer announces over the
+/// network, use [`super::discovery::Discovery`].
 pub struct NamespaceManager {
     /// Published namespaces
     published: Arc<RwLock<HashMap<String, NamespaceAnnouncement>>>,
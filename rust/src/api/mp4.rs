@@ -0,0 +1,979 @@
+//! MP4 muxing and demuxing
+//!
+//! Builds the two kinds of segment a fragmented MP4 (CMAF) is made of:
+//! - an init segment (`ftyp` + `moov`) describing the tracks, and
+//! - media segments (`moof` + `mdat`), each carrying a run of samples for
+//!   one track.
+//!
+//! [`Mp4Reader`] goes the other way for file replay: it reads the sample
+//! tables of a regular MP4, or the fragments of a fragmented one, and
+//! fetches sample data on demand.
+//!
+//! Only what live streaming needs is covered: H.264 (`avc1`/`avcC`) video
+//! and AAC (`mp4a`/`esds`) or Opus (`Opus`/`dOps`) audio. Sample data is
+//! written as-is, so H.264 must already be length-prefixed;
+//! [`annexb_to_avcc`] converts Annex B.
+
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::Path;
+
+use anyhow::{Context, Result, bail};
+use bytes::{BufMut, Bytes};
+use tracing::debug;
+
+/// Codec and parameters of a track in the init segment
+#[derive(Debug, Clone, PartialEq)]
+pub enum Mp4Codec {
+    /// H.264 with its avcC decoder configuration record
+    Avc { width: u32, height: u32, avcc: Bytes },
+    /// AAC with its AudioSpecificConfig
+    Aac { sample_rate: u32, channel_count: u32, config: Bytes },
+    /// Opus
+    Opus { sample_rate: u32, channel_count: u32 },
+}
+
+/// A track in the init segment
+#[derive(Debug, Clone, PartialEq)]
+pub struct Mp4Track {
+    /// Track id, referenced by media segments
+    pub id: u32,
+    /// Ticks per second for this track's timestamps
+    pub timescale: u32,
+    pub codec: Mp4Codec,
+}
+
+/// One sample in a media segment
+#[derive(Debug, Clone)]
+pub struct Mp4Sample {
+    pub data: Bytes,
+    /// Duration in track timescale ticks
+    pub duration: u32,
+    /// Sync sample (IDR for video; every audio sample is one)
+    pub keyframe: bool,
+}
+
+/// Opus encoder delay at 48 kHz, per RFC 7845
+const OPUS_PRE_SKIP: u16 = 312;
+
+/// Identity transform used by `mvhd` and `tkhd`
+const MATRIX: [u32; 9] = [0x0001_0000, 0, 0, 0, 0x0001_0000, 0, 0, 0, 0x4000_0000];
+
+/// Write a box, filling in its size once the body is written
+fn write_box(out: &mut Vec<u8>, kind: &[u8; 4], body: impl FnOnce(&mut Vec<u8>)) {
+    let start = out.len();
+    out.put_u32(0);
+    out.put_slice(kind);
+    body(out);
+    let size = (out.len() - start) as u32;
+    out[start..start + 4].copy_from_slice(&size.to_be_bytes());
+}
+
+/// Write a full box (a box with version and flags)
+fn write_full_box(
+    out: &mut Vec<u8>,
+    kind: &[u8; 4],
+    version: u8,
+    flags: u32,
+    body: impl FnOnce(&mut Vec<u8>),
+) {
+    write_box(out, kind, |out| {
+        out.put_u32((version as u32) << 24 | (flags & 0x00ff_ffff));
+        body(out);
+    });
+}
+
+/// Convert microseconds to `timescale` ticks
+pub fn ticks(us: i64, timescale: u32) -> u64 {
+    (us.max(0) as u128 * timescale as u128 / 1_000_000) as u64
+}
+
+/// Build an init segment (`ftyp` + `moov`) for the given tracks
+pub fn init_segment(tracks: &[Mp4Track]) -> Vec<u8> {
+    let mut out = Vec::new();
+    write_box(&mut out, b"ftyp", |out| {
+        out.put_slice(b"iso6");
+        out.put_u32(0);
+        for brand in [b"iso6", b"cmfc", b"mp41", b"avc1"] {
+            out.put_slice(brand);
+        }
+    });
+
+    write_box(&mut out, b"moov", |out| {
+        write_full_box(out, b"mvhd", 0, 0, |out| {
+            out.put_u32(0); // creation time
+            out.put_u32(0); // modification time
+            out.put_u32(1000); // timescale
+            out.put_u32(0); // duration: unknown, it's all in fragments
+            out.put_u32(0x0001_0000); // rate 1.0
+            out.put_u16(0x0100); // volume 1.0
+            out.put_slice(&[0; 10]);
+            MATRIX.iter().for_each(|v| out.put_u32(*v));
+            out.put_slice(&[0; 24]);
+            out.put_u32(tracks.iter().map(|t| t.id).max().unwrap_or(0) + 1);
+        });
+        for track in tracks {
+            write_trak(out, track);
+        }
+        write_box(out, b"mvex", |out| {
+            for track in tracks {
+                write_full_box(out, b"trex", 0, 0, |out| {
+                    out.put_u32(track.id);
+                    out.put_u32(1); // sample description index
+                    out.put_u32(0); // default duration
+                    out.put_u32(0); // default size
+                    out.put_u32(0); // default flags
+                });
+            }
+        });
+    });
+    out
+}
+
+fn write_trak(out: &mut Vec<u8>, track: &Mp4Track) {
+    let (width, height, is_audio) = match &track.codec {
+        Mp4Codec::Avc { width, height, .. } => (*width, *height, false),
+        Mp4Codec::Aac { .. } | Mp4Codec::Opus { .. } => (0, 0, true),
+    };
+
+    write_box(out, b"trak", |out| {
+        // Flags: enabled, in movie
+        write_full_box(out, b"tkhd", 0, 0x3, |out| {
+            out.put_u32(0);
+            out.put_u32(0);
+            out.put_u32(track.id);
+            out.put_u32(0);
+            out.put_u32(0); // duration
+            out.put_slice(&[0; 8]);
+            out.put_u16(0); // layer
+            out.put_u16(0); // alternate group
+            out.put_u16(if is_audio { 0x0100 } else { 0 });
+            out.put_u16(0);
+            MATRIX.iter().for_each(|v| out.put_u32(*v));
+            out.put_u32(width << 16);
+            out.put_u32(height << 16);
+        });
+        write_box(out, b"mdia", |out| {
+            write_full_box(out, b"mdhd", 0, 0, |out| {
+                out.put_u32(0);
+                out.put_u32(0);
+                out.put_u32(track.timescale);
+                out.put_u32(0);
+                out.put_u16(0x55c4); // "und"
+                out.put_u16(0);
+            });
+            write_full_box(out, b"hdlr", 0, 0, |out| {
+                out.put_u32(0);
+                out.put_slice(if is_audio { b"soun" } else { b"vide" });
+                out.put_slice(&[0; 12]);
+                out.put_slice(if is_audio { b"SoundHandler\0" } else { b"VideoHandler\0" });
+            });
+            write_box(out, b"minf", |out| {
+                if is_audio {
+                    write_full_box(out, b"smhd", 0, 0, |out| out.put_u32(0));
+                } else {
+                    write_full_box(out, b"vmhd", 0, 1, |out| out.put_slice(&[0; 8]));
+                }
+                write_box(out, b"dinf", |out| {
+                    write_full_box(out, b"dref", 0, 0, |out| {
+                        out.put_u32(1);
+                        // Self-contained: media is in this file
+                        write_full_box(out, b"url ", 0, 1, |_| {});
+                    });
+                });
+                write_box(out, b"stbl", |out| {
+                    write_full_box(out, b"stsd", 0, 0, |out| {
+                        out.put_u32(1);
+                        write_sample_entry(out, &track.codec);
+                    });
+                    // Samples live in fragments, so the tables are empty
+                    write_full_box(out, b"stts", 0, 0, |out| out.put_u32(0));
+                    write_full_box(out, b"stsc", 0, 0, |out| out.put_u32(0));
+                    write_full_box(out, b"stsz", 0, 0, |out| out.put_slice(&[0; 8]));
+                    write_full_box(out, b"stco", 0, 0, |out| out.put_u32(0));
+                });
+            });
+        });
+    });
+}
+
+fn write_sample_entry(out: &mut Vec<u8>, codec: &Mp4Codec) {
+    match codec {
+        Mp4Codec::Avc { width, height, avcc } => write_box(out, b"avc1", |out| {
+            out.put_slice(&[0; 6]);
+            out.put_u16(1); // data reference index
+            out.put_slice(&[0; 16]);
+            out.put_u16(*width as u16);
+            out.put_u16(*height as u16);
+            out.put_u32(0x0048_0000); // 72 dpi
+            out.put_u32(0x0048_0000);
+            out.put_u32(0);
+            out.put_u16(1); // frame count
+            out.put_slice(&[0; 32]); // compressor name
+            out.put_u16(0x0018); // depth
+            out.put_i16(-1);
+            write_box(out, b"avcC", |out| out.put_slice(avcc));
+        }),
+        Mp4Codec::Aac { sample_rate, channel_count, config } => write_box(out, b"mp4a", |out| {
+            write_audio_entry(out, *sample_rate, *channel_count);
+            write_full_box(out, b"esds", 0, 0, |out| {
+                // ES descriptor > decoder config > decoder specific info, then SL config
+                let decoder_config_len = 13 + 2 + config.len();
+                out.put_u8(0x03);
+                out.put_u8((3 + 2 + decoder_config_len + 3) as u8);
+                out.put_u16(0); // ES id
+                out.put_u8(0);
+                out.put_u8(0x04);
+                out.put_u8(decoder_config_len as u8);
+                out.put_u8(0x40); // MPEG-4 audio
+                out.put_u8(0x15); // audio stream
+                out.put_slice(&[0; 11]); // buffer size, max and average bitrate
+                out.put_u8(0x05);
+                out.put_u8(config.len() as u8);
+                out.put_slice(config);
+                out.put_slice(&[0x06, 1, 2]);
+            });
+        }),
+        Mp4Codec::Opus { sample_rate, channel_count } => write_box(out, b"Opus", |out| {
+            write_audio_entry(out, *sample_rate, *channel_count);
+            write_box(out, b"dOps", |out| {
+                out.put_u8(0); // version
+                out.put_u8(*channel_count as u8);
+                out.put_u16(OPUS_PRE_SKIP);
+                out.put_u32(*sample_rate);
+                out.put_i16(0); // output gain
+                out.put_u8(0); // mono/stereo mapping
+            });
+        }),
+    }
+}
+
+/// Fields shared by audio sample entries
+fn write_audio_entry(out: &mut Vec<u8>, sample_rate: u32, channel_count: u32) {
+    out.put_slice(&[0; 6]);
+    out.put_u16(1); // data reference index
+    out.put_slice(&[0; 8]);
+    out.put_u16(channel_count as u16);
+    out.put_u16(16); // sample size
+    out.put_u32(0);
+    out.put_u32(sample_rate << 16);
+}
+
+/// Build a media segment (`moof` + `mdat`) for one track
+///
+/// `base_time` is the decode time of the first sample, in the track's
+/// timescale.
+pub fn media_segment(sequence: u32, track_id: u32, base_time: u64, samples: &[Mp4Sample]) -> Vec<u8> {
+    let mut out = Vec::new();
+    let mut data_offset_at = 0;
+
+    write_box(&mut out, b"moof", |out| {
+        write_full_box(out, b"mfhd", 0, 0, |out| out.put_u32(sequence));
+        write_box(out, b"traf", |out| {
+            // default-base-is-moof: data offsets are relative to the moof
+            write_full_box(out, b"tfhd", 0, 0x02_0000, |out| out.put_u32(track_id));
+            write_full_box(out, b"tfdt", 1, 0, |out| out.put_u64(base_time));
+            // Data offset plus per-sample duration, size and flags
+            write_full_box(out, b"trun", 0, 0x0701, |out| {
+                out.put_u32(samples.len() as u32);
+                data_offset_at = out.len();
+                out.put_i32(0);
+                for sample in samples {
+                    out.put_u32(sample.duration);
+                    out.put_u32(sample.data.len() as u32);
+                    out.put_u32(if sample.keyframe { 0x0200_0000 } else { 0x0101_0000 });
+                }
+            });
+        });
+    });
+
+    // Samples start right after the mdat header
+    let data_offset = (out.len() + 8) as i32;
+    out[data_offset_at..data_offset_at + 4].copy_from_slice(&data_offset.to_be_bytes());
+
+    write_box(&mut out, b"mdat", |out| {
+        for sample in samples {
+            out.put_slice(&sample.data);
+        }
+    });
+    out
+}
+
+/// NAL units of an Annex B byte stream, without start codes
+pub fn annexb_nals(data: &[u8]) -> Vec<&[u8]> {
+    let mut starts = Vec::new();
+    let mut i = 0;
+    while i + 3 <= data.len() {
+        if data[i..i + 3] == [0, 0, 1] {
+            starts.push(i + 3);
+            i += 3;
+        } else {
+            i += 1;
+        }
+    }
+
+    let mut nals = Vec::with_capacity(starts.len());
+    for (index, &start) in starts.iter().enumerate() {
+        let end = starts.get(index + 1).map_or(data.len(), |next| next - 3);
+        // Trailing zeros belong to the next 4-byte start code
+        let mut nal = &data[start..end];
+        while let [rest @ .., 0] = nal {
+            nal = rest;
+        }
+        if !nal.is_empty() {
+            nals.push(nal);
+        }
+    }
+    nals
+}
+
+/// Whether an access unit is in Annex B format
+pub fn is_annexb(data: &[u8]) -> bool {
+    data.starts_with(&[0, 0, 1]) || data.starts_with(&[0, 0, 0, 1])
+}
+
+/// Convert an Annex B access unit to 4-byte length-prefixed NAL units
+pub fn annexb_to_avcc(data: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(data.len() + 8);
+    for nal in annexb_nals(data) {
+        out.put_u32(nal.len() as u32);
+        out.put_slice(nal);
+    }
+    out
+}
+
+/// Build an avcC record from the SPS and PPS in an Annex B keyframe
+///
+/// None if the access unit carries no SPS/PPS.
+pub fn avcc_from_annexb(data: &[u8]) -> Option<Vec<u8>> {
+    let nals = annexb_nals(data);
+    let sps = nals.iter().find(|nal| nal[0] & 0x1f == 7 && nal.len() >= 4)?;
+    let pps = nals.iter().find(|nal| nal[0] & 0x1f == 8)?;
+
+    let mut out = Vec::with_capacity(11 + sps.len() + pps.len());
+    out.put_u8(1); // configuration version
+    out.put_slice(&sps[1..4]); // profile, constraints, level
+    out.put_u8(0xff); // 4-byte NAL lengths
+    out.put_u8(0xe1); // one SPS
+    out.put_u16(sps.len() as u16);
+    out.put_slice(sps);
+    out.put_u8(1); // one PPS
+    out.put_u16(pps.len() as u16);
+    out.put_slice(pps);
+    Some(out)
+}
+
+/// Location and timing of a sample in a file
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SampleInfo {
+    /// Byte offset of the sample data in the file
+    pub offset: u64,
+    pub size: u32,
+    /// Decode time in track timescale ticks
+    pub dts: u64,
+    /// Duration in track timescale ticks
+    pub duration: u32,
+    /// Presentation time minus decode time, in ticks
+    pub cts_offset: i32,
+    /// Sync sample (IDR for video)
+    pub keyframe: bool,
+}
+
+/// A track read from an MP4 file
+#[derive(Debug, Clone)]
+pub struct Mp4TrackInfo {
+    pub id: u32,
+    pub timescale: u32,
+    pub codec: Mp4Codec,
+    /// Samples in decode order
+    pub samples: Vec<SampleInfo>,
+}
+
+impl Mp4TrackInfo {
+    /// Convert track ticks to microseconds
+    pub fn to_us(&self, ticks: i64) -> i64 {
+        (ticks as i128 * 1_000_000 / self.timescale.max(1) as i128) as i64
+    }
+
+    /// Duration of the track in microseconds
+    pub fn duration_us(&self) -> i64 {
+        self.samples
+            .last()
+            .map_or(0, |last| self.to_us((last.dts + last.duration as u64) as i64))
+    }
+
+    /// Average bitrate in bits per second
+    pub fn bitrate(&self) -> u64 {
+        let bytes: u64 = self.samples.iter().map(|s| s.size as u64).sum();
+        match self.duration_us() {
+            0 => 0,
+            duration => bytes * 8 * 1_000_000 / duration as u64,
+        }
+    }
+
+    pub fn is_video(&self) -> bool {
+        matches!(self.codec, Mp4Codec::Avc { .. })
+    }
+}
+
+/// An MP4 file opened for reading samples
+///
+/// The sample tables are read up front; sample data is read on demand.
+/// Tracks with codecs other than H.264, AAC and Opus are skipped.
+pub struct Mp4Reader {
+    file: File,
+    tracks: Vec<Mp4TrackInfo>,
+}
+
+impl Mp4Reader {
+    /// Open a regular or fragmented MP4 file
+    pub fn open(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let mut file = File::open(path).with_context(|| format!("failed to open {}", path.display()))?;
+        let len = file.metadata()?.len();
+
+        // Walk the top-level boxes without reading the media data
+        let mut moov = None;
+        let mut moofs = Vec::new();
+        let mut pos = 0;
+        while pos + 8 <= len {
+            file.seek(SeekFrom::Start(pos))?;
+            let mut header = [0u8; 16];
+            file.read_exact(&mut header[..8])?;
+            let mut size = u32::from_be_bytes(header[..4].try_into().unwrap()) as u64;
+            let mut header_len = 8;
+            if size == 1 {
+                file.read_exact(&mut header[8..16])?;
+                size = u64::from_be_bytes(header[8..16].try_into().unwrap());
+                header_len = 16;
+            } else if size == 0 {
+                size = len - pos;
+            }
+            if size < header_len || pos + size > len {
+                bail!("invalid box size {size} at offset {pos}");
+            }
+
+            let kind = &header[4..8];
+            if kind == b"moov" || kind == b"moof" {
+                let mut body = vec![0; (size - header_len) as usize];
+                file.read_exact(&mut body)?;
+                if kind == b"moov" {
+                    moov = Some(body);
+                } else {
+                    moofs.push((pos, body));
+                }
+            }
+            pos += size;
+        }
+
+        let moov = moov.context("no moov box")?;
+        let (mut tracks, defaults) = parse_moov(&moov)?;
+        for (offset, moof) in &moofs {
+            parse_moof(moof, *offset, &defaults, &mut tracks)?;
+        }
+        if tracks.is_empty() {
+            bail!("no supported tracks in {}", path.display());
+        }
+        Ok(Self { file, tracks })
+    }
+
+    pub fn tracks(&self) -> &[Mp4TrackInfo] {
+        &self.tracks
+    }
+
+    /// Read a sample's data
+    pub fn read_sample(&mut self, sample: &SampleInfo) -> Result<Bytes> {
+        let mut data = vec![0; sample.size as usize];
+        self.file.seek(SeekFrom::Start(sample.offset))?;
+        self.file.read_exact(&mut data)?;
+        Ok(Bytes::from(data))
+    }
+}
+
+/// Big-endian reader over a box body
+struct Cursor<'a> {
+    data: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Cursor<'a> {
+    fn new(data: &'a [u8]) -> Self {
+        Self { data, pos: 0 }
+    }
+
+    fn bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+        let end = self.pos.checked_add(len).filter(|end| *end <= self.data.len()).context("truncated box")?;
+        let bytes = &self.data[self.pos..end];
+        self.pos = end;
+        Ok(bytes)
+    }
+
+    fn skip(&mut self, len: usize) -> Result<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    fn u8(&mut self) -> Result<u8> {
+        Ok(self.bytes(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16> {
+        Ok(u16::from_be_bytes(self.bytes(2)?.try_into().unwrap()))
+    }
+
+    fn u32(&mut self) -> Result<u32> {
+        Ok(u32::from_be_bytes(self.bytes(4)?.try_into().unwrap()))
+    }
+
+    fn u64(&mut self) -> Result<u64> {
+        Ok(u64::from_be_bytes(self.bytes(8)?.try_into().unwrap()))
+    }
+
+    fn rest(&self) -> &'a [u8] {
+        &self.data[self.pos..]
+    }
+}
+
+/// Child boxes of a container body as (type, body)
+fn child_boxes(data: &[u8]) -> Result<Vec<([u8; 4], &[u8])>> {
+    let mut boxes = Vec::new();
+    let mut cursor = Cursor::new(data);
+    while cursor.rest().len() >= 8 {
+        let start = cursor.pos;
+        let mut size = cursor.u32()? as usize;
+        let kind: [u8; 4] = cursor.bytes(4)?.try_into().unwrap();
+        if size == 1 {
+            size = cursor.u64()? as usize;
+        } else if size == 0 {
+            size = data.len() - start;
+        }
+        let header = cursor.pos - start;
+        if size < header {
+            bail!("invalid {} box size {size}", String::from_utf8_lossy(&kind));
+        }
+        boxes.push((kind, cursor.bytes(size - header)?));
+    }
+    Ok(boxes)
+}
+
+fn find_box<'a>(data: &'a [u8], kind: &[u8; 4]) -> Result<Option<&'a [u8]>> {
+    Ok(child_boxes(data)?.into_iter().find(|(k, _)| k == kind).map(|(_, body)| body))
+}
+
+fn require_box<'a>(data: &'a [u8], kind: &[u8; 4]) -> Result<&'a [u8]> {
+    find_box(data, kind)?.with_context(|| format!("missing {} box", String::from_utf8_lossy(kind)))
+}
+
+/// Split a full box body into (version, flags, content)
+fn full_box(body: &[u8]) -> Result<(u8, u32, Cursor<'_>)> {
+    let mut cursor = Cursor::new(body);
+    let header = cursor.u32()?;
+    Ok(((header >> 24) as u8, header & 0x00ff_ffff, cursor))
+}
+
+/// trex defaults per track: (duration, size, flags)
+type TrackDefaults = HashMap<u32, (u32, u32, u32)>;
+
+fn parse_moov(moov: &[u8]) -> Result<(Vec<Mp4TrackInfo>, TrackDefaults)> {
+    let mut tracks = Vec::new();
+    for (kind, body) in child_boxes(moov)? {
+        if &kind == b"trak" {
+            if let Some(track) = parse_trak(body)? {
+                tracks.push(track);
+            }
+        }
+    }
+
+    let mut defaults = HashMap::new();
+    if let Some(mvex) = find_box(moov, b"mvex")? {
+        for (kind, body) in child_boxes(mvex)? {
+            if &kind == b"trex" {
+                let (_, _, mut c) = full_box(body)?;
+                let id = c.u32()?;
+                c.skip(4)?; // sample description index
+                defaults.insert(id, (c.u32()?, c.u32()?, c.u32()?));
+            }
+        }
+    }
+    Ok((tracks, defaults))
+}
+
+fn parse_trak(trak: &[u8]) -> Result<Option<Mp4TrackInfo>> {
+    let (version, _, mut tkhd) = full_box(require_box(trak, b"tkhd")?)?;
+    tkhd.skip(if version == 1 { 16 } else { 8 })?;
+    let id = tkhd.u32()?;
+
+    let mdia = require_box(trak, b"mdia")?;
+    let (version, _, mut mdhd) = full_box(require_box(mdia, b"mdhd")?)?;
+    mdhd.skip(if version == 1 { 16 } else { 8 })?;
+    let timescale = mdhd.u32()?;
+
+    let stbl = require_box(require_box(mdia, b"minf")?, b"stbl")?;
+    let (_, _, mut stsd) = full_box(require_box(stbl, b"stsd")?)?;
+    stsd.skip(4)?; // entry count; only the first description is used
+    let Some((kind, entry)) = child_boxes(stsd.rest())?.into_iter().next() else {
+        return Ok(None);
+    };
+    let Some(codec) = parse_sample_entry(&kind, entry)? else {
+        debug!("skipping track {id}: unsupported codec {}", String::from_utf8_lossy(&kind));
+        return Ok(None);
+    };
+
+    Ok(Some(Mp4TrackInfo {
+        id,
+        timescale,
+        codec,
+        samples: parse_sample_table(stbl)?,
+    }))
+}
+
+fn parse_sample_entry(kind: &[u8; 4], entry: &[u8]) -> Result<Option<Mp4Codec>> {
+    let mut c = Cursor::new(entry);
+    match kind {
+        b"avc1" | b"avc3" => {
+            c.skip(24)?;
+            let width = c.u16()? as u32;
+            let height = c.u16()? as u32;
+            c.skip(50)?;
+            let avcc = require_box(c.rest(), b"avcC")?;
+            Ok(Some(Mp4Codec::Avc { width, height, avcc: Bytes::copy_from_slice(avcc) }))
+        }
+        b"mp4a" | b"Opus" => {
+            c.skip(8)?;
+            // QuickTime sound descriptions carry extra fields after version 0
+            let extra = match c.u16()? {
+                1 => 16,
+                2 => 36,
+                _ => 0,
+            };
+            c.skip(6)?;
+            let channel_count = c.u16()? as u32;
+            c.skip(6)?;
+            let sample_rate = c.u32()? >> 16;
+            c.skip(extra)?;
+
+            if kind == b"Opus" {
+                return Ok(Some(Mp4Codec::Opus { sample_rate, channel_count }));
+            }
+            let (_, _, esds) = full_box(require_box(c.rest(), b"esds")?)?;
+            let config = aac_config(esds.rest())?.context("esds has no AudioSpecificConfig")?;
+            Ok(Some(Mp4Codec::Aac { sample_rate, channel_count, config: Bytes::copy_from_slice(config) }))
+        }
+        _ => Ok(None),
+    }
+}
+
+/// Find the AudioSpecificConfig in an esds descriptor tree
+fn aac_config(data: &[u8]) -> Result<Option<&[u8]>> {
+    let mut c = Cursor::new(data);
+    while !c.rest().is_empty() {
+        let tag = c.u8()?;
+        let mut len = 0usize;
+        for _ in 0..4 {
+            let byte = c.u8()?;
+            len = len << 7 | (byte & 0x7f) as usize;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        let body = c.bytes(len)?;
+        match tag {
+            // ES descriptor: id, flags and their optional fields, then children
+            0x03 => {
+                let mut es = Cursor::new(body);
+                es.skip(2)?;
+                let flags = es.u8()?;
+                if flags & 0x80 != 0 {
+                    es.skip(2)?;
+                }
+                if flags & 0x40 != 0 {
+                    let url_len = es.u8()? as usize;
+                    es.skip(url_len)?;
+                }
+                if flags & 0x20 != 0 {
+                    es.skip(2)?;
+                }
+                return aac_config(es.rest());
+            }
+            // Decoder config: 13 bytes of fields, then children
+            0x04 => return aac_config(body.get(13..).context("truncated decoder config")?),
+            0x05 => return Ok(Some(body)),
+            _ => {}
+        }
+    }
+    Ok(None)
+}
+
+/// Expand a run-length table of (count, value) entries
+fn run_lengths(mut c: Cursor<'_>) -> Result<Vec<(u32, u32)>> {
+    let count = c.u32()?;
+    (0..count).map(|_| Ok((c.u32()?, c.u32()?))).collect()
+}
+
+fn parse_sample_table(stbl: &[u8]) -> Result<Vec<SampleInfo>> {
+    let (_, _, mut stsz) = full_box(require_box(stbl, b"stsz")?)?;
+    let fixed_size = stsz.u32()?;
+    let count = stsz.u32()? as usize;
+    let sizes = (0..count)
+        .map(|_| if fixed_size == 0 { stsz.u32() } else { Ok(fixed_size) })
+        .collect::<Result<Vec<_>>>()?;
+    if count == 0 {
+        return Ok(Vec::new());
+    }
+
+    let stts = run_lengths(full_box(require_box(stbl, b"stts")?)?.2)?;
+    let durations: Vec<u32> = stts.iter().flat_map(|&(n, delta)| std::iter::repeat_n(delta, n as usize)).collect();
+    let cts_offsets: Vec<i32> = match find_box(stbl, b"ctts")? {
+        Some(ctts) => run_lengths(full_box(ctts)?.2)?
+            .iter()
+            .flat_map(|&(n, offset)| std::iter::repeat_n(offset as i32, n as usize))
+            .collect(),
+        None => Vec::new(),
+    };
+    let sync: Option<Vec<u32>> = match find_box(stbl, b"stss")? {
+        Some(stss) => {
+            let (_, _, mut c) = full_box(stss)?;
+            let n = c.u32()?;
+            Some((0..n).map(|_| c.u32()).collect::<Result<_>>()?)
+        }
+        None => None,
+    };
+
+    let chunk_offsets: Vec<u64> = if let Some(stco) = find_box(stbl, b"stco")? {
+        let (_, _, mut c) = full_box(stco)?;
+        let n = c.u32()?;
+        (0..n).map(|_| c.u32().map(u64::from)).collect::<Result<_>>()?
+    } else {
+        let (_, _, mut c) = full_box(require_box(stbl, b"co64")?)?;
+        let n = c.u32()?;
+        (0..n).map(|_| c.u64()).collect::<Result<_>>()?
+    };
+
+    // stsc entries: (first chunk, samples per chunk, description index)
+    let (_, _, mut stsc) = full_box(require_box(stbl, b"stsc")?)?;
+    let entries = stsc.u32()?;
+    let stsc = (0..entries)
+        .map(|_| {
+            let entry = (stsc.u32()?, stsc.u32()?);
+            stsc.skip(4)?;
+            Ok(entry)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut samples = Vec::with_capacity(count);
+    let mut dts = 0u64;
+    for (chunk_index, &chunk_offset) in chunk_offsets.iter().enumerate() {
+        let chunk = chunk_index as u32 + 1;
+        let per_chunk = stsc.iter().rev().find(|(first, _)| *first <= chunk).map_or(0, |e| e.1);
+        let mut offset = chunk_offset;
+        for _ in 0..per_chunk {
+            let index = samples.len();
+            if index == count {
+                break;
+            }
+            let duration = durations.get(index).or(durations.last()).copied().unwrap_or(0);
+            samples.push(SampleInfo {
+                offset,
+                size: sizes[index],
+                dts,
+                duration,
+                cts_offset: cts_offsets.get(index).copied().unwrap_or(0),
+                keyframe: sync.as_ref().is_none_or(|sync| sync.binary_search(&(index as u32 + 1)).is_ok()),
+            });
+            offset += sizes[index] as u64;
+            dts += duration as u64;
+        }
+    }
+    Ok(samples)
+}
+
+/// Append the samples of a movie fragment to their tracks
+fn parse_moof(moof: &[u8], moof_offset: u64, defaults: &TrackDefaults, tracks: &mut [Mp4TrackInfo]) -> Result<()> {
+    for (kind, traf) in child_boxes(moof)? {
+        if &kind != b"traf" {
+            continue;
+        }
+
+        let (_, flags, mut tfhd) = full_box(require_box(traf, b"tfhd")?)?;
+        let id = tfhd.u32()?;
+        let Some(track) = tracks.iter_mut().find(|t| t.id == id) else {
+            continue;
+        };
+        let (mut default_duration, mut default_size, mut default_flags) =
+            defaults.get(&id).copied().unwrap_or_default();
+        // Without an explicit base, offsets are relative to the moof
+        let base = if flags & 0x01 != 0 { tfhd.u64()? } else { moof_offset };
+        if flags & 0x02 != 0 {
+            tfhd.skip(4)?;
+        }
+        if flags & 0x08 != 0 {
+            default_duration = tfhd.u32()?;
+        }
+        if flags & 0x10 != 0 {
+            default_size = tfhd.u32()?;
+        }
+        if flags & 0x20 != 0 {
+            default_flags = tfhd.u32()?;
+        }
+
+        let mut dts = match find_box(traf, b"tfdt")? {
+            Some(tfdt) => {
+                let (version, _, mut c) = full_box(tfdt)?;
+                if version == 1 { c.u64()? } else { c.u32()? as u64 }
+            }
+            None => track.samples.last().map_or(0, |s| s.dts + s.duration as u64),
+        };
+
+        let mut offset = base;
+        for (kind, trun) in child_boxes(traf)? {
+            if &kind != b"trun" {
+                continue;
+            }
+            let (_, flags, mut c) = full_box(trun)?;
+            let count = c.u32()?;
+            if flags & 0x01 != 0 {
+                offset = base.wrapping_add_signed(c.u32()? as i32 as i64);
+            }
+            let first_flags = if flags & 0x04 != 0 { Some(c.u32()?) } else { None };
+            for index in 0..count {
+                let duration = if flags & 0x100 != 0 { c.u32()? } else { default_duration };
+                let size = if flags & 0x200 != 0 { c.u32()? } else { default_size };
+                let mut sample_flags = if flags & 0x400 != 0 { c.u32()? } else { default_flags };
+                if index == 0 {
+                    sample_flags = first_flags.unwrap_or(sample_flags);
+                }
+                let cts_offset = if flags & 0x800 != 0 { c.u32()? as i32 } else { 0 };
+                track.samples.push(SampleInfo {
+                    offset,
+                    size,
+                    dts,
+                    duration,
+                    cts_offset,
+                    // sample_is_non_sync_sample
+                    keyframe: sample_flags & 0x0001_0000 == 0,
+                });
+                offset += size as u64;
+                dts += duration as u64;
+            }
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// (type, offset, size) of the top-level boxes in `data`
+    fn boxes(data: &[u8]) -> Vec<(String, usize, usize)> {
+        let mut out = Vec::new();
+        let mut offset = 0;
+        while offset + 8 <= data.len() {
+            let size = u32::from_be_bytes(data[offset..offset + 4].try_into().unwrap()) as usize;
+            let kind = String::from_utf8_lossy(&data[offset + 4..offset + 8]).to_string();
+            out.push((kind, offset, size));
+            offset += size;
+        }
+        assert_eq!(offset, data.len(), "boxes must tile the buffer");
+        out
+    }
+
+    #[test]
+    fn test_init_segment_layout() {
+        let tracks = [
+            Mp4Track {
+                id: 1,
+                timescale: 90_000,
+                codec: Mp4Codec::Avc { width: 1280, height: 720, avcc: Bytes::from_static(&[1, 0x42, 0xe0, 0x1e]) },
+            },
+            Mp4Track {
+                id: 2,
+                timescale: 48_000,
+                codec: Mp4Codec::Opus { sample_rate: 48_000, channel_count: 2 },
+            },
+        ];
+        let init = init_segment(&tracks);
+        let top: Vec<_> = boxes(&init).into_iter().map(|(kind, ..)| kind).collect();
+        assert_eq!(top, ["ftyp", "moov"]);
+
+        let find = |needle: &[u8]| init.windows(needle.len()).filter(|w| *w == needle).count();
+        assert_eq!(find(b"trak"), 2);
+        assert_eq!(find(b"trex"), 2);
+        assert_eq!(find(b"avcC"), 1);
+        assert_eq!(find(b"dOps"), 1);
+    }
+
+    #[test]
+    fn test_media_segment_data_offset() {
+        let samples = [
+            Mp4Sample { data: Bytes::from_static(b"key"), duration: 3000, keyframe: true },
+            Mp4Sample { data: Bytes::from_static(b"delta"), duration: 3000, keyframe: false },
+        ];
+        let segment = media_segment(7, 1, 90_000, &samples);
+        let top = boxes(&segment);
+        assert_eq!((top[0].0.as_str(), top[1].0.as_str()), ("moof", "mdat"));
+
+        // The trun data offset points at the first sample in the mdat
+        let trun = segment.windows(4).position(|w| w == b"trun").unwrap();
+        let offset_at = trun + 4 + 4 + 4;
+        let offset = i32::from_be_bytes(segment[offset_at..offset_at + 4].try_into().unwrap()) as usize;
+        assert_eq!(&segment[offset..offset + 8], b"keydelta");
+    }
+
+    #[test]
+    fn test_read_fragmented_file() {
+        let avcc = avcc_from_annexb(&[0, 0, 1, 0x67, 0x42, 0xe0, 0x1e, 0, 0, 1, 0x68, 0xce]).unwrap();
+        let tracks = [
+            Mp4Track {
+                id: 1,
+                timescale: 90_000,
+                codec: Mp4Codec::Avc { width: 640, height: 360, avcc: Bytes::from(avcc) },
+            },
+            Mp4Track {
+                id: 2,
+                timescale: 44_100,
+                codec: Mp4Codec::Aac { sample_rate: 44_100, channel_count: 2, config: Bytes::from_static(&[0x12, 0x10]) },
+            },
+        ];
+        let video = [
+            Mp4Sample { data: Bytes::from_static(b"idr"), duration: 3000, keyframe: true },
+            Mp4Sample { data: Bytes::from_static(b"p"), duration: 3000, keyframe: false },
+        ];
+        let audio = [Mp4Sample { data: Bytes::from_static(b"aac"), duration: 1024, keyframe: true }];
+
+        let mut file = init_segment(&tracks);
+        file.extend(media_segment(1, 1, 0, &video));
+        file.extend(media_segment(2, 2, 0, &audio));
+        file.extend(media_segment(3, 1, 6000, &video));
+        let path = std::env::temp_dir().join(format!("mp4-test-{}.mp4", std::process::id()));
+        std::fs::write(&path, &file).unwrap();
+
+        let mut reader = Mp4Reader::open(&path).unwrap();
+        let read: Vec<_> = reader.tracks().iter().map(|t| (t.id, t.codec.clone())).collect();
+        assert_eq!(read, tracks.iter().map(|t| (t.id, t.codec.clone())).collect::<Vec<_>>());
+
+        let video_track = reader.tracks()[0].clone();
+        let dts: Vec<_> = video_track.samples.iter().map(|s| (s.dts, s.keyframe)).collect();
+        assert_eq!(dts, [(0, true), (3000, false), (6000, true), (9000, false)]);
+        assert_eq!(video_track.duration_us(), 133_333);
+        assert_eq!(reader.read_sample(&video_track.samples[2]).unwrap(), Bytes::from_static(b"idr"));
+        let audio_sample = reader.tracks()[1].samples[0];
+        assert_eq!(reader.read_sample(&audio_sample).unwrap(), Bytes::from_static(b"aac"));
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_annexb_conversion() {
+        let au = [0, 0, 0, 1, 0x67, 0x42, 0xe0, 0x1e, 0, 0, 1, 0x68, 0xce, 0, 0, 0, 1, 0x65, 0x88];
+        assert!(is_annexb(&au));
+        assert_eq!(annexb_nals(&au), [&[0x67, 0x42, 0xe0, 0x1e][..], &[0x68, 0xce], &[0x65, 0x88]]);
+        assert_eq!(
+            annexb_to_avcc(&au),
+            [0, 0, 0, 4, 0x67, 0x42, 0xe0, 0x1e, 0, 0, 0, 2, 0x68, 0xce, 0, 0, 0, 2, 0x65, 0x88]
+        );
+
+        let avcc = avcc_from_annexb(&au).unwrap();
+        assert_eq!(&avcc[..6], &[1, 0x42, 0xe0, 0x1e, 0xff, 0xe1]);
+        assert!(avcc_from_annexb(&[0, 0, 1, 0x65, 0x88]).is_none());
+    }
+}
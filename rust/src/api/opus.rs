@@ -0,0 +1,739 @@
+//! Opus audio coding for the av/publish layer
+//!
+//! This module provides:
+//! - OpusConfig: Encoder settings (bitrate, FEC, DTX) and catalog configs
+//! - AudioFramer: Cuts an AudioSource into 20ms frames
+//! - SampleQueue: PCM buffer that is both an AudioSink and an AudioSource
+//! - OpusEncoder / OpusDecoder: libopus via FFmpeg (requires ffmpeg feature)
+//! - OpusTrackSink: AudioSink that publishes Opus on a broadcast's audio
+//!   track (requires ffmpeg feature)
+//!
+//! Packets are raw Opus (no Ogg framing), one 20ms frame each, which is what
+//! the hang catalog's `opus` codec describes.
+
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+
+use super::av::{AudioCatalogConfig, AudioCodec, AudioFormat, AudioFrame, AudioPreset, AudioSink, AudioSource};
+use super::publish_broadcast::AudioQuality;
+
+#[cfg(feature = "ffmpeg")]
+use anyhow::{Context, anyhow};
+#[cfg(feature = "ffmpeg")]
+use ffmpeg_next::{self as ffmpeg, ChannelLayout, codec, format::Sample, format::sample::Type};
+#[cfg(feature = "ffmpeg")]
+use tracing::{debug, info};
+
+#[cfg(feature = "ffmpeg")]
+use super::publish_broadcast::{EncodedAudioFrame, PublishHandle};
+
+/// Sample rates libopus accepts
+pub const OPUS_SAMPLE_RATES: [u32; 5] = [48_000, 24_000, 16_000, 12_000, 8_000];
+
+/// Opus encoder tuning target
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OpusApplication {
+    /// Speech, favouring intelligibility
+    Voip,
+    /// Music and mixed content
+    Audio,
+    /// Lowest algorithmic delay, no speech modes
+    LowDelay,
+}
+
+impl OpusApplication {
+    /// libopus option value
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OpusApplication::Voip => "voip",
+            OpusApplication::Audio => "audio",
+            OpusApplication::LowDelay => "lowdelay",
+        }
+    }
+}
+
+/// Opus encoder configuration
+#[derive(Debug, Clone)]
+pub struct OpusConfig {
+    /// Input format (interleaved f32)
+    pub format: AudioFormat,
+    /// Target bitrate (bps)
+    pub bitrate: u32,
+    pub application: OpusApplication,
+    /// In-band forward error correction, sized for the expected loss
+    pub fec: bool,
+    /// Expected packet loss (percent), used to size FEC
+    pub expected_loss_percent: u8,
+    /// Discontinuous transmission: send almost nothing during silence
+    pub dtx: bool,
+}
+
+impl OpusConfig {
+    /// Configuration for an audio preset
+    ///
+    /// The low quality preset is aimed at voice, so it enables FEC and DTX.
+    pub fn from_preset(preset: AudioPreset) -> Self {
+        let format = AudioFormat::new(preset.sample_rate(), preset.channel_count());
+        let bitrate = preset.bitrate_kbps() * 1000;
+        match preset {
+            AudioPreset::Hq => Self {
+                format,
+                bitrate,
+                application: OpusApplication::Audio,
+                fec: false,
+                expected_loss_percent: 0,
+                dtx: false,
+            },
+            AudioPreset::Lq => Self {
+                format,
+                bitrate,
+                application: OpusApplication::Voip,
+                fec: true,
+                expected_loss_percent: 10,
+                dtx: true,
+            },
+        }
+    }
+
+    /// Configuration for a broadcast audio rendition
+    ///
+    /// Matches the rendition's default catalog entry: 48 kHz stereo at the
+    /// quality's bitrate.
+    pub fn for_quality(quality: AudioQuality) -> Self {
+        Self {
+            format: AudioFormat::stereo_48khz(),
+            bitrate: quality.bitrate(),
+            ..Self::from_preset(AudioPreset::Hq)
+        }
+    }
+
+    /// Enable FEC for the given expected loss (0 disables it)
+    pub fn with_fec(mut self, expected_loss_percent: u8) -> Self {
+        self.fec = expected_loss_percent > 0;
+        self.expected_loss_percent = expected_loss_percent.min(100);
+        self
+    }
+
+    /// Enable or disable DTX
+    pub fn with_dtx(mut self, dtx: bool) -> Self {
+        self.dtx = dtx;
+        self
+    }
+
+    /// Check the settings are ones libopus supports
+    pub fn validate(&self) -> Result<()> {
+        if !OPUS_SAMPLE_RATES.contains(&self.format.sample_rate) {
+            bail!("opus does not support {} Hz", self.format.sample_rate);
+        }
+        if !(1..=2).contains(&self.format.channel_count) {
+            bail!("opus encoder supports mono or stereo, got {} channels", self.format.channel_count);
+        }
+        if !(6_000..=510_000).contains(&self.bitrate) {
+            bail!("opus bitrate out of range: {}", self.bitrate);
+        }
+        Ok(())
+    }
+
+    /// Interleaved samples in one 20ms frame
+    pub fn samples_per_frame(&self) -> usize {
+        (self.format.samples_per_20ms() * self.format.channel_count) as usize
+    }
+
+    /// Catalog entry for the av layer
+    pub fn catalog_config(&self) -> AudioCatalogConfig {
+        AudioCatalogConfig {
+            codec: AudioCodec::Opus,
+            sample_rate: self.format.sample_rate,
+            channel_count: self.format.channel_count,
+            bitrate: Some(self.bitrate),
+        }
+    }
+
+    /// Catalog entry for a hang broadcast
+    ///
+    /// Raw Opus packets need no description.
+    pub fn audio_config(&self) -> hang::catalog::AudioConfig {
+        hang::catalog::AudioConfig {
+            codec: hang::catalog::AudioCodec::Opus,
+            sample_rate: self.format.sample_rate,
+            channel_count: self.format.channel_count,
+            bitrate: Some(self.bitrate as u64),
+            description: None,
+        }
+    }
+
+    /// libopus encoder options
+    pub fn encoder_options(&self) -> Vec<(&'static str, String)> {
+        vec![
+            ("application", self.application.as_str().to_string()),
+            ("frame_duration", "20".to_string()),
+            ("vbr", "on".to_string()),
+            ("fec", u8::from(self.fec).to_string()),
+            ("packet_loss", self.expected_loss_percent.to_string()),
+            ("dtx", u8::from(self.dtx).to_string()),
+        ]
+    }
+}
+
+impl Default for OpusConfig {
+    fn default() -> Self {
+        Self::from_preset(AudioPreset::Hq)
+    }
+}
+
+/// Collects samples from an [`AudioSource`] into 20ms frames
+pub struct AudioFramer {
+    format: AudioFormat,
+    buf: Vec<f32>,
+    filled: usize,
+    /// Samples per channel handed out so far
+    position: u64,
+}
+
+impl AudioFramer {
+    /// Create a framer for the given format
+    pub fn new(format: AudioFormat) -> Self {
+        let len = (format.samples_per_20ms() * format.channel_count) as usize;
+        Self {
+            format,
+            buf: vec![0.0; len],
+            filled: 0,
+            position: 0,
+        }
+    }
+
+    /// Pull from `source` until a full frame is ready
+    ///
+    /// Returns None when the source has nothing more right now; partial
+    /// frames are kept for the next call.
+    pub fn pull(&mut self, source: &mut dyn AudioSource) -> Result<Option<AudioFrame>> {
+        let source_format = source.format();
+        if source_format.sample_rate != self.format.sample_rate
+            || source_format.channel_count != self.format.channel_count
+        {
+            bail!(
+                "audio source is {} Hz x{}, encoder expects {} Hz x{}",
+                source_format.sample_rate,
+                source_format.channel_count,
+                self.format.sample_rate,
+                self.format.channel_count
+            );
+        }
+
+        while self.filled < self.buf.len() {
+            match source.pop_samples(&mut self.buf[self.filled..])? {
+                Some(n) if n > 0 => self.filled += n.min(self.buf.len() - self.filled),
+                _ => return Ok(None),
+            }
+        }
+
+        let timestamp =
+            Duration::from_micros(self.position * 1_000_000 / self.format.sample_rate as u64);
+        self.position += self.format.samples_per_20ms() as u64;
+        self.filled = 0;
+        Ok(Some(AudioFrame::new(self.format, self.buf.clone(), timestamp)))
+    }
+}
+
+/// Queue of interleaved PCM between two audio stages
+///
+/// Capture pushes samples in as an [`AudioSink`] and an encoder pulls them
+/// out as an [`AudioSource`], or a decoder pushes and playback pulls.
+/// Clones share the same queue.
+#[derive(Clone)]
+pub struct SampleQueue {
+    format: AudioFormat,
+    samples: Arc<Mutex<VecDeque<f32>>>,
+}
+
+impl SampleQueue {
+    /// Create an empty queue for `format`
+    pub fn new(format: AudioFormat) -> Self {
+        Self {
+            format,
+            samples: Arc::default(),
+        }
+    }
+
+    /// Interleaved samples waiting in the queue
+    pub fn len(&self) -> usize {
+        self.samples.lock().expect("poisoned").len()
+    }
+
+    /// Whether the queue is empty
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl AudioSource for SampleQueue {
+    fn format(&self) -> AudioFormat {
+        self.format
+    }
+
+    fn pop_samples(&mut self, buf: &mut [f32]) -> Result<Option<usize>> {
+        let mut samples = self.samples.lock().expect("poisoned");
+        let n = buf.len().min(samples.len());
+        if n == 0 {
+            return Ok(None);
+        }
+        for (dst, sample) in buf.iter_mut().zip(samples.drain(..n)) {
+            *dst = sample;
+        }
+        Ok(Some(n))
+    }
+
+    fn cloned_boxed(&self) -> Box<dyn AudioSource> {
+        Box::new(self.clone())
+    }
+}
+
+impl AudioSink for SampleQueue {
+    fn format(&self) -> Result<AudioFormat> {
+        Ok(self.format)
+    }
+
+    fn push_samples(&mut self, samples: &[f32]) -> Result<()> {
+        self.samples.lock().expect("poisoned").extend(samples);
+        Ok(())
+    }
+}
+
+/// Opus encoder backed by libopus
+#[cfg(feature = "ffmpeg")]
+pub struct OpusEncoder {
+    encoder: ffmpeg::encoder::audio::Encoder,
+    config: OpusConfig,
+    framer: AudioFramer,
+    layout: ChannelLayout,
+}
+
+// Make encoder Send safe
+#[cfg(feature = "ffmpeg")]
+unsafe impl Send for OpusEncoder {}
+
+#[cfg(feature = "ffmpeg")]
+impl OpusEncoder {
+    /// Create an encoder
+    pub fn new(config: OpusConfig) -> Result<Self> {
+        config.validate()?;
+        info!(
+            "Initializing Opus encoder: {} Hz x{} @ {} bps (fec={}, dtx={})",
+            config.format.sample_rate,
+            config.format.channel_count,
+            config.bitrate,
+            config.fec,
+            config.dtx
+        );
+        ffmpeg::init()?;
+
+        let codec = ffmpeg::codec::encoder::find_by_name("libopus")
+            .context("libopus encoder not found")?;
+        let layout = ChannelLayout::default(config.format.channel_count as i32);
+
+        let mut ctx = codec::context::Context::new_with_codec(codec).encoder().audio()?;
+        ctx.set_rate(config.format.sample_rate as i32);
+        ctx.set_channel_layout(layout);
+        ctx.set_format(Sample::F32(Type::Packed));
+        ctx.set_bit_rate(config.bitrate as usize);
+        ctx.set_time_base((1, config.format.sample_rate as i32));
+
+        let options = config.encoder_options();
+        let options = ffmpeg::Dictionary::from_iter(
+            options.iter().map(|(key, value)| (*key, value.as_str())),
+        );
+        let encoder = ctx.open_as_with(codec, options)?;
+        debug!("Opus encoder frame size: {}", encoder.frame_size());
+
+        Ok(Self {
+            encoder,
+            framer: AudioFramer::new(config.format),
+            config,
+            layout,
+        })
+    }
+
+    /// Create an encoder for an audio preset
+    pub fn with_preset(preset: AudioPreset) -> Result<Self> {
+        Self::new(OpusConfig::from_preset(preset))
+    }
+
+    /// Encoder configuration
+    pub fn config(&self) -> &OpusConfig {
+        &self.config
+    }
+
+    /// Get audio config for hang catalog
+    pub fn audio_config(&self) -> hang::catalog::AudioConfig {
+        self.config.audio_config()
+    }
+
+    /// Encode one 20ms frame of interleaved samples
+    ///
+    /// Returns the packets the encoder produced, usually one.
+    pub fn encode(&mut self, frame: &AudioFrame) -> Result<Vec<hang::Frame>> {
+        if frame.samples.len() != self.config.samples_per_frame() {
+            bail!(
+                "opus needs 20ms frames ({} samples), got {}",
+                self.config.samples_per_frame(),
+                frame.samples.len()
+            );
+        }
+
+        let rate = self.config.format.sample_rate;
+        let mut input = ffmpeg::frame::Audio::new(
+            Sample::F32(Type::Packed),
+            frame.sample_count(),
+            self.layout,
+        );
+        input.set_rate(rate);
+        input.set_pts(Some((frame.timestamp.as_micros() as u64 * rate as u64 / 1_000_000) as i64));
+        for (dst, sample) in input.data_mut(0).chunks_exact_mut(4).zip(&frame.samples) {
+            dst.copy_from_slice(&sample.to_ne_bytes());
+        }
+
+        self.encoder
+            .send_frame(&input)
+            .map_err(|e| anyhow!("send_frame failed: {e:?}"))?;
+        self.receive_packets()
+    }
+
+    /// Encode every full frame the source has available
+    pub fn encode_from(&mut self, source: &mut dyn AudioSource) -> Result<Vec<hang::Frame>> {
+        let mut packets = Vec::new();
+        while let Some(frame) = self.framer.pull(source)? {
+            packets.extend(self.encode(&frame)?);
+        }
+        Ok(packets)
+    }
+
+    /// Flush buffered audio at the end of the stream
+    pub fn flush(&mut self) -> Result<Vec<hang::Frame>> {
+        self.encoder.send_eof()?;
+        self.receive_packets()
+    }
+
+    fn receive_packets(&mut self) -> Result<Vec<hang::Frame>> {
+        let rate = self.config.format.sample_rate as u64;
+        let mut packets = Vec::new();
+        loop {
+            let mut packet = ffmpeg::packet::Packet::empty();
+            match self.encoder.receive_packet(&mut packet) {
+                Ok(()) => {
+                    let pts = packet.pts().unwrap_or(0).max(0) as u64;
+                    packets.push(hang::Frame {
+                        payload: packet.data().unwrap_or(&[]).to_vec().into(),
+                        timestamp: Duration::from_micros(pts * 1_000_000 / rate),
+                        keyframe: true,
+                    });
+                }
+                Err(ffmpeg::Error::Eof) => break,
+                Err(ffmpeg::Error::Other { errno }) if errno == ffmpeg::util::error::EAGAIN => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(packets)
+    }
+}
+
+/// Publishes pushed PCM as Opus on a broadcast's audio rendition
+///
+/// Creating it replaces the rendition's catalog entry with the encoder's
+/// config, so subscribers see the format and bitrate actually sent.
+#[cfg(feature = "ffmpeg")]
+pub struct OpusTrackSink {
+    encoder: OpusEncoder,
+    queue: SampleQueue,
+    handle: PublishHandle,
+    quality: AudioQuality,
+    /// Timestamp (µs) of the first sample pushed
+    origin_us: Option<i64>,
+}
+
+#[cfg(feature = "ffmpeg")]
+impl OpusTrackSink {
+    /// Encode to `quality`'s track of the broadcast behind `handle`
+    pub fn new(handle: PublishHandle, quality: AudioQuality, config: OpusConfig) -> Result<Self> {
+        let encoder = OpusEncoder::new(config)?;
+        handle.set_audio_config(quality, encoder.audio_config())?;
+        let queue = SampleQueue::new(encoder.config().format);
+        Ok(Self {
+            encoder,
+            queue,
+            handle,
+            quality,
+            origin_us: None,
+        })
+    }
+
+    /// Push samples captured at `timestamp_us`
+    ///
+    /// Only the first call's timestamp is used: later packets are timed by
+    /// the samples encoded since, so pushes must not leave gaps.
+    pub fn push_at(&mut self, format: AudioFormat, samples: &[f32], timestamp_us: i64) -> Result<()> {
+        let expected = self.queue.format;
+        if format.sample_rate != expected.sample_rate || format.channel_count != expected.channel_count {
+            bail!(
+                "audio is {} Hz x{}, encoder expects {} Hz x{}",
+                format.sample_rate,
+                format.channel_count,
+                expected.sample_rate,
+                expected.channel_count
+            );
+        }
+        self.origin_us.get_or_insert(timestamp_us);
+        self.push_samples(samples)
+    }
+}
+
+#[cfg(feature = "ffmpeg")]
+impl AudioSink for OpusTrackSink {
+    fn format(&self) -> Result<AudioFormat> {
+        Ok(self.queue.format)
+    }
+
+    /// Queue samples and publish every complete 20ms frame
+    fn push_samples(&mut self, samples: &[f32]) -> Result<()> {
+        self.queue.push_samples(samples)?;
+        let origin_us = *self.origin_us.get_or_insert(0);
+        for packet in self.encoder.encode_from(&mut self.queue)? {
+            self.handle.try_push_audio(EncodedAudioFrame {
+                data: packet.payload,
+                pts_us: origin_us + packet.timestamp.as_micros() as i64,
+                quality: self.quality,
+            })?;
+        }
+        Ok(())
+    }
+}
+
+/// Opus decoder backed by FFmpeg
+#[cfg(feature = "ffmpeg")]
+pub struct OpusDecoder {
+    decoder: ffmpeg::decoder::Audio,
+    format: AudioFormat,
+}
+
+// Make decoder Send safe
+#[cfg(feature = "ffmpeg")]
+unsafe impl Send for OpusDecoder {}
+
+#[cfg(feature = "ffmpeg")]
+impl OpusDecoder {
+    /// Create a decoder producing interleaved f32 in `format`
+    pub fn new(format: AudioFormat) -> Result<Self> {
+        ffmpeg::init()?;
+        let codec = ffmpeg::codec::decoder::find_by_name("libopus")
+            .or_else(|| ffmpeg::codec::decoder::find(codec::Id::OPUS))
+            .context("opus decoder not found")?;
+
+        let mut ctx = codec::context::Context::new_with_codec(codec);
+        unsafe {
+            let ctx_mut = ctx.as_mut_ptr();
+            (*ctx_mut).sample_rate = format.sample_rate as i32;
+            ffmpeg::ffi::av_channel_layout_default(
+                &mut (*ctx_mut).ch_layout,
+                format.channel_count as i32,
+            );
+        }
+        let decoder = ctx.decoder().audio()?;
+        debug!("Opus decoder: {} ({} Hz x{})", codec.name(), format.sample_rate, format.channel_count);
+
+        Ok(Self { decoder, format })
+    }
+
+    /// Create a decoder matching a catalog entry
+    pub fn from_catalog(config: &AudioCatalogConfig) -> Result<Self> {
+        if config.codec != AudioCodec::Opus {
+            bail!("not an opus rendition: {}", config.codec.name());
+        }
+        Self::new(AudioFormat::new(config.sample_rate, config.channel_count))
+    }
+
+    /// Output format
+    pub fn format(&self) -> AudioFormat {
+        self.format
+    }
+
+    /// Decode one packet into interleaved f32 samples
+    pub fn decode(&mut self, payload: &[u8]) -> Result<Vec<f32>> {
+        let packet = ffmpeg::packet::Packet::copy(payload);
+        self.decoder
+            .send_packet(&packet)
+            .map_err(|e| anyhow!("send_packet failed: {e:?}"))?;
+
+        let mut samples = Vec::new();
+        let mut frame = ffmpeg::frame::Audio::empty();
+        loop {
+            match self.decoder.receive_frame(&mut frame) {
+                Ok(()) => interleave(&frame, &mut samples)?,
+                Err(ffmpeg::Error::Eof) => break,
+                Err(ffmpeg::Error::Other { errno }) if errno == ffmpeg::util::error::EAGAIN => break,
+                Err(e) => return Err(e.into()),
+            }
+        }
+        Ok(samples)
+    }
+
+    /// Decode one packet and push the samples to `sink`
+    pub fn decode_into(&mut self, payload: &[u8], sink: &mut dyn AudioSink) -> Result<()> {
+        let sink_format = sink.format()?;
+        if sink_format.sample_rate != self.format.sample_rate
+            || sink_format.channel_count != self.format.channel_count
+        {
+            bail!(
+                "audio sink is {} Hz x{}, decoder produces {} Hz x{}",
+                sink_format.sample_rate,
+                sink_format.channel_count,
+                self.format.sample_rate,
+                self.format.channel_count
+            );
+        }
+        let samples = self.decode(payload)?;
+        if !samples.is_empty() {
+            sink.push_samples(&samples)?;
+        }
+        Ok(())
+    }
+}
+
+/// Append a decoded frame to `out` as interleaved f32
+#[cfg(feature = "ffmpeg")]
+fn interleave(frame: &ffmpeg::frame::Audio, out: &mut Vec<f32>) -> Result<()> {
+    let channels = frame.channels() as usize;
+    let samples = frame.samples();
+
+    // FFmpeg only sets linesize[0] for audio, so read planes through raw pointers
+    fn plane<T: Copy>(frame: &ffmpeg::frame::Audio, index: usize, len: usize) -> &[T] {
+        unsafe { std::slice::from_raw_parts((*frame.as_ptr()).data[index] as *const T, len) }
+    }
+
+    match frame.format() {
+        Sample::F32(Type::Packed) => {
+            out.extend_from_slice(plane::<f32>(frame, 0, samples * channels));
+        }
+        Sample::I16(Type::Packed) => {
+            out.extend(plane::<i16>(frame, 0, samples * channels).iter().map(|&s| s as f32 / 32768.0));
+        }
+        Sample::F32(Type::Planar) => {
+            let planes: Vec<&[f32]> = (0..channels).map(|c| plane(frame, c, samples)).collect();
+            for i in 0..samples {
+                out.extend(planes.iter().map(|p| p[i]));
+            }
+        }
+        Sample::I16(Type::Planar) => {
+            let planes: Vec<&[i16]> = (0..channels).map(|c| plane(frame, c, samples)).collect();
+            for i in 0..samples {
+                out.extend(planes.iter().map(|p| p[i] as f32 / 32768.0));
+            }
+        }
+        other => bail!("unsupported decoded sample format: {other:?}"),
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Source that hands out a fixed number of samples per call
+    #[derive(Clone)]
+    struct TestSource {
+        format: AudioFormat,
+        chunk: usize,
+        remaining: usize,
+    }
+
+    impl AudioSource for TestSource {
+        fn format(&self) -> AudioFormat {
+            self.format
+        }
+
+        fn pop_samples(&mut self, buf: &mut [f32]) -> Result<Option<usize>> {
+            let n = self.chunk.min(buf.len()).min(self.remaining);
+            if n == 0 {
+                return Ok(None);
+            }
+            buf[..n].fill(0.5);
+            self.remaining -= n;
+            Ok(Some(n))
+        }
+
+        fn cloned_boxed(&self) -> Box<dyn AudioSource> {
+            Box::new(self.clone())
+        }
+    }
+
+    #[test]
+    fn test_framer_cuts_20ms_frames() {
+        let format = AudioFormat::stereo_48khz();
+        // 2.5 frames delivered in 10ms chunks
+        let mut source = TestSource { format, chunk: 960, remaining: 960 * 5 };
+        let mut framer = AudioFramer::new(format);
+
+        let first = framer.pull(&mut source).unwrap().unwrap();
+        assert_eq!(first.samples.len(), 1920);
+        assert_eq!(first.timestamp, Duration::ZERO);
+        let second = framer.pull(&mut source).unwrap().unwrap();
+        assert_eq!(second.timestamp, Duration::from_millis(20));
+        // Half a frame stays buffered
+        assert!(framer.pull(&mut source).unwrap().is_none());
+
+        source.remaining = 960;
+        let third = framer.pull(&mut source).unwrap().unwrap();
+        assert_eq!(third.timestamp, Duration::from_millis(40));
+    }
+
+    #[test]
+    fn test_framer_rejects_format_mismatch() {
+        let mut source = TestSource { format: AudioFormat::mono_16khz(), chunk: 320, remaining: 320 };
+        let mut framer = AudioFramer::new(AudioFormat::stereo_48khz());
+        assert!(framer.pull(&mut source).is_err());
+    }
+
+    #[test]
+    fn test_sample_queue_feeds_framer() {
+        let format = AudioFormat::mono_16khz();
+        let mut queue = SampleQueue::new(format);
+        let mut framer = AudioFramer::new(format);
+
+        // Decoder or capture side pushes, the clone is pulled from
+        let mut sink = queue.clone();
+        sink.push_samples(&[0.25; 200]).unwrap();
+        assert!(framer.pull(&mut queue).unwrap().is_none());
+        sink.push_samples(&[0.25; 200]).unwrap();
+        let frame = framer.pull(&mut queue).unwrap().unwrap();
+        assert_eq!(frame.samples.len(), 320);
+        assert_eq!(queue.len(), 80);
+
+        let mut buf = [0.0; 100];
+        assert_eq!(queue.pop_samples(&mut buf).unwrap(), Some(80));
+        assert_eq!(queue.pop_samples(&mut buf).unwrap(), None);
+    }
+
+    #[test]
+    fn test_opus_config() {
+        let voice = OpusConfig::from_preset(AudioPreset::Lq);
+        assert!(voice.fec && voice.dtx);
+        assert_eq!(voice.samples_per_frame(), 960);
+        assert!(voice.encoder_options().contains(&("packet_loss", "10".to_string())));
+
+        let music = OpusConfig::default().with_fec(5).with_dtx(true);
+        assert!(music.encoder_options().contains(&("fec", "1".to_string())));
+        assert_eq!(music.audio_config().codec, hang::catalog::AudioCodec::Opus);
+        assert_eq!(music.catalog_config().bitrate, Some(96_000));
+
+        let medium = OpusConfig::for_quality(AudioQuality::Medium);
+        assert!(medium.validate().is_ok());
+        assert_eq!(medium.audio_config(), AudioQuality::Medium.catalog_config());
+
+        let bad = OpusConfig {
+            format: AudioFormat::new(44_100, 2),
+            ..Default::default()
+        };
+        assert!(bad.validate().is_err());
+    }
+}
@@ -0,0 +1,175 @@
+//! Playout timing for subscribed media
+//!
+//! This module provides:
+//! - PlayoutClock: Maps presentation timestamps to wall-clock play times
+//! - VideoPlayout: Whether to render a video frame, and when
+//!
+//! Audio is the master: the first audio frame anchors the clock
+//! `target_latency` in the future, and audio that drifts outside the jitter
+//! window re-anchors it. Video is scheduled against that clock and dropped
+//! once it is too late to show. Until audio arrives, video anchors the clock
+//! so video-only broadcasts still play.
+
+use n0_future::time::{Duration, Instant};
+use tracing::debug;
+
+/// How late a video frame may be and still be rendered
+pub const VIDEO_LATE_TOLERANCE: Duration = Duration::from_millis(40);
+
+/// What to do with a received video frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VideoPlayout {
+    /// Render the frame at this time (may already have passed, within tolerance)
+    Render(Instant),
+    /// The frame is too late; skip it
+    Drop {
+        /// How far past its play time the frame arrived
+        late_by: Duration,
+    },
+}
+
+/// Presentation-time to wall-clock mapping, driven by audio
+#[derive(Debug, Clone)]
+pub struct PlayoutClock {
+    target_latency: Duration,
+    /// Media time (µs) and the instant it plays
+    anchor: Option<(i64, Instant)>,
+    /// Whether audio set the anchor
+    audio_anchored: bool,
+}
+
+impl PlayoutClock {
+    /// Create a clock that plays media `target_latency` after it arrives
+    pub fn new(target_latency: Duration) -> Self {
+        Self {
+            target_latency,
+            anchor: None,
+            audio_anchored: false,
+        }
+    }
+
+    /// Target latency between arrival and playout
+    pub fn target_latency(&self) -> Duration {
+        self.target_latency
+    }
+
+    /// When media with presentation time `pts_us` plays, if anchored
+    pub fn play_time(&self, pts_us: i64) -> Option<Instant> {
+        let (anchor_pts, anchor_at) = self.anchor?;
+        let offset = pts_us - anchor_pts;
+        Some(if offset >= 0 {
+            anchor_at + Duration::from_micros(offset as u64)
+        } else {
+            anchor_at
+                .checked_sub(Duration::from_micros(offset.unsigned_abs()))
+                .unwrap_or(anchor_at)
+        })
+    }
+
+    fn anchor_at(&mut self, pts_us: i64, now: Instant) -> Instant {
+        let at = now + self.target_latency;
+        self.anchor = Some((pts_us, at));
+        at
+    }
+
+    /// Schedule an audio frame, returning when it should play
+    ///
+    /// Audio arriving after its play time (underrun) or more than twice the
+    /// target latency early (sender clock drift, burst after a stall)
+    /// re-anchors the clock.
+    pub fn audio(&mut self, pts_us: i64, now: Instant) -> Instant {
+        if !self.audio_anchored {
+            self.audio_anchored = true;
+            return self.anchor_at(pts_us, now);
+        }
+
+        let at = self.play_time(pts_us).unwrap_or(now);
+        if at < now || at > now + self.target_latency * 2 {
+            debug!(pts_us, "audio outside jitter window, re-anchoring playout");
+            return self.anchor_at(pts_us, now);
+        }
+        at
+    }
+
+    /// Decide when to render a video frame
+    ///
+    /// Keyframes are never dropped, since every following frame in the
+    /// group depends on them.
+    pub fn video(&mut self, pts_us: i64, is_keyframe: bool, now: Instant) -> VideoPlayout {
+        let at = match self.play_time(pts_us) {
+            Some(at) => at,
+            None => self.anchor_at(pts_us, now),
+        };
+
+        if !is_keyframe && now > at + VIDEO_LATE_TOLERANCE {
+            return VideoPlayout::Drop { late_by: now - at };
+        }
+        // Without audio, video is the master and a late keyframe resyncs
+        if !self.audio_anchored && now > at + VIDEO_LATE_TOLERANCE {
+            return VideoPlayout::Render(self.anchor_at(pts_us, now));
+        }
+        VideoPlayout::Render(at)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATENCY: Duration = Duration::from_millis(100);
+
+    fn ms(ms: u64) -> Duration {
+        Duration::from_millis(ms)
+    }
+
+    #[test]
+    fn test_audio_anchors_and_schedules_video() {
+        let mut clock = PlayoutClock::new(LATENCY);
+        let start = Instant::now();
+
+        assert_eq!(clock.audio(1_000_000, start), start + LATENCY);
+        assert_eq!(clock.audio(1_020_000, start + ms(5)), start + LATENCY + ms(20));
+        assert_eq!(
+            clock.video(1_033_000, false, start + ms(10)),
+            VideoPlayout::Render(start + LATENCY + ms(33))
+        );
+
+        // Video more than the tolerance behind the audio clock is dropped
+        assert_eq!(
+            clock.video(1_000_000, false, start + ms(150)),
+            VideoPlayout::Drop { late_by: ms(50) }
+        );
+        // ...unless it is a keyframe
+        assert_eq!(
+            clock.video(1_000_000, true, start + ms(150)),
+            VideoPlayout::Render(start + LATENCY)
+        );
+    }
+
+    #[test]
+    fn test_late_audio_reanchors() {
+        let mut clock = PlayoutClock::new(LATENCY);
+        let start = Instant::now();
+        clock.audio(0, start);
+
+        // A 300ms stall: audio for 20ms arrives well after its play time
+        let now = start + ms(300);
+        assert_eq!(clock.audio(20_000, now), now + LATENCY);
+        assert_eq!(clock.play_time(40_000), Some(now + LATENCY + ms(20)));
+    }
+
+    #[test]
+    fn test_audio_takes_over_from_video() {
+        let mut clock = PlayoutClock::new(LATENCY);
+        let start = Instant::now();
+
+        assert_eq!(clock.video(0, true, start), VideoPlayout::Render(start + LATENCY));
+        // Audio starts later with its own timeline and becomes the master
+        let now = start + ms(50);
+        assert_eq!(clock.audio(500_000, now), now + LATENCY);
+        assert_eq!(
+            clock.video(533_000, false, now),
+            VideoPlayout::Render(now + LATENCY + ms(33))
+        );
+    }
+}
@@ -236,6 +236,8 @@ impl SimpleVideoEncoder {
 // ============================================================================
 
 /// Simple pass-through "encoder" for audio
+///
+/// Packs 16-bit PCM. Real Opus encoding is `opus::OpusEncoder` (ffmpeg feature).
 pub struct SimpleAudioEncoder {
     preset: AudioPreset,
     codec: AudioCodec,
@@ -4,15 +4,65 @@
 //! - VideoRenditions: Multi-quality video tracks
 //! - AudioRenditions: Multi-quality audio tracks  
 //! - PublishBroadcast: Orchestrates media encoding and MoQ transmission
+//!
+//! Every broadcast carries a hang `catalog.json` track describing its
+//! renditions, so subscribers don't have to guess track names. Media frames
+//! use hang's framing (a varint timestamp ahead of the payload) so
+//! subscribers can schedule playout.
+//!
+//! When the publisher falls behind, video writers shed load in steps:
+//! non-reference frames go first, then the current group is closed early
+//! and frames are skipped until the next keyframe. Falling behind means
+//! frames piling up in the command queue, or viewers still waiting on
+//! groups the publisher has finished (see [`SendBacklog`]).
+//!
+//! Video renditions can be added and removed while the broadcast runs. The
+//! catalog lists a new rendition before its first frame and drops a removed
+//! one before its track is closed.
+//!
+//! With `PublishConfig::encryption` set, frame payloads are sealed with the
+//! broadcast's media key, rotating at every group (see [`super::encryption`]).
+//!
+//! With `PublishConfig::chat` set, the broadcast also carries a `chat` track
+//! (see [`super::chat`]); posts from the publisher and, through
+//! [`PublishHandle::chat_inbox`], from viewers are written to it in order.
+//!
+//! [`PublishHandle::stop`] ends the broadcast cleanly: open groups are
+//! closed and every track is finished, which subscribers see as the end of
+//! the broadcast rather than a lost connection.
 
-use std::time::Instant;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
 
 use anyhow::Result;
-use moq_lite::{BroadcastProducer, Broadcast, Track, TrackProducer, GroupProducer};
-use tokio::sync::mpsc;
+use hang::catalog::{Audio, AudioCodec, AudioConfig, Video, VideoCodec, VideoConfig, H264};
+use hang::{Catalog, CatalogProducer};
+use moq_lite::{BroadcastProducer, Broadcast, Track, TrackProducer};
+use tokio::sync::{broadcast, mpsc, oneshot, watch};
 use tracing::{debug, info};
 use bytes::Bytes;
 
+use super::chat::{CHAT_TRACK, ChatInbox, ChatMessage, ChatPost, ChatWriter};
+use super::encryption::{FrameEncryptor, MediaKey};
+use super::roster::SendBacklog;
+
+/// Audio frames per group
+pub const AUDIO_GROUP_FRAMES: u64 = 20;
+
+/// How long an audio group lasts with 20ms Opus frames
+///
+/// Subscribers read audio a group at a time, so they must let it fall at
+/// least this far behind before skipping ahead.
+pub const AUDIO_GROUP_DURATION: Duration = Duration::from_millis(20 * AUDIO_GROUP_FRAMES);
+
+/// Chat posts queued for the publisher
+const CHAT_QUEUE: usize = 64;
+
+/// Chat messages buffered for slow listeners
+const CHAT_HISTORY: usize = 256;
+
 /// Video quality level
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum VideoQuality {
@@ -43,6 +93,37 @@ impl VideoQuality {
         }
     }
 
+    /// Get the width for this quality level (16:9)
+    pub fn width(&self) -> u32 {
+        match self {
+            VideoQuality::Low => 854,
+            VideoQuality::Medium => 1280,
+            VideoQuality::High => 1920,
+        }
+    }
+
+    /// Frame size for this quality when scaling a `width` x `height` source
+    ///
+    /// The quality's height applies to the source's shorter side, so portrait
+    /// sources stay portrait; the longer side keeps the source's aspect ratio,
+    /// rounded to an even number for 4:2:0 chroma. Sources smaller than the
+    /// quality aren't scaled up.
+    pub fn dimensions_for(&self, width: u32, height: u32) -> (u32, u32) {
+        if width == 0 || height == 0 {
+            return (self.width(), self.height());
+        }
+        let short = (self.height().min(width.min(height)) & !1) as u64;
+        let scale = |long: u32, short_side: u32| {
+            let scaled = (long as u64 * short + short_side as u64 / 2) / short_side as u64;
+            (((scaled + 1) & !1) as u32).min(long & !1)
+        };
+        if width >= height {
+            (scale(width, height), short as u32)
+        } else {
+            (short as u32, scale(height, width))
+        }
+    }
+
     /// Get the track name suffix
     pub fn suffix(&self) -> &'static str {
         match self {
@@ -51,6 +132,43 @@ impl VideoQuality {
             VideoQuality::High => "high",
         }
     }
+
+    /// Parse a track name suffix
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "low" => Some(VideoQuality::Low),
+            "med" => Some(VideoQuality::Medium),
+            "high" => Some(VideoQuality::High),
+            _ => None,
+        }
+    }
+
+    /// Name of the track carrying this quality
+    pub fn track_name(&self) -> String {
+        format!("video.{}", self.suffix())
+    }
+
+    /// Default catalog entry for this quality
+    ///
+    /// Carries no avcC description, so decoders rely on in-band SPS/PPS
+    /// until the encoder's real config is set.
+    pub fn catalog_config(&self) -> VideoConfig {
+        VideoConfig {
+            codec: VideoCodec::H264(H264 {
+                profile: 0x42, // Baseline
+                constraints: 0xE0,
+                level: 0x1E, // Level 3.0
+            }),
+            description: None,
+            coded_width: Some(self.width()),
+            coded_height: Some(self.height()),
+            display_ratio_width: None,
+            display_ratio_height: None,
+            bitrate: Some(self.bitrate() as u64),
+            framerate: Some(30.0),
+            optimize_for_latency: Some(true),
+        }
+    }
 }
 
 /// Audio quality level
@@ -82,6 +200,62 @@ impl AudioQuality {
             AudioQuality::High => "high",
         }
     }
+
+    /// Parse a track name suffix
+    pub fn from_suffix(suffix: &str) -> Option<Self> {
+        match suffix {
+            "low" => Some(AudioQuality::Low),
+            "med" => Some(AudioQuality::Medium),
+            "high" => Some(AudioQuality::High),
+            _ => None,
+        }
+    }
+
+    /// Name of the track carrying this quality
+    pub fn track_name(&self) -> String {
+        format!("audio.{}", self.suffix())
+    }
+
+    /// Default catalog entry for this quality (48 kHz stereo Opus)
+    pub fn catalog_config(&self) -> AudioConfig {
+        AudioConfig {
+            codec: AudioCodec::Opus,
+            sample_rate: 48_000,
+            channel_count: 2,
+            bitrate: Some(self.bitrate() as u64),
+            description: None,
+        }
+    }
+}
+
+/// Build a catalog describing the given renditions
+pub fn catalog_for(video: &[VideoQuality], audio: &[AudioQuality]) -> Catalog {
+    let video = (!video.is_empty()).then(|| Video {
+        renditions: video
+            .iter()
+            .map(|q| (q.track_name(), q.catalog_config()))
+            .collect::<HashMap<_, _>>(),
+        priority: 1,
+        display: None,
+        rotation: None,
+        flip: None,
+        detection: None,
+    });
+    let audio = (!audio.is_empty()).then(|| Audio {
+        renditions: audio
+            .iter()
+            .map(|q| (q.track_name(), q.catalog_config()))
+            .collect::<HashMap<_, _>>(),
+        priority: 2,
+        captions: None,
+        speaking: None,
+    });
+
+    Catalog {
+        video,
+        audio,
+        ..Default::default()
+    }
 }
 
 /// Encoded video frame ready for transmission
@@ -108,31 +282,216 @@ pub struct EncodedAudioFrame {
     pub quality: AudioQuality,
 }
 
+/// How hard the publisher is shedding load
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CongestionLevel {
+    /// Write every frame
+    Clear,
+    /// Drop frames no other frame references
+    DropNonReference,
+    /// Close the current group and skip to the next keyframe
+    SkipToKeyframe,
+}
+
+/// Publisher congestion policy
+#[derive(Debug, Clone)]
+pub struct CongestionConfig {
+    /// Capacity of the frame queue between the handle and the publisher
+    pub queue_capacity: usize,
+    /// Queued commands at which non-reference frames are dropped
+    pub drop_non_reference_depth: usize,
+    /// Queued commands at which writers skip to the next keyframe
+    pub skip_to_keyframe_depth: usize,
+    /// Send backlog at which non-reference frames are dropped
+    pub drop_non_reference_lag: Duration,
+    /// Send backlog at which writers skip to the next keyframe
+    pub skip_to_keyframe_lag: Duration,
+    /// Ask the encoder for an immediate keyframe after cutting a group
+    pub request_keyframe: bool,
+}
+
+impl Default for CongestionConfig {
+    fn default() -> Self {
+        Self {
+            queue_capacity: 256,
+            drop_non_reference_depth: 64,
+            skip_to_keyframe_depth: 160,
+            drop_non_reference_lag: Duration::from_millis(500),
+            skip_to_keyframe_lag: Duration::from_secs(2),
+            request_keyframe: true,
+        }
+    }
+}
+
+impl CongestionConfig {
+    /// Congestion level for a given queue depth
+    pub fn level(&self, queued: usize) -> CongestionLevel {
+        if queued >= self.skip_to_keyframe_depth {
+            CongestionLevel::SkipToKeyframe
+        } else if queued >= self.drop_non_reference_depth {
+            CongestionLevel::DropNonReference
+        } else {
+            CongestionLevel::Clear
+        }
+    }
+
+    /// Congestion level for a given send backlog
+    pub fn lag_level(&self, lag: Duration) -> CongestionLevel {
+        if lag >= self.skip_to_keyframe_lag {
+            CongestionLevel::SkipToKeyframe
+        } else if lag >= self.drop_non_reference_lag {
+            CongestionLevel::DropNonReference
+        } else {
+            CongestionLevel::Clear
+        }
+    }
+}
+
+/// Whether an H.264 access unit can be dropped without breaking decoding
+///
+/// True when every slice has `nal_ref_idc == 0`. Accepts Annex B and
+/// 4-byte length-prefixed (AVCC) input; anything unparseable counts as a
+/// reference frame.
+pub fn is_non_reference(data: &[u8]) -> bool {
+    let mut nals = Vec::new();
+    if data.starts_with(&[0, 0, 1]) || data.starts_with(&[0, 0, 0, 1]) {
+        let mut i = 0;
+        while i + 3 <= data.len() {
+            if data[i..i + 3] == [0, 0, 1] {
+                if let Some(&header) = data.get(i + 3) {
+                    nals.push(header);
+                }
+                i += 3;
+            } else {
+                i += 1;
+            }
+        }
+    } else {
+        let mut rest = data;
+        while rest.len() >= 4 {
+            let len = u32::from_be_bytes([rest[0], rest[1], rest[2], rest[3]]) as usize;
+            let Some(nal) = rest.get(4..4 + len) else {
+                return false;
+            };
+            if let Some(&header) = nal.first() {
+                nals.push(header);
+            }
+            rest = &rest[4 + len..];
+        }
+        if !rest.is_empty() {
+            return false;
+        }
+    }
+
+    let mut slices = nals.iter().filter(|header| matches!(*header & 0x1f, 1..=5)).peekable();
+    slices.peek().is_some() && slices.all(|header| header & 0x60 == 0)
+}
+
+/// Frame for a hang track, with the payload as it goes on the wire
+fn hang_frame(pts_us: i64, keyframe: bool, payload: Bytes) -> hang::Frame {
+    hang::Frame {
+        timestamp: Duration::from_micros(pts_us.max(0) as u64),
+        keyframe,
+        payload,
+    }
+}
+
+/// Payload as it goes on the wire, sealed when the track is encrypted
+fn seal(encryptor: Option<&FrameEncryptor>, pts_us: i64, data: &Bytes) -> Bytes {
+    match encryptor {
+        Some(encryptor) => encryptor.encrypt(pts_us, data),
+        None => data.clone(),
+    }
+}
+
+/// Video track counters
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct VideoTrackStats {
+    /// Frames written to the track
+    pub frames_written: u64,
+    /// Bytes written to the track
+    pub bytes_written: u64,
+    /// Frames dropped by the congestion policy
+    pub frames_dropped: u64,
+    /// Groups abandoned before their next keyframe
+    pub groups_cut: u64,
+}
+
 /// Video track producer wrapping MoQ track
 pub struct VideoTrackWriter {
-    producer: TrackProducer,
-    current_group: Option<GroupProducer>,
+    track: hang::TrackProducer,
+    /// Whether hang has a group open on the track
+    group_open: bool,
+    /// Dropping frames until the next keyframe
+    skipping: bool,
     quality: VideoQuality,
-    frames_written: u64,
-    bytes_written: u64,
+    stats: VideoTrackStats,
+    /// Seals payloads, with a new key per group
+    encryptor: Option<FrameEncryptor>,
 }
 
 impl VideoTrackWriter {
     /// Create a new video track writer
     pub fn new(producer: TrackProducer, quality: VideoQuality) -> Self {
         Self {
-            producer,
-            current_group: None,
+            track: producer.into(),
+            group_open: false,
+            skipping: false,
             quality,
-            frames_written: 0,
-            bytes_written: 0,
+            stats: VideoTrackStats::default(),
+            encryptor: None,
         }
     }
 
+    /// Seal frame payloads with `key`
+    pub fn with_encryption(mut self, key: MediaKey) -> Self {
+        self.encryptor = Some(FrameEncryptor::new(key));
+        self
+    }
+
     /// Write an encoded frame to the track
     pub fn write_frame(&mut self, frame: &EncodedVideoFrame) {
-        // For MoQ, each group starts with a keyframe
-        // We create a new group for each keyframe
+        self.write_frame_under(frame, CongestionLevel::Clear);
+    }
+
+    /// Write an encoded frame, shedding load according to `level`
+    ///
+    /// Returns true when a group was cut, i.e. the encoder should produce
+    /// a keyframe soon.
+    pub fn write_frame_under(&mut self, frame: &EncodedVideoFrame, level: CongestionLevel) -> bool {
+        if !frame.is_keyframe {
+            if self.skipping {
+                self.stats.frames_dropped += 1;
+                return false;
+            }
+
+            if level == CongestionLevel::SkipToKeyframe {
+                if self.group_open {
+                    // hang keeps its open group private; a fresh producer
+                    // on the same track abandons it
+                    self.track = hang::TrackProducer::new(self.track.inner.clone());
+                    self.group_open = false;
+                    self.stats.groups_cut += 1;
+                }
+                self.skipping = true;
+                self.stats.frames_dropped += 1;
+                debug!(quality = ?self.quality, ?level, "cut group, skipping to keyframe");
+                return true;
+            }
+
+            if level == CongestionLevel::DropNonReference && is_non_reference(&frame.data) {
+                self.stats.frames_dropped += 1;
+                return false;
+            }
+        }
+
+        self.append(frame);
+        false
+    }
+
+    fn append(&mut self, frame: &EncodedVideoFrame) {
+        // For MoQ, each group starts with a keyframe; hang closes the
+        // current group and starts a new one on every keyframe
         if frame.is_keyframe {
             debug!(
                 quality = ?self.quality,
@@ -140,67 +499,84 @@ impl VideoTrackWriter {
                 size = frame.data.len(),
                 "writing keyframe - starting new group"
             );
-            // Start a new group for keyframe
-            self.current_group = Some(self.producer.append_group());
+            self.skipping = false;
         }
 
-        // Get or create current group
-        let group = match &mut self.current_group {
-            Some(g) => g,
-            None => {
-                // No group yet, create one
-                self.current_group = Some(self.producer.append_group());
-                self.current_group.as_mut().unwrap()
+        if frame.is_keyframe || !self.group_open {
+            if let Some(encryptor) = &mut self.encryptor {
+                encryptor.rotate();
             }
-        };
+            self.group_open = true;
+        }
 
-        // Write frame to the group
-        group.write_frame(frame.data.clone());
-        
-        self.frames_written += 1;
-        self.bytes_written += frame.data.len() as u64;
+        let payload = seal(self.encryptor.as_ref(), frame.pts_us, &frame.data);
+        self.track.write(hang_frame(frame.pts_us, frame.is_keyframe, payload));
+
+        self.stats.frames_written += 1;
+        self.stats.bytes_written += frame.data.len() as u64;
     }
 
     /// Get statistics
-    pub fn stats(&self) -> (u64, u64) {
-        (self.frames_written, self.bytes_written)
+    pub fn stats(&self) -> VideoTrackStats {
+        self.stats
+    }
+
+    /// Quality of this track
+    pub fn quality(&self) -> VideoQuality {
+        self.quality
+    }
+
+    /// End the track cleanly
+    ///
+    /// Subscribers read to the last frame and then see the track finish,
+    /// rather than lose it as if the connection had dropped.
+    pub fn finish(self) {
+        self.track.inner.close();
     }
 }
 
 /// Audio track producer wrapping MoQ track
 pub struct AudioTrackWriter {
-    producer: TrackProducer,
-    current_group: Option<GroupProducer>,
+    track: hang::TrackProducer,
     quality: AudioQuality,
     frames_written: u64,
     bytes_written: u64,
+    /// Seals payloads, with a new key per group
+    encryptor: Option<FrameEncryptor>,
 }
 
 impl AudioTrackWriter {
     /// Create a new audio track writer
     pub fn new(producer: TrackProducer, quality: AudioQuality) -> Self {
         Self {
-            producer,
-            current_group: None,
+            track: producer.into(),
             quality,
             frames_written: 0,
             bytes_written: 0,
+            encryptor: None,
         }
     }
 
+    /// Seal frame payloads with `key`
+    pub fn with_encryption(mut self, key: MediaKey) -> Self {
+        self.encryptor = Some(FrameEncryptor::new(key));
+        self
+    }
+
     /// Write an encoded audio frame to the track
     pub fn write_frame(&mut self, frame: &EncodedAudioFrame) {
-        // Audio frames typically start new groups periodically (e.g., every ~20 frames)
-        // For simplicity, we create a new group every 20 frames
-        let start_new_group = self.current_group.is_none() || (self.frames_written % 20 == 0);
-        
+        // Every AUDIO_GROUP_FRAMES-th frame is marked a keyframe, which
+        // makes hang start a new group
+        let start_new_group = self.frames_written.is_multiple_of(AUDIO_GROUP_FRAMES);
+
         if start_new_group {
-            self.current_group = Some(self.producer.append_group());
-        }
-        
-        if let Some(ref mut group) = self.current_group {
-            group.write_frame(frame.data.clone());
+            if let Some(encryptor) = &mut self.encryptor {
+                encryptor.rotate();
+            }
         }
+
+        let payload = seal(self.encryptor.as_ref(), frame.pts_us, &frame.data);
+        self.track.write(hang_frame(frame.pts_us, start_new_group, payload));
         
         self.frames_written += 1;
         self.bytes_written += frame.data.len() as u64;
@@ -210,57 +586,115 @@ impl AudioTrackWriter {
     pub fn stats(&self) -> (u64, u64) {
         (self.frames_written, self.bytes_written)
     }
+
+    /// End the track cleanly
+    pub fn finish(self) {
+        self.track.inner.close();
+    }
 }
 
 /// Collection of video renditions (multiple quality levels)
 pub struct VideoRenditions {
     tracks: Vec<(VideoQuality, VideoTrackWriter)>,
+    /// Key for sealing the payloads of every rendition
+    encryption: Option<MediaKey>,
 }
 
 impl VideoRenditions {
     /// Create video renditions from a broadcast producer
-    pub fn new(broadcast: &mut BroadcastProducer, qualities: &[VideoQuality]) -> Self {
-        let mut tracks = Vec::new();
-        
+    pub fn new(
+        broadcast: &mut BroadcastProducer,
+        qualities: &[VideoQuality],
+        encryption: Option<MediaKey>,
+    ) -> Self {
+        let mut renditions = Self { tracks: Vec::new(), encryption };
         for quality in qualities {
-            let track_name = format!("video.{}", quality.suffix());
-            let track = Track {
-                name: track_name.clone(),
-                priority: match quality {
-                    VideoQuality::High => 0,
-                    VideoQuality::Medium => 1,
-                    VideoQuality::Low => 2,
-                },
-            };
-            
-            let producer = broadcast.create_track(track);
-            tracks.push((*quality, VideoTrackWriter::new(producer, *quality)));
-            
-            info!("created video track: {track_name}");
+            renditions.add(broadcast, *quality);
         }
-        
-        Self { tracks }
+        renditions
+    }
+
+    /// Create the track for a rendition; false if it already exists
+    pub fn add(&mut self, broadcast: &mut BroadcastProducer, quality: VideoQuality) -> bool {
+        if self.tracks.iter().any(|(q, _)| *q == quality) {
+            return false;
+        }
+
+        let track_name = quality.track_name();
+        let track = Track {
+            name: track_name.clone(),
+            priority: match quality {
+                VideoQuality::High => 0,
+                VideoQuality::Medium => 1,
+                VideoQuality::Low => 2,
+            },
+        };
+
+        let producer = broadcast.create_track(track);
+        let mut writer = VideoTrackWriter::new(producer, quality);
+        if let Some(key) = &self.encryption {
+            writer = writer.with_encryption(key.clone());
+        }
+        self.tracks.push((quality, writer));
+
+        info!("created video track: {track_name}");
+        true
+    }
+
+    /// Close a rendition's track; false if there is no such rendition
+    pub fn remove(&mut self, broadcast: &mut BroadcastProducer, quality: VideoQuality) -> bool {
+        let Some(index) = self.tracks.iter().position(|(q, _)| *q == quality) else {
+            return false;
+        };
+        let (_, writer) = self.tracks.remove(index);
+        writer.finish();
+        broadcast.remove_track(&quality.track_name());
+
+        info!("removed video track: {}", quality.track_name());
+        true
+    }
+
+    /// Qualities currently published
+    pub fn qualities(&self) -> Vec<VideoQuality> {
+        self.tracks.iter().map(|(quality, _)| *quality).collect()
     }
 
     /// Write a frame to the appropriate quality track
     pub fn write_frame(&mut self, frame: &EncodedVideoFrame) {
+        self.write_frame_under(frame, CongestionLevel::Clear);
+    }
+
+    /// Write a frame under congestion, returning true if a keyframe is wanted
+    pub fn write_frame_under(&mut self, frame: &EncodedVideoFrame, level: CongestionLevel) -> bool {
         for (quality, writer) in &mut self.tracks {
             if *quality == frame.quality {
-                writer.write_frame(frame);
-                return;
+                return writer.write_frame_under(frame, level);
             }
         }
         
         // If no matching quality, write to all (for single-quality mode)
         if self.tracks.len() == 1 {
-            self.tracks[0].1.write_frame(frame);
+            return self.tracks[0].1.write_frame_under(frame, level);
         }
+        false
+    }
+
+    /// Counters for every rendition
+    pub fn stats(&self) -> Vec<(VideoQuality, VideoTrackStats)> {
+        self.tracks.iter().map(|(quality, writer)| (*quality, writer.stats())).collect()
     }
 
     /// Get the primary (highest quality) writer
     pub fn primary(&mut self) -> Option<&mut VideoTrackWriter> {
         self.tracks.first_mut().map(|(_, w)| w)
     }
+
+    /// End every rendition's track cleanly
+    pub fn finish(&mut self) {
+        for (_, writer) in self.tracks.drain(..) {
+            writer.finish();
+        }
+    }
 }
 
 /// Collection of audio renditions (multiple quality levels)
@@ -270,11 +704,15 @@ pub struct AudioRenditions {
 
 impl AudioRenditions {
     /// Create audio renditions from a broadcast producer
-    pub fn new(broadcast: &mut BroadcastProducer, qualities: &[AudioQuality]) -> Self {
+    pub fn new(
+        broadcast: &mut BroadcastProducer,
+        qualities: &[AudioQuality],
+        encryption: Option<&MediaKey>,
+    ) -> Self {
         let mut tracks = Vec::new();
         
         for quality in qualities {
-            let track_name = format!("audio.{}", quality.suffix());
+            let track_name = quality.track_name();
             let track = Track {
                 name: track_name.clone(),
                 priority: match quality {
@@ -285,7 +723,11 @@ impl AudioRenditions {
             };
             
             let producer = broadcast.create_track(track);
-            tracks.push((*quality, AudioTrackWriter::new(producer, *quality)));
+            let mut writer = AudioTrackWriter::new(producer, *quality);
+            if let Some(key) = encryption {
+                writer = writer.with_encryption(key.clone());
+            }
+            tracks.push((*quality, writer));
             
             info!("created audio track: {track_name}");
         }
@@ -312,6 +754,13 @@ impl AudioRenditions {
     pub fn primary(&mut self) -> Option<&mut AudioTrackWriter> {
         self.tracks.first_mut().map(|(_, w)| w)
     }
+
+    /// End every rendition's track cleanly
+    pub fn finish(&mut self) {
+        for (_, writer) in self.tracks.drain(..) {
+            writer.finish();
+        }
+    }
 }
 
 /// Messages for the broadcast publisher
@@ -320,14 +769,24 @@ pub enum PublishCommand {
     PushVideo(EncodedVideoFrame),
     /// Push an encoded audio frame
     PushAudio(EncodedAudioFrame),
-    /// Stop publishing
-    Stop,
+    /// Start publishing a video rendition with the given catalog entry
+    AddVideo(VideoQuality, VideoConfig),
+    /// Stop publishing a video rendition
+    RemoveVideo(VideoQuality),
+    /// End the broadcast, acknowledged once its tracks are finished
+    Stop(oneshot::Sender<()>),
 }
 
 /// Handle for sending frames to a broadcast
 #[derive(Clone)]
 pub struct PublishHandle {
     tx: mpsc::Sender<PublishCommand>,
+    catalog: CatalogProducer,
+    keyframe_requested: Arc<AtomicBool>,
+    video_stats: watch::Receiver<Vec<(VideoQuality, VideoTrackStats)>>,
+    /// Unset when the broadcast has no chat
+    chat_tx: Option<ChatInbox>,
+    chat_messages: broadcast::Sender<ChatMessage>,
 }
 
 impl PublishHandle {
@@ -345,10 +804,158 @@ impl PublishHandle {
         Ok(())
     }
 
-    /// Stop the broadcast
+    /// Push an encoded video frame without waiting for queue space
+    ///
+    /// Fails if the queue is full, so callers on a non-async thread can
+    /// drop the frame instead of blocking.
+    pub fn try_push_video(&self, frame: EncodedVideoFrame) -> Result<()> {
+        self.tx.try_send(PublishCommand::PushVideo(frame))
+            .map_err(|e| anyhow::anyhow!("failed to queue video frame: {e}"))?;
+        Ok(())
+    }
+
+    /// Blocking variant of `push_video` for encoder threads
+    ///
+    /// For frames that must not be dropped, such as the ones an encoder
+    /// flushes at the end. Panics if called from inside an async runtime.
+    pub fn blocking_push_video(&self, frame: EncodedVideoFrame) -> Result<()> {
+        self.tx.blocking_send(PublishCommand::PushVideo(frame))
+            .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        Ok(())
+    }
+
+    /// Push an encoded audio frame without waiting for queue space
+    pub fn try_push_audio(&self, frame: EncodedAudioFrame) -> Result<()> {
+        self.tx.try_send(PublishCommand::PushAudio(frame))
+            .map_err(|e| anyhow::anyhow!("failed to queue audio frame: {e}"))?;
+        Ok(())
+    }
+
+    /// Take a pending keyframe request
+    ///
+    /// Set when the congestion policy cuts a group; the encoder should emit
+    /// an IDR frame next so subscribers don't wait out a whole GOP.
+    pub fn take_keyframe_request(&self) -> bool {
+        self.keyframe_requested.swap(false, Ordering::Relaxed)
+    }
+
+    /// Per-rendition video counters
+    pub fn video_stats(&self) -> Vec<(VideoQuality, VideoTrackStats)> {
+        self.video_stats.borrow().clone()
+    }
+
+    /// Get a snapshot of the broadcast catalog
+    pub fn catalog(&self) -> Catalog {
+        self.catalog.clone().update().clone()
+    }
+
+    /// Replace the catalog entry for a video rendition and republish the catalog
+    ///
+    /// Call this with the encoder's config (e.g. `H264Encoder::video_config`)
+    /// before pushing its first frame so subscribers get the avcC description.
+    pub fn set_video_config(&self, quality: VideoQuality, config: VideoConfig) -> Result<()> {
+        let mut catalog = self.catalog.clone();
+        {
+            let mut current = catalog.update();
+            let rendition = current
+                .video
+                .as_mut()
+                .and_then(|video| video.renditions.get_mut(&quality.track_name()))
+                .ok_or_else(|| anyhow::anyhow!("no video rendition: {}", quality.track_name()))?;
+            *rendition = config;
+        }
+        catalog.publish();
+        Ok(())
+    }
+
+    /// Replace the catalog entry for an audio rendition and republish the catalog
+    pub fn set_audio_config(&self, quality: AudioQuality, config: AudioConfig) -> Result<()> {
+        let mut catalog = self.catalog.clone();
+        {
+            let mut current = catalog.update();
+            let rendition = current
+                .audio
+                .as_mut()
+                .and_then(|audio| audio.renditions.get_mut(&quality.track_name()))
+                .ok_or_else(|| anyhow::anyhow!("no audio rendition: {}", quality.track_name()))?;
+            *rendition = config;
+        }
+        catalog.publish();
+        Ok(())
+    }
+
+    /// Start publishing another video rendition
+    ///
+    /// The track is created and added to the catalog before any frame
+    /// queued after this call is written. Adding a published rendition
+    /// only updates its catalog entry.
+    pub async fn add_video_rendition(&self, quality: VideoQuality, config: VideoConfig) -> Result<()> {
+        self.tx.send(PublishCommand::AddVideo(quality, config)).await
+            .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        Ok(())
+    }
+
+    /// Stop publishing a video rendition
+    ///
+    /// The rendition leaves the catalog and its track is closed; frames for
+    /// it that were queued earlier are still written.
+    pub async fn remove_video_rendition(&self, quality: VideoQuality) -> Result<()> {
+        self.tx.send(PublishCommand::RemoveVideo(quality)).await
+            .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        Ok(())
+    }
+
+    /// Blocking variant of `add_video_rendition` for encoder threads
+    ///
+    /// Panics if called from inside an async runtime.
+    pub fn blocking_add_video_rendition(&self, quality: VideoQuality, config: VideoConfig) -> Result<()> {
+        self.tx.blocking_send(PublishCommand::AddVideo(quality, config))
+            .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        Ok(())
+    }
+
+    /// Blocking variant of `remove_video_rendition` for encoder threads
+    pub fn blocking_remove_video_rendition(&self, quality: VideoQuality) -> Result<()> {
+        self.tx.blocking_send(PublishCommand::RemoveVideo(quality))
+            .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        Ok(())
+    }
+
+    /// Where viewers' posts go, for [`super::live_protocol::Live::open_chat`]
+    pub fn chat_inbox(&self) -> Option<ChatInbox> {
+        self.chat_tx.clone()
+    }
+
+    /// Post to the broadcast's chat
+    pub async fn post_chat(&self, post: ChatPost) -> Result<()> {
+        post.kind.validate()?;
+        let tx = self.chat_tx.as_ref().ok_or_else(|| anyhow::anyhow!("broadcast has no chat"))?;
+        tx.send(post).await
+            .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        Ok(())
+    }
+
+    /// Follow the messages sent on the broadcast's chat, from any sender
+    pub fn chat_messages(&self) -> broadcast::Receiver<ChatMessage> {
+        self.chat_messages.subscribe()
+    }
+
+    /// Whether the broadcast has stopped taking frames
+    pub fn is_closed(&self) -> bool {
+        self.tx.is_closed()
+    }
+
+    /// End the broadcast
+    ///
+    /// Frames queued before this call are still written. Returns once every
+    /// track is finished, so subscribers see the broadcast end cleanly
+    /// rather than drop; unpublish it from [`super::live_protocol::Live`]
+    /// after this.
     pub async fn stop(&self) -> Result<()> {
-        self.tx.send(PublishCommand::Stop).await
+        let (tx, rx) = oneshot::channel();
+        self.tx.send(PublishCommand::Stop(tx)).await
             .map_err(|_| anyhow::anyhow!("broadcast closed"))?;
+        let _ = rx.await;
         Ok(())
     }
 }
@@ -362,6 +969,12 @@ pub struct PublishConfig {
     pub video_qualities: Vec<VideoQuality>,
     /// Audio qualities to publish
     pub audio_qualities: Vec<AudioQuality>,
+    /// Load shedding when the publisher falls behind
+    pub congestion: CongestionConfig,
+    /// Seal frame payloads so relays can forward but not decode them
+    pub encryption: Option<MediaKey>,
+    /// Carry a chat track
+    pub chat: bool,
 }
 
 impl Default for PublishConfig {
@@ -370,6 +983,9 @@ impl Default for PublishConfig {
             name: "broadcast".to_string(),
             video_qualities: vec![VideoQuality::Medium],
             audio_qualities: vec![AudioQuality::Medium],
+            congestion: CongestionConfig::default(),
+            encryption: None,
+            chat: true,
         }
     }
 }
@@ -389,12 +1005,24 @@ pub struct PublishBroadcast {
     video: VideoRenditions,
     /// Audio renditions
     audio: AudioRenditions,
+    /// Catalog track describing the renditions
+    catalog: CatalogProducer,
+    /// Set when a writer wants a keyframe
+    keyframe_requested: Arc<AtomicBool>,
+    /// Latest per-rendition video counters
+    video_stats: watch::Sender<Vec<(VideoQuality, VideoTrackStats)>>,
+    /// How far viewers are behind, as reported by their sessions
+    send_backlog: SendBacklog,
+    /// Chat track, if the broadcast has one
+    chat: Option<ChatWriter>,
+    chat_rx: mpsc::Receiver<ChatPost>,
+    chat_messages: broadcast::Sender<ChatMessage>,
 }
 
 impl PublishBroadcast {
     /// Create a new broadcast publisher
     pub fn new(config: PublishConfig) -> (Self, PublishHandle) {
-        let (tx, rx) = mpsc::channel(256);
+        let (tx, rx) = mpsc::channel(config.congestion.queue_capacity);
         
         // Create broadcast producer
         let produce = Broadcast::produce();
@@ -402,23 +1030,60 @@ impl PublishBroadcast {
         let _consumer = produce.consumer;
         
         // Create video renditions
-        let video = VideoRenditions::new(&mut broadcast, &config.video_qualities);
+        let video = VideoRenditions::new(&mut broadcast, &config.video_qualities, config.encryption.clone());
         
         // Create audio renditions  
-        let audio = AudioRenditions::new(&mut broadcast, &config.audio_qualities);
+        let audio = AudioRenditions::new(&mut broadcast, &config.audio_qualities, config.encryption.as_ref());
+
+        // Create and publish the catalog
+        let catalog = catalog_for(&config.video_qualities, &config.audio_qualities).produce();
+        broadcast.insert_track(catalog.consumer.track);
+        let mut catalog = catalog.producer;
+        catalog.publish();
+
+        let chat = config.chat.then(|| {
+            let producer = broadcast.create_track(Track {
+                name: CHAT_TRACK.to_string(),
+                priority: 3,
+            });
+            let writer = ChatWriter::new(producer);
+            match &config.encryption {
+                Some(key) => writer.with_encryption(key.clone()),
+                None => writer,
+            }
+        });
+        let (chat_tx, chat_rx) = mpsc::channel(CHAT_QUEUE);
+        let (chat_messages, _) = broadcast::channel(CHAT_HISTORY);
         
         info!("created broadcast: {}", config.name);
+
+        let keyframe_requested = Arc::new(AtomicBool::new(false));
+        let (video_stats, video_stats_rx) = watch::channel(video.stats());
         
+        let handle = PublishHandle {
+            tx,
+            catalog: catalog.clone(),
+            keyframe_requested: keyframe_requested.clone(),
+            video_stats: video_stats_rx,
+            chat_tx: chat.is_some().then_some(chat_tx),
+            chat_messages: chat_messages.clone(),
+        };
+
         let publisher = Self {
             config,
             broadcast,
             rx,
             video,
             audio,
+            catalog,
+            keyframe_requested,
+            video_stats,
+            send_backlog: SendBacklog::default(),
+            chat,
+            chat_rx,
+            chat_messages,
         };
         
-        let handle = PublishHandle { tx };
-        
         (publisher, handle)
     }
 
@@ -427,6 +1092,19 @@ impl PublishBroadcast {
         &self.broadcast
     }
 
+    /// Get the catalog producer
+    pub fn catalog(&self) -> &CatalogProducer {
+        &self.catalog
+    }
+
+    /// Send backlog feeding the congestion policy
+    ///
+    /// Publish the broadcast with [`super::live_protocol::Live::publish_tracked`]
+    /// so sessions report into it; otherwise only the command queue counts.
+    pub fn send_backlog(&self) -> SendBacklog {
+        self.send_backlog.clone()
+    }
+
     /// Run the publisher, processing incoming frames
     pub async fn run(mut self) {
         info!("starting broadcast: {}", self.config.name);
@@ -434,11 +1112,31 @@ impl PublishBroadcast {
         let mut video_frames = 0u64;
         let mut audio_frames = 0u64;
         let start = Instant::now();
+        let mut stopped = None;
         
-        while let Some(cmd) = self.rx.recv().await {
+        loop {
+            let cmd = tokio::select! {
+                cmd = self.rx.recv() => match cmd {
+                    Some(cmd) => cmd,
+                    None => break,
+                },
+                Some(post) = self.chat_rx.recv(), if self.chat.is_some() => {
+                    if let Some(chat) = &mut self.chat {
+                        let _ = self.chat_messages.send(chat.post(post));
+                    }
+                    continue;
+                }
+            };
             match cmd {
                 PublishCommand::PushVideo(frame) => {
-                    self.video.write_frame(&frame);
+                    let congestion = &self.config.congestion;
+                    let level = congestion.level(self.rx.len())
+                        .max(congestion.lag_level(self.send_backlog.lag()));
+                    let wants_keyframe = self.video.write_frame_under(&frame, level);
+                    if wants_keyframe && congestion.request_keyframe {
+                        self.keyframe_requested.store(true, Ordering::Relaxed);
+                    }
+                    self.video_stats.send_replace(self.video.stats());
                     video_frames += 1;
                     
                     if video_frames % 300 == 0 {
@@ -451,12 +1149,43 @@ impl PublishBroadcast {
                     self.audio.write_frame(&frame);
                     audio_frames += 1;
                 }
-                PublishCommand::Stop => {
+                PublishCommand::AddVideo(quality, config) => {
+                    self.video.add(&mut self.broadcast, quality);
+                    self.update_video_catalog(|renditions| {
+                        renditions.insert(quality.track_name(), config);
+                    });
+                    self.config.video_qualities = self.video.qualities();
+                    self.video_stats.send_replace(self.video.stats());
+                }
+                PublishCommand::RemoveVideo(quality) => {
+                    // Unlist the rendition before closing its track
+                    self.update_video_catalog(|renditions| {
+                        renditions.remove(&quality.track_name());
+                    });
+                    if !self.video.remove(&mut self.broadcast, quality) {
+                        debug!(?quality, "no such video rendition to remove");
+                    }
+                    self.config.video_qualities = self.video.qualities();
+                    self.video_stats.send_replace(self.video.stats());
+                }
+                PublishCommand::Stop(done) => {
                     info!("stopping broadcast: {}", self.config.name);
+                    stopped = Some(done);
                     break;
                 }
             }
         }
+
+        // Close open groups and end every track, so subscribers can tell
+        // the end of the broadcast from a dropped connection
+        self.video.finish();
+        self.audio.finish();
+        if let Some(chat) = self.chat.take() {
+            chat.finish();
+        }
+        if let Some(done) = stopped {
+            let _ = done.send(());
+        }
         
         let elapsed = start.elapsed();
         info!(
@@ -469,6 +1198,28 @@ impl PublishBroadcast {
     }
 }
 
+impl PublishBroadcast {
+    /// Edit the catalog's video renditions and republish it
+    fn update_video_catalog(&mut self, edit: impl FnOnce(&mut HashMap<String, VideoConfig>)) {
+        {
+            let mut catalog = self.catalog.update();
+            let video = catalog.video.get_or_insert_with(|| Video {
+                renditions: HashMap::new(),
+                priority: 1,
+                display: None,
+                rotation: None,
+                flip: None,
+                detection: None,
+            });
+            edit(&mut video.renditions);
+            if video.renditions.is_empty() {
+                catalog.video = None;
+            }
+        }
+        self.catalog.publish();
+    }
+}
+
 /// Builder for creating PublishBroadcast
 pub struct PublishBroadcastBuilder {
     config: PublishConfig,
@@ -497,6 +1248,18 @@ impl PublishBroadcastBuilder {
         self
     }
 
+    /// Set the congestion policy
+    pub fn congestion(mut self, congestion: CongestionConfig) -> Self {
+        self.config.congestion = congestion;
+        self
+    }
+
+    /// Seal frame payloads with a media key
+    pub fn encryption(mut self, key: MediaKey) -> Self {
+        self.config.encryption = Some(key);
+        self
+    }
+
     /// Build the publisher
     pub fn build(self) -> (PublishBroadcast, PublishHandle) {
         PublishBroadcast::new(self.config)
@@ -514,10 +1277,136 @@ mod tests {
         assert_eq!(VideoQuality::High.height(), 1080);
     }
 
+    #[test]
+    fn test_dimensions_for() {
+        // 16:9 sources match the fixed sizes
+        assert_eq!(VideoQuality::Low.dimensions_for(1920, 1080), (854, 480));
+        assert_eq!(VideoQuality::Medium.dimensions_for(3840, 2160), (1280, 720));
+        // Other aspect ratios and portrait sources keep their shape
+        assert_eq!(VideoQuality::Low.dimensions_for(1440, 1080), (640, 480));
+        assert_eq!(VideoQuality::Medium.dimensions_for(1080, 1920), (720, 1280));
+        assert_eq!(VideoQuality::Low.dimensions_for(1000, 1000), (480, 480));
+        // Small sources aren't scaled up
+        assert_eq!(VideoQuality::High.dimensions_for(640, 480), (640, 480));
+        assert_eq!(VideoQuality::Medium.dimensions_for(641, 361), (640, 360));
+    }
+
     #[test]
     fn test_audio_quality() {
         assert_eq!(AudioQuality::Low.bitrate(), 32_000);
         assert_eq!(AudioQuality::Medium.bitrate(), 64_000);
         assert_eq!(AudioQuality::High.bitrate(), 128_000);
     }
+
+    #[test]
+    fn test_try_push_video_full_queue() {
+        let (_broadcast, handle) = PublishBroadcast::new(PublishConfig::default());
+        let frame = EncodedVideoFrame {
+            data: Bytes::from_static(&[0, 0, 0, 1]),
+            pts_us: 0,
+            is_keyframe: true,
+            quality: VideoQuality::Medium,
+        };
+
+        // Nothing drains the queue, so pushes succeed until it is full
+        let mut queued = 0;
+        while handle.try_push_video(frame.clone()).is_ok() {
+            queued += 1;
+        }
+        assert_eq!(queued, 256);
+    }
+
+    #[test]
+    fn test_catalog_for() {
+        let catalog = catalog_for(&[VideoQuality::Low, VideoQuality::High], &[AudioQuality::Medium]);
+        let video = catalog.video.unwrap();
+        assert_eq!(video.renditions.len(), 2);
+        assert_eq!(video.renditions["video.high"].coded_height, Some(1080));
+        assert!(catalog.audio.unwrap().renditions.contains_key("audio.med"));
+    }
+
+    #[test]
+    fn test_set_video_config() {
+        let (_broadcast, handle) = PublishBroadcast::new(PublishConfig::default());
+        let mut config = VideoQuality::Medium.catalog_config();
+        config.description = Some(Bytes::from_static(&[1, 2, 3]));
+
+        handle.set_video_config(VideoQuality::Medium, config.clone()).unwrap();
+        assert_eq!(handle.catalog().video.unwrap().renditions["video.med"], config);
+        assert!(handle.set_video_config(VideoQuality::High, config).is_err());
+    }
+
+    #[test]
+    fn test_add_and_remove_video_rendition() {
+        let (mut publisher, handle) = PublishBroadcast::new(PublishConfig::default());
+
+        assert!(publisher.video.add(&mut publisher.broadcast, VideoQuality::Low));
+        assert!(!publisher.video.add(&mut publisher.broadcast, VideoQuality::Low));
+        publisher.update_video_catalog(|renditions| {
+            renditions.insert(VideoQuality::Low.track_name(), VideoQuality::Low.catalog_config());
+        });
+        let video = handle.catalog().video.unwrap();
+        assert!(video.renditions.contains_key("video.low"));
+        assert!(video.renditions.contains_key("video.med"));
+
+        // Removing the last rendition drops the video section
+        for quality in [VideoQuality::Low, VideoQuality::Medium] {
+            assert!(publisher.video.remove(&mut publisher.broadcast, quality));
+            publisher.update_video_catalog(|renditions| {
+                renditions.remove(&quality.track_name());
+            });
+        }
+        assert!(!publisher.video.remove(&mut publisher.broadcast, VideoQuality::Low));
+        assert!(publisher.video.qualities().is_empty());
+        assert!(handle.catalog().video.is_none());
+    }
+
+    #[test]
+    fn test_is_non_reference() {
+        // Annex B: SPS (ref) + IDR slice (ref)
+        assert!(!is_non_reference(&[0, 0, 0, 1, 0x67, 1, 0, 0, 1, 0x65, 2]));
+        // Annex B: non-reference P slice
+        assert!(is_non_reference(&[0, 0, 0, 1, 0x01, 9, 9]));
+        // AVCC: SEI + non-reference slice
+        assert!(is_non_reference(&[0, 0, 0, 2, 0x06, 5, 0, 0, 0, 2, 0x01, 7]));
+        // AVCC: reference P slice
+        assert!(!is_non_reference(&[0, 0, 0, 2, 0x41, 7]));
+        // Truncated or empty input is kept
+        assert!(!is_non_reference(&[0, 0, 0, 9, 0x01]));
+        assert!(!is_non_reference(&[]));
+    }
+
+    #[test]
+    fn test_congestion_drops_then_skips_to_keyframe() {
+        let producer = Track::new(VideoQuality::Medium.track_name()).produce().producer;
+        let mut writer = VideoTrackWriter::new(producer, VideoQuality::Medium);
+        let frame = |pts_us, is_keyframe, nal: u8| EncodedVideoFrame {
+            data: Bytes::from(vec![0, 0, 0, 1, nal, 0]),
+            pts_us,
+            is_keyframe,
+            quality: VideoQuality::Medium,
+        };
+
+        assert!(!writer.write_frame_under(&frame(0, true, 0x65), CongestionLevel::Clear));
+        // Non-reference frames go first, reference frames are kept
+        writer.write_frame_under(&frame(33_000, false, 0x01), CongestionLevel::DropNonReference);
+        writer.write_frame_under(&frame(66_000, false, 0x41), CongestionLevel::DropNonReference);
+        assert_eq!((writer.stats().frames_written, writer.stats().frames_dropped), (2, 1));
+
+        // Severe congestion cuts the group once, then drops until a keyframe
+        assert!(writer.write_frame_under(&frame(99_000, false, 0x41), CongestionLevel::SkipToKeyframe));
+        assert!(!writer.write_frame_under(&frame(133_000, false, 0x41), CongestionLevel::Clear));
+        assert!(!writer.write_frame_under(&frame(166_000, true, 0x65), CongestionLevel::Clear));
+        let stats = writer.stats();
+        assert_eq!((stats.frames_written, stats.frames_dropped, stats.groups_cut), (3, 3, 1));
+
+        // A long group is left alone while nothing is backed up
+        assert!(!writer.write_frame_under(&frame(10_166_000, false, 0x41), CongestionLevel::Clear));
+        assert_eq!(writer.stats().groups_cut, 1);
+
+        let congestion = CongestionConfig::default();
+        assert_eq!(congestion.lag_level(Duration::from_millis(100)), CongestionLevel::Clear);
+        assert_eq!(congestion.lag_level(Duration::from_secs(1)), CongestionLevel::DropNonReference);
+        assert_eq!(congestion.lag_level(Duration::from_secs(3)), CongestionLevel::SkipToKeyframe);
+    }
 }
@@ -0,0 +1,404 @@
+//! Reconnecting subscriptions
+//!
+//! When the connection to a publisher drops, a [`BroadcastLink`] dials the
+//! ticket's address again with exponential backoff and subscribes to the
+//! same broadcast. The address carries both the relay URL and the direct
+//! addresses, and iroh tries every path it knows on each attempt. moq-lite
+//! starts track subscriptions at the latest group and groups begin on a
+//! keyframe, so the decoder picks up cleanly after the gap.
+//!
+//! Progress is reported as [`ConnectionState`]s through a
+//! [`StateReporter`]: connecting, live once video flows, stalled when it
+//! stops while the session is still up, reconnecting between attempts and
+//! ended when the broadcast is over, the publisher kicked us or withdrew
+//! our access, or the retries run out.
+
+use std::sync::Arc;
+
+use anyhow::Result;
+use iroh::EndpointAddr;
+use moq_lite::BroadcastConsumer;
+use n0_future::time::{self, Duration, Instant};
+use tokio::sync::{mpsc, watch};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, warn};
+
+use super::access::SignedToken;
+use super::live_protocol::{Live, LiveSession};
+use super::relay;
+use super::subscribe_broadcast::TrackStats;
+
+/// How long a withdrawn broadcast may wait for its session to close before
+/// it counts as ended rather than dropped
+///
+/// A dying connection can withdraw its broadcasts just before it reports
+/// closing.
+const CLOSE_GRACE: Duration = Duration::from_millis(500);
+
+/// How often [`watch_progress`] looks at the frame counter
+const PROGRESS_POLL: Duration = Duration::from_millis(250);
+
+/// Where a subscription's connection to the publisher stands
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Dialing the publisher and waiting for the broadcast
+    Connecting,
+    /// Video is arriving
+    Live,
+    /// Still connected, but no video for a while
+    Stalled,
+    /// Waiting `delay` before reconnect attempt `attempt` (from 1)
+    Reconnecting { attempt: u32, delay: Duration },
+    /// Over for good: the publisher ended the broadcast (no error) or the
+    /// subscription gave up
+    Ended { error: Option<String> },
+}
+
+impl ConnectionState {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Connecting => "connecting",
+            Self::Live => "live",
+            Self::Stalled => "stalled",
+            Self::Reconnecting { .. } => "reconnecting",
+            Self::Ended { .. } => "ended",
+        }
+    }
+}
+
+/// Retry settings for [`BroadcastLink`]
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// Wait before the first retry; doubles with each failed attempt
+    pub initial_delay: Duration,
+    /// Longest wait between attempts
+    pub max_delay: Duration,
+    /// Attempts in a row before giving up; None retries until stopped
+    pub max_attempts: Option<u32>,
+    /// Time allowed for dialing and finding the broadcast on each attempt
+    pub connect_timeout: Duration,
+    /// Time without video before the subscription counts as stalled
+    pub stall_timeout: Duration,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+            max_attempts: Some(20),
+            connect_timeout: Duration::from_secs(15),
+            stall_timeout: Duration::from_secs(3),
+        }
+    }
+}
+
+/// Exponential backoff between reconnect attempts
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    initial: Duration,
+    max: Duration,
+    attempt: u32,
+}
+
+impl Backoff {
+    pub fn new(initial: Duration, max: Duration) -> Self {
+        Self { initial, max, attempt: 0 }
+    }
+
+    /// Delay before the next attempt, counting it
+    pub fn next_delay(&mut self) -> Duration {
+        let delay = self
+            .initial
+            .saturating_mul(1u32 << self.attempt.min(16))
+            .min(self.max);
+        self.attempt += 1;
+        delay
+    }
+
+    /// Attempts counted since the last reset
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Start over after a successful connection
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+/// Spread delays by ±20% so viewers of one publisher don't retry in step
+fn jitter(delay: Duration) -> Duration {
+    delay.mul_f64(rand::random_range(0.8..1.2))
+}
+
+/// Hands a subscription's connection states to whoever follows them
+///
+/// Keeps the current state and queues every change.
+#[derive(Debug, Clone)]
+pub struct StateReporter {
+    current: Arc<watch::Sender<ConnectionState>>,
+    events: mpsc::UnboundedSender<ConnectionState>,
+}
+
+impl StateReporter {
+    /// A reporter starting out connecting, and the queue of its changes
+    pub fn new() -> (Self, mpsc::UnboundedReceiver<ConnectionState>) {
+        let (current, _) = watch::channel(ConnectionState::Connecting);
+        let (events, events_rx) = mpsc::unbounded_channel();
+        let reporter = Self {
+            current: Arc::new(current),
+            events,
+        };
+        (reporter, events_rx)
+    }
+
+    /// Move to `state`, if it isn't already the current one
+    pub fn report(&self, state: ConnectionState) {
+        if *self.current.borrow() == state {
+            return;
+        }
+        debug!(state = state.name(), "connection state");
+        self.current.send_replace(state.clone());
+        let _ = self.events.send(state);
+    }
+
+    pub fn current(&self) -> ConnectionState {
+        self.current.borrow().clone()
+    }
+
+    /// Whether the subscription is over for good
+    pub fn is_ended(&self) -> bool {
+        matches!(*self.current.borrow(), ConnectionState::Ended { .. })
+    }
+}
+
+/// How a connection to the publisher was lost
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lost {
+    /// The session closed under the broadcast
+    Dropped,
+    /// The publisher withdrew the broadcast over a live session, kicked
+    /// this viewer or no longer admits it
+    Ended,
+}
+
+/// A session to a publisher for one broadcast, reopened when it drops
+pub struct BroadcastLink {
+    live: Live,
+    addr: EndpointAddr,
+    name: String,
+    token: Option<SignedToken>,
+    config: ReconnectConfig,
+    backoff: Backoff,
+    states: StateReporter,
+    session: Option<LiveSession>,
+}
+
+impl BroadcastLink {
+    pub fn new(
+        live: Live,
+        addr: EndpointAddr,
+        name: impl ToString,
+        token: Option<SignedToken>,
+        config: ReconnectConfig,
+        states: StateReporter,
+    ) -> Self {
+        let backoff = Backoff::new(config.initial_delay, config.max_delay);
+        Self {
+            live,
+            addr,
+            name: name.to_string(),
+            token,
+            config,
+            backoff,
+            states,
+            session: None,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn config(&self) -> &ReconnectConfig {
+        &self.config
+    }
+
+    pub fn states(&self) -> &StateReporter {
+        &self.states
+    }
+
+    /// Session of the current connection
+    pub fn session(&self) -> Option<&LiveSession> {
+        self.session.as_ref()
+    }
+
+    /// Dial the publisher and subscribe to the broadcast, without retrying
+    pub async fn connect(&mut self) -> Result<BroadcastConsumer> {
+        self.states.report(ConnectionState::Connecting);
+        self.session = None;
+        let attempt = async {
+            let mut session = self.live.connect_with_token(self.addr.clone(), self.token.as_ref()).await?;
+            let broadcast = session.subscribe(&self.name).await?;
+            anyhow::Ok((session, broadcast))
+        };
+        let (session, broadcast) = time::timeout(self.config.connect_timeout, attempt)
+            .await
+            .map_err(|_| anyhow::anyhow!("timed out connecting to the publisher"))??;
+        self.session = Some(session);
+        self.backoff.reset();
+        Ok(broadcast)
+    }
+
+    /// Connect again, backing off between attempts
+    ///
+    /// Returns None once the attempts run out, having reported the
+    /// subscription ended.
+    pub async fn reconnect(&mut self) -> Option<BroadcastConsumer> {
+        loop {
+            if let Some(max) = self.config.max_attempts.filter(|max| self.backoff.attempt() >= *max) {
+                self.states.report(ConnectionState::Ended {
+                    error: Some(format!("publisher unreachable after {max} attempts")),
+                });
+                return None;
+            }
+            let delay = jitter(self.backoff.next_delay());
+            let attempt = self.backoff.attempt();
+            self.states.report(ConnectionState::Reconnecting { attempt, delay });
+            time::sleep(delay).await;
+
+            match self.connect().await {
+                Ok(broadcast) => {
+                    info!("reconnected to '{}' after {attempt} attempts", self.name);
+                    return Some(broadcast);
+                }
+                Err(e) => warn!("reconnect attempt {attempt} for '{}' failed: {e:#}", self.name),
+            }
+        }
+    }
+
+    /// Wait until the connection is lost
+    ///
+    /// Reports the subscription ended when the publisher withdrew the
+    /// broadcast or kicked this viewer. A withdrawal is checked with the
+    /// publisher, so one for lack of access ends with the reason. Returns
+    /// at once without a session.
+    pub async fn lost(&mut self) -> Lost {
+        let Some(session) = self.session.as_mut() else {
+            return Lost::Dropped;
+        };
+        session.broadcast_ended(&self.name).await;
+        let closed = time::timeout(CLOSE_GRACE, session.closed()).await.is_ok();
+        let kicked = session.kicked();
+        self.session = None;
+        if kicked {
+            info!("kicked from '{}'", self.name);
+            self.states.report(ConnectionState::Ended {
+                error: Some("kicked by the publisher".to_string()),
+            });
+            Lost::Ended
+        } else if closed {
+            Lost::Dropped
+        } else if let Some(denied) = self.denied().await {
+            info!("access to '{}' withdrawn: {denied}", self.name);
+            self.states.report(ConnectionState::Ended {
+                error: Some(format!("access denied: {denied}")),
+            });
+            Lost::Ended
+        } else {
+            info!("publisher ended '{}'", self.name);
+            self.states.report(ConnectionState::Ended { error: None });
+            Lost::Ended
+        }
+    }
+
+    /// Ask the publisher why it no longer admits us to the broadcast
+    ///
+    /// None if it still does, or doesn't answer in time.
+    async fn denied(&self) -> Option<String> {
+        let query = relay::query_denied(self.live.endpoint(), self.addr.clone(), &self.name, self.token.as_ref());
+        match time::timeout(self.config.connect_timeout, query).await {
+            Ok(Ok(denied)) => denied,
+            Ok(Err(e)) => {
+                debug!("access query for '{}' failed: {e:#}", self.name);
+                None
+            }
+            Err(_) => None,
+        }
+    }
+
+    /// Drop the current session
+    pub fn close(&mut self) {
+        self.session = None;
+    }
+}
+
+/// Report a subscription live while its video frame count grows, and
+/// stalled once it stops for `stall_timeout`
+///
+/// Runs until cancelled or the subscription ends.
+pub async fn watch_progress(
+    frames: Arc<TrackStats>,
+    states: StateReporter,
+    stall_timeout: Duration,
+    cancel: CancellationToken,
+) {
+    let mut last = frames.frames();
+    let mut last_change = Instant::now();
+    let mut interval = time::interval(PROGRESS_POLL);
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            _ = interval.tick() => {}
+        }
+        if states.is_ended() {
+            break;
+        }
+        let now = frames.frames();
+        if now != last {
+            last = now;
+            last_change = Instant::now();
+            states.report(ConnectionState::Live);
+        } else if last_change.elapsed() >= stall_timeout {
+            states.report(ConnectionState::Stalled);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_doubles_up_to_max() {
+        let mut backoff = Backoff::new(Duration::from_millis(500), Duration::from_secs(5));
+        let delays: Vec<_> = (0..6).map(|_| backoff.next_delay().as_millis()).collect();
+        assert_eq!(delays, vec![500, 1000, 2000, 4000, 5000, 5000]);
+        assert_eq!(backoff.attempt(), 6);
+
+        backoff.reset();
+        assert_eq!(backoff.next_delay(), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn test_jitter_stays_close() {
+        for _ in 0..100 {
+            let delay = jitter(Duration::from_secs(10));
+            assert!(delay >= Duration::from_secs(8) && delay <= Duration::from_secs(12));
+        }
+    }
+
+    #[test]
+    fn test_reporter_skips_repeats() {
+        let (states, mut events) = StateReporter::new();
+        states.report(ConnectionState::Connecting);
+        states.report(ConnectionState::Live);
+        states.report(ConnectionState::Live);
+        states.report(ConnectionState::Stalled);
+        let seen: Vec<_> = std::iter::from_fn(|| events.try_recv().ok()).collect();
+        assert_eq!(seen, vec![ConnectionState::Live, ConnectionState::Stalled]);
+        assert_eq!(states.current(), ConnectionState::Stalled);
+        assert!(!states.is_ended());
+    }
+}
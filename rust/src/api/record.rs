@@ -0,0 +1,771 @@
+//! Local recording of a live broadcast to fragmented MP4
+//!
+//! [`Recorder`] subscribes to a broadcast's catalog plus one video and one
+//! audio rendition and writes them to disk as CMAF (fragmented MP4). Each
+//! MoQ group becomes one fragment, so a file stays playable up to its last
+//! complete group even if the app dies mid-recording.
+//!
+//! Codec parameters come from the catalog: the avcC record for
+//! length-prefixed H.264 (or SPS/PPS from the first keyframe for Annex B
+//! streams) and the Opus sample rate and channel count. A rendition switch,
+//! or a catalog update that changes the recorded rendition, starts a new file
+//! with its own init segment at the first group of the new track:
+//! `rec.mp4`, `rec.1.mp4`, `rec.2.mp4`, ...
+//!
+//! Recording can start and stop anywhere in the stream; the first file
+//! begins at the next video group.
+//!
+//! Encrypted broadcasts need their media key in `RecordConfig::encryption`;
+//! files are always written decrypted.
+
+use std::fs::File;
+use std::io::{BufWriter, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use bytes::Bytes;
+use hang::catalog::{AudioCodec, AudioConfig, VideoConfig};
+use hang::{Catalog, CatalogConsumer};
+use moq_lite::{BroadcastConsumer, Track, TrackConsumer};
+use n0_future::time::Duration;
+use tokio::sync::{mpsc, oneshot, watch};
+use tokio::task::JoinSet;
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
+
+use super::mp4::{
+    Mp4Codec, Mp4Sample, Mp4Track, annexb_to_avcc, avcc_from_annexb, init_segment, is_annexb,
+    media_segment, ticks,
+};
+use super::encryption::{FrameDecryptor, MediaKey};
+use super::subscribe_broadcast::{read_media_frame, select_audio_rendition, select_video_rendition};
+
+const VIDEO_TRACK_ID: u32 = 1;
+const AUDIO_TRACK_ID: u32 = 2;
+/// Conventional MP4 video timescale; audio uses its sample rate
+const VIDEO_TIMESCALE: u32 = 90_000;
+/// Duration of the last frame in a group when the catalog has no framerate
+const DEFAULT_VIDEO_FRAME_US: i64 = 33_333;
+/// Opus frames are 20ms (see [`super::opus`])
+const AUDIO_FRAME_US: i64 = 20_000;
+
+/// Recording configuration
+#[derive(Debug, Clone)]
+pub struct RecordConfig {
+    /// Preferred video quality (track name suffix: "high", "med", "low")
+    pub video_quality: Option<String>,
+    /// Preferred audio quality (track name suffix: "high", "med", "low")
+    pub audio_quality: Option<String>,
+    /// How long to wait for the catalog before giving up
+    pub catalog_timeout: Duration,
+    /// Media key of an encrypted broadcast
+    pub encryption: Option<MediaKey>,
+}
+
+impl Default for RecordConfig {
+    fn default() -> Self {
+        Self {
+            video_quality: Some("high".to_string()),
+            audio_quality: Some("high".to_string()),
+            catalog_timeout: Duration::from_secs(5),
+            encryption: None,
+        }
+    }
+}
+
+/// Progress of a recording
+#[derive(Debug, Clone, Default)]
+pub struct RecordStats {
+    /// Files written so far, one per init segment
+    pub files: Vec<PathBuf>,
+    /// Fragments (moof + mdat) written across all files
+    pub fragments: u64,
+    pub video_frames: u64,
+    pub audio_frames: u64,
+    /// Bytes written across all files
+    pub bytes: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Video,
+    Audio,
+}
+
+/// A group read from a track; partial if the recording stopped mid-group
+struct TrackGroup {
+    kind: Kind,
+    track: String,
+    /// (pts µs, payload) in decode order; the first frame is a keyframe
+    frames: Vec<(i64, Bytes)>,
+}
+
+/// Renditions a file records
+#[derive(Debug, Clone, Default, PartialEq)]
+struct Selection {
+    video: Option<(String, VideoConfig)>,
+    audio: Option<(String, AudioConfig)>,
+}
+
+impl Selection {
+    fn video_track(&self) -> Option<&str> {
+        self.video.as_ref().map(|(name, _)| name.as_str())
+    }
+
+    fn audio_track(&self) -> Option<&str> {
+        self.audio.as_ref().map(|(name, _)| name.as_str())
+    }
+}
+
+enum WriterEvent {
+    /// Renditions for the next file; takes effect at the next group
+    Select(Selection),
+    Group(TrackGroup),
+}
+
+/// Handle for a running recording
+pub struct RecordHandle {
+    cancel: CancellationToken,
+    stats: watch::Receiver<RecordStats>,
+    done: oneshot::Receiver<Result<RecordStats>>,
+}
+
+impl RecordHandle {
+    /// Progress so far
+    pub fn stats(&self) -> RecordStats {
+        self.stats.borrow().clone()
+    }
+
+    /// Stop recording and wait for the files to be flushed
+    ///
+    /// Groups in flight are written as shortened final fragments.
+    pub async fn stop(self) -> Result<RecordStats> {
+        self.cancel.cancel();
+        self.done
+            .await
+            .map_err(|_| anyhow::anyhow!("recorder exited without a result"))?
+    }
+}
+
+/// Records a broadcast to fragmented MP4 files
+pub struct Recorder {
+    broadcast: BroadcastConsumer,
+    path: PathBuf,
+    config: RecordConfig,
+    follow: Option<watch::Receiver<String>>,
+}
+
+impl Recorder {
+    /// Record `broadcast` to `path`
+    ///
+    /// Later files after a rendition switch are named after `path` with an
+    /// index before the extension.
+    pub fn new(broadcast: BroadcastConsumer, path: impl Into<PathBuf>, config: RecordConfig) -> Self {
+        Self {
+            broadcast,
+            path: path.into(),
+            config,
+            follow: None,
+        }
+    }
+
+    /// Record whichever video track `track` names, switching when it does
+    ///
+    /// Pass [`super::subscribe_broadcast::VideoSwitcher::watch_track`] to
+    /// record what a subscriber is watching, including ABR switches.
+    pub fn follow(mut self, track: watch::Receiver<String>) -> Self {
+        self.follow = Some(track);
+        self
+    }
+
+    /// Start recording
+    ///
+    /// Fails if no catalog arrives within `catalog_timeout` (the codec
+    /// parameters are needed for the init segment) or `path` can't be
+    /// created.
+    pub async fn start(mut self) -> Result<RecordHandle> {
+        let mut catalog_consumer =
+            CatalogConsumer::new(self.broadcast.subscribe_track(&Catalog::default_track()));
+        let catalog = n0_future::time::timeout(self.config.catalog_timeout, catalog_consumer.next())
+            .await
+            .map_err(|_| anyhow::anyhow!("no catalog received"))??
+            .context("catalog track ended before a catalog was published")?;
+
+        let file = File::create(&self.path)
+            .with_context(|| format!("failed to create {}", self.path.display()))?;
+
+        let cancel = CancellationToken::new();
+        let (groups_tx, groups_rx) = mpsc::channel(16);
+        let (writer_tx, writer_rx) = mpsc::channel(16);
+        let (stats_tx, stats_rx) = watch::channel(RecordStats::default());
+        let (done_tx, done_rx) = oneshot::channel();
+
+        let writer = Mp4Writer::new(self.path.clone(), file, stats_tx);
+        std::thread::spawn(move || {
+            let _ = done_tx.send(run_writer(writer, writer_rx));
+        });
+
+        let preferred_video = self.follow.as_mut().map(|track| track.borrow_and_update().clone());
+        let task = RecordTask {
+            broadcast: self.broadcast,
+            catalog,
+            config: self.config,
+            preferred_video,
+            selection: Selection::default(),
+            groups_tx,
+            readers: JoinSet::new(),
+            video_reader: None,
+            audio_reader: None,
+            stale_video: None,
+            cancel: cancel.clone(),
+        };
+        tokio::spawn(task.run(catalog_consumer, self.follow, groups_rx, writer_tx));
+
+        info!("recording to {}", self.path.display());
+        Ok(RecordHandle {
+            cancel,
+            stats: stats_rx,
+            done: done_rx,
+        })
+    }
+}
+
+/// Follows the catalog and rendition switches, feeding groups to the writer
+struct RecordTask {
+    broadcast: BroadcastConsumer,
+    catalog: Catalog,
+    config: RecordConfig,
+    /// Video track to record instead of the configured quality
+    preferred_video: Option<String>,
+    selection: Selection,
+    groups_tx: mpsc::Sender<TrackGroup>,
+    readers: JoinSet<()>,
+    video_reader: Option<(String, CancellationToken)>,
+    audio_reader: Option<(String, CancellationToken)>,
+    /// Previous video reader, kept until the new track delivers a group
+    stale_video: Option<CancellationToken>,
+    cancel: CancellationToken,
+}
+
+impl RecordTask {
+    #[instrument(skip_all, name = "recorder")]
+    async fn run(
+        mut self,
+        mut catalog_consumer: CatalogConsumer,
+        mut follow: Option<watch::Receiver<String>>,
+        mut groups_rx: mpsc::Receiver<TrackGroup>,
+        writer_tx: mpsc::Sender<WriterEvent>,
+    ) {
+        if self.update(&writer_tx).await.is_err() {
+            self.cancel.cancel();
+        }
+        let mut catalog_open = true;
+
+        loop {
+            tokio::select! {
+                _ = self.cancel.cancelled() => break,
+                result = catalog_consumer.next(), if catalog_open => match result {
+                    Ok(Some(catalog)) => {
+                        debug!("catalog updated");
+                        self.catalog = catalog;
+                        if self.update(&writer_tx).await.is_err() {
+                            break;
+                        }
+                    }
+                    Ok(None) | Err(_) => catalog_open = false,
+                },
+                Ok(()) = async { follow.as_mut().unwrap().changed().await }, if follow.is_some() => {
+                    self.preferred_video = follow.as_mut().map(|track| track.borrow_and_update().clone());
+                    if self.update(&writer_tx).await.is_err() {
+                        break;
+                    }
+                }
+                Some(group) = groups_rx.recv() => {
+                    // The new video track is flowing; the old one is no longer needed
+                    let current = self.video_reader.as_ref().map(|(name, _)| name);
+                    if group.kind == Kind::Video && current == Some(&group.track) {
+                        if let Some(stale) = self.stale_video.take() {
+                            stale.cancel();
+                        }
+                    }
+                    if writer_tx.send(WriterEvent::Group(group)).await.is_err() {
+                        break;
+                    }
+                }
+                _ = self.readers.join_next(), if !self.readers.is_empty() => {
+                    if self.readers.is_empty() {
+                        info!("broadcast ended");
+                        break;
+                    }
+                }
+            }
+        }
+
+        // Readers flush their partial groups on cancellation
+        self.cancel.cancel();
+        drop(self.groups_tx);
+        while let Some(group) = groups_rx.recv().await {
+            if writer_tx.send(WriterEvent::Group(group)).await.is_err() {
+                break;
+            }
+        }
+        info!("recording stopped");
+    }
+
+    /// The renditions to record under the current catalog and preferences
+    fn select(&self) -> Selection {
+        let video = self
+            .preferred_video
+            .clone()
+            .filter(|name| {
+                self.catalog
+                    .video
+                    .as_ref()
+                    .is_some_and(|video| video.renditions.contains_key(name))
+            })
+            .or_else(|| select_video_rendition(&self.catalog, self.config.video_quality.as_deref()))
+            .and_then(|name| {
+                let config = self.catalog.video.as_ref()?.renditions.get(&name)?.clone();
+                Some((name, config))
+            });
+        let audio = select_audio_rendition(&self.catalog, self.config.audio_quality.as_deref())
+            .and_then(|name| {
+                let config = self.catalog.audio.as_ref()?.renditions.get(&name)?.clone();
+                Some((name, config))
+            });
+        Selection { video, audio }
+    }
+
+    /// Re-select renditions, subscribing to any track that changed
+    async fn update(&mut self, writer_tx: &mpsc::Sender<WriterEvent>) -> Result<()> {
+        let selection = self.select();
+        if selection == self.selection {
+            return Ok(());
+        }
+
+        let video = selection.video_track().map(str::to_string);
+        if video.as_ref() != self.video_reader.as_ref().map(|(name, _)| name) {
+            info!(from = ?self.video_reader.as_ref().map(|(name, _)| name), to = ?video, "recording video track");
+            if let Some((_, old)) = self.video_reader.take() {
+                if let Some(stale) = self.stale_video.replace(old) {
+                    stale.cancel();
+                }
+            }
+            self.video_reader = video.map(|name| {
+                let cancel = self.spawn_reader(Kind::Video, name.clone());
+                (name, cancel)
+            });
+        }
+
+        // Audio groups are short, so there's no need to overlap the tracks
+        let audio = selection.audio_track().map(str::to_string);
+        if audio.as_ref() != self.audio_reader.as_ref().map(|(name, _)| name) {
+            if let Some((_, old)) = self.audio_reader.take() {
+                old.cancel();
+            }
+            self.audio_reader = audio.map(|name| {
+                let cancel = self.spawn_reader(Kind::Audio, name.clone());
+                (name, cancel)
+            });
+        }
+
+        self.selection = selection.clone();
+        writer_tx
+            .send(WriterEvent::Select(selection))
+            .await
+            .map_err(|_| anyhow::anyhow!("writer stopped"))
+    }
+
+    fn spawn_reader(&mut self, kind: Kind, name: String) -> CancellationToken {
+        let consumer = self.broadcast.subscribe_track(&Track {
+            name: name.clone(),
+            priority: 0,
+        });
+        let cancel = self.cancel.child_token();
+        let decryptor = self.config.encryption.clone().map(FrameDecryptor::new);
+        self.readers.spawn(read_groups(
+            kind,
+            name,
+            consumer,
+            decryptor,
+            self.groups_tx.clone(),
+            cancel.clone(),
+        ));
+        cancel
+    }
+}
+
+/// Read whole groups from a track until it ends or is cancelled
+async fn read_groups(
+    kind: Kind,
+    name: String,
+    mut consumer: TrackConsumer,
+    mut decryptor: Option<FrameDecryptor>,
+    groups_tx: mpsc::Sender<TrackGroup>,
+    cancel: CancellationToken,
+) {
+    loop {
+        let mut group = tokio::select! {
+            _ = cancel.cancelled() => return,
+            result = consumer.next_group() => match result {
+                Ok(Some(group)) => hang::GroupConsumer::new(group),
+                Ok(None) => {
+                    debug!("track ended: {name}");
+                    return;
+                }
+                Err(e) => {
+                    warn!("track error on {name}: {e}");
+                    return;
+                }
+            },
+        };
+
+        let mut frames = Vec::new();
+        let mut stopped = false;
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => {
+                    stopped = true;
+                    break;
+                }
+                result = group.read() => match result {
+                    Ok(Some(frame)) => match read_media_frame(frame, decryptor.as_mut()) {
+                        Ok(frame) => frames.push(frame),
+                        Err(e) => warn!("unreadable frame on {name}: {e}"),
+                    },
+                    // End of group (or a reset group); keep what arrived
+                    Ok(None) | Err(_) => break,
+                },
+            }
+        }
+
+        if !frames.is_empty() {
+            let group = TrackGroup {
+                kind,
+                track: name.clone(),
+                frames,
+            };
+            if groups_tx.send(group).await.is_err() {
+                return;
+            }
+        }
+        if stopped {
+            return;
+        }
+    }
+}
+
+/// Writes fragments to the current file, starting new files as needed
+struct Mp4Writer {
+    path: PathBuf,
+    /// Created up front by [`Recorder::start`], used for the first file
+    first: Option<File>,
+    file: Option<BufWriter<File>>,
+    /// Renditions requested for the next file
+    pending: Selection,
+    /// Renditions in the current file
+    current: Selection,
+    /// Timestamp (µs) at time zero of the current file
+    origin_us: Option<i64>,
+    /// mfhd sequence number of the last fragment in the current file
+    sequence: u32,
+    stats: RecordStats,
+    stats_tx: watch::Sender<RecordStats>,
+}
+
+impl Mp4Writer {
+    fn new(path: PathBuf, first: File, stats_tx: watch::Sender<RecordStats>) -> Self {
+        Self {
+            path,
+            first: Some(first),
+            file: None,
+            pending: Selection::default(),
+            current: Selection::default(),
+            origin_us: None,
+            sequence: 0,
+            stats: RecordStats::default(),
+            stats_tx,
+        }
+    }
+
+    fn write_group(&mut self, group: TrackGroup) -> Result<()> {
+        // New files start on a video group when there's video, so they open on a keyframe
+        let starts_file = self.pending != self.current
+            && match group.kind {
+                Kind::Video => self.pending.video_track() == Some(group.track.as_str()),
+                Kind::Audio => {
+                    self.pending.video.is_none()
+                        && self.pending.audio_track() == Some(group.track.as_str())
+                }
+            };
+        if starts_file && !self.start_file(&group)? {
+            return Ok(());
+        }
+
+        let current = match group.kind {
+            Kind::Video => self.current.video_track(),
+            Kind::Audio => self.current.audio_track(),
+        };
+        if self.file.is_none() || current != Some(group.track.as_str()) {
+            // Before the first file, or a leftover group from a replaced track
+            return Ok(());
+        }
+
+        match group.kind {
+            Kind::Video => {
+                let frame_us = self
+                    .current
+                    .video
+                    .as_ref()
+                    .and_then(|(_, config)| config.framerate)
+                    .filter(|fps| *fps > 0.0)
+                    .map_or(DEFAULT_VIDEO_FRAME_US, |fps| (1_000_000.0 / fps) as i64);
+                self.write_fragment(VIDEO_TRACK_ID, VIDEO_TIMESCALE, frame_us, group)
+            }
+            Kind::Audio => {
+                let sample_rate = self.current.audio.as_ref().map_or(48_000, |(_, c)| c.sample_rate);
+                self.write_fragment(AUDIO_TRACK_ID, sample_rate, AUDIO_FRAME_US, group)
+            }
+        }
+    }
+
+    /// Close the current file and open the next one with the pending renditions
+    ///
+    /// Returns false (writing nothing) if an Annex B stream's keyframe lacks
+    /// SPS/PPS; the next group is tried instead.
+    fn start_file(&mut self, group: &TrackGroup) -> Result<bool> {
+        let mut selection = self.pending.clone();
+        let mut tracks = Vec::new();
+
+        if let Some((name, config)) = &selection.video {
+            let avcc = match &config.description {
+                Some(description) => description.clone(),
+                None => {
+                    let keyframe = group.frames.first().map(|(_, data)| data.as_ref()).unwrap_or_default();
+                    match avcc_from_annexb(keyframe) {
+                        Some(record) => Bytes::from(record),
+                        None => {
+                            debug!("no SPS/PPS in the first group of {name}, waiting");
+                            return Ok(false);
+                        }
+                    }
+                }
+            };
+            tracks.push(Mp4Track {
+                id: VIDEO_TRACK_ID,
+                timescale: VIDEO_TIMESCALE,
+                codec: Mp4Codec::Avc {
+                    width: config.coded_width.unwrap_or(0),
+                    height: config.coded_height.unwrap_or(0),
+                    avcc,
+                },
+            });
+        }
+
+        if let Some((name, config)) = &selection.audio {
+            if matches!(config.codec, AudioCodec::Opus) {
+                tracks.push(Mp4Track {
+                    id: AUDIO_TRACK_ID,
+                    timescale: config.sample_rate,
+                    codec: Mp4Codec::Opus {
+                        sample_rate: config.sample_rate,
+                        channel_count: config.channel_count,
+                    },
+                });
+            } else {
+                warn!("not recording {name}: unsupported codec {}", config.codec);
+                selection.audio = None;
+            }
+        }
+
+        self.finish_file()?;
+        let path = segment_path(&self.path, self.stats.files.len());
+        let file = match self.first.take() {
+            Some(file) => file,
+            None => File::create(&path).with_context(|| format!("failed to create {}", path.display()))?,
+        };
+        let mut file = BufWriter::new(file);
+        let init = init_segment(&tracks);
+        file.write_all(&init)?;
+
+        info!(video = ?selection.video_track(), audio = ?selection.audio_track(), "started {}", path.display());
+        self.stats.bytes += init.len() as u64;
+        self.stats.files.push(path);
+        self.stats_tx.send_replace(self.stats.clone());
+
+        // An unsupported audio rendition stays out without forcing another file
+        self.current = selection;
+        self.pending = self.current.clone();
+        self.file = Some(file);
+        self.origin_us = None;
+        self.sequence = 0;
+        Ok(true)
+    }
+
+    fn write_fragment(&mut self, track_id: u32, timescale: u32, last_us: i64, group: TrackGroup) -> Result<()> {
+        let Some(file) = self.file.as_mut() else {
+            return Ok(());
+        };
+        let origin = *self.origin_us.get_or_insert(group.frames[0].0);
+        let is_video = group.kind == Kind::Video;
+
+        // Durations come from timestamp differences, so rounding doesn't drift
+        let frames: Vec<_> = group.frames.into_iter().filter(|(pts, _)| *pts >= origin).collect();
+        let Some(&(first_pts, _)) = frames.first() else {
+            return Ok(());
+        };
+        let samples: Vec<_> = frames
+            .iter()
+            .enumerate()
+            .map(|(index, (pts, data))| {
+                let next = frames.get(index + 1).map_or(pts + last_us, |(next, _)| *next);
+                let duration = ticks(next - origin, timescale).saturating_sub(ticks(pts - origin, timescale));
+                let data = if is_video && is_annexb(data) {
+                    Bytes::from(annexb_to_avcc(data))
+                } else {
+                    data.clone()
+                };
+                Mp4Sample {
+                    data,
+                    duration: duration as u32,
+                    keyframe: !is_video || index == 0,
+                }
+            })
+            .collect();
+
+        self.sequence += 1;
+        let segment = media_segment(self.sequence, track_id, ticks(first_pts - origin, timescale), &samples);
+        file.write_all(&segment)?;
+        // Flush per fragment so the file is playable up to here
+        file.flush()?;
+
+        self.stats.fragments += 1;
+        self.stats.bytes += segment.len() as u64;
+        match group.kind {
+            Kind::Video => self.stats.video_frames += samples.len() as u64,
+            Kind::Audio => self.stats.audio_frames += samples.len() as u64,
+        }
+        self.stats_tx.send_replace(self.stats.clone());
+        Ok(())
+    }
+
+    fn finish_file(&mut self) -> Result<()> {
+        if let Some(mut file) = self.file.take() {
+            file.flush()?;
+        }
+        Ok(())
+    }
+
+    fn finish(mut self) -> Result<RecordStats> {
+        self.finish_file()?;
+        // Nothing was recorded; don't leave an empty file behind
+        if self.first.take().is_some() {
+            let _ = std::fs::remove_file(&self.path);
+        }
+        Ok(self.stats)
+    }
+}
+
+/// Write groups until the coordinator goes away
+fn run_writer(mut writer: Mp4Writer, mut rx: mpsc::Receiver<WriterEvent>) -> Result<RecordStats> {
+    while let Some(event) = rx.blocking_recv() {
+        match event {
+            WriterEvent::Select(selection) => writer.pending = selection,
+            WriterEvent::Group(group) => writer.write_group(group)?,
+        }
+    }
+    let stats = writer.finish()?;
+    info!(files = stats.files.len(), fragments = stats.fragments, "recording finished");
+    Ok(stats)
+}
+
+/// Path of the `index`th file of a recording: `rec.mp4`, `rec.1.mp4`, ...
+pub fn segment_path(path: &Path, index: usize) -> PathBuf {
+    if index == 0 {
+        return path.to_path_buf();
+    }
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    let name = match path.extension() {
+        Some(ext) => format!("{stem}.{index}.{}", ext.to_string_lossy()),
+        None => format!("{stem}.{index}"),
+    };
+    path.with_file_name(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::publish_broadcast::{AudioQuality, VideoQuality};
+
+    /// Annex B keyframe with SPS and PPS
+    const KEYFRAME: &[u8] = &[0, 0, 0, 1, 0x67, 0x42, 0xe0, 0x1e, 0, 0, 0, 1, 0x68, 0xce, 0, 0, 0, 1, 0x65, 0x88];
+    const DELTA: &[u8] = &[0, 0, 0, 1, 0x41, 0x9a];
+
+    fn selection(video: VideoQuality) -> Selection {
+        Selection {
+            video: Some((format!("video.{}", video.suffix()), video.catalog_config())),
+            audio: Some(("audio.med".to_string(), AudioQuality::Medium.catalog_config())),
+        }
+    }
+
+    fn video_group(track: &str, start_us: i64) -> TrackGroup {
+        TrackGroup {
+            kind: Kind::Video,
+            track: track.to_string(),
+            frames: vec![
+                (start_us, Bytes::from_static(KEYFRAME)),
+                (start_us + 33_333, Bytes::from_static(DELTA)),
+            ],
+        }
+    }
+
+    fn audio_group(start_us: i64) -> TrackGroup {
+        TrackGroup {
+            kind: Kind::Audio,
+            track: "audio.med".to_string(),
+            frames: vec![(start_us, Bytes::from_static(b"opus"))],
+        }
+    }
+
+    #[test]
+    fn test_segment_path() {
+        let path = Path::new("/tmp/rec.mp4");
+        assert_eq!(segment_path(path, 0), PathBuf::from("/tmp/rec.mp4"));
+        assert_eq!(segment_path(path, 2), PathBuf::from("/tmp/rec.2.mp4"));
+        assert_eq!(segment_path(Path::new("rec"), 1), PathBuf::from("rec.1"));
+    }
+
+    #[test]
+    fn test_rendition_switch_starts_new_file() {
+        let dir = std::env::temp_dir().join(format!("record-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("rec.mp4");
+        let (stats_tx, _stats_rx) = watch::channel(RecordStats::default());
+        let mut writer = Mp4Writer::new(path.clone(), File::create(&path).unwrap(), stats_tx);
+
+        writer.pending = selection(VideoQuality::Medium);
+        // Audio before the first keyframe is dropped
+        writer.write_group(audio_group(0)).unwrap();
+        writer.write_group(video_group("video.med", 1_000_000)).unwrap();
+        writer.write_group(audio_group(1_000_000)).unwrap();
+
+        // Switch: old-track groups keep going to the current file until the new track arrives
+        writer.pending = selection(VideoQuality::High);
+        writer.write_group(video_group("video.med", 2_000_000)).unwrap();
+        writer.write_group(video_group("video.high", 2_000_000)).unwrap();
+        writer.write_group(video_group("video.med", 3_000_000)).unwrap();
+
+        let stats = writer.finish().unwrap();
+        assert_eq!(stats.files, [path.clone(), dir.join("rec.1.mp4")]);
+        assert_eq!(stats.fragments, 4);
+        assert_eq!(stats.video_frames, 6);
+        assert_eq!(stats.audio_frames, 1);
+
+        // Each file opens with its own init segment
+        for file in &stats.files {
+            let data = std::fs::read(file).unwrap();
+            assert_eq!(&data[4..8], b"ftyp");
+        }
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
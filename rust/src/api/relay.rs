@@ -0,0 +1,282 @@
+//! Relaying remote broadcasts to downstream peers
+//!
+//! A relay subscribes to a broadcast on an upstream node and re-publishes
+//! the resulting `BroadcastConsumer` through its own [`Live`] actor, so
+//! viewers fan out from the relay instead of all pulling from the publisher.
+//! Tracks are only fetched upstream while a downstream viewer wants them.
+//!
+//! Every node answers route queries on [`ROUTE_ALPN`] with the path a
+//! broadcast took to reach it. Before relaying, a node asks its upstream for
+//! that path and refuses if it is already on it, so relays can't form a
+//! loop; overly long routes are refused too. Route queries go through the
+//! same [`AccessControl`](super::access::AccessControl) as subscriptions, so
+//! a restricted broadcast's route is only revealed to peers allowed to watch
+//! it. Peers that aren't are told why instead, which is how a viewer learns
+//! that a broadcast was withdrawn from it for lack of access.
+//!
+//! A relayed broadcast is withdrawn when the upstream session closes or the
+//! upstream stops announcing it.
+
+use anyhow::{Context, Result, bail};
+use iroh::endpoint::Connection;
+use iroh::protocol::ProtocolHandler;
+use iroh::{Endpoint, EndpointAddr, EndpointId};
+use n0_future::time::Duration;
+use serde::{Deserialize, Serialize};
+use tokio_util::sync::CancellationToken;
+use tracing::{Instrument, debug, info, info_span, warn};
+
+use super::access::SignedToken;
+use super::live_protocol::{Live, Route};
+
+/// ALPN for route queries between relays
+pub const ROUTE_ALPN: &[u8] = b"iroh-live/route/1";
+
+/// Upper bound on route query messages
+const MAX_ROUTE_MESSAGE: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteRequest {
+    broadcast: String,
+    /// Token for the broadcast, if it is restricted on the queried node
+    token: Option<SignedToken>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RouteResponse {
+    /// None if the broadcast isn't published on the queried node, or the
+    /// querier may not receive it
+    route: Option<Route>,
+    /// Why the querier may not receive the broadcast
+    denied: Option<String>,
+}
+
+/// Relay configuration
+#[derive(Debug, Clone)]
+pub struct RelayConfig {
+    /// Longest route (nodes before this one) to relay from
+    pub max_hops: usize,
+    /// How long to wait for the upstream to announce the broadcast
+    pub announce_timeout: Duration,
+    /// Token for the upstream, if the broadcast is restricted there
+    pub token: Option<SignedToken>,
+}
+
+impl Default for RelayConfig {
+    fn default() -> Self {
+        Self {
+            max_hops: 8,
+            announce_timeout: Duration::from_secs(10),
+            token: None,
+        }
+    }
+}
+
+/// Check that a broadcast arriving over `route` may be relayed by `local`
+pub fn check_route(route: &[EndpointId], local: EndpointId, max_hops: usize) -> Result<()> {
+    if route.contains(&local) {
+        bail!("relay loop: this node is already on the broadcast's route");
+    }
+    if route.len() > max_hops {
+        bail!("route too long: {} hops (max {max_hops})", route.len());
+    }
+    Ok(())
+}
+
+/// Answers route queries from the local [`Live`] actor
+#[derive(Debug, Clone)]
+pub struct RouteProtocolHandler {
+    live: Live,
+}
+
+impl RouteProtocolHandler {
+    pub fn new(live: Live) -> Self {
+        Self { live }
+    }
+
+    async fn handle_connection(&self, conn: Connection) -> Result<()> {
+        let (mut send, mut recv) = conn.accept_bi().await?;
+        let request: RouteRequest = postcard::from_bytes(&recv.read_to_end(MAX_ROUTE_MESSAGE).await?)?;
+        let response = match self.live.access().check(conn.remote_id(), &request.broadcast, request.token.as_ref()) {
+            Ok(()) => RouteResponse {
+                route: self.live.route(&request.broadcast).await?,
+                denied: None,
+            },
+            Err(denied) => {
+                debug!(broadcast = %request.broadcast, remote = %conn.remote_id().fmt_short(), "route query denied: {denied}");
+                RouteResponse {
+                    route: None,
+                    denied: Some(denied.to_string()),
+                }
+            }
+        };
+        debug!(broadcast = %request.broadcast, route = ?response.route, "answering route query");
+
+        send.write_all(&postcard::to_stdvec(&response)?).await?;
+        send.finish()?;
+        // Let the querier read the response before the connection goes away
+        conn.closed().await;
+        Ok(())
+    }
+}
+
+impl ProtocolHandler for RouteProtocolHandler {
+    async fn accept(&self, conn: Connection) -> Result<(), iroh::protocol::AcceptError> {
+        if let Err(e) = self.handle_connection(conn).await {
+            warn!("route query failed: {e:#}");
+        }
+        Ok(())
+    }
+}
+
+/// Ask `upstream` for the route of a broadcast it publishes
+///
+/// Returns None if the upstream doesn't publish it, or `token` doesn't
+/// admit this node to it.
+pub async fn query_route(
+    endpoint: &Endpoint,
+    upstream: EndpointAddr,
+    broadcast: &str,
+    token: Option<&SignedToken>,
+) -> Result<Option<Route>> {
+    Ok(query(endpoint, upstream, broadcast, token).await?.route)
+}
+
+/// Ask `upstream` why `token` doesn't admit this node to a broadcast
+///
+/// Returns None if it does, or if the broadcast is open.
+pub async fn query_denied(
+    endpoint: &Endpoint,
+    upstream: EndpointAddr,
+    broadcast: &str,
+    token: Option<&SignedToken>,
+) -> Result<Option<String>> {
+    Ok(query(endpoint, upstream, broadcast, token).await?.denied)
+}
+
+async fn query(
+    endpoint: &Endpoint,
+    upstream: EndpointAddr,
+    broadcast: &str,
+    token: Option<&SignedToken>,
+) -> Result<RouteResponse> {
+    let conn = endpoint
+        .connect(upstream, ROUTE_ALPN)
+        .await
+        .context("failed to connect for route query")?;
+    let (mut send, mut recv) = conn.open_bi().await?;
+    let request = RouteRequest {
+        broadcast: broadcast.to_string(),
+        token: token.cloned(),
+    };
+    send.write_all(&postcard::to_stdvec(&request)?).await?;
+    send.finish()?;
+
+    let response: RouteResponse = postcard::from_bytes(&recv.read_to_end(MAX_ROUTE_MESSAGE).await?)?;
+    conn.close(0u32.into(), b"done");
+    Ok(response)
+}
+
+/// Handle for a broadcast being relayed
+#[derive(Debug, Clone)]
+pub struct RelayHandle {
+    name: String,
+    route: Route,
+    cancel: CancellationToken,
+}
+
+impl RelayHandle {
+    /// Name of the relayed broadcast
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Route the broadcast takes to reach this node, origin first
+    pub fn route(&self) -> &[EndpointId] {
+        &self.route
+    }
+
+    /// Whether the broadcast is still being relayed
+    pub fn is_active(&self) -> bool {
+        !self.cancel.is_cancelled()
+    }
+
+    /// Stop relaying and withdraw the broadcast
+    pub fn stop(&self) {
+        self.cancel.cancel();
+    }
+}
+
+/// Subscribe to `name` on `upstream` and re-publish it through `live`
+///
+/// Downstream peers connected to `live` see the broadcast as if it were
+/// local. Fails if relaying would create a loop or exceed `max_hops`.
+pub async fn relay(live: &Live, upstream: EndpointAddr, name: &str, config: &RelayConfig) -> Result<RelayHandle> {
+    let local = live.endpoint().id();
+    let upstream_id = upstream.id;
+    if upstream_id == local {
+        bail!("cannot relay from this node");
+    }
+
+    let mut route = query_route(live.endpoint(), upstream.clone(), name, config.token.as_ref())
+        .await?
+        .with_context(|| format!("upstream does not publish '{name}'"))?;
+    route.push(upstream_id);
+    check_route(&route, local, config.max_hops)?;
+
+    let mut session = live.connect_with_token(upstream, config.token.as_ref()).await?;
+    let broadcast = n0_future::time::timeout(config.announce_timeout, session.subscribe(name))
+        .await
+        .map_err(|_| anyhow::anyhow!("broadcast '{name}' was not announced upstream"))??;
+    live.publish_relayed(name, broadcast, route.clone()).await?;
+    info!(broadcast = %name, upstream = %upstream_id.fmt_short(), hops = route.len(), "relaying broadcast");
+
+    let cancel = CancellationToken::new();
+    let handle = RelayHandle {
+        name: name.to_string(),
+        route,
+        cancel: cancel.clone(),
+    };
+
+    // Withdraw the broadcast once the upstream goes away
+    let live = live.clone();
+    let name = name.to_string();
+    let span = info_span!("relay", broadcast = %name);
+    tokio::spawn(
+        async move {
+            tokio::select! {
+                _ = cancel.cancelled() => debug!("relay stopped"),
+                _ = session.broadcast_ended(&name) => info!("upstream ended"),
+            }
+            cancel.cancel();
+            if let Err(e) = live.unpublish_relayed(&name, upstream_id).await {
+                debug!("failed to withdraw relayed broadcast: {e}");
+            }
+            // Dropping the session unsubscribes upstream
+            drop(session);
+        }
+        .instrument(span),
+    );
+
+    Ok(handle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use iroh::SecretKey;
+
+    fn id(seed: u8) -> EndpointId {
+        SecretKey::from_bytes(&[seed; 32]).public()
+    }
+
+    #[test]
+    fn test_check_route() {
+        let (origin, relay, local) = (id(1), id(2), id(3));
+        assert!(check_route(&[origin, relay], local, 8).is_ok());
+        // The broadcast already passed through this node
+        assert!(check_route(&[origin, local, relay], local, 8).is_err());
+        assert!(check_route(&[local], local, 8).is_err());
+        assert!(check_route(&[origin, relay], local, 1).is_err());
+    }
+}
@@ -0,0 +1,439 @@
+//! Replay of an MP4 file as a live broadcast
+//!
+//! [`FileReplay`] demuxes a file with [`Mp4Reader`] and feeds its samples
+//! into a [`PublishBroadcast`](super::publish_broadcast::PublishBroadcast)
+//! through its [`PublishHandle`], paced in real time by their timestamps.
+//! Viewers join it like any other broadcast instead of downloading the file.
+//!
+//! The file's first H.264 track becomes a single video rendition (named
+//! after the nearest [`VideoQuality`]) with its avcC in the catalog, and its
+//! first AAC or Opus track a single audio rendition. Timestamps keep
+//! increasing across loops and seeks, and a seek resumes at the keyframe at
+//! or before the target.
+
+use std::path::Path;
+use std::time::Instant;
+
+use anyhow::{Result, bail};
+use hang::catalog::{AAC, AudioCodec, AudioConfig, H264, VideoCodec, VideoConfig};
+use n0_future::time::Duration;
+use tokio::sync::{mpsc, watch};
+use tokio_util::sync::CancellationToken;
+use tracing::{debug, info, instrument, warn};
+
+use super::mp4::{Mp4Codec, Mp4Reader, Mp4TrackInfo};
+use super::publish_broadcast::{
+    AudioQuality, EncodedAudioFrame, EncodedVideoFrame, PublishConfig, PublishHandle, VideoQuality,
+};
+
+/// The audio rendition a file is published as
+const AUDIO_QUALITY: AudioQuality = AudioQuality::Medium;
+
+/// Replay configuration
+#[derive(Debug, Clone, Default)]
+pub struct ReplayConfig {
+    /// Start over at the end of the file instead of stopping
+    pub looping: bool,
+}
+
+enum ReplayCommand {
+    Seek(Duration),
+    SetLooping(bool),
+}
+
+/// Controls a running replay
+#[derive(Clone)]
+pub struct ReplayHandle {
+    tx: mpsc::UnboundedSender<ReplayCommand>,
+    position: watch::Receiver<Duration>,
+    duration: Duration,
+    cancel: CancellationToken,
+}
+
+impl ReplayHandle {
+    /// Jump to `position` in the file
+    ///
+    /// Playback resumes at the closest keyframe at or before it.
+    pub fn seek(&self, position: Duration) -> Result<()> {
+        self.tx
+            .send(ReplayCommand::Seek(position))
+            .map_err(|_| anyhow::anyhow!("replay ended"))
+    }
+
+    /// Turn looping on or off
+    pub fn set_looping(&self, looping: bool) -> Result<()> {
+        self.tx
+            .send(ReplayCommand::SetLooping(looping))
+            .map_err(|_| anyhow::anyhow!("replay ended"))
+    }
+
+    /// Position in the file of the last sample sent
+    pub fn position(&self) -> Duration {
+        *self.position.borrow()
+    }
+
+    /// Length of the file
+    pub fn duration(&self) -> Duration {
+        self.duration
+    }
+
+    /// Whether the replay is still running
+    pub fn is_running(&self) -> bool {
+        !self.tx.is_closed()
+    }
+
+    /// Stop sending samples
+    pub fn stop(&self) {
+        self.cancel.cancel();
+    }
+}
+
+/// An MP4 file ready to be replayed as a broadcast
+pub struct FileReplay {
+    reader: Mp4Reader,
+    /// Track index and rendition of the video track
+    video: Option<(usize, VideoQuality)>,
+    /// Track index of the audio track
+    audio: Option<usize>,
+    /// Samples of both tracks in decode order: (dts µs, track index, sample index)
+    timeline: Vec<(i64, usize, usize)>,
+    config: ReplayConfig,
+}
+
+impl FileReplay {
+    /// Open a file and index its samples
+    pub fn open(path: impl AsRef<Path>, config: ReplayConfig) -> Result<Self> {
+        let reader = Mp4Reader::open(path)?;
+        let tracks = reader.tracks();
+
+        let video = tracks.iter().position(Mp4TrackInfo::is_video).map(|index| {
+            let quality = match &tracks[index].codec {
+                Mp4Codec::Avc { height, .. } => quality_for_height(*height),
+                _ => VideoQuality::Medium,
+            };
+            (index, quality)
+        });
+        let audio = tracks.iter().position(|track| !track.is_video());
+        if let Some((index, _)) = video {
+            if let Mp4Codec::Avc { avcc, .. } = &tracks[index].codec {
+                if avcc.len() < 4 {
+                    bail!("invalid avcC record in track {}", tracks[index].id);
+                }
+            }
+        }
+
+        let mut timeline: Vec<_> = [video.map(|(index, _)| index), audio]
+            .into_iter()
+            .flatten()
+            .flat_map(|index| {
+                let track = &tracks[index];
+                track
+                    .samples
+                    .iter()
+                    .enumerate()
+                    .map(move |(sample, info)| (track.to_us(info.dts as i64), index, sample))
+            })
+            .collect();
+        timeline.sort_by_key(|&(dts, track, _)| (dts, track));
+        if timeline.is_empty() {
+            bail!("file has no samples");
+        }
+
+        Ok(Self {
+            reader,
+            video,
+            audio,
+            timeline,
+            config,
+        })
+    }
+
+    /// Length of the longest replayed track
+    pub fn duration(&self) -> Duration {
+        let duration_us = [self.video.map(|(index, _)| index), self.audio]
+            .into_iter()
+            .flatten()
+            .map(|index| self.reader.tracks()[index].duration_us())
+            .max()
+            .unwrap_or(0);
+        Duration::from_micros(duration_us.max(0) as u64)
+    }
+
+    /// Rendition the video track is published as
+    pub fn video_quality(&self) -> Option<VideoQuality> {
+        self.video.map(|(_, quality)| quality)
+    }
+
+    /// Catalog entry for the video track
+    pub fn video_config(&self) -> Option<VideoConfig> {
+        let track = &self.reader.tracks()[self.video?.0];
+        let Mp4Codec::Avc { width, height, avcc } = &track.codec else {
+            return None;
+        };
+        let duration_us = track.duration_us();
+        Some(VideoConfig {
+            codec: VideoCodec::H264(H264 {
+                profile: avcc[1],
+                constraints: avcc[2],
+                level: avcc[3],
+            }),
+            description: Some(avcc.clone()),
+            coded_width: Some(*width),
+            coded_height: Some(*height),
+            display_ratio_width: None,
+            display_ratio_height: None,
+            bitrate: Some(track.bitrate()),
+            framerate: (duration_us > 0).then(|| track.samples.len() as f64 * 1_000_000.0 / duration_us as f64),
+            optimize_for_latency: Some(false),
+        })
+    }
+
+    /// Catalog entry for the audio track
+    pub fn audio_config(&self) -> Option<AudioConfig> {
+        let track = &self.reader.tracks()[self.audio?];
+        let config = match &track.codec {
+            Mp4Codec::Aac { sample_rate, channel_count, config } => AudioConfig {
+                codec: AudioCodec::AAC(AAC {
+                    profile: config.first().map_or(2, |byte| byte >> 3),
+                }),
+                sample_rate: *sample_rate,
+                channel_count: *channel_count,
+                bitrate: Some(track.bitrate()),
+                description: Some(config.clone()),
+            },
+            Mp4Codec::Opus { sample_rate, channel_count } => AudioConfig {
+                codec: AudioCodec::Opus,
+                sample_rate: *sample_rate,
+                channel_count: *channel_count,
+                bitrate: Some(track.bitrate()),
+                description: None,
+            },
+            Mp4Codec::Avc { .. } => return None,
+        };
+        Some(config)
+    }
+
+    /// Publish configuration with this file's renditions
+    pub fn publish_config(&self, name: impl ToString) -> PublishConfig {
+        PublishConfig {
+            name: name.to_string(),
+            video_qualities: self.video_quality().into_iter().collect(),
+            audio_qualities: self.audio.map(|_| AUDIO_QUALITY).into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Start replaying into a broadcast created from [`Self::publish_config`]
+    ///
+    /// Replaces the catalog entries with the file's codec configuration
+    /// before the first sample is sent.
+    pub fn start(self, handle: PublishHandle) -> Result<ReplayHandle> {
+        if let (Some(quality), Some(config)) = (self.video_quality(), self.video_config()) {
+            handle.set_video_config(quality, config)?;
+        }
+        if let Some(config) = self.audio_config() {
+            handle.set_audio_config(AUDIO_QUALITY, config)?;
+        }
+
+        let (tx, rx) = mpsc::unbounded_channel();
+        let (position_tx, position) = watch::channel(Duration::ZERO);
+        let cancel = CancellationToken::new();
+        let replay = ReplayHandle {
+            tx,
+            position,
+            duration: self.duration(),
+            cancel: cancel.clone(),
+        };
+        info!(duration = ?replay.duration, looping = self.config.looping, "starting file replay");
+        tokio::spawn(self.run(handle, rx, position_tx, cancel));
+        Ok(replay)
+    }
+
+    /// Index of the timeline entry to resume from for a seek to `position`
+    fn seek_index(&self, position: Duration) -> usize {
+        let target_us = position.as_micros() as i64;
+        let start = match self.video {
+            // Back up to the keyframe at or before the target
+            Some((video, _)) => {
+                let track = &self.reader.tracks()[video];
+                let keyframe = track
+                    .samples
+                    .iter()
+                    .enumerate()
+                    .rev()
+                    .find(|(_, info)| info.keyframe && track.to_us(info.dts as i64) <= target_us)
+                    .map_or(0, |(index, _)| index);
+                self.timeline
+                    .iter()
+                    .position(|&(_, t, s)| t == video && s == keyframe)
+            }
+            None => self.timeline.iter().position(|&(dts, ..)| dts >= target_us),
+        };
+        start.unwrap_or(self.timeline.len())
+    }
+
+    #[instrument(skip_all, name = "replay")]
+    async fn run(
+        mut self,
+        handle: PublishHandle,
+        mut commands: mpsc::UnboundedReceiver<ReplayCommand>,
+        position: watch::Sender<Duration>,
+        cancel: CancellationToken,
+    ) {
+        let mut index = 0;
+        // Wall clock time at which the sample at `origin_us` is due
+        let mut clock = Instant::now();
+        let mut origin_us = self.timeline[0].0;
+        // Output timestamp of the sample at `origin_us`
+        let mut epoch_us = 0i64;
+        // Output timestamp just past the last sample sent
+        let mut end_us = 0i64;
+
+        loop {
+            if index >= self.timeline.len() {
+                if !self.config.looping {
+                    info!("replay finished");
+                    break;
+                }
+                debug!("looping replay");
+                index = 0;
+                origin_us = self.timeline[0].0;
+                clock = Instant::now();
+                epoch_us = end_us;
+            }
+
+            let (dts_us, track, sample) = self.timeline[index];
+            let due = clock + Duration::from_micros((dts_us - origin_us).max(0) as u64);
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                Some(command) = commands.recv() => {
+                    match command {
+                        ReplayCommand::Seek(target) => {
+                            index = self.seek_index(target);
+                            if let Some(&(dts_us, ..)) = self.timeline.get(index) {
+                                info!(?target, "seeking");
+                                origin_us = dts_us;
+                                clock = Instant::now();
+                                epoch_us = end_us;
+                            }
+                        }
+                        ReplayCommand::SetLooping(looping) => self.config.looping = looping,
+                    }
+                    continue;
+                }
+                _ = n0_future::time::sleep(due.saturating_duration_since(Instant::now())) => {}
+            }
+
+            let info = self.reader.tracks()[track].samples[sample];
+            let data = match self.reader.read_sample(&info) {
+                Ok(data) => data,
+                Err(e) => {
+                    warn!("failed to read sample: {e}");
+                    break;
+                }
+            };
+            let track_info = &self.reader.tracks()[track];
+            let offset_us = epoch_us + dts_us - origin_us;
+            let pts_us = offset_us + track_info.to_us(info.cts_offset as i64);
+            end_us = end_us.max(offset_us + track_info.to_us(info.duration as i64));
+
+            let result = match self.video {
+                Some((video, quality)) if video == track => {
+                    handle
+                        .push_video(EncodedVideoFrame {
+                            data,
+                            pts_us,
+                            is_keyframe: info.keyframe,
+                            quality,
+                        })
+                        .await
+                }
+                _ => {
+                    handle
+                        .push_audio(EncodedAudioFrame {
+                            data,
+                            pts_us,
+                            quality: AUDIO_QUALITY,
+                        })
+                        .await
+                }
+            };
+            if result.is_err() {
+                debug!("broadcast closed");
+                break;
+            }
+
+            position.send_replace(Duration::from_micros(dts_us.max(0) as u64));
+            index += 1;
+        }
+    }
+}
+
+/// Rendition name for a video height
+fn quality_for_height(height: u32) -> VideoQuality {
+    match height {
+        0..=480 => VideoQuality::Low,
+        481..=720 => VideoQuality::Medium,
+        _ => VideoQuality::High,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::mp4::{Mp4Sample, Mp4Track, avcc_from_annexb, init_segment, media_segment};
+    use bytes::Bytes;
+
+    /// A 720p file with keyframes every 3 frames and one AAC frame per video frame
+    fn write_file(path: &Path) {
+        let avcc = avcc_from_annexb(&[0, 0, 1, 0x67, 0x64, 0x00, 0x1f, 0, 0, 1, 0x68, 0xce]).unwrap();
+        let tracks = [
+            Mp4Track {
+                id: 1,
+                timescale: 90_000,
+                codec: Mp4Codec::Avc { width: 1280, height: 720, avcc: Bytes::from(avcc) },
+            },
+            Mp4Track {
+                id: 2,
+                timescale: 48_000,
+                codec: Mp4Codec::Aac { sample_rate: 48_000, channel_count: 2, config: Bytes::from_static(&[0x11, 0x90]) },
+            },
+        ];
+        let mut file = init_segment(&tracks);
+        for group in 0..3u64 {
+            let video: Vec<_> = (0..3)
+                .map(|i| Mp4Sample { data: Bytes::from_static(b"frame"), duration: 3000, keyframe: i == 0 })
+                .collect();
+            let audio: Vec<_> = (0..3)
+                .map(|_| Mp4Sample { data: Bytes::from_static(b"aac"), duration: 1600, keyframe: true })
+                .collect();
+            file.extend(media_segment(group as u32 * 2 + 1, 1, group * 9000, &video));
+            file.extend(media_segment(group as u32 * 2 + 2, 2, group * 4800, &audio));
+        }
+        std::fs::write(path, file).unwrap();
+    }
+
+    #[test]
+    fn test_replay_config_and_seek() {
+        let path = std::env::temp_dir().join(format!("replay-test-{}.mp4", std::process::id()));
+        write_file(&path);
+        let replay = FileReplay::open(&path, ReplayConfig::default()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(replay.duration(), Duration::from_millis(300));
+        assert_eq!(replay.video_quality(), Some(VideoQuality::Medium));
+        let video = replay.video_config().unwrap();
+        assert_eq!(video.codec, VideoCodec::H264(H264 { profile: 0x64, constraints: 0, level: 0x1f }));
+        assert_eq!(video.framerate.map(f64::round), Some(30.0));
+        let audio = replay.audio_config().unwrap();
+        assert_eq!(audio.codec, AudioCodec::AAC(AAC { profile: 2 }));
+
+        // Seeking into the second group resumes at its keyframe (100ms)
+        let index = replay.seek_index(Duration::from_millis(150));
+        let (dts, track, sample) = replay.timeline[index];
+        assert_eq!((dts, track, sample), (100_000, 0, 3));
+        // Past the end: the last keyframe
+        let index = replay.seek_index(Duration::from_secs(10));
+        assert_eq!(replay.timeline[index], (200_000, 0, 6));
+    }
+}
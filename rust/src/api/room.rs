@@ -0,0 +1,349 @@
+//! Rooms: calls between a few peers over one session each
+//!
+//! A room has a host and members. Members connect to the host only, over a
+//! single [`LiveSession`], and announce their own broadcast on it; the host
+//! publishes every member's broadcast on to everyone else, so each member
+//! receives all the others over the same connection it sends on. With one
+//! member this is a 1:1 call.
+//!
+//! Every participant's broadcast is named [`member_path`]: the room name and
+//! its endpoint ID. The host only takes a member's broadcast at that
+//! member's own path, so members can't speak for each other. A [`Room`]
+//! reports every other participant's broadcast as it comes and goes; it
+//! never reports the participant's own.
+//!
+//! Who may join follows the host's [`super::access`] policy for the room
+//! name, as if the room were a broadcast: restrict it to require a token
+//! issued for the room, or to allow-listed members. The host ignores
+//! broadcasts from peers the policy refuses. Participants' broadcasts are
+//! allow-listed to the current members, so other peers connected to the
+//! host, such as viewers or discovery browsers, are never offered them.
+
+use std::collections::HashSet;
+use std::future::Future;
+
+use anyhow::Result;
+use iroh::{EndpointAddr, EndpointId};
+use moq_lite::BroadcastConsumer;
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+use tokio_util::sync::{CancellationToken, DropGuard};
+use tracing::{debug, info, warn};
+
+use super::access::{BroadcastPolicy, SignedToken};
+use super::live_protocol::{Live, LiveSession, PeerEvent};
+use super::roster::RosterEvent;
+
+/// Room events buffered for a slow reader
+pub const EVENT_CAPACITY: usize = 64;
+
+/// Name of `member`'s broadcast in `room`
+pub fn member_path(room: &str, member: EndpointId) -> String {
+    format!("{room}/{member}")
+}
+
+/// Member a broadcast in `room` belongs to, if it is a member's broadcast
+fn parse_member(room: &str, path: &str) -> Option<EndpointId> {
+    path.strip_prefix(room)?.strip_prefix('/')?.parse().ok()
+}
+
+/// Another participant's broadcast coming or going
+#[derive(Clone)]
+pub enum RoomEvent {
+    Joined {
+        member: EndpointId,
+        broadcast: BroadcastConsumer,
+    },
+    Left {
+        member: EndpointId,
+    },
+}
+
+impl std::fmt::Debug for RoomEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Joined { member, .. } => f
+                .debug_struct("Joined")
+                .field("member", &member.fmt_short().to_string())
+                .finish_non_exhaustive(),
+            Self::Left { member } => f
+                .debug_struct("Left")
+                .field("member", &member.fmt_short().to_string())
+                .finish(),
+        }
+    }
+}
+
+enum Command {
+    Publish(BroadcastConsumer),
+}
+
+/// This node's place in a room
+pub struct Room {
+    name: String,
+    host: EndpointId,
+    commands: mpsc::Sender<Command>,
+    events: mpsc::Receiver<RoomEvent>,
+    task: JoinHandle<()>,
+    _cancel: DropGuard,
+}
+
+impl Room {
+    /// Host a room on this node
+    ///
+    /// Members join with [`Room::join`] and this node's address. Set an
+    /// access policy for `name` to control who may.
+    pub fn host(live: &Live, name: impl ToString) -> Self {
+        let name = name.to_string();
+        let host = live.endpoint().id();
+        info!("hosting room {name}");
+        Self::spawn(name.clone(), host, |commands, events, cancel| {
+            run_host(live.clone(), name, commands, events, cancel)
+        })
+    }
+
+    /// Join the room `name` hosted by `host`
+    ///
+    /// Opens the session to the host and keeps it for as long as the room
+    /// lives; the room ends when the host goes away.
+    pub async fn join(
+        live: &Live,
+        host: impl Into<EndpointAddr>,
+        name: impl ToString,
+        token: Option<&SignedToken>,
+    ) -> Result<Self> {
+        let name = name.to_string();
+        let session = live.connect_with_token(host, token).await?;
+        let own = live.endpoint().id();
+        info!("joined room {name} hosted by {}", session.remote.fmt_short());
+        Ok(Self::spawn(name.clone(), session.remote, |commands, events, cancel| {
+            run_member(session, name, own, commands, events, cancel)
+        }))
+    }
+
+    fn spawn<F, Fut>(name: String, host: EndpointId, run: F) -> Self
+    where
+        F: FnOnce(mpsc::Receiver<Command>, mpsc::Sender<RoomEvent>, CancellationToken) -> Fut,
+        Fut: Future<Output = ()> + Send + 'static,
+    {
+        let (commands_tx, commands) = mpsc::channel(4);
+        let (events_tx, events) = mpsc::channel(EVENT_CAPACITY);
+        let cancel = CancellationToken::new();
+        let task = tokio::spawn(run(commands, events_tx, cancel.clone()));
+        Self {
+            name,
+            host,
+            commands: commands_tx,
+            events,
+            task,
+            _cancel: cancel.drop_guard(),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Endpoint ID of the room's host
+    pub fn host_id(&self) -> EndpointId {
+        self.host
+    }
+
+    /// Send this node's broadcast to the room
+    ///
+    /// Replaces a broadcast sent earlier.
+    pub async fn publish(&self, broadcast: BroadcastConsumer) -> Result<()> {
+        self.commands
+            .send(Command::Publish(broadcast))
+            .await
+            .map_err(|_| anyhow::anyhow!("room closed"))
+    }
+
+    /// Next participant joining or leaving, or None once the room ends
+    ///
+    /// Events are dropped rather than held for a reader more than
+    /// [`EVENT_CAPACITY`] behind.
+    pub async fn recv(&mut self) -> Option<RoomEvent> {
+        self.events.recv().await
+    }
+
+    /// Next participant joining or leaving, if one is waiting
+    pub fn try_recv(&mut self) -> Option<RoomEvent> {
+        self.events.try_recv().ok()
+    }
+
+    /// Leave the room, withdrawing this node's broadcast
+    ///
+    /// A host leaving ends the room for everyone. Dropping the room also
+    /// leaves it, without waiting.
+    pub async fn leave(self) {
+        let Self { _cancel: cancel, task, .. } = self;
+        drop(cancel);
+        if let Err(e) = task.await {
+            warn!("room task failed: {e}");
+        }
+    }
+}
+
+/// Host side: republish members' broadcasts to everyone
+async fn run_host(
+    live: Live,
+    name: String,
+    mut commands: mpsc::Receiver<Command>,
+    events: mpsc::Sender<RoomEvent>,
+    cancel: CancellationToken,
+) {
+    let host = live.endpoint().id();
+    let own = member_path(&name, host);
+    let mut peers = live.peer_events();
+    let mut roster = live.roster_events();
+    let mut members = HashSet::new();
+    let mut published = false;
+    if restrict_to_members(&live, &name, host, &members).await.is_err() {
+        return;
+    }
+
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            Some(command) = commands.recv() => match command {
+                Command::Publish(broadcast) => {
+                    published = live.publish_consumer(&own, broadcast).await.is_ok();
+                }
+            },
+            event = peers.recv() => match event {
+                Ok(PeerEvent::Announced { remote, name: path, broadcast }) => {
+                    if parse_member(&name, &path) != Some(remote) {
+                        continue;
+                    }
+                    match live.admits(remote, &name).await {
+                        Ok(true) => {}
+                        Ok(false) => {
+                            debug!(room = %name, "refusing {}, not admitted to the room", remote.fmt_short());
+                            continue;
+                        }
+                        Err(_) => break,
+                    }
+                    debug!(room = %name, "member {} joined", remote.fmt_short());
+                    members.insert(remote);
+                    // Restrict the broadcast before it is offered to anyone
+                    if restrict_to_members(&live, &name, host, &members).await.is_err()
+                        || live.publish_relayed(&path, broadcast.clone(), vec![remote]).await.is_err()
+                    {
+                        break;
+                    }
+                    report(&events, RoomEvent::Joined { member: remote, broadcast });
+                }
+                Ok(PeerEvent::Unannounced { remote, name: path }) => {
+                    if parse_member(&name, &path) == Some(remote) {
+                        leave_member(&live, &name, remote, &mut members, &events).await;
+                    }
+                }
+                Err(broadcast::error::RecvError::Lagged(n)) => warn!(room = %name, "missed {n} peer events"),
+                Err(broadcast::error::RecvError::Closed) => break,
+            },
+            // A member that drops off may not withdraw its broadcast first
+            Ok(RosterEvent::Left { endpoint_id, .. }) = roster.recv() => {
+                leave_member(&live, &name, endpoint_id, &mut members, &events).await;
+            }
+        }
+    }
+
+    for member in members {
+        let _ = live.unpublish_relayed(member_path(&name, member), member).await;
+        let _ = live.clear_access_policy(&member_path(&name, member)).await;
+    }
+    if published {
+        let _ = live.unpublish(&own).await;
+    }
+    let _ = live.clear_access_policy(&own).await;
+    info!("closed room {name}");
+}
+
+/// Allow-list the current members on every participant's broadcast
+async fn restrict_to_members(live: &Live, room: &str, host: EndpointId, members: &HashSet<EndpointId>) -> Result<()> {
+    let policy = BroadcastPolicy {
+        require_token: true,
+        allow: members.clone(),
+        ..Default::default()
+    };
+    for participant in members.iter().copied().chain([host]) {
+        live.set_access_policy(member_path(room, participant), policy.clone()).await?;
+    }
+    Ok(())
+}
+
+/// Pass an event on without holding up the room for a reader that lags
+fn report(events: &mpsc::Sender<RoomEvent>, event: RoomEvent) {
+    if events.try_send(event).is_err() {
+        debug!("room event dropped, reader lagging or gone");
+    }
+}
+
+async fn leave_member(
+    live: &Live,
+    room: &str,
+    member: EndpointId,
+    members: &mut HashSet<EndpointId>,
+    events: &mpsc::Sender<RoomEvent>,
+) {
+    if !members.remove(&member) {
+        return;
+    }
+    debug!(%room, "member {} left", member.fmt_short());
+    let _ = live.unpublish_relayed(member_path(room, member), member).await;
+    let _ = live.clear_access_policy(&member_path(room, member)).await;
+    let _ = restrict_to_members(live, room, live.endpoint().id(), members).await;
+    report(events, RoomEvent::Left { member });
+}
+
+/// Member side: send our broadcast to the host and follow everyone else's
+async fn run_member(
+    mut session: LiveSession,
+    name: String,
+    own: EndpointId,
+    mut commands: mpsc::Receiver<Command>,
+    events: mpsc::Sender<RoomEvent>,
+    cancel: CancellationToken,
+) {
+    loop {
+        tokio::select! {
+            _ = cancel.cancelled() => break,
+            Some(command) = commands.recv() => match command {
+                Command::Publish(broadcast) => session.publish(member_path(&name, own), broadcast),
+            },
+            announced = session.announced() => {
+                let Some((path, broadcast)) = announced else {
+                    info!(room = %name, "host left");
+                    break;
+                };
+                let Some(member) = parse_member(&name, &path).filter(|member| *member != own) else {
+                    continue;
+                };
+                let event = match broadcast {
+                    Some(broadcast) => RoomEvent::Joined { member, broadcast },
+                    None => RoomEvent::Left { member },
+                };
+                report(&events, event);
+            }
+        }
+    }
+    // Dropping the session closes it, withdrawing our broadcast
+    drop(session);
+    info!("left room {name}");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_member() {
+        let member = iroh::SecretKey::generate(&mut rand::rng()).public();
+        assert_eq!(parse_member("call", &member_path("call", member)), Some(member));
+        assert_eq!(parse_member("call", &member_path("other", member)), None);
+        assert_eq!(parse_member("call", &format!("call/{member}/chat")), None);
+        assert_eq!(parse_member("call", "call/not-an-id"), None);
+        assert_eq!(parse_member("call", "callx"), None);
+    }
+}
@@ -0,0 +1,290 @@
+//! Viewer presence for publishers
+//!
+//! Every session accepted by the [`super::live_protocol::Live`] actor is a
+//! viewer. The roster lists them with the display name they sent in the
+//! session handshake, when they joined, which tracks they are receiving and
+//! what their connection looks like. Joins and leaves are also streamed as
+//! [`RosterEvent`]s.
+//!
+//! moq-lite serves track subscriptions without telling the publisher, so
+//! each viewer is offered its own copy of every broadcast: a thin proxy
+//! that forwards the tracks the viewer asks for and records their names.
+//! Groups and frames are passed through unchanged; encrypted payloads stay
+//! sealed. The proxy also sees when a viewer's copy of a group is done
+//! sending, which it reports to the publisher as a [`SendBacklog`].
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::sync::{Arc, Mutex};
+
+use iroh::EndpointId;
+use iroh::endpoint::Connection;
+use moq_lite::{Broadcast, BroadcastConsumer, GroupConsumer, GroupProducer, TrackConsumer, TrackProducer};
+use n0_future::time::{Duration, Instant, SystemTime};
+use tokio_util::sync::CancellationToken;
+use tracing::debug;
+
+/// Longest display name kept from a handshake, in characters
+pub const MAX_DISPLAY_NAME: usize = 64;
+
+/// A connected viewer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Viewer {
+    pub endpoint_id: EndpointId,
+    /// Name the viewer chose, if any; not verified
+    pub display_name: Option<String>,
+    pub connected_at: SystemTime,
+    /// Tracks being received, as `broadcast/track`
+    pub tracks: Vec<String>,
+    /// Bytes sent to the viewer, including QUIC overhead
+    pub bytes_sent: u64,
+    /// Bytes received from the viewer, including QUIC overhead
+    pub bytes_received: u64,
+    /// Current round trip time estimate
+    pub rtt: Duration,
+}
+
+/// A change to the roster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterEvent {
+    Joined(Viewer),
+    Left {
+        endpoint_id: EndpointId,
+        /// Whether the publisher kicked the viewer
+        kicked: bool,
+    },
+}
+
+/// Tracks a viewer is receiving, shared with its proxies
+#[derive(Debug, Clone, Default)]
+pub(crate) struct SubscribedTracks(Arc<Mutex<BTreeSet<String>>>);
+
+impl SubscribedTracks {
+    fn insert(&self, track: String) {
+        self.0.lock().unwrap().insert(track);
+    }
+
+    fn remove(&self, track: &str) {
+        self.0.lock().unwrap().remove(track);
+    }
+
+    pub(crate) fn list(&self) -> Vec<String> {
+        self.0.lock().unwrap().iter().cloned().collect()
+    }
+}
+
+/// Presence information the actor keeps per session
+#[derive(Debug)]
+pub(crate) struct Presence {
+    pub conn: Connection,
+    pub display_name: Option<String>,
+    pub connected_at: SystemTime,
+    pub tracks: SubscribedTracks,
+}
+
+impl Presence {
+    pub(crate) fn new(conn: Connection, display_name: Option<String>) -> Self {
+        Self {
+            conn,
+            display_name: display_name.map(|name| name.chars().take(MAX_DISPLAY_NAME).collect()),
+            connected_at: SystemTime::now(),
+            tracks: SubscribedTracks::default(),
+        }
+    }
+
+    /// Snapshot for the roster
+    pub(crate) fn viewer(&self, endpoint_id: EndpointId) -> Viewer {
+        let stats = self.conn.stats();
+        Viewer {
+            endpoint_id,
+            display_name: self.display_name.clone(),
+            connected_at: self.connected_at,
+            tracks: self.tracks.list(),
+            bytes_sent: stats.udp_tx.bytes,
+            bytes_received: stats.udp_rx.bytes,
+            rtt: self.conn.rtt(),
+        }
+    }
+}
+
+/// Groups of a broadcast that some viewer is still being sent
+///
+/// A viewer's copy of a group is pending from when the publisher finishes
+/// the group until moq-lite lets go of it: its stream was acknowledged, or
+/// abandoned for a newer group. The publisher reads [`SendBacklog::lag`]
+/// to decide when to shed load.
+#[derive(Debug, Clone, Default)]
+pub struct SendBacklog(Arc<Mutex<PendingGroups>>);
+
+#[derive(Debug, Default)]
+struct PendingGroups {
+    next: u64,
+    /// When each pending group was finished, in order
+    since: BTreeMap<u64, Instant>,
+}
+
+impl SendBacklog {
+    /// How long the oldest pending group has been waiting
+    pub fn lag(&self) -> Duration {
+        let pending = self.0.lock().unwrap();
+        pending.since.first_key_value().map(|(_, since)| since.elapsed()).unwrap_or_default()
+    }
+
+    /// Mark a group pending until the guard is dropped
+    fn pending(&self) -> PendingGroup {
+        let mut pending = self.0.lock().unwrap();
+        let id = pending.next;
+        pending.next += 1;
+        pending.since.insert(id, Instant::now());
+        PendingGroup { backlog: self.clone(), id }
+    }
+}
+
+struct PendingGroup {
+    backlog: SendBacklog,
+    id: u64,
+}
+
+impl Drop for PendingGroup {
+    fn drop(&mut self) {
+        self.backlog.0.lock().unwrap().since.remove(&self.id);
+    }
+}
+
+/// Offer `broadcast` to one viewer through a proxy that records its tracks
+///
+/// The proxy closes when the source broadcast does, the viewer's session
+/// lets go of it, or `cancel` fires, which withdraws the announcement from
+/// the viewer.
+pub(crate) fn tap_broadcast(
+    name: &str,
+    broadcast: BroadcastConsumer,
+    tracks: SubscribedTracks,
+    backlog: SendBacklog,
+    cancel: CancellationToken,
+) -> BroadcastConsumer {
+    let proxy = Broadcast::produce();
+    let mut producer = proxy.producer;
+    let name = name.to_string();
+
+    tokio::spawn(async move {
+        let unused = producer.unused();
+        tokio::pin!(unused);
+        loop {
+            tokio::select! {
+                _ = cancel.cancelled() => break,
+                _ = &mut unused => break,
+                _ = broadcast.closed() => break,
+                requested = producer.requested_track() => {
+                    let Some(track) = requested else { break };
+                    let upstream = broadcast.subscribe_track(&track.info);
+                    let label = format!("{name}/{}", track.info.name);
+                    tokio::spawn(forward_track(
+                        upstream,
+                        track,
+                        label,
+                        tracks.clone(),
+                        backlog.clone(),
+                        cancel.child_token(),
+                    ));
+                }
+            }
+        }
+        debug!("stopped offering {name}");
+    });
+
+    proxy.consumer
+}
+
+/// Forward a track's groups until the source ends or nobody watches
+///
+/// The proxy keeps its own handle on every track it was asked for, so
+/// dropping `downstream` would leave the viewer waiting; the source's end
+/// is passed on explicitly instead. A withdrawal finishes the track.
+async fn forward_track(
+    mut upstream: TrackConsumer,
+    mut downstream: TrackProducer,
+    label: String,
+    tracks: SubscribedTracks,
+    backlog: SendBacklog,
+    cancel: CancellationToken,
+) {
+    tracks.insert(label.clone());
+    let end = loop {
+        tokio::select! {
+            // A publisher ends its tracks before withdrawing the broadcast;
+            // pass that end on before the withdrawal is seen
+            biased;
+            next = upstream.next_group() => match next {
+                Ok(Some(group)) => {
+                    let out = downstream.append_group();
+                    tokio::spawn(forward_group(group, out, backlog.clone()));
+                }
+                Ok(None) => break Some(Ok(())),
+                Err(e) => break Some(Err(e)),
+            },
+            _ = cancel.cancelled() => break Some(Ok(())),
+            _ = downstream.unused() => break None,
+        }
+    };
+    tracks.remove(&label);
+    match end {
+        Some(Ok(())) => downstream.close(),
+        Some(Err(e)) => {
+            debug!("track {label} failed: {e}");
+            downstream.abort(e);
+        }
+        None => {}
+    }
+}
+
+async fn forward_group(mut group: GroupConsumer, mut out: GroupProducer, backlog: SendBacklog) {
+    while let Ok(Some(data)) = group.read_frame().await {
+        let mut frame = out.create_frame(data.len().into());
+        frame.write_chunk(data);
+        frame.close();
+    }
+    let _pending = backlog.pending();
+    let sent = out.unused();
+    out.close();
+    sent.await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use moq_lite::Track;
+
+    #[tokio::test]
+    async fn test_backlog_until_viewer_is_sent_group() {
+        let source = Broadcast::produce();
+        let mut producer = source.producer;
+        let info = Track { name: "video".to_string(), priority: 0 };
+        let mut track = producer.create_track(info.clone());
+        let backlog = SendBacklog::default();
+        let proxy = tap_broadcast(
+            "live",
+            source.consumer,
+            SubscribedTracks::default(),
+            backlog.clone(),
+            CancellationToken::new(),
+        );
+        let mut viewer = proxy.subscribe_track(&info);
+
+        let mut first = track.append_group();
+        first.write_frame(&b"key"[..]);
+        // The viewer's session is still sending the first group...
+        let sending = viewer.next_group().await.unwrap().unwrap();
+        first.close();
+        let mut second = track.append_group();
+        second.write_frame(&b"key"[..]);
+        viewer.next_group().await.unwrap().unwrap();
+
+        n0_future::time::sleep(Duration::from_millis(20)).await;
+        assert!(backlog.lag() >= Duration::from_millis(20));
+
+        // ...until it lets go of it
+        drop(sending);
+        n0_future::time::sleep(Duration::from_millis(5)).await;
+        assert_eq!(backlog.lag(), Duration::ZERO);
+    }
+}
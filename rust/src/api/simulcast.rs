@@ -0,0 +1,423 @@
+//! Simulcast encoding from a single capture source
+//!
+//! [`SimulcastEncoder`] converts each raw frame from an [`av::VideoSource`]
+//! once, downscales it with one [`Rescaler`] per rendition and encodes every
+//! rendition with its own [`H264Encoder`]. Renditions keep the source's
+//! aspect ratio (see [`VideoQuality::dimensions_for`]). Keyframes are forced on all
+//! encoders at the same source frame, so groups of every rendition start at
+//! the same timestamps and subscribers can switch at any group boundary.
+//!
+//! [`SimulcastPipeline`] drives an encoder from a source on its own thread
+//! and writes into a [`PublishHandle`]. Renditions are added and removed
+//! through [`SimulcastHandle`] while the broadcast keeps running.
+//!
+//! [`av::VideoSource`]: super::av::VideoSource
+//!
+//! Enable with: cargo build --features ffmpeg
+
+#![cfg(feature = "ffmpeg")]
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use anyhow::{Result, bail};
+use ffmpeg_next::{format::Pixel, frame::Video as FfmpegFrame};
+use hang::catalog::VideoConfig;
+use tokio::sync::mpsc::{self, error::TryRecvError};
+use tracing::{debug, info, warn};
+
+use super::av::{VideoFrame, VideoSource};
+use super::ffmpeg_encoder::{H264Encoder, Rescaler, pixel, plane_layout};
+use super::publish_broadcast::{EncodedVideoFrame, PublishHandle, VideoQuality};
+
+/// Simulcast encoder settings
+#[derive(Debug, Clone)]
+pub struct SimulcastConfig {
+    /// Source frame rate, shared by every rendition
+    pub framerate: u32,
+    /// Source frames between aligned keyframes
+    pub keyframe_interval: u32,
+}
+
+impl Default for SimulcastConfig {
+    fn default() -> Self {
+        Self {
+            framerate: 30,
+            // Matches the encoder's own GOP length
+            keyframe_interval: 30,
+        }
+    }
+}
+
+/// Scaler and encoder for one rendition
+struct Rendition {
+    quality: VideoQuality,
+    rescaler: Rescaler,
+    encoder: H264Encoder,
+    /// PTS of frames sent to the encoder but not yet received
+    pending_pts: VecDeque<i64>,
+}
+
+impl Rendition {
+    fn encode(
+        &mut self,
+        source: &FfmpegFrame,
+        pts_us: i64,
+        keyframe: bool,
+        out: &mut Vec<EncodedVideoFrame>,
+    ) -> Result<()> {
+        let scaled = self.rescaler.process(source)?;
+        if keyframe {
+            self.encoder.request_keyframe();
+        }
+        self.encoder.encode_frame(scaled)?;
+        self.pending_pts.push_back(pts_us);
+
+        // No B-frames, so packets come out in input order
+        while let Some(packet) = self.encoder.pop_packet()? {
+            out.push(EncodedVideoFrame {
+                data: packet.payload.into(),
+                pts_us: self.pending_pts.pop_front().unwrap_or(pts_us),
+                is_keyframe: packet.keyframe,
+                quality: self.quality,
+            });
+        }
+        Ok(())
+    }
+
+    /// Drain the frames the encoder still holds
+    fn flush(&mut self, out: &mut Vec<EncodedVideoFrame>) -> Result<()> {
+        for packet in self.encoder.flush()? {
+            let Some(pts_us) = self.pending_pts.pop_front() else {
+                break;
+            };
+            out.push(EncodedVideoFrame {
+                data: packet.payload.into(),
+                pts_us,
+                is_keyframe: packet.keyframe,
+                quality: self.quality,
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Encodes one source into several renditions with aligned keyframes
+pub struct SimulcastEncoder {
+    config: SimulcastConfig,
+    /// Source frame size, which sets the renditions' aspect ratio
+    source_size: (u32, u32),
+    renditions: Vec<Rendition>,
+    /// Source frames encoded so far
+    frame_index: u64,
+    /// Force a keyframe on every rendition at the next frame
+    force_keyframe: bool,
+}
+
+impl SimulcastEncoder {
+    /// Create an encoder with no renditions for a `width` x `height` source
+    pub fn new(config: SimulcastConfig, width: u32, height: u32) -> Self {
+        Self {
+            config,
+            source_size: (width, height),
+            renditions: Vec::new(),
+            frame_index: 0,
+            force_keyframe: false,
+        }
+    }
+
+    /// Open an encoder for a rendition and return its catalog entry
+    ///
+    /// Every rendition restarts its group at the next frame so the new one
+    /// is aligned with the rest.
+    pub fn add(&mut self, quality: VideoQuality) -> Result<VideoConfig> {
+        if self.renditions.iter().any(|r| r.quality == quality) {
+            bail!("rendition already encoded: {}", quality.track_name());
+        }
+
+        let (width, height) = quality.dimensions_for(self.source_size.0, self.source_size.1);
+        let encoder = H264Encoder::new(width, height, self.config.framerate)?;
+        let config = encoder.video_config()?;
+        self.renditions.push(Rendition {
+            quality,
+            rescaler: Rescaler::new(Pixel::YUV420P, Some((width, height)))?,
+            encoder,
+            pending_pts: VecDeque::new(),
+        });
+        self.force_keyframe = true;
+
+        info!("simulcast: added {} ({width}x{height})", quality.track_name());
+        Ok(config)
+    }
+
+    /// Drop a rendition's encoder; false if there is no such rendition
+    pub fn remove(&mut self, quality: VideoQuality) -> bool {
+        let Some(index) = self.renditions.iter().position(|r| r.quality == quality) else {
+            return false;
+        };
+        self.renditions.remove(index);
+
+        info!("simulcast: removed {}", quality.track_name());
+        true
+    }
+
+    /// Qualities currently encoded
+    pub fn qualities(&self) -> Vec<VideoQuality> {
+        self.renditions.iter().map(|r| r.quality).collect()
+    }
+
+    /// Make the next frame a keyframe on every rendition
+    pub fn request_keyframe(&mut self) {
+        self.force_keyframe = true;
+    }
+
+    /// Encode a source frame into every rendition
+    ///
+    /// A rendition that fails to encode is skipped for this frame.
+    pub fn encode(&mut self, frame: &VideoFrame) -> Result<Vec<EncodedVideoFrame>> {
+        if self.renditions.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let source = to_ffmpeg(frame)?;
+        let interval = self.config.keyframe_interval.max(1) as u64;
+        let keyframe = std::mem::take(&mut self.force_keyframe) || self.frame_index.is_multiple_of(interval);
+        self.frame_index += 1;
+
+        let mut encoded = Vec::with_capacity(self.renditions.len());
+        for rendition in &mut self.renditions {
+            if let Err(e) = rendition.encode(&source, frame.pts_us, keyframe, &mut encoded) {
+                warn!(quality = ?rendition.quality, "simulcast encode failed: {e:#}");
+            }
+        }
+        Ok(encoded)
+    }
+
+    /// Flush every rendition's encoder and close them all
+    ///
+    /// Returns the frames the encoders were still holding, which belong at
+    /// the end of the broadcast.
+    pub fn finish(&mut self) -> Vec<EncodedVideoFrame> {
+        let mut encoded = Vec::new();
+        for mut rendition in self.renditions.drain(..) {
+            if let Err(e) = rendition.flush(&mut encoded) {
+                warn!(quality = ?rendition.quality, "simulcast flush failed: {e:#}");
+            }
+        }
+        encoded
+    }
+}
+
+/// Copy a tightly packed `av` frame into an FFmpeg picture
+fn to_ffmpeg(frame: &VideoFrame) -> Result<FfmpegFrame> {
+    let format = frame.format.pixel_format;
+    let planes = plane_layout(format, frame.width(), frame.height());
+    let needed: usize = planes.iter().map(|(row, rows)| row * rows).sum();
+    if frame.data.len() < needed {
+        bail!("short {} frame: {} of {needed} bytes", format.name(), frame.data.len());
+    }
+
+    let mut picture = FfmpegFrame::new(pixel(format), frame.width(), frame.height());
+    let mut offset = 0;
+    for (index, (row, rows)) in planes.into_iter().enumerate() {
+        let stride = picture.stride(index);
+        let data = picture.data_mut(index);
+        for y in 0..rows {
+            data[y * stride..y * stride + row].copy_from_slice(&frame.data[offset..offset + row]);
+            offset += row;
+        }
+    }
+    picture.set_pts(Some(frame.pts_us));
+    Ok(picture)
+}
+
+/// Rendition changes for a running pipeline
+enum SimulcastCommand {
+    Add(VideoQuality),
+    Remove(VideoQuality),
+    Stop,
+}
+
+/// Handle for changing a running pipeline's renditions
+///
+/// Dropping every handle stops the pipeline, as does the broadcast ending.
+#[derive(Clone)]
+pub struct SimulcastHandle {
+    tx: mpsc::UnboundedSender<SimulcastCommand>,
+}
+
+impl SimulcastHandle {
+    /// Start encoding and publishing another rendition
+    pub fn add_rendition(&self, quality: VideoQuality) -> Result<()> {
+        self.send(SimulcastCommand::Add(quality))
+    }
+
+    /// Stop encoding a rendition and remove it from the broadcast
+    pub fn remove_rendition(&self, quality: VideoQuality) -> Result<()> {
+        self.send(SimulcastCommand::Remove(quality))
+    }
+
+    /// Stop the pipeline; the broadcast itself keeps running
+    ///
+    /// The frames still in the encoders are written before it stops, so
+    /// stop the pipeline before the broadcast.
+    pub fn stop(&self) -> Result<()> {
+        self.send(SimulcastCommand::Stop)
+    }
+
+    fn send(&self, cmd: SimulcastCommand) -> Result<()> {
+        self.tx.send(cmd).map_err(|_| anyhow::anyhow!("simulcast pipeline stopped"))
+    }
+}
+
+/// Pulls frames from a source and publishes every rendition
+pub struct SimulcastPipeline {
+    source: Box<dyn VideoSource>,
+    encoder: SimulcastEncoder,
+    publish: PublishHandle,
+    rx: mpsc::UnboundedReceiver<SimulcastCommand>,
+    /// PTS of the last frame taken from the source
+    last_pts: Option<i64>,
+}
+
+impl SimulcastPipeline {
+    /// Create a pipeline encoding `source` into `qualities`
+    ///
+    /// Encoders are opened when the pipeline starts running; each
+    /// rendition's catalog entry is then replaced with its encoder's config.
+    pub fn new<S: VideoSource>(
+        source: S,
+        publish: PublishHandle,
+        qualities: &[VideoQuality],
+        config: SimulcastConfig,
+    ) -> (Self, SimulcastHandle) {
+        let (tx, rx) = mpsc::unbounded_channel();
+        let handle = SimulcastHandle { tx };
+        for quality in qualities {
+            let _ = handle.add_rendition(*quality);
+        }
+
+        let format = source.format();
+        let pipeline = Self {
+            source: Box::new(source),
+            encoder: SimulcastEncoder::new(config, format.width, format.height),
+            publish,
+            rx,
+            last_pts: None,
+        };
+        (pipeline, handle)
+    }
+
+    /// Run the pipeline on the current thread until stopped
+    ///
+    /// Polls the source every `interval_ms` while it has no new frame. Must
+    /// not be called from inside an async runtime.
+    pub fn run_blocking(mut self, interval_ms: u64) {
+        let interval = Duration::from_millis(interval_ms);
+        info!("simulcast pipeline started");
+
+        while self.apply_commands() {
+            if self.publish.take_keyframe_request() {
+                self.encoder.request_keyframe();
+            }
+
+            // Sources may repeat their last frame; only encode newer ones
+            let frame = match self.source.pop_frame() {
+                Ok(Some(frame)) if self.last_pts < Some(frame.pts_us) => frame,
+                Ok(_) => {
+                    std::thread::sleep(interval);
+                    continue;
+                }
+                Err(e) => {
+                    warn!("Video source error: {}", e);
+                    break;
+                }
+            };
+            self.last_pts = Some(frame.pts_us);
+
+            let encoded = match self.encoder.encode(&frame) {
+                Ok(encoded) => encoded,
+                Err(e) => {
+                    warn!("simulcast: dropping source frame: {e:#}");
+                    continue;
+                }
+            };
+            for frame in encoded {
+                if let Err(e) = self.publish.try_push_video(frame) {
+                    if self.publish.is_closed() {
+                        info!("simulcast: broadcast closed");
+                        return;
+                    }
+                    // The group is broken now; restart it on every rendition
+                    debug!("simulcast: {e}");
+                    self.encoder.request_keyframe();
+                }
+            }
+        }
+
+        // Send the tail the encoders were holding back
+        for frame in self.encoder.finish() {
+            if self.publish.blocking_push_video(frame).is_err() {
+                break;
+            }
+        }
+        info!("simulcast pipeline stopped");
+    }
+
+    /// Apply queued rendition changes; false once the pipeline should stop
+    fn apply_commands(&mut self) -> bool {
+        loop {
+            match self.rx.try_recv() {
+                Ok(SimulcastCommand::Add(quality)) => {
+                    let config = match self.encoder.add(quality) {
+                        Ok(config) => config,
+                        Err(e) => {
+                            warn!(?quality, "simulcast: failed to add rendition: {e:#}");
+                            continue;
+                        }
+                    };
+                    if self.publish.blocking_add_video_rendition(quality, config).is_err() {
+                        return false;
+                    }
+                }
+                Ok(SimulcastCommand::Remove(quality)) => {
+                    if !self.encoder.remove(quality) {
+                        debug!(?quality, "simulcast: no such rendition to remove");
+                        continue;
+                    }
+                    if self.publish.blocking_remove_video_rendition(quality).is_err() {
+                        return false;
+                    }
+                }
+                Ok(SimulcastCommand::Stop) | Err(TryRecvError::Disconnected) => return false,
+                Err(TryRecvError::Empty) => return true,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::av::{PixelFormat, VideoFormat};
+
+    #[test]
+    fn test_to_ffmpeg_copies_planes() {
+        // 4x2 I420: 8 luma bytes, then 2 bytes each of U and V
+        let data: Vec<u8> = (0..12).collect();
+        let format = VideoFormat::new(4, 2, PixelFormat::Yuv420p);
+        let frame = VideoFrame::new(format, data, Duration::from_millis(40));
+
+        let picture = to_ffmpeg(&frame).unwrap();
+        assert_eq!(picture.format(), Pixel::YUV420P);
+        assert_eq!(picture.pts(), Some(40_000));
+        let stride = picture.stride(0);
+        assert_eq!(&picture.data(0)[..4], &[0, 1, 2, 3]);
+        assert_eq!(&picture.data(0)[stride..stride + 4], &[4, 5, 6, 7]);
+        assert_eq!(&picture.data(1)[..2], &[8, 9]);
+        assert_eq!(&picture.data(2)[..2], &[10, 11]);
+
+        let short = VideoFrame::new(VideoFormat::rgba(4, 2), vec![0; 8], Duration::ZERO);
+        assert!(to_ffmpeg(&short).is_err());
+    }
+}
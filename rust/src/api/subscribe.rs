@@ -407,6 +407,8 @@ impl SimpleVideoDecoder {
 // ============================================================================
 
 /// Simple pass-through "decoder" for audio
+///
+/// Unpacks `SimpleAudioEncoder` PCM. Opus packets need `opus::OpusDecoder`.
 pub struct SimpleAudioDecoder {
     config: AudioCatalogConfig,
 }
@@ -4,25 +4,55 @@
 //! - SubscribeBroadcast: Receives and decodes media from a remote broadcast
 //! - WatchTrack: Video track receiver with quality selection
 //! - AudioTrack: Audio track receiver
+//!
+//! Renditions are picked from the broadcast's `catalog.json` track when the
+//! publisher provides one, and the video rendition can be switched while
+//! the subscription is running.
+//!
+//! Frames carry their presentation timestamps; [`SubscribeHandle::next_playout`]
+//! schedules them against an audio-master [`PlayoutClock`].
+//!
+//! A [`VideoDecoder`] can be plugged in to receive raw pixel buffers from
+//! [`SubscribeHandle::recv_decoded`] instead of encoded frames.
+//!
+//! Encrypted broadcasts are opened with the media key from the ticket
+//! (`SubscribeConfig::encryption`); frames that fail to open are dropped.
+//!
+//! When the subscription is over, [`SubscribeHandle::ended`] tells why: the
+//! publisher ended the broadcast, it was stopped here, or a track failed.
 
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Instant;
 
 use anyhow::Result;
 use bytes::Bytes;
+use hang::{Catalog, CatalogConsumer};
 use moq_lite::{BroadcastConsumer, Track, TrackConsumer};
-use tokio::sync::mpsc;
+use n0_future::time::Duration;
+use tokio::sync::{mpsc, watch};
 use tokio_util::sync::CancellationToken;
 use tracing::{debug, info, instrument, warn};
 
+use super::av::DecodedFrame;
+use super::encryption::{FrameDecryptor, MediaKey};
+use super::playout::{PlayoutClock, VideoPlayout};
+use super::publish_broadcast::{AUDIO_GROUP_DURATION, AudioQuality, VideoQuality};
+
 /// Received video frame
 #[derive(Debug, Clone)]
 pub struct ReceivedVideoFrame {
     /// H.264/AVC NAL units
     pub data: Bytes,
+    /// Presentation timestamp in microseconds
+    pub pts_us: i64,
     /// Track name this frame came from
     pub track: String,
     /// Frame sequence number
     pub sequence: u64,
+    /// Whether this frame starts a group (groups begin on a keyframe)
+    pub is_keyframe: bool,
 }
 
 /// Received audio frame
@@ -30,12 +60,160 @@ pub struct ReceivedVideoFrame {
 pub struct ReceivedAudioFrame {
     /// Opus encoded audio
     pub data: Bytes,
+    /// Presentation timestamp in microseconds
+    pub pts_us: i64,
     /// Track name this frame came from
     pub track: String,
     /// Frame sequence number
     pub sequence: u64,
 }
 
+/// Split a hang frame into its timestamp (µs) and payload, opening the
+/// payload if the track is encrypted
+pub(crate) fn read_media_frame(frame: hang::Frame, decryptor: Option<&mut FrameDecryptor>) -> Result<(i64, Bytes)> {
+    let pts_us = frame.timestamp.as_micros() as i64;
+    match decryptor {
+        Some(decryptor) => Ok((pts_us, decryptor.decrypt(pts_us, &frame.payload)?)),
+        None => Ok((pts_us, frame.payload)),
+    }
+}
+
+/// A frame released by [`SubscribeHandle::next_playout`]
+#[derive(Debug, Clone)]
+pub enum Playout {
+    /// Audio to play now
+    Audio(ReceivedAudioFrame),
+    /// Video to render at the given time
+    Video {
+        frame: ReceivedVideoFrame,
+        render_at: n0_future::time::Instant,
+    },
+    /// Video that arrived too late to render
+    ///
+    /// Still handed out so the decoder can keep its reference state.
+    LateVideo(ReceivedVideoFrame),
+}
+
+/// Why a subscription ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EndReason {
+    /// The publisher finished its tracks: the broadcast is over
+    Ended,
+    /// Stopped from this side
+    Stopped,
+    /// A track failed, e.g. because the connection dropped
+    Error(String),
+}
+
+impl EndReason {
+    /// Combine the ends of a subscription's tracks
+    ///
+    /// Only the publisher finishes a track cleanly, so one clean end means
+    /// the broadcast ended even if another track failed on the way out.
+    fn of_tracks(ends: impl IntoIterator<Item = EndReason>) -> EndReason {
+        let mut reason = EndReason::Stopped;
+        for end in ends {
+            match end {
+                EndReason::Ended => return EndReason::Ended,
+                EndReason::Error(_) if reason == EndReason::Stopped => reason = end,
+                _ => {}
+            }
+        }
+        reason
+    }
+}
+
+/// Decoder for received video
+///
+/// Runs on its own thread, so implementations may block.
+pub trait VideoDecoder: Send + 'static {
+    /// (Re)initialize for a track, e.g. after a rendition switch
+    fn configure(&mut self, track: &str, config: &hang::catalog::VideoConfig) -> Result<()>;
+
+    /// Decode one frame; None while the decoder has no picture to show
+    fn decode(&mut self, frame: &ReceivedVideoFrame) -> Result<Option<DecodedFrame>>;
+}
+
+/// Decoded pictures are large, so only a few are buffered
+const DECODED_BUFFER: usize = 4;
+
+/// Request to move a [`WatchTrack`] onto another track
+type SwitchRequest = (String, TrackConsumer);
+
+/// Receive counters for a video track, shared with whoever monitors it
+///
+/// Counters only grow; consumers sample them and work with deltas.
+#[derive(Debug, Default)]
+pub struct TrackStats {
+    bytes: AtomicU64,
+    frames: AtomicU64,
+    late_frames: AtomicU64,
+    skipped_groups: AtomicU64,
+}
+
+impl TrackStats {
+    /// Payload bytes received
+    pub fn bytes(&self) -> u64 {
+        self.bytes.load(Ordering::Relaxed)
+    }
+
+    /// Frames received
+    pub fn frames(&self) -> u64 {
+        self.frames.load(Ordering::Relaxed)
+    }
+
+    /// Frames that arrived well behind the usual frame interval
+    pub fn late_frames(&self) -> u64 {
+        self.late_frames.load(Ordering::Relaxed)
+    }
+
+    /// Groups the track moved past before we could read them
+    pub fn skipped_groups(&self) -> u64 {
+        self.skipped_groups.load(Ordering::Relaxed)
+    }
+}
+
+/// Detects frames arriving late relative to the recent frame interval
+#[derive(Default)]
+struct LatenessTracker {
+    last_arrival: Option<Instant>,
+    /// Smoothed inter-frame interval in seconds
+    interval: f64,
+}
+
+impl LatenessTracker {
+    /// Minimum stall, in seconds, before a frame counts as late
+    const MIN_STALL: f64 = 0.05;
+
+    /// Record a frame arrival, returning whether it was late
+    fn record(&mut self, now: Instant) -> bool {
+        let Some(last) = self.last_arrival.replace(now) else {
+            return false;
+        };
+        let gap = now.duration_since(last).as_secs_f64();
+        if self.interval == 0.0 {
+            self.interval = gap;
+            return false;
+        }
+        let late = gap > Self::MIN_STALL && gap > self.interval * 2.0;
+        // Keep stalls out of the average so one hiccup doesn't mask the next
+        if !late {
+            self.interval = self.interval * 0.9 + gap * 0.1;
+        }
+        late
+    }
+
+    /// Leave out time spent blocked on our own consumer
+    ///
+    /// A full output channel holds up the next read, which would otherwise
+    /// look like the network delivering late.
+    fn discount(&mut self, blocked: Duration) {
+        if let Some(last) = &mut self.last_arrival {
+            *last += blocked;
+        }
+    }
+}
+
 /// Video track receiver
 pub struct WatchTrack {
     /// Track name
@@ -46,6 +224,14 @@ pub struct WatchTrack {
     frame_count: u64,
     /// Output channel
     output_tx: mpsc::Sender<ReceivedVideoFrame>,
+    /// Incoming rendition switch requests
+    switch_rx: Option<mpsc::UnboundedReceiver<SwitchRequest>>,
+    /// Publishes the track name after each cut-over
+    track_tx: Option<watch::Sender<String>>,
+    /// Receive counters
+    stats: Arc<TrackStats>,
+    /// Opens sealed payloads of an encrypted broadcast
+    decryptor: Option<FrameDecryptor>,
 }
 
 impl WatchTrack {
@@ -60,62 +246,158 @@ impl WatchTrack {
             consumer,
             frame_count: 0,
             output_tx,
+            switch_rx: None,
+            track_tx: None,
+            stats: Arc::default(),
+            decryptor: None,
         }
     }
 
+    /// Open frame payloads sealed with `key`
+    pub fn with_decryption(mut self, key: MediaKey) -> Self {
+        self.decryptor = Some(FrameDecryptor::new(key));
+        self
+    }
+
+    /// Receive counters for this track
+    pub fn stats(&self) -> Arc<TrackStats> {
+        self.stats.clone()
+    }
+
+    /// Accept rendition switches, reporting the active track after each one
+    fn with_switching(
+        mut self,
+        switch_rx: mpsc::UnboundedReceiver<SwitchRequest>,
+        track_tx: watch::Sender<String>,
+    ) -> Self {
+        self.switch_rx = Some(switch_rx);
+        self.track_tx = Some(track_tx);
+        self
+    }
+
     /// Run the track receiver
-    pub async fn run(mut self, cancel: CancellationToken) {
+    ///
+    /// On a switch request the current track keeps playing until the new
+    /// track delivers the start of a group. Groups begin on a keyframe, so
+    /// the cut-over never hands the decoder a partial GOP.
+    pub async fn run(mut self, cancel: CancellationToken) -> EndReason {
         info!("watching video track: {}", self.name);
         let start = Instant::now();
 
-        loop {
+        let mut switch_rx = self.switch_rx.take();
+        let mut group: Option<hang::GroupConsumer> = None;
+        let mut pending: Option<SwitchRequest> = None;
+        let mut last_sequence: Option<u64> = None;
+        let mut lateness = LatenessTracker::default();
+
+        let end = loop {
             tokio::select! {
                 _ = cancel.cancelled() => {
                     debug!("video track cancelled: {}", self.name);
-                    break;
+                    break EndReason::Stopped;
+                }
+                Some(request) = async { switch_rx.as_mut()?.recv().await } => {
+                    if request.0 == self.name {
+                        // Switching back to the active track cancels any pending switch
+                        pending = None;
+                    } else {
+                        debug!("switching video track: {} -> {}", self.name, request.0);
+                        pending = Some(request);
+                    }
                 }
-                result = self.consumer.next_group() => {
+                result = async { pending.as_mut().map(|(_, c)| c).unwrap().next_group().await }, if pending.is_some() => {
+                    let (name, consumer) = pending.take().unwrap();
                     match result {
-                        Ok(Some(mut group)) => {
-                            // Read all frames from this group
-                            while let Ok(Some(data)) = group.read_frame().await {
-                                self.frame_count += 1;
-                                
-                                let frame = ReceivedVideoFrame {
-                                    data,
-                                    track: self.name.clone(),
-                                    sequence: self.frame_count,
-                                };
-                                
-                                if self.output_tx.send(frame).await.is_err() {
-                                    debug!("video output closed");
-                                    return;
-                                }
-                                
-                                if self.frame_count % 300 == 0 {
-                                    let elapsed = start.elapsed().as_secs_f64();
-                                    let fps = self.frame_count as f64 / elapsed;
-                                    debug!(
-                                        track = %self.name,
-                                        frames = self.frame_count,
-                                        fps = fps,
-                                        "video progress"
-                                    );
-                                }
+                        Ok(Some(next)) => {
+                            info!("switched video track: {} -> {}", self.name, name);
+                            // Dropping the old consumer unsubscribes from the old track
+                            self.name = name;
+                            self.consumer = consumer;
+                            last_sequence = Some(next.info.sequence);
+                            group = Some(hang::GroupConsumer::new(next));
+                            if let Some(track_tx) = &self.track_tx {
+                                track_tx.send_replace(self.name.clone());
                             }
                         }
+                        Ok(None) => warn!("video track {name} ended before switching"),
+                        Err(e) => warn!("failed to switch to video track {name}: {e}"),
+                    }
+                }
+                result = self.consumer.next_group(), if group.is_none() => {
+                    match result {
+                        Ok(Some(next)) => {
+                            // next_group jumps to the latest group, so a gap means we fell behind
+                            let sequence = next.info.sequence;
+                            if let Some(last) = last_sequence {
+                                let skipped = sequence.saturating_sub(last + 1);
+                                self.stats.skipped_groups.fetch_add(skipped, Ordering::Relaxed);
+                            }
+                            last_sequence = Some(sequence);
+                            group = Some(hang::GroupConsumer::new(next));
+                        }
                         Ok(None) => {
                             debug!("video track ended: {}", self.name);
-                            break;
+                            break EndReason::Ended;
                         }
                         Err(e) => {
                             warn!("video track error: {e}");
-                            break;
+                            break EndReason::Error(e.to_string());
                         }
                     }
                 }
+                result = async { group.as_mut().unwrap().read().await }, if group.is_some() => {
+                    let frame = match result {
+                        Ok(Some(frame)) => frame,
+                        // End of group (or a reset group); move on to the next one
+                        Ok(None) | Err(_) => {
+                            group = None;
+                            continue;
+                        }
+                    };
+                    // hang marks the first frame of each group as the keyframe
+                    let is_keyframe = frame.keyframe;
+                    let (pts_us, data) = match read_media_frame(frame, self.decryptor.as_mut()) {
+                        Ok(frame) => frame,
+                        Err(e) => {
+                            warn!("unreadable video frame on {}: {e}", self.name);
+                            continue;
+                        }
+                    };
+                    self.frame_count += 1;
+                    self.stats.frames.fetch_add(1, Ordering::Relaxed);
+                    self.stats.bytes.fetch_add(data.len() as u64, Ordering::Relaxed);
+                    if lateness.record(Instant::now()) {
+                        self.stats.late_frames.fetch_add(1, Ordering::Relaxed);
+                    }
+
+                    let frame = ReceivedVideoFrame {
+                        data,
+                        pts_us,
+                        track: self.name.clone(),
+                        sequence: self.frame_count,
+                        is_keyframe,
+                    };
+
+                    let sent = Instant::now();
+                    if self.output_tx.send(frame).await.is_err() {
+                        debug!("video output closed");
+                        break EndReason::Stopped;
+                    }
+                    lateness.discount(sent.elapsed());
+
+                    if self.frame_count.is_multiple_of(300) {
+                        let elapsed = start.elapsed().as_secs_f64();
+                        let fps = self.frame_count as f64 / elapsed;
+                        debug!(
+                            track = %self.name,
+                            frames = self.frame_count,
+                            fps = fps,
+                            "video progress"
+                        );
+                    }
+                }
             }
-        }
+        };
 
         let elapsed = start.elapsed();
         info!(
@@ -124,6 +406,7 @@ impl WatchTrack {
             self.frame_count,
             elapsed.as_secs_f64()
         );
+        end
     }
 }
 
@@ -131,12 +414,14 @@ impl WatchTrack {
 pub struct AudioTrack {
     /// Track name
     name: String,
-    /// Track consumer from MoQ
-    consumer: TrackConsumer,
+    /// Track consumer, reading hang frames
+    consumer: hang::TrackConsumer,
     /// Frame counter
     frame_count: u64,
     /// Output channel
     output_tx: mpsc::Sender<ReceivedAudioFrame>,
+    /// Opens sealed payloads of an encrypted broadcast
+    decryptor: Option<FrameDecryptor>,
 }
 
 impl AudioTrack {
@@ -148,59 +433,128 @@ impl AudioTrack {
     ) -> Self {
         Self {
             name,
-            consumer,
+            consumer: consumer.into(),
             frame_count: 0,
             output_tx,
+            decryptor: None,
         }
     }
 
+    /// Open frame payloads sealed with `key`
+    pub fn with_decryption(mut self, key: MediaKey) -> Self {
+        self.decryptor = Some(FrameDecryptor::new(key));
+        self
+    }
+
+    /// Skip groups that fall more than `latency` behind the newest one
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.consumer.set_latency(latency);
+        self
+    }
+
     /// Run the track receiver
-    pub async fn run(mut self, cancel: CancellationToken) {
+    pub async fn run(mut self, cancel: CancellationToken) -> EndReason {
         info!("watching audio track: {}", self.name);
 
-        loop {
+        let end = loop {
             tokio::select! {
                 _ = cancel.cancelled() => {
                     debug!("audio track cancelled: {}", self.name);
-                    break;
+                    break EndReason::Stopped;
                 }
-                result = self.consumer.next_group() => {
+                result = self.consumer.read() => {
                     match result {
-                        Ok(Some(mut group)) => {
-                            // Read all frames from this group
-                            while let Ok(Some(data)) = group.read_frame().await {
-                                self.frame_count += 1;
-                                
-                                let frame = ReceivedAudioFrame {
-                                    data,
-                                    track: self.name.clone(),
-                                    sequence: self.frame_count,
-                                };
-                                
-                                if self.output_tx.send(frame).await.is_err() {
-                                    debug!("audio output closed");
-                                    return;
+                        Ok(Some(frame)) => {
+                            let (pts_us, data) = match read_media_frame(frame, self.decryptor.as_mut()) {
+                                Ok(frame) => frame,
+                                Err(e) => {
+                                    warn!("unreadable audio frame on {}: {e}", self.name);
+                                    continue;
                                 }
+                            };
+                            self.frame_count += 1;
+
+                            let frame = ReceivedAudioFrame {
+                                data,
+                                pts_us,
+                                track: self.name.clone(),
+                                sequence: self.frame_count,
+                            };
+
+                            if self.output_tx.send(frame).await.is_err() {
+                                debug!("audio output closed");
+                                break EndReason::Stopped;
                             }
                         }
                         Ok(None) => {
                             debug!("audio track ended: {}", self.name);
-                            break;
+                            break EndReason::Ended;
                         }
                         Err(e) => {
                             warn!("audio track error: {e}");
-                            break;
+                            break EndReason::Error(e.to_string());
                         }
                     }
                 }
             }
-        }
+        };
 
         info!(
             "audio track finished: {} - {} frames",
             self.name,
             self.frame_count
         );
+        end
+    }
+}
+
+/// Switches the video rendition of a running subscription
+///
+/// Cloneable, so it can be kept after the [`SubscribeHandle`] is moved into
+/// a receive loop.
+#[derive(Clone)]
+pub struct VideoSwitcher {
+    broadcast: BroadcastConsumer,
+    catalog: watch::Receiver<Option<Catalog>>,
+    switch_tx: mpsc::UnboundedSender<SwitchRequest>,
+    current: watch::Receiver<String>,
+}
+
+impl VideoSwitcher {
+    /// Name of the video track currently being received
+    pub fn current_track(&self) -> String {
+        self.current.borrow().clone()
+    }
+
+    /// Watch the name of the video track being received
+    pub fn watch_track(&self) -> watch::Receiver<String> {
+        self.current.clone()
+    }
+
+    /// Switch to the `video.<quality>` rendition
+    ///
+    /// Returns once the switch is requested; frames from the new track start
+    /// at its next group boundary.
+    pub fn switch_video(&self, quality: &str) -> Result<()> {
+        let name = format!("video.{quality}");
+        if let Some(catalog) = self.catalog.borrow().as_ref() {
+            let known = catalog
+                .video
+                .as_ref()
+                .is_some_and(|video| video.renditions.contains_key(&name));
+            if !known {
+                anyhow::bail!("broadcast has no video rendition: {name}");
+            }
+        }
+
+        let consumer = self.broadcast.subscribe_track(&Track {
+            name: name.clone(),
+            priority: 0,
+        });
+        self.switch_tx
+            .send((name, consumer))
+            .map_err(|_| anyhow::anyhow!("subscription ended"))?;
+        Ok(())
     }
 }
 
@@ -210,6 +564,22 @@ pub struct SubscribeHandle {
     pub video_rx: mpsc::Receiver<ReceivedVideoFrame>,
     /// Audio frame receiver
     pub audio_rx: mpsc::Receiver<ReceivedAudioFrame>,
+    /// Decoded video, when a [`VideoDecoder`] is plugged in
+    decoded_rx: Option<mpsc::Receiver<DecodedFrame>>,
+    /// Video rendition switching
+    switcher: VideoSwitcher,
+    /// Video receive counters
+    video_stats: Arc<TrackStats>,
+    /// Audio track being received
+    audio_track: String,
+    /// Latest catalog published by the broadcaster
+    catalog: watch::Receiver<Option<Catalog>>,
+    /// Playout clock for [`SubscribeHandle::next_playout`]
+    clock: PlayoutClock,
+    /// Audio waiting for its play time
+    jitter: VecDeque<(n0_future::time::Instant, ReceivedAudioFrame)>,
+    /// Set once the subscription is over
+    end: watch::Receiver<Option<EndReason>>,
     /// Cancellation token to stop subscription
     cancel: CancellationToken,
 }
@@ -220,17 +590,196 @@ impl SubscribeHandle {
         self.video_rx.recv().await
     }
 
+    /// Receive the next decoded video frame
+    ///
+    /// Only available with a [`VideoDecoder`]; encoded frames then go to the
+    /// decoder and `recv_video` returns None. Returns None without a decoder
+    /// or once the video track has ended.
+    pub async fn recv_decoded(&mut self) -> Option<DecodedFrame> {
+        self.decoded_rx.as_mut()?.recv().await
+    }
+
+    /// Take the next decoded video frame without waiting
+    pub fn try_recv_decoded(&mut self) -> Option<DecodedFrame> {
+        self.decoded_rx.as_mut()?.try_recv().ok()
+    }
+
+    /// Whether video is decoded before it is handed out
+    pub fn is_decoding(&self) -> bool {
+        self.decoded_rx.is_some()
+    }
+
+    /// Take the decoded frame receiver, to read it apart from the handle
+    pub fn take_decoded_rx(&mut self) -> Option<mpsc::Receiver<DecodedFrame>> {
+        self.decoded_rx.take()
+    }
+
     /// Receive the next audio frame
     pub async fn recv_audio(&mut self) -> Option<ReceivedAudioFrame> {
         self.audio_rx.recv().await
     }
 
+    /// Receive the next frame in playout order
+    ///
+    /// Audio is held in a jitter buffer and released at its play time.
+    /// Video is released on arrival with the time to render it, or as
+    /// [`Playout::LateVideo`] when it is too late. Don't mix this with
+    /// `recv_video`/`recv_audio`. Returns None once both tracks have ended
+    /// and the buffer is drained.
+    pub async fn next_playout(&mut self) -> Option<Playout> {
+        let mut audio_open = !self.audio_rx.is_closed() || !self.audio_rx.is_empty();
+        let mut video_open = !self.video_rx.is_closed() || !self.video_rx.is_empty();
+
+        loop {
+            let now = n0_future::time::Instant::now();
+            if self.jitter.front().is_some_and(|(at, _)| *at <= now) {
+                return self.jitter.pop_front().map(|(_, frame)| Playout::Audio(frame));
+            }
+            let next_audio = self.jitter.front().map(|(at, _)| *at);
+            if !audio_open && !video_open {
+                let (_, frame) = self.jitter.pop_front()?;
+                n0_future::time::sleep_until(next_audio?).await;
+                return Some(Playout::Audio(frame));
+            }
+
+            tokio::select! {
+                frame = self.audio_rx.recv(), if audio_open => match frame {
+                    Some(frame) => {
+                        let at = self.clock.audio(frame.pts_us, n0_future::time::Instant::now());
+                        self.jitter.push_back((at, frame));
+                    }
+                    None => audio_open = false,
+                },
+                frame = self.video_rx.recv(), if video_open => match frame {
+                    Some(frame) => {
+                        let now = n0_future::time::Instant::now();
+                        return Some(match self.clock.video(frame.pts_us, frame.is_keyframe, now) {
+                            VideoPlayout::Render(render_at) => Playout::Video { frame, render_at },
+                            VideoPlayout::Drop { late_by } => {
+                                debug!(pts = frame.pts_us, ?late_by, "video frame late");
+                                Playout::LateVideo(frame)
+                            }
+                        });
+                    }
+                    None => video_open = false,
+                },
+                _ = n0_future::time::sleep_until(next_audio.unwrap_or(now)), if next_audio.is_some() => {}
+            }
+        }
+    }
+
+    /// Playout clock used by [`SubscribeHandle::next_playout`]
+    pub fn playout_clock(&self) -> &PlayoutClock {
+        &self.clock
+    }
+
+    /// Name of the video track being received
+    pub fn video_track(&self) -> String {
+        self.switcher.current_track()
+    }
+
+    /// Switch to another video rendition (see [`VideoSwitcher::switch_video`])
+    pub fn switch_video(&self, quality: &str) -> Result<()> {
+        self.switcher.switch_video(quality)
+    }
+
+    /// Get a cloneable switcher for this subscription
+    pub fn switcher(&self) -> VideoSwitcher {
+        self.switcher.clone()
+    }
+
+    /// Receive counters for the video track (carried across switches)
+    pub fn video_stats(&self) -> Arc<TrackStats> {
+        self.video_stats.clone()
+    }
+
+    /// Name of the audio track being received
+    pub fn audio_track(&self) -> &str {
+        &self.audio_track
+    }
+
+    /// Latest catalog, if the broadcaster publishes one
+    pub fn catalog(&self) -> Option<Catalog> {
+        self.catalog.borrow().clone()
+    }
+
+    /// Why the subscription ended, if it has
+    pub fn end_reason(&self) -> Option<EndReason> {
+        self.end.borrow().clone()
+    }
+
+    /// Wait for the subscription to end
+    ///
+    /// Owns what it needs, so it can be awaited alongside the receive
+    /// methods.
+    pub fn ended(&self) -> impl std::future::Future<Output = EndReason> + Send + 'static {
+        let mut end = self.end.clone();
+        async move {
+            match end.wait_for(Option::is_some).await {
+                Ok(reason) => reason.clone().unwrap_or(EndReason::Stopped),
+                Err(_) => EndReason::Error("subscription task failed".to_string()),
+            }
+        }
+    }
+
     /// Stop the subscription
     pub fn stop(&self) {
         self.cancel.cancel();
     }
 }
 
+/// Pick the rendition to use from `(track name, metric)` candidates
+///
+/// An exact name match wins. Otherwise the largest candidate at or below
+/// `target` is used, falling back to the smallest; with no target the
+/// largest is used.
+fn select_rendition(
+    mut candidates: Vec<(String, u64)>,
+    preferred: &str,
+    target: Option<u64>,
+) -> Option<String> {
+    if candidates.iter().any(|(name, _)| name == preferred) {
+        return Some(preferred.to_string());
+    }
+
+    candidates.sort_by(|a, b| a.1.cmp(&b.1).then_with(|| a.0.cmp(&b.0)));
+    let chosen = match target {
+        Some(target) => candidates
+            .iter()
+            .rev()
+            .find(|(_, metric)| *metric <= target)
+            .or(candidates.first()),
+        None => candidates.last(),
+    };
+    chosen.map(|(name, _)| name.clone())
+}
+
+/// Pick a video track from the catalog for the preferred quality suffix
+pub fn select_video_rendition(catalog: &Catalog, quality: Option<&str>) -> Option<String> {
+    let video = catalog.video.as_ref()?;
+    let candidates = video
+        .renditions
+        .iter()
+        .map(|(name, config)| (name.clone(), config.coded_height.unwrap_or(0) as u64))
+        .collect();
+    let quality = quality.unwrap_or("med");
+    let target = VideoQuality::from_suffix(quality).map(|q| q.height() as u64);
+    select_rendition(candidates, &format!("video.{quality}"), target)
+}
+
+/// Pick an audio track from the catalog for the preferred quality suffix
+pub fn select_audio_rendition(catalog: &Catalog, quality: Option<&str>) -> Option<String> {
+    let audio = catalog.audio.as_ref()?;
+    let candidates = audio
+        .renditions
+        .iter()
+        .map(|(name, config)| (name.clone(), config.bitrate.unwrap_or(0)))
+        .collect();
+    let quality = quality.unwrap_or("med");
+    let target = AudioQuality::from_suffix(quality).map(|q| q.bitrate() as u64);
+    select_rendition(candidates, &format!("audio.{quality}"), target)
+}
+
 /// Configuration for subscribe broadcast
 #[derive(Debug, Clone)]
 pub struct SubscribeConfig {
@@ -240,6 +789,12 @@ pub struct SubscribeConfig {
     pub audio_quality: Option<String>,
     /// Buffer size for received frames
     pub buffer_size: usize,
+    /// How long to wait for the catalog before guessing track names
+    pub catalog_timeout: Duration,
+    /// Delay between a frame arriving and playing it, absorbing network jitter
+    pub target_latency: Duration,
+    /// Media key of an encrypted broadcast
+    pub encryption: Option<MediaKey>,
 }
 
 impl Default for SubscribeConfig {
@@ -248,6 +803,9 @@ impl Default for SubscribeConfig {
             video_quality: Some("med".to_string()),
             audio_quality: Some("med".to_string()),
             buffer_size: 64,
+            catalog_timeout: Duration::from_secs(5),
+            target_latency: Duration::from_millis(150),
+            encryption: None,
         }
     }
 }
@@ -260,6 +818,8 @@ pub struct SubscribeBroadcast {
     config: SubscribeConfig,
     /// Broadcast consumer
     broadcast: BroadcastConsumer,
+    /// Optional decoder for the video track
+    decoder: Option<Box<dyn VideoDecoder>>,
     /// Cancellation token
     cancel: CancellationToken,
 }
@@ -270,109 +830,268 @@ impl SubscribeBroadcast {
         Self {
             config,
             broadcast,
+            decoder: None,
             cancel: CancellationToken::new(),
         }
     }
 
+    /// Decode video before handing it out
+    ///
+    /// Frames are then read with [`SubscribeHandle::recv_decoded`].
+    pub fn with_video_decoder(mut self, decoder: impl VideoDecoder) -> Self {
+        self.decoder = Some(Box::new(decoder));
+        self
+    }
+
     /// Create with default config
     pub fn with_default(broadcast: BroadcastConsumer) -> Self {
         Self::new(broadcast, SubscribeConfig::default())
     }
 
     /// Start receiving and return a handle for consuming frames
-    pub async fn start(self) -> Result<SubscribeHandle> {
-        let (video_tx, video_rx) = mpsc::channel(self.config.buffer_size);
+    ///
+    /// Waits up to `catalog_timeout` for the catalog and picks renditions
+    /// from it; publishers without a catalog get the configured track names.
+    pub async fn start(mut self) -> Result<SubscribeHandle> {
+        let (video_tx, mut video_rx) = mpsc::channel(self.config.buffer_size);
         let (audio_tx, audio_rx) = mpsc::channel(self.config.buffer_size);
 
+        let mut catalog_consumer =
+            CatalogConsumer::new(self.broadcast.subscribe_track(&Catalog::default_track()));
+        let catalog = match n0_future::time::timeout(
+            self.config.catalog_timeout,
+            catalog_consumer.next(),
+        )
+        .await
+        {
+            Ok(Ok(Some(catalog))) => Some(catalog),
+            Ok(Ok(None)) => {
+                warn!("catalog track ended before a catalog was published");
+                None
+            }
+            Ok(Err(e)) => {
+                warn!("failed to read catalog: {e}");
+                None
+            }
+            Err(_) => {
+                warn!("no catalog received, guessing track names");
+                None
+            }
+        };
+
+        let video_quality = self.config.video_quality.as_deref();
+        let audio_quality = self.config.audio_quality.as_deref();
+        let video_track = catalog
+            .as_ref()
+            .and_then(|c| select_video_rendition(c, video_quality))
+            .unwrap_or_else(|| format!("video.{}", video_quality.unwrap_or("med")));
+        let audio_track = catalog
+            .as_ref()
+            .and_then(|c| select_audio_rendition(c, audio_quality))
+            .unwrap_or_else(|| format!("audio.{}", audio_quality.unwrap_or("med")));
+        info!(video = %video_track, audio = %audio_track, "selected renditions");
+
+        let (catalog_tx, catalog_rx) = watch::channel(catalog);
+        let (end_tx, end_rx) = watch::channel(None);
+        let (switch_tx, switch_rx) = mpsc::unbounded_channel();
+        let (track_tx, track_rx) = watch::channel(video_track.clone());
         let cancel = self.cancel.clone();
 
+        let switcher = VideoSwitcher {
+            broadcast: self.broadcast.clone(),
+            catalog: catalog_rx.clone(),
+            switch_tx,
+            current: track_rx,
+        };
+
         // Start the subscriber task
-        tokio::spawn(self.run_subscriber(video_tx, audio_tx));
+        let mut watch = WatchTrack::new(
+            video_track.clone(),
+            self.broadcast.subscribe_track(&Track {
+                name: video_track,
+                priority: 0,
+            }),
+            video_tx,
+        )
+        .with_switching(switch_rx, track_tx);
+        if let Some(key) = &self.config.encryption {
+            watch = watch.with_decryption(key.clone());
+        }
+        let video_stats = watch.stats();
+        let clock = PlayoutClock::new(self.config.target_latency);
+
+        // With a decoder, encoded video goes to the decode thread instead
+        let decoded_rx = self.decoder.take().map(|decoder| {
+            let (decoded_tx, decoded_rx) = mpsc::channel(DECODED_BUFFER);
+            let (_, closed_rx) = mpsc::channel(1);
+            let encoded_rx = std::mem::replace(&mut video_rx, closed_rx);
+            let catalog = catalog_rx.clone();
+            std::thread::spawn(move || run_decoder(decoder, encoded_rx, catalog, decoded_tx));
+            decoded_rx
+        });
+
+        tokio::spawn(self.run_subscriber(
+            watch,
+            audio_track.clone(),
+            catalog_consumer,
+            catalog_tx,
+            audio_tx,
+            end_tx,
+        ));
 
         Ok(SubscribeHandle {
             video_rx,
             audio_rx,
+            decoded_rx,
+            switcher,
+            video_stats,
+            audio_track,
+            catalog: catalog_rx,
+            clock,
+            jitter: VecDeque::new(),
+            end: end_rx,
             cancel,
         })
     }
 
-    /// Run the subscriber, receiving tracks from the catalog
+    /// Run the subscriber, receiving the selected tracks and catalog updates
     #[instrument(skip_all, name = "subscriber")]
     async fn run_subscriber(
         self,
-        video_tx: mpsc::Sender<ReceivedVideoFrame>,
+        watch: WatchTrack,
+        audio_track_name: String,
+        mut catalog_consumer: CatalogConsumer,
+        catalog_tx: watch::Sender<Option<Catalog>>,
         audio_tx: mpsc::Sender<ReceivedAudioFrame>,
+        end_tx: watch::Sender<Option<EndReason>>,
     ) {
         info!("starting broadcast subscription");
 
         // Find and subscribe to tracks
         let mut tasks = tokio::task::JoinSet::new();
 
-        // Subscribe to video track
-        let video_track_name = self
-            .config
-            .video_quality
-            .clone()
-            .map(|q| format!("video.{q}"))
-            .unwrap_or_else(|| "video.med".to_string());
-        
-        let video_track = Track {
-            name: video_track_name.clone(),
-            priority: 0,
-        };
-        let video_consumer = self.broadcast.subscribe_track(&video_track);
-        
-        let watch = WatchTrack::new(video_track_name, video_consumer, video_tx);
+        // Receive the video track
         let cancel = self.cancel.child_token();
-        tasks.spawn(async move {
-            watch.run(cancel).await;
-        });
+        tasks.spawn(async move { watch.run(cancel).await });
 
         // Subscribe to audio track
-        let audio_track_name = self
-            .config
-            .audio_quality
-            .clone()
-            .map(|q| format!("audio.{q}"))
-            .unwrap_or_else(|| "audio.med".to_string());
-        
         let audio_track = Track {
             name: audio_track_name.clone(),
             priority: 0,
         };
         let audio_consumer = self.broadcast.subscribe_track(&audio_track);
         
-        let audio = AudioTrack::new(audio_track_name, audio_consumer, audio_tx);
+        // hang reads audio a whole group at a time; a latency shorter than
+        // a group would throw away the rest of it at every stall
+        let mut audio = AudioTrack::new(audio_track_name, audio_consumer, audio_tx)
+            .with_latency(self.config.target_latency.max(AUDIO_GROUP_DURATION));
+        if let Some(key) = &self.config.encryption {
+            audio = audio.with_decryption(key.clone());
+        }
+        let cancel = self.cancel.child_token();
+        tasks.spawn(async move { audio.run(cancel).await });
+
+        // Keep the handle's catalog up to date
         let cancel = self.cancel.child_token();
-        tasks.spawn(async move {
-            audio.run(cancel).await;
+        tokio::spawn(async move {
+            loop {
+                tokio::select! {
+                    _ = cancel.cancelled() => break,
+                    result = catalog_consumer.next() => match result {
+                        Ok(Some(catalog)) => {
+                            debug!("catalog updated");
+                            catalog_tx.send_replace(Some(catalog));
+                        }
+                        Ok(None) => break,
+                        Err(e) => {
+                            debug!("catalog track error: {e}");
+                            break;
+                        }
+                    },
+                }
+            }
         });
 
         // Wait for all tracks to finish or cancellation
-        loop {
+        let mut ends = Vec::new();
+        let reason = loop {
             tokio::select! {
                 _ = self.cancel.cancelled() => {
                     info!("subscription cancelled");
                     tasks.abort_all();
-                    break;
+                    break EndReason::Stopped;
                 }
-                result = tasks.join_next(), if !tasks.is_empty() => {
-                    if result.is_none() {
+                result = tasks.join_next() => match result {
+                    Some(Ok(end)) => ends.push(end),
+                    Some(Err(e)) => ends.push(EndReason::Error(format!("track task failed: {e}"))),
+                    None => {
                         debug!("all tracks finished");
-                        break;
+                        break EndReason::of_tracks(ends);
                     }
+                },
+            }
+        };
+
+        // Media tracks are done; stop following the catalog too
+        self.cancel.cancel();
+        info!(?reason, "subscription ended");
+        end_tx.send_replace(Some(reason));
+    }
+}
+
+/// Decode video frames until the track or the handle goes away
+///
+/// The decoder is reconfigured from the catalog whenever frames start
+/// arriving from a different track.
+fn run_decoder(
+    mut decoder: Box<dyn VideoDecoder>,
+    mut encoded_rx: mpsc::Receiver<ReceivedVideoFrame>,
+    catalog: watch::Receiver<Option<Catalog>>,
+    decoded_tx: mpsc::Sender<DecodedFrame>,
+) {
+    let mut configured: Option<String> = None;
+    let mut warned = false;
+
+    while let Some(frame) = encoded_rx.blocking_recv() {
+        if configured.as_deref() != Some(frame.track.as_str()) {
+            let config = catalog
+                .borrow()
+                .as_ref()
+                .and_then(|c| c.video.as_ref()?.renditions.get(&frame.track).cloned());
+            let Some(config) = config else {
+                if !warned {
+                    warn!(track = %frame.track, "no catalog entry for video track, can't decode");
+                    warned = true;
                 }
+                continue;
+            };
+            if let Err(e) = decoder.configure(&frame.track, &config) {
+                warn!(track = %frame.track, "failed to configure video decoder: {e}");
+                continue;
             }
+            debug!(track = %frame.track, "video decoder configured");
+            configured = Some(frame.track.clone());
+            warned = false;
         }
 
-        info!("subscription ended");
+        match decoder.decode(&frame) {
+            Ok(Some(decoded)) => {
+                if decoded_tx.blocking_send(decoded).is_err() {
+                    break;
+                }
+            }
+            Ok(None) => {}
+            Err(e) => debug!(sequence = frame.sequence, "failed to decode video frame: {e}"),
+        }
     }
+    debug!("video decoder stopped");
 }
 
 /// Builder for SubscribeBroadcast
 pub struct SubscribeBroadcastBuilder {
     broadcast: BroadcastConsumer,
     config: SubscribeConfig,
+    decoder: Option<Box<dyn VideoDecoder>>,
 }
 
 impl SubscribeBroadcastBuilder {
@@ -381,6 +1100,7 @@ impl SubscribeBroadcastBuilder {
         Self {
             broadcast,
             config: SubscribeConfig::default(),
+            decoder: None,
         }
     }
 
@@ -402,8 +1122,303 @@ impl SubscribeBroadcastBuilder {
         self
     }
 
+    /// Set how long to wait for the catalog
+    pub fn catalog_timeout(mut self, timeout: Duration) -> Self {
+        self.config.catalog_timeout = timeout;
+        self
+    }
+
+    /// Set the playout target latency
+    pub fn target_latency(mut self, latency: Duration) -> Self {
+        self.config.target_latency = latency;
+        self
+    }
+
+    /// Open an encrypted broadcast with its media key
+    pub fn encryption(mut self, key: MediaKey) -> Self {
+        self.config.encryption = Some(key);
+        self
+    }
+
+    /// Decode video with the given decoder
+    pub fn video_decoder(mut self, decoder: impl VideoDecoder) -> Self {
+        self.decoder = Some(Box::new(decoder));
+        self
+    }
+
     /// Build the subscriber
     pub fn build(self) -> SubscribeBroadcast {
-        SubscribeBroadcast::new(self.broadcast, self.config)
+        let mut subscriber = SubscribeBroadcast::new(self.broadcast, self.config);
+        subscriber.decoder = self.decoder;
+        subscriber
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::publish_broadcast::catalog_for;
+
+    #[test]
+    fn test_select_exact_rendition() {
+        let catalog = catalog_for(&[VideoQuality::Low, VideoQuality::Medium], &[AudioQuality::High]);
+        assert_eq!(select_video_rendition(&catalog, Some("low")).as_deref(), Some("video.low"));
+        assert_eq!(select_audio_rendition(&catalog, Some("high")).as_deref(), Some("audio.high"));
+    }
+
+    #[test]
+    fn test_select_closest_rendition() {
+        let catalog = catalog_for(&[VideoQuality::Low, VideoQuality::Medium], &[AudioQuality::High]);
+        // No 1080p rendition: take the largest below it
+        assert_eq!(select_video_rendition(&catalog, Some("high")).as_deref(), Some("video.med"));
+        // Nothing at or below the target: take the smallest
+        assert_eq!(select_audio_rendition(&catalog, Some("low")).as_deref(), Some("audio.high"));
+        assert_eq!(select_video_rendition(&Catalog::default(), None), None);
+    }
+
+    #[test]
+    fn test_lateness_tracker() {
+        let mut tracker = LatenessTracker::default();
+        let start = Instant::now();
+        let at = |ms| start + std::time::Duration::from_millis(ms);
+
+        for i in 0..10 {
+            assert!(!tracker.record(at(i * 33)));
+        }
+        // A 200ms stall at 30fps is late; the next on-time frame is not
+        assert!(tracker.record(at(9 * 33 + 200)));
+        assert!(!tracker.record(at(9 * 33 + 233)));
+
+        // Time blocked on a full output channel isn't the network's fault
+        tracker.discount(std::time::Duration::from_millis(200));
+        assert!(!tracker.record(at(9 * 33 + 466)));
+    }
+
+    #[tokio::test]
+    async fn test_switch_video_at_group_boundary() {
+        let produce = moq_lite::Broadcast::produce();
+        let mut broadcast = produce.producer;
+        let mut low = hang::TrackProducer::new(
+            broadcast.create_track(Track { name: "video.low".to_string(), priority: 0 }),
+        );
+        let mut med = hang::TrackProducer::new(
+            broadcast.create_track(Track { name: "video.med".to_string(), priority: 0 }),
+        );
+        let frame = |ms, keyframe, payload: &'static [u8]| hang::Frame {
+            timestamp: std::time::Duration::from_millis(ms),
+            keyframe,
+            payload: Bytes::from_static(payload),
+        };
+
+        let mut handle = SubscribeBroadcastBuilder::new(produce.consumer)
+            .video_quality("low")
+            .catalog_timeout(Duration::from_millis(10))
+            .build()
+            .start()
+            .await
+            .unwrap();
+
+        low.write(frame(0, true, b"low-key"));
+        let received = handle.recv_video().await.unwrap();
+        assert_eq!((received.track.as_str(), received.is_keyframe), ("video.low", true));
+
+        // The old track keeps playing until the new one starts a group
+        handle.switch_video("med").unwrap();
+        low.write(frame(33, false, b"low-delta"));
+        let received = handle.recv_video().await.unwrap();
+        assert_eq!((received.track.as_str(), received.is_keyframe), ("video.low", false));
+
+        med.write(frame(66, true, b"med-key"));
+        let received = handle.recv_video().await.unwrap();
+        assert_eq!((received.track.as_str(), received.is_keyframe), ("video.med", true));
+        assert_eq!(received.data, Bytes::from_static(b"med-key"));
+        assert_eq!(received.pts_us, 66_000);
+        assert_eq!(handle.video_track(), "video.med");
+
+        handle.stop();
+    }
+
+    #[tokio::test]
+    async fn test_playout_carries_timestamps() {
+        use crate::api::publish_broadcast::{
+            EncodedAudioFrame, EncodedVideoFrame, PublishBroadcast, PublishConfig,
+        };
+
+        let (broadcast, publish) = PublishBroadcast::new(PublishConfig::default());
+        let consumer = broadcast.producer().consume();
+        tokio::spawn(broadcast.run());
+
+        let mut handle = SubscribeBroadcastBuilder::new(consumer)
+            .target_latency(Duration::from_millis(20))
+            .build()
+            .start()
+            .await
+            .unwrap();
+
+        publish.push_audio(EncodedAudioFrame {
+            data: Bytes::from_static(b"opus"),
+            pts_us: 40_000,
+            quality: AudioQuality::Medium,
+        }).await.unwrap();
+        publish.push_video(EncodedVideoFrame {
+            data: Bytes::from_static(b"idr"),
+            pts_us: 40_000,
+            is_keyframe: true,
+            quality: VideoQuality::Medium,
+        }).await.unwrap();
+
+        let mut video_at = None;
+        let mut audio_at = None;
+        while video_at.is_none() || audio_at.is_none() {
+            match handle.next_playout().await.unwrap() {
+                Playout::Video { frame, render_at } => {
+                    assert_eq!((frame.pts_us, &frame.data[..]), (40_000, &b"idr"[..]));
+                    video_at = Some(render_at);
+                }
+                Playout::Audio(frame) => {
+                    assert_eq!((frame.pts_us, &frame.data[..]), (40_000, &b"opus"[..]));
+                    audio_at = Some(n0_future::time::Instant::now());
+                }
+                Playout::LateVideo(_) => panic!("video should be on time"),
+            }
+        }
+        // Same timestamp: video renders when the audio plays
+        assert!(audio_at.unwrap() >= video_at.unwrap());
+
+        handle.stop();
+        publish.stop().await.unwrap();
+    }
+
+    #[test]
+    fn test_end_reason_of_tracks() {
+        let error = || EndReason::Error("reset".to_string());
+        assert_eq!(EndReason::of_tracks([error(), EndReason::Ended]), EndReason::Ended);
+        assert_eq!(EndReason::of_tracks([EndReason::Stopped, error()]), error());
+        assert_eq!(EndReason::of_tracks([]), EndReason::Stopped);
+    }
+
+    #[tokio::test]
+    async fn test_stopped_publisher_ends_subscription() {
+        use crate::api::publish_broadcast::{EncodedVideoFrame, PublishBroadcast, PublishConfig};
+
+        let (broadcast, publish) = PublishBroadcast::new(PublishConfig::default());
+        let consumer = broadcast.producer().consume();
+        tokio::spawn(broadcast.run());
+
+        let subscribe = |consumer: BroadcastConsumer| SubscribeBroadcastBuilder::new(consumer).build().start();
+        let mut watching = subscribe(consumer.clone()).await.unwrap();
+        let stopped = subscribe(consumer).await.unwrap();
+
+        publish.push_video(EncodedVideoFrame {
+            data: Bytes::from_static(b"idr"),
+            pts_us: 0,
+            is_keyframe: true,
+            quality: VideoQuality::Medium,
+        }).await.unwrap();
+        assert_eq!(&watching.recv_video().await.unwrap().data[..], b"idr");
+
+        stopped.stop();
+        assert_eq!(stopped.ended().await, EndReason::Stopped);
+        assert_eq!(watching.end_reason(), None);
+
+        // The publisher finishing its tracks is an end, not an error
+        publish.stop().await.unwrap();
+        assert!(watching.recv_video().await.is_none());
+        assert_eq!(watching.ended().await, EndReason::Ended);
+        assert_eq!(watching.end_reason(), Some(EndReason::Ended));
+    }
+
+    #[tokio::test]
+    async fn test_encrypted_broadcast() {
+        use crate::api::publish_broadcast::{EncodedVideoFrame, PublishBroadcastBuilder};
+
+        let key = MediaKey::generate();
+        let (broadcast, publish) = PublishBroadcastBuilder::new("sealed")
+            .encryption(key.clone())
+            .build();
+        let consumer = broadcast.producer().consume();
+        tokio::spawn(broadcast.run());
+
+        // What a relay forwards: the raw track
+        let mut raw = consumer.subscribe_track(&Track { name: "video.med".to_string(), priority: 0 });
+        let mut handle = SubscribeBroadcastBuilder::new(consumer)
+            .encryption(key)
+            .build()
+            .start()
+            .await
+            .unwrap();
+
+        for (pts_us, is_keyframe, data) in [(0, true, &b"idr-0"[..]), (33_000, false, b"p-1"), (66_000, true, b"idr-2")] {
+            publish.push_video(EncodedVideoFrame {
+                data: Bytes::from_static(data),
+                pts_us,
+                is_keyframe,
+                quality: VideoQuality::Medium,
+            }).await.unwrap();
+            let received = handle.recv_video().await.unwrap();
+            assert_eq!((received.pts_us, &received.data[..]), (pts_us, data));
+        }
+
+        let mut group = hang::GroupConsumer::new(raw.next_group().await.unwrap().unwrap());
+        let sealed = group.read().await.unwrap().unwrap().payload;
+        assert!(!sealed.windows(4).any(|w| w == b"idr-"), "relays must not see the payload");
+
+        handle.stop();
+        publish.stop().await.unwrap();
+    }
+
+    /// Decoder that reports its configuration and echoes payloads
+    struct EchoDecoder {
+        configured: std::sync::mpsc::Sender<String>,
+    }
+
+    impl VideoDecoder for EchoDecoder {
+        fn configure(&mut self, track: &str, _config: &hang::catalog::VideoConfig) -> Result<()> {
+            self.configured.send(track.to_string()).unwrap();
+            Ok(())
+        }
+
+        fn decode(&mut self, frame: &ReceivedVideoFrame) -> Result<Option<DecodedFrame>> {
+            Ok(Some(DecodedFrame {
+                data: frame.data.to_vec(),
+                width: 2,
+                height: 2,
+                pixel_format: Default::default(),
+                timestamp: std::time::Duration::from_micros(frame.pts_us as u64),
+            }))
+        }
+    }
+
+    #[tokio::test]
+    async fn test_video_decoder_configured_from_catalog() {
+        use crate::api::publish_broadcast::{EncodedVideoFrame, PublishBroadcast, PublishConfig};
+
+        let (broadcast, publish) = PublishBroadcast::new(PublishConfig::default());
+        let consumer = broadcast.producer().consume();
+        tokio::spawn(broadcast.run());
+
+        let (configured_tx, configured_rx) = std::sync::mpsc::channel();
+        let mut handle = SubscribeBroadcastBuilder::new(consumer)
+            .video_decoder(EchoDecoder { configured: configured_tx })
+            .build()
+            .start()
+            .await
+            .unwrap();
+
+        publish.push_video(EncodedVideoFrame {
+            data: Bytes::from_static(b"idr"),
+            pts_us: 66_000,
+            is_keyframe: true,
+            quality: VideoQuality::Medium,
+        }).await.unwrap();
+
+        let decoded = handle.recv_decoded().await.unwrap();
+        assert_eq!((decoded.data.as_slice(), decoded.timestamp), (&b"idr"[..], Duration::from_millis(66)));
+        assert_eq!(configured_rx.recv().unwrap(), "video.med");
+        // Encoded frames went to the decoder
+        assert!(handle.recv_video().await.is_none());
+
+        handle.stop();
+        publish.stop().await.unwrap();
     }
 }
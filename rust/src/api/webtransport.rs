@@ -185,7 +185,13 @@ impl Session {
     }
 
     /// Close the session
+    ///
+    /// Does nothing once the connection is closed, so the peer's close
+    /// reason isn't overwritten.
     pub fn close(&self, code: u32, reason: &str) {
+        if self.conn.close_reason().is_some() {
+            return;
+        }
         let code = quinn::VarInt::try_from(code).unwrap_or(quinn::VarInt::from_u32(0));
         self.conn.close(code, reason.as_bytes());
     }
@@ -267,8 +273,15 @@ impl web_transport_trait::SendStream for SendStream {
         SendStream::reset(self, code);
     }
 
+    /// Finish the stream and wait until the peer has it
+    ///
+    /// Blocks until all written data is acknowledged or the peer sends
+    /// STOP_SENDING. moq-lite resets streams on drop, which would discard
+    /// data still in flight.
     async fn finish(&mut self) -> Result<(), Self::Error> {
-        SendStream::finish(self)
+        SendStream::finish(self)?;
+        self.inner.stopped().await.map_err(|_| SessionError::Closed)?;
+        Ok(())
     }
 
     async fn closed(&mut self) -> Result<(), Self::Error> {
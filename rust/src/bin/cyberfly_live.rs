@@ -0,0 +1,606 @@
+//! Headless publisher, subscriber and relay for the streaming core
+//!
+//! Runs the same `Live` actor and broadcast pipelines as the Flutter app,
+//! so a Linux box can publish a test pattern or MP4 file, record a remote
+//! broadcast, or relay one to other viewers without the app:
+//!
+//! ```text
+//! cyberfly-live publish --test-pattern --name demo   # needs --features ffmpeg
+//! cyberfly-live publish --file talk.mp4 --looping --encrypt
+//! cyberfly-live subscribe <ticket> --out talk.mp4
+//! cyberfly-live relay <ticket>
+//! cyberfly-live discover <ticket> --prefix room
+//! cyberfly-live ticket inspect <ticket>
+//! ```
+//!
+//! Build with: cargo build --features cli --bin cyberfly-live
+//!
+//! Chat messages on the broadcast are printed as they arrive.
+//!
+//! Stats are printed every `--stats-interval` seconds; Ctrl-C stops the
+//! command, finishing any recording before the endpoint closes.
+
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use clap::{Args, Parser, Subcommand};
+use iroh::Endpoint;
+use iroh::protocol::Router;
+use n0_future::time::{self, Duration};
+use tracing::{info, warn};
+use tracing_subscriber::EnvFilter;
+
+use rust_lib_cyberfly_streaming::api::chat::{ChatKind, ChatMessage, ChatRate, ChatReader};
+use rust_lib_cyberfly_streaming::api::discovery::{Discovery, DiscoveryEvent};
+use rust_lib_cyberfly_streaming::api::encryption::MediaKey;
+use rust_lib_cyberfly_streaming::api::identity;
+use rust_lib_cyberfly_streaming::api::iroh_live::LiveTicket;
+use rust_lib_cyberfly_streaming::api::live_protocol::{ALPN, LEGACY_ALPN, Live};
+use rust_lib_cyberfly_streaming::api::publish_broadcast::{PublishBroadcast, PublishConfig, PublishHandle, VideoQuality};
+use rust_lib_cyberfly_streaming::api::record::{RecordConfig, Recorder};
+use rust_lib_cyberfly_streaming::api::relay::{self, ROUTE_ALPN, RelayConfig, RouteProtocolHandler};
+use rust_lib_cyberfly_streaming::api::replay::{FileReplay, ReplayConfig};
+use rust_lib_cyberfly_streaming::api::roster::RosterEvent;
+use rust_lib_cyberfly_streaming::api::subscribe_broadcast::{EndReason, SubscribeBroadcast, SubscribeConfig};
+
+#[derive(Debug, Parser)]
+#[command(name = "cyberfly-live", version, about = "Headless iroh-live publisher, subscriber and relay")]
+struct Cli {
+    /// Seconds between stats lines
+    #[arg(long, global = true, default_value_t = 2)]
+    stats_interval: u64,
+    /// Identity file, created on first use; keeps tickets stable across runs
+    #[arg(long, global = true)]
+    key: Option<PathBuf>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Publish a broadcast and print its ticket
+    Publish(PublishArgs),
+    /// Watch a broadcast, optionally recording it to MP4
+    Subscribe(SubscribeArgs),
+    /// Re-publish a remote broadcast from this node
+    Relay {
+        /// Ticket of the broadcast to relay
+        ticket: String,
+        /// Longest route (nodes before this one) to relay from
+        #[arg(long, default_value_t = RelayConfig::default().max_hops)]
+        max_hops: usize,
+    },
+    /// List the broadcasts a node offers as they come and go
+    Discover {
+        /// Ticket of any broadcast on the node
+        ticket: String,
+        /// Only broadcasts under this path
+        #[arg(long, default_value = "")]
+        prefix: String,
+    },
+    /// Work with broadcast tickets
+    #[command(subcommand)]
+    Ticket(TicketCommand),
+}
+
+#[derive(Debug, Args)]
+struct PublishArgs {
+    /// Broadcast name
+    #[arg(long, default_value = "cyberfly-live")]
+    name: String,
+    /// Publish a generated test pattern
+    #[arg(long, conflicts_with = "file", required_unless_present = "file")]
+    test_pattern: bool,
+    /// Publish an MP4 file in real time
+    #[arg(long)]
+    file: Option<PathBuf>,
+    /// Start the file over when it ends
+    #[arg(long, requires = "file")]
+    looping: bool,
+    /// Test pattern renditions (low, med, high)
+    #[arg(long, value_delimiter = ',', default_value = "med", value_parser = parse_quality)]
+    quality: Vec<VideoQuality>,
+    /// Test pattern frame rate
+    #[arg(long, default_value_t = 30)]
+    fps: u32,
+    /// Encrypt frames end to end; the key goes in the printed ticket
+    #[arg(long)]
+    encrypt: bool,
+}
+
+#[derive(Debug, Args)]
+struct SubscribeArgs {
+    /// Ticket of the broadcast to watch
+    ticket: String,
+    /// Record the broadcast to this MP4 file
+    #[arg(long)]
+    out: Option<PathBuf>,
+    /// Video rendition to receive (low, med, high)
+    #[arg(long, default_value = "high")]
+    quality: String,
+    /// Take the media key from this ticket, e.g. the publisher's when
+    /// watching an encrypted broadcast through a relay
+    #[arg(long)]
+    key_ticket: Option<String>,
+    /// Name shown in the publisher's viewer roster
+    #[arg(long)]
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Subcommand)]
+enum TicketCommand {
+    /// Print what a ticket points to
+    Inspect {
+        ticket: String,
+    },
+}
+
+fn parse_quality(s: &str) -> Result<VideoQuality, String> {
+    VideoQuality::from_suffix(s).ok_or_else(|| format!("unknown quality '{s}', expected low, med or high"))
+}
+
+#[tokio::main]
+async fn main() -> Result<()> {
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("info")))
+        .with_writer(std::io::stderr)
+        .init();
+
+    let cli = Cli::parse();
+    let stats_interval = Duration::from_secs(cli.stats_interval.max(1));
+    let key = cli.key.as_deref();
+    match cli.command {
+        Command::Ticket(TicketCommand::Inspect { ticket }) => inspect(&ticket),
+        Command::Publish(args) => publish(args, key, stats_interval).await,
+        Command::Subscribe(args) => subscribe(args, key, stats_interval).await,
+        Command::Relay { ticket, max_hops } => run_relay(&ticket, max_hops, key, stats_interval).await,
+        Command::Discover { ticket, prefix } => discover(&ticket, &prefix, key).await,
+    }
+}
+
+fn inspect(ticket: &str) -> Result<()> {
+    let ticket = LiveTicket::deserialize(ticket).context("invalid ticket")?;
+    println!("broadcast:    {}", ticket.broadcast_name);
+    println!("endpoint id:  {}", ticket.endpoint_id);
+    println!("relay url:    {}", ticket.relay_url.as_deref().unwrap_or("-"));
+    if ticket.direct_addrs.is_empty() {
+        println!("direct addrs: -");
+    }
+    for addr in &ticket.direct_addrs {
+        println!("direct addr:  {addr}");
+    }
+    println!("encrypted:    {}", if ticket.media_key.is_some() { "yes, key included" } else { "no key" });
+    match &ticket.token {
+        None => println!("token:        -"),
+        Some(token) => {
+            let capability = &token.capability;
+            println!("token id:     {}", token.id());
+            println!("expires at:   {} (unix)", capability.expires_at);
+            match capability.subject {
+                Some(subject) => println!("subject:      {subject}"),
+                None => println!("subject:      anyone"),
+            }
+            if !capability.broadcasts.is_empty() {
+                println!("grants:       {}", capability.broadcasts.join(", "));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Bind an endpoint and wait briefly for its home relay
+async fn bind(key: Option<&Path>) -> Result<Endpoint> {
+    let secret_key = match key {
+        Some(path) => identity::load_or_create_secret_key(path)?,
+        None => identity::generate_secret_key(),
+    };
+    let endpoint = Endpoint::builder()
+        .secret_key(secret_key)
+        .alpns(vec![ALPN.to_vec(), ROUTE_ALPN.to_vec()])
+        .bind()
+        .await?;
+    info!("endpoint id: {}", endpoint.id());
+    if time::timeout(Duration::from_secs(10), endpoint.online()).await.is_err() {
+        warn!("no relay connection yet, only direct connections may work");
+    }
+    Ok(endpoint)
+}
+
+/// Serve MoQ sessions and relay route queries for `live`
+fn serve(live: &Live) -> Router {
+    Router::builder(live.endpoint().clone())
+        .accept(ALPN, live.protocol_handler())
+        .accept(LEGACY_ALPN, live.protocol_handler())
+        .accept(ROUTE_ALPN, RouteProtocolHandler::new(live.clone()))
+        .spawn()
+}
+
+async fn close(live: Live, router: Option<Router>) {
+    live.shutdown();
+    if let Some(router) = router {
+        if let Err(e) = router.shutdown().await {
+            warn!("router shutdown failed: {e}");
+        }
+    }
+    live.endpoint().close().await;
+}
+
+async fn publish(args: PublishArgs, key: Option<&Path>, stats_interval: Duration) -> Result<()> {
+    let endpoint = bind(key).await?;
+    let live = Live::new(endpoint);
+    let router = serve(&live);
+
+    let file = match &args.file {
+        Some(path) => Some(FileReplay::open(path, ReplayConfig { looping: args.looping })?),
+        None => None,
+    };
+    let media_key = args.encrypt.then(MediaKey::generate);
+    let config = PublishConfig {
+        encryption: media_key.clone(),
+        ..match &file {
+            Some(file) => file.publish_config(&args.name),
+            None => test_pattern::publish_config(&args),
+        }
+    };
+    let (broadcast, handle) = PublishBroadcast::new(config);
+    let producer = broadcast.producer().clone();
+    let backlog = broadcast.send_backlog();
+    tokio::spawn(broadcast.run());
+
+    // Start the source before announcing, so a failure publishes nothing
+    let (replay, pattern) = match file {
+        Some(file) => (Some(file.start(handle.clone())?), None),
+        None => (None, Some(test_pattern::start(&args, handle.clone())?)),
+    };
+    live.publish_tracked(&args.name, producer, backlog).await?;
+    if let Some(inbox) = handle.chat_inbox() {
+        live.open_chat(&args.name, inbox, ChatRate::default()).await?;
+    }
+
+    let ticket = LiveTicket::with_addr(live.endpoint().addr(), &args.name);
+    println!("publishing '{}'", args.name);
+    match media_key {
+        Some(key) => {
+            println!("ticket (viewers only): {}", ticket.clone().with_media_key(key).serialize());
+            println!("ticket (for relays):   {}", ticket.serialize());
+        }
+        None => println!("ticket: {}", ticket.serialize()),
+    }
+
+    let mut roster = live.roster_events();
+    let mut chat = handle.chat_messages();
+    let mut interval = time::interval(stats_interval);
+    interval.tick().await;
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            Ok(event) = roster.recv() => {
+                print_roster_event(&event);
+                continue;
+            }
+            Ok(message) = chat.recv() => {
+                print_chat_message(&message);
+                continue;
+            }
+            _ = interval.tick() => {}
+        }
+        if replay.as_ref().is_some_and(|r| !r.is_running()) {
+            info!("file ended");
+            break;
+        }
+        let viewers = live.roster().await.map(|v| v.len()).unwrap_or_default();
+        print_publish_stats(&handle, replay.as_ref().map(|r| r.position()), viewers);
+    }
+
+    if let Some(replay) = replay {
+        replay.stop();
+    }
+    if let Some(pattern) = pattern {
+        tokio::task::spawn_blocking(move || pattern.stop()).await.ok();
+    }
+    // Finish the tracks before withdrawing, so viewers see the end
+    handle.stop().await.ok();
+    live.unpublish(&args.name).await.ok();
+    close(live, Some(router)).await;
+    Ok(())
+}
+
+fn print_chat_message(message: &ChatMessage) {
+    let name = message.display_name.as_deref().unwrap_or("anonymous");
+    match &message.kind {
+        ChatKind::Text(text) => println!("[{}] {} ({}): {}", message.seq, name, message.sender.fmt_short(), text),
+        ChatKind::Reaction { target, emoji } => println!("[{}] {} reacted {} to [{}]", message.seq, name, emoji, target),
+    }
+}
+
+fn print_roster_event(event: &RosterEvent) {
+    match event {
+        RosterEvent::Joined(viewer) => println!(
+            "viewer joined: {} ({})",
+            viewer.endpoint_id.fmt_short(),
+            viewer.display_name.as_deref().unwrap_or("anonymous"),
+        ),
+        RosterEvent::Left { endpoint_id, kicked } => println!(
+            "viewer left: {}{}",
+            endpoint_id.fmt_short(),
+            if *kicked { " (kicked)" } else { "" },
+        ),
+    }
+}
+
+fn print_publish_stats(handle: &PublishHandle, position: Option<Duration>, viewers: usize) {
+    let mut line = format!("{viewers} viewers ");
+    if let Some(position) = position {
+        line.push_str(&format!("position {:.1}s ", position.as_secs_f64()));
+    }
+    for (quality, stats) in handle.video_stats() {
+        line.push_str(&format!(
+            "| {} {} frames {} KiB {} dropped ",
+            quality.suffix(),
+            stats.frames_written,
+            stats.bytes_written / 1024,
+            stats.frames_dropped,
+        ));
+    }
+    println!("{}", line.trim_end());
+}
+
+async fn subscribe(args: SubscribeArgs, key: Option<&Path>, stats_interval: Duration) -> Result<()> {
+    let mut ticket = LiveTicket::deserialize(&args.ticket).context("invalid ticket")?;
+    if let Some(key_ticket) = &args.key_ticket {
+        let media_key = LiveTicket::deserialize(key_ticket)
+            .context("invalid key ticket")?
+            .media_key
+            .context("key ticket has no media key")?;
+        ticket = ticket.with_media_key(media_key);
+    }
+    let endpoint = bind(key).await?;
+    let live = Live::new(endpoint);
+    live.set_display_name(args.display_name.clone());
+
+    let mut session = live
+        .connect_with_token(ticket.to_endpoint_addr(), ticket.token.as_ref())
+        .await?;
+    let broadcast = session.subscribe(&ticket.broadcast_name).await?;
+    let config = SubscribeConfig {
+        video_quality: Some(args.quality.clone()),
+        encryption: ticket.media_key.clone(),
+        ..Default::default()
+    };
+    let mut handle = SubscribeBroadcast::new(broadcast.clone(), config).start().await?;
+    println!("watching '{}' ({})", ticket.broadcast_name, handle.video_track());
+    let mut chat = match &ticket.media_key {
+        Some(key) => ChatReader::subscribe(&broadcast).with_decryption(key.clone()),
+        None => ChatReader::subscribe(&broadcast),
+    };
+
+    let recording = match &args.out {
+        Some(path) => {
+            let config = RecordConfig {
+                video_quality: Some(args.quality.clone()),
+                encryption: ticket.media_key.clone(),
+                ..Default::default()
+            };
+            let recording = Recorder::new(broadcast, path, config)
+                .follow(handle.switcher().watch_track())
+                .start()
+                .await?;
+            println!("recording to {}", path.display());
+            Some(recording)
+        }
+        None => None,
+    };
+
+    let video_stats = handle.video_stats();
+    let mut audio_frames = 0u64;
+    let mut interval = time::interval(stats_interval);
+    interval.tick().await;
+    let mut last = (0, 0);
+    let ended = handle.ended();
+    tokio::pin!(ended);
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = session.broadcast_ended(&ticket.broadcast_name) => {
+                info!("broadcast ended");
+                break;
+            }
+            reason = &mut ended => {
+                match reason {
+                    EndReason::Ended => println!("broadcast ended"),
+                    EndReason::Error(e) => println!("subscription failed: {e}"),
+                    EndReason::Stopped => {}
+                }
+                break;
+            }
+            // Frames are only counted; keep the channels drained
+            Some(_) = handle.video_rx.recv() => {}
+            Some(_) = handle.audio_rx.recv() => audio_frames += 1,
+            Some(message) = chat.recv() => print_chat_message(&message),
+            _ = interval.tick() => {
+                let (frames, bytes) = (video_stats.frames(), video_stats.bytes());
+                let secs = stats_interval.as_secs_f64();
+                let mut line = format!(
+                    "video {} frames ({:.1} fps, {:.0} kbps) {} late {} skipped groups | audio {} frames",
+                    frames,
+                    (frames - last.0) as f64 / secs,
+                    (bytes - last.1) as f64 * 8.0 / 1000.0 / secs,
+                    video_stats.late_frames(),
+                    video_stats.skipped_groups(),
+                    audio_frames,
+                );
+                if let Some(recording) = &recording {
+                    let stats = recording.stats();
+                    line.push_str(&format!(" | recorded {} KiB in {} files", stats.bytes / 1024, stats.files.len()));
+                }
+                println!("{line}");
+                last = (frames, bytes);
+            }
+        }
+    }
+
+    handle.stop();
+    if let Some(recording) = recording {
+        let stats = recording.stop().await?;
+        println!(
+            "recorded {} video and {} audio frames ({} KiB) to:",
+            stats.video_frames,
+            stats.audio_frames,
+            stats.bytes / 1024,
+        );
+        for file in &stats.files {
+            println!("  {}", file.display());
+        }
+    }
+    drop(session);
+    close(live, None).await;
+    Ok(())
+}
+
+async fn run_relay(ticket: &str, max_hops: usize, key: Option<&Path>, stats_interval: Duration) -> Result<()> {
+    let ticket = LiveTicket::deserialize(ticket).context("invalid ticket")?;
+    let endpoint = bind(key).await?;
+    let live = Live::new(endpoint);
+    let router = serve(&live);
+
+    let config = RelayConfig {
+        max_hops,
+        token: ticket.token.clone(),
+        ..Default::default()
+    };
+    let handle = relay::relay(&live, ticket.to_endpoint_addr(), &ticket.broadcast_name, &config).await?;
+    let route = handle.route().iter().map(|id| id.fmt_short().to_string()).collect::<Vec<_>>();
+    println!("relaying '{}' via {}", ticket.broadcast_name, route.join(" -> "));
+    println!(
+        "ticket: {}",
+        LiveTicket::with_addr(live.endpoint().addr(), &ticket.broadcast_name).serialize()
+    );
+
+    let start = time::Instant::now();
+    let mut interval = time::interval(stats_interval);
+    interval.tick().await;
+    loop {
+        tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            _ = interval.tick() => {}
+        }
+        if !handle.is_active() {
+            info!("upstream ended");
+            break;
+        }
+        println!(
+            "relaying '{}' over {} hops for {}s",
+            handle.name(),
+            handle.route().len(),
+            start.elapsed().as_secs(),
+        );
+    }
+
+    handle.stop();
+    close(live, Some(router)).await;
+    Ok(())
+}
+
+async fn discover(ticket: &str, prefix: &str, key: Option<&Path>) -> Result<()> {
+    let ticket = LiveTicket::deserialize(ticket).context("invalid ticket")?;
+    let endpoint = bind(key).await?;
+    let live = Live::new(endpoint);
+
+    let mut discovery = Discovery::connect(&live, ticket.to_endpoint_addr(), prefix, ticket.token.as_ref()).await?;
+    loop {
+        let event = tokio::select! {
+            _ = tokio::signal::ctrl_c() => break,
+            event = discovery.recv() => event,
+        };
+        match event {
+            Some(DiscoveryEvent::Added { name }) => {
+                println!("+ {name}  {}", ticket.for_broadcast(&name).serialize());
+            }
+            Some(DiscoveryEvent::Removed { name }) => println!("- {name}"),
+            None => {
+                info!("node went away");
+                break;
+            }
+        }
+    }
+
+    discovery.close().await;
+    close(live, None).await;
+    Ok(())
+}
+
+/// Test pattern publishing, encoded with the simulcast pipeline
+#[cfg(feature = "ffmpeg")]
+mod test_pattern {
+    use anyhow::Result;
+    use rust_lib_cyberfly_streaming::api::capture::{TestPattern, TestPatternSource};
+    use rust_lib_cyberfly_streaming::api::publish_broadcast::{PublishConfig, PublishHandle, VideoQuality};
+    use rust_lib_cyberfly_streaming::api::simulcast::{SimulcastConfig, SimulcastHandle, SimulcastPipeline};
+
+    use super::PublishArgs;
+
+    /// A running test pattern
+    pub struct Pattern {
+        simulcast: SimulcastHandle,
+        thread: std::thread::JoinHandle<()>,
+    }
+
+    impl Pattern {
+        /// Stop encoding, once the encoders' last frames are written
+        pub fn stop(self) {
+            let _ = self.simulcast.stop();
+            let _ = self.thread.join();
+        }
+    }
+
+    pub fn publish_config(args: &PublishArgs) -> PublishConfig {
+        PublishConfig {
+            name: args.name.clone(),
+            video_qualities: args.quality.clone(),
+            // No audio source to go with the pattern
+            audio_qualities: Vec::new(),
+            ..Default::default()
+        }
+    }
+
+    pub fn start(args: &PublishArgs, handle: PublishHandle) -> Result<Pattern> {
+        // Generate at the largest rendition; the pipeline scales down
+        let top = args.quality.iter().max_by_key(|q| q.height()).copied().unwrap_or(VideoQuality::Medium);
+        let source = TestPatternSource::new(top.width(), top.height(), args.fps, TestPattern::MovingBox);
+        let config = SimulcastConfig {
+            framerate: args.fps,
+            keyframe_interval: args.fps,
+        };
+        let (pipeline, simulcast) = SimulcastPipeline::new(source, handle, &args.quality, config);
+        let interval_ms = (1000 / args.fps.max(1) as u64 / 2).max(1);
+        let thread = std::thread::Builder::new()
+            .name("test-pattern".to_string())
+            .spawn(move || pipeline.run_blocking(interval_ms))?;
+        Ok(Pattern { simulcast, thread })
+    }
+}
+
+#[cfg(not(feature = "ffmpeg"))]
+mod test_pattern {
+    use anyhow::{Result, bail};
+    use rust_lib_cyberfly_streaming::api::publish_broadcast::{PublishConfig, PublishHandle};
+
+    use super::PublishArgs;
+
+    pub struct Pattern;
+
+    impl Pattern {
+        pub fn stop(self) {}
+    }
+
+    pub fn publish_config(args: &PublishArgs) -> PublishConfig {
+        PublishConfig {
+            name: args.name.clone(),
+            ..Default::default()
+        }
+    }
+
+    pub fn start(_args: &PublishArgs, _handle: PublishHandle) -> Result<Pattern> {
+        bail!("--test-pattern needs H.264 encoding; rebuild with --features ffmpeg")
+    }
+}
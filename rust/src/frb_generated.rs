@@ -37,7 +37,7 @@ flutter_rust_bridge::frb_generated_boilerplate!(
     default_rust_auto_opaque = RustAutoOpaqueMoi,
 );
 pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_VERSION: &str = "2.11.1";
-pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 1470217968;
+pub(crate) const FLUTTER_RUST_BRIDGE_CODEGEN_CONTENT_HASH: i32 = 887595007;
 
 // Section: executor
 
@@ -1702,6 +1702,40 @@ fn wire__crate__api__ffmpeg_flutter_api__ffmpeg_reset_video_encoder_impl(
         },
     )
 }
+fn wire__crate__api__iroh_live_flutter_api__flutter_endpoint_config_default_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_normal::<flutter_rust_bridge::for_generated::SseCodec, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "flutter_endpoint_config_default",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| {
+                transform_result_sse::<_, ()>((move || {
+                    let output_ok = Result::<_, ()>::Ok(
+                        crate::api::iroh_live_flutter_api::FlutterEndpointConfig::default(),
+                    )?;
+                    Ok(output_ok)
+                })())
+            }
+        },
+    )
+}
 fn wire__crate__api__live_flutter_api__get_all_video_qualities_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -2257,6 +2291,46 @@ fn wire__crate__api__direct_flutter_api__init_direct_streaming_impl(
         },
     )
 }
+fn wire__crate__api__direct_flutter_api__init_direct_streaming_with_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "init_direct_streaming_with_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_secret_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::direct_flutter_api::init_direct_streaming_with_key(
+                                api_secret_key,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__flutter_api__init_streaming_node_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
@@ -2556,6 +2630,168 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_catalog_to_json_impl(
         },
     )
 }
+fn wire__crate__api__iroh_live_flutter_api__iroh_discovery_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_discovery_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_discovery_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_discovery_events(
+                                api_discovery_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_discovery_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_discovery_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_discovery_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_discovery_list(
+                            api_discovery_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_discovery_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_discovery_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_discovery_id = <String>::sse_decode(&mut deserializer);
+            let api_ticket_string = <String>::sse_decode(&mut deserializer);
+            let api_prefix = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_discovery_start(
+                            api_discovery_id,
+                            api_ticket_string,
+                            api_prefix,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_discovery_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_discovery_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_discovery_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_discovery_stop(
+                            api_discovery_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
 fn wire__crate__api__iroh_live_flutter_api__iroh_get_audio_presets_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2740,14 +2976,14 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_get_video_presets_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_is_codec_hw_accelerated_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_identity_endpoint_id_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_is_codec_hw_accelerated",
+            debug_name: "iroh_identity_endpoint_id",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -2761,28 +2997,26 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_is_codec_hw_accelerated_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_codec = <String>::sse_decode(&mut deserializer);
+            let api_secret_key = <Vec<u8>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_is_codec_hw_accelerated(api_codec),
-                )?;
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::iroh_live_flutter_api::iroh_identity_endpoint_id(api_secret_key)?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_node_get_endpoint_id_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__iroh_live_flutter_api__iroh_identity_generate_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_node_get_endpoint_id",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "iroh_identity_generate",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2795,30 +3029,25 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_node_get_endpoint_id_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_node_get_endpoint_id().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_identity_generate(),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_node_init_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__iroh_live_flutter_api__iroh_is_codec_hw_accelerated_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_node_init",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "iroh_is_codec_hw_accelerated",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -2830,20 +3059,18 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_node_init_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_codec = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, String>(
-                    (move || async move {
-                        let output_ok = crate::api::iroh_live_flutter_api::iroh_node_init().await?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_is_codec_hw_accelerated(api_codec),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_export_key_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2851,7 +3078,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_node_shutdown",
+            debug_name: "iroh_node_export_key",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2870,7 +3097,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_node_shutdown().await?;
+                            crate::api::iroh_live_flutter_api::iroh_node_export_key().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2879,16 +3106,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_get_endpoint_id_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_create",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_get_endpoint_id",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2900,18 +3128,21 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_create(api_publisher_id),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_node_get_endpoint_id().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_get_ticket_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -2919,7 +3150,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_create_async",
+            debug_name: "iroh_node_get_ticket",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -2933,18 +3164,12 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
-            let api_broadcast_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_publish_create_async(
-                                api_publisher_id,
-                                api_broadcast_name,
-                            )
-                            .await?;
+                            crate::api::iroh_live_flutter_api::iroh_node_get_ticket().await?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -2953,16 +3178,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_status_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_init_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_get_status",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_init",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -2974,27 +3200,30 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_status_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_get_status(api_publisher_id),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_node_init().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_ticket_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_init_from_file_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_get_ticket",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_init_from_file",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3006,27 +3235,39 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_ticket_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::iroh_live_flutter_api::FlutterEndpointConfig>>::sse_decode(
+                    &mut deserializer,
+                );
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_get_ticket(api_publisher_id),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_node_init_from_file(
+                                api_path, api_config,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_audio_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_init_with_config_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_push_audio",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_init_with_config",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3038,33 +3279,37 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_audio_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
-            let api_samples = <crate::api::iroh_live_flutter_api::FlutterAudioSamples>::sse_decode(
+            let api_config = <crate::api::iroh_live_flutter_api::FlutterEndpointConfig>::sse_decode(
                 &mut deserializer,
             );
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_push_audio(
-                        api_publisher_id,
-                        api_samples,
-                    ),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_node_init_with_config(
+                                api_config,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_audio_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_init_with_key_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_push_encoded_audio",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_init_with_key",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3076,34 +3321,39 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_audio_impl
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
-            let api_packet =
-                <crate::api::iroh_live_flutter_api::FlutterEncodedAudioPacket>::sse_decode(
+            let api_secret_key = <Vec<u8>>::sse_decode(&mut deserializer);
+            let api_config =
+                <Option<crate::api::iroh_live_flutter_api::FlutterEndpointConfig>>::sse_decode(
                     &mut deserializer,
                 );
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_push_encoded_audio(
-                        api_publisher_id,
-                        api_packet,
-                    ),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_node_init_with_key(
+                            api_secret_key,
+                            api_config,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_video_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_set_display_name_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_push_encoded_video",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_set_display_name",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3115,34 +3365,33 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_video_impl
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
-            let api_packet =
-                <crate::api::iroh_live_flutter_api::FlutterEncodedVideoPacket>::sse_decode(
-                    &mut deserializer,
-                );
+            let api_name = <Option<String>>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_push_encoded_video(
-                        api_publisher_id,
-                        api_packet,
-                    ),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_node_set_display_name(api_name)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_video_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_push_video",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_node_shutdown",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3154,31 +3403,1679 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_video_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
-            let api_frame = <crate::api::iroh_live_flutter_api::FlutterVideoFrame>::sse_decode(
-                &mut deserializer,
-            );
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_push_video(
-                        api_publisher_id,
-                        api_frame,
-                    ),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_node_shutdown().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_remove_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_chat_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_chat_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_publish_chat_messages(
+                                api_publisher_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_create",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_create(api_publisher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_create_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_broadcast_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_create_async(
+                                api_publisher_id,
+                                api_broadcast_name,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_enable_encryption_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_enable_encryption",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_enable_encryption(
+                                api_publisher_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_file_position_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_file_position",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_publish_file_position(
+                                api_publisher_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_file_seek_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_file_seek",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_position_ms = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_publish_file_seek(
+                            api_publisher_id,
+                            api_position_ms,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_file_start_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_file_start_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            let api_looping = <bool>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_file_start_async(
+                                api_publisher_id,
+                                api_path,
+                                api_looping,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_catalog_json_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_get_catalog_json",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_publish_get_catalog_json(
+                                api_publisher_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_status_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_get_status",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_get_status(api_publisher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_ticket_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_get_ticket",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_get_ticket(api_publisher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_get_video_stats_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_get_video_stats",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_get_video_stats(
+                        api_publisher_id,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_issue_ticket_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_issue_ticket",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_ttl_secs = <u64>::sse_decode(&mut deserializer);
+            let api_subject_endpoint_id = <Option<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_issue_ticket(
+                                api_publisher_id,
+                                api_ttl_secs,
+                                api_subject_endpoint_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_audio_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_push_audio",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_samples = <crate::api::iroh_live_flutter_api::FlutterAudioSamples>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_push_audio(
+                        api_publisher_id,
+                        api_samples,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_audio_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_push_encoded_audio",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_packet =
+                <crate::api::iroh_live_flutter_api::FlutterEncodedAudioPacket>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_push_encoded_audio(
+                        api_publisher_id,
+                        api_packet,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_video_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_push_encoded_video",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_packet =
+                <crate::api::iroh_live_flutter_api::FlutterEncodedVideoPacket>::sse_decode(
+                    &mut deserializer,
+                );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_push_encoded_video(
+                        api_publisher_id,
+                        api_packet,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_push_video_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_push_video",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_frame = <crate::api::iroh_live_flutter_api::FlutterVideoFrame>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_push_video(
+                        api_publisher_id,
+                        api_frame,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_record_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_record_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_record_start(
+                                api_publisher_id,
+                                api_path,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_record_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_record_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_record_stop(
+                                api_publisher_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_remove_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_remove",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_remove(api_publisher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_revoke_token_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_revoke_token",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_token_id = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_revoke_token(
+                                api_token_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_send_chat_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_send_chat",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_reaction_to = <Option<u64>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_publish_send_chat(
+                            api_publisher_id,
+                            api_text,
+                            api_reaction_to,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_set_access_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_set_access",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_policy = <crate::api::iroh_live_flutter_api::FlutterAccessPolicy>::sse_decode(
+                &mut deserializer,
+            );
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_publish_set_access(
+                            api_publisher_id,
+                            api_policy,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_set_video_renditions_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_set_video_renditions",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_renditions = <Vec<String>>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_set_video_renditions(
+                        api_publisher_id,
+                        api_renditions,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_start",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_start(api_publisher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_start_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_publish_start_async(
+                                api_publisher_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_stop",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_stop(api_publisher_id),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_stop_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_publish_stop_async(
+                            api_publisher_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_publish_take_keyframe_request_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_publish_take_keyframe_request",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_publish_take_keyframe_request(
+                        api_publisher_id,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_relay_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_relay_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_relay_list().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_relay_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_relay_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ticket_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_relay_start(api_ticket_string)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_relay_stop_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_relay_stop",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_broadcast_name = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_relay_stop(api_broadcast_name)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_room_events(api_room).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_host_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_host",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room = <String>::sse_decode(&mut deserializer);
+            let api_ttl_secs = <u64>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_room_host(
+                            api_room,
+                            api_ttl_secs,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_join_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_join",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_ticket_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_room_join(api_ticket_string)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_leave_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_leave",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_room_leave(api_room).await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_members_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_members",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_room_members(api_room).await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_publish_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_publish",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room = <String>::sse_decode(&mut deserializer);
+            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_room_publish(
+                            api_room,
+                            api_publisher_id,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_room_set_decode_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_room_set_decode",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_room = <String>::sse_decode(&mut deserializer);
+            let api_pixel_format = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok = crate::api::iroh_live_flutter_api::iroh_room_set_decode(
+                            api_room,
+                            api_pixel_format,
+                        )
+                        .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_roster_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_roster_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_roster_events().await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_roster_kick_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_roster_kick",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_endpoint_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_roster_kick(api_endpoint_id)
+                                .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_roster_list_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_roster_list",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_roster_list().await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_abr_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_abr_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_abr_events(
+                                api_subscriber_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_chat_messages_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_chat_messages",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_chat_messages(
+                                api_subscriber_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_impl(
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_remove",
+            debug_name: "iroh_subscribe_connect",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3192,25 +5089,149 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_remove_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_remove(api_publisher_id),
+                    crate::api::iroh_live_flutter_api::iroh_subscribe_connect(api_subscriber_id),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_set_video_renditions_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_connect_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_ticket_string = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_connect_async(
+                                api_subscriber_id,
+                                api_ticket_string,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connection_events_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_connection_events",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_connection_events(
+                                api_subscriber_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connection_state_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_connection_state",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, ()>(
+                    (move || async move {
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_connection_state(
+                                api_subscriber_id,
+                            )
+                            .await,
+                        )?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_set_video_renditions",
+            debug_name: "iroh_subscribe_create",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3224,29 +5245,70 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_set_video_renditions_im
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
-            let api_renditions = <Vec<String>>::sse_decode(&mut deserializer);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_broadcast_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_set_video_renditions(
-                        api_publisher_id,
-                        api_renditions,
-                    ),
-                )?;
+                let output_ok =
+                    Result::<_, ()>::Ok(crate::api::iroh_live_flutter_api::iroh_subscribe_create(
+                        api_subscriber_id,
+                        api_broadcast_id,
+                    ))?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_create_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_broadcast_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_create_async(
+                                api_subscriber_id,
+                                api_broadcast_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_start",
+            debug_name: "iroh_subscribe_disconnect",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3260,18 +5322,58 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_start(api_publisher_id),
+                    crate::api::iroh_live_flutter_api::iroh_subscribe_disconnect(api_subscriber_id),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+        flutter_rust_bridge::for_generated::TaskInfo {
+            debug_name: "iroh_subscribe_disconnect_async",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+        },
+        move || {
+            let message = unsafe {
+                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
+                    ptr_,
+                    rust_vec_len_,
+                    data_len_,
+                )
+            };
+            let mut deserializer =
+                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            deserializer.end();
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_disconnect_async(
+                                api_subscriber_id,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
+        },
+    )
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_catalog_json_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3279,7 +5381,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_start_async",
+            debug_name: "iroh_subscribe_get_catalog_json",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3293,16 +5395,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_publish_start_async(
-                                api_publisher_id,
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_get_catalog_json(
+                                api_subscriber_id,
                             )
-                            .await?;
+                            .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3311,14 +5414,14 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_status_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_stop",
+            debug_name: "iroh_subscribe_get_status",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3332,18 +5435,33 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_publish_stop(api_publisher_id),
+                    crate::api::iroh_live_flutter_api::iroh_subscribe_get_status(api_subscriber_id),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_decoded_frame_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
+    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
+    rust_vec_len_: i32,
+    data_len_: i32,
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec,_,_,_>(flutter_rust_bridge::for_generated::TaskInfo{ debug_name: "iroh_subscribe_receive_decoded_frame", port: Some(port_), mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal }, move || { 
+            let message = unsafe { flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(ptr_, rust_vec_len_, data_len_) };
+            let mut deserializer = flutter_rust_bridge::for_generated::SseDeserializer::new(message);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);deserializer.end(); move |context| async move {
+                    transform_result_sse::<_, ()>((move || async move {
+                         let output_ok = Result::<_,()>::Ok(crate::api::iroh_live_flutter_api::iroh_subscribe_receive_decoded_frame(api_subscriber_id).await)?;   Ok(output_ok)
+                    })().await)
+                } })
+}
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_frame_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3351,7 +5469,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_publish_stop_async",
+            debug_name: "iroh_subscribe_receive_frame",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3365,15 +5483,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_publisher_id = <String>::sse_decode(&mut deserializer);
+            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
-                transform_result_sse::<_, String>(
+                transform_result_sse::<_, ()>(
                     (move || async move {
-                        let output_ok = crate::api::iroh_live_flutter_api::iroh_publish_stop_async(
-                            api_publisher_id,
-                        )
-                        .await?;
+                        let output_ok = Result::<_, ()>::Ok(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_receive_frame(
+                                api_subscriber_id,
+                            )
+                            .await,
+                        )?;
                         Ok(output_ok)
                     })()
                     .await,
@@ -3382,16 +5502,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_record_start_impl(
+    port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
+) {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_connect",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
+            debug_name: "iroh_subscribe_record_start",
+            port: Some(port_),
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
         move || {
             let message = unsafe {
@@ -3404,17 +5525,26 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_path = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_subscribe_connect(api_subscriber_id),
-                )?;
-                Ok(output_ok)
-            })())
+            move |context| async move {
+                transform_result_sse::<_, String>(
+                    (move || async move {
+                        let output_ok =
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_record_start(
+                                api_subscriber_id,
+                                api_path,
+                            )
+                            .await?;
+                        Ok(output_ok)
+                    })()
+                    .await,
+                )
+            }
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_record_stop_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3422,7 +5552,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_connect_async",
+            debug_name: "iroh_subscribe_record_stop",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3437,15 +5567,13 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
-            let api_ticket_string = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_subscribe_connect_async(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_record_stop(
                                 api_subscriber_id,
-                                api_ticket_string,
                             )
                             .await?;
                         Ok(output_ok)
@@ -3456,14 +5584,14 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_remove_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_create",
+            debug_name: "iroh_subscribe_remove",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3478,20 +5606,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
-            let api_broadcast_id = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
-                let output_ok =
-                    Result::<_, ()>::Ok(crate::api::iroh_live_flutter_api::iroh_subscribe_create(
-                        api_subscriber_id,
-                        api_broadcast_id,
-                    ))?;
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_subscribe_remove(api_subscriber_id),
+                )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_send_chat_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3499,7 +5624,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_create_async",
+            debug_name: "iroh_subscribe_send_chat",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3514,15 +5639,17 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
-            let api_broadcast_id = <String>::sse_decode(&mut deserializer);
+            let api_text = <String>::sse_decode(&mut deserializer);
+            let api_reaction_to = <Option<u64>>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_subscribe_create_async(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_send_chat(
                                 api_subscriber_id,
-                                api_broadcast_id,
+                                api_text,
+                                api_reaction_to,
                             )
                             .await?;
                         Ok(output_ok)
@@ -3533,39 +5660,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_impl(
-    ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
-    rust_vec_len_: i32,
-    data_len_: i32,
-) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
-        flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_disconnect",
-            port: None,
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
-        },
-        move || {
-            let message = unsafe {
-                flutter_rust_bridge::for_generated::Dart2RustMessageSse::from_wire(
-                    ptr_,
-                    rust_vec_len_,
-                    data_len_,
-                )
-            };
-            let mut deserializer =
-                flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
-            deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_subscribe_disconnect(api_subscriber_id),
-                )?;
-                Ok(output_ok)
-            })())
-        },
-    )
-}
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_decode_impl(
     port_: flutter_rust_bridge::for_generated::MessagePort,
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
@@ -3573,7 +5668,7 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl
 ) {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_disconnect_async",
+            debug_name: "iroh_subscribe_set_decode",
             port: Some(port_),
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
         },
@@ -3588,13 +5683,15 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_pixel_format = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             move |context| async move {
                 transform_result_sse::<_, String>(
                     (move || async move {
                         let output_ok =
-                            crate::api::iroh_live_flutter_api::iroh_subscribe_disconnect_async(
+                            crate::api::iroh_live_flutter_api::iroh_subscribe_set_decode(
                                 api_subscriber_id,
+                                api_pixel_format,
                             )
                             .await?;
                         Ok(output_ok)
@@ -3605,14 +5702,14 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_status_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_quality_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_get_status",
+            debug_name: "iroh_subscribe_set_quality",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3627,27 +5724,30 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_status_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_quality = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_subscribe_get_status(api_subscriber_id),
+                    crate::api::iroh_live_flutter_api::iroh_subscribe_set_quality(
+                        api_subscriber_id,
+                        api_quality,
+                    ),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_frame_impl(
-    port_: flutter_rust_bridge::for_generated::MessagePort,
+fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_simulate_video_receive_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
-) {
-    FLUTTER_RUST_BRIDGE_HANDLER.wrap_async::<flutter_rust_bridge::for_generated::SseCodec, _, _, _>(
+) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
+    FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_receive_frame",
-            port: Some(port_),
-            mode: flutter_rust_bridge::for_generated::FfiCallMode::Normal,
+            debug_name: "iroh_subscribe_simulate_video_receive",
+            port: None,
+            mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
         move || {
             let message = unsafe {
@@ -3660,32 +5760,28 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_frame_impl(
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
             let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_frame_size = <u64>::sse_decode(&mut deserializer);
             deserializer.end();
-            move |context| async move {
-                transform_result_sse::<_, ()>(
-                    (move || async move {
-                        let output_ok = Result::<_, ()>::Ok(
-                            crate::api::iroh_live_flutter_api::iroh_subscribe_receive_frame(
-                                api_subscriber_id,
-                            )
-                            .await,
-                        )?;
-                        Ok(output_ok)
-                    })()
-                    .await,
-                )
-            }
+            transform_result_sse::<_, ()>((move || {
+                let output_ok = Result::<_, ()>::Ok(
+                    crate::api::iroh_live_flutter_api::iroh_subscribe_simulate_video_receive(
+                        api_subscriber_id,
+                        api_frame_size,
+                    ),
+                )?;
+                Ok(output_ok)
+            })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_remove_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_ticket_for_broadcast_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_remove",
+            debug_name: "iroh_ticket_for_broadcast",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3699,25 +5795,27 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_remove_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
+            let api_ticket = <String>::sse_decode(&mut deserializer);
+            let api_broadcast_name = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_subscribe_remove(api_subscriber_id),
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::iroh_live_flutter_api::iroh_ticket_for_broadcast(
+                    api_ticket,
+                    api_broadcast_name,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_quality_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_ticket_parse_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_set_quality",
+            debug_name: "iroh_ticket_parse",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3731,29 +5829,25 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_quality_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
-            let api_quality = <String>::sse_decode(&mut deserializer);
+            let api_ticket_string = <String>::sse_decode(&mut deserializer);
             deserializer.end();
             transform_result_sse::<_, ()>((move || {
                 let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_subscribe_set_quality(
-                        api_subscriber_id,
-                        api_quality,
-                    ),
+                    crate::api::iroh_live_flutter_api::iroh_ticket_parse(api_ticket_string),
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_simulate_video_receive_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_ticket_with_media_key_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_subscribe_simulate_video_receive",
+            debug_name: "iroh_ticket_with_media_key",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3767,29 +5861,27 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_subscribe_simulate_video_receiv
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_subscriber_id = <String>::sse_decode(&mut deserializer);
-            let api_frame_size = <u64>::sse_decode(&mut deserializer);
+            let api_ticket = <String>::sse_decode(&mut deserializer);
+            let api_key_ticket = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_subscribe_simulate_video_receive(
-                        api_subscriber_id,
-                        api_frame_size,
-                    ),
+            transform_result_sse::<_, String>((move || {
+                let output_ok = crate::api::iroh_live_flutter_api::iroh_ticket_with_media_key(
+                    api_ticket,
+                    api_key_ticket,
                 )?;
                 Ok(output_ok)
             })())
         },
     )
 }
-fn wire__crate__api__iroh_live_flutter_api__iroh_ticket_parse_impl(
+fn wire__crate__api__iroh_live_flutter_api__iroh_ticket_without_media_key_impl(
     ptr_: flutter_rust_bridge::for_generated::PlatformGeneralizedUint8ListPtr,
     rust_vec_len_: i32,
     data_len_: i32,
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     FLUTTER_RUST_BRIDGE_HANDLER.wrap_sync::<flutter_rust_bridge::for_generated::SseCodec, _>(
         flutter_rust_bridge::for_generated::TaskInfo {
-            debug_name: "iroh_ticket_parse",
+            debug_name: "iroh_ticket_without_media_key",
             port: None,
             mode: flutter_rust_bridge::for_generated::FfiCallMode::Sync,
         },
@@ -3803,12 +5895,11 @@ fn wire__crate__api__iroh_live_flutter_api__iroh_ticket_parse_impl(
             };
             let mut deserializer =
                 flutter_rust_bridge::for_generated::SseDeserializer::new(message);
-            let api_ticket_string = <String>::sse_decode(&mut deserializer);
+            let api_ticket = <String>::sse_decode(&mut deserializer);
             deserializer.end();
-            transform_result_sse::<_, ()>((move || {
-                let output_ok = Result::<_, ()>::Ok(
-                    crate::api::iroh_live_flutter_api::iroh_ticket_parse(api_ticket_string),
-                )?;
+            transform_result_sse::<_, String>((move || {
+                let output_ok =
+                    crate::api::iroh_live_flutter_api::iroh_ticket_without_media_key(api_ticket)?;
                 Ok(output_ok)
             })())
         },
@@ -5790,6 +7881,42 @@ impl SseDecode for f64 {
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterAbrEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_fromTrack = <String>::sse_decode(deserializer);
+        let mut var_toTrack = <String>::sse_decode(deserializer);
+        let mut var_reason = <String>::sse_decode(deserializer);
+        let mut var_throughputBps = <u64>::sse_decode(deserializer);
+        let mut var_rttMs = <u32>::sse_decode(deserializer);
+        let mut var_lossPercent = <f32>::sse_decode(deserializer);
+        let mut var_lateFrames = <u64>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterAbrEvent {
+            from_track: var_fromTrack,
+            to_track: var_toTrack,
+            reason: var_reason,
+            throughput_bps: var_throughputBps,
+            rtt_ms: var_rttMs,
+            loss_percent: var_lossPercent,
+            late_frames: var_lateFrames,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterAccessPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_requireToken = <bool>::sse_decode(deserializer);
+        let mut var_allow = <Vec<String>>::sse_decode(deserializer);
+        let mut var_deny = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterAccessPolicy {
+            require_token: var_requireToken,
+            allow: var_allow,
+            deny: var_deny,
+        };
+    }
+}
+
 impl SseDecode for crate::api::ffmpeg_flutter_api::FlutterAudioCodec {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -5892,6 +8019,44 @@ impl SseDecode for crate::api::live_flutter_api::FlutterCatalog {
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterChatMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_seq = <u64>::sse_decode(deserializer);
+        let mut var_sender = <String>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_timestampMs = <u64>::sse_decode(deserializer);
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_text = <String>::sse_decode(deserializer);
+        let mut var_reactionTo = <Option<u64>>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterChatMessage {
+            seq: var_seq,
+            sender: var_sender,
+            display_name: var_displayName,
+            timestamp_ms: var_timestampMs,
+            kind: var_kind,
+            text: var_text,
+            reaction_to: var_reactionTo,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterConnectionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_state = <String>::sse_decode(deserializer);
+        let mut var_attempt = <u32>::sse_decode(deserializer);
+        let mut var_retryInMs = <u64>::sse_decode(deserializer);
+        let mut var_error = <Option<String>>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterConnectionState {
+            state: var_state,
+            attempt: var_attempt,
+            retry_in_ms: var_retryInMs,
+            error: var_error,
+        };
+    }
+}
+
 impl SseDecode for crate::api::live_flutter_api::FlutterConnectionStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6049,6 +8214,18 @@ impl SseDecode for crate::api::direct_flutter_api::FlutterDirectEvent {
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_broadcastName = <String>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent {
+            kind: var_kind,
+            broadcast_name: var_broadcastName,
+        };
+    }
+}
+
 impl SseDecode for crate::api::ffmpeg_flutter_api::FlutterEncodedAudioFrame {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6149,6 +8326,21 @@ impl SseDecode for crate::api::ffmpeg_flutter_api::FlutterEncoderPreset {
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterEndpointConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_relayMode =
+            <crate::api::iroh_live_flutter_api::FlutterRelayMode>::sse_decode(deserializer);
+        let mut var_bindPort = <Option<u16>>::sse_decode(deserializer);
+        let mut var_localDiscovery = <bool>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterEndpointConfig {
+            relay_mode: var_relayMode,
+            bind_port: var_bindPort,
+            local_discovery: var_localDiscovery,
+        };
+    }
+}
+
 impl SseDecode for crate::api::moq_flutter_api::FlutterFilterType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6219,6 +8411,20 @@ impl SseDecode for crate::api::ffmpeg_flutter_api::FlutterHardwareAccel {
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterIssuedTicket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_ticket = <String>::sse_decode(deserializer);
+        let mut var_tokenId = <u64>::sse_decode(deserializer);
+        let mut var_expiresAt = <u64>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterIssuedTicket {
+            ticket: var_ticket,
+            token_id: var_tokenId,
+            expires_at: var_expiresAt,
+        };
+    }
+}
+
 impl SseDecode for crate::api::live_flutter_api::FlutterLiveEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6413,6 +8619,109 @@ impl SseDecode for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterRecordingStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_files = <Vec<String>>::sse_decode(deserializer);
+        let mut var_fragments = <u64>::sse_decode(deserializer);
+        let mut var_videoFrames = <u64>::sse_decode(deserializer);
+        let mut var_audioFrames = <u64>::sse_decode(deserializer);
+        let mut var_bytes = <u64>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterRecordingStats {
+            files: var_files,
+            fragments: var_fragments,
+            video_frames: var_videoFrames,
+            audio_frames: var_audioFrames,
+            bytes: var_bytes,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterRelayInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_broadcastName = <String>::sse_decode(deserializer);
+        let mut var_route = <Vec<String>>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterRelayInfo {
+            broadcast_name: var_broadcastName,
+            route: var_route,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterRelayMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut tag_ = <i32>::sse_decode(deserializer);
+        match tag_ {
+            0 => {
+                return crate::api::iroh_live_flutter_api::FlutterRelayMode::Default;
+            }
+            1 => {
+                let mut var_url = <String>::sse_decode(deserializer);
+                return crate::api::iroh_live_flutter_api::FlutterRelayMode::Custom {
+                    url: var_url,
+                };
+            }
+            2 => {
+                return crate::api::iroh_live_flutter_api::FlutterRelayMode::Disabled;
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterRenditionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_trackName = <String>::sse_decode(deserializer);
+        let mut var_framesWritten = <u64>::sse_decode(deserializer);
+        let mut var_bytesWritten = <u64>::sse_decode(deserializer);
+        let mut var_framesDropped = <u64>::sse_decode(deserializer);
+        let mut var_groupsCut = <u64>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterRenditionStats {
+            track_name: var_trackName,
+            frames_written: var_framesWritten,
+            bytes_written: var_bytesWritten,
+            frames_dropped: var_framesDropped,
+            groups_cut: var_groupsCut,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterRoomEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_member = <String>::sse_decode(deserializer);
+        let mut var_subscriberId = <String>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterRoomEvent {
+            kind: var_kind,
+            member: var_member,
+            subscriber_id: var_subscriberId,
+        };
+    }
+}
+
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterRosterEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_kind = <String>::sse_decode(deserializer);
+        let mut var_endpointId = <String>::sse_decode(deserializer);
+        let mut var_viewer =
+            <Option<crate::api::iroh_live_flutter_api::FlutterViewer>>::sse_decode(deserializer);
+        let mut var_kicked = <bool>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterRosterEvent {
+            kind: var_kind,
+            endpoint_id: var_endpointId,
+            viewer: var_viewer,
+            kicked: var_kicked,
+        };
+    }
+}
+
 impl SseDecode for crate::api::flutter_api::FlutterStreamEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6673,6 +8982,28 @@ impl SseDecode for crate::api::live_flutter_api::FlutterVideoTrack {
     }
 }
 
+impl SseDecode for crate::api::iroh_live_flutter_api::FlutterViewer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut var_endpointId = <String>::sse_decode(deserializer);
+        let mut var_displayName = <Option<String>>::sse_decode(deserializer);
+        let mut var_connectedAtMs = <u64>::sse_decode(deserializer);
+        let mut var_tracks = <Vec<String>>::sse_decode(deserializer);
+        let mut var_bytesSent = <u64>::sse_decode(deserializer);
+        let mut var_bytesReceived = <u64>::sse_decode(deserializer);
+        let mut var_rttMs = <u32>::sse_decode(deserializer);
+        return crate::api::iroh_live_flutter_api::FlutterViewer {
+            endpoint_id: var_endpointId,
+            display_name: var_displayName,
+            connected_at_ms: var_connectedAtMs,
+            tracks: var_tracks,
+            bytes_sent: var_bytesSent,
+            bytes_received: var_bytesReceived,
+            rtt_ms: var_rttMs,
+        };
+    }
+}
+
 impl SseDecode for i16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6706,6 +9037,20 @@ impl SseDecode for Vec<String> {
     }
 }
 
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterAbrEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterAbrEvent>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterAudioRendition> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6736,6 +9081,36 @@ impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterCaptureDevice>
     }
 }
 
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterChatMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterChatMessage>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterConnectionState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterConnectionState>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::direct_flutter_api::FlutterDirectEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6750,6 +9125,22 @@ impl SseDecode for Vec<crate::api::direct_flutter_api::FlutterDirectEvent> {
     }
 }
 
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::ffmpeg_flutter_api::FlutterEncodedVideoFrame> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6808,6 +9199,64 @@ impl SseDecode for Vec<crate::api::moq_flutter_api::FlutterNamespaceAnnouncement
     }
 }
 
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterRelayInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterRelayInfo>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterRenditionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterRenditionStats>::sse_decode(
+                    deserializer,
+                ),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterRoomEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterRoomEvent>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterRosterEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(
+                <crate::api::iroh_live_flutter_api::FlutterRosterEvent>::sse_decode(deserializer),
+            );
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<crate::api::flutter_api::FlutterStreamEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6878,6 +9327,18 @@ impl SseDecode for Vec<crate::api::live_flutter_api::FlutterVideoTrack> {
     }
 }
 
+impl SseDecode for Vec<crate::api::iroh_live_flutter_api::FlutterViewer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        let mut len_ = <i32>::sse_decode(deserializer);
+        let mut ans_ = vec![];
+        for idx_ in 0..len_ {
+            ans_.push(<crate::api::iroh_live_flutter_api::FlutterViewer>::sse_decode(deserializer));
+        }
+        return ans_;
+    }
+}
+
 impl SseDecode for Vec<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -6948,6 +9409,36 @@ impl SseDecode for Option<f64> {
     }
 }
 
+impl SseDecode for Option<crate::api::iroh_live_flutter_api::FlutterConnectionState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::iroh_live_flutter_api::FlutterConnectionState>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::iroh_live_flutter_api::FlutterEndpointConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::iroh_live_flutter_api::FlutterEndpointConfig>::sse_decode(
+                    deserializer,
+                ),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<crate::api::moq_flutter_api::FlutterMoqObject> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7034,6 +9525,43 @@ impl SseDecode for Option<crate::api::iroh_live_flutter_api::FlutterTicketInfo>
     }
 }
 
+impl SseDecode for Option<crate::api::iroh_live_flutter_api::FlutterVideoFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::iroh_live_flutter_api::FlutterVideoFrame>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<crate::api::iroh_live_flutter_api::FlutterViewer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(
+                <crate::api::iroh_live_flutter_api::FlutterViewer>::sse_decode(deserializer),
+            );
+        } else {
+            return None;
+        }
+    }
+}
+
+impl SseDecode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
+        if (<bool>::sse_decode(deserializer)) {
+            return Some(<u16>::sse_decode(deserializer));
+        } else {
+            return None;
+        }
+    }
+}
+
 impl SseDecode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_decode(deserializer: &mut flutter_rust_bridge::for_generated::SseDeserializer) -> Self {
@@ -7310,509 +9838,791 @@ fn pde_ffi_dispatcher_primary_impl(
             rust_vec_len,
             data_len,
         ),
-        25 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_destroy_quality_ladder_impl(
+        25 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_destroy_quality_ladder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        26 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_destroy_video_decoder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        27 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_destroy_video_encoder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        28 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_detect_hardware_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        29 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_encode_all_qualities_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        30 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_encode_audio_frame_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        31 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_encode_video_frame_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        32 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_flush_video_encoder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        33 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_audio_bitrate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        34 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_audio_mime_type_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        35 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_quality_dimensions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        36 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_recommended_bitrate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        37 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_version_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        38 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_video_mime_type_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        39 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_is_available_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        40 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_list_audio_codecs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        41 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_list_hardware_accels_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        42 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_list_video_codecs_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        43 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_reset_video_encoder_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        44 => wire__crate__api__iroh_live_flutter_api__flutter_endpoint_config_default_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        45 => wire__crate__api__live_flutter_api__get_all_video_qualities_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        46 => wire__crate__api__direct_flutter_api__get_direct_endpoint_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        47 => wire__crate__api__direct_flutter_api__get_direct_peer_count_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        48 => wire__crate__api__live_flutter_api__get_direct_ticket_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        49 => {
+            wire__crate__api__flutter_api__get_endpoint_id_impl(port, ptr, rust_vec_len, data_len)
+        }
+        50 => wire__crate__api__live_flutter_api__get_live_connection_stats_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        51 => wire__crate__api__live_flutter_api__get_live_ticket_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        52 => wire__crate__api__live_flutter_api__get_quality_bitrate_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        54 => wire__crate__api__live_flutter_api__get_quality_dimensions_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        55 => wire__crate__api__live_flutter_api__get_recommended_quality_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        57 => wire__crate__api__live_flutter_api__has_active_broadcast_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        58 => wire__crate__api__live_flutter_api__has_active_subscription_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        59 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
+        60 => wire__crate__api__direct_flutter_api__init_direct_streaming_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        61 => wire__crate__api__direct_flutter_api__init_direct_streaming_with_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        62 => wire__crate__api__flutter_api__init_streaming_node_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        71 => wire__crate__api__iroh_live_flutter_api__iroh_discovery_events_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        72 => wire__crate__api__iroh_live_flutter_api__iroh_discovery_list_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        73 => wire__crate__api__iroh_live_flutter_api__iroh_discovery_start_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        74 => wire__crate__api__iroh_live_flutter_api__iroh_discovery_stop_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        84 => wire__crate__api__iroh_live_flutter_api__iroh_node_export_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        85 => wire__crate__api__iroh_live_flutter_api__iroh_node_get_endpoint_id_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        86 => wire__crate__api__iroh_live_flutter_api__iroh_node_get_ticket_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        87 => wire__crate__api__iroh_live_flutter_api__iroh_node_init_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        88 => wire__crate__api__iroh_live_flutter_api__iroh_node_init_from_file_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        89 => wire__crate__api__iroh_live_flutter_api__iroh_node_init_with_config_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        90 => wire__crate__api__iroh_live_flutter_api__iroh_node_init_with_key_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        91 => wire__crate__api__iroh_live_flutter_api__iroh_node_set_display_name_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        92 => wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
+            port,
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        93 => wire__crate__api__iroh_live_flutter_api__iroh_publish_chat_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        26 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_destroy_video_decoder_impl(
+        95 => wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        27 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_destroy_video_encoder_impl(
+        96 => wire__crate__api__iroh_live_flutter_api__iroh_publish_enable_encryption_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        28 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_detect_hardware_impl(
+        97 => wire__crate__api__iroh_live_flutter_api__iroh_publish_file_position_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        29 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_encode_all_qualities_impl(
+        98 => wire__crate__api__iroh_live_flutter_api__iroh_publish_file_seek_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        30 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_encode_audio_frame_impl(
+        99 => wire__crate__api__iroh_live_flutter_api__iroh_publish_file_start_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        31 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_encode_video_frame_impl(
+        100 => wire__crate__api__iroh_live_flutter_api__iroh_publish_get_catalog_json_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        32 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_flush_video_encoder_impl(
+        104 => wire__crate__api__iroh_live_flutter_api__iroh_publish_issue_ticket_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        33 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_audio_bitrate_impl(
+        109 => wire__crate__api__iroh_live_flutter_api__iroh_publish_record_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        34 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_audio_mime_type_impl(
+        110 => wire__crate__api__iroh_live_flutter_api__iroh_publish_record_stop_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        35 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_quality_dimensions_impl(
+        112 => wire__crate__api__iroh_live_flutter_api__iroh_publish_revoke_token_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        36 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_recommended_bitrate_impl(
+        113 => wire__crate__api__iroh_live_flutter_api__iroh_publish_send_chat_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        37 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_version_impl(
+        114 => wire__crate__api__iroh_live_flutter_api__iroh_publish_set_access_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        38 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_get_video_mime_type_impl(
+        117 => wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        39 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_is_available_impl(
+        119 => wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        40 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_list_audio_codecs_impl(
+        121 => wire__crate__api__iroh_live_flutter_api__iroh_relay_list_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        41 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_list_hardware_accels_impl(
+        122 => wire__crate__api__iroh_live_flutter_api__iroh_relay_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        42 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_list_video_codecs_impl(
+        123 => wire__crate__api__iroh_live_flutter_api__iroh_relay_stop_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        43 => wire__crate__api__ffmpeg_flutter_api__ffmpeg_reset_video_encoder_impl(
+        124 => wire__crate__api__iroh_live_flutter_api__iroh_room_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        44 => wire__crate__api__live_flutter_api__get_all_video_qualities_impl(
+        125 => wire__crate__api__iroh_live_flutter_api__iroh_room_host_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        45 => wire__crate__api__direct_flutter_api__get_direct_endpoint_id_impl(
+        126 => wire__crate__api__iroh_live_flutter_api__iroh_room_join_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        46 => wire__crate__api__direct_flutter_api__get_direct_peer_count_impl(
+        127 => wire__crate__api__iroh_live_flutter_api__iroh_room_leave_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        47 => wire__crate__api__live_flutter_api__get_direct_ticket_impl(
+        128 => wire__crate__api__iroh_live_flutter_api__iroh_room_members_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        48 => {
-            wire__crate__api__flutter_api__get_endpoint_id_impl(port, ptr, rust_vec_len, data_len)
-        }
-        49 => wire__crate__api__live_flutter_api__get_live_connection_stats_impl(
+        129 => wire__crate__api__iroh_live_flutter_api__iroh_room_publish_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        50 => wire__crate__api__live_flutter_api__get_live_ticket_impl(
+        130 => wire__crate__api__iroh_live_flutter_api__iroh_room_set_decode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        51 => wire__crate__api__live_flutter_api__get_quality_bitrate_impl(
+        131 => wire__crate__api__iroh_live_flutter_api__iroh_roster_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        53 => wire__crate__api__live_flutter_api__get_quality_dimensions_impl(
+        132 => wire__crate__api__iroh_live_flutter_api__iroh_roster_kick_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        54 => wire__crate__api__live_flutter_api__get_recommended_quality_impl(
+        133 => wire__crate__api__iroh_live_flutter_api__iroh_roster_list_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        56 => wire__crate__api__live_flutter_api__has_active_broadcast_impl(
+        134 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_abr_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        57 => wire__crate__api__live_flutter_api__has_active_subscription_impl(
+        135 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_chat_messages_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        58 => wire__crate__api__simple__init_app_impl(port, ptr, rust_vec_len, data_len),
-        59 => wire__crate__api__direct_flutter_api__init_direct_streaming_impl(
+        137 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        60 => wire__crate__api__flutter_api__init_streaming_node_impl(
+        138 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connection_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        76 => wire__crate__api__iroh_live_flutter_api__iroh_node_get_endpoint_id_impl(
+        139 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connection_state_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        77 => wire__crate__api__iroh_live_flutter_api__iroh_node_init_impl(
+        141 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        78 => wire__crate__api__iroh_live_flutter_api__iroh_node_shutdown_impl(
+        143 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        80 => wire__crate__api__iroh_live_flutter_api__iroh_publish_create_async_impl(
+        144 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_catalog_json_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        90 => wire__crate__api__iroh_live_flutter_api__iroh_publish_start_async_impl(
+        146 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_decoded_frame_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        92 => wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_async_impl(
+        147 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_frame_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        94 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_async_impl(
+        148 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_record_start_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        96 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_async_impl(
+        149 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_record_stop_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        98 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_async_impl(
+        151 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_send_chat_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        100 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_receive_frame_impl(
+        152 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_decode_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        108 => wire__crate__api__direct_flutter_api__join_direct_stream_impl(
+        162 => wire__crate__api__direct_flutter_api__join_direct_stream_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        109 => wire__crate__api__live_flutter_api__join_live_broadcast_impl(
+        163 => wire__crate__api__live_flutter_api__join_live_broadcast_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        110 => wire__crate__api__flutter_api__join_stream_impl(port, ptr, rust_vec_len, data_len),
-        111 => wire__crate__api__direct_flutter_api__leave_direct_stream_impl(
+        164 => wire__crate__api__flutter_api__join_stream_impl(port, ptr, rust_vec_len, data_len),
+        165 => wire__crate__api__direct_flutter_api__leave_direct_stream_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        112 => wire__crate__api__live_flutter_api__leave_live_broadcast_impl(
+        166 => wire__crate__api__live_flutter_api__leave_live_broadcast_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        113 => wire__crate__api__flutter_api__leave_stream_impl(port, ptr, rust_vec_len, data_len),
-        114 => wire__crate__api__live_flutter_api__live_broadcast_chunk_impl(
+        167 => wire__crate__api__flutter_api__leave_stream_impl(port, ptr, rust_vec_len, data_len),
+        168 => wire__crate__api__live_flutter_api__live_broadcast_chunk_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        115 => wire__crate__api__live_flutter_api__live_broadcast_metadata_impl(
+        169 => wire__crate__api__live_flutter_api__live_broadcast_metadata_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        116 => wire__crate__api__live_flutter_api__live_broadcast_peer_count_impl(
+        170 => wire__crate__api__live_flutter_api__live_broadcast_peer_count_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        117 => wire__crate__api__live_flutter_api__live_request_chunk_impl(
+        171 => wire__crate__api__live_flutter_api__live_request_chunk_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        118 => wire__crate__api__moq_flutter_api__moq_add_object_impl(
+        172 => wire__crate__api__moq_flutter_api__moq_add_object_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        119 => wire__crate__api__moq_flutter_api__moq_announce_namespace_impl(
+        173 => wire__crate__api__moq_flutter_api__moq_announce_namespace_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        120 => wire__crate__api__moq_flutter_api__moq_create_end_of_group_impl(
+        174 => wire__crate__api__moq_flutter_api__moq_create_end_of_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        121 => wire__crate__api__moq_flutter_api__moq_create_end_of_track_impl(
+        175 => wire__crate__api__moq_flutter_api__moq_create_end_of_track_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        122 => wire__crate__api__moq_flutter_api__moq_create_track_impl(
+        176 => wire__crate__api__moq_flutter_api__moq_create_track_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        123 => wire__crate__api__moq_flutter_api__moq_create_track_path_impl(
+        177 => wire__crate__api__moq_flutter_api__moq_create_track_path_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        124 => wire__crate__api__moq_flutter_api__moq_dequeue_object_impl(
+        178 => wire__crate__api__moq_flutter_api__moq_dequeue_object_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        125 => wire__crate__api__moq_flutter_api__moq_drop_low_priority_impl(
+        179 => wire__crate__api__moq_flutter_api__moq_drop_low_priority_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        126 => wire__crate__api__moq_flutter_api__moq_enqueue_object_impl(
+        180 => wire__crate__api__moq_flutter_api__moq_enqueue_object_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        127 => wire__crate__api__moq_flutter_api__moq_estimate_delivery_time_impl(
+        181 => wire__crate__api__moq_flutter_api__moq_estimate_delivery_time_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        128 => wire__crate__api__moq_flutter_api__moq_fetch_impl(port, ptr, rust_vec_len, data_len),
-        129 => wire__crate__api__moq_flutter_api__moq_get_filter_types_impl(
+        182 => wire__crate__api__moq_flutter_api__moq_fetch_impl(port, ptr, rust_vec_len, data_len),
+        183 => wire__crate__api__moq_flutter_api__moq_get_filter_types_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        130 => wire__crate__api__moq_flutter_api__moq_get_group_orders_impl(
+        184 => wire__crate__api__moq_flutter_api__moq_get_group_orders_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        131 => wire__crate__api__moq_flutter_api__moq_get_namespace_impl(
+        185 => wire__crate__api__moq_flutter_api__moq_get_namespace_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        132 => wire__crate__api__moq_flutter_api__moq_get_priority_info_impl(
+        186 => wire__crate__api__moq_flutter_api__moq_get_priority_info_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        133 => wire__crate__api__moq_flutter_api__moq_get_queue_length_impl(
+        187 => wire__crate__api__moq_flutter_api__moq_get_queue_length_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        134 => {
+        188 => {
             wire__crate__api__moq_flutter_api__moq_get_stats_impl(port, ptr, rust_vec_len, data_len)
         }
-        135 => wire__crate__api__moq_flutter_api__moq_get_track_status_impl(
+        189 => wire__crate__api__moq_flutter_api__moq_get_track_status_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        136 => wire__crate__api__moq_flutter_api__moq_list_namespaces_impl(
+        190 => wire__crate__api__moq_flutter_api__moq_list_namespaces_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        137 => wire__crate__api__moq_flutter_api__moq_namespace_is_prefix_impl(
+        191 => wire__crate__api__moq_flutter_api__moq_namespace_is_prefix_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        138 => wire__crate__api__moq_flutter_api__moq_parse_track_path_impl(
+        192 => wire__crate__api__moq_flutter_api__moq_parse_track_path_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        139 => wire__crate__api__moq_flutter_api__moq_start_group_impl(
+        193 => wire__crate__api__moq_flutter_api__moq_start_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        140 => {
+        194 => {
             wire__crate__api__moq_flutter_api__moq_subscribe_impl(port, ptr, rust_vec_len, data_len)
         }
-        141 => wire__crate__api__moq_flutter_api__moq_subscribe_latest_group_impl(
+        195 => wire__crate__api__moq_flutter_api__moq_subscribe_latest_group_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        142 => wire__crate__api__moq_flutter_api__moq_subscribe_live_impl(
+        196 => wire__crate__api__moq_flutter_api__moq_subscribe_live_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        143 => wire__crate__api__moq_flutter_api__moq_subscribe_namespace_impl(
+        197 => wire__crate__api__moq_flutter_api__moq_subscribe_namespace_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        144 => wire__crate__api__moq_flutter_api__moq_subscribe_range_impl(
+        198 => wire__crate__api__moq_flutter_api__moq_subscribe_range_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        145 => wire__crate__api__live_flutter_api__parse_live_ticket_impl(
+        199 => wire__crate__api__live_flutter_api__parse_live_ticket_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        146 => wire__crate__api__direct_flutter_api__poll_direct_events_impl(
+        200 => wire__crate__api__direct_flutter_api__poll_direct_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        147 => wire__crate__api__flutter_api__poll_events_impl(port, ptr, rust_vec_len, data_len),
-        148 => wire__crate__api__live_flutter_api__poll_live_events_impl(
+        201 => wire__crate__api__flutter_api__poll_events_impl(port, ptr, rust_vec_len, data_len),
+        202 => wire__crate__api__live_flutter_api__poll_live_events_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        149 => wire__crate__api__live_flutter_api__request_live_catalog_impl(
+        203 => wire__crate__api__live_flutter_api__request_live_catalog_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        150 => wire__crate__api__flutter_api__send_presence_impl(port, ptr, rust_vec_len, data_len),
-        151 => wire__crate__api__flutter_api__send_signal_impl(port, ptr, rust_vec_len, data_len),
-        152 => wire__crate__api__live_flutter_api__set_video_quality_impl(
+        204 => wire__crate__api__flutter_api__send_presence_impl(port, ptr, rust_vec_len, data_len),
+        205 => wire__crate__api__flutter_api__send_signal_impl(port, ptr, rust_vec_len, data_len),
+        206 => wire__crate__api__live_flutter_api__set_video_quality_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        153 => wire__crate__api__direct_flutter_api__shutdown_direct_streaming_impl(
+        207 => wire__crate__api__direct_flutter_api__shutdown_direct_streaming_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        154 => wire__crate__api__flutter_api__shutdown_streaming_impl(
+        208 => wire__crate__api__flutter_api__shutdown_streaming_impl(
             port,
             ptr,
             rust_vec_len,
             data_len,
         ),
-        155 => wire__crate__api__live_flutter_api__stop_live_broadcast_impl(
+        209 => wire__crate__api__live_flutter_api__stop_live_broadcast_impl(
             port,
             ptr,
             rust_vec_len,
@@ -7830,189 +10640,224 @@ fn pde_ffi_dispatcher_sync_impl(
 ) -> flutter_rust_bridge::for_generated::WireSyncRust2DartSse {
     // Codec=Pde (Serialization + dispatch), see doc to use other codecs
     match func_id {
-        52 => {
+        53 => {
             wire__crate__api__flutter_api__get_quality_constraints_impl(ptr, rust_vec_len, data_len)
         }
-        55 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
-        61 => wire__crate__api__iroh_live_flutter_api__iroh_capture_current_device_impl(
+        56 => wire__crate__api__simple__greet_impl(ptr, rust_vec_len, data_len),
+        63 => wire__crate__api__iroh_live_flutter_api__iroh_capture_current_device_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        64 => wire__crate__api__iroh_live_flutter_api__iroh_capture_get_test_frame_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        65 => wire__crate__api__iroh_live_flutter_api__iroh_capture_init_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        66 => wire__crate__api__iroh_live_flutter_api__iroh_capture_list_devices_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        67 => wire__crate__api__iroh_live_flutter_api__iroh_capture_start_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        68 => wire__crate__api__iroh_live_flutter_api__iroh_capture_stop_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        69 => wire__crate__api__iroh_live_flutter_api__iroh_catalog_create_impl(
+            ptr,
+            rust_vec_len,
+            data_len,
+        ),
+        70 => wire__crate__api__iroh_live_flutter_api__iroh_catalog_to_json_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        62 => wire__crate__api__iroh_live_flutter_api__iroh_capture_get_test_frame_impl(
+        75 => wire__crate__api__iroh_live_flutter_api__iroh_get_audio_presets_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        63 => wire__crate__api__iroh_live_flutter_api__iroh_capture_init_impl(
+        76 => wire__crate__api__iroh_live_flutter_api__iroh_get_features_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        64 => wire__crate__api__iroh_live_flutter_api__iroh_capture_list_devices_impl(
+        77 => wire__crate__api__iroh_live_flutter_api__iroh_get_supported_audio_codecs_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        65 => wire__crate__api__iroh_live_flutter_api__iroh_capture_start_impl(
+        78 => wire__crate__api__iroh_live_flutter_api__iroh_get_supported_video_codecs_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        66 => wire__crate__api__iroh_live_flutter_api__iroh_capture_stop_impl(
+        79 => wire__crate__api__iroh_live_flutter_api__iroh_get_version_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        67 => wire__crate__api__iroh_live_flutter_api__iroh_catalog_create_impl(
+        80 => wire__crate__api__iroh_live_flutter_api__iroh_get_video_presets_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        68 => wire__crate__api__iroh_live_flutter_api__iroh_catalog_to_json_impl(
+        81 => wire__crate__api__iroh_live_flutter_api__iroh_identity_endpoint_id_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        69 => wire__crate__api__iroh_live_flutter_api__iroh_get_audio_presets_impl(
+        82 => wire__crate__api__iroh_live_flutter_api__iroh_identity_generate_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        70 => wire__crate__api__iroh_live_flutter_api__iroh_get_features_impl(
+        83 => wire__crate__api__iroh_live_flutter_api__iroh_is_codec_hw_accelerated_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        71 => wire__crate__api__iroh_live_flutter_api__iroh_get_supported_audio_codecs_impl(
+        94 => wire__crate__api__iroh_live_flutter_api__iroh_publish_create_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        72 => wire__crate__api__iroh_live_flutter_api__iroh_get_supported_video_codecs_impl(
+        101 => wire__crate__api__iroh_live_flutter_api__iroh_publish_get_status_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        73 => wire__crate__api__iroh_live_flutter_api__iroh_get_version_impl(
+        102 => wire__crate__api__iroh_live_flutter_api__iroh_publish_get_ticket_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        74 => wire__crate__api__iroh_live_flutter_api__iroh_get_video_presets_impl(
+        103 => wire__crate__api__iroh_live_flutter_api__iroh_publish_get_video_stats_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        75 => wire__crate__api__iroh_live_flutter_api__iroh_is_codec_hw_accelerated_impl(
+        105 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_audio_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        79 => wire__crate__api__iroh_live_flutter_api__iroh_publish_create_impl(
+        106 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_audio_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        81 => wire__crate__api__iroh_live_flutter_api__iroh_publish_get_status_impl(
+        107 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_video_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        82 => wire__crate__api__iroh_live_flutter_api__iroh_publish_get_ticket_impl(
+        108 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_video_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        83 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_audio_impl(
+        111 => wire__crate__api__iroh_live_flutter_api__iroh_publish_remove_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        84 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_audio_impl(
+        115 => wire__crate__api__iroh_live_flutter_api__iroh_publish_set_video_renditions_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        85 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_encoded_video_impl(
+        116 => wire__crate__api__iroh_live_flutter_api__iroh_publish_start_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        86 => wire__crate__api__iroh_live_flutter_api__iroh_publish_push_video_impl(
+        118 => wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        87 => wire__crate__api__iroh_live_flutter_api__iroh_publish_remove_impl(
+        120 => wire__crate__api__iroh_live_flutter_api__iroh_publish_take_keyframe_request_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        88 => wire__crate__api__iroh_live_flutter_api__iroh_publish_set_video_renditions_impl(
+        136 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        89 => wire__crate__api__iroh_live_flutter_api__iroh_publish_start_impl(
+        140 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        91 => wire__crate__api__iroh_live_flutter_api__iroh_publish_stop_impl(
+        142 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        93 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_connect_impl(
+        145 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_status_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        95 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_create_impl(
+        150 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_remove_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        97 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_disconnect_impl(
+        153 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_quality_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        99 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_get_status_impl(
+        154 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_simulate_video_receive_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        101 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_remove_impl(
+        155 => wire__crate__api__iroh_live_flutter_api__iroh_ticket_for_broadcast_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        102 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_set_quality_impl(
+        156 => wire__crate__api__iroh_live_flutter_api__iroh_ticket_parse_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        103 => wire__crate__api__iroh_live_flutter_api__iroh_subscribe_simulate_video_receive_impl(
+        157 => wire__crate__api__iroh_live_flutter_api__iroh_ticket_with_media_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        104 => wire__crate__api__iroh_live_flutter_api__iroh_ticket_parse_impl(
+        158 => wire__crate__api__iroh_live_flutter_api__iroh_ticket_without_media_key_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        105 => {
+        159 => {
             wire__crate__api__flutter_api__is_connected_to_stream_impl(ptr, rust_vec_len, data_len)
         }
-        106 => wire__crate__api__direct_flutter_api__is_direct_streaming_initialized_impl(
+        160 => wire__crate__api__direct_flutter_api__is_direct_streaming_initialized_impl(
             ptr,
             rust_vec_len,
             data_len,
         ),
-        107 => wire__crate__api__flutter_api__is_streaming_initialized_impl(
+        161 => wire__crate__api__flutter_api__is_streaming_initialized_impl(
             ptr,
             rust_vec_len,
             data_len,
@@ -8023,6 +10868,54 @@ fn pde_ffi_dispatcher_sync_impl(
 
 // Section: rust2dart
 
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterAbrEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.from_track.into_into_dart().into_dart(),
+            self.to_track.into_into_dart().into_dart(),
+            self.reason.into_into_dart().into_dart(),
+            self.throughput_bps.into_into_dart().into_dart(),
+            self.rtt_ms.into_into_dart().into_dart(),
+            self.loss_percent.into_into_dart().into_dart(),
+            self.late_frames.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterAbrEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterAbrEvent>
+    for crate::api::iroh_live_flutter_api::FlutterAbrEvent
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterAbrEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterAccessPolicy {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.require_token.into_into_dart().into_dart(),
+            self.allow.into_into_dart().into_dart(),
+            self.deny.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterAccessPolicy
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterAccessPolicy>
+    for crate::api::iroh_live_flutter_api::FlutterAccessPolicy
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterAccessPolicy {
+        self
+    }
+}
 // Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::ffmpeg_flutter_api::FlutterAudioCodec {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
@@ -8164,6 +11057,55 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::live_flutter_api::FlutterCata
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterChatMessage {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.seq.into_into_dart().into_dart(),
+            self.sender.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.timestamp_ms.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.text.into_into_dart().into_dart(),
+            self.reaction_to.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterChatMessage
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterChatMessage>
+    for crate::api::iroh_live_flutter_api::FlutterChatMessage
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterChatMessage {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterConnectionState {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.state.into_into_dart().into_dart(),
+            self.attempt.into_into_dart().into_dart(),
+            self.retry_in_ms.into_into_dart().into_dart(),
+            self.error.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterConnectionState
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterConnectionState>
+    for crate::api::iroh_live_flutter_api::FlutterConnectionState
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterConnectionState {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::live_flutter_api::FlutterConnectionStats {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8342,6 +11284,27 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::direct_flutter_api::FlutterDi
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.broadcast_name.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent>
+    for crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::ffmpeg_flutter_api::FlutterEncodedAudioFrame {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
@@ -8472,6 +11435,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::ffmpeg_flutter_api::FlutterEn
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterEndpointConfig {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.relay_mode.into_into_dart().into_dart(),
+            self.bind_port.into_into_dart().into_dart(),
+            self.local_discovery.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterEndpointConfig
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterEndpointConfig>
+    for crate::api::iroh_live_flutter_api::FlutterEndpointConfig
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterEndpointConfig {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::moq_flutter_api::FlutterFilterType {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -8571,6 +11556,28 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::ffmpeg_flutter_api::FlutterHa
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterIssuedTicket {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.ticket.into_into_dart().into_dart(),
+            self.token_id.into_into_dart().into_dart(),
+            self.expires_at.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterIssuedTicket
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterIssuedTicket>
+    for crate::api::iroh_live_flutter_api::FlutterIssuedTicket
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterIssuedTicket {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::live_flutter_api::FlutterLiveEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -8752,39 +11759,183 @@ impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::Flutte
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::iroh_live_flutter_api::FlutterPublisherStatus
+    for crate::api::iroh_live_flutter_api::FlutterPublisherStatus
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterPublisherStatus>
+    for crate::api::iroh_live_flutter_api::FlutterPublisherStatus
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterPublisherStatus {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart
+    for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
+{
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.timestamp_ms.into_into_dart().into_dart(),
+            self.width.into_into_dart().into_dart(),
+            self.height.into_into_dart().into_dart(),
+            self.is_keyframe.into_into_dart().into_dart(),
+            self.data.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame>
+    for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterRecordingStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.files.into_into_dart().into_dart(),
+            self.fragments.into_into_dart().into_dart(),
+            self.video_frames.into_into_dart().into_dart(),
+            self.audio_frames.into_into_dart().into_dart(),
+            self.bytes.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterRecordingStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterRecordingStats>
+    for crate::api::iroh_live_flutter_api::FlutterRecordingStats
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterRecordingStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterRelayInfo {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.broadcast_name.into_into_dart().into_dart(),
+            self.route.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterRelayInfo
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterRelayInfo>
+    for crate::api::iroh_live_flutter_api::FlutterRelayInfo
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterRelayInfo {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterRelayMode {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        match self {
+            crate::api::iroh_live_flutter_api::FlutterRelayMode::Default => {
+                [0.into_dart()].into_dart()
+            }
+            crate::api::iroh_live_flutter_api::FlutterRelayMode::Custom { url } => {
+                [1.into_dart(), url.into_into_dart().into_dart()].into_dart()
+            }
+            crate::api::iroh_live_flutter_api::FlutterRelayMode::Disabled => {
+                [2.into_dart()].into_dart()
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterRelayMode
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterRelayMode>
+    for crate::api::iroh_live_flutter_api::FlutterRelayMode
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterRelayMode {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterRenditionStats {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.track_name.into_into_dart().into_dart(),
+            self.frames_written.into_into_dart().into_dart(),
+            self.bytes_written.into_into_dart().into_dart(),
+            self.frames_dropped.into_into_dart().into_dart(),
+            self.groups_cut.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterRenditionStats
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterRenditionStats>
+    for crate::api::iroh_live_flutter_api::FlutterRenditionStats
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterRenditionStats {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterRoomEvent {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.kind.into_into_dart().into_dart(),
+            self.member.into_into_dart().into_dart(),
+            self.subscriber_id.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterRoomEvent
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterPublisherStatus>
-    for crate::api::iroh_live_flutter_api::FlutterPublisherStatus
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterRoomEvent>
+    for crate::api::iroh_live_flutter_api::FlutterRoomEvent
 {
-    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterPublisherStatus {
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterRoomEvent {
         self
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
-impl flutter_rust_bridge::IntoDart
-    for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
-{
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterRosterEvent {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         [
-            self.timestamp_ms.into_into_dart().into_dart(),
-            self.width.into_into_dart().into_dart(),
-            self.height.into_into_dart().into_dart(),
-            self.is_keyframe.into_into_dart().into_dart(),
-            self.data.into_into_dart().into_dart(),
+            self.kind.into_into_dart().into_dart(),
+            self.endpoint_id.into_into_dart().into_dart(),
+            self.viewer.into_into_dart().into_dart(),
+            self.kicked.into_into_dart().into_dart(),
         ]
         .into_dart()
     }
 }
 impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
-    for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
+    for crate::api::iroh_live_flutter_api::FlutterRosterEvent
 {
 }
-impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame>
-    for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterRosterEvent>
+    for crate::api::iroh_live_flutter_api::FlutterRosterEvent
 {
-    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame {
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterRosterEvent {
         self
     }
 }
@@ -9121,6 +12272,32 @@ impl flutter_rust_bridge::IntoIntoDart<crate::api::live_flutter_api::FlutterVide
     }
 }
 // Codec=Dco (DartCObject based), see doc to use other codecs
+impl flutter_rust_bridge::IntoDart for crate::api::iroh_live_flutter_api::FlutterViewer {
+    fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
+        [
+            self.endpoint_id.into_into_dart().into_dart(),
+            self.display_name.into_into_dart().into_dart(),
+            self.connected_at_ms.into_into_dart().into_dart(),
+            self.tracks.into_into_dart().into_dart(),
+            self.bytes_sent.into_into_dart().into_dart(),
+            self.bytes_received.into_into_dart().into_dart(),
+            self.rtt_ms.into_into_dart().into_dart(),
+        ]
+        .into_dart()
+    }
+}
+impl flutter_rust_bridge::for_generated::IntoDartExceptPrimitive
+    for crate::api::iroh_live_flutter_api::FlutterViewer
+{
+}
+impl flutter_rust_bridge::IntoIntoDart<crate::api::iroh_live_flutter_api::FlutterViewer>
+    for crate::api::iroh_live_flutter_api::FlutterViewer
+{
+    fn into_into_dart(self) -> crate::api::iroh_live_flutter_api::FlutterViewer {
+        self
+    }
+}
+// Codec=Dco (DartCObject based), see doc to use other codecs
 impl flutter_rust_bridge::IntoDart for crate::api::flutter_api::Quality {
     fn into_dart(self) -> flutter_rust_bridge::for_generated::DartAbi {
         match self {
@@ -9209,6 +12386,28 @@ impl SseEncode for f64 {
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterAbrEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.from_track, serializer);
+        <String>::sse_encode(self.to_track, serializer);
+        <String>::sse_encode(self.reason, serializer);
+        <u64>::sse_encode(self.throughput_bps, serializer);
+        <u32>::sse_encode(self.rtt_ms, serializer);
+        <f32>::sse_encode(self.loss_percent, serializer);
+        <u64>::sse_encode(self.late_frames, serializer);
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterAccessPolicy {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.require_token, serializer);
+        <Vec<String>>::sse_encode(self.allow, serializer);
+        <Vec<String>>::sse_encode(self.deny, serializer);
+    }
+}
+
 impl SseEncode for crate::api::ffmpeg_flutter_api::FlutterAudioCodec {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9288,6 +12487,29 @@ impl SseEncode for crate::api::live_flutter_api::FlutterCatalog {
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterChatMessage {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <u64>::sse_encode(self.seq, serializer);
+        <String>::sse_encode(self.sender, serializer);
+        <Option<String>>::sse_encode(self.display_name, serializer);
+        <u64>::sse_encode(self.timestamp_ms, serializer);
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.text, serializer);
+        <Option<u64>>::sse_encode(self.reaction_to, serializer);
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterConnectionState {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.state, serializer);
+        <u32>::sse_encode(self.attempt, serializer);
+        <u64>::sse_encode(self.retry_in_ms, serializer);
+        <Option<String>>::sse_encode(self.error, serializer);
+    }
+}
+
 impl SseEncode for crate::api::live_flutter_api::FlutterConnectionStats {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9419,6 +12641,14 @@ impl SseEncode for crate::api::direct_flutter_api::FlutterDirectEvent {
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.broadcast_name, serializer);
+    }
+}
+
 impl SseEncode for crate::api::ffmpeg_flutter_api::FlutterEncodedAudioFrame {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9488,6 +12718,18 @@ impl SseEncode for crate::api::ffmpeg_flutter_api::FlutterEncoderPreset {
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterEndpointConfig {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <crate::api::iroh_live_flutter_api::FlutterRelayMode>::sse_encode(
+            self.relay_mode,
+            serializer,
+        );
+        <Option<u16>>::sse_encode(self.bind_port, serializer);
+        <bool>::sse_encode(self.local_discovery, serializer);
+    }
+}
+
 impl SseEncode for crate::api::moq_flutter_api::FlutterFilterType {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9565,6 +12807,15 @@ impl SseEncode for crate::api::ffmpeg_flutter_api::FlutterHardwareAccel {
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterIssuedTicket {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.ticket, serializer);
+        <u64>::sse_encode(self.token_id, serializer);
+        <u64>::sse_encode(self.expires_at, serializer);
+    }
+}
+
 impl SseEncode for crate::api::live_flutter_api::FlutterLiveEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9704,6 +12955,79 @@ impl SseEncode for crate::api::iroh_live_flutter_api::FlutterReceivedVideoFrame
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterRecordingStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <Vec<String>>::sse_encode(self.files, serializer);
+        <u64>::sse_encode(self.fragments, serializer);
+        <u64>::sse_encode(self.video_frames, serializer);
+        <u64>::sse_encode(self.audio_frames, serializer);
+        <u64>::sse_encode(self.bytes, serializer);
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterRelayInfo {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.broadcast_name, serializer);
+        <Vec<String>>::sse_encode(self.route, serializer);
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterRelayMode {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        match self {
+            crate::api::iroh_live_flutter_api::FlutterRelayMode::Default => {
+                <i32>::sse_encode(0, serializer);
+            }
+            crate::api::iroh_live_flutter_api::FlutterRelayMode::Custom { url } => {
+                <i32>::sse_encode(1, serializer);
+                <String>::sse_encode(url, serializer);
+            }
+            crate::api::iroh_live_flutter_api::FlutterRelayMode::Disabled => {
+                <i32>::sse_encode(2, serializer);
+            }
+            _ => {
+                unimplemented!("");
+            }
+        }
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterRenditionStats {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.track_name, serializer);
+        <u64>::sse_encode(self.frames_written, serializer);
+        <u64>::sse_encode(self.bytes_written, serializer);
+        <u64>::sse_encode(self.frames_dropped, serializer);
+        <u64>::sse_encode(self.groups_cut, serializer);
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterRoomEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.member, serializer);
+        <String>::sse_encode(self.subscriber_id, serializer);
+    }
+}
+
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterRosterEvent {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.kind, serializer);
+        <String>::sse_encode(self.endpoint_id, serializer);
+        <Option<crate::api::iroh_live_flutter_api::FlutterViewer>>::sse_encode(
+            self.viewer,
+            serializer,
+        );
+        <bool>::sse_encode(self.kicked, serializer);
+    }
+}
+
 impl SseEncode for crate::api::flutter_api::FlutterStreamEvent {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9917,6 +13241,19 @@ impl SseEncode for crate::api::live_flutter_api::FlutterVideoTrack {
     }
 }
 
+impl SseEncode for crate::api::iroh_live_flutter_api::FlutterViewer {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <String>::sse_encode(self.endpoint_id, serializer);
+        <Option<String>>::sse_encode(self.display_name, serializer);
+        <u64>::sse_encode(self.connected_at_ms, serializer);
+        <Vec<String>>::sse_encode(self.tracks, serializer);
+        <u64>::sse_encode(self.bytes_sent, serializer);
+        <u64>::sse_encode(self.bytes_received, serializer);
+        <u32>::sse_encode(self.rtt_ms, serializer);
+    }
+}
+
 impl SseEncode for i16 {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9948,6 +13285,16 @@ impl SseEncode for Vec<String> {
     }
 }
 
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterAbrEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterAbrEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterAudioRendition> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9970,6 +13317,28 @@ impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterCaptureDevice>
     }
 }
 
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterChatMessage> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterChatMessage>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterConnectionState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterConnectionState>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::direct_flutter_api::FlutterDirectEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -9980,6 +13349,18 @@ impl SseEncode for Vec<crate::api::direct_flutter_api::FlutterDirectEvent> {
     }
 }
 
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterDiscoveryEvent>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::ffmpeg_flutter_api::FlutterEncodedVideoFrame> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10024,6 +13405,48 @@ impl SseEncode for Vec<crate::api::moq_flutter_api::FlutterNamespaceAnnouncement
     }
 }
 
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterRelayInfo> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterRelayInfo>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterRenditionStats> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterRenditionStats>::sse_encode(
+                item, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterRoomEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterRoomEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterRosterEvent> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterRosterEvent>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<crate::api::flutter_api::FlutterStreamEvent> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10076,6 +13499,16 @@ impl SseEncode for Vec<crate::api::live_flutter_api::FlutterVideoTrack> {
     }
 }
 
+impl SseEncode for Vec<crate::api::iroh_live_flutter_api::FlutterViewer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <i32>::sse_encode(self.len() as _, serializer);
+        for item in self {
+            <crate::api::iroh_live_flutter_api::FlutterViewer>::sse_encode(item, serializer);
+        }
+    }
+}
+
 impl SseEncode for Vec<i16> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10136,6 +13569,30 @@ impl SseEncode for Option<f64> {
     }
 }
 
+impl SseEncode for Option<crate::api::iroh_live_flutter_api::FlutterConnectionState> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::iroh_live_flutter_api::FlutterConnectionState>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::iroh_live_flutter_api::FlutterEndpointConfig> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::iroh_live_flutter_api::FlutterEndpointConfig>::sse_encode(
+                value, serializer,
+            );
+        }
+    }
+}
+
 impl SseEncode for Option<crate::api::moq_flutter_api::FlutterMoqObject> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
@@ -10204,6 +13661,36 @@ impl SseEncode for Option<crate::api::iroh_live_flutter_api::FlutterTicketInfo>
     }
 }
 
+impl SseEncode for Option<crate::api::iroh_live_flutter_api::FlutterVideoFrame> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::iroh_live_flutter_api::FlutterVideoFrame>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<crate::api::iroh_live_flutter_api::FlutterViewer> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <crate::api::iroh_live_flutter_api::FlutterViewer>::sse_encode(value, serializer);
+        }
+    }
+}
+
+impl SseEncode for Option<u16> {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
+        <bool>::sse_encode(self.is_some(), serializer);
+        if let Some(value) = self {
+            <u16>::sse_encode(value, serializer);
+        }
+    }
+}
+
 impl SseEncode for Option<u32> {
     // Codec=Sse (Serialization based), see doc to use other codecs
     fn sse_encode(self, serializer: &mut flutter_rust_bridge::for_generated::SseSerializer) {
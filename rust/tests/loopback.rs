@@ -0,0 +1,807 @@
+//! End-to-end tests over two in-process iroh endpoints
+//!
+//! Endpoints bind to localhost with relays and discovery disabled and dial
+//! each other by direct address, so these run offline. They cover the real
+//! network path: `Live` actor, WebTransport session, MoQ announce/subscribe
+//! and the broadcast publisher and subscriber pipelines.
+
+use std::collections::HashMap;
+use std::net::{Ipv4Addr, SocketAddrV4};
+
+use bytes::Bytes;
+use iroh::protocol::Router;
+use iroh::{Endpoint, EndpointAddr, RelayMode};
+use n0_future::time::{Duration, Instant, sleep, timeout};
+
+use rust_lib_cyberfly_streaming::api::access::{BroadcastPolicy, SignedToken};
+use rust_lib_cyberfly_streaming::api::av::VideoSource;
+use rust_lib_cyberfly_streaming::api::capture::{TestPattern, TestPatternSource};
+use rust_lib_cyberfly_streaming::api::chat::{ChatKind, ChatPost, ChatRate, ChatReader};
+use rust_lib_cyberfly_streaming::api::discovery::{Discovery, DiscoveryEvent};
+use rust_lib_cyberfly_streaming::api::endpoint_config::{EndpointConfig, RelayOption};
+use rust_lib_cyberfly_streaming::api::iroh_live::LiveNode;
+use rust_lib_cyberfly_streaming::api::live_protocol::{ALPN, LEGACY_ALPN, Live, LiveSession, PeerEvent};
+use rust_lib_cyberfly_streaming::api::publish_broadcast::{
+    AudioQuality, EncodedAudioFrame, EncodedVideoFrame, PublishBroadcast, PublishConfig, PublishHandle,
+    VideoQuality,
+};
+use rust_lib_cyberfly_streaming::api::reconnect::{BroadcastLink, ConnectionState, Lost, ReconnectConfig, StateReporter};
+use rust_lib_cyberfly_streaming::api::relay::{self, ROUTE_ALPN, RelayConfig, RouteProtocolHandler};
+use rust_lib_cyberfly_streaming::api::room::{Room, RoomEvent, member_path};
+use rust_lib_cyberfly_streaming::api::roster::RosterEvent;
+use rust_lib_cyberfly_streaming::api::subscribe_broadcast::{EndReason, ReceivedVideoFrame, SubscribeBroadcast};
+use rust_lib_cyberfly_streaming::api::webtransport::Session;
+
+const BROADCAST: &str = "loopback";
+/// Test pattern frames to send
+const FRAMES: usize = 60;
+/// Frames per group; every group starts on a keyframe
+const GROUP: usize = 15;
+const FPS: u32 = 60;
+const WAIT: Duration = Duration::from_secs(10);
+
+/// A node with its endpoint, `Live` actor and router
+struct Node {
+    live: Live,
+    router: Router,
+}
+
+impl Node {
+    async fn spawn() -> Self {
+        let endpoint = Endpoint::empty_builder(RelayMode::Disabled)
+            .alpns(vec![ALPN.to_vec(), LEGACY_ALPN.to_vec(), ROUTE_ALPN.to_vec()])
+            .bind_addr_v4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0))
+            .bind()
+            .await
+            .expect("bind endpoint");
+        let live = Live::new(endpoint.clone());
+        let router = Router::builder(endpoint)
+            .accept(ALPN, live.protocol_handler())
+            .accept(LEGACY_ALPN, live.protocol_handler())
+            .accept(ROUTE_ALPN, RouteProtocolHandler::new(live.clone()))
+            .spawn();
+        Self { live, router }
+    }
+
+    /// Direct loopback address; there is no relay or discovery to find it
+    fn addr(&self) -> EndpointAddr {
+        let endpoint = self.live.endpoint();
+        endpoint
+            .bound_sockets()
+            .into_iter()
+            .filter(|addr| addr.is_ipv4())
+            .fold(EndpointAddr::new(endpoint.id()), |addr, socket| addr.with_ip_addr(socket))
+    }
+
+    async fn connect(&self, other: &Node) -> LiveSession {
+        timeout(WAIT, self.live.connect(other.addr()))
+            .await
+            .expect("connect timed out")
+            .expect("connect")
+    }
+
+    async fn shutdown(self) {
+        self.live.shutdown();
+        timeout(WAIT, self.router.shutdown())
+            .await
+            .expect("router shutdown timed out")
+            .expect("router shutdown");
+    }
+}
+
+/// Publish a broadcast with one video and one audio rendition
+async fn publish(node: &Node) -> PublishHandle {
+    let config = PublishConfig {
+        name: BROADCAST.to_string(),
+        video_qualities: vec![VideoQuality::Medium],
+        audio_qualities: vec![AudioQuality::Medium],
+        ..Default::default()
+    };
+    let (broadcast, handle) = PublishBroadcast::new(config);
+    node.live.publish(BROADCAST, broadcast.producer().clone()).await.unwrap();
+    tokio::spawn(broadcast.run());
+    handle
+}
+
+/// Push test pattern frames as they come due, returning their payloads
+///
+/// The raw RGBA frames stand in for encoded video; the transport doesn't
+/// look inside them.
+async fn push_test_pattern(handle: &PublishHandle) -> Vec<Bytes> {
+    let mut source = TestPatternSource::new(128, 72, FPS, TestPattern::MovingBox);
+    let mut sent = Vec::new();
+    while sent.len() < FRAMES {
+        let Some(frame) = source.pop_frame().unwrap() else {
+            sleep(Duration::from_millis(2)).await;
+            continue;
+        };
+        let data = Bytes::from(frame.data);
+        handle
+            .push_video(EncodedVideoFrame {
+                data: data.clone(),
+                pts_us: frame.pts_us,
+                is_keyframe: sent.len() % GROUP == 0,
+                quality: VideoQuality::Medium,
+            })
+            .await
+            .unwrap();
+        handle
+            .push_audio(EncodedAudioFrame {
+                data: Bytes::from_static(&[0xf8, 0xff, 0xfe]),
+                pts_us: frame.pts_us,
+                quality: AudioQuality::Medium,
+            })
+            .await
+            .unwrap();
+        sent.push(data);
+    }
+    sent
+}
+
+#[tokio::test]
+async fn test_loopback_broadcast() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+
+    let mut session = viewer.connect(&publisher).await;
+    let broadcast = timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+    let mut subscription = SubscribeBroadcast::with_default(broadcast).start().await.unwrap();
+    assert_eq!(subscription.video_track(), "video.med");
+    assert_eq!(subscription.audio_track(), "audio.med");
+    // Let the track subscriptions reach the publisher before the first group
+    sleep(Duration::from_millis(250)).await;
+
+    let sent = push_test_pattern(&handle).await;
+
+    let mut received: Vec<ReceivedVideoFrame> = Vec::new();
+    while received.len() < FRAMES {
+        let frame = timeout(WAIT, subscription.recv_video())
+            .await
+            .expect("video frame timed out")
+            .expect("video track ended early");
+        received.push(frame);
+    }
+
+    // Every frame arrives once, in order and intact
+    let payloads: Vec<_> = received.iter().map(|f| f.data.clone()).collect();
+    assert_eq!(payloads, sent);
+    for pair in received.windows(2) {
+        assert!(pair[0].pts_us < pair[1].pts_us, "timestamps out of order");
+        assert!(pair[0].sequence < pair[1].sequence, "sequence numbers out of order");
+    }
+
+    // Groups start exactly where the publisher put keyframes
+    let keyframes: Vec<_> = received
+        .iter()
+        .enumerate()
+        .filter(|(_, frame)| frame.is_keyframe)
+        .map(|(i, _)| i)
+        .collect();
+    assert_eq!(keyframes, (0..FRAMES).step_by(GROUP).collect::<Vec<_>>());
+    assert_eq!(subscription.video_stats().frames(), FRAMES as u64);
+    assert_eq!(subscription.video_stats().skipped_groups(), 0);
+
+    let audio = timeout(WAIT, subscription.recv_audio())
+        .await
+        .expect("audio frame timed out")
+        .expect("audio track ended early");
+    assert_eq!(audio.track, "audio.med");
+
+    // Stopping the broadcast ends the tracks and withdraws the announcement
+    handle.stop().await.unwrap();
+    publisher.live.unpublish(BROADCAST).await.unwrap();
+    timeout(WAIT, session.broadcast_ended(BROADCAST))
+        .await
+        .expect("broadcast did not end");
+    let rest = timeout(WAIT, async {
+        let mut rest = 0;
+        while subscription.recv_video().await.is_some() {
+            rest += 1;
+        }
+        rest
+    })
+    .await
+    .expect("video track did not close");
+    assert_eq!(rest, 0, "frames after the last one sent");
+    // ...and the subscription tells that apart from a dropped connection
+    let reason = timeout(WAIT, subscription.ended()).await.expect("subscription did not end");
+    assert_eq!(reason, EndReason::Ended);
+
+    subscription.stop();
+    drop(session);
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_relay_refuses_loop() {
+    let origin = Node::spawn().await;
+    let relay_a = Node::spawn().await;
+    let relay_b = Node::spawn().await;
+    let handle = publish(&origin).await;
+
+    let config = RelayConfig::default();
+    let first = timeout(WAIT, relay::relay(&relay_a.live, origin.addr(), BROADCAST, &config))
+        .await
+        .expect("relay timed out")
+        .unwrap();
+    assert_eq!(first.route(), [origin.live.endpoint().id()]);
+
+    let second = timeout(WAIT, relay::relay(&relay_b.live, relay_a.addr(), BROADCAST, &config))
+        .await
+        .expect("relay timed out")
+        .unwrap();
+    assert_eq!(
+        second.route(),
+        [origin.live.endpoint().id(), relay_a.live.endpoint().id()]
+    );
+
+    // relay_a is already on the route it would get from relay_b
+    let looped = relay::relay(&relay_a.live, relay_b.addr(), BROADCAST, &config).await;
+    assert!(looped.is_err());
+    assert!(first.is_active());
+
+    second.stop();
+    first.stop();
+    handle.stop().await.unwrap();
+    relay_b.shutdown().await;
+    relay_a.shutdown().await;
+    origin.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_legacy_alpn() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+
+    // A version 1 peer starts MoQ straight away, without the handshake
+    let conn = timeout(WAIT, viewer.live.endpoint().connect(publisher.addr(), LEGACY_ALPN))
+        .await
+        .expect("connect timed out")
+        .unwrap();
+    let mut session = LiveSession::session_connect(Session::new(conn), &Default::default())
+        .await
+        .unwrap();
+    timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+
+    handle.stop().await.unwrap();
+    drop(session);
+    publisher.shutdown().await;
+
+    // Dialing a node that only speaks version 1 falls back to it
+    let endpoint = Endpoint::empty_builder(RelayMode::Disabled)
+        .bind_addr_v4(SocketAddrV4::new(Ipv4Addr::LOCALHOST, 0))
+        .bind()
+        .await
+        .expect("bind endpoint");
+    let live = Live::new(endpoint.clone());
+    let router = Router::builder(endpoint)
+        .accept(LEGACY_ALPN, live.protocol_handler())
+        .spawn();
+    let legacy = Node { live, router };
+    let handle = publish(&legacy).await;
+    let mut session = viewer.connect(&legacy).await;
+    assert_eq!(session.conn().alpn(), LEGACY_ALPN);
+    timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+
+    handle.stop().await.unwrap();
+    drop(session);
+    viewer.shutdown().await;
+    legacy.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_restricted_broadcast() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    publisher
+        .live
+        .set_access_policy(BROADCAST, BroadcastPolicy {
+            require_token: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+
+    // Without a token the broadcast is never announced, nor its route revealed
+    let mut anonymous = viewer.connect(&publisher).await;
+    let announced = timeout(Duration::from_secs(1), anonymous.subscribe(BROADCAST)).await;
+    assert!(announced.is_err(), "restricted broadcast announced without a token");
+    let route = relay::query_route(viewer.live.endpoint(), publisher.addr(), BROADCAST, None)
+        .await
+        .unwrap();
+    assert_eq!(route, None, "route revealed without a token");
+
+    let token = SignedToken::issue(
+        publisher.live.endpoint().secret_key(),
+        vec![BROADCAST.to_string()],
+        Some(viewer.live.endpoint().id()),
+        Duration::from_secs(60),
+    );
+    let route = relay::query_route(viewer.live.endpoint(), publisher.addr(), BROADCAST, Some(&token))
+        .await
+        .unwrap();
+    assert_eq!(route, Some(vec![]));
+    let mut session = timeout(WAIT, viewer.live.connect_with_token(publisher.addr(), Some(&token)))
+        .await
+        .expect("connect timed out")
+        .unwrap();
+    timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+
+    // Revoking the token withdraws the broadcast, but not the session
+    publisher.live.revoke_token(token.id()).await.unwrap();
+    timeout(WAIT, session.broadcast_ended(BROADCAST))
+        .await
+        .expect("broadcast not withdrawn after revoke");
+    assert!(session.conn().close_reason().is_none(), "session closed on revoke");
+    let denied = relay::query_denied(viewer.live.endpoint(), publisher.addr(), BROADCAST, Some(&token))
+        .await
+        .unwrap();
+    assert_eq!(denied.as_deref(), Some("token has been revoked"));
+
+    handle.stop().await.unwrap();
+    drop((anonymous, session));
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_viewer_roster() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    let mut events = publisher.live.roster_events();
+    viewer.live.set_display_name(Some("alice".to_string()));
+    let viewer_id = viewer.live.endpoint().id();
+
+    let mut session = viewer.connect(&publisher).await;
+    let joined = timeout(WAIT, events.recv()).await.expect("join timed out").unwrap();
+    let RosterEvent::Joined(joined) = joined else {
+        panic!("expected a join, got {joined:?}");
+    };
+    assert_eq!(joined.endpoint_id, viewer_id);
+    assert_eq!(joined.display_name.as_deref(), Some("alice"));
+
+    let broadcast = timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+    let subscription = SubscribeBroadcast::with_default(broadcast).start().await.unwrap();
+
+    // The roster shows which tracks the viewer receives
+    let video = format!("{BROADCAST}/video.med");
+    timeout(WAIT, async {
+        loop {
+            let viewers = publisher.live.roster().await.unwrap();
+            if viewers.iter().any(|v| v.endpoint_id == viewer_id && v.tracks.contains(&video)) {
+                break;
+            }
+            sleep(Duration::from_millis(50)).await;
+        }
+    })
+    .await
+    .expect("video track never showed in the roster");
+
+    assert!(publisher.live.kick(viewer_id).await.unwrap());
+    let left = timeout(WAIT, events.recv()).await.expect("leave timed out").unwrap();
+    assert_eq!(left, RosterEvent::Left {
+        endpoint_id: viewer_id,
+        kicked: true,
+    });
+    assert!(publisher.live.roster().await.unwrap().is_empty());
+    assert!(!publisher.live.kick(viewer_id).await.unwrap());
+
+    subscription.stop();
+    handle.stop().await.unwrap();
+    drop(session);
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_chat() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    let rate = ChatRate {
+        burst: 2,
+        per_second: 0.1,
+    };
+    publisher.live.open_chat(BROADCAST, handle.chat_inbox().unwrap(), rate).await.unwrap();
+    let mut published = handle.chat_messages();
+    viewer.live.set_display_name(Some("bob".to_string()));
+    let viewer_id = viewer.live.endpoint().id();
+
+    let mut session = viewer.connect(&publisher).await;
+    let broadcast = timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+    let mut chat = ChatReader::subscribe(&broadcast);
+    let mut sender = session.chat(BROADCAST);
+
+    // The publisher posts as itself, viewers through their session
+    let publisher_id = publisher.live.endpoint().id();
+    handle
+        .post_chat(ChatPost {
+            sender: publisher_id,
+            display_name: None,
+            kind: ChatKind::Text("welcome".to_string()),
+        })
+        .await
+        .unwrap();
+    let welcome = timeout(WAIT, chat.recv()).await.expect("chat timed out").unwrap();
+    assert_eq!((welcome.seq, welcome.sender), (0, publisher_id));
+
+    sender.send(ChatKind::Text("hello".to_string())).unwrap();
+    sender.send(ChatKind::Reaction { target: 0, emoji: "👋".to_string() }).unwrap();
+    // Over the burst, so the publisher drops it
+    sender.send(ChatKind::Text("spam".to_string())).unwrap();
+
+    for (seq, kind) in [
+        (1, ChatKind::Text("hello".to_string())),
+        (2, ChatKind::Reaction { target: 0, emoji: "👋".to_string() }),
+    ] {
+        let message = timeout(WAIT, chat.recv()).await.expect("chat timed out").unwrap();
+        assert_eq!(message.seq, seq);
+        assert_eq!(message.kind, kind);
+        assert_eq!(message.sender, viewer_id);
+        assert_eq!(message.display_name.as_deref(), Some("bob"));
+    }
+    assert!(timeout(Duration::from_millis(500), chat.recv()).await.is_err(), "rate limit not applied");
+
+    // Another session from the same endpoint shares the limit
+    let mut second = viewer.connect(&publisher).await;
+    timeout(WAIT, second.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+    let mut second_sender = second.chat(BROADCAST);
+    second_sender.send(ChatKind::Text("more spam".to_string())).unwrap();
+    assert!(timeout(Duration::from_millis(500), chat.recv()).await.is_err(), "rate limit reset by a new session");
+
+    // The publisher sees the same messages, in the same order
+    let seqs: Vec<_> = std::iter::from_fn(|| published.try_recv().ok()).map(|m| m.seq).collect();
+    assert_eq!(seqs, vec![0, 1, 2]);
+
+    // Only the inbox of an open chat is taken for one
+    let mut peer_events = publisher.live.peer_events();
+    session.publish("other/chat".to_string(), broadcast.clone());
+    let announced = timeout(WAIT, async {
+        loop {
+            if let Ok(PeerEvent::Announced { name, .. }) = peer_events.recv().await {
+                return name;
+            }
+        }
+    });
+    assert_eq!(announced.await.expect("broadcast taken for an inbox"), "other/chat");
+
+    handle.stop().await.unwrap();
+    drop((sender, second_sender, second, session));
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_room() {
+    let host = Node::spawn().await;
+    let alice = Node::spawn().await;
+    let bob = Node::spawn().await;
+    let eve = Node::spawn().await;
+    host.live
+        .set_access_policy("call", BroadcastPolicy {
+            require_token: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let token = SignedToken::issue(
+        host.live.endpoint().secret_key(),
+        vec!["call".to_string()],
+        None,
+        Duration::from_secs(60),
+    );
+    let join = |node: &Node, token: Option<SignedToken>| {
+        let live = node.live.clone();
+        let addr = host.addr();
+        async move {
+            timeout(WAIT, Room::join(&live, addr, "call", token.as_ref()))
+                .await
+                .expect("join timed out")
+                .unwrap()
+        }
+    };
+    let mut rooms = vec![
+        Room::host(&host.live, "call"),
+        join(&alice, Some(token.clone())).await,
+        join(&bob, Some(token)).await,
+    ];
+    let ids = [&host, &alice, &bob].map(|node| node.live.endpoint().id());
+
+    let mut handles = Vec::new();
+    for room in &rooms {
+        let (broadcast, handle) = PublishBroadcast::new(PublishConfig::default());
+        room.publish(broadcast.producer().consume()).await.unwrap();
+        tokio::spawn(broadcast.run());
+        handles.push(handle);
+    }
+
+    // Everyone gets the two others' broadcasts, never their own
+    let mut seen = Vec::new();
+    for (room, own) in rooms.iter_mut().zip(ids) {
+        let mut members = HashMap::new();
+        while members.len() < 2 {
+            match timeout(WAIT, room.recv()).await.expect("room event timed out").unwrap() {
+                RoomEvent::Joined { member, broadcast } => {
+                    assert_ne!(member, own);
+                    members.insert(member, broadcast);
+                }
+                RoomEvent::Left { member } => panic!("{member} left early"),
+            }
+        }
+        seen.push(members);
+    }
+
+    // Without a token Eve is not let in, nor offered the members' broadcasts
+    let eve_room = join(&eve, None).await;
+    let (eve_broadcast, eve_handle) = PublishBroadcast::new(PublishConfig::default());
+    eve_room.publish(eve_broadcast.producer().consume()).await.unwrap();
+    let mut eve_session = eve.connect(&host).await;
+    let offered = timeout(Duration::from_secs(1), eve_session.subscribe(&member_path("call", ids[1]))).await;
+    assert!(offered.is_err(), "member broadcast offered outside the room");
+    for room in &mut rooms {
+        assert!(room.try_recv().is_none(), "outsider joined the room");
+    }
+
+    // Bob watches Alice through the host, over his one session
+    let alice_seen_by_bob = seen[2][&ids[1]].clone();
+    let subscription = timeout(WAIT, SubscribeBroadcast::with_default(alice_seen_by_bob).start())
+        .await
+        .expect("catalog timed out")
+        .unwrap();
+    assert_eq!(subscription.video_track(), "video.med");
+
+    // Alice leaving is seen by the host and by Bob
+    let bob_room = rooms.pop().unwrap();
+    let alice_room = rooms.pop().unwrap();
+    alice_room.leave().await;
+    for mut room in [rooms.pop().unwrap(), bob_room] {
+        let event = timeout(WAIT, room.recv()).await.expect("leave timed out").unwrap();
+        assert!(matches!(event, RoomEvent::Left { member } if member == ids[1]), "unexpected {event:?}");
+        room.leave().await;
+    }
+
+    // Stopped only now: moq-lite drops a session whose subscribe stream is
+    // reset before the host has read it
+    subscription.stop();
+    for handle in handles {
+        handle.stop().await.ok();
+    }
+    drop((eve_session, eve_handle));
+    eve_room.leave().await;
+    eve.shutdown().await;
+    bob.shutdown().await;
+    alice.shutdown().await;
+    host.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_discovery() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    let discover = |prefix: &'static str| {
+        timeout(WAIT, Discovery::connect(&viewer.live, publisher.addr(), prefix, None))
+    };
+    let mut all = discover("").await.expect("connect timed out").unwrap();
+    let mut extras = discover("extra").await.expect("connect timed out").unwrap();
+    let added = |name: &str| Some(DiscoveryEvent::Added { name: name.to_string() });
+
+    assert_eq!(timeout(WAIT, all.recv()).await.expect("discovery timed out"), added(BROADCAST));
+
+    // A later broadcast shows up everywhere its prefix is followed
+    let (extra, extra_handle) = PublishBroadcast::new(PublishConfig::default());
+    publisher.live.publish("extra/one", extra.producer().clone()).await.unwrap();
+    tokio::spawn(extra.run());
+    assert_eq!(timeout(WAIT, all.recv()).await.expect("discovery timed out"), added("extra/one"));
+    assert_eq!(timeout(WAIT, extras.recv()).await.expect("discovery timed out"), added("extra/one"));
+    assert_eq!(all.broadcasts(), vec!["extra/one".to_string(), BROADCAST.to_string()]);
+    assert_eq!(extras.broadcasts(), vec!["extra/one".to_string()]);
+
+    // Ending a broadcast withdraws it
+    handle.stop().await.unwrap();
+    assert_eq!(
+        timeout(WAIT, all.recv()).await.expect("removal timed out"),
+        Some(DiscoveryEvent::Removed { name: BROADCAST.to_string() }),
+    );
+    assert_eq!(extras.broadcasts(), vec!["extra/one".to_string()]);
+    assert!(extras.try_recv().is_none());
+
+    extra_handle.stop().await.unwrap();
+    all.close().await;
+    extras.close().await;
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_reconnect() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    let (states, mut events) = StateReporter::new();
+    let config = ReconnectConfig {
+        initial_delay: Duration::from_millis(50),
+        connect_timeout: WAIT,
+        ..Default::default()
+    };
+    let mut link = BroadcastLink::new(viewer.live.clone(), publisher.addr(), BROADCAST, None, config, states.clone());
+    timeout(WAIT, link.connect()).await.expect("connect timed out").unwrap();
+
+    // Losing the connection drops the session; the link dials again
+    link.session().unwrap().conn().close(0u32.into(), b"gone");
+    assert_eq!(timeout(WAIT, link.lost()).await.expect("drop not noticed"), Lost::Dropped);
+    let broadcast = timeout(WAIT, link.reconnect())
+        .await
+        .expect("reconnect timed out")
+        .expect("reconnect gave up");
+    let subscription = timeout(WAIT, SubscribeBroadcast::with_default(broadcast).start())
+        .await
+        .expect("catalog timed out")
+        .unwrap();
+    assert_eq!(subscription.video_track(), "video.med");
+    let seen: Vec<_> = std::iter::from_fn(|| events.try_recv().ok()).map(|s| s.name()).collect();
+    assert_eq!(seen, vec!["reconnecting", "connecting"]);
+
+    // The publisher ending the broadcast is final
+    handle.stop().await.unwrap();
+    assert_eq!(timeout(WAIT, link.lost()).await.expect("end not noticed"), Lost::Ended);
+    assert_eq!(states.current(), ConnectionState::Ended { error: None });
+
+    subscription.stop();
+    drop(link);
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_kicked_viewer_stays_out() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    let (states, _events) = StateReporter::new();
+    let config = ReconnectConfig {
+        initial_delay: Duration::from_millis(50),
+        connect_timeout: WAIT,
+        ..Default::default()
+    };
+    let mut link = BroadcastLink::new(viewer.live.clone(), publisher.addr(), BROADCAST, None, config, states.clone());
+    timeout(WAIT, link.connect()).await.expect("connect timed out").unwrap();
+
+    // A kick is final, like the publisher ending the broadcast
+    assert!(publisher.live.kick(viewer.live.endpoint().id()).await.unwrap());
+    assert_eq!(timeout(WAIT, link.lost()).await.expect("kick not noticed"), Lost::Ended);
+    assert_eq!(
+        states.current(),
+        ConnectionState::Ended { error: Some("kicked by the publisher".to_string()) },
+    );
+    assert!(link.session().is_none());
+    sleep(Duration::from_millis(200)).await;
+    assert!(publisher.live.roster().await.unwrap().is_empty(), "kicked viewer came back");
+
+    handle.stop().await.unwrap();
+    drop(link);
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_loopback_revoked_viewer_ends() {
+    let publisher = Node::spawn().await;
+    let viewer = Node::spawn().await;
+    let handle = publish(&publisher).await;
+    publisher
+        .live
+        .set_access_policy(BROADCAST, BroadcastPolicy {
+            require_token: true,
+            ..Default::default()
+        })
+        .await
+        .unwrap();
+    let (open, open_handle) = PublishBroadcast::new(PublishConfig::default());
+    publisher.live.publish("open", open.producer().clone()).await.unwrap();
+    tokio::spawn(open.run());
+    let token = SignedToken::issue(
+        publisher.live.endpoint().secret_key(),
+        vec![BROADCAST.to_string()],
+        Some(viewer.live.endpoint().id()),
+        Duration::from_secs(60),
+    );
+
+    let (states, _events) = StateReporter::new();
+    let config = ReconnectConfig {
+        initial_delay: Duration::from_millis(50),
+        connect_timeout: WAIT,
+        ..Default::default()
+    };
+    let mut link = BroadcastLink::new(
+        viewer.live.clone(),
+        publisher.addr(),
+        BROADCAST,
+        Some(token.clone()),
+        config,
+        states.clone(),
+    );
+    timeout(WAIT, link.connect()).await.expect("connect timed out").unwrap();
+    let mut session = timeout(WAIT, viewer.live.connect_with_token(publisher.addr(), Some(&token)))
+        .await
+        .expect("connect timed out")
+        .unwrap();
+    timeout(WAIT, session.subscribe(BROADCAST))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+    let other = timeout(WAIT, session.subscribe("open"))
+        .await
+        .expect("announce timed out")
+        .unwrap();
+
+    // Losing access ends the subscription for good, with the reason
+    publisher.live.revoke_token(token.id()).await.unwrap();
+    assert_eq!(timeout(WAIT, link.lost()).await.expect("revoke not noticed"), Lost::Ended);
+    assert_eq!(
+        states.current(),
+        ConnectionState::Ended { error: Some("access denied: token has been revoked".to_string()) },
+    );
+
+    // The viewer's other broadcasts over the same session carry on
+    timeout(WAIT, session.broadcast_ended(BROADCAST))
+        .await
+        .expect("broadcast not withdrawn after revoke");
+    assert!(session.conn().close_reason().is_none(), "session closed on revoke");
+    assert!(
+        timeout(Duration::from_millis(200), other.closed()).await.is_err(),
+        "open broadcast withdrawn on revoke",
+    );
+
+    handle.stop().await.unwrap();
+    open_handle.stop().await.unwrap();
+    drop((link, session));
+    viewer.shutdown().await;
+    publisher.shutdown().await;
+}
+
+#[tokio::test]
+async fn test_relay_less_node() {
+    let config = EndpointConfig {
+        relay: RelayOption::Disabled,
+        ..Default::default()
+    };
+    let start = Instant::now();
+    let node = LiveNode::with_config(None, config).await.unwrap();
+    // Nothing to wait for without a relay
+    assert!(start.elapsed() < Duration::from_secs(5));
+
+    let ticket = node
+        .create_publisher("pub".to_string(), BROADCAST.to_string())
+        .await
+        .unwrap();
+    assert_eq!(ticket.relay_url, None);
+    assert_eq!(ticket.endpoint_id, node.endpoint_id());
+    node.shutdown().await;
+}